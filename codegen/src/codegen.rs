@@ -1,21 +1,33 @@
 use indexmap::IndexMap;
 
 use crate::{
-  parser::{api::API, context::ParseContext, parser::schema_parser::SchemaParser, ParsedData},
+  common::RenameRule,
+  parser::{
+    api::{PayloadFormat, API},
+    context::ParseContext,
+    parser::schema_parser::SchemaParser,
+    targets::Targets,
+    ParsedData,
+  },
   schemas::{schema::SchemaDefinition, APIDescription},
   structures::enums::{Enum, EnumField},
   writer::{
-    APIEntryModule, APIFunction, APIModule, Directory, TypeEntryModule, TypeModule, Writer,
+    APIEntryModule, APIFunction, APIModule, Directory, Manifest, MediaTypeConstant,
+    OperationsModule, TargetsModule, TypeEntryModule, TypeModule, WebhookEventsModule, Writer,
   },
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Clone)]
 pub struct ParsedAPIDescription {
   tags: IndexMap<String, String>,
   types: IndexMap<String, ParsedData>,
   webhooks: IndexMap<String, ParsedData>,
+  /// The name, operation id and payload of every webhook, in the order of `WebhookEvent`
+  webhook_events: Vec<(String, String, String)>,
   apis: IndexMap<String, Vec<API>>,
+  /// The features of GitHub Enterprise Cloud and Server, e.g. `ghes-3-12`
+  targets: Vec<String>,
 }
 
 pub struct Codegen;
@@ -26,7 +38,10 @@ impl Codegen {
   }
 
   pub fn parse(&self) -> ParsedAPIDescription {
-    let api_description = APIDescription::try_load().unwrap();
+    let mut api_description = APIDescription::try_load().unwrap();
+
+    // The descriptions of GitHub Enterprise Cloud and Server, if any
+    let targets = Targets::merge(&mut api_description, Path::new("codegen/resources")).unwrap();
 
     let mut parse_context = ParseContext::new(api_description.clone());
 
@@ -48,7 +63,10 @@ impl Codegen {
 
         parse_context.set_working_tag(&api.tags[0]);
 
-        let parsed_api = API::parse(&mut parse_context, &method, path, &api);
+        let mut parsed_api = API::parse(&mut parse_context, &method, path, &api);
+
+        parsed_api.github_com = targets.github_com(&method, path);
+        parsed_api.targets = targets.targets(&method, path);
 
         parse_context.add_api(parsed_api);
       }
@@ -57,6 +75,7 @@ impl Codegen {
     let mut schema_parser = SchemaParser::new();
 
     let mut enum_ = Enum::new(&"WebhookEvent".to_string());
+    let mut webhook_events = Vec::new();
 
     for (name, webhook) in &api_description.webhooks {
       parse_context.start_parsing_webhook(name);
@@ -66,6 +85,16 @@ impl Codegen {
         .clone()
         .unwrap_or_else(|| panic!("No post for webhook {}", name));
 
+      // Every event, e.g. `pull-request` of `pull-request/opened`, has its own feature
+      let event_name = webhook
+        .operation_id
+        .split('/')
+        .next()
+        .unwrap_or(&webhook.operation_id);
+      let feature = format!("webhook_{}", event_name.replace('-', "_"));
+
+      parse_context.set_working_tag(&feature);
+
       let request_body = webhook
         .request_body
         .clone()
@@ -81,6 +110,8 @@ impl Codegen {
             let mut field = EnumField::new(name);
 
             field.set_type_name(&struct_.name);
+            field.add_tag(&"full".to_string());
+            field.add_tag(&feature);
 
             enum_.add_field(field);
           }
@@ -88,20 +119,34 @@ impl Codegen {
             let mut field = EnumField::new(name);
 
             field.set_type_name(&generated.name);
+            field.add_tag(&"full".to_string());
+            field.add_tag(&feature);
 
             enum_.add_field(field);
           }
           _ => panic!("Expected struct or enum for webhook {}", name),
         }
 
+        webhook_events.push((name.clone(), webhook.operation_id.clone(), parsed.name()));
+
         parse_context.add_reference(&parsed.name(), parsed);
       } else {
         panic!("No body schema found for webhook {}", name);
       }
     }
 
+    // A known event with an `action` added after this version
+    let mut field = EnumField::new(&"Unknown".to_string());
+
+    field.set_type_name(&"UnknownWebhookEvent".to_string());
+    field.set_description(&"An event with an `action` that GitHub added after this version, rejected by the `strict` feature".to_string());
+
+    enum_.add_field(field);
     enum_.untagged();
 
+    // The variants are gated by their own features, the enum by any of them
+    parse_context.set_working_tag(&"webhook_event".to_string());
+
     parse_context.add_reference(&"WebhookEvent".to_string(), ParsedData::Enum(enum_));
 
     parse_context.finish_parsing();
@@ -112,7 +157,29 @@ impl Codegen {
       apis: parse_context.get_apis(),
       types: parse_context.get_references(),
       webhooks: parse_context.get_webhooks(),
+      webhook_events,
+      targets: targets.features().to_vec(),
+    }
+  }
+
+  /// Add the features of the modules and targets that the manifests don't have yet.
+  pub fn write_features(&self, parsed: &ParsedAPIDescription, apis: &Path, types: &Path) {
+    let mut apis_manifest = Manifest::new(apis);
+    let mut types_manifest = Manifest::new(types);
+
+    for tag in parsed.apis.keys() {
+      let feature = RenameRule::FieldName.apply(tag);
+
+      apis_manifest.add_feature(&feature, vec![format!("octocrate-types/{}", feature)]);
+      types_manifest.add_feature(&feature, vec![]);
+    }
+
+    for target in &parsed.targets {
+      apis_manifest.add_feature(target, vec![]);
     }
+
+    apis_manifest.write();
+    types_manifest.write();
   }
 
   pub fn write_apis(&self, parsed: ParsedAPIDescription, path: &PathBuf) {
@@ -121,18 +188,61 @@ impl Codegen {
     // APIs
     println!("Writing apis to file system");
 
-    let mut directory = Directory::new("apis");
+    writer.add_file(self.api_directory("apis", &parsed, |api| api.github_com));
+
+    // Every target has its own module, e.g. `octocrate::ghes_3_12`
+    let mut directory = Directory::new("targets");
+    let mut targets_module = TargetsModule::new();
+
+    for target in &parsed.targets {
+      let module_name = RenameRule::FieldName.apply(target);
+
+      directory
+        .add_file(self.api_directory(&module_name, &parsed, |api| api.targets.contains(target)));
+      targets_module.add_target(target, &module_name);
+    }
+
+    directory.add_file(targets_module);
+
+    writer.add_file(directory);
+
+    writer.write();
+
+    println!("Finished writing");
+  }
+
+  /// The API modules of the operations that the filter keeps.
+  fn api_directory(
+    &self,
+    name: &str,
+    parsed: &ParsedAPIDescription,
+    filter: impl Fn(&API) -> bool,
+  ) -> Directory {
+    let mut directory = Directory::new(name);
 
     let mut api_entry_module = APIEntryModule::new();
 
-    for (tag, api_functions) in parsed.apis {
-      let description = parsed.tags.get(&tag).cloned();
+    let mut operations_module = OperationsModule::new();
+
+    for (tag, api_functions) in &parsed.apis {
+      let api_functions = api_functions
+        .iter()
+        .filter(|api| filter(api))
+        .cloned()
+        .collect::<Vec<_>>();
+
+      if api_functions.is_empty() {
+        continue;
+      }
 
-      let mut api_module = APIModule::new(&tag, description);
+      let description = parsed.tags.get(tag).cloned();
+
+      let mut api_module = APIModule::new(tag, description);
 
       for api in api_functions {
         let body_type = api
           .body
+          .clone()
           .map(|b| match b {
             ParsedData::Type(t) => {
               if t.type_name.starts_with("Option<") {
@@ -152,12 +262,25 @@ impl Codegen {
 
         let mut url = api.path.clone();
 
-        if let Some(p) = api.parameters {
+        if let Some(p) = api.parameters.clone() {
           for field in p.fields {
+            let original_name = field.rename.as_ref().unwrap_or(&field.name);
+
+            // Read from the credentials the operation is called with
+            if api.basic_auth && original_name == "client_id" {
+              continue;
+            }
+
+            let encode = match field.type_name.as_str() {
+              "String" => Some(api.path_encoding(original_name).to_string()),
+              _ => None,
+            };
+
             let parameter = crate::writer::Parameter {
               name: field.name.clone(),
               type_name: field.type_name.clone(),
               rename: field.rename.clone(),
+              encode,
             };
 
             if let Some(rename) = &field.rename {
@@ -172,6 +295,26 @@ impl Codegen {
           }
         }
 
+        let response_type = api.response.map(|r| r.name());
+
+        let media_types = api
+          .media_types
+          .iter()
+          .map(|media_type| MediaTypeConstant {
+            name: media_type.name.clone(),
+            accept: media_type.accept.clone(),
+            description: media_type.description.clone(),
+            format: media_type
+              .format
+              .variant_name()
+              .unwrap_or("Json".to_string()),
+            response_type: match media_type.format {
+              PayloadFormat::Json => response_type.clone().unwrap_or_default(),
+              _ => media_type.format.parsed_data().name(),
+            },
+          })
+          .collect();
+
         let api_function = APIFunction {
           function_name: api.name.clone(),
           method: api.method.to_string(),
@@ -182,27 +325,45 @@ impl Codegen {
           description: api.description.clone(),
           summary: api.summary.clone(),
           body_type,
+          body_format: api.body_format.variant_name(),
+          raw_body_type: match api.body_format {
+            PayloadFormat::Json => None,
+            format => Some(format.parsed_data().name()),
+          },
           query_type: api
             .query
             .map(|q| q.name.clone())
             .unwrap_or("()".to_string()),
-          response_type: api.response.map(|r| r.name()),
+          response_type,
+          response_format: api.response_format.variant_name(),
+          media_type_name: format!(
+            "{}MediaType",
+            RenameRule::VariantName.apply(&api.scope_name)
+          ),
+          media_types,
+          uploads: api.uploads,
+          download: api.download,
+          basic_auth: api.basic_auth,
         };
 
+        operations_module.add_operation(
+          tag,
+          &api_function.function_name,
+          &api_function.method,
+          &api_function.url,
+        );
+
         api_module.add_function(api_function);
       }
 
-      api_entry_module.add_module(&tag);
+      api_entry_module.add_module(tag);
       directory.add_file(api_module);
     }
 
     directory.add_file(api_entry_module);
+    directory.add_file(operations_module);
 
-    writer.add_file(directory);
-
-    writer.write();
-
-    println!("Finished writing");
+    directory
   }
 
   pub fn write_types(&self, parsed: ParsedAPIDescription, path: &PathBuf) {
@@ -234,6 +395,16 @@ impl Codegen {
 
     writer.add_file(webhooks_module);
 
+    let mut webhook_events_module = WebhookEventsModule::new();
+
+    for (name, operation_id, type_name) in parsed.webhook_events.iter() {
+      webhook_events_module.add_event(name, operation_id, type_name);
+    }
+
+    type_entry_module.add_module("webhook_events");
+
+    writer.add_file(webhook_events_module);
+
     // Add type entry module
     writer.add_file(type_entry_module);
 
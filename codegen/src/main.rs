@@ -18,6 +18,11 @@ fn main() {
   let apis_dir = std::path::PathBuf::from("octocrate/src");
   let types_dir = std::path::PathBuf::from("types/src");
 
+  codegen.write_features(
+    &parsed_api_description,
+    std::path::Path::new("octocrate/Cargo.toml"),
+    std::path::Path::new("types/Cargo.toml"),
+  );
   codegen.write_apis(parsed_api_description.clone(), &apis_dir);
   codegen.write_types(parsed_api_description, &types_dir);
 
@@ -5,7 +5,8 @@ use crate::{
   schemas::{
     api::{Responses, API as APISchema},
     parameters::ParameterDefinition,
-    schema::SchemaDefinition,
+    schema::{SchemaDefinition, SchemaTypeDefination},
+    Content, ContentOrRef,
   },
   structures::{
     enums::{Enum, EnumField},
@@ -13,6 +14,12 @@ use crate::{
     types::Type,
   },
 };
+use regex::Regex;
+
+/// The path parameters that span several segments and keep their `/` separators, such
+/// as the `path` of `repos/get-content` or the `ref` of `git/get-ref`, e.g. `heads/main`.
+/// The description of GitHub doesn't mark them.
+const MULTI_SEGMENT_PARAMETERS: &[&str] = &["branch", "ref", "path", "basehead"];
 
 #[derive(Clone, Debug)]
 pub enum Method {
@@ -23,21 +30,70 @@ pub enum Method {
   DELETE,
 }
 
+/// How the body of a request or response is encoded, `PayloadFormat` in octocrate-core
+#[derive(Clone, Debug, PartialEq)]
+pub enum PayloadFormat {
+  Json,
+  Text,
+  Bytes,
+}
+
+/// A custom media type listed in the description of an operation, such as
+/// `application/vnd.github.diff`
+#[derive(Clone, Debug)]
+pub struct CustomMediaType {
+  pub name: String,
+  pub accept: String,
+  pub description: String,
+  pub format: PayloadFormat,
+}
+
 #[derive(Clone, Debug)]
 pub struct API {
   pub name: String,
+  pub scope_name: String,
   pub summary: String,
   pub document_url: String,
   pub description: String,
   pub path: String,
   pub method: Method,
   pub parameters: Option<Struct>,
+  /// The path parameters that keep their `/` separators when encoded
+  pub multi_segment_parameters: Vec<String>,
   pub body: Option<ParsedData>,
+  /// A text or binary body is sent as it is, without a body type
+  pub body_format: PayloadFormat,
   pub query: Option<Struct>,
   pub response: Option<ParsedData>,
+  pub response_format: PayloadFormat,
+  pub media_types: Vec<CustomMediaType>,
+  /// Whether the operation is on the upload server, e.g. `uploads.github.com`
+  pub uploads: bool,
+  /// Whether the operation redirects to content that is downloaded, e.g. an archive
+  pub download: bool,
+  /// Whether the operation must be called with the `client_id` and `client_secret` of
+  /// an app, e.g. `apps/check-token`
+  pub basic_auth: bool,
+  /// Whether github.com has the operation, which is otherwise on some targets only
+  pub github_com: bool,
+  /// The features of the targets that have the operation, e.g. `ghes-3-12`
+  pub targets: Vec<String>,
 }
 
 impl API {
+  /// The function of `octocrate_core` that percent-encodes a `String` path parameter.
+  pub fn path_encoding(&self, parameter: &str) -> &'static str {
+    if self
+      .multi_segment_parameters
+      .iter()
+      .any(|name| name == parameter)
+    {
+      "encode_path"
+    } else {
+      "encode_path_segment"
+    }
+  }
+
   pub fn parse(ctx: &mut ParseContext, method: &String, path: &String, schema: &APISchema) -> Self {
     let api_name = schema.operation_id.split('/').last().unwrap().to_string();
     let api_name = RenameRule::FieldName.apply(&api_name);
@@ -69,6 +125,12 @@ impl API {
       }
     }
 
+    let multi_segment_parameters = path_parameters
+      .iter()
+      .filter(|parameter| MULTI_SEGMENT_PARAMETERS.contains(&parameter.name.as_str()))
+      .map(|parameter| parameter.name.clone())
+      .collect();
+
     // Dot add reference for path parameters
     let parameters = ParametersParser::new(path_parameters).parse(
       ctx,
@@ -88,16 +150,24 @@ impl API {
 
     let mut schema_parser = SchemaParser::new();
 
-    let body_schema: Option<SchemaDefinition> =
-      schema.request_body.clone().and_then(|body| body.into());
+    let body_format = PayloadFormat::from_content(schema.request_body.as_ref());
 
-    let body = body_schema.map(|schema| {
-      let body = schema_parser.parse(ctx, &format!("{}_request", scope_name), &schema);
+    let body = match body_format {
+      PayloadFormat::Json => {
+        let body_schema: Option<SchemaDefinition> =
+          schema.request_body.clone().and_then(|body| body.into());
 
-      ctx.add_reference(&body.name(), body.clone());
+        body_schema.map(|schema| {
+          let body = schema_parser.parse(ctx, &format!("{}_request", scope_name), &schema);
 
-      body
-    });
+          ctx.add_reference(&body.name(), body.clone());
+
+          body
+        })
+      }
+      // Taken by the generated function and sent with `raw_body`
+      _ => None,
+    };
 
     // let body = match body {
     //   Some(ParsedData::Struct(struct_)) => Some(struct_),
@@ -119,24 +189,57 @@ impl API {
     // };
 
     // parse response
-    let response = Self::parse_responses(
-      ctx,
-      &scope_name,
-      &mut schema_parser,
-      schema.responses.clone(),
-    );
+    let response_format = PayloadFormat::from_content(schema.responses.content());
+
+    let response = match response_format {
+      PayloadFormat::Json => Self::parse_responses(
+        ctx,
+        &scope_name,
+        &mut schema_parser,
+        schema.responses.clone(),
+      ),
+      _ => Some(response_format.parsed_data()),
+    };
+
+    let media_types = match &response {
+      Some(_) => CustomMediaType::parse_description(&schema.description),
+      None => vec![],
+    };
+
+    let uploads = schema
+      .servers
+      .iter()
+      .flatten()
+      .any(|server| server.url.contains("uploads"));
+
+    let download = schema.responses.is_download();
+
+    // Only stated by the description, the schema has no security requirements
+    let basic_auth = schema
+      .description
+      .contains("You must use [Basic Authentication]");
 
     Self {
       name: api_name,
+      scope_name,
       summary: schema.summary.clone(),
       document_url: schema.external_docs.url.clone(),
       description: schema.description.clone(),
       path: path.clone(),
       method,
       parameters,
+      multi_segment_parameters,
       body,
+      body_format,
       query,
       response,
+      response_format,
+      media_types,
+      uploads,
+      download,
+      basic_auth,
+      github_com: true,
+      targets: vec![],
     }
   }
 
@@ -235,6 +338,155 @@ impl API {
   }
 }
 
+impl PayloadFormat {
+  /// Pick the format from the media types of a request body or response. GitHub
+  /// answers with plain text for the JSON strings of its description, e.g. `meta/get-zen`
+  pub fn from_content(content: Option<&ContentOrRef>) -> Self {
+    let content = match content {
+      Some(ContentOrRef::Content {
+        content: Some(content),
+      }) => content,
+      _ => return PayloadFormat::Json,
+    };
+
+    if let Some(json) = &content.application_json {
+      return if is_string(Some(&json.schema)) {
+        PayloadFormat::Text
+      } else {
+        PayloadFormat::Json
+      };
+    }
+
+    Self::from_media_types(content)
+  }
+
+  fn from_media_types(content: &Content) -> Self {
+    let Some((media_type, media_type_content)) = content.media_types.first() else {
+      return PayloadFormat::Json;
+    };
+
+    let schema = media_type_content.schema.as_ref();
+
+    if media_type.ends_with("json") {
+      PayloadFormat::Json
+    } else if media_type.starts_with("text/") || is_string(schema) {
+      PayloadFormat::Text
+    } else {
+      PayloadFormat::Bytes
+    }
+  }
+
+  /// The type of the payload when it isn't JSON
+  pub fn parsed_data(&self) -> ParsedData {
+    match self {
+      PayloadFormat::Json => unreachable!("JSON payloads are parsed from their schema"),
+      PayloadFormat::Text => ParsedData::Type(Type::new(&"String".to_string())),
+      PayloadFormat::Bytes => ParsedData::Type(Type::new(&"Vec<u8>".to_string())),
+    }
+  }
+
+  /// The variant of `PayloadFormat` of the generated request or body, none for JSON
+  pub fn variant_name(&self) -> Option<String> {
+    match self {
+      PayloadFormat::Json => None,
+      PayloadFormat::Text => Some("Text".to_string()),
+      PayloadFormat::Bytes => Some("Bytes".to_string()),
+    }
+  }
+}
+
+impl CustomMediaType {
+  /// Read the media types listed by the description of an operation, as in
+  /// "- **`application/vnd.github.diff`**: Returns the diff of the commit."
+  pub fn parse_description(description: &str) -> Vec<Self> {
+    let pattern = Regex::new(r"(?m)^- \*\*`([^`]+)`\*\*: (.*)$").unwrap();
+
+    let mut media_types = Vec::<Self>::new();
+
+    for captures in pattern.captures_iter(description) {
+      if !media_types.iter().any(|known| known.accept == captures[1]) {
+        media_types.push(Self::new(&captures[1], &captures[2]));
+      }
+    }
+
+    Self::rename_duplicates(&mut media_types);
+
+    media_types
+  }
+
+  /// The media types of one operation may share a name, e.g. the `raw+json` of a comment
+  /// and the raw content of a file. The text one is then suffixed with `Text`, and the
+  /// others that still collide are named after their full subtype.
+  fn rename_duplicates(media_types: &mut [Self]) {
+    let count = |media_types: &[Self], name: &str| {
+      media_types
+        .iter()
+        .filter(|media_type| media_type.name == name)
+        .count()
+    };
+
+    for index in 0..media_types.len() {
+      if media_types[index].format == PayloadFormat::Text
+        && count(media_types, &media_types[index].name) > 1
+      {
+        media_types[index].name.push_str("Text");
+      }
+    }
+
+    for index in 1..media_types.len() {
+      let name = &media_types[index].name;
+
+      if media_types[..index].iter().any(|known| &known.name == name) {
+        let subtype = media_types[index].accept.trim_start_matches("application/");
+
+        media_types[index].name = RenameRule::VariantName
+          .apply(&subtype.replace(|ch: char| !ch.is_ascii_alphanumeric(), "_"));
+      }
+    }
+  }
+
+  fn new(accept: &str, description: &str) -> Self {
+    let subtype = accept.trim_start_matches("application/");
+
+    // `vnd.github.diff` and `vnd.github-commitcomment.html+json` are `Diff` and
+    // `Html`, the default `vnd.github+json` is `Json`
+    let name = match subtype.strip_prefix("vnd.github") {
+      Some(rest) => rest.split_once('.').map(|(_, name)| name).unwrap_or("json"),
+      None => subtype,
+    };
+    let name = name.strip_suffix("+json").unwrap_or(name);
+
+    // The `raw` and `html` media types of files and licenses return their content
+    // instead of JSON, while those of issues, comments or gists keep the JSON
+    // response and only change how its markdown is rendered
+    let json = subtype.starts_with("vnd.github")
+      && subtype.ends_with("json")
+      && (!matches!(name, "raw" | "html") || description.contains("markdown"));
+
+    CustomMediaType {
+      name: RenameRule::VariantName.apply(name),
+      accept: accept.to_string(),
+      description: description.to_string(),
+      format: if json {
+        PayloadFormat::Json
+      } else {
+        PayloadFormat::Text
+      },
+    }
+  }
+}
+
+/// Whether the schema is a string that isn't binary data
+fn is_string(schema: Option<&SchemaDefinition>) -> bool {
+  match schema {
+    Some(SchemaDefinition::Schema(schema)) => {
+      schema.type_ == Some(SchemaTypeDefination::String("string".to_string()))
+        && schema.format.as_deref() != Some("binary")
+    }
+    _ => false,
+  }
+}
+
 fn is_same_type(target_type_name: &String, enum_: &Enum) -> bool {
   for field in enum_.fields.iter() {
     if &field.name == target_type_name {
@@ -277,3 +529,189 @@ impl ToString for Method {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::schemas::APIDescription;
+
+  fn parse_api(method: &str, path: &str, operation_id: &str, parameters: &[&str]) -> API {
+    let parameters = parameters
+      .iter()
+      .map(|name| {
+        serde_json::json!({
+          "name": name,
+          "in": "path",
+          "required": true,
+          "schema": { "type": "string" }
+        })
+      })
+      .collect::<Vec<_>>();
+
+    let schema: APISchema = serde_json::from_value(serde_json::json!({
+      "summary": "",
+      "description": "",
+      "tags": ["repos"],
+      "operationId": operation_id,
+      "parameters": parameters,
+      "responses": {},
+      "externalDocs": { "url": "" }
+    }))
+    .unwrap();
+
+    let description: APIDescription = serde_json::from_value(serde_json::json!({
+      "paths": {},
+      "webhooks": {},
+      "components": { "parameters": {}, "schemas": {} },
+      "tags": [],
+      "externalDocs": { "url": "" }
+    }))
+    .unwrap();
+
+    let mut ctx = ParseContext::new(description);
+
+    API::parse(&mut ctx, &method.to_string(), &path.to_string(), &schema)
+  }
+
+  fn format(content: serde_json::Value) -> PayloadFormat {
+    let content: ContentOrRef =
+      serde_json::from_value(serde_json::json!({ "content": content })).unwrap();
+
+    PayloadFormat::from_content(Some(&content))
+  }
+
+  #[test]
+  fn test_payload_format() {
+    let json = format(serde_json::json!({
+      "application/json": { "schema": { "type": "object" } }
+    }));
+    let zen = format(serde_json::json!({
+      "application/json": { "schema": { "type": "string" } }
+    }));
+    let html = format(serde_json::json!({
+      "text/html": { "schema": { "type": "string" } }
+    }));
+    let octocat = format(serde_json::json!({
+      "application/octocat-stream": { "schema": { "type": "string" } }
+    }));
+    let asset = format(serde_json::json!({
+      "application/octet-stream": { "schema": { "type": "string", "format": "binary" } }
+    }));
+
+    assert_eq!(json, PayloadFormat::Json);
+    assert_eq!(zen, PayloadFormat::Text);
+    assert_eq!(html, PayloadFormat::Text);
+    assert_eq!(octocat, PayloadFormat::Text);
+    assert_eq!(asset, PayloadFormat::Bytes);
+    assert_eq!(PayloadFormat::from_content(None), PayloadFormat::Json);
+  }
+
+  #[test]
+  fn test_download() {
+    let responses =
+      |responses: serde_json::Value| -> Responses { serde_json::from_value(responses).unwrap() };
+
+    let artifact = responses(serde_json::json!({
+      "302": { "description": "Response", "headers": { "Location": { "$ref": "#/components/headers/location" } } },
+      "410": { "$ref": "#/components/responses/gone" }
+    }));
+    let database = responses(serde_json::json!({
+      "200": { "content": { "application/json": { "schema": { "type": "object" } } } },
+      "302": { "description": "Found" }
+    }));
+
+    assert!(artifact.is_download());
+    assert!(!database.is_download());
+  }
+
+  #[test]
+  fn test_custom_media_types() {
+    let description = [
+      "Lists details of a pull request.",
+      "",
+      "This endpoint supports the following custom media types.",
+      "",
+      "- **`application/vnd.github.html+json`**: Returns HTML rendered from the body's markdown. Response will include `body_html`.",
+      "- **`application/vnd.github-commitcomment.raw+json`**: Returns the raw markdown body.",
+      "- **`application/vnd.github.diff`**: For more information, see \"[git-diff](https://git-scm.com/docs/git-diff)\".",
+      "- **`application/vnd.github.raw+json`**: Returns the raw file contents.",
+      "- **`application/vnd.github+json`**: Returns a JSON representation of the blob.",
+    ]
+    .join("\n");
+
+    let media_types = CustomMediaType::parse_description(&description)
+      .into_iter()
+      .map(|media_type| (media_type.name, media_type.format))
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      media_types,
+      vec![
+        ("Html".to_string(), PayloadFormat::Json),
+        ("Raw".to_string(), PayloadFormat::Json),
+        ("Diff".to_string(), PayloadFormat::Text),
+        ("RawText".to_string(), PayloadFormat::Text),
+        ("Json".to_string(), PayloadFormat::Json),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_custom_media_type_names_are_unique() {
+    let description = [
+      "- **`application/vnd.github.raw+json`**: Returns the raw markdown body.",
+      "- **`application/vnd.github-commitcomment.raw+json`**: Returns the raw markdown body.",
+      "- **`application/vnd.github.raw+json`**: Returns the raw markdown body.",
+      "- **`application/vnd.github.raw`**: Returns the raw file contents.",
+      "- **`application/vnd.github.object+json`**: Returns the contents in a consistent object format.",
+      "- **`application/vnd.github.object`**: Returns the contents in a consistent object format.",
+    ]
+    .join("\n");
+
+    let names = CustomMediaType::parse_description(&description)
+      .into_iter()
+      .map(|media_type| media_type.name)
+      .collect::<Vec<_>>();
+
+    assert_eq!(
+      names,
+      vec![
+        "Raw",
+        "VndGithubCommitcommentRawJson",
+        "RawText",
+        "Object",
+        "ObjectText",
+      ]
+    );
+
+    let mut unique = names.clone();
+    unique.sort();
+    unique.dedup();
+
+    assert_eq!(unique.len(), names.len());
+  }
+
+  #[test]
+  fn test_path_encoding() {
+    let get_content = parse_api(
+      "get",
+      "/repos/{owner}/{repo}/contents/{path}",
+      "repos/get-content",
+      &["owner", "repo", "path"],
+    );
+
+    assert_eq!(get_content.path_encoding("owner"), "encode_path_segment");
+    assert_eq!(get_content.path_encoding("repo"), "encode_path_segment");
+    assert_eq!(get_content.path_encoding("path"), "encode_path");
+
+    let get_ref = parse_api(
+      "get",
+      "/repos/{owner}/{repo}/git/ref/{ref}",
+      "git/get-ref",
+      &["owner", "repo", "ref"],
+    );
+
+    assert_eq!(get_ref.path_encoding("ref"), "encode_path");
+    assert_eq!(get_ref.path_encoding("owner"), "encode_path_segment");
+  }
+}
@@ -184,10 +184,7 @@ impl ParseContext {
   }
 
   pub fn start_parsing_webhook(&mut self, webhook: &String) {
-    if self.stage == Stage::ParsingAPI {
-      self.stage = Stage::ParsingWebhook;
-      self.working_tag = Some("webhook".to_string());
-    }
+    self.stage = Stage::ParsingWebhook;
 
     self
       .progress_bar
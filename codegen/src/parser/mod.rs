@@ -3,6 +3,7 @@ use crate::structures::{enums::Enum, structs::Struct, types::Type};
 pub mod api;
 pub mod context;
 pub mod parser;
+pub mod targets;
 
 #[derive(Clone, Debug)]
 pub enum ParsedData {
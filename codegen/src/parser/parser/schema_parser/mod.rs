@@ -106,6 +106,13 @@ impl SchemaParser {
 
     let type_name = schema_types.to_full_type();
 
+    // `DateTime` and `Date` are chrono types with the `chrono_timestamps` feature
+    let type_name = match (schema.format.as_deref(), type_name.as_str()) {
+      (Some("date-time"), "String" | "Option<String>") => type_name.replace("String", "DateTime"),
+      (Some("date"), "String" | "Option<String>") => type_name.replace("String", "Date"),
+      _ => type_name,
+    };
+
     ParsedData::Type(Type::new(&type_name))
   }
 }
@@ -139,4 +146,35 @@ mod schema_parser_tests {
       panic!("Expected struct");
     }
   }
+
+  #[test]
+  fn test_schema_parser_with_date_time() {
+    let mut parser = SchemaParser::new();
+
+    let mut ctx = ParseContext::default();
+
+    let mut parse = |json: &str| {
+      let schema: Schema = serde_json::from_str(json).unwrap();
+
+      match parser.parse(
+        &mut ctx,
+        &"Response".to_string(),
+        &SchemaDefinition::Schema(schema),
+      ) {
+        ParsedData::Type(type_) => type_.type_name,
+        _ => panic!("Expected type"),
+      }
+    };
+
+    assert_eq!(
+      parse(r#"{ "type": "string", "format": "date-time" }"#),
+      "DateTime"
+    );
+    assert_eq!(
+      parse(r#"{ "type": ["string", "null"], "format": "date-time" }"#),
+      "Option<DateTime>"
+    );
+    assert_eq!(parse(r#"{ "type": "string", "format": "date" }"#), "Date");
+    assert_eq!(parse(r#"{ "type": "string", "format": "uri" }"#), "String");
+  }
 }
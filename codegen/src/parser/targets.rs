@@ -0,0 +1,237 @@
+use crate::schemas::APIDescription;
+use std::{
+  collections::{HashMap, HashSet},
+  path::Path,
+};
+
+/// The descriptions of GitHub Enterprise Cloud and Server, e.g. `ghec.json` or
+/// `ghes-3.12.json` next to `api.json`, each enabled by a feature such as `ghes-3-12`.
+///
+/// Their operations are merged into the description of github.com, so that the types of
+/// the operations of one product only, e.g. the `enterprise-admin` ones of GitHub
+/// Enterprise Server, are generated too. Every target then gets its own API module with
+/// the operations it has, e.g. `octocrate::ghes_3_12`, so that enabling a target only
+/// ever adds code.
+#[derive(Default)]
+pub struct Targets {
+  /// The feature of every target, sorted
+  features: Vec<String>,
+  /// The features of the targets of every operation, keyed by `METHOD path`
+  operations: HashMap<String, Vec<String>>,
+  /// The operations of github.com
+  github_com: HashSet<String>,
+}
+
+impl Targets {
+  /// The features of the targets, e.g. `ghes-3-12`
+  pub fn features(&self) -> &[String] {
+    &self.features
+  }
+
+  /// Load the descriptions of the targets in the directory and merge them into the one
+  /// of github.com.
+  pub fn merge(description: &mut APIDescription, directory: &Path) -> Result<Self, String> {
+    let mut descriptions = Vec::new();
+
+    let entries = std::fs::read_dir(directory).map_err(|err| err.to_string())?;
+
+    for entry in entries {
+      let path = entry.map_err(|err| err.to_string())?.path();
+
+      let Some(feature) = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .and_then(feature_name)
+      else {
+        continue;
+      };
+
+      let json = std::fs::read_to_string(&path).map_err(|err| err.to_string())?;
+      let target = APIDescription::try_from(&json)
+        .map_err(|err| format!("Failed to parse {}: {}", path.display(), err))?;
+
+      descriptions.push((feature, target));
+    }
+
+    descriptions.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut targets = Targets {
+      github_com: operation_keys(description).collect(),
+      ..Default::default()
+    };
+
+    for (feature, target) in descriptions {
+      targets.add(description, &feature, target);
+    }
+
+    Ok(targets)
+  }
+
+  fn add(&mut self, description: &mut APIDescription, feature: &str, target: APIDescription) {
+    for key in operation_keys(&target) {
+      self
+        .operations
+        .entry(key)
+        .or_default()
+        .push(feature.to_string());
+    }
+
+    for (path, operations) in target.paths {
+      let merged = description.paths.entry(path).or_default();
+
+      for (method, operation) in &operations {
+        if let Some(slot) = merged.operation_mut(&method) {
+          slot.get_or_insert(operation);
+        }
+      }
+    }
+
+    // The schemas of github.com win, those of the operations of the target only are added
+    for (name, schema) in target.components.schemas {
+      description.components.schemas.entry(name).or_insert(schema);
+    }
+
+    for (name, parameter) in target.components.parameters {
+      description
+        .components
+        .parameters
+        .entry(name)
+        .or_insert(parameter);
+    }
+
+    for tag in target.tags {
+      if !description.tags.iter().any(|known| known.name == tag.name) {
+        description.tags.push(tag);
+      }
+    }
+
+    self.features.push(feature.to_string());
+  }
+
+  /// Whether github.com has the operation.
+  pub fn github_com(&self, method: &str, path: &str) -> bool {
+    self.github_com.contains(&operation_key(method, path))
+  }
+
+  /// The features of the targets that have the operation.
+  pub fn targets(&self, method: &str, path: &str) -> Vec<String> {
+    self
+      .operations
+      .get(&operation_key(method, path))
+      .cloned()
+      .unwrap_or_default()
+  }
+}
+
+/// The feature of a description file, e.g. `ghes-3-12` for `ghes-3.12`, or `None` if it
+/// isn't the description of a target.
+fn feature_name(stem: &str) -> Option<String> {
+  if stem == "ghec" || stem.starts_with("ghes-") {
+    Some(stem.replace('.', "-"))
+  } else {
+    None
+  }
+}
+
+fn operation_key(method: &str, path: &str) -> String {
+  format!("{} {}", method.to_uppercase(), path)
+}
+
+fn operation_keys(description: &APIDescription) -> impl Iterator<Item = String> + '_ {
+  description.paths.iter().flat_map(|(path, operations)| {
+    operations
+      .into_iter()
+      .map(move |(method, _)| operation_key(&method, path))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn operation(operation_id: &str, tag: &str) -> serde_json::Value {
+    serde_json::json!({
+      "summary": "",
+      "description": "",
+      "tags": [tag],
+      "operationId": operation_id,
+      "responses": {},
+      "externalDocs": { "url": "" }
+    })
+  }
+
+  fn description(paths: serde_json::Value, tags: &[&str]) -> APIDescription {
+    let tags = tags
+      .iter()
+      .map(|name| serde_json::json!({ "name": name, "description": "" }))
+      .collect::<Vec<_>>();
+
+    serde_json::from_value(serde_json::json!({
+      "paths": paths,
+      "webhooks": {},
+      "components": { "parameters": {}, "schemas": {} },
+      "tags": tags,
+      "externalDocs": { "url": "" }
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_feature_name() {
+    assert_eq!(feature_name("ghes-3.12"), Some("ghes-3-12".to_string()));
+    assert_eq!(feature_name("ghec"), Some("ghec".to_string()));
+    assert_eq!(feature_name("api"), None);
+  }
+
+  #[test]
+  fn test_merge_targets() {
+    let mut github_com = description(
+      serde_json::json!({
+        "/repos/{owner}/{repo}": { "get": operation("repos/get", "repos") },
+        "/repos/{owner}/{repo}/rulesets": { "get": operation("repos/get-repo-rulesets", "repos") }
+      }),
+      &["repos"],
+    );
+    let ghes = description(
+      serde_json::json!({
+        "/repos/{owner}/{repo}": { "get": operation("repos/get", "repos") },
+        "/enterprise/settings/license": {
+          "get": operation("enterprise-admin/get-license-information", "enterprise-admin")
+        }
+      }),
+      &["repos", "enterprise-admin"],
+    );
+
+    let mut targets = Targets {
+      github_com: operation_keys(&github_com).collect(),
+      ..Default::default()
+    };
+
+    targets.add(&mut github_com, "ghes-3-12", ghes);
+
+    assert!(github_com.paths["/enterprise/settings/license"]
+      .get
+      .is_some());
+    assert_eq!(github_com.tags.len(), 2);
+    assert_eq!(targets.features(), ["ghes-3-12"]);
+
+    assert!(targets.github_com("get", "/repos/{owner}/{repo}"));
+    assert_eq!(
+      targets.targets("get", "/repos/{owner}/{repo}"),
+      ["ghes-3-12"]
+    );
+
+    // Missing on the target
+    assert!(targets.github_com("get", "/repos/{owner}/{repo}/rulesets"));
+    assert!(targets
+      .targets("get", "/repos/{owner}/{repo}/rulesets")
+      .is_empty());
+
+    // Only on the target
+    assert!(!targets.github_com("get", "/enterprise/settings/license"));
+    assert_eq!(
+      targets.targets("get", "/enterprise/settings/license"),
+      ["ghes-3-12"]
+    );
+  }
+}
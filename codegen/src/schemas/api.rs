@@ -12,6 +12,14 @@ pub struct Responses {
   pub created: Option<ContentOrRef>,
   #[serde(rename = "202")]
   pub accepted: Option<ContentOrRef>,
+  /// A redirect to the content, e.g. the URL of an archive or of logs
+  #[serde(rename = "302")]
+  pub found: Option<serde::de::IgnoredAny>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Server {
+  pub url: String,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -27,6 +35,24 @@ pub struct API {
   pub request_body: Option<ContentOrRef>,
   #[serde(rename = "externalDocs")]
   pub external_docs: ExternalDocs,
+  /// Set for the operations that aren't on the API server, such as the uploads
+  pub servers: Option<Vec<Server>>,
+}
+
+impl Responses {
+  /// The content of the first successful response
+  pub fn content(&self) -> Option<&ContentOrRef> {
+    self
+      .success
+      .as_ref()
+      .or(self.created.as_ref())
+      .or(self.accepted.as_ref())
+  }
+
+  /// Whether the operation only redirects to its content, which is downloaded
+  pub fn is_download(&self) -> bool {
+    self.found.is_some() && self.content().is_none()
+  }
 }
 
 impl IntoIterator for Responses {
@@ -19,10 +19,18 @@ pub struct ApplicationJSONContent {
   pub schema: SchemaDefinition,
 }
 
+#[derive(Deserialize, Clone, Debug)]
+pub struct MediaTypeContent {
+  pub schema: Option<SchemaDefinition>,
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct Content {
   #[serde(rename = "application/json")]
   pub application_json: Option<ApplicationJSONContent>,
+  /// Other media types, such as `text/html` or `application/octet-stream`
+  #[serde(flatten)]
+  pub media_types: IndexMap<String, MediaTypeContent>,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -38,7 +46,7 @@ pub enum ContentOrRef {
   Ref(Reference),
 }
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Deserialize, Clone, Debug, Default)]
 pub struct Paths {
   pub get: Option<api::API>,
   pub post: Option<api::API>,
@@ -104,6 +112,20 @@ impl Default for APIDescription {
   }
 }
 
+impl Paths {
+  /// The operation of a lowercase method, `None` for methods that aren't generated.
+  pub fn operation_mut(&mut self, method: &str) -> Option<&mut Option<api::API>> {
+    match method {
+      "get" => Some(&mut self.get),
+      "post" => Some(&mut self.post),
+      "put" => Some(&mut self.put),
+      "patch" => Some(&mut self.patch),
+      "delete" => Some(&mut self.delete),
+      _ => None,
+    }
+  }
+}
+
 impl IntoIterator for &Paths {
   type Item = (String, api::API);
   type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -39,7 +39,7 @@ pub struct Schema {
   pub title: Option<String>,
   #[serde(rename = "type")]
   pub type_: Option<SchemaTypeDefination>,
-  // pub format: Option<String>,
+  pub format: Option<String>,
   pub items: Option<Box<SchemaDefinition>>,
   pub properties: Option<BTreeMap<String, SchemaDefinition>>,
   pub required: Option<Vec<String>>,
@@ -11,6 +11,8 @@ pub struct EnumField {
   pub type_name: Option<String>,
   pub description: Option<String>,
   pub reference: Option<String>,
+  /// Features of the variant, for variants that are not in every build of the enum
+  pub tags: Vec<String>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -21,6 +23,9 @@ pub struct Enum {
   pub untagged: bool,
   tags: Vec<String>,
   impl_to_string: bool,
+  /// The variant that keeps the values added by GitHub after the enum was generated,
+  /// `Unknown(String)`, or `Other(String)` when `Unknown` is already a value
+  fallback: Option<String>,
 }
 
 impl Enum {
@@ -31,6 +36,7 @@ impl Enum {
       description: None,
       untagged: false,
       impl_to_string: false,
+      fallback: None,
       tags: vec!["full".to_string()],
     }
   }
@@ -40,6 +46,12 @@ impl Enum {
 
     self.impl_to_string =
       !self.fields.is_empty() && self.fields.iter().all(|f| f.type_name.is_none());
+
+    self.fallback = match self.impl_to_string && !self.untagged {
+      true if self.fields.iter().any(|f| f.name == "Unknown") => Some("Other".to_string()),
+      true => Some("Unknown".to_string()),
+      false => None,
+    };
   }
 
   pub fn set_description(&mut self, description: &String) {
@@ -58,6 +70,7 @@ impl Enum {
 
   pub fn untagged(&mut self) {
     self.untagged = true;
+    self.fallback = None;
   }
 }
 
@@ -72,6 +85,7 @@ impl EnumField {
         type_name: None,
         description: None,
         reference: None,
+        tags: vec![],
       }
     } else {
       Self {
@@ -80,6 +94,7 @@ impl EnumField {
         type_name: None,
         description: None,
         reference: None,
+        tags: vec![],
       }
     }
   }
@@ -95,4 +110,58 @@ impl EnumField {
   pub fn set_type_name(&mut self, type_name: &String) {
     self.type_name = Some(type_name.to_string());
   }
+
+  pub fn add_tag(&mut self, tag: &String) {
+    if !self.tags.contains(tag) {
+      self.tags.push(tag.clone());
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn enum_of(values: &[&str]) -> Enum {
+    let mut enum_ = Enum::new(&"conclusion".to_string());
+
+    for value in values {
+      enum_.add_field(EnumField::new(&value.to_string()));
+    }
+
+    enum_
+  }
+
+  #[test]
+  fn test_fallback() {
+    let conclusion = enum_of(&["success", "failure"]);
+
+    assert_eq!(conclusion.fallback, Some("Unknown".to_string()));
+
+    // `unknown` is already a value of the enumeration
+    let severity = enum_of(&["unknown", "low", "high"]);
+
+    assert_eq!(severity.fields[0].name, "Unknown");
+    assert_eq!(severity.fallback, Some("Other".to_string()));
+  }
+
+  #[test]
+  fn test_no_fallback() {
+    assert_eq!(enum_of(&[]).fallback, None);
+
+    let mut untagged = enum_of(&["success", "failure"]);
+
+    untagged.untagged();
+
+    assert_eq!(untagged.fallback, None);
+
+    // Enumerations of types are matched by their content instead of their name
+    let mut one_of = Enum::new(&"repository_or_integer".to_string());
+    let mut field = EnumField::new(&"integer".to_string());
+
+    field.set_type_name(&"i64".to_string());
+    one_of.add_field(field);
+
+    assert_eq!(one_of.fallback, None);
+  }
 }
@@ -11,11 +11,21 @@ pub struct StructField {
   pub reference: Option<String>,
 }
 
+/// How a list response that wraps its items in an object, like
+/// `{ "total_count": 2, "items": [...] }`, exposes them to the paginator.
+#[derive(Clone, Debug, Serialize, PartialEq)]
+pub struct PaginatedItems {
+  pub item_type: String,
+  pub items: String,
+  pub total_count: String,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct Struct {
   pub name: String,
   pub description: Option<String>,
   pub fields: Vec<StructField>,
+  pub paginated_items: Option<PaginatedItems>,
   tags: Vec<String>,
 }
 
@@ -25,6 +35,7 @@ impl Struct {
       name: RenameRule::VariantName.apply(name),
       description: Some(description.clone()),
       fields: vec![],
+      paginated_items: None,
       tags: vec!["full".to_string()],
     }
   }
@@ -34,6 +45,7 @@ impl Struct {
       name: RenameRule::VariantName.apply(&name),
       description: None,
       fields: vec![],
+      paginated_items: None,
       tags: vec!["full".to_string()],
     }
   }
@@ -52,11 +64,64 @@ impl Struct {
     self.description = Some(description.clone());
   }
 
+  /// Detect a `total_count` field next to exactly one list field, which is how GitHub
+  /// wraps the items of list responses such as search results or workflow runs.
+  pub fn detect_paginated_items(&mut self) {
+    let total_count = self.fields.iter().find_map(|field| {
+      if field.name != "total_count" {
+        return None;
+      }
+
+      match field.type_name.as_str() {
+        "i64" => Some("Some(self.total_count)".to_string()),
+        "f64" => Some("Some(self.total_count as i64)".to_string()),
+        "Option<i64>" => Some("self.total_count".to_string()),
+        "Option<f64>" => Some("self.total_count.map(|count| count as i64)".to_string()),
+        _ => None,
+      }
+    });
+
+    let lists = self
+      .fields
+      .iter()
+      .filter_map(|field| {
+        if let Some(item_type) = strip_wrapper(&field.type_name, "Vec<") {
+          Some((format!("self.{}", field.name), item_type))
+        } else {
+          strip_wrapper(&field.type_name, "Option<Vec<").map(|item_type| {
+            (
+              format!("self.{}.unwrap_or_default()", field.name),
+              item_type,
+            )
+          })
+        }
+      })
+      .collect::<Vec<_>>();
+
+    self.paginated_items = match (total_count, lists.as_slice()) {
+      (Some(total_count), [(items, item_type)]) => Some(PaginatedItems {
+        item_type: item_type.clone(),
+        items: items.clone(),
+        total_count,
+      }),
+      _ => None,
+    };
+  }
+
   pub fn set_name(&mut self, name: &String) {
     self.name = RenameRule::VariantName.apply(name);
   }
 }
 
+fn strip_wrapper(type_name: &str, prefix: &str) -> Option<String> {
+  let closing = ">".repeat(prefix.matches('<').count());
+
+  type_name
+    .strip_prefix(prefix)
+    .and_then(|inner| inner.strip_suffix(closing.as_str()))
+    .map(|inner| inner.to_string())
+}
+
 impl StructField {
   // pub fn new_with_description(name: &String, type_name: &String, description: &String) -> Self {
   //   let normalized_name = RenameRule::FieldName.apply(&name);
@@ -102,3 +167,73 @@ impl StructField {
     self.reference = Some(reference.clone());
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_detect_paginated_items() {
+    let mut struct_ = Struct::new("SearchReposResponse".to_string());
+
+    struct_.add_field(StructField::new(
+      &"total_count".to_string(),
+      &"i64".to_string(),
+    ));
+    struct_.add_field(StructField::new(
+      &"incomplete_results".to_string(),
+      &"bool".to_string(),
+    ));
+    struct_.add_field(StructField::new(
+      &"items".to_string(),
+      &"Vec<RepoSearchResultItem>".to_string(),
+    ));
+
+    struct_.detect_paginated_items();
+
+    assert_eq!(
+      struct_.paginated_items,
+      Some(PaginatedItems {
+        item_type: "RepoSearchResultItem".to_string(),
+        items: "self.items".to_string(),
+        total_count: "Some(self.total_count)".to_string(),
+      })
+    );
+
+    let mut struct_ = Struct::new("ReposGetAllEnvironmentsResponse".to_string());
+
+    struct_.add_field(StructField::new(
+      &"environments".to_string(),
+      &"Option<Vec<Environment>>".to_string(),
+    ));
+    struct_.add_field(StructField::new(
+      &"total_count".to_string(),
+      &"Option<i64>".to_string(),
+    ));
+
+    struct_.detect_paginated_items();
+
+    assert_eq!(
+      struct_.paginated_items,
+      Some(PaginatedItems {
+        item_type: "Environment".to_string(),
+        items: "self.environments.unwrap_or_default()".to_string(),
+        total_count: "self.total_count".to_string(),
+      })
+    );
+  }
+
+  #[test]
+  fn test_detect_paginated_items_without_total_count() {
+    let mut struct_ = Struct::new("Repository".to_string());
+
+    struct_.add_field(StructField::new(
+      &"topics".to_string(),
+      &"Option<Vec<String>>".to_string(),
+    ));
+
+    struct_.detect_paginated_items();
+
+    assert_eq!(struct_.paginated_items, None);
+  }
+}
@@ -10,6 +10,17 @@ pub struct Parameter {
   pub name: String,
   pub type_name: String,
   pub rename: Option<String>,
+  /// The function of `octocrate_core` that percent-encodes the parameter
+  pub encode: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct MediaTypeConstant {
+  pub name: String,
+  pub accept: String,
+  pub description: String,
+  pub format: String,
+  pub response_type: String,
 }
 
 #[derive(Serialize)]
@@ -22,9 +33,19 @@ pub struct APIFunction {
   pub method: String,
   pub parameters: Vec<Parameter>,
   pub body_type: String,
+  pub body_format: Option<String>,
+  /// The type of a text or binary body taken by the function, e.g. `String`
+  pub raw_body_type: Option<String>,
   pub query_type: String,
   pub response_type: Option<String>,
+  pub response_format: Option<String>,
   pub stringify_params: Vec<String>,
+  pub media_type_name: String,
+  pub media_types: Vec<MediaTypeConstant>,
+  pub uploads: bool,
+  pub download: bool,
+  /// Takes `ClientCredentials` instead of the `client_id` parameter
+  pub basic_auth: bool,
 }
 
 #[derive(Serialize)]
@@ -1,5 +1,9 @@
 mod api_module;
 mod entry_module;
+mod operations_module;
+mod targets_module;
 
-pub use api_module::{APIFunction, APIModule, Parameter};
+pub use api_module::{APIFunction, APIModule, MediaTypeConstant, Parameter};
 pub use entry_module::APIEntryModule;
+pub use operations_module::OperationsModule;
+pub use targets_module::TargetsModule;
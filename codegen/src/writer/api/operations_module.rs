@@ -0,0 +1,60 @@
+use super::super::File;
+use crate::{
+  common::{render_template, RenameRule},
+  writer::format_code,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Operation {
+  id: String,
+  method: String,
+  path: String,
+}
+
+#[derive(Serialize)]
+pub struct OperationsModule {
+  operations: Vec<Operation>,
+}
+
+impl OperationsModule {
+  pub fn new() -> OperationsModule {
+    OperationsModule {
+      operations: Vec::new(),
+    }
+  }
+
+  pub fn add_operation(
+    &mut self,
+    module_name: &String,
+    function_name: &str,
+    method: &str,
+    path: &str,
+  ) {
+    self.operations.push(Operation {
+      id: format!(
+        "{}.{}",
+        RenameRule::FieldName.apply(module_name),
+        function_name
+      ),
+      method: method.to_uppercase(),
+      path: path.to_string(),
+    });
+  }
+}
+
+impl File for OperationsModule {
+  fn file_name(&self) -> String {
+    "operations.rs".to_string()
+  }
+
+  fn write(&self, path: &std::path::PathBuf) {
+    let template = include_str!("../../../templates/api/operations.hbs");
+
+    let rendered = format_code(render_template(template, self));
+
+    let file_path = path.join(self.file_name());
+
+    std::fs::write(file_path, rendered).expect("Unable to write file");
+  }
+}
@@ -0,0 +1,78 @@
+use super::super::File;
+use crate::{common::render_template, writer::format_code};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Target {
+  feature: String,
+  module_name: String,
+  /// e.g. `GitHub Enterprise Server 3.12`
+  product: String,
+}
+
+#[derive(Serialize)]
+pub struct TargetsModule {
+  targets: Vec<Target>,
+}
+
+impl TargetsModule {
+  pub fn new() -> TargetsModule {
+    TargetsModule {
+      targets: Vec::new(),
+    }
+  }
+
+  /// Add the module of a target, e.g. `ghes_3_12` for the `ghes-3-12` feature.
+  pub fn add_target(&mut self, feature: &str, module_name: &str) {
+    let product = match feature.strip_prefix("ghes-") {
+      Some(version) => format!("GitHub Enterprise Server {}", version.replace('-', ".")),
+      None => "GitHub Enterprise Cloud".to_string(),
+    };
+
+    self.targets.push(Target {
+      feature: feature.to_string(),
+      module_name: module_name.to_string(),
+      product,
+    });
+  }
+}
+
+impl File for TargetsModule {
+  fn file_name(&self) -> String {
+    "mod.rs".to_string()
+  }
+
+  fn write(&self, path: &std::path::PathBuf) {
+    let template = include_str!("../../../templates/api/targets.hbs");
+
+    let rendered = format_code(render_template(template, self));
+
+    let file_path = path.join(self.file_name());
+
+    std::fs::write(file_path, rendered).expect("Unable to write file");
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::common::RenameRule;
+
+  #[test]
+  fn test_render() {
+    let template = include_str!("../../../templates/api/targets.hbs");
+
+    let mut module = TargetsModule::new();
+
+    module.add_target("ghec", &RenameRule::FieldName.apply("ghec"));
+    module.add_target("ghes-3-12", &RenameRule::FieldName.apply("ghes-3-12"));
+
+    assert_eq!(module.targets[1].module_name, "ghes_3_12");
+    assert_eq!(module.targets[1].product, "GitHub Enterprise Server 3.12");
+
+    let rendered = render_template(template, &module);
+
+    assert!(rendered.contains("/// The operations of GitHub Enterprise Cloud"));
+    assert!(rendered.contains("#[cfg(feature = \"ghes-3-12\")]\npub mod ghes_3_12;"));
+  }
+}
@@ -0,0 +1,128 @@
+use std::path::PathBuf;
+
+/// The `[features]` of a crate manifest, e.g. `octocrate/Cargo.toml`.
+///
+/// The features of the generated modules and targets that it doesn't have yet are added
+/// at the end of the table, the ones that it has are left as they are.
+pub struct Manifest {
+  pub path: PathBuf,
+  pub features: Vec<(String, Vec<String>)>,
+}
+
+impl Manifest {
+  pub fn new(path: impl Into<PathBuf>) -> Manifest {
+    Manifest {
+      path: path.into(),
+      features: Vec::new(),
+    }
+  }
+
+  pub fn add_feature(&mut self, name: &str, dependencies: Vec<String>) {
+    self.features.push((name.to_string(), dependencies));
+  }
+
+  pub fn write(&self) {
+    let manifest = std::fs::read_to_string(&self.path).expect("Unable to read manifest");
+
+    std::fs::write(&self.path, add_features(&manifest, &self.features))
+      .expect("Unable to write manifest");
+  }
+}
+
+fn add_features(manifest: &str, features: &[(String, Vec<String>)]) -> String {
+  let mut lines = manifest.lines().map(str::to_string).collect::<Vec<_>>();
+
+  let start = lines
+    .iter()
+    .position(|line| line.trim() == "[features]")
+    .map(|index| index + 1)
+    .unwrap_or_else(|| {
+      lines.extend(["".to_string(), "[features]".to_string()]);
+      lines.len()
+    });
+
+  let mut end = lines[start..]
+    .iter()
+    .position(|line| line.starts_with('['))
+    .map(|index| start + index)
+    .unwrap_or(lines.len());
+
+  // Before the blank lines that separate the table from the next one
+  while end > start && lines[end - 1].trim().is_empty() {
+    end -= 1;
+  }
+
+  let defined = lines[start..end]
+    .iter()
+    .filter_map(|line| line.split_once('='))
+    .map(|(name, _)| name.trim().to_string())
+    .collect::<Vec<_>>();
+
+  let added = features
+    .iter()
+    .filter(|(name, _)| !defined.contains(name))
+    .map(|(name, dependencies)| {
+      let dependencies = dependencies
+        .iter()
+        .map(|dependency| format!("\"{}\"", dependency))
+        .collect::<Vec<_>>();
+
+      format!("{} = [{}]", name, dependencies.join(", "))
+    })
+    .collect::<Vec<_>>();
+
+  lines.splice(end..end, added);
+
+  lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_add_features() {
+    let manifest = r#"[package]
+name = "octocrate"
+
+[features]
+full = [
+  "octocrate-types/full",
+]
+repos = ["octocrate-types/repos"]
+
+[dependencies]
+serde = "1"
+"#;
+
+    let features = vec![
+      (
+        "repos".to_string(),
+        vec!["octocrate-types/repos".to_string()],
+      ),
+      (
+        "enterprise_admin".to_string(),
+        vec!["octocrate-types/enterprise_admin".to_string()],
+      ),
+      ("ghes-3-12".to_string(), vec![]),
+    ];
+
+    assert_eq!(
+      add_features(manifest, &features),
+      r#"[package]
+name = "octocrate"
+
+[features]
+full = [
+  "octocrate-types/full",
+]
+repos = ["octocrate-types/repos"]
+enterprise_admin = ["octocrate-types/enterprise_admin"]
+ghes-3-12 = []
+
+[dependencies]
+serde = "1"
+"#
+    );
+  }
+}
@@ -1,9 +1,11 @@
 mod api;
 mod format;
+mod manifest;
 mod types;
 
 pub use api::*;
 pub use format::*;
+pub use manifest::*;
 use std::path::PathBuf;
 pub use types::*;
 
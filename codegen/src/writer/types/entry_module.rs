@@ -49,6 +49,9 @@ mod tests {
 
     module.add_module("test_module");
 
-    let _rendered = render_template(template, &module);
+    let rendered = render_template(template, &module);
+
+    // The fallback variants of the enumerations use it with the `strict` feature
+    assert!(rendered.contains("pub fn reject_unknown_variant"));
   }
 }
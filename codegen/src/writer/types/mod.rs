@@ -1,5 +1,7 @@
 mod entry_module;
 mod type_module;
+mod webhook_events_module;
 
 pub use entry_module::TypeEntryModule;
 pub use type_module::TypeModule;
+pub use webhook_events_module::WebhookEventsModule;
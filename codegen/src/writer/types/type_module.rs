@@ -31,7 +31,13 @@ impl TypeModule {
   pub fn add_type(&mut self, parsed: &ParsedData) {
     match parsed {
       ParsedData::Enum(e) => self.enums.push(e.clone()),
-      ParsedData::Struct(s) => self.structs.push(s.clone()),
+      ParsedData::Struct(s) => {
+        let mut s = s.clone();
+
+        s.detect_paginated_items();
+
+        self.structs.push(s)
+      }
       ParsedData::Type(t) => self.types.push(t.clone()),
     }
   }
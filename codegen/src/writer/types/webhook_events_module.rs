@@ -0,0 +1,112 @@
+use super::super::File;
+use crate::{
+  common::{render_template, RenameRule},
+  writer::format_code,
+};
+use serde::Serialize;
+
+/// Events whose `action` is chosen by the sender, e.g. the `event_type` of a
+/// `repository_dispatch`.
+const ANY_ACTION_EVENTS: [&str; 1] = ["repository_dispatch"];
+
+#[derive(Serialize)]
+struct WebhookEventVariant {
+  variant: String,
+  event_name: String,
+  action: Option<String>,
+  /// The payload of the variant
+  type_name: String,
+}
+
+#[derive(Serialize)]
+pub struct WebhookEventsModule {
+  events: Vec<WebhookEventVariant>,
+  /// The events with actions, whose other actions are read as `WebhookEvent::Unknown`
+  fallback_events: Vec<String>,
+}
+
+impl WebhookEventsModule {
+  pub fn new() -> WebhookEventsModule {
+    WebhookEventsModule {
+      events: Vec::new(),
+      fallback_events: Vec::new(),
+    }
+  }
+
+  /// Add the variant of a webhook, its operation id is `event/action`, e.g.
+  /// `pull-request/opened`, or only the event for events without actions.
+  pub fn add_event(&mut self, name: &str, operation_id: &str, type_name: &str) {
+    let (event_name, action) = match operation_id.split_once('/') {
+      Some((event_name, action)) => (event_name, Some(action)),
+      None => (operation_id, None),
+    };
+    let event_name = event_name.replace('-', "_");
+    let action = action
+      .filter(|_| !ANY_ACTION_EVENTS.contains(&event_name.as_str()))
+      .map(|action| action.replace('-', "_"));
+
+    if action.is_some() && !self.fallback_events.contains(&event_name) {
+      self.fallback_events.push(event_name.clone());
+    }
+
+    self.events.push(WebhookEventVariant {
+      variant: RenameRule::VariantName.apply(name),
+      event_name,
+      action,
+      type_name: type_name.to_string(),
+    });
+  }
+}
+
+impl File for WebhookEventsModule {
+  fn file_name(&self) -> String {
+    "webhook_events.rs".to_string()
+  }
+
+  fn write(&self, path: &std::path::PathBuf) {
+    let template = include_str!("../../../templates/types/webhook_events.hbs");
+
+    let rendered = format_code(render_template(template, self));
+
+    let file_path = path.join(self.file_name());
+
+    std::fs::write(file_path, rendered).expect("Unable to write file");
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_add_event() {
+    let mut module = WebhookEventsModule::new();
+
+    module.add_event(
+      "pull-request-review-requested",
+      "pull-request/review-requested",
+      "PullRequestReviewRequestedEvent",
+    );
+    module.add_event("push", "push", "PushEvent");
+    module.add_event(
+      "repository-dispatch-sample.collected",
+      "repository-dispatch/sample.collected",
+      "RepositoryDispatchSampleCollectedEvent",
+    );
+
+    let event = &module.events[0];
+
+    assert_eq!(event.variant, "PullRequestReviewRequested");
+    assert_eq!(event.event_name, "pull_request");
+    assert_eq!(event.action.as_deref(), Some("review_requested"));
+    assert_eq!(event.type_name, "PullRequestReviewRequestedEvent");
+
+    assert_eq!(module.events[1].event_name, "push");
+    assert_eq!(module.events[1].action, None);
+
+    assert_eq!(module.events[2].event_name, "repository_dispatch");
+    assert_eq!(module.events[2].action, None);
+
+    assert_eq!(module.fallback_events, vec!["pull_request"]);
+  }
+}
@@ -1,43 +1,193 @@
-use crate::expirable_token::ExpirableToken;
+use crate::{
+  cache::CacheStore, middleware::Middleware, rate_limit::RateLimitPolicy, retry::RetryPolicy,
+  token_provider::TokenProvider,
+};
 use std::sync::Arc;
 
+pub(crate) const DEFAULT_BASE_URL: &str = "https://api.github.com";
+const DEFAULT_UPLOAD_URL: &str = "https://uploads.github.com";
+const DEFAULT_USER_AGENT: &str = "octocrate";
+
+#[derive(Clone)]
 pub struct APIConfig {
   pub base_url: String,
-  pub token: Option<Arc<dyn ExpirableToken>>,
+  /// The server of the operations that upload files, such as release assets.
+  pub upload_url: String,
+  pub token: Option<Arc<dyn TokenProvider>>,
+  /// The HTTP client shared by every request made with this config, so that
+  /// connections, TLS sessions and DNS lookups are reused between calls.
+  pub client: reqwest::Client,
+  pub user_agent: String,
+  pub rate_limit_policy: RateLimitPolicy,
+  /// Retry policy of every request made with this config, requests are only sent once
+  /// when it is not set.
+  pub retry_policy: Option<RetryPolicy>,
+  /// Middlewares around every request, the first one registered is the outermost.
+  pub middlewares: Vec<Arc<dyn Middleware>>,
+  /// Responses of `GET` requests kept to send conditional requests, nothing is cached
+  /// when it is not set.
+  pub cache: Option<Arc<dyn CacheStore>>,
+  /// The client of `DownloadRequest::location`, which must not follow redirects. One
+  /// without any settings is used when it is not set.
+  pub location_client: Option<reqwest::Client>,
 }
 
 pub type SharedAPIConfig = Arc<APIConfig>;
 
-impl APIConfig {
-  pub fn default() -> Self {
+impl Default for APIConfig {
+  fn default() -> Self {
     APIConfig {
-      base_url: "https://api.github.com".to_string(),
+      base_url: DEFAULT_BASE_URL.to_string(),
+      upload_url: DEFAULT_UPLOAD_URL.to_string(),
       token: None,
+      client: reqwest::Client::new(),
+      user_agent: DEFAULT_USER_AGENT.to_string(),
+      rate_limit_policy: RateLimitPolicy::default(),
+      retry_policy: None,
+      middlewares: Vec::new(),
+      cache: None,
+      location_client: None,
     }
   }
+}
 
-  pub fn with_token<T: ExpirableToken + 'static>(token: T) -> Self {
+impl APIConfig {
+  pub fn with_token<T: TokenProvider + 'static>(token: T) -> Self {
     APIConfig {
-      base_url: "https://api.github.com".to_string(),
       token: Some(Arc::new(token)),
+      ..Default::default()
     }
   }
 
   pub fn with_base_url(base_url: &str) -> Self {
     APIConfig {
       base_url: base_url.to_string(),
-      token: None,
+      upload_url: upload_url_for(base_url),
+      ..Default::default()
     }
   }
 
-  pub fn new<T: ExpirableToken + 'static>(base_url: &str, token: T) -> SharedAPIConfig {
+  /// A config for the GitHub Enterprise Server at the given host, e.g.
+  /// `https://github.example.com`, whose API is `/api/v3` and upload server `/api/uploads`.
+  pub fn enterprise(host: &str) -> Self {
+    Self::with_base_url(&format!("{}/api/v3", host.trim_end_matches('/')))
+  }
+
+  pub fn new<T: TokenProvider + 'static>(base_url: &str, token: T) -> SharedAPIConfig {
     Arc::new(APIConfig {
       base_url: base_url.to_string(),
+      upload_url: upload_url_for(base_url),
       token: Some(Arc::new(token)),
+      ..Default::default()
     })
   }
 
+  /// Send the given token with every request, e.g. with the config of
+  /// [`APIConfig::enterprise`].
+  pub fn token<T: TokenProvider + 'static>(mut self, token: T) -> Self {
+    self.token = Some(Arc::new(token));
+
+    self
+  }
+
+  /// Use the given client for every request, e.g. one built with [`ClientConfig`](crate::ClientConfig)
+  /// or one that is already shared with other parts of the application.
+  pub fn client(mut self, client: reqwest::Client) -> Self {
+    self.client = client;
+
+    self
+  }
+
+  /// Use the given client, built with [`ClientConfig::follow_redirects`](crate::ClientConfig::follow_redirects)
+  /// turned off, to read the `Location` of downloads.
+  pub fn location_client(mut self, client: reqwest::Client) -> Self {
+    self.location_client = Some(client);
+
+    self
+  }
+
+  pub fn upload_url(mut self, upload_url: impl Into<String>) -> Self {
+    self.upload_url = upload_url.into();
+
+    self
+  }
+
+  pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+    self.user_agent = user_agent.into();
+
+    self
+  }
+
+  pub fn rate_limit_policy(mut self, policy: RateLimitPolicy) -> Self {
+    self.rate_limit_policy = policy;
+
+    self
+  }
+
+  pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+    self.retry_policy = Some(policy);
+
+    self
+  }
+
+  pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+    self.middlewares.push(Arc::new(middleware));
+
+    self
+  }
+
+  pub fn cache<C: CacheStore + 'static>(mut self, cache: C) -> Self {
+    self.cache = Some(Arc::new(cache));
+
+    self
+  }
+
   pub fn shared(self) -> SharedAPIConfig {
     Arc::new(self)
   }
 }
+
+/// The upload server that goes with an API server: `https://HOST/api/uploads` for the
+/// `https://HOST/api/v3` of GitHub Enterprise Server, and the API server itself for
+/// anything else, such as a proxy or a mock.
+fn upload_url_for(base_url: &str) -> String {
+  let base_url = base_url.trim_end_matches('/');
+
+  if base_url == DEFAULT_BASE_URL {
+    return DEFAULT_UPLOAD_URL.to_string();
+  }
+
+  match base_url.strip_suffix("/api/v3") {
+    Some(host) => format!("{}/api/uploads", host),
+    None => base_url.to_string(),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_upload_url() {
+    assert_eq!(
+      APIConfig::default().upload_url,
+      "https://uploads.github.com"
+    );
+    assert_eq!(
+      APIConfig::with_base_url("https://github.example.com/api/v3").upload_url,
+      "https://github.example.com/api/uploads"
+    );
+    assert_eq!(
+      APIConfig::enterprise("https://github.example.com/").base_url,
+      "https://github.example.com/api/v3"
+    );
+    assert_eq!(
+      APIConfig::enterprise("https://github.example.com").upload_url,
+      "https://github.example.com/api/uploads"
+    );
+    assert_eq!(
+      APIConfig::with_base_url("http://127.0.0.1:8080").upload_url,
+      "http://127.0.0.1:8080"
+    );
+  }
+}
@@ -48,33 +48,55 @@ impl AppAuthorization {
       iss: self.app_id.clone(),
     };
 
-    let token = encode(
-      &Header::new(Algorithm::RS256),
-      &payload,
-      &EncodingKey::from_rsa_pem(self.private_key.as_bytes()).unwrap(),
-    )
-    .map_err(|_| Error::Error("Failed to generate token".to_string()))?;
+    let key = EncodingKey::from_rsa_pem(self.private_key.as_bytes())
+      .map_err(|err| Error::Error(format!("Invalid private key of GitHub App: {}", err)))?;
+
+    let token = encode(&Header::new(Algorithm::RS256), &payload, &key)
+      .map_err(|err| Error::Error(format!("Failed to generate token: {}", err)))?;
 
     Ok(GeneratedToken { token, expires_at })
   }
-}
 
-impl ExpirableToken for AppAuthorization {
-  fn get_token(&self) -> Option<String> {
+  fn get_or_generate_token(&self) -> Result<GeneratedToken, Error> {
     let mut token = self.inner.token.lock().unwrap();
 
     if let Some(token) = &*token {
       if token.expires_at > chrono::Utc::now() {
-        return Some(token.token.clone());
+        return Ok(token.clone());
       }
     }
 
-    match self.generate_token() {
-      Ok(new_token) => {
-        *token = Some(new_token.clone());
-        Some(new_token.token)
-      }
-      Err(_) => None,
-    }
+    let new_token = self.generate_token()?;
+
+    *token = Some(new_token.clone());
+
+    Ok(new_token)
+  }
+}
+
+impl ExpirableToken for AppAuthorization {
+  fn get_token(&self) -> Option<String> {
+    self.try_get_token().ok()
+  }
+
+  fn try_get_token(&self) -> Result<String, Error> {
+    self.get_or_generate_token().map(|token| token.token)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::token_provider::TokenProvider;
+
+  #[tokio::test]
+  async fn test_invalid_private_key() {
+    let authorization = AppAuthorization::new("1234".to_string(), "not a key".to_string());
+
+    let error = authorization.token().await.unwrap_err();
+
+    assert!(error
+      .to_string()
+      .contains("Invalid private key of GitHub App"));
   }
 }
@@ -0,0 +1,372 @@
+use crate::error::Error;
+use reqwest::{
+  header::{
+    HeaderMap, HeaderName, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED,
+  },
+  Method, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+  collections::{HashMap, VecDeque},
+  future::Future,
+  path::PathBuf,
+  pin::Pin,
+  sync::Mutex,
+};
+
+pub type CacheFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Where the responses of `GET` requests are kept with their `ETag` or `Last-Modified`
+/// header, to send conditional requests.
+///
+/// GitHub answers a conditional request with `304 Not Modified` when the resource
+/// didn't change, which doesn't count against the rate limit, and the cached response is
+/// returned instead. Failing to read or write the store never fails a request.
+///
+/// ```rust,ignore
+/// let config = APIConfig::with_token(token)
+///   .cache(MemoryCache::new(1000))
+///   .shared();
+/// ```
+pub trait CacheStore: Send + Sync {
+  fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<CachedResponse>>;
+
+  fn set<'a>(&'a self, key: &'a str, response: CachedResponse) -> CacheFuture<'a, ()>;
+}
+
+/// A response stored by a [`CacheStore`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedResponse {
+  pub status: u16,
+  pub headers: Vec<(String, String)>,
+  pub body: Vec<u8>,
+}
+
+impl CachedResponse {
+  pub(crate) fn new(status: StatusCode, headers: &HeaderMap, body: Vec<u8>) -> Self {
+    CachedResponse {
+      status: status.as_u16(),
+      headers: headers
+        .iter()
+        .filter_map(|(name, value)| {
+          value
+            .to_str()
+            .ok()
+            .map(|value| (name.to_string(), value.to_string()))
+        })
+        .collect(),
+      body,
+    }
+  }
+
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(header, _)| header.eq_ignore_ascii_case(name))
+      .map(|(_, value)| value.as_str())
+  }
+
+  pub fn etag(&self) -> Option<&str> {
+    self.header(ETAG.as_str())
+  }
+
+  pub fn last_modified(&self) -> Option<&str> {
+    self.header(LAST_MODIFIED.as_str())
+  }
+
+  pub(crate) fn status_code(&self) -> StatusCode {
+    StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK)
+  }
+
+  /// The stored headers, updated with the headers of the `304` response, such as the
+  /// rate limit.
+  pub(crate) fn header_map(&self, not_modified: &HeaderMap) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    for (name, value) in &self.headers {
+      if let (Ok(name), Ok(value)) = (
+        HeaderName::from_bytes(name.as_bytes()),
+        HeaderValue::from_str(value),
+      ) {
+        headers.append(name, value);
+      }
+    }
+
+    for (name, value) in not_modified {
+      headers.insert(name, value.clone());
+    }
+
+    headers
+  }
+}
+
+/// The cache entry of a request, found before it was sent.
+pub(crate) struct CacheLookup {
+  pub key: String,
+  pub cached: Option<CachedResponse>,
+}
+
+/// Look up a `GET` request in the cache, and add the conditional headers of the cached
+/// response to it.
+///
+/// Responses are kept per `Authorization` value, so that a store shared between configs
+/// never returns the private response of one token to another.
+pub(crate) async fn lookup(
+  store: &dyn CacheStore,
+  builder: reqwest::RequestBuilder,
+  accept: Option<&str>,
+  authorization: Option<&str>,
+) -> Result<(reqwest::RequestBuilder, Option<CacheLookup>), Error> {
+  let (client, request) = builder.build_split();
+  let mut request = request.map_err(|err| Error::Error(err.to_string()))?;
+
+  if request.method() != Method::GET {
+    return Ok((reqwest::RequestBuilder::from_parts(client, request), None));
+  }
+
+  // The same URL returns another representation for another media type
+  let key = format!(
+    "{} {} {}",
+    authorization
+      .map(|authorization| hex::encode(Sha256::digest(authorization.as_bytes())))
+      .unwrap_or_else(|| "anonymous".to_string()),
+    accept.unwrap_or("application/vnd.github+json"),
+    request.url()
+  );
+  let cached = store.get(&key).await;
+
+  if let Some(cached) = &cached {
+    let headers = request.headers_mut();
+
+    if let Some(value) = cached
+      .etag()
+      .and_then(|etag| HeaderValue::from_str(etag).ok())
+    {
+      headers.insert(IF_NONE_MATCH, value);
+    }
+
+    if let Some(value) = cached
+      .last_modified()
+      .and_then(|last_modified| HeaderValue::from_str(last_modified).ok())
+    {
+      headers.insert(IF_MODIFIED_SINCE, value);
+    }
+  }
+
+  Ok((
+    reqwest::RequestBuilder::from_parts(client, request),
+    Some(CacheLookup { key, cached }),
+  ))
+}
+
+/// Whether a response can be revalidated later, and is worth storing.
+pub(crate) fn is_cacheable(headers: &HeaderMap) -> bool {
+  headers.contains_key(ETAG) || headers.contains_key(LAST_MODIFIED)
+}
+
+/// An in-memory store that keeps the most recently used responses.
+pub struct MemoryCache {
+  capacity: usize,
+  entries: Mutex<MemoryEntries>,
+}
+
+#[derive(Default)]
+struct MemoryEntries {
+  responses: HashMap<String, CachedResponse>,
+  /// The keys from the least to the most recently used.
+  order: VecDeque<String>,
+}
+
+impl MemoryEntries {
+  fn touch(&mut self, key: &str) {
+    if let Some(index) = self.order.iter().position(|entry| entry == key) {
+      if let Some(entry) = self.order.remove(index) {
+        self.order.push_back(entry);
+      }
+    }
+  }
+}
+
+impl MemoryCache {
+  pub fn new(capacity: usize) -> Self {
+    MemoryCache {
+      capacity,
+      entries: Mutex::new(MemoryEntries::default()),
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.lock().unwrap().responses.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+}
+
+impl CacheStore for MemoryCache {
+  fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<CachedResponse>> {
+    let mut entries = self.entries.lock().unwrap();
+    let response = entries.responses.get(key).cloned();
+
+    if response.is_some() {
+      entries.touch(key);
+    }
+
+    Box::pin(async move { response })
+  }
+
+  fn set<'a>(&'a self, key: &'a str, response: CachedResponse) -> CacheFuture<'a, ()> {
+    let mut entries = self.entries.lock().unwrap();
+
+    if entries
+      .responses
+      .insert(key.to_string(), response)
+      .is_some()
+    {
+      entries.touch(key);
+    } else {
+      entries.order.push_back(key.to_string());
+    }
+
+    while entries.responses.len() > self.capacity {
+      match entries.order.pop_front() {
+        Some(oldest) => entries.responses.remove(&oldest),
+        None => break,
+      };
+    }
+
+    Box::pin(async {})
+  }
+}
+
+/// A store that keeps each response in a JSON file of a directory, so that the cache
+/// survives restarts.
+pub struct DiskCache {
+  directory: PathBuf,
+}
+
+impl DiskCache {
+  pub fn new(directory: impl Into<PathBuf>) -> Self {
+    DiskCache {
+      directory: directory.into(),
+    }
+  }
+
+  fn path(&self, key: &str) -> PathBuf {
+    let name = hex::encode(Sha256::digest(key.as_bytes()));
+
+    self.directory.join(format!("{}.json", name))
+  }
+}
+
+impl CacheStore for DiskCache {
+  fn get<'a>(&'a self, key: &'a str) -> CacheFuture<'a, Option<CachedResponse>> {
+    Box::pin(async move {
+      let content = tokio::fs::read(self.path(key)).await.ok()?;
+
+      serde_json::from_slice(&content).ok()
+    })
+  }
+
+  fn set<'a>(&'a self, key: &'a str, response: CachedResponse) -> CacheFuture<'a, ()> {
+    Box::pin(async move {
+      let Ok(content) = serde_json::to_vec(&response) else {
+        return;
+      };
+
+      if tokio::fs::create_dir_all(&self.directory).await.is_ok() {
+        let _ = tokio::fs::write(self.path(key), content).await;
+      }
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn response(etag: &str) -> CachedResponse {
+    CachedResponse {
+      status: 200,
+      headers: vec![("etag".to_string(), etag.to_string())],
+      body: b"{}".to_vec(),
+    }
+  }
+
+  #[tokio::test]
+  async fn test_memory_cache() {
+    let cache = MemoryCache::new(2);
+
+    cache.set("a", response("\"a\"")).await;
+    cache.set("b", response("\"b\"")).await;
+
+    // `a` is now the most recently used, so `b` is evicted
+    assert_eq!(cache.get("a").await.unwrap().etag(), Some("\"a\""));
+
+    cache.set("c", response("\"c\"")).await;
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("b").await.is_none());
+    assert!(cache.get("c").await.is_some());
+  }
+
+  #[tokio::test]
+  async fn test_disk_cache() {
+    let directory = std::env::temp_dir().join(format!("octocrate-cache-{}", std::process::id()));
+    let cache = DiskCache::new(&directory);
+
+    assert!(cache.get("a").await.is_none());
+
+    cache.set("a", response("W/\"a\"")).await;
+
+    assert_eq!(cache.get("a").await, Some(response("W/\"a\"")));
+
+    std::fs::remove_dir_all(directory).unwrap();
+  }
+
+  #[tokio::test]
+  async fn test_lookup_per_authorization() {
+    let cache = MemoryCache::new(10);
+    let client = reqwest::Client::new();
+    let request = || client.get("https://api.github.com/repos/panghu-huang/octocrate");
+
+    let (_, lookup) = lookup(&cache, request(), None, Some("Bearer a"))
+      .await
+      .unwrap();
+
+    cache.set(&lookup.unwrap().key, response("\"a\"")).await;
+
+    let (builder, lookup) = lookup_with(&cache, request(), Some("Bearer a")).await;
+
+    assert!(lookup.cached.is_some());
+    assert!(builder
+      .build()
+      .unwrap()
+      .headers()
+      .contains_key(IF_NONE_MATCH));
+
+    for authorization in [Some("Bearer b"), None] {
+      let (builder, lookup) = lookup_with(&cache, request(), authorization).await;
+
+      assert!(lookup.cached.is_none());
+      assert!(!builder
+        .build()
+        .unwrap()
+        .headers()
+        .contains_key(IF_NONE_MATCH));
+    }
+  }
+
+  async fn lookup_with(
+    cache: &MemoryCache,
+    builder: reqwest::RequestBuilder,
+    authorization: Option<&str>,
+  ) -> (reqwest::RequestBuilder, CacheLookup) {
+    let (builder, lookup) = lookup(cache, builder, None, authorization).await.unwrap();
+
+    (builder, lookup.unwrap())
+  }
+}
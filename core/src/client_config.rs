@@ -0,0 +1,233 @@
+use crate::error::Error;
+use std::time::Duration;
+
+/// Settings used to build the shared HTTP client of an [`APIConfig`](crate::APIConfig).
+///
+/// ```rust,ignore
+/// let client = ClientConfig::new()
+///   .timeout(Duration::from_secs(30))
+///   .proxy(reqwest::Proxy::https("http://proxy.internal:8080")?)
+///   .build()?;
+///
+/// let config = APIConfig::with_token(token).client(client).shared();
+/// ```
+#[derive(Default)]
+pub struct ClientConfig {
+  pub timeout: Option<Duration>,
+  pub connect_timeout: Option<Duration>,
+  pub pool_idle_timeout: Option<Duration>,
+  pub proxies: Vec<reqwest::Proxy>,
+  pub root_certificates: Vec<reqwest::Certificate>,
+  /// Whether redirects are followed, they are by default.
+  pub follow_redirects: Option<bool>,
+}
+
+impl ClientConfig {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Total timeout of a request, from connecting until the body has been read.
+  pub fn timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = Some(timeout);
+
+    self
+  }
+
+  pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+    self.connect_timeout = Some(timeout);
+
+    self
+  }
+
+  pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+    self.pool_idle_timeout = Some(timeout);
+
+    self
+  }
+
+  pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+    self.proxies.push(proxy);
+
+    self
+  }
+
+  /// Trust an additional root certificate, e.g. the CA of a GitHub Enterprise Server instance.
+  pub fn root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+    self.root_certificates.push(certificate);
+
+    self
+  }
+
+  /// Don't follow redirects, e.g. for the client of [`APIConfig::location_client`](crate::APIConfig::location_client).
+  pub fn follow_redirects(mut self, follow: bool) -> Self {
+    self.follow_redirects = Some(follow);
+
+    self
+  }
+
+  pub fn build(self) -> Result<reqwest::Client, Error> {
+    let mut builder = reqwest::Client::builder();
+
+    if let Some(timeout) = self.timeout {
+      builder = builder.timeout(timeout);
+    }
+
+    if let Some(timeout) = self.connect_timeout {
+      builder = builder.connect_timeout(timeout);
+    }
+
+    if let Some(timeout) = self.pool_idle_timeout {
+      builder = builder.pool_idle_timeout(timeout);
+    }
+
+    for proxy in self.proxies {
+      builder = builder.proxy(proxy);
+    }
+
+    for certificate in self.root_certificates {
+      builder = builder.add_root_certificate(certificate);
+    }
+
+    if self.follow_redirects == Some(false) {
+      builder = builder.redirect(reqwest::redirect::Policy::none());
+    }
+
+    builder
+      .build()
+      .map_err(|err| Error::Error(format!("Failed to build HTTP client: {}", err)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_server::serve;
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+  };
+  use tokio_native_tls::{native_tls, TlsAcceptor};
+
+  const CA: &[u8] = include_bytes!("../fixtures/ca.pem");
+  const CERTIFICATE: &[u8] = include_bytes!("../fixtures/localhost.pem");
+  const KEY: &[u8] = include_bytes!("../fixtures/localhost.key");
+
+  #[test]
+  fn test_build_client() {
+    let client = ClientConfig::new()
+      .timeout(Duration::from_secs(10))
+      .connect_timeout(Duration::from_secs(2))
+      .proxy(reqwest::Proxy::https("http://127.0.0.1:8080").unwrap())
+      .build();
+
+    assert!(client.is_ok());
+  }
+
+  #[tokio::test]
+  async fn test_timeout() {
+    // Accepts connections but never answers
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+      let mut connections = Vec::new();
+
+      while let Ok((stream, _)) = listener.accept().await {
+        connections.push(stream);
+      }
+    });
+
+    let client = ClientConfig::new()
+      .timeout(Duration::from_millis(100))
+      .build()
+      .unwrap();
+
+    let error = client
+      .get(format!("http://{}/zen", address))
+      .send()
+      .await
+      .unwrap_err();
+
+    assert!(error.is_timeout());
+  }
+
+  #[tokio::test]
+  async fn test_proxy() {
+    let (proxy_url, requests) = serve(vec![("200 OK", "", "{}")]).await;
+
+    let client = ClientConfig::new()
+      .proxy(reqwest::Proxy::http(&proxy_url).unwrap())
+      .build()
+      .unwrap();
+
+    let response = client
+      .get("http://github.example.invalid/api/v3/zen")
+      .send()
+      .await
+      .unwrap();
+
+    assert_eq!(response.status(), 200);
+    assert!(requests.lock().unwrap()[0]
+      .starts_with("GET http://github.example.invalid/api/v3/zen HTTP/1.1"));
+  }
+
+  /// Serve `200 OK` over TLS with a certificate for `localhost` signed by the test CA.
+  async fn serve_tls() -> String {
+    let identity = native_tls::Identity::from_pkcs8(CERTIFICATE, KEY).unwrap();
+    let acceptor = TlsAcceptor::from(native_tls::TlsAcceptor::new(identity).unwrap());
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+      while let Ok((stream, _)) = listener.accept().await {
+        // Clients that don't trust the CA abort the handshake
+        let Ok(mut stream) = acceptor.accept(stream).await else {
+          continue;
+        };
+
+        let mut buffer = [0; 4096];
+        let _ = stream.read(&mut buffer).await;
+        let _ = stream
+          .write_all(b"HTTP/1.1 200 OK\r\nconnection: close\r\ncontent-length: 0\r\n\r\n")
+          .await;
+        let _ = stream.shutdown().await;
+      }
+    });
+
+    format!("https://localhost:{}/zen", port)
+  }
+
+  #[tokio::test]
+  async fn test_root_certificate() {
+    let url = serve_tls().await;
+
+    let error = ClientConfig::new()
+      .build()
+      .unwrap()
+      .get(&url)
+      .send()
+      .await
+      .unwrap_err();
+
+    assert!(error.is_connect());
+
+    let client = ClientConfig::new()
+      .root_certificate(reqwest::Certificate::from_pem(CA).unwrap())
+      .build()
+      .unwrap();
+
+    assert_eq!(client.get(&url).send().await.unwrap().status(), 200);
+  }
+
+  #[tokio::test]
+  async fn test_follow_redirects() {
+    let (url, requests) = serve(vec![("302 Found", "location: /artifacts/1.zip\r\n", "")]).await;
+
+    let client = ClientConfig::new().follow_redirects(false).build().unwrap();
+    let response = client.get(&url).send().await.unwrap();
+
+    assert_eq!(response.status(), 302);
+    assert_eq!(requests.lock().unwrap().len(), 1);
+  }
+}
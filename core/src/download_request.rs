@@ -0,0 +1,273 @@
+use crate::{
+  api_config::SharedAPIConfig,
+  download_request_builder::DownloadRequestBuilder,
+  error::{Error, TransportError, TransportErrorKind},
+  retry::RetryPolicy,
+  send::{send, send_expecting},
+};
+use bytes::Bytes;
+use reqwest::{
+  header::{HeaderMap, LOCATION},
+  Method, StatusCode, Url,
+};
+use std::{marker::PhantomData, path::Path};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// A request for an archive, log or artifact, which GitHub answers with a redirect to
+/// a short-lived URL of the content.
+///
+/// The redirect is followed, and the content can be read chunk by chunk instead of
+/// being buffered, or only its URL is read with [`DownloadRequest::location`]:
+///
+/// ```rust,ignore
+/// let download = api
+///   .actions
+///   .download_artifact("panghu-huang", "octocrate", 1, "zip")
+///   .send()
+///   .await?;
+///
+/// println!("Downloading {}", download.url());
+///
+/// download.save("artifact.zip").await?;
+/// ```
+pub struct DownloadRequest<Query> {
+  pub(crate) builder: reqwest::RequestBuilder,
+  pub(crate) api_config: SharedAPIConfig,
+  pub(crate) retry_policy: Option<RetryPolicy>,
+  pub(crate) _query: PhantomData<Query>,
+}
+
+impl<Query> DownloadRequest<Query>
+where
+  Query: serde::Serialize,
+{
+  pub fn builder(config: &SharedAPIConfig) -> DownloadRequestBuilder<Query> {
+    DownloadRequestBuilder::new(config)
+  }
+
+  pub fn query(mut self, query: &Query) -> Self {
+    self.builder = self.builder.query(query);
+
+    self
+  }
+
+  /// Retry this request with the given policy instead of the one of the config.
+  pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+    self.retry_policy = Some(policy);
+
+    self
+  }
+
+  /// Follow the redirect and return the response before its body is read.
+  pub async fn send(self) -> Result<Download, Error> {
+    let (method, response) = send(
+      &self.api_config,
+      self.builder,
+      None,
+      self.retry_policy.as_ref(),
+    )
+    .await?;
+
+    Ok(Download { method, response })
+  }
+
+  /// The `Location` GitHub redirects to, e.g. to hand it to another process, without
+  /// following it or downloading the content. The URL is only valid for a few minutes.
+  pub async fn location(self) -> Result<Url, Error> {
+    let client = match &self.api_config.location_client {
+      Some(client) => client.clone(),
+      None => reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|err| Error::Error(format!("Failed to build HTTP client: {}", err)))?,
+    };
+
+    let (_, request) = self.builder.build_split();
+    let request = request.map_err(|err| Error::Error(err.to_string()))?;
+
+    let (method, response) = send_expecting(
+      &self.api_config,
+      reqwest::RequestBuilder::from_parts(client, request),
+      None,
+      self.retry_policy.as_ref(),
+      |status| status.is_success() || status.is_redirection(),
+    )
+    .await?;
+
+    response
+      .headers()
+      .get(LOCATION)
+      .and_then(|location| location.to_str().ok())
+      .and_then(|location| response.url().join(location).ok())
+      .ok_or_else(|| {
+        Error::Error(format!(
+          "{} {} did not redirect, it answered with status {}",
+          method,
+          response.url(),
+          response.status()
+        ))
+      })
+  }
+}
+
+/// The content of a download, read from the URL GitHub redirected to.
+pub struct Download {
+  method: Method,
+  response: reqwest::Response,
+}
+
+impl Download {
+  /// The URL the content is read from, i.e. the `Location` of the redirect.
+  pub fn url(&self) -> &Url {
+    self.response.url()
+  }
+
+  pub fn status(&self) -> StatusCode {
+    self.response.status()
+  }
+
+  pub fn headers(&self) -> &HeaderMap {
+    self.response.headers()
+  }
+
+  /// The size of the content, if the server sent it.
+  pub fn content_length(&self) -> Option<u64> {
+    self.response.content_length()
+  }
+
+  /// The next chunk of the content, or `None` once all of it has been read.
+  pub async fn chunk(&mut self) -> Result<Option<Bytes>, Error> {
+    match self.response.chunk().await {
+      Ok(chunk) => Ok(chunk),
+      Err(err) => Err(self.transport_error()(&err)),
+    }
+  }
+
+  /// Read all of the content into memory.
+  pub async fn bytes(self) -> Result<Bytes, Error> {
+    let error = self.transport_error();
+
+    self.response.bytes().await.map_err(|err| error(&err))
+  }
+
+  /// Read all of the content as text, e.g. the logs of a job.
+  pub async fn text(self) -> Result<String, Error> {
+    let bytes = self.bytes().await?;
+
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+  }
+
+  /// Write the content to the writer as it arrives, returning the number of bytes
+  /// written.
+  pub async fn write_to<W>(mut self, writer: &mut W) -> Result<u64, Error>
+  where
+    W: AsyncWrite + Unpin,
+  {
+    let mut written = 0;
+
+    while let Some(chunk) = self.chunk().await? {
+      writer
+        .write_all(&chunk)
+        .await
+        .map_err(|err| Error::Error(format!("Failed to write download: {}", err)))?;
+
+      written += chunk.len() as u64;
+    }
+
+    writer
+      .flush()
+      .await
+      .map_err(|err| Error::Error(format!("Failed to write download: {}", err)))?;
+
+    Ok(written)
+  }
+
+  /// Write the content to a file, which is created or truncated.
+  pub async fn save(self, path: impl AsRef<Path>) -> Result<u64, Error> {
+    let path = path.as_ref();
+    let mut file = tokio::fs::File::create(path)
+      .await
+      .map_err(|err| Error::Error(format!("Failed to create {}: {}", path.display(), err)))?;
+
+    self.write_to(&mut file).await
+  }
+
+  fn transport_error(&self) -> impl Fn(&reqwest::Error) -> Error {
+    let method = self.method.clone();
+    let url = self.response.url().clone();
+    let status = self.response.status();
+
+    move |err| {
+      Error::Transport(Box::new(TransportError {
+        method: method.clone(),
+        url: url.clone(),
+        kind: TransportErrorKind::from(err),
+        message: format!("Failed to read download with status {}: {}", status, err),
+      }))
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{test_server::serve, APIConfig};
+
+  #[tokio::test]
+  async fn test_download() {
+    let (base_url, requests) = serve(vec![
+      ("302 Found", "location: /artifacts/1.zip\r\n", ""),
+      ("200 OK", "", "PK\u{3}\u{4}artifact"),
+    ])
+    .await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let download = DownloadRequest::<()>::builder(&config)
+      .get("/repos/panghu-huang/octocrate/actions/artifacts/1/zip")
+      .build()
+      .send()
+      .await
+      .unwrap();
+
+    assert_eq!(download.url().path(), "/artifacts/1.zip");
+    assert_eq!(download.content_length(), Some(12));
+
+    let mut content = Vec::new();
+
+    assert_eq!(download.write_to(&mut content).await.unwrap(), 12);
+    assert_eq!(content, b"PK\x03\x04artifact");
+    assert_eq!(requests.lock().unwrap().len(), 2);
+  }
+
+  #[tokio::test]
+  async fn test_location() {
+    let (base_url, requests) =
+      serve(vec![("302 Found", "location: /artifacts/1.zip\r\n", "")]).await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let location = DownloadRequest::<()>::builder(&config)
+      .get("/repos/panghu-huang/octocrate/actions/artifacts/1/zip")
+      .build()
+      .location()
+      .await
+      .unwrap();
+
+    assert_eq!(location.as_str(), format!("{}/artifacts/1.zip", base_url));
+    assert_eq!(requests.lock().unwrap().len(), 1);
+  }
+
+  #[tokio::test]
+  async fn test_location_without_redirect() {
+    let (base_url, _) = serve(vec![("200 OK", "", "{}")]).await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let error = DownloadRequest::<()>::builder(&config)
+      .get("/repos/panghu-huang/octocrate/actions/artifacts/1/zip")
+      .build()
+      .location()
+      .await
+      .unwrap_err();
+
+    assert!(error.to_string().contains("did not redirect"));
+  }
+}
@@ -0,0 +1,41 @@
+use crate::{
+  api_config::SharedAPIConfig, download_request::DownloadRequest, request_builder::join_url,
+};
+use std::marker::PhantomData;
+
+pub struct DownloadRequestBuilder<Query> {
+  builder: Option<reqwest::RequestBuilder>,
+  api_config: SharedAPIConfig,
+  _query: PhantomData<Query>,
+}
+
+impl<Query> DownloadRequestBuilder<Query>
+where
+  Query: serde::Serialize,
+{
+  pub fn new(config: &SharedAPIConfig) -> Self {
+    Self {
+      builder: None,
+      api_config: config.clone(),
+      _query: PhantomData,
+    }
+  }
+
+  pub fn get(mut self, url: impl Into<String>) -> Self {
+    let url = join_url(&self.api_config.base_url, url.into());
+    let builder = self.api_config.client.get(url);
+
+    self.builder = Some(builder);
+
+    self
+  }
+
+  pub fn build(self) -> DownloadRequest<Query> {
+    DownloadRequest {
+      builder: self.builder.expect("RequestBuilder is not configured"),
+      api_config: self.api_config.clone(),
+      retry_policy: None,
+      _query: PhantomData,
+    }
+  }
+}
@@ -1,18 +1,253 @@
+use chrono::{DateTime, Utc};
+use reqwest::{header::HeaderMap, Method, StatusCode, Url};
 use serde::Deserialize;
+use std::time::Duration;
 use thiserror::Error as ThisError;
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Debug, Clone, Default)]
 pub struct APIErrorResponse {
   pub message: String,
-  pub documentation_url: String,
+  #[serde(default)]
+  pub documentation_url: Option<String>,
+  /// The fields that failed validation, sent along with `422 Unprocessable Entity`.
+  #[serde(default)]
+  pub errors: Vec<ValidationError>,
+}
+
+/// One entry of the `errors` array of a validation failure.
+///
+/// Most endpoints describe the failure with `resource`, `field` and `code`, some only
+/// send a `message`, or even a bare string.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(from = "RawValidationError")]
+pub struct ValidationError {
+  pub resource: Option<String>,
+  pub field: Option<String>,
+  pub code: Option<String>,
+  pub message: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawValidationError {
+  Message(String),
+  Object {
+    resource: Option<String>,
+    field: Option<String>,
+    code: Option<String>,
+    message: Option<String>,
+  },
+}
+
+impl From<RawValidationError> for ValidationError {
+  fn from(value: RawValidationError) -> Self {
+    match value {
+      RawValidationError::Message(message) => ValidationError {
+        message: Some(message),
+        ..Default::default()
+      },
+      RawValidationError::Object {
+        resource,
+        field,
+        code,
+        message,
+      } => ValidationError {
+        resource,
+        field,
+        code,
+        message,
+      },
+    }
+  }
+}
+
+/// A response of GitHub without a success status, and the request it answered.
+#[derive(Debug, Clone)]
+pub struct APIError {
+  pub method: Method,
+  pub url: Url,
+  pub status: StatusCode,
+  pub headers: HeaderMap,
+  pub response: APIErrorResponse,
+}
+
+/// A request that couldn't be sent, or whose response couldn't be read.
+#[derive(Debug, Clone)]
+pub struct TransportError {
+  pub method: Method,
+  pub url: Url,
+  pub kind: TransportErrorKind,
+  pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+  /// The connection couldn't be established.
+  Connect,
+  Timeout,
+  /// The connection failed while the request was sent, e.g. it was reset.
+  Request,
+  /// The response body couldn't be read.
+  Body,
+  Other,
+}
+
+impl From<&reqwest::Error> for TransportErrorKind {
+  fn from(error: &reqwest::Error) -> Self {
+    if error.is_connect() {
+      TransportErrorKind::Connect
+    } else if error.is_timeout() {
+      TransportErrorKind::Timeout
+    } else if error.is_request() {
+      TransportErrorKind::Request
+    } else if error.is_body() || error.is_decode() {
+      TransportErrorKind::Body
+    } else {
+      TransportErrorKind::Other
+    }
+  }
+}
+
+/// A failure of an OAuth flow, such as an `access_denied` or `bad_verification_code`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OAuthError {
+  pub error: String,
+  #[serde(default)]
+  pub error_description: Option<String>,
+  #[serde(default)]
+  pub error_uri: Option<String>,
+}
+
+/// A success response whose body doesn't match the expected type.
+#[derive(Debug, Clone)]
+pub struct DecodeError {
+  pub method: Method,
+  pub url: Url,
+  pub status: StatusCode,
+  pub message: String,
+  pub body: String,
 }
 
 #[derive(ThisError, Debug, Clone)]
 pub enum Error {
   #[error("Error: {0}")]
   Error(String),
-  #[error("Request failed with {0}")]
-  RequestFailed(APIErrorResponse),
+  /// 404, the resource doesn't exist or isn't visible with the current token.
+  #[error("Not found: {0}")]
+  NotFound(Box<APIError>),
+  /// 401, the token is missing, invalid or expired.
+  #[error("Unauthorized: {0}")]
+  Unauthorized(Box<APIError>),
+  /// 403, the token isn't allowed to access the resource.
+  #[error("Forbidden: {0}")]
+  Forbidden(Box<APIError>),
+  /// 422, the fields that failed are in `response.errors`.
+  #[error("Validation failed: {0}")]
+  Validation(Box<APIError>),
+  /// 409, e.g. a merge conflict or a repository that is being created.
+  #[error("Conflict: {0}")]
+  Conflict(Box<APIError>),
+  /// Any other status that isn't a success.
+  #[error("Request failed: {0}")]
+  RequestFailed(Box<APIError>),
+  /// GitHub rejected the request because of a primary or secondary rate limit.
+  #[error("Rate limited, retry after {retry_after:?} or at {reset_at:?}: {error}")]
+  RateLimited {
+    error: Box<APIError>,
+    reset_at: Option<DateTime<Utc>>,
+    retry_after: Option<Duration>,
+  },
+  /// The request couldn't be sent or the response couldn't be read.
+  #[error("Failed to send {} {}: {}", .0.method, .0.url, .0.message)]
+  Transport(Box<TransportError>),
+  /// The response body doesn't match the expected type.
+  #[error(
+    "Failed to parse response of {} {} with status {}: {}",
+    .0.method,
+    .0.url,
+    .0.status,
+    .0.message
+  )]
+  Decode(Box<DecodeError>),
+  /// An OAuth flow failed, e.g. the user denied the authorization or the code expired.
+  #[error("OAuth failed with {}: {}", .0.error, .0.error_description.as_deref().unwrap_or_default())]
+  OAuth(Box<OAuthError>),
+}
+
+impl APIError {
+  pub(crate) fn new(
+    method: Method,
+    url: Url,
+    status: StatusCode,
+    headers: HeaderMap,
+    body: &str,
+  ) -> Self {
+    APIError {
+      method,
+      url,
+      status,
+      headers,
+      response: serde_json::from_str(body).unwrap_or_else(|_| APIErrorResponse {
+        message: body.to_string(),
+        ..Default::default()
+      }),
+    }
+  }
+
+  pub(crate) fn into_error(self) -> Error {
+    let status = self.status;
+    let error = Box::new(self);
+
+    match status {
+      StatusCode::NOT_FOUND => Error::NotFound(error),
+      StatusCode::UNAUTHORIZED => Error::Unauthorized(error),
+      StatusCode::FORBIDDEN => Error::Forbidden(error),
+      StatusCode::UNPROCESSABLE_ENTITY => Error::Validation(error),
+      StatusCode::CONFLICT => Error::Conflict(error),
+      _ => Error::RequestFailed(error),
+    }
+  }
+}
+
+impl Error {
+  /// The status of the response, if one was received.
+  pub fn status(&self) -> Option<StatusCode> {
+    match self {
+      Error::Error(_) | Error::Transport(_) | Error::OAuth(_) => None,
+      Error::Decode(error) => Some(error.status),
+      Error::RateLimited { error, .. } => Some(error.status),
+      Error::NotFound(error)
+      | Error::Unauthorized(error)
+      | Error::Forbidden(error)
+      | Error::Validation(error)
+      | Error::Conflict(error)
+      | Error::RequestFailed(error) => Some(error.status),
+    }
+  }
+
+  /// The method and URL of the failed request.
+  pub fn request(&self) -> Option<(&Method, &Url)> {
+    match self {
+      Error::Error(_) | Error::OAuth(_) => None,
+      Error::Transport(error) => Some((&error.method, &error.url)),
+      Error::Decode(error) => Some((&error.method, &error.url)),
+      Error::RateLimited { error, .. }
+      | Error::NotFound(error)
+      | Error::Unauthorized(error)
+      | Error::Forbidden(error)
+      | Error::Validation(error)
+      | Error::Conflict(error)
+      | Error::RequestFailed(error) => Some((&error.method, &error.url)),
+    }
+  }
+
+  /// The fields that failed validation, empty for any other error.
+  pub fn validation_errors(&self) -> &[ValidationError] {
+    match self {
+      Error::Validation(error) => &error.response.errors,
+      _ => &[],
+    }
+  }
 }
 
 impl std::fmt::Display for APIErrorResponse {
@@ -20,3 +255,85 @@ impl std::fmt::Display for APIErrorResponse {
     write!(f, "Error: {}", self.message)
   }
 }
+
+impl std::fmt::Display for APIError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    write!(
+      f,
+      "{} {} responded with {}, {}",
+      self.method, self.url, self.status, self.response.message
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn api_error(status: StatusCode, body: &str) -> Error {
+    APIError::new(
+      Method::POST,
+      "https://api.github.com/repos/panghu-huang/octocrate/issues"
+        .parse()
+        .unwrap(),
+      status,
+      HeaderMap::new(),
+      body,
+    )
+    .into_error()
+  }
+
+  #[test]
+  fn test_validation_error() {
+    let error = api_error(
+      StatusCode::UNPROCESSABLE_ENTITY,
+      r#"{
+        "message": "Validation Failed",
+        "errors": [
+          { "resource": "Issue", "field": "title", "code": "missing_field" },
+          "Label does not exist"
+        ],
+        "documentation_url": "https://docs.github.com/rest/issues/issues#create-an-issue"
+      }"#,
+    );
+
+    assert!(matches!(error, Error::Validation(_)));
+    assert_eq!(error.status(), Some(StatusCode::UNPROCESSABLE_ENTITY));
+    assert_eq!(error.request().unwrap().0, Method::POST);
+    assert_eq!(
+      error.validation_errors(),
+      &[
+        ValidationError {
+          resource: Some("Issue".to_string()),
+          field: Some("title".to_string()),
+          code: Some("missing_field".to_string()),
+          message: None,
+        },
+        ValidationError {
+          message: Some("Label does not exist".to_string()),
+          ..Default::default()
+        }
+      ]
+    );
+  }
+
+  #[test]
+  fn test_error_without_documentation_url() {
+    let error = api_error(StatusCode::NOT_FOUND, r#"{ "message": "Not Found" }"#);
+
+    match error {
+      Error::NotFound(error) => {
+        assert_eq!(error.response.message, "Not Found");
+        assert_eq!(error.response.documentation_url, None);
+      }
+      _ => panic!("Expected NotFound"),
+    }
+
+    let error = api_error(StatusCode::BAD_GATEWAY, "Bad Gateway");
+
+    match error {
+      Error::RequestFailed(error) => assert_eq!(error.response.message, "Bad Gateway"),
+      _ => panic!("Expected RequestFailed"),
+    }
+  }
+}
@@ -1,12 +1,32 @@
+use crate::{error::Error, token_provider::AuthScheme};
+
 pub trait ExpirableToken: Send + Sync {
   // fn is_expired(&self) -> bool;
   fn get_token(&self) -> Option<String>;
+
+  /// The token, or why there is none, e.g. an invalid key. Fails with `Token is expired`
+  /// when [`ExpirableToken::get_token`] returns `None` by default.
+  fn try_get_token(&self) -> Result<String, Error> {
+    self
+      .get_token()
+      .ok_or_else(|| Error::Error("Token is expired".to_string()))
+  }
+
+  /// How the token is sent in the `Authorization` header, `Bearer` by default.
+  fn auth_scheme(&self) -> AuthScheme {
+    AuthScheme::Bearer
+  }
 }
 
 #[cfg(any(feature = "full", feature = "apps"))]
 impl ExpirableToken for octocrate_types::InstallationToken {
   fn get_token(&self) -> Option<String> {
-    let is_expired = self.expires_at < chrono::Utc::now().to_rfc3339();
+    use crate::timestamp::Timestamp;
+
+    let is_expired = self
+      .expires_at
+      .to_date_time()
+      .map_or(true, |expires_at| expires_at < chrono::Utc::now());
 
     if is_expired {
       None
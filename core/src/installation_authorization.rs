@@ -0,0 +1,341 @@
+use crate::{
+  api_config::{APIConfig, SharedAPIConfig},
+  app_authorization::AppAuthorization,
+  error::Error,
+  path_encoding::encode_path_segment,
+  request::Request,
+  timestamp::Timestamp,
+  token_provider::{Token, TokenFuture, TokenProvider},
+};
+use chrono::{Duration, Utc};
+use octocrate_types::InstallationToken;
+use serde::Deserialize;
+use std::{
+  collections::HashMap,
+  sync::{Arc, Mutex},
+};
+
+const DEFAULT_REFRESH_BEFORE: i64 = 5 * 60;
+
+// Only the id of an installation is needed to create its tokens
+#[derive(Deserialize)]
+struct InstallationId {
+  id: i64,
+}
+
+/// Installation access tokens of a GitHub App, created with the app JWT when they are
+/// first needed and renewed shortly before they expire.
+///
+/// Tokens are cached per installation, clones share the same cache.
+#[derive(Clone)]
+pub struct InstallationAuthorization {
+  app_config: SharedAPIConfig,
+  refresh_before: Duration,
+  tokens: Arc<Mutex<HashMap<i64, Token>>>,
+  // Held while a token is created, so that concurrent requests only create one
+  refreshing: Arc<Mutex<HashMap<i64, Arc<tokio::sync::Mutex<()>>>>>,
+}
+
+impl InstallationAuthorization {
+  pub fn new(app_authorization: AppAuthorization) -> Self {
+    Self::with_config(APIConfig::with_token(app_authorization))
+  }
+
+  /// Create the tokens with the given config, e.g. one with the base URL of a GitHub
+  /// Enterprise Server, or a shared client. Its token must be an [`AppAuthorization`].
+  pub fn with_config(app_config: APIConfig) -> Self {
+    Self {
+      app_config: app_config.shared(),
+      refresh_before: Duration::seconds(DEFAULT_REFRESH_BEFORE),
+      tokens: Arc::new(Mutex::new(HashMap::new())),
+      refreshing: Arc::new(Mutex::new(HashMap::new())),
+    }
+  }
+
+  /// How long before its expiration a token is renewed, 5 minutes by default.
+  pub fn refresh_before(mut self, refresh_before: std::time::Duration) -> Self {
+    self.refresh_before = Duration::from_std(refresh_before).unwrap_or(Duration::zero());
+
+    self
+  }
+
+  /// The token source of an installation, to use with [`APIConfig::with_token`].
+  pub fn installation(&self, installation_id: i64) -> InstallationTokenSource {
+    InstallationTokenSource {
+      authorization: self.clone(),
+      installation_id,
+    }
+  }
+
+  /// A config authenticated as the installation, with every other setting of the app
+  /// config, such as its base URL, client, policies, middlewares and cache.
+  pub fn config(&self, installation_id: i64) -> APIConfig {
+    APIConfig {
+      token: Some(Arc::new(self.installation(installation_id))),
+      ..(*self.app_config).clone()
+    }
+  }
+
+  pub async fn repo_installation(
+    &self,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+  ) -> Result<InstallationTokenSource, Error> {
+    let url = format!(
+      "/repos/{}/{}/installation",
+      encode_path_segment(owner),
+      encode_path_segment(repo)
+    );
+
+    self.find_installation(url).await
+  }
+
+  pub async fn org_installation(
+    &self,
+    org: impl Into<String>,
+  ) -> Result<InstallationTokenSource, Error> {
+    let url = format!("/orgs/{}/installation", encode_path_segment(org));
+
+    self.find_installation(url).await
+  }
+
+  pub async fn user_installation(
+    &self,
+    username: impl Into<String>,
+  ) -> Result<InstallationTokenSource, Error> {
+    let url = format!("/users/{}/installation", encode_path_segment(username));
+
+    self.find_installation(url).await
+  }
+
+  /// The token of the installation, created or renewed if it expires soon.
+  pub async fn token(&self, installation_id: i64) -> Result<Token, Error> {
+    if let Some(token) = self.cached_token(installation_id) {
+      return Ok(token);
+    }
+
+    let lock = self
+      .refreshing
+      .lock()
+      .unwrap()
+      .entry(installation_id)
+      .or_default()
+      .clone();
+    let _guard = lock.lock().await;
+
+    // Another request may have renewed it while this one was waiting
+    if let Some(token) = self.cached_token(installation_id) {
+      return Ok(token);
+    }
+
+    let url = format!("/app/installations/{}/access_tokens", installation_id);
+
+    let token = Request::<(), (), InstallationToken>::builder(&self.app_config)
+      .post(url)
+      .build()
+      .send()
+      .await?;
+
+    let expires_at = token.expires_at.to_date_time().ok_or_else(|| {
+      Error::Error(format!(
+        "Invalid expiration of installation token {:?}",
+        token.expires_at
+      ))
+    })?;
+
+    let token = Token::new(token.token).expires_at(expires_at);
+
+    self
+      .tokens
+      .lock()
+      .unwrap()
+      .insert(installation_id, token.clone());
+
+    Ok(token)
+  }
+
+  fn cached_token(&self, installation_id: i64) -> Option<Token> {
+    let tokens = self.tokens.lock().unwrap();
+
+    tokens
+      .get(&installation_id)
+      .filter(|token| {
+        token
+          .expires_at
+          .is_some_and(|expires_at| expires_at - self.refresh_before > Utc::now())
+      })
+      .cloned()
+  }
+
+  async fn find_installation(&self, url: String) -> Result<InstallationTokenSource, Error> {
+    let installation = Request::<(), (), InstallationId>::builder(&self.app_config)
+      .get(url)
+      .build()
+      .send()
+      .await?;
+
+    Ok(self.installation(installation.id))
+  }
+}
+
+/// The token of one installation of an [`InstallationAuthorization`].
+#[derive(Clone)]
+pub struct InstallationTokenSource {
+  authorization: InstallationAuthorization,
+  installation_id: i64,
+}
+
+impl InstallationTokenSource {
+  pub fn installation_id(&self) -> i64 {
+    self.installation_id
+  }
+
+  pub fn config(&self) -> APIConfig {
+    self.authorization.config(self.installation_id)
+  }
+}
+
+impl TokenProvider for InstallationTokenSource {
+  fn token(&self) -> TokenFuture<'_> {
+    Box::pin(self.authorization.token(self.installation_id))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    middleware::{Middleware, MiddlewareFuture, Next},
+    retry::RetryPolicy,
+    test_server::serve,
+  };
+
+  const PRIVATE_KEY: &str = include_str!("../fixtures/private_key.pem");
+
+  fn authorization(base_url: &str) -> InstallationAuthorization {
+    let app_authorization = AppAuthorization::new("1234".to_string(), PRIVATE_KEY.to_string());
+
+    InstallationAuthorization::with_config(APIConfig {
+      base_url: base_url.to_string(),
+      ..APIConfig::with_token(app_authorization)
+    })
+  }
+
+  fn token_response(token: &str, expires_in: Duration) -> String {
+    format!(
+      r#"{{"token":"{}","expires_at":"{}"}}"#,
+      token,
+      (Utc::now() + expires_in).to_rfc3339()
+    )
+  }
+
+  #[tokio::test]
+  async fn test_cache_and_refresh_tokens() {
+    let first = token_response("first", Duration::hours(1)).leak();
+    let second = token_response("second", Duration::minutes(2)).leak();
+    let third = token_response("third", Duration::hours(1)).leak();
+
+    let (base_url, requests) = serve(vec![
+      ("201 Created", "", first),
+      ("201 Created", "", second),
+      ("201 Created", "", third),
+    ])
+    .await;
+
+    let authorization = authorization(&base_url);
+
+    assert_eq!(authorization.token(1).await.unwrap().value, "first");
+    assert_eq!(authorization.token(1).await.unwrap().value, "first");
+    // Another installation has its own token, which expires soon
+    assert_eq!(authorization.token(2).await.unwrap().value, "second");
+    assert_eq!(authorization.token(2).await.unwrap().value, "third");
+
+    let requests = requests.lock().unwrap();
+
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].starts_with("POST /app/installations/1/access_tokens "));
+    assert!(requests[0].contains("authorization: Bearer ey"));
+    assert!(requests[2].starts_with("POST /app/installations/2/access_tokens "));
+  }
+
+  #[tokio::test]
+  async fn test_repo_installation() {
+    let token = token_response("installation", Duration::hours(1)).leak();
+
+    let (base_url, requests) = serve(vec![
+      ("200 OK", "", r#"{"id":42}"#),
+      ("201 Created", "", token),
+      ("200 OK", "", "{}"),
+    ])
+    .await;
+
+    let authorization = authorization(&base_url);
+
+    let installation = authorization
+      .repo_installation("panghu-huang", "octocrate")
+      .await
+      .unwrap();
+
+    assert_eq!(installation.installation_id(), 42);
+
+    Request::<(), (), serde_json::Value>::builder(&installation.config().shared())
+      .get("/repos/panghu-huang/octocrate")
+      .build()
+      .send()
+      .await
+      .unwrap();
+
+    let requests = requests.lock().unwrap();
+
+    assert!(requests[0].starts_with("GET /repos/panghu-huang/octocrate/installation "));
+    assert!(requests[1].starts_with("POST /app/installations/42/access_tokens "));
+    assert!(requests[2].contains("authorization: Bearer installation"));
+  }
+
+  struct Tag;
+
+  impl Middleware for Tag {
+    fn handle<'a>(&'a self, mut request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+      request
+        .headers_mut()
+        .insert("x-tag", "app".parse().unwrap());
+
+      Box::pin(async move { next.run(request).await })
+    }
+  }
+
+  #[tokio::test]
+  async fn test_config_keeps_app_settings() {
+    let token = token_response("installation", Duration::hours(1)).leak();
+
+    let (base_url, requests) = serve(vec![
+      ("201 Created", "", token),
+      ("502 Bad Gateway", "", r#"{"message":"Server Error"}"#),
+      ("200 OK", "", "{}"),
+    ])
+    .await;
+
+    let app_authorization = AppAuthorization::new("1234".to_string(), PRIVATE_KEY.to_string());
+    let authorization = InstallationAuthorization::with_config(
+      APIConfig {
+        base_url: base_url.clone(),
+        ..APIConfig::with_token(app_authorization)
+      }
+      .retry_policy(RetryPolicy::new().initial_delay(std::time::Duration::from_millis(10)))
+      .middleware(Tag),
+    );
+
+    Request::<(), (), serde_json::Value>::builder(&authorization.config(42).shared())
+      .get("/repos/panghu-huang/octocrate")
+      .build()
+      .send()
+      .await
+      .unwrap();
+
+    let requests = requests.lock().unwrap();
+
+    assert_eq!(requests.len(), 3);
+    assert!(requests[2].contains("authorization: Bearer installation"));
+    assert!(requests[2].contains("x-tag: app"));
+  }
+}
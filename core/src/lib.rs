@@ -1,21 +1,61 @@
 mod api_config;
 mod app_authorization;
+mod cache;
+mod client_config;
+mod client_credentials;
+mod download_request;
+mod download_request_builder;
 mod error;
 mod expirable_token;
+#[cfg(any(feature = "full", feature = "apps"))]
+mod installation_authorization;
+mod media_type;
+mod middleware;
 mod no_content_request;
 mod no_content_request_builder;
+mod oauth;
+mod operation;
+#[cfg(feature = "pagination")]
+mod pagination;
+mod path_encoding;
+mod payload_format;
 mod personal_access_token;
+mod rate_limit;
 mod request;
 mod request_builder;
 mod response;
+mod retry;
+mod send;
+#[cfg(test)]
+mod test_server;
+mod timestamp;
+mod token_provider;
 
 pub use api_config::*;
 pub use app_authorization::*;
+pub use cache::{CacheFuture, CacheStore, CachedResponse, DiskCache, MemoryCache};
+pub use client_config::*;
+pub use client_credentials::*;
+pub use download_request::*;
+pub use download_request_builder::*;
 pub use error::*;
 pub use expirable_token::*;
+#[cfg(any(feature = "full", feature = "apps"))]
+pub use installation_authorization::*;
+pub use media_type::*;
+pub use middleware::*;
 pub use no_content_request::*;
 pub use no_content_request_builder::*;
+pub use oauth::*;
+pub use operation::*;
+pub use path_encoding::*;
+pub use payload_format::*;
 pub use personal_access_token::*;
+pub use rate_limit::{RateLimit, RateLimitPolicy};
 pub use request::*;
 pub use request_builder::*;
+pub use reqwest;
 pub use response::*;
+pub use retry::RetryPolicy;
+pub use timestamp::Timestamp;
+pub use token_provider::*;
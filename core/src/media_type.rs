@@ -0,0 +1,96 @@
+use crate::payload_format::PayloadFormat;
+use std::marker::PhantomData;
+
+/// A custom media type of an operation, sent as the `Accept` header, with the type of
+/// the response it returns.
+///
+/// The media types of each operation are generated as constants, e.g.
+/// `PullsGetMediaType::Diff` returns the diff of a pull request as a `String`, and
+/// `PullsGetMediaType::Html` the pull request with its `body_html`.
+pub struct MediaType<Response> {
+  pub accept: &'static str,
+  pub format: PayloadFormat,
+  _response: PhantomData<fn() -> Response>,
+}
+
+impl<Response> MediaType<Response> {
+  pub const fn new(accept: &'static str, format: PayloadFormat) -> Self {
+    MediaType {
+      accept,
+      format,
+      _response: PhantomData,
+    }
+  }
+}
+
+impl<Response> Clone for MediaType<Response> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<Response> Copy for MediaType<Response> {}
+
+impl<Response> std::fmt::Debug for MediaType<Response> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("MediaType")
+      .field("accept", &self.accept)
+      .field("format", &self.format)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{api_config::APIConfig, request::Request, test_server::serve};
+
+  #[tokio::test]
+  async fn test_media_type() {
+    const DIFF: MediaType<String> =
+      MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+
+    let (base_url, requests) = serve(vec![
+      ("200 OK", "", "diff --git a/README.md b/README.md"),
+      ("200 OK", "", r#"{"id":1}"#),
+    ])
+    .await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    #[derive(serde::Deserialize)]
+    struct PullRequest {
+      id: u64,
+    }
+
+    let diff = Request::<(), (), PullRequest>::builder(&config)
+      .get("/repos/panghu-huang/octocrate/pulls/1")
+      .build()
+      .media_type(DIFF)
+      .send()
+      .await
+      .unwrap();
+
+    assert_eq!(diff, "diff --git a/README.md b/README.md");
+
+    let pull_request = Request::<(), (), PullRequest>::builder(&config)
+      .get("/repos/panghu-huang/octocrate/pulls/1")
+      .build()
+      .send()
+      .await
+      .unwrap();
+
+    assert_eq!(pull_request.id, 1);
+
+    let requests = requests.lock().unwrap();
+    let accept = |request: &String| {
+      request
+        .lines()
+        .filter(|line| line.to_lowercase().starts_with("accept:"))
+        .map(|line| line[7..].trim().to_string())
+        .collect::<Vec<_>>()
+    };
+
+    assert_eq!(accept(&requests[0]), vec!["application/vnd.github.diff"]);
+    assert_eq!(accept(&requests[1]), vec!["application/vnd.github+json"]);
+  }
+}
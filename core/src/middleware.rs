@@ -0,0 +1,74 @@
+use crate::error::{Error, TransportError, TransportErrorKind};
+use std::{future::Future, pin::Pin, sync::Arc};
+
+pub type MiddlewareFuture<'a> =
+  Pin<Box<dyn Future<Output = Result<reqwest::Response, Error>> + Send + 'a>>;
+
+/// A hook around every request sent with an [`APIConfig`](crate::APIConfig), e.g. for
+/// logging, metrics, tracing spans or extra headers.
+///
+/// The middleware receives the outgoing request with the headers and authorization
+/// already set, and calls `next.run(request)` to send it and get the response back.
+/// Retried requests go through the middlewares again on every attempt.
+///
+/// ```rust,ignore
+/// struct RequestId;
+///
+/// impl Middleware for RequestId {
+///   fn handle<'a>(&'a self, mut request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+///     request
+///       .headers_mut()
+///       .insert("x-request-id", uuid::Uuid::new_v4().to_string().parse().unwrap());
+///
+///     Box::pin(async move {
+///       let response = next.run(request).await?;
+///       println!("{} {}", response.status(), response.url());
+///       Ok(response)
+///     })
+///   }
+/// }
+///
+/// let config = APIConfig::with_token(token).middleware(RequestId).shared();
+/// ```
+pub trait Middleware: Send + Sync {
+  fn handle<'a>(&'a self, request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a>;
+}
+
+/// The rest of the middleware chain, ending with the HTTP client.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+  client: &'a reqwest::Client,
+  middlewares: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+  pub(crate) fn new(client: &'a reqwest::Client, middlewares: &'a [Arc<dyn Middleware>]) -> Self {
+    Next {
+      client,
+      middlewares,
+    }
+  }
+
+  pub async fn run(self, request: reqwest::Request) -> Result<reqwest::Response, Error> {
+    match self.middlewares.split_first() {
+      Some((middleware, middlewares)) => {
+        middleware
+          .handle(request, Next::new(self.client, middlewares))
+          .await
+      }
+      None => {
+        let method = request.method().clone();
+        let url = request.url().clone();
+
+        self.client.execute(request).await.map_err(|err| {
+          Error::Transport(Box::new(TransportError {
+            method,
+            url,
+            kind: TransportErrorKind::from(&err),
+            message: err.to_string(),
+          }))
+        })
+      }
+    }
+  }
+}
@@ -1,13 +1,14 @@
 use crate::{
-  api_config::SharedAPIConfig,
-  error::{APIErrorResponse, Error},
-  no_content_request_builder::NoContentRequestBuilder,
+  api_config::SharedAPIConfig, error::Error, no_content_request_builder::NoContentRequestBuilder,
+  retry::RetryPolicy, send::send, token_provider::TokenProvider,
 };
-use std::marker::PhantomData;
+use std::{marker::PhantomData, sync::Arc};
 
 pub struct NoContentRequest<Body, Query> {
   pub(crate) builder: reqwest::RequestBuilder,
   pub(crate) api_config: SharedAPIConfig,
+  pub(crate) retry_policy: Option<RetryPolicy>,
+  pub(crate) token: Option<Arc<dyn TokenProvider>>,
   pub(crate) _body: PhantomData<Body>,
   pub(crate) _query: PhantomData<Query>,
 }
@@ -33,34 +34,40 @@ where
     self
   }
 
-  pub async fn send(self) -> Result<(), Error> {
-    let mut builder = self
+  /// Send raw content as the body with its media type.
+  pub fn raw_body(mut self, body: impl Into<reqwest::Body>, content_type: &str) -> Self {
+    self.builder = self
       .builder
-      .header("User-Agent", "octocrate")
-      .header("Accept", "application/vnd.github+json");
+      .header(reqwest::header::CONTENT_TYPE, content_type)
+      .body(body);
+
+    self
+  }
 
-    if let Some(token) = &self.api_config.token {
-      if let Some(token) = token.get_token() {
-        builder = builder.header("Authorization", format!("Bearer {}", token));
-      }
-    }
+  /// Retry this request with the given policy instead of the one of the config.
+  pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+    self.retry_policy = Some(policy);
 
-    let res = builder.send().await;
-    match res {
-      Ok(res) => {
-        let status = res.status();
-        if !status.is_success() {
-          if let Ok(error_response) = res.json::<APIErrorResponse>().await {
-            return Err(Error::RequestFailed(error_response));
-          }
+    self
+  }
 
-          let err_msg = format!("Request failed with {}", status);
-          return Err(Error::Error(err_msg));
-        }
+  /// Authenticate this request with the given token instead of the one of the config,
+  /// e.g. the [`ClientCredentials`](crate::ClientCredentials) of an OAuth app.
+  pub fn token<T: TokenProvider + 'static>(mut self, token: T) -> Self {
+    self.token = Some(Arc::new(token));
+
+    self
+  }
+
+  pub async fn send(self) -> Result<(), Error> {
+    send(
+      &self.api_config,
+      self.builder,
+      self.token.as_ref(),
+      self.retry_policy.as_ref(),
+    )
+    .await?;
 
-        Ok(())
-      }
-      Err(err) => Err(Error::Error(err.to_string())),
-    }
+    Ok(())
   }
 }
@@ -1,5 +1,6 @@
-use crate::{api_config::SharedAPIConfig, no_content_request::NoContentRequest};
-use reqwest::Client;
+use crate::{
+  api_config::SharedAPIConfig, no_content_request::NoContentRequest, request_builder::join_url,
+};
 use std::marker::PhantomData;
 
 pub struct NoContentRequestBuilder<Body, Query> {
@@ -24,8 +25,8 @@ where
   }
 
   pub fn get(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().get(url);
+    let url = join_url(&self.api_config.base_url, url.into());
+    let builder = self.api_config.client.get(url);
 
     self.builder = Some(builder);
 
@@ -33,8 +34,8 @@ where
   }
 
   pub fn post(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().post(url);
+    let url = join_url(&self.api_config.base_url, url.into());
+    let builder = self.api_config.client.post(url);
 
     self.builder = Some(builder);
 
@@ -42,8 +43,8 @@ where
   }
 
   pub fn put(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().put(url);
+    let url = join_url(&self.api_config.base_url, url.into());
+    let builder = self.api_config.client.put(url);
 
     self.builder = Some(builder);
 
@@ -51,8 +52,8 @@ where
   }
 
   pub fn patch(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().patch(url);
+    let url = join_url(&self.api_config.base_url, url.into());
+    let builder = self.api_config.client.patch(url);
 
     self.builder = Some(builder);
 
@@ -60,8 +61,8 @@ where
   }
 
   pub fn delete(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().delete(url);
+    let url = join_url(&self.api_config.base_url, url.into());
+    let builder = self.api_config.client.delete(url);
 
     self.builder = Some(builder);
 
@@ -72,6 +73,8 @@ where
     NoContentRequest {
       builder: self.builder.expect("RequestBuilder is not configured"),
       api_config: self.api_config.clone(),
+      retry_policy: None,
+      token: None,
       _body: PhantomData,
       _query: PhantomData,
     }
@@ -0,0 +1,631 @@
+use crate::{
+  api_config::{APIConfig, SharedAPIConfig},
+  client_credentials::ClientCredentials,
+  error::{Error, OAuthError},
+  expirable_token::ExpirableToken,
+  media_type::MediaType,
+  payload_format::PayloadFormat,
+  request::Request,
+  token_provider::{Token, TokenFuture, TokenProvider},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+const DEFAULT_WEB_URL: &str = "https://github.com";
+const DEFAULT_REFRESH_BEFORE: i64 = 5 * 60;
+const DEVICE_CODE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// An OAuth app or the OAuth credentials of a GitHub App, signing users in with the web
+/// flow or the device flow.
+///
+/// The endpoints of the flows are on the web server, `https://github.com`, or the host
+/// of a GitHub Enterprise Server base URL ending with `/api/v3`.
+#[derive(Clone)]
+pub struct OAuthApp {
+  client_id: String,
+  client_secret: Option<String>,
+  web_url: String,
+  config: SharedAPIConfig,
+}
+
+impl OAuthApp {
+  pub fn new(client_id: impl Into<String>) -> Self {
+    Self::with_config(client_id, APIConfig::default())
+  }
+
+  /// Send the requests of the flows with the client, user agent, middlewares and retry
+  /// policy of the given config. Its token is not sent.
+  pub fn with_config(client_id: impl Into<String>, config: APIConfig) -> Self {
+    Self {
+      client_id: client_id.into(),
+      client_secret: None,
+      web_url: web_url_for(&config.base_url),
+      config: APIConfig {
+        token: None,
+        cache: None,
+        ..config
+      }
+      .shared(),
+    }
+  }
+
+  /// The secret of the app, required to exchange codes and refresh tokens, but not for
+  /// the device flow.
+  pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+    self.client_secret = Some(client_secret.into());
+
+    self
+  }
+
+  /// The web server of the endpoints, instead of the one that goes with the base URL
+  /// of the config.
+  pub fn web_url(mut self, web_url: impl Into<String>) -> Self {
+    self.web_url = web_url.into().trim_end_matches('/').to_string();
+
+    self
+  }
+
+  pub fn client_id(&self) -> &str {
+    &self.client_id
+  }
+
+  /// The credentials of the app for the endpoints that manage its tokens, if the
+  /// secret is set.
+  pub fn client_credentials(&self) -> Option<ClientCredentials> {
+    self
+      .client_secret
+      .as_ref()
+      .map(|client_secret| ClientCredentials::new(&self.client_id, client_secret))
+  }
+
+  /// The URL to redirect the user to, the first step of the web flow. GitHub then
+  /// redirects the user back to the `redirect_uri` with a `code` and the `state`.
+  pub fn authorize_url(&self, options: &AuthorizeOptions) -> String {
+    let mut params = vec![("client_id", self.client_id.clone())];
+
+    if let Some(redirect_uri) = &options.redirect_uri {
+      params.push(("redirect_uri", redirect_uri.clone()));
+    }
+
+    if !options.scopes.is_empty() {
+      params.push(("scope", options.scopes.join(" ")));
+    }
+
+    if let Some(state) = &options.state {
+      params.push(("state", state.clone()));
+    }
+
+    if let Some(login) = &options.login {
+      params.push(("login", login.clone()));
+    }
+
+    if let Some(allow_signup) = options.allow_signup {
+      params.push(("allow_signup", allow_signup.to_string()));
+    }
+
+    let url = format!("{}/login/oauth/authorize", self.web_url);
+
+    match reqwest::Url::parse_with_params(&url, &params) {
+      Ok(url) => url.to_string(),
+      Err(_) => url,
+    }
+  }
+
+  /// Exchange the `code` the user was redirected back with for a token, the last step of
+  /// the web flow. The `redirect_uri` must be the one of the authorize URL, if any.
+  pub async fn exchange_code(
+    &self,
+    code: impl Into<String>,
+    redirect_uri: Option<&str>,
+  ) -> Result<OAuthToken, Error> {
+    let request = TokenRequest {
+      client_id: self.client_id.clone(),
+      client_secret: Some(self.require_client_secret()?),
+      code: Some(code.into()),
+      redirect_uri: redirect_uri.map(str::to_string),
+      ..Default::default()
+    };
+
+    self.request_token(&request).await?.into_token()
+  }
+
+  /// Renew an expiring user access token with its refresh token. The refresh token can
+  /// only be used once, the returned token has a new one.
+  pub async fn refresh(&self, refresh_token: impl Into<String>) -> Result<OAuthToken, Error> {
+    let request = TokenRequest {
+      client_id: self.client_id.clone(),
+      client_secret: Some(self.require_client_secret()?),
+      grant_type: Some("refresh_token".to_string()),
+      refresh_token: Some(refresh_token.into()),
+      ..Default::default()
+    };
+
+    self.request_token(&request).await?.into_token()
+  }
+
+  /// Start the device flow. The user enters the `user_code` at the `verification_uri`,
+  /// while [`OAuthApp::poll_device_code`] waits for the token.
+  pub async fn request_device_code(&self, scopes: &[&str]) -> Result<DeviceCode, Error> {
+    let request = DeviceCodeRequest {
+      client_id: self.client_id.clone(),
+      scope: (!scopes.is_empty()).then(|| scopes.join(" ")),
+    };
+
+    let response = Request::<DeviceCodeRequest, (), DeviceCodeResponse>::builder(&self.config)
+      .post(format!("{}/login/device/code", self.web_url))
+      .build()
+      .body(&request)
+      // The OAuth endpoints answer with a form encoded body unless JSON is asked for
+      .media_type(MediaType::new("application/json", PayloadFormat::Json))
+      .send()
+      .await?;
+
+    match response {
+      DeviceCodeResponse::DeviceCode(device_code) => Ok(device_code),
+      DeviceCodeResponse::Error(error) => Err(Error::OAuth(Box::new(error))),
+    }
+  }
+
+  /// Poll for the token of a device code at the interval asked by GitHub, until the user
+  /// authorizes the app. Fails with an `access_denied` or `expired_token`
+  /// [`Error::OAuth`] if the user cancels or the code expires first.
+  pub async fn poll_device_code(&self, device_code: &DeviceCode) -> Result<OAuthToken, Error> {
+    let expires_at = Utc::now() + Duration::seconds(device_code.expires_in as i64);
+    let mut interval = device_code.interval;
+
+    let request = TokenRequest {
+      client_id: self.client_id.clone(),
+      device_code: Some(device_code.device_code.clone()),
+      grant_type: Some(DEVICE_CODE_GRANT_TYPE.to_string()),
+      ..Default::default()
+    };
+
+    loop {
+      tokio::time::sleep(std::time::Duration::from_secs(interval)).await;
+
+      match self.request_token(&request).await? {
+        TokenResponse::Token(token) => return Ok(token.into()),
+        TokenResponse::Error {
+          error,
+          interval: next_interval,
+        } => match error.error.as_str() {
+          "authorization_pending" => {}
+          // Every poll that is too early adds 5 seconds to the interval
+          "slow_down" => interval = next_interval.unwrap_or(interval + 5),
+          _ => return Err(Error::OAuth(Box::new(error))),
+        },
+      }
+
+      if Utc::now() >= expires_at {
+        return Err(Error::OAuth(Box::new(OAuthError {
+          error: "expired_token".to_string(),
+          error_description: Some("The device code has expired".to_string()),
+          error_uri: None,
+        })));
+      }
+    }
+  }
+
+  /// A token source that renews the token with its refresh token shortly before it
+  /// expires, to use with [`APIConfig::with_token`].
+  pub fn refreshing_token(&self, token: OAuthToken) -> RefreshingToken {
+    RefreshingToken {
+      app: self.clone(),
+      token: Arc::new(tokio::sync::Mutex::new(token)),
+      refresh_before: Duration::seconds(DEFAULT_REFRESH_BEFORE),
+      on_refresh: None,
+    }
+  }
+
+  fn require_client_secret(&self) -> Result<String, Error> {
+    self
+      .client_secret
+      .clone()
+      .ok_or_else(|| Error::Error("The client secret of the OAuth app is required".to_string()))
+  }
+
+  async fn request_token(&self, request: &TokenRequest) -> Result<TokenResponse, Error> {
+    Request::<TokenRequest, (), TokenResponse>::builder(&self.config)
+      .post(format!("{}/login/oauth/access_token", self.web_url))
+      .build()
+      .body(request)
+      .media_type(MediaType::new("application/json", PayloadFormat::Json))
+      .send()
+      .await
+  }
+}
+
+/// The parameters of the authorize URL of the web flow.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizeOptions {
+  /// Where GitHub redirects the user back to, the callback URL of the app by default.
+  pub redirect_uri: Option<String>,
+  /// Ignored by GitHub Apps, whose permissions are set on the app.
+  pub scopes: Vec<String>,
+  /// An unguessable value, sent back with the code to protect against forgery.
+  pub state: Option<String>,
+  /// The account suggested to sign in with.
+  pub login: Option<String>,
+  pub allow_signup: Option<bool>,
+}
+
+/// The codes of the device flow.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DeviceCode {
+  pub device_code: String,
+  /// The code the user enters at the `verification_uri`.
+  pub user_code: String,
+  pub verification_uri: String,
+  /// In seconds.
+  pub expires_in: u64,
+  /// The minimum number of seconds between two polls.
+  pub interval: u64,
+}
+
+/// A user access token of the web or device flow.
+///
+/// Tokens of GitHub Apps with expiring user tokens come with a refresh token, and have
+/// to be renewed, e.g. by a [`RefreshingToken`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OAuthToken {
+  pub access_token: String,
+  pub token_type: String,
+  pub scopes: Vec<String>,
+  pub expires_at: Option<DateTime<Utc>>,
+  pub refresh_token: Option<String>,
+  pub refresh_token_expires_at: Option<DateTime<Utc>>,
+}
+
+impl OAuthToken {
+  pub fn is_expired(&self) -> bool {
+    self
+      .expires_at
+      .is_some_and(|expires_at| expires_at <= Utc::now())
+  }
+
+  fn can_refresh(&self) -> bool {
+    self.refresh_token.is_some()
+      && self
+        .refresh_token_expires_at
+        .map_or(true, |expires_at| expires_at > Utc::now())
+  }
+}
+
+impl ExpirableToken for OAuthToken {
+  fn get_token(&self) -> Option<String> {
+    if self.is_expired() {
+      None
+    } else {
+      Some(self.access_token.clone())
+    }
+  }
+}
+
+type RefreshHook = Arc<dyn Fn(&OAuthToken) + Send + Sync>;
+
+/// A user access token that is renewed with its refresh token shortly before it
+/// expires. Clones share the same token.
+#[derive(Clone)]
+pub struct RefreshingToken {
+  app: OAuthApp,
+  // Held while the token is renewed, so that concurrent requests only renew it once
+  token: Arc<tokio::sync::Mutex<OAuthToken>>,
+  refresh_before: Duration,
+  on_refresh: Option<RefreshHook>,
+}
+
+impl RefreshingToken {
+  /// How long before its expiration the token is renewed, 5 minutes by default.
+  pub fn refresh_before(mut self, refresh_before: std::time::Duration) -> Self {
+    self.refresh_before = Duration::from_std(refresh_before).unwrap_or(Duration::zero());
+
+    self
+  }
+
+  /// Called with every renewed token, e.g. to store its new refresh token, as the
+  /// previous one can't be used again.
+  pub fn on_refresh<F>(mut self, on_refresh: F) -> Self
+  where
+    F: Fn(&OAuthToken) + Send + Sync + 'static,
+  {
+    self.on_refresh = Some(Arc::new(on_refresh));
+
+    self
+  }
+
+  /// The current token, as last renewed.
+  pub async fn current(&self) -> OAuthToken {
+    self.token.lock().await.clone()
+  }
+
+  /// The access token, renewed first if it expires soon.
+  pub async fn access_token(&self) -> Result<Token, Error> {
+    let mut token = self.token.lock().await;
+
+    let expires_soon = token
+      .expires_at
+      .is_some_and(|expires_at| expires_at - self.refresh_before <= Utc::now());
+
+    if expires_soon && token.can_refresh() {
+      let refresh_token = token.refresh_token.clone().unwrap_or_default();
+
+      *token = self.app.refresh(refresh_token).await?;
+
+      if let Some(on_refresh) = &self.on_refresh {
+        on_refresh(&token);
+      }
+    }
+
+    if token.is_expired() {
+      return Err(Error::Error(
+        "OAuth token is expired and can't be refreshed".to_string(),
+      ));
+    }
+
+    let mut access_token = Token::new(token.access_token.clone());
+
+    if let Some(expires_at) = token.expires_at {
+      access_token = access_token.expires_at(expires_at);
+    }
+
+    Ok(access_token)
+  }
+}
+
+impl TokenProvider for RefreshingToken {
+  fn token(&self) -> TokenFuture<'_> {
+    Box::pin(self.access_token())
+  }
+}
+
+/// The web server that goes with an API server: `https://HOST` for the
+/// `https://HOST/api/v3` of GitHub Enterprise Server, and the API server itself for
+/// anything else, such as a proxy or a mock.
+fn web_url_for(base_url: &str) -> String {
+  let base_url = base_url.trim_end_matches('/');
+
+  if base_url == crate::api_config::DEFAULT_BASE_URL {
+    return DEFAULT_WEB_URL.to_string();
+  }
+
+  base_url
+    .strip_suffix("/api/v3")
+    .unwrap_or(base_url)
+    .to_string()
+}
+
+#[derive(Serialize)]
+struct DeviceCodeRequest {
+  client_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  scope: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DeviceCodeResponse {
+  DeviceCode(DeviceCode),
+  Error(OAuthError),
+}
+
+#[derive(Serialize, Default)]
+struct TokenRequest {
+  client_id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  client_secret: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  redirect_uri: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  device_code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  grant_type: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  refresh_token: Option<String>,
+}
+
+// Failures are sent with a `200 OK` status
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TokenResponse {
+  Token(RawToken),
+  Error {
+    #[serde(flatten)]
+    error: OAuthError,
+    interval: Option<u64>,
+  },
+}
+
+impl TokenResponse {
+  fn into_token(self) -> Result<OAuthToken, Error> {
+    match self {
+      TokenResponse::Token(token) => Ok(token.into()),
+      TokenResponse::Error { error, .. } => Err(Error::OAuth(Box::new(error))),
+    }
+  }
+}
+
+#[derive(Deserialize)]
+struct RawToken {
+  access_token: String,
+  token_type: String,
+  #[serde(default)]
+  scope: String,
+  expires_in: Option<i64>,
+  refresh_token: Option<String>,
+  refresh_token_expires_in: Option<i64>,
+}
+
+impl From<RawToken> for OAuthToken {
+  fn from(token: RawToken) -> Self {
+    let now = Utc::now();
+
+    OAuthToken {
+      access_token: token.access_token,
+      token_type: token.token_type,
+      scopes: token
+        .scope
+        .split(',')
+        .filter(|scope| !scope.is_empty())
+        .map(str::to_string)
+        .collect(),
+      expires_at: token
+        .expires_in
+        .map(|expires_in| now + Duration::seconds(expires_in)),
+      refresh_token: token.refresh_token,
+      refresh_token_expires_at: token
+        .refresh_token_expires_in
+        .map(|expires_in| now + Duration::seconds(expires_in)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_server::serve;
+  use std::sync::Mutex;
+
+  fn app(base_url: &str) -> OAuthApp {
+    OAuthApp::with_config("Iv1.client", APIConfig::with_base_url(base_url)).client_secret("secret")
+  }
+
+  #[test]
+  fn test_authorize_url() {
+    let url = OAuthApp::new("Iv1.client").authorize_url(&AuthorizeOptions {
+      redirect_uri: Some("https://example.com/callback".to_string()),
+      scopes: vec!["repo".to_string(), "read:org".to_string()],
+      state: Some("a b".to_string()),
+      ..Default::default()
+    });
+
+    assert_eq!(
+      url,
+      "https://github.com/login/oauth/authorize?client_id=Iv1.client&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&scope=repo+read%3Aorg&state=a+b"
+    );
+
+    let app = OAuthApp::with_config(
+      "Iv1.client",
+      APIConfig::with_base_url("https://github.example.com/api/v3"),
+    );
+
+    assert!(app
+      .authorize_url(&AuthorizeOptions::default())
+      .starts_with("https://github.example.com/login/oauth/authorize?"));
+  }
+
+  #[tokio::test]
+  async fn test_device_flow() {
+    let (base_url, requests) = serve(vec![
+      (
+        "200 OK",
+        "",
+        r#"{"device_code":"3584d83","user_code":"WDJB-MJHT","verification_uri":"https://github.com/login/device","expires_in":900,"interval":0}"#,
+      ),
+      ("200 OK", "", r#"{"error":"authorization_pending"}"#),
+      ("200 OK", "", r#"{"error":"slow_down","interval":0}"#),
+      (
+        "200 OK",
+        "",
+        r#"{"access_token":"gho_token","token_type":"bearer","scope":"repo,read:org"}"#,
+      ),
+    ])
+    .await;
+
+    let app = OAuthApp::with_config("Iv1.client", APIConfig::with_base_url(&base_url));
+
+    let device_code = app
+      .request_device_code(&["repo", "read:org"])
+      .await
+      .unwrap();
+
+    assert_eq!(device_code.user_code, "WDJB-MJHT");
+
+    let token = app.poll_device_code(&device_code).await.unwrap();
+
+    assert_eq!(token.access_token, "gho_token");
+    assert_eq!(token.scopes, vec!["repo", "read:org"]);
+    assert!(token.expires_at.is_none());
+
+    let requests = requests.lock().unwrap();
+
+    assert_eq!(requests.len(), 4);
+    assert!(requests[0].starts_with("POST /login/device/code "));
+    assert!(requests[0].contains(r#"{"client_id":"Iv1.client","scope":"repo read:org"}"#));
+    assert!(requests[1].starts_with("POST /login/oauth/access_token "));
+    assert!(requests[1].contains("accept: application/json"));
+    assert!(requests[3].contains(DEVICE_CODE_GRANT_TYPE));
+    assert!(!requests[3].contains("authorization:"));
+  }
+
+  #[tokio::test]
+  async fn test_device_flow_denied() {
+    let (base_url, _) = serve(vec![(
+      "200 OK",
+      "",
+      r#"{"error":"access_denied","error_description":"The authorization request was denied."}"#,
+    )])
+    .await;
+
+    let device_code = DeviceCode {
+      device_code: "3584d83".to_string(),
+      user_code: "WDJB-MJHT".to_string(),
+      verification_uri: "https://github.com/login/device".to_string(),
+      expires_in: 900,
+      interval: 0,
+    };
+
+    let result = app(&base_url).poll_device_code(&device_code).await;
+
+    assert!(matches!(result, Err(Error::OAuth(error)) if error.error == "access_denied"));
+  }
+
+  #[tokio::test]
+  async fn test_exchange_code_and_refresh() {
+    let (base_url, requests) = serve(vec![
+      (
+        "200 OK",
+        "",
+        r#"{"access_token":"ghu_first","expires_in":60,"refresh_token":"ghr_first","refresh_token_expires_in":15897600,"scope":"","token_type":"bearer"}"#,
+      ),
+      (
+        "200 OK",
+        "",
+        r#"{"access_token":"ghu_second","expires_in":28800,"refresh_token":"ghr_second","refresh_token_expires_in":15897600,"scope":"","token_type":"bearer"}"#,
+      ),
+    ])
+    .await;
+
+    let app = app(&base_url);
+
+    let token = app
+      .exchange_code("code", Some("https://example.com/callback"))
+      .await
+      .unwrap();
+
+    assert_eq!(token.refresh_token.as_deref(), Some("ghr_first"));
+
+    let refreshed = Arc::new(Mutex::new(Vec::new()));
+    let log = refreshed.clone();
+
+    // The first token expires within 5 minutes, and is renewed before it is used
+    let token = app.refreshing_token(token).on_refresh(move |token| {
+      log
+        .lock()
+        .unwrap()
+        .push(token.refresh_token.clone().unwrap())
+    });
+
+    assert_eq!(token.token().await.unwrap().value, "ghu_second");
+    assert_eq!(token.token().await.unwrap().value, "ghu_second");
+    assert_eq!(*refreshed.lock().unwrap(), vec!["ghr_second"]);
+
+    let requests = requests.lock().unwrap();
+
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].contains(r#""client_secret":"secret","code":"code""#));
+    assert!(requests[1].contains(r#""grant_type":"refresh_token","refresh_token":"ghr_first""#));
+  }
+}
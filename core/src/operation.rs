@@ -0,0 +1,9 @@
+/// An endpoint of the REST API, as generated from the OpenAPI description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Operation {
+  /// The API module and function that send the request, e.g. `pulls.get`.
+  pub id: &'static str,
+  pub method: &'static str,
+  /// The path template, e.g. `/repos/{owner}/{repo}/pulls/{pull_number}`.
+  pub path: &'static str,
+}
@@ -0,0 +1,108 @@
+use crate::{error::Error, request::Request};
+use futures_util::{stream, Stream, StreamExt, TryStreamExt};
+use octocrate_types::PaginatedItems;
+use reqwest::header::HeaderMap;
+use std::marker::PhantomData;
+
+impl<Body, Query, ResponseData> Request<Body, Query, ResponseData>
+where
+  Body: serde::Serialize,
+  Query: serde::Serialize,
+  ResponseData: serde::de::DeserializeOwned + PaginatedItems,
+{
+  /// Send the request and yield the items of every page one by one, following the
+  /// `next` URL of the `Link` header until the last page has been read.
+  ///
+  /// GitHub keeps the `per_page` of the first request in the `next` URL, so the page
+  /// size set on the query is respected for every page. Responses that wrap the items
+  /// in an object, such as the search results, yield the wrapped items.
+  pub fn into_stream(self) -> impl Stream<Item = Result<ResponseData::Item, Error>> {
+    stream::try_unfold(Some(self), |request| async move {
+      let Some(request) = request else {
+        return Ok(None);
+      };
+
+      let api_config = request.api_config.clone();
+      let retry_policy = request.retry_policy.clone();
+      let token = request.token.clone();
+      let response_format = request.response_format;
+      let media_type = request.media_type;
+      let response = request.send_with_response().await?;
+
+      let next_request = next_page_url(&response.headers).map(|url| Request {
+        builder: api_config.client.get(url),
+        api_config,
+        retry_policy,
+        token,
+        response_format,
+        media_type,
+        _body: PhantomData,
+        _query: PhantomData,
+        _response: PhantomData,
+      });
+
+      Ok(Some((response.data, next_request)))
+    })
+    .map_ok(|data| stream::iter(data.into_items().into_iter().map(Ok)))
+    .try_flatten()
+  }
+
+  /// Collect the items of every page, stopping as soon as `limit` items have been read.
+  pub async fn collect_all(self, limit: Option<usize>) -> Result<Vec<ResponseData::Item>, Error> {
+    self
+      .into_stream()
+      .take(limit.unwrap_or(usize::MAX))
+      .try_collect()
+      .await
+  }
+}
+
+fn next_page_url(headers: &HeaderMap) -> Option<String> {
+  let link = headers.get("link")?.to_str().ok()?;
+
+  link.split(',').find_map(|section| {
+    let (url, rel) = section.trim().split_once(';')?;
+
+    if rel.trim() == r#"rel="next""# {
+      Some(
+        url
+          .trim_start_matches('<')
+          .trim_end_matches('>')
+          .to_string(),
+      )
+    } else {
+      None
+    }
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_next_page_url() {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(
+      "link",
+      r#"<https://api.github.com/repositories/1300192/issues?per_page=10&page=2>; rel="prev", <https://api.github.com/repositories/1300192/issues?per_page=10&page=4>; rel="next", <https://api.github.com/repositories/1300192/issues?per_page=10&page=515>; rel="last""#
+        .parse()
+        .unwrap(),
+    );
+
+    assert_eq!(
+      next_page_url(&headers),
+      Some("https://api.github.com/repositories/1300192/issues?per_page=10&page=4".to_string())
+    );
+
+    headers.insert(
+      "link",
+      r#"<https://api.github.com/repositories/1300192/issues?page=1>; rel="first""#
+        .parse()
+        .unwrap(),
+    );
+
+    assert_eq!(next_page_url(&headers), None);
+  }
+}
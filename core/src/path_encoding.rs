@@ -0,0 +1,96 @@
+/// Percent-encode a path parameter, so that it is a single segment of the URL, e.g.
+/// `good first issue` becomes `good%20first%20issue` and `feature/#1` becomes
+/// `feature%2F%231`.
+///
+/// Every character except the unreserved ones, `A-Z a-z 0-9 - . _ ~`, is encoded.
+pub fn encode_path_segment(value: impl Into<String>) -> String {
+  encode(value.into(), false)
+}
+
+/// Percent-encode a path parameter that spans several segments, such as the `path` of
+/// repository contents or a `ref` like `heads/feature/a`, keeping its `/` separators.
+pub fn encode_path(value: impl Into<String>) -> String {
+  encode(value.into(), true)
+}
+
+fn encode(value: String, keep_slashes: bool) -> String {
+  let is_kept = |byte: u8| {
+    byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) || (keep_slashes && byte == b'/')
+  };
+
+  if value.bytes().all(is_kept) {
+    return value;
+  }
+
+  let mut encoded = String::with_capacity(value.len() * 3);
+
+  for byte in value.bytes() {
+    if is_kept(byte) {
+      encoded.push(byte as char);
+    } else {
+      encoded.push_str(&format!("%{:02X}", byte));
+    }
+  }
+
+  encoded
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{api_config::APIConfig, no_content_request::NoContentRequest, test_server::serve};
+
+  #[test]
+  fn test_encode_path_segment() {
+    assert_eq!(encode_path_segment("octocrate"), "octocrate");
+    assert_eq!(
+      encode_path_segment("good first issue"),
+      "good%20first%20issue"
+    );
+    assert_eq!(encode_path_segment("feature/#1?"), "feature%2F%231%3F");
+    assert_eq!(encode_path_segment("ß"), "%C3%9F");
+  }
+
+  #[test]
+  fn test_encode_path() {
+    assert_eq!(encode_path("heads/feature/a"), "heads/feature/a");
+    assert_eq!(
+      encode_path("docs/getting started#1.md"),
+      "docs/getting%20started%231.md"
+    );
+  }
+
+  #[tokio::test]
+  async fn test_encoded_path_reaches_server() {
+    let (base_url, requests) =
+      serve(vec![("204 No Content", "", ""), ("204 No Content", "", "")]).await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let urls = [
+      format!(
+        "/repos/panghu-huang/octocrate/branches/{}",
+        encode_path("feature/#1")
+      ),
+      format!(
+        "/repos/panghu-huang/octocrate/releases/tags/{}",
+        encode_path_segment("v1.0 beta?")
+      ),
+    ];
+
+    for url in urls {
+      NoContentRequest::<(), ()>::builder(&config)
+        .get(url)
+        .build()
+        .send()
+        .await
+        .unwrap();
+    }
+
+    let requests = requests.lock().unwrap();
+
+    assert!(requests[0].starts_with("GET /repos/panghu-huang/octocrate/branches/feature/%231 "));
+    assert!(
+      requests[1].starts_with("GET /repos/panghu-huang/octocrate/releases/tags/v1.0%20beta%3F ")
+    );
+  }
+}
@@ -0,0 +1,91 @@
+use serde::de::{
+  value::{Error as ValueError, SeqDeserializer},
+  DeserializeOwned, IntoDeserializer,
+};
+
+/// How the body of a response is decoded, picked by codegen from the media type of the
+/// operation. Text and binary request bodies are sent with `raw_body` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PayloadFormat {
+  /// `application/json` and the `+json` media types of GitHub.
+  #[default]
+  Json,
+  /// Plain text, HTML or Markdown, read into a `String`.
+  Text,
+  /// Binary content such as archives or release assets, read into a `Vec<u8>`.
+  Bytes,
+}
+
+impl PayloadFormat {
+  /// The `Content-Type` of a raw request body in this format.
+  pub fn content_type(&self) -> &'static str {
+    match self {
+      PayloadFormat::Json => "application/json",
+      PayloadFormat::Text => "text/plain; charset=utf-8",
+      PayloadFormat::Bytes => "application/octet-stream",
+    }
+  }
+
+  /// Read the body of a response into the response type of the operation.
+  pub(crate) fn decode<T: DeserializeOwned>(&self, body: &[u8]) -> Result<T, String> {
+    match self {
+      PayloadFormat::Json => serde_json::from_slice(body).map_err(|err| err.to_string()),
+      PayloadFormat::Text => {
+        let text = String::from_utf8_lossy(body).into_owned();
+
+        T::deserialize(IntoDeserializer::<ValueError>::into_deserializer(text))
+          .map_err(|err| err.to_string())
+      }
+      PayloadFormat::Bytes => {
+        T::deserialize(SeqDeserializer::<_, ValueError>::new(body.iter().copied()))
+          .map_err(|err| err.to_string())
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{api_config::APIConfig, request::Request, test_server::serve};
+
+  #[test]
+  fn test_decode() {
+    let zen: String = PayloadFormat::Text.decode(b"Design for failure.").unwrap();
+
+    assert_eq!(zen, "Design for failure.");
+
+    let archive: Vec<u8> = PayloadFormat::Bytes.decode(&[0x1f, 0x8b, 0x08]).unwrap();
+
+    assert_eq!(archive, vec![0x1f, 0x8b, 0x08]);
+
+    let versions: Vec<String> = PayloadFormat::Json.decode(br#"["2022-11-28"]"#).unwrap();
+
+    assert_eq!(versions, vec!["2022-11-28"]);
+    assert!(PayloadFormat::Json
+      .decode::<String>(b"Design for failure.")
+      .is_err());
+  }
+
+  #[tokio::test]
+  async fn test_text_payloads() {
+    let (base_url, requests) = serve(vec![("200 OK", "", "<p><strong>Hello</strong></p>")]).await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let html = Request::<(), (), String>::builder(&config)
+      .post("/markdown/raw")
+      .response_format(PayloadFormat::Text)
+      .build()
+      .raw_body("**Hello**", PayloadFormat::Text.content_type())
+      .send()
+      .await
+      .unwrap();
+
+    assert_eq!(html, "<p><strong>Hello</strong></p>");
+
+    let request = requests.lock().unwrap()[0].to_lowercase();
+
+    assert!(request.contains("content-type: text/plain"));
+    assert!(request.ends_with("\r\n\r\n**hello**"));
+  }
+}
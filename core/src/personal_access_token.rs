@@ -1,12 +1,23 @@
-use crate::expirable_token::ExpirableToken;
+use crate::{expirable_token::ExpirableToken, token_provider::AuthScheme};
 
 pub struct PersonalAccessToken {
   token: String,
+  scheme: AuthScheme,
 }
 
 impl PersonalAccessToken {
   pub fn new(token: String) -> Self {
-    Self { token }
+    Self {
+      token,
+      scheme: AuthScheme::Bearer,
+    }
+  }
+
+  /// Send the token with another scheme, e.g. the legacy `token` scheme.
+  pub fn scheme(mut self, scheme: AuthScheme) -> Self {
+    self.scheme = scheme;
+
+    self
   }
 }
 
@@ -14,4 +25,8 @@ impl ExpirableToken for PersonalAccessToken {
   fn get_token(&self) -> Option<String> {
     Some(self.token.clone())
   }
+
+  fn auth_scheme(&self) -> AuthScheme {
+    self.scheme
+  }
 }
@@ -0,0 +1,240 @@
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest::{header::HeaderMap, StatusCode};
+use std::time::Duration;
+
+/// What to do when GitHub answers with a primary or secondary rate limit.
+#[derive(Debug, Clone, Default)]
+pub enum RateLimitPolicy {
+  /// Return [`Error::RateLimited`](crate::Error::RateLimited) straight away.
+  #[default]
+  FailFast,
+  /// Sleep until the limit resets and send the request again, as long as the total
+  /// time spent waiting stays within `max_wait`.
+  WaitUntilReset { max_wait: Duration },
+  /// Send the request again after the `retry-after` delay, or after an exponential
+  /// backoff starting at `initial_delay` when GitHub doesn't give one.
+  RetryWithBackoff {
+    max_retries: u32,
+    initial_delay: Duration,
+  },
+}
+
+/// The rate limit budget reported by the `x-ratelimit-*` headers of a response.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+  pub limit: u64,
+  pub remaining: u64,
+  pub used: u64,
+  pub reset_at: DateTime<Utc>,
+  /// The rate limit resource the request counted against, e.g. `core` or `search`.
+  pub resource: Option<String>,
+}
+
+impl RateLimit {
+  pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+    Some(RateLimit {
+      limit: header_value(headers, "x-ratelimit-limit")?,
+      remaining: header_value(headers, "x-ratelimit-remaining")?,
+      used: header_value(headers, "x-ratelimit-used").unwrap_or_default(),
+      reset_at: Utc
+        .timestamp_opt(header_value(headers, "x-ratelimit-reset")?, 0)
+        .single()?,
+      resource: headers
+        .get("x-ratelimit-resource")
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string()),
+    })
+  }
+}
+
+/// The details of a response rejected because of a rate limit.
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimited {
+  pub reset_at: Option<DateTime<Utc>>,
+  pub retry_after: Option<Duration>,
+}
+
+impl RateLimited {
+  /// GitHub answers with 429, or with 403 and either an exhausted budget, a `retry-after`
+  /// header or a message mentioning the secondary rate limit.
+  pub fn detect(status: StatusCode, headers: &HeaderMap, body: &str) -> Option<Self> {
+    if status != StatusCode::TOO_MANY_REQUESTS && status != StatusCode::FORBIDDEN {
+      return None;
+    }
+
+    let rate_limit = RateLimit::from_headers(headers);
+    let retry_after = header_value(headers, "retry-after").map(Duration::from_secs);
+
+    let is_rate_limited = status == StatusCode::TOO_MANY_REQUESTS
+      || retry_after.is_some()
+      || rate_limit
+        .as_ref()
+        .is_some_and(|limit| limit.remaining == 0)
+      || body.to_lowercase().contains("rate limit");
+
+    if !is_rate_limited {
+      return None;
+    }
+
+    Some(RateLimited {
+      reset_at: rate_limit.map(|limit| limit.reset_at),
+      retry_after,
+    })
+  }
+
+  /// How long to wait before sending the request again, `None` to give up.
+  pub fn delay(
+    &self,
+    policy: &RateLimitPolicy,
+    attempt: u32,
+    waited: Duration,
+  ) -> Option<Duration> {
+    match policy {
+      RateLimitPolicy::FailFast => None,
+      RateLimitPolicy::WaitUntilReset { max_wait } => {
+        let delay = self.retry_after.or_else(|| {
+          let reset_at = self.reset_at?;
+
+          // A second of leeway, the reset time is truncated to whole seconds
+          Some((reset_at - Utc::now()).to_std().unwrap_or_default() + Duration::from_secs(1))
+        })?;
+
+        (waited + delay <= *max_wait).then_some(delay)
+      }
+      RateLimitPolicy::RetryWithBackoff {
+        max_retries,
+        initial_delay,
+      } => {
+        if attempt >= *max_retries {
+          return None;
+        }
+
+        Some(
+          self
+            .retry_after
+            .unwrap_or_else(|| initial_delay.saturating_mul(2u32.saturating_pow(attempt))),
+        )
+      }
+    }
+  }
+}
+
+fn header_value<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+  headers.get(name)?.to_str().ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn headers(values: &[(&'static str, &str)]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    for (name, value) in values {
+      headers.insert(*name, value.parse().unwrap());
+    }
+
+    headers
+  }
+
+  #[test]
+  fn test_rate_limit_from_headers() {
+    let headers = headers(&[
+      ("x-ratelimit-limit", "5000"),
+      ("x-ratelimit-remaining", "4987"),
+      ("x-ratelimit-used", "13"),
+      ("x-ratelimit-reset", "1700000000"),
+      ("x-ratelimit-resource", "core"),
+    ]);
+
+    assert_eq!(
+      RateLimit::from_headers(&headers),
+      Some(RateLimit {
+        limit: 5000,
+        remaining: 4987,
+        used: 13,
+        reset_at: Utc.timestamp_opt(1700000000, 0).unwrap(),
+        resource: Some("core".to_string()),
+      })
+    );
+  }
+
+  #[test]
+  fn test_detect_rate_limited() {
+    let exhausted = headers(&[
+      ("x-ratelimit-limit", "5000"),
+      ("x-ratelimit-remaining", "0"),
+      ("x-ratelimit-reset", "1700000000"),
+    ]);
+
+    let limited = RateLimited::detect(StatusCode::FORBIDDEN, &exhausted, "").unwrap();
+
+    assert_eq!(
+      limited.reset_at,
+      Some(Utc.timestamp_opt(1700000000, 0).unwrap())
+    );
+
+    let secondary = headers(&[("retry-after", "60")]);
+    let limited = RateLimited::detect(StatusCode::FORBIDDEN, &secondary, "").unwrap();
+
+    assert_eq!(limited.retry_after, Some(Duration::from_secs(60)));
+
+    let limited = RateLimited::detect(
+      StatusCode::FORBIDDEN,
+      &HeaderMap::new(),
+      r#"{"message":"You have exceeded a secondary rate limit."}"#,
+    );
+
+    assert!(limited.is_some());
+
+    let forbidden = RateLimited::detect(
+      StatusCode::FORBIDDEN,
+      &HeaderMap::new(),
+      r#"{"message":"Resource not accessible by integration"}"#,
+    );
+
+    assert!(forbidden.is_none());
+  }
+
+  #[test]
+  fn test_rate_limit_policy_delay() {
+    let limited = RateLimited {
+      reset_at: None,
+      retry_after: None,
+    };
+
+    let policy = RateLimitPolicy::RetryWithBackoff {
+      max_retries: 2,
+      initial_delay: Duration::from_secs(1),
+    };
+
+    assert_eq!(
+      limited.delay(&policy, 0, Duration::ZERO),
+      Some(Duration::from_secs(1))
+    );
+    assert_eq!(
+      limited.delay(&policy, 1, Duration::ZERO),
+      Some(Duration::from_secs(2))
+    );
+    assert_eq!(limited.delay(&policy, 2, Duration::ZERO), None);
+    assert_eq!(
+      limited.delay(&RateLimitPolicy::FailFast, 0, Duration::ZERO),
+      None
+    );
+
+    let limited = RateLimited {
+      reset_at: None,
+      retry_after: Some(Duration::from_secs(30)),
+    };
+
+    let policy = RateLimitPolicy::WaitUntilReset {
+      max_wait: Duration::from_secs(60),
+    };
+
+    assert_eq!(
+      limited.delay(&policy, 0, Duration::ZERO),
+      Some(Duration::from_secs(30))
+    );
+    assert_eq!(limited.delay(&policy, 1, Duration::from_secs(40)), None);
+  }
+}
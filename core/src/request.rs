@@ -1,14 +1,25 @@
 use crate::{
   api_config::SharedAPIConfig,
-  error::{APIErrorResponse, Error},
+  cache::{self, CacheLookup, CachedResponse},
+  error::{DecodeError, Error, TransportError, TransportErrorKind},
+  media_type::MediaType,
+  payload_format::PayloadFormat,
   request_builder::RequestBuilder,
   response::GitHubResponse,
+  retry::RetryPolicy,
+  send::send_expecting,
+  token_provider::TokenProvider,
 };
-use std::marker::PhantomData;
+use reqwest::StatusCode;
+use std::{marker::PhantomData, sync::Arc};
 
 pub struct Request<Body, Query, Response> {
   pub(crate) builder: reqwest::RequestBuilder,
   pub(crate) api_config: SharedAPIConfig,
+  pub(crate) retry_policy: Option<RetryPolicy>,
+  pub(crate) token: Option<Arc<dyn TokenProvider>>,
+  pub(crate) response_format: PayloadFormat,
+  pub(crate) media_type: Option<&'static str>,
   pub(crate) _body: PhantomData<Body>,
   pub(crate) _query: PhantomData<Query>,
   pub(crate) _response: PhantomData<Response>,
@@ -36,66 +47,173 @@ where
     self
   }
 
+  /// Send raw content as the body with its media type, e.g. the Markdown of
+  /// `markdown.render_raw` or the bytes of a release asset.
+  pub fn raw_body(mut self, body: impl Into<reqwest::Body>, content_type: &str) -> Self {
+    self.builder = self
+      .builder
+      .header(reqwest::header::CONTENT_TYPE, content_type)
+      .body(body);
+
+    self
+  }
+
+  /// Add a header to this request, e.g. the `Content-Length` of a streamed body.
+  pub fn header(mut self, name: &str, value: impl ToString) -> Self {
+    self.builder = self.builder.header(name, value.to_string());
+
+    self
+  }
+
+  /// Ask for a custom media type of the operation, which also picks the type of the
+  /// response, e.g. `PullsGetMediaType::Diff` for the diff of a pull request.
+  pub fn media_type<Response>(
+    self,
+    media_type: MediaType<Response>,
+  ) -> Request<Body, Query, Response>
+  where
+    Response: serde::de::DeserializeOwned,
+  {
+    Request {
+      builder: self.builder,
+      api_config: self.api_config,
+      retry_policy: self.retry_policy,
+      token: self.token,
+      response_format: media_type.format,
+      media_type: Some(media_type.accept),
+      _body: PhantomData,
+      _query: PhantomData,
+      _response: PhantomData,
+    }
+  }
+
+  /// Retry this request with the given policy instead of the one of the config.
+  pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+    self.retry_policy = Some(policy);
+
+    self
+  }
+
+  /// Authenticate this request with the given token instead of the one of the config,
+  /// e.g. the [`ClientCredentials`](crate::ClientCredentials) of an OAuth app.
+  pub fn token<T: TokenProvider + 'static>(mut self, token: T) -> Self {
+    self.token = Some(Arc::new(token));
+
+    self
+  }
+
   /// Send the request and wrap the response with a GitHubResponse struct which
   /// proved access to some of the response metadata.
   pub async fn send_with_response(self) -> Result<GitHubResponse<ResponseData>, Error> {
-    let mut builder = self
-      .builder
-      .header("User-Agent", "octocrate")
-      .header("Accept", "application/vnd.github+json");
+    let builder = match self.media_type {
+      Some(media_type) => self.builder.header(reqwest::header::ACCEPT, media_type),
+      None => self.builder,
+    };
+
+    let token = self.token.as_ref().or(self.api_config.token.as_ref());
 
-    if let Some(token) = &self.api_config.token {
-      if let Some(token) = token.get_token() {
-        builder = builder.header("Authorization", format!("Bearer {}", token));
+    let (builder, token, lookup) = match (&self.api_config.cache, token) {
+      (Some(cache), Some(token)) => {
+        // Resolved once, for the cache key and the request
+        let token = token.token().await?;
+        let (builder, lookup) = cache::lookup(
+          cache.as_ref(),
+          builder,
+          self.media_type,
+          Some(&token.header_value()),
+        )
+        .await?;
+
+        (
+          builder,
+          Some(Arc::new(token) as Arc<dyn TokenProvider>),
+          lookup,
+        )
       }
-    }
+      (Some(cache), None) => {
+        let (builder, lookup) =
+          cache::lookup(cache.as_ref(), builder, self.media_type, None).await?;
+
+        (builder, None, lookup)
+      }
+      (None, token) => (builder, token.cloned(), None),
+    };
+
+    // A `304 Not Modified` only answers the conditional headers of a cached response
+    let expected: fn(StatusCode) -> bool = match &lookup {
+      Some(CacheLookup {
+        cached: Some(_), ..
+      }) => |status| status.is_success() || status == StatusCode::NOT_MODIFIED,
+      _ => |status| status.is_success(),
+    };
+
+    let (method, res) = send_expecting(
+      &self.api_config,
+      builder,
+      token.as_ref(),
+      self.retry_policy.as_ref(),
+      expected,
+    )
+    .await?;
+
+    let mut status = res.status();
+    let mut content_length = res.content_length();
+    let mut headers = res.headers().clone();
+    let version = res.version();
+    let url = res.url().clone();
+
+    let mut body = match res.bytes().await {
+      Ok(body) => body,
+      Err(err) => {
+        return Err(Error::Transport(Box::new(TransportError {
+          method,
+          url,
+          kind: TransportErrorKind::from(&err),
+          message: format!("Failed to read response with status {}: {}", status, err),
+        })))
+      }
+    };
+
+    let mut cached = false;
 
-    let res = builder.send().await;
-    match res {
-      Ok(res) => {
-        let status = res.status();
-        let content_length = res.content_length();
-        let headers = res.headers().clone();
-        let version = res.version();
-        let url = res.url().clone();
-
-        if !status.is_success() {
-          if let Ok(error_response) = res.json::<APIErrorResponse>().await {
-            return Err(Error::RequestFailed(error_response));
-          }
-
-          let err_msg = format!("Request failed with {}", status);
-          return Err(Error::Error(err_msg));
+    if let (Some(cache), Some(lookup)) = (&self.api_config.cache, lookup) {
+      match lookup.cached {
+        Some(cached_response) if status == StatusCode::NOT_MODIFIED => {
+          status = cached_response.status_code();
+          headers = cached_response.header_map(&headers);
+          content_length = Some(cached_response.body.len() as u64);
+          body = cached_response.body.into();
+          cached = true;
         }
+        _ if status.is_success() && cache::is_cacheable(&headers) => {
+          let response = CachedResponse::new(status, &headers, body.to_vec());
 
-        let res = res.text().await.map_err(|err| {
-          Error::Error(format!(
-            "Failed to read response with status {}: {}",
-            status, err
-          ))
-        })?;
-
-        match serde_json::from_str(&res) {
-          Ok(data) => {
-            let github_response = GitHubResponse {
-              content_length,
-              data,
-              headers,
-              status,
-              url,
-              version,
-            };
-            Ok(github_response)
-          }
-          Err(error) => Err(Error::Error(format!(
-            r#"Failed to parse response with status {}: {}
-
-              Response: {}"#,
-            status, error, res
-          ))),
+          cache.set(&lookup.key, response).await;
         }
+        _ => {}
       }
-      Err(err) => Err(Error::Error(err.to_string())),
+    }
+
+    match self.response_format.decode(&body) {
+      Ok(data) => {
+        let github_response = GitHubResponse {
+          content_length,
+          data,
+          headers,
+          status,
+          url,
+          version,
+          cached,
+        };
+        Ok(github_response)
+      }
+      Err(error) => Err(Error::Decode(Box::new(DecodeError {
+        method,
+        url,
+        status,
+        message: error,
+        body: String::from_utf8_lossy(&body).into_owned(),
+      }))),
     }
   }
 
@@ -109,7 +227,7 @@ impl<Body, Query, ResponseData> Request<Body, Query, ResponseData>
 where
   Body: serde::Serialize,
   Query: serde::Serialize,
-  ResponseData: serde::de::DeserializeOwned + IntoIterator,
+  ResponseData: serde::de::DeserializeOwned + octocrate_types::PaginatedItems,
 {
   pub async fn paginated_send(self) -> Result<octocrate_types::PaginatedData<ResponseData>, Error> {
     Ok(self.paginated_send_with_response().await?.paginate())
@@ -125,7 +243,7 @@ where
 #[cfg(test)]
 mod tests {
   use super::*;
-  use crate::api_config::APIConfig;
+  use crate::{api_config::APIConfig, test_server::serve};
 
   #[tokio::test]
   async fn test_request_builder() {
@@ -154,4 +272,63 @@ mod tests {
 
     assert_eq!(response.full_name, "panghu-huang/octocrate");
   }
+
+  #[tokio::test]
+  async fn test_conditional_requests() {
+    let (base_url, requests) = serve(vec![
+      (
+        "200 OK",
+        "etag: \"644b5b0155e6404a9cc4bd9d8b1ae730\"\r\n",
+        r#"{"id":1}"#,
+      ),
+      (
+        "304 Not Modified",
+        "etag: \"644b5b0155e6404a9cc4bd9d8b1ae730\"\r\nx-ratelimit-remaining: 4999\r\n",
+        "",
+      ),
+    ])
+    .await;
+    let config = APIConfig::with_base_url(&base_url)
+      .cache(crate::cache::MemoryCache::new(10))
+      .shared();
+
+    let request = || {
+      Request::<(), (), serde_json::Value>::builder(&config)
+        .get("/repos/panghu-huang/octocrate")
+        .build()
+    };
+
+    let response = request().send_with_response().await.unwrap();
+
+    assert!(!response.cached);
+
+    let response = request().send_with_response().await.unwrap();
+
+    assert!(response.cached);
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.data, serde_json::json!({ "id": 1 }));
+    assert_eq!(response.headers["x-ratelimit-remaining"], "4999");
+
+    let requests = requests.lock().unwrap();
+
+    assert!(!requests[0].to_lowercase().contains("if-none-match"));
+    assert!(requests[1]
+      .to_lowercase()
+      .contains("if-none-match: \"644b5b0155e6404a9cc4bd9d8b1ae730\""));
+  }
+
+  #[tokio::test]
+  async fn test_not_modified_without_cache() {
+    let (base_url, _) = serve(vec![("304 Not Modified", "", "")]).await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let error = Request::<(), (), serde_json::Value>::builder(&config)
+      .get("/repos/panghu-huang/octocrate")
+      .build()
+      .send()
+      .await
+      .unwrap_err();
+
+    assert_eq!(error.status(), Some(StatusCode::NOT_MODIFIED));
+  }
 }
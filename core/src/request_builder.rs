@@ -1,10 +1,11 @@
-use crate::{api_config::SharedAPIConfig, request::Request};
-use reqwest::Client;
+use crate::{api_config::SharedAPIConfig, payload_format::PayloadFormat, request::Request};
 use std::marker::PhantomData;
 
 pub struct RequestBuilder<Body, Query, Response> {
   builder: Option<reqwest::RequestBuilder>,
   api_config: SharedAPIConfig,
+  base_url: String,
+  response_format: PayloadFormat,
   _body: PhantomData<Body>,
   _query: PhantomData<Query>,
   _response: PhantomData<Response>,
@@ -20,15 +21,25 @@ where
     Self {
       builder: None,
       api_config: config.clone(),
+      base_url: config.base_url.clone(),
+      response_format: PayloadFormat::Json,
       _body: PhantomData,
       _query: PhantomData,
       _response: PhantomData,
     }
   }
 
+  /// Send the request to the upload server of the config instead of the API, e.g. to
+  /// upload release assets.
+  pub fn uploads(mut self) -> Self {
+    self.base_url = self.api_config.upload_url.clone();
+
+    self
+  }
+
   pub fn get(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().get(url);
+    let url = join_url(&self.base_url, url.into());
+    let builder = self.api_config.client.get(url);
 
     self.builder = Some(builder);
 
@@ -36,8 +47,8 @@ where
   }
 
   pub fn post(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().post(url);
+    let url = join_url(&self.base_url, url.into());
+    let builder = self.api_config.client.post(url);
 
     self.builder = Some(builder);
 
@@ -45,8 +56,8 @@ where
   }
 
   pub fn put(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().put(url);
+    let url = join_url(&self.base_url, url.into());
+    let builder = self.api_config.client.put(url);
 
     self.builder = Some(builder);
 
@@ -54,8 +65,8 @@ where
   }
 
   pub fn patch(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().patch(url);
+    let url = join_url(&self.base_url, url.into());
+    let builder = self.api_config.client.patch(url);
 
     self.builder = Some(builder);
 
@@ -63,21 +74,40 @@ where
   }
 
   pub fn delete(mut self, url: impl Into<String>) -> Self {
-    let url = format!("{}{}", self.api_config.base_url, url.into());
-    let builder = Client::new().delete(url);
+    let url = join_url(&self.base_url, url.into());
+    let builder = self.api_config.client.delete(url);
 
     self.builder = Some(builder);
 
     self
   }
 
+  pub fn response_format(mut self, format: PayloadFormat) -> Self {
+    self.response_format = format;
+
+    self
+  }
+
   pub fn build(self) -> Request<Body, Query, Response> {
     Request {
       builder: self.builder.expect("RequestBuilder is not configured"),
       api_config: self.api_config.clone(),
+      retry_policy: None,
+      token: None,
+      response_format: self.response_format,
+      media_type: None,
       _body: PhantomData,
       _query: PhantomData,
       _response: PhantomData,
     }
   }
 }
+
+/// Absolute URLs, such as the `upload_url` of a release, are used as they are.
+pub(crate) fn join_url(base_url: &str, url: String) -> String {
+  if url.starts_with("https://") || url.starts_with("http://") {
+    url
+  } else {
+    format!("{}{}", base_url, url)
+  }
+}
@@ -1,3 +1,4 @@
+use crate::rate_limit::RateLimit;
 use reqwest::{header::HeaderMap, StatusCode, Url, Version};
 
 #[derive(Clone)]
@@ -8,6 +9,15 @@ pub struct GitHubResponse<ResponseData> {
   pub version: Version,
   pub content_length: Option<u64>,
   pub url: Url,
+  /// Whether the data is the cached response of a `304 Not Modified`.
+  pub cached: bool,
+}
+
+impl<ResponseData> GitHubResponse<ResponseData> {
+  /// The rate limit budget left after this request.
+  pub fn rate_limit(&self) -> Option<RateLimit> {
+    RateLimit::from_headers(&self.headers)
+  }
 }
 
 #[cfg(feature = "pagination")]
@@ -23,7 +33,7 @@ pub struct GitHubPaginatedResponse<ResponseData> {
 }
 
 #[cfg(feature = "pagination")]
-impl<ResponseData: serde::de::DeserializeOwned + IntoIterator>
+impl<ResponseData: serde::de::DeserializeOwned + octocrate_types::PaginatedItems>
   GitHubPaginatedResponse<ResponseData>
 {
   pub fn paginate(self) -> octocrate_types::PaginatedData<ResponseData> {
@@ -35,8 +45,8 @@ impl<ResponseData: serde::de::DeserializeOwned + IntoIterator>
 }
 
 #[cfg(feature = "pagination")]
-impl<ResponseData: serde::de::DeserializeOwned + IntoIterator> From<GitHubResponse<ResponseData>>
-  for GitHubPaginatedResponse<ResponseData>
+impl<ResponseData: serde::de::DeserializeOwned + octocrate_types::PaginatedItems>
+  From<GitHubResponse<ResponseData>> for GitHubPaginatedResponse<ResponseData>
 {
   fn from(value: GitHubResponse<ResponseData>) -> Self {
     let GitHubResponse::<ResponseData> {
@@ -46,6 +56,7 @@ impl<ResponseData: serde::de::DeserializeOwned + IntoIterator> From<GitHubRespon
       version,
       content_length,
       url,
+      ..
     } = value;
 
     Self {
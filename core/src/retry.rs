@@ -0,0 +1,147 @@
+use crate::error::{Error, TransportErrorKind};
+use reqwest::{Method, StatusCode};
+use std::{
+  collections::hash_map::RandomState,
+  hash::{BuildHasher, Hasher},
+  time::Duration,
+};
+
+/// Retry requests that failed with a 5xx status or a network error, waiting an
+/// exponential backoff with full jitter between the attempts.
+///
+/// Only idempotent methods are retried, unless [`retry_post`](Self::retry_post) is set.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+  /// The number of times a request is sent at most, including the first attempt.
+  pub max_attempts: u32,
+  pub initial_delay: Duration,
+  pub max_delay: Duration,
+  /// Also retry `POST` and `PATCH` requests, which may not be safe to send twice.
+  pub retry_post: bool,
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    RetryPolicy {
+      max_attempts: 3,
+      initial_delay: Duration::from_millis(500),
+      max_delay: Duration::from_secs(30),
+      retry_post: false,
+    }
+  }
+}
+
+impl RetryPolicy {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Send every request only once.
+  pub fn never() -> Self {
+    RetryPolicy {
+      max_attempts: 1,
+      ..Default::default()
+    }
+  }
+
+  pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+    self.max_attempts = max_attempts;
+
+    self
+  }
+
+  pub fn initial_delay(mut self, delay: Duration) -> Self {
+    self.initial_delay = delay;
+
+    self
+  }
+
+  pub fn max_delay(mut self, delay: Duration) -> Self {
+    self.max_delay = delay;
+
+    self
+  }
+
+  pub fn retry_post(mut self, retry_post: bool) -> Self {
+    self.retry_post = retry_post;
+
+    self
+  }
+
+  pub(crate) fn should_retry_method(&self, method: &Method) -> bool {
+    match *method {
+      Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE => true,
+      Method::POST | Method::PATCH => self.retry_post,
+      _ => false,
+    }
+  }
+
+  pub(crate) fn should_retry_status(&self, status: StatusCode) -> bool {
+    status.is_server_error()
+  }
+
+  pub(crate) fn should_retry_error(&self, error: &Error) -> bool {
+    matches!(
+      error,
+      Error::Transport(error) if matches!(
+        error.kind,
+        TransportErrorKind::Connect | TransportErrorKind::Timeout | TransportErrorKind::Request
+      )
+    )
+  }
+
+  /// How long to wait after the given failed attempt, `None` once all attempts are used.
+  pub(crate) fn delay(&self, attempt: u32) -> Option<Duration> {
+    if attempt + 1 >= self.max_attempts {
+      return None;
+    }
+
+    let backoff = self
+      .initial_delay
+      .saturating_mul(2u32.saturating_pow(attempt))
+      .min(self.max_delay);
+
+    Some(backoff.mul_f64(jitter()))
+  }
+}
+
+/// A random factor between 0 and 1, without pulling in a random number generator.
+fn jitter() -> f64 {
+  let random = RandomState::new().build_hasher().finish();
+
+  (random as f64) / (u64::MAX as f64)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_retry_methods() {
+    let policy = RetryPolicy::new();
+
+    assert!(policy.should_retry_method(&Method::GET));
+    assert!(policy.should_retry_method(&Method::PUT));
+    assert!(policy.should_retry_method(&Method::DELETE));
+    assert!(!policy.should_retry_method(&Method::POST));
+    assert!(!policy.should_retry_method(&Method::PATCH));
+
+    let policy = policy.retry_post(true);
+
+    assert!(policy.should_retry_method(&Method::POST));
+  }
+
+  #[test]
+  fn test_retry_delay() {
+    let policy = RetryPolicy::new()
+      .max_attempts(4)
+      .initial_delay(Duration::from_secs(1))
+      .max_delay(Duration::from_secs(3));
+
+    assert!(policy.delay(0).unwrap() <= Duration::from_secs(1));
+    assert!(policy.delay(1).unwrap() <= Duration::from_secs(2));
+    assert!(policy.delay(2).unwrap() <= Duration::from_secs(3));
+    assert_eq!(policy.delay(3), None);
+    assert_eq!(RetryPolicy::never().delay(0), None);
+  }
+}
@@ -0,0 +1,333 @@
+use crate::{
+  api_config::APIConfig,
+  error::{APIError, Error},
+  middleware::Next,
+  rate_limit::RateLimited,
+  retry::RetryPolicy,
+  token_provider::TokenProvider,
+};
+use reqwest::{
+  header::{HeaderValue, ACCEPT},
+  Method, StatusCode,
+};
+use std::{sync::Arc, time::Duration};
+
+/// Send a request with the headers and authorization of the config through its
+/// middlewares, applying its rate limit policy and the retry policy of the request or
+/// the config. The token of the request, if any, replaces the one of the config. Responses
+/// without a success status are turned into errors.
+pub(crate) async fn send(
+  api_config: &APIConfig,
+  builder: reqwest::RequestBuilder,
+  token: Option<&Arc<dyn TokenProvider>>,
+  retry_policy: Option<&RetryPolicy>,
+) -> Result<(Method, reqwest::Response), Error> {
+  send_expecting(api_config, builder, token, retry_policy, |status| {
+    status.is_success()
+  })
+  .await
+}
+
+/// Send the request, any status that isn't `expected` is an error.
+pub(crate) async fn send_expecting(
+  api_config: &APIConfig,
+  builder: reqwest::RequestBuilder,
+  token: Option<&Arc<dyn TokenProvider>>,
+  retry_policy: Option<&RetryPolicy>,
+  expected: fn(StatusCode) -> bool,
+) -> Result<(Method, reqwest::Response), Error> {
+  let mut builder = builder.header("User-Agent", &api_config.user_agent);
+
+  if let Some(token) = token.or(api_config.token.as_ref()) {
+    let token = token.token().await?;
+
+    builder = builder.header("Authorization", token.header_value());
+  }
+
+  let (client, request) = builder.build_split();
+  let mut request = request.map_err(|err| Error::Error(err.to_string()))?;
+
+  // Unless a custom media type was chosen
+  request
+    .headers_mut()
+    .entry(ACCEPT)
+    .or_insert(HeaderValue::from_static("application/vnd.github+json"));
+
+  let retry_policy = retry_policy
+    .or(api_config.retry_policy.as_ref())
+    .filter(|policy| policy.should_retry_method(request.method()));
+
+  let method = request.method().clone();
+  let url = request.url().clone();
+
+  let mut rate_limit_attempt = 0;
+  let mut retry_attempt = 0;
+  let mut waited = Duration::ZERO;
+
+  loop {
+    // Requests with a streamed body can't be cloned, and are only sent once
+    let retry_request = request.try_clone();
+
+    let res = match Next::new(&client, &api_config.middlewares)
+      .run(request)
+      .await
+    {
+      Ok(res) => res,
+      Err(err) => {
+        let delay = retry_policy
+          .filter(|policy| policy.should_retry_error(&err))
+          .and_then(|policy| policy.delay(retry_attempt));
+
+        if let (Some(delay), Some(retry_request)) = (delay, retry_request) {
+          tokio::time::sleep(delay).await;
+
+          retry_attempt += 1;
+          request = retry_request;
+
+          continue;
+        }
+
+        return Err(err);
+      }
+    };
+
+    let status = res.status();
+
+    if expected(status) {
+      return Ok((method, res));
+    }
+
+    let headers = res.headers().clone();
+    let body = res.text().await.unwrap_or_default();
+
+    if let Some(rate_limited) = RateLimited::detect(status, &headers, &body) {
+      let delay = rate_limited.delay(&api_config.rate_limit_policy, rate_limit_attempt, waited);
+
+      match (delay, retry_request) {
+        (Some(delay), Some(retry_request)) => {
+          tokio::time::sleep(delay).await;
+
+          rate_limit_attempt += 1;
+          waited += delay;
+          request = retry_request;
+
+          continue;
+        }
+        _ => {
+          return Err(Error::RateLimited {
+            error: Box::new(APIError::new(method, url, status, headers, &body)),
+            reset_at: rate_limited.reset_at,
+            retry_after: rate_limited.retry_after,
+          })
+        }
+      }
+    }
+
+    let delay = retry_policy
+      .filter(|policy| policy.should_retry_status(status))
+      .and_then(|policy| policy.delay(retry_attempt));
+
+    if let (Some(delay), Some(retry_request)) = (delay, retry_request) {
+      tokio::time::sleep(delay).await;
+
+      retry_attempt += 1;
+      request = retry_request;
+
+      continue;
+    }
+
+    return Err(APIError::new(method, url, status, headers, &body).into_error());
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_server::serve;
+  use crate::{
+    middleware::{Middleware, MiddlewareFuture},
+    no_content_request::NoContentRequest,
+    rate_limit::RateLimitPolicy,
+    request::Request,
+    retry::RetryPolicy,
+  };
+  use std::sync::Mutex;
+
+  const SECONDARY_RATE_LIMIT: (&str, &str, &str) = (
+    "403 Forbidden",
+    "retry-after: 0\r\n",
+    r#"{"message":"You have exceeded a secondary rate limit"}"#,
+  );
+
+  const OK: (&str, &str, &str) = (
+    "200 OK",
+    "x-ratelimit-limit: 5000\r\nx-ratelimit-remaining: 4999\r\nx-ratelimit-used: 1\r\nx-ratelimit-reset: 1700000000\r\n",
+    r#"{"id":1234}"#,
+  );
+
+  const BAD_GATEWAY: (&str, &str, &str) = ("502 Bad Gateway", "", r#"{"message":"Server Error"}"#);
+
+  #[derive(serde::Deserialize)]
+  struct Response {
+    id: u64,
+  }
+
+  #[tokio::test]
+  async fn test_fail_fast_on_rate_limit() {
+    let (base_url, _) = serve(vec![SECONDARY_RATE_LIMIT]).await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let result = Request::<(), (), Response>::builder(&config)
+      .get("/repos/panghu-huang/octocrate")
+      .build()
+      .send()
+      .await;
+
+    assert!(matches!(
+      result,
+      Err(Error::RateLimited {
+        retry_after: Some(retry_after),
+        ..
+      }) if retry_after == Duration::ZERO
+    ));
+  }
+
+  #[tokio::test]
+  async fn test_retry_after_rate_limit() {
+    let (base_url, _) = serve(vec![SECONDARY_RATE_LIMIT, OK]).await;
+    let config = APIConfig::with_base_url(&base_url)
+      .rate_limit_policy(RateLimitPolicy::RetryWithBackoff {
+        max_retries: 1,
+        initial_delay: Duration::from_millis(10),
+      })
+      .shared();
+
+    let response = Request::<(), (), Response>::builder(&config)
+      .get("/repos/panghu-huang/octocrate")
+      .build()
+      .send_with_response()
+      .await
+      .unwrap();
+
+    assert_eq!(response.data.id, 1234);
+    assert_eq!(response.rate_limit().unwrap().remaining, 4999);
+  }
+
+  #[tokio::test]
+  async fn test_retry_server_errors() {
+    let (base_url, _) = serve(vec![BAD_GATEWAY, BAD_GATEWAY, OK]).await;
+    let config = APIConfig::with_base_url(&base_url)
+      .retry_policy(RetryPolicy::new().initial_delay(Duration::from_millis(10)))
+      .shared();
+
+    let response = Request::<(), (), Response>::builder(&config)
+      .get("/repos/panghu-huang/octocrate")
+      .build()
+      .send()
+      .await
+      .unwrap();
+
+    assert_eq!(response.id, 1234);
+  }
+
+  #[tokio::test]
+  async fn test_post_is_not_retried_by_default() {
+    let (base_url, _) = serve(vec![BAD_GATEWAY, OK]).await;
+    let config = APIConfig::with_base_url(&base_url)
+      .retry_policy(RetryPolicy::new().initial_delay(Duration::from_millis(10)))
+      .shared();
+
+    let result = Request::<(), (), Response>::builder(&config)
+      .post("/repos/panghu-huang/octocrate/forks")
+      .build()
+      .send()
+      .await;
+
+    assert!(matches!(result, Err(Error::RequestFailed(error)) if error.status == 502));
+
+    // Opting in for this request only
+    let (base_url, _) = serve(vec![BAD_GATEWAY, OK]).await;
+    let config = APIConfig::with_base_url(&base_url).shared();
+
+    let response = Request::<(), (), Response>::builder(&config)
+      .post("/repos/panghu-huang/octocrate/forks")
+      .build()
+      .retry_policy(
+        RetryPolicy::new()
+          .initial_delay(Duration::from_millis(10))
+          .retry_post(true),
+      )
+      .send()
+      .await
+      .unwrap();
+
+    assert_eq!(response.id, 1234);
+  }
+
+  struct Audit {
+    log: Arc<Mutex<Vec<String>>>,
+  }
+
+  impl Middleware for Audit {
+    fn handle<'a>(&'a self, mut request: reqwest::Request, next: Next<'a>) -> MiddlewareFuture<'a> {
+      request
+        .headers_mut()
+        .insert("x-audit-id", "42".parse().unwrap());
+
+      Box::pin(async move {
+        let method = request.method().clone();
+        let path = request.url().path().to_string();
+        let response = next.run(request).await?;
+
+        self.log.lock().unwrap().push(format!(
+          "{} {} {}",
+          method,
+          path,
+          response.status().as_u16()
+        ));
+
+        Ok(response)
+      })
+    }
+  }
+
+  #[tokio::test]
+  async fn test_middleware() {
+    let (base_url, requests) = serve(vec![BAD_GATEWAY, OK, OK]).await;
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let config = APIConfig::with_base_url(&base_url)
+      .retry_policy(RetryPolicy::new().initial_delay(Duration::from_millis(10)))
+      .middleware(Audit { log: log.clone() })
+      .shared();
+
+    Request::<(), (), Response>::builder(&config)
+      .get("/repos/panghu-huang/octocrate")
+      .build()
+      .send()
+      .await
+      .unwrap();
+
+    NoContentRequest::<(), ()>::builder(&config)
+      .delete("/repos/panghu-huang/octocrate/subscription")
+      .build()
+      .send()
+      .await
+      .unwrap();
+
+    // Every attempt goes through the middleware
+    assert_eq!(
+      *log.lock().unwrap(),
+      vec![
+        "GET /repos/panghu-huang/octocrate 502",
+        "GET /repos/panghu-huang/octocrate 200",
+        "DELETE /repos/panghu-huang/octocrate/subscription 200",
+      ]
+    );
+
+    assert!(requests
+      .lock()
+      .unwrap()
+      .iter()
+      .all(|request| request.contains("x-audit-id: 42")));
+  }
+}
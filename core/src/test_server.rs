@@ -0,0 +1,42 @@
+use std::sync::{Arc, Mutex};
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::TcpListener,
+};
+
+/// Serve the given responses in order, one per connection, recording the raw requests.
+pub(crate) async fn serve(
+  responses: Vec<(&'static str, &'static str, &'static str)>,
+) -> (String, Arc<Mutex<Vec<String>>>) {
+  let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+  let address = listener.local_addr().unwrap();
+  let requests = Arc::new(Mutex::new(Vec::new()));
+  let recorded = requests.clone();
+
+  tokio::spawn(async move {
+    for (status, headers, body) in responses {
+      let (mut stream, _) = listener.accept().await.unwrap();
+      let mut buffer = [0; 4096];
+
+      let read = stream.read(&mut buffer).await.unwrap();
+
+      recorded
+        .lock()
+        .unwrap()
+        .push(String::from_utf8_lossy(&buffer[..read]).to_string());
+
+      let response = format!(
+        "HTTP/1.1 {}\r\n{}content-type: application/json\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{}",
+        status,
+        headers,
+        body.len(),
+        body
+      );
+
+      stream.write_all(response.as_bytes()).await.unwrap();
+      stream.shutdown().await.unwrap();
+    }
+  });
+
+  (format!("http://{}", address), requests)
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+
+/// A `date-time` of the generated models, which is an RFC 3339 string, or a
+/// `chrono::DateTime<Utc>` with the `chrono_timestamps` feature.
+///
+/// Code that compares timestamps works with both:
+///
+/// ```rust,ignore
+/// let expired = token
+///   .expires_at
+///   .to_date_time()
+///   .is_some_and(|expires_at| expires_at <= Utc::now());
+/// ```
+pub trait Timestamp {
+  /// The timestamp in UTC, `None` when a string isn't a valid RFC 3339 timestamp.
+  fn to_date_time(&self) -> Option<DateTime<Utc>>;
+}
+
+impl Timestamp for String {
+  fn to_date_time(&self) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(self)
+      .ok()
+      .map(|date_time| date_time.with_timezone(&Utc))
+  }
+}
+
+impl Timestamp for DateTime<Utc> {
+  fn to_date_time(&self) -> Option<DateTime<Utc>> {
+    Some(*self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_to_date_time() {
+    let date_time = "2024-04-01T08:00:00+08:00"
+      .to_string()
+      .to_date_time()
+      .unwrap();
+
+    assert_eq!(date_time.to_rfc3339(), "2024-04-01T00:00:00+00:00");
+    assert_eq!(date_time.to_date_time(), Some(date_time));
+    assert!("yesterday".to_string().to_date_time().is_none());
+  }
+}
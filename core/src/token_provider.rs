@@ -0,0 +1,120 @@
+use crate::{error::Error, expirable_token::ExpirableToken};
+use chrono::{DateTime, Utc};
+use std::{future::Future, pin::Pin};
+
+/// How a token is sent in the `Authorization` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthScheme {
+  /// `Bearer <token>`, accepted for every kind of token.
+  #[default]
+  Bearer,
+  /// `token <token>`, the legacy scheme of personal access tokens and OAuth tokens.
+  Token,
+  /// `Basic <credentials>`, with the credentials already encoded in base64.
+  Basic,
+}
+
+impl AuthScheme {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      AuthScheme::Bearer => "Bearer",
+      AuthScheme::Token => "token",
+      AuthScheme::Basic => "Basic",
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+  pub value: String,
+  pub scheme: AuthScheme,
+  /// When the token stops being valid, if it is known.
+  pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl Token {
+  pub fn new(value: impl Into<String>) -> Self {
+    Token {
+      value: value.into(),
+      scheme: AuthScheme::Bearer,
+      expires_at: None,
+    }
+  }
+
+  pub fn scheme(mut self, scheme: AuthScheme) -> Self {
+    self.scheme = scheme;
+
+    self
+  }
+
+  pub fn expires_at(mut self, expires_at: DateTime<Utc>) -> Self {
+    self.expires_at = Some(expires_at);
+
+    self
+  }
+
+  pub fn is_expired(&self) -> bool {
+    self
+      .expires_at
+      .is_some_and(|expires_at| expires_at <= Utc::now())
+  }
+
+  /// The value of the `Authorization` header.
+  pub fn header_value(&self) -> String {
+    format!("{} {}", self.scheme.as_str(), self.value)
+  }
+}
+
+pub type TokenFuture<'a> = Pin<Box<dyn Future<Output = Result<Token, Error>> + Send + 'a>>;
+
+/// A source of the token sent with every request, which may fetch or renew it over the
+/// network, e.g. from a secrets manager.
+///
+/// Every [`ExpirableToken`] is a token provider sending its token with its
+/// [`ExpirableToken::auth_scheme`].
+pub trait TokenProvider: Send + Sync {
+  fn token(&self) -> TokenFuture<'_>;
+}
+
+impl<T: ExpirableToken + ?Sized> TokenProvider for T {
+  fn token(&self) -> TokenFuture<'_> {
+    let token = self
+      .try_get_token()
+      .map(|token| Token::new(token).scheme(self.auth_scheme()));
+
+    Box::pin(async move { token })
+  }
+}
+
+/// A token that was already fetched is sent as it is.
+impl TokenProvider for Token {
+  fn token(&self) -> TokenFuture<'_> {
+    let token = self.clone();
+
+    Box::pin(async move { Ok(token) })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::personal_access_token::PersonalAccessToken;
+
+  #[tokio::test]
+  async fn test_expirable_token_provider() {
+    let token = PersonalAccessToken::new("ghp_token".to_string())
+      .token()
+      .await
+      .unwrap();
+
+    assert_eq!(token.header_value(), "Bearer ghp_token");
+    assert!(!token.is_expired());
+
+    let token = Token::new("gho_token")
+      .scheme(AuthScheme::Token)
+      .expires_at(Utc::now());
+
+    assert_eq!(token.header_value(), "token gho_token");
+    assert!(token.is_expired());
+  }
+}
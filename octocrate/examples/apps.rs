@@ -1,4 +1,4 @@
-use octocrate::{APIConfig, AppAuthorization, GitHubAPI};
+use octocrate::{APIConfig, AppAuthorization, GitHubAPI, InstallationAuthorization};
 
 #[tokio::test]
 async fn test_app_installation() {
@@ -45,3 +45,33 @@ async fn test_app_installation() {
   assert_eq!(repository.name, "octocrate");
   assert_eq!(repository.full_name, "panghu-huang/octocrate");
 }
+
+#[tokio::test]
+async fn test_installation_authorization() {
+  dotenv::dotenv().ok();
+
+  let app_id = std::env::var("GITHUB_APP_ID").expect("GITHUB_APP_ID is not set");
+  let private_key_path =
+    std::env::var("GITHUB_APP_PRIVATE_KEY_PATH").expect("GITHUB_APP_PRIVATE_KEY_PATH is not set");
+
+  let private_key = std::fs::read_to_string(&private_key_path).unwrap();
+
+  let installation_authorization =
+    InstallationAuthorization::new(AppAuthorization::new(app_id, private_key));
+
+  let installation = installation_authorization
+    .repo_installation("panghu-huang", "octocrate")
+    .await
+    .unwrap();
+
+  let api = GitHubAPI::new(&installation.config().shared());
+
+  let repository = api
+    .repos
+    .get("panghu-huang", "octocrate")
+    .send()
+    .await
+    .unwrap();
+
+  assert_eq!(repository.full_name, "panghu-huang/octocrate");
+}
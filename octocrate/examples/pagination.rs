@@ -49,4 +49,68 @@ async fn test_pagination() {
   assert!(repositories.data.get(0).unwrap().full_name != repository.full_name);
 }
 
+#[tokio::test]
+async fn test_pagination_collect_all() {
+  use octocrate::{APIConfig, GitHubAPI, PersonalAccessToken, ReposListForUserQuery};
+
+  dotenv::dotenv().ok();
+
+  let personal_access_token =
+    std::env::var("GITHUB_PERSONAL_ACCESS_TOKEN").expect("GITHUB_PERSONAL_ACCESS_TOKEN is not set");
+
+  let personal_access_token = PersonalAccessToken::new(personal_access_token);
+
+  let config = APIConfig::with_token(personal_access_token).shared();
+
+  let api = GitHubAPI::new(&config);
+
+  let query = ReposListForUserQuery::builder().per_page(10).build();
+
+  // Follows the `next` links until 25 repositories have been read
+  let repositories = api
+    .repos
+    .list_for_user("ifiokjr")
+    .query(&query)
+    .collect_all(Some(25))
+    .await
+    .unwrap();
+
+  assert_eq!(repositories.len(), 25);
+  assert!(repositories
+    .iter()
+    .all(|repository| repository.owner.login == "ifiokjr"));
+}
+
+#[tokio::test]
+async fn test_pagination_search_results() {
+  use octocrate::{APIConfig, GitHubAPI, PersonalAccessToken, SearchIssuesAndPullRequestsQuery};
+
+  dotenv::dotenv().ok();
+
+  let personal_access_token =
+    std::env::var("GITHUB_PERSONAL_ACCESS_TOKEN").expect("GITHUB_PERSONAL_ACCESS_TOKEN is not set");
+
+  let personal_access_token = PersonalAccessToken::new(personal_access_token);
+
+  let config = APIConfig::with_token(personal_access_token).shared();
+
+  let api = GitHubAPI::new(&config);
+
+  let query = SearchIssuesAndPullRequestsQuery::builder()
+    .q("repo:facebook/react is:pr")
+    .per_page(30)
+    .build();
+
+  // The `items` of every page are collected, not the wrapping response
+  let pull_requests = api
+    .search
+    .issues_and_pull_requests()
+    .query(&query)
+    .collect_all(Some(50))
+    .await
+    .unwrap();
+
+  assert_eq!(pull_requests.len(), 50);
+}
+
 fn main() {}
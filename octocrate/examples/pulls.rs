@@ -106,10 +106,10 @@ async fn test_check_if_pull_request_has_been_merged() {
 
   let err = res.unwrap_err();
 
-  if let Error::RequestFailed(error) = err {
-    assert_eq!(error.message, "Not Found");
+  if let Error::NotFound(error) = err {
+    assert_eq!(error.response.message, "Not Found");
   } else {
-    panic!("Expected RequestFailed");
+    panic!("Expected NotFound");
   }
 
   // 204 merged
@@ -26,7 +26,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), ActionsCacheUsageOrgEnterprise> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/cache/usage");
 
     Request::<(), (), ActionsCacheUsageOrgEnterprise>::builder(&self.config)
@@ -50,7 +50,7 @@ impl GitHubActionsAPI {
     ActionsGetActionsCacheUsageByRepoForOrgQuery,
     ActionsGetActionsCacheUsageByRepoForOrgResponse,
   > {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/cache/usage-by-repository");
 
     Request::<
@@ -73,7 +73,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), ActionsOrganizationPermissions> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions");
 
     Request::<(), (), ActionsOrganizationPermissions>::builder(&self.config)
@@ -92,7 +92,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<ActionsSetGithubActionsPermissionsOrganizationRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions");
 
     NoContentRequest::<ActionsSetGithubActionsPermissionsOrganizationRequest, ()>::builder(
@@ -117,7 +117,7 @@ impl GitHubActionsAPI {
     ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationQuery,
     ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationResponse,
   > {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions/repositories");
 
     Request::<
@@ -142,7 +142,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
   ) -> NoContentRequest<ActionsSetSelectedRepositoriesEnabledGithubActionsOrganizationRequest, ()>
   {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions/repositories");
 
     NoContentRequest::<ActionsSetSelectedRepositoriesEnabledGithubActionsOrganizationRequest, ()>::builder(&self.config)
@@ -162,7 +162,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/actions/permissions/repositories/{repository_id}");
 
@@ -183,7 +183,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/actions/permissions/repositories/{repository_id}");
 
@@ -203,7 +203,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), SelectedActions> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions/selected-actions");
 
     Request::<(), (), SelectedActions>::builder(&self.config)
@@ -222,7 +222,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<SelectedActions, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions/selected-actions");
 
     NoContentRequest::<SelectedActions, ()>::builder(&self.config)
@@ -243,7 +243,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), ActionsGetDefaultWorkflowPermissions> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions/workflow");
 
     Request::<(), (), ActionsGetDefaultWorkflowPermissions>::builder(&self.config)
@@ -264,7 +264,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<ActionsSetDefaultWorkflowPermissions, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/permissions/workflow");
 
     NoContentRequest::<ActionsSetDefaultWorkflowPermissions, ()>::builder(&self.config)
@@ -289,7 +289,7 @@ impl GitHubActionsAPI {
     ActionsListSelfHostedRunnersForOrgQuery,
     ActionsListSelfHostedRunnersForOrgResponse,
   > {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/runners");
 
     Request::<(), ActionsListSelfHostedRunnersForOrgQuery, ActionsListSelfHostedRunnersForOrgResponse>::builder(&self.config)
@@ -310,7 +310,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), RunnerApplicationArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/runners/downloads");
 
     Request::<(), (), RunnerApplicationArray>::builder(&self.config)
@@ -331,7 +331,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<ActionsGenerateRunnerJitconfigForOrgRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/runners/generate-jitconfig");
 
     NoContentRequest::<ActionsGenerateRunnerJitconfigForOrgRequest, ()>::builder(&self.config)
@@ -358,7 +358,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), AuthenticationToken> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/runners/registration-token");
 
     Request::<(), (), AuthenticationToken>::builder(&self.config)
@@ -385,7 +385,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), AuthenticationToken> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/runners/remove-token");
 
     Request::<(), (), AuthenticationToken>::builder(&self.config)
@@ -407,7 +407,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> Request<(), (), SelfHostedRunners> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let runner_id = runner_id.into();
     let url = format!("/orgs/{org}/actions/runners/{runner_id}");
 
@@ -430,7 +430,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let runner_id = runner_id.into();
     let url = format!("/orgs/{org}/actions/runners/{runner_id}");
 
@@ -453,7 +453,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let runner_id = runner_id.into();
     let url = format!("/orgs/{org}/actions/runners/{runner_id}/labels");
 
@@ -476,7 +476,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<ActionsAddCustomLabelsToSelfHostedRunnerForOrgRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let runner_id = runner_id.into();
     let url = format!("/orgs/{org}/actions/runners/{runner_id}/labels");
 
@@ -502,7 +502,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<ActionsSetCustomLabelsForSelfHostedRunnerForOrgRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let runner_id = runner_id.into();
     let url = format!("/orgs/{org}/actions/runners/{runner_id}/labels");
 
@@ -528,7 +528,7 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let runner_id = runner_id.into();
     let url = format!("/orgs/{org}/actions/runners/{runner_id}/labels");
 
@@ -556,9 +556,9 @@ impl GitHubActionsAPI {
     runner_id: impl Into<i64>,
     name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let runner_id = runner_id.into();
-    let name = name.into();
+    let name = encode_path_segment(name);
     let url = format!("/orgs/{org}/actions/runners/{runner_id}/labels/{name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -580,7 +580,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), ActionsListOrgSecretsQuery, ActionsListOrgSecretsResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/secrets");
 
     Request::<(), ActionsListOrgSecretsQuery, ActionsListOrgSecretsResponse>::builder(&self.config)
@@ -599,7 +599,7 @@ impl GitHubActionsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/actions/secrets#get-an-organization-public-key](https://docs.github.com/rest/actions/secrets#get-an-organization-public-key)
   pub fn get_org_public_key(&self, org: impl Into<String>) -> Request<(), (), ActionsPublicKey> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/secrets/public-key");
 
     Request::<(), (), ActionsPublicKey>::builder(&self.config)
@@ -621,8 +621,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<(), (), ActionsSecretForAnOrganization> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/actions/secrets/{secret_name}");
 
     Request::<(), (), ActionsSecretForAnOrganization>::builder(&self.config)
@@ -645,8 +645,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<ActionsCreateOrUpdateOrgSecretRequest, (), EmptyObject> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/actions/secrets/{secret_name}");
 
     Request::<ActionsCreateOrUpdateOrgSecretRequest, (), EmptyObject>::builder(&self.config)
@@ -668,8 +668,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/actions/secrets/{secret_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -696,8 +696,8 @@ impl GitHubActionsAPI {
     ActionsListSelectedReposForOrgSecretQuery,
     ActionsListSelectedReposForOrgSecretResponse,
   > {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/actions/secrets/{secret_name}/repositories");
 
     Request::<
@@ -725,8 +725,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<ActionsSetSelectedReposForOrgSecretRequest, ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/actions/secrets/{secret_name}/repositories");
 
     NoContentRequest::<ActionsSetSelectedReposForOrgSecretRequest, ()>::builder(&self.config)
@@ -751,8 +751,8 @@ impl GitHubActionsAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -778,8 +778,8 @@ impl GitHubActionsAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -801,7 +801,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), ActionsListOrgVariablesQuery, ActionsListOrgVariablesResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/variables");
 
     Request::<(), ActionsListOrgVariablesQuery, ActionsListOrgVariablesResponse>::builder(
@@ -824,7 +824,7 @@ impl GitHubActionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<ActionsCreateOrgVariableRequest, (), EmptyObject> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/variables");
 
     Request::<ActionsCreateOrgVariableRequest, (), EmptyObject>::builder(&self.config)
@@ -846,8 +846,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     name: impl Into<String>,
   ) -> Request<(), (), ActionsVariableForAnOrganization> {
-    let org = org.into();
-    let name = name.into();
+    let org = encode_path_segment(org);
+    let name = encode_path_segment(name);
     let url = format!("/orgs/{org}/actions/variables/{name}");
 
     Request::<(), (), ActionsVariableForAnOrganization>::builder(&self.config)
@@ -869,8 +869,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     name: impl Into<String>,
   ) -> NoContentRequest<ActionsUpdateOrgVariableRequest, ()> {
-    let org = org.into();
-    let name = name.into();
+    let org = encode_path_segment(org);
+    let name = encode_path_segment(name);
     let url = format!("/orgs/{org}/actions/variables/{name}");
 
     NoContentRequest::<ActionsUpdateOrgVariableRequest, ()>::builder(&self.config)
@@ -892,8 +892,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let name = name.into();
+    let org = encode_path_segment(org);
+    let name = encode_path_segment(name);
     let url = format!("/orgs/{org}/actions/variables/{name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -920,8 +920,8 @@ impl GitHubActionsAPI {
     ActionsListSelectedReposForOrgVariableQuery,
     ActionsListSelectedReposForOrgVariableResponse,
   > {
-    let org = org.into();
-    let name = name.into();
+    let org = encode_path_segment(org);
+    let name = encode_path_segment(name);
     let url = format!("/orgs/{org}/actions/variables/{name}/repositories");
 
     Request::<
@@ -949,8 +949,8 @@ impl GitHubActionsAPI {
     org: impl Into<String>,
     name: impl Into<String>,
   ) -> NoContentRequest<ActionsSetSelectedReposForOrgVariableRequest, ()> {
-    let org = org.into();
-    let name = name.into();
+    let org = encode_path_segment(org);
+    let name = encode_path_segment(name);
     let url = format!("/orgs/{org}/actions/variables/{name}/repositories");
 
     NoContentRequest::<ActionsSetSelectedReposForOrgVariableRequest, ()>::builder(&self.config)
@@ -974,8 +974,8 @@ impl GitHubActionsAPI {
     name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let name = name.into();
+    let org = encode_path_segment(org);
+    let name = encode_path_segment(name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/actions/variables/{name}/repositories/{repository_id}");
 
@@ -1001,8 +1001,8 @@ impl GitHubActionsAPI {
     name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let name = name.into();
+    let org = encode_path_segment(org);
+    let name = encode_path_segment(name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/actions/variables/{name}/repositories/{repository_id}");
 
@@ -1025,8 +1025,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActionsListArtifactsForRepoQuery, ActionsListArtifactsForRepoResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/artifacts");
 
     Request::<(), ActionsListArtifactsForRepoQuery, ActionsListArtifactsForRepoResponse>::builder(
@@ -1051,8 +1051,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     artifact_id: impl Into<i64>,
   ) -> Request<(), (), Artifact> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let artifact_id = artifact_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/artifacts/{artifact_id}");
 
@@ -1073,8 +1073,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     artifact_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let artifact_id = artifact_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/artifacts/{artifact_id}");
 
@@ -1097,14 +1097,14 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     artifact_id: impl Into<i64>,
     archive_format: impl Into<String>,
-  ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+  ) -> DownloadRequest<()> {
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let artifact_id = artifact_id.into();
-    let archive_format = archive_format.into();
+    let archive_format = encode_path_segment(archive_format);
     let url = format!("/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -1124,8 +1124,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ActionsCacheUsageByRepository> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/cache/usage");
 
     Request::<(), (), ActionsCacheUsageByRepository>::builder(&self.config)
@@ -1145,8 +1145,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActionsGetActionsCacheListQuery, RepositoryActionsCaches> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/caches");
 
     Request::<(), ActionsGetActionsCacheListQuery, RepositoryActionsCaches>::builder(&self.config)
@@ -1166,8 +1166,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActionsDeleteActionsCacheByKeyQuery, RepositoryActionsCaches> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/caches");
 
     Request::<(), ActionsDeleteActionsCacheByKeyQuery, RepositoryActionsCaches>::builder(
@@ -1190,8 +1190,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     cache_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let cache_id = cache_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/caches/{cache_id}");
 
@@ -1215,8 +1215,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     job_id: impl Into<i64>,
   ) -> Request<(), (), Job> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let job_id = job_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/jobs/{job_id}");
 
@@ -1240,13 +1240,13 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
     job_id: impl Into<i64>,
-  ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+  ) -> DownloadRequest<()> {
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let job_id = job_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/jobs/{job_id}/logs");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -1264,8 +1264,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     job_id: impl Into<i64>,
   ) -> Request<ActionsReRunJobForWorkflowRunRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let job_id = job_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/jobs/{job_id}/rerun");
 
@@ -1286,8 +1286,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ActionsOidcSubjectCustomizationForARepository> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/oidc/customization/sub");
 
     Request::<(), (), ActionsOidcSubjectCustomizationForARepository>::builder(&self.config)
@@ -1307,8 +1307,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ActionsOidcSubjectCustomizationForARepository, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/oidc/customization/sub");
 
     Request::<ActionsOidcSubjectCustomizationForARepository, (), EmptyObject>::builder(&self.config)
@@ -1335,8 +1335,8 @@ impl GitHubActionsAPI {
     ActionsListRepoOrganizationSecretsQuery,
     ActionsListRepoOrganizationSecretsResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/organization-secrets");
 
     Request::<(), ActionsListRepoOrganizationSecretsQuery, ActionsListRepoOrganizationSecretsResponse>::builder(&self.config)
@@ -1362,8 +1362,8 @@ impl GitHubActionsAPI {
     ActionsListRepoOrganizationVariablesQuery,
     ActionsListRepoOrganizationVariablesResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/organization-variables");
 
     Request::<
@@ -1387,8 +1387,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ActionsRepositoryPermissions> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions");
 
     Request::<(), (), ActionsRepositoryPermissions>::builder(&self.config)
@@ -1408,8 +1408,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<ActionsSetGithubActionsPermissionsRepositoryRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions");
 
     NoContentRequest::<ActionsSetGithubActionsPermissionsRepositoryRequest, ()>::builder(
@@ -1433,8 +1433,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ActionsWorkflowAccessToRepository> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions/access");
 
     Request::<(), (), ActionsWorkflowAccessToRepository>::builder(&self.config)
@@ -1456,8 +1456,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<ActionsWorkflowAccessToRepository, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions/access");
 
     NoContentRequest::<ActionsWorkflowAccessToRepository, ()>::builder(&self.config)
@@ -1477,8 +1477,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), SelectedActions> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions/selected-actions");
 
     Request::<(), (), SelectedActions>::builder(&self.config)
@@ -1498,8 +1498,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<SelectedActions, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions/selected-actions");
 
     NoContentRequest::<SelectedActions, ()>::builder(&self.config)
@@ -1521,8 +1521,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ActionsGetDefaultWorkflowPermissions> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions/workflow");
 
     Request::<(), (), ActionsGetDefaultWorkflowPermissions>::builder(&self.config)
@@ -1544,8 +1544,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<ActionsSetDefaultWorkflowPermissions, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/permissions/workflow");
 
     NoContentRequest::<ActionsSetDefaultWorkflowPermissions, ()>::builder(&self.config)
@@ -1571,8 +1571,8 @@ impl GitHubActionsAPI {
     ActionsListSelfHostedRunnersForRepoQuery,
     ActionsListSelfHostedRunnersForRepoResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/runners");
 
     Request::<
@@ -1598,8 +1598,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), RunnerApplicationArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/runners/downloads");
 
     Request::<(), (), RunnerApplicationArray>::builder(&self.config)
@@ -1621,8 +1621,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<ActionsGenerateRunnerJitconfigForRepoRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/runners/generate-jitconfig");
 
     NoContentRequest::<ActionsGenerateRunnerJitconfigForRepoRequest, ()>::builder(&self.config)
@@ -1650,8 +1650,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), AuthenticationToken> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/runners/registration-token");
 
     Request::<(), (), AuthenticationToken>::builder(&self.config)
@@ -1679,8 +1679,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), AuthenticationToken> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/runners/remove-token");
 
     Request::<(), (), AuthenticationToken>::builder(&self.config)
@@ -1703,8 +1703,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> Request<(), (), SelfHostedRunners> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let runner_id = runner_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runners/{runner_id}");
 
@@ -1728,8 +1728,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let runner_id = runner_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runners/{runner_id}");
 
@@ -1753,8 +1753,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let runner_id = runner_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runners/{runner_id}/labels");
 
@@ -1778,8 +1778,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<ActionsAddCustomLabelsToSelfHostedRunnerForRepoRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let runner_id = runner_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runners/{runner_id}/labels");
 
@@ -1806,8 +1806,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<ActionsSetCustomLabelsForSelfHostedRunnerForRepoRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let runner_id = runner_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runners/{runner_id}/labels");
 
@@ -1834,8 +1834,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     runner_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let runner_id = runner_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runners/{runner_id}/labels");
 
@@ -1864,10 +1864,10 @@ impl GitHubActionsAPI {
     runner_id: impl Into<i64>,
     name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let runner_id = runner_id.into();
-    let name = name.into();
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/actions/runners/{runner_id}/labels/{name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1891,8 +1891,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActionsListWorkflowRunsForRepoQuery, ActionsListWorkflowRunsForRepoResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/runs");
 
     Request::<(), ActionsListWorkflowRunsForRepoQuery, ActionsListWorkflowRunsForRepoResponse>::builder(&self.config)
@@ -1915,8 +1915,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), ActionsGetWorkflowRunQuery, WorkflowRun> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}");
 
@@ -1940,8 +1940,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}");
 
@@ -1963,8 +1963,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), (), EnvironmentApprovalArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/approvals");
 
@@ -1986,8 +1986,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/approve");
 
@@ -2012,8 +2012,8 @@ impl GitHubActionsAPI {
     run_id: impl Into<i64>,
   ) -> Request<(), ActionsListWorkflowRunArtifactsQuery, ActionsListWorkflowRunArtifactsResponse>
   {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts");
 
@@ -2038,8 +2038,8 @@ impl GitHubActionsAPI {
     run_id: impl Into<i64>,
     attempt_number: impl Into<i64>,
   ) -> Request<(), ActionsGetWorkflowRunAttemptQuery, WorkflowRun> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let attempt_number = attempt_number.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}");
@@ -2070,8 +2070,8 @@ impl GitHubActionsAPI {
     ActionsListJobsForWorkflowRunAttemptQuery,
     ActionsListJobsForWorkflowRunAttemptResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let attempt_number = attempt_number.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/jobs");
@@ -2101,14 +2101,14 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
     attempt_number: impl Into<i64>,
-  ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+  ) -> DownloadRequest<()> {
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let attempt_number = attempt_number.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/logs");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -2126,8 +2126,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/cancel");
 
@@ -2152,8 +2152,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> NoContentRequest<ActionsReviewCustomGatesForRunRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/deployment_protection_rule");
 
@@ -2176,8 +2176,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/force-cancel");
 
@@ -2202,8 +2202,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), ActionsListJobsForWorkflowRunQuery, ActionsListJobsForWorkflowRunResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/jobs");
 
@@ -2227,13 +2227,13 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
     run_id: impl Into<i64>,
-  ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+  ) -> DownloadRequest<()> {
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/logs");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -2251,8 +2251,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/logs");
 
@@ -2276,8 +2276,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), (), PendingDeploymentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments");
 
@@ -2301,8 +2301,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<ActionsReviewPendingDeploymentsForRunRequest, (), DeploymentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments");
 
@@ -2326,8 +2326,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<ActionsReRunWorkflowRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/rerun");
 
@@ -2349,8 +2349,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<ActionsReRunWorkflowFailedJobsRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/rerun-failed-jobs");
 
@@ -2374,8 +2374,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     run_id: impl Into<i64>,
   ) -> Request<(), (), WorkflowRunUsage> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let run_id = run_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/runs/{run_id}/timing");
 
@@ -2399,8 +2399,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActionsListRepoSecretsQuery, ActionsListRepoSecretsResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/secrets");
 
     Request::<(), ActionsListRepoSecretsQuery, ActionsListRepoSecretsResponse>::builder(
@@ -2425,8 +2425,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ActionsPublicKey> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/secrets/public-key");
 
     Request::<(), (), ActionsPublicKey>::builder(&self.config)
@@ -2449,9 +2449,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<(), (), ActionsSecret> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/actions/secrets/{secret_name}");
 
     Request::<(), (), ActionsSecret>::builder(&self.config)
@@ -2475,9 +2475,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<ActionsCreateOrUpdateRepoSecretRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/actions/secrets/{secret_name}");
 
     Request::<ActionsCreateOrUpdateRepoSecretRequest, (), EmptyObject>::builder(&self.config)
@@ -2500,9 +2500,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/actions/secrets/{secret_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -2524,8 +2524,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActionsListRepoVariablesQuery, ActionsListRepoVariablesResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/variables");
 
     Request::<(), ActionsListRepoVariablesQuery, ActionsListRepoVariablesResponse>::builder(
@@ -2549,8 +2549,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ActionsCreateRepoVariableRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/variables");
 
     Request::<ActionsCreateRepoVariableRequest, (), EmptyObject>::builder(&self.config)
@@ -2573,9 +2573,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     name: impl Into<String>,
   ) -> Request<(), (), ActionsVariable> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/actions/variables/{name}");
 
     Request::<(), (), ActionsVariable>::builder(&self.config)
@@ -2598,9 +2598,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     name: impl Into<String>,
   ) -> NoContentRequest<ActionsUpdateRepoVariableRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/actions/variables/{name}");
 
     NoContentRequest::<ActionsUpdateRepoVariableRequest, ()>::builder(&self.config)
@@ -2623,9 +2623,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/actions/variables/{name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -2647,8 +2647,8 @@ impl GitHubActionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActionsListRepoWorkflowsQuery, ActionsListRepoWorkflowsResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/actions/workflows");
 
     Request::<(), ActionsListRepoWorkflowsQuery, ActionsListRepoWorkflowsResponse>::builder(
@@ -2674,8 +2674,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     workflow_id: impl Into<StringOrInteger>,
   ) -> Request<(), (), Workflow> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let workflow_id = workflow_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/workflows/{workflow_id}");
 
@@ -2697,8 +2697,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     workflow_id: impl Into<StringOrInteger>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let workflow_id = workflow_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/workflows/{workflow_id}/disable");
 
@@ -2722,8 +2722,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     workflow_id: impl Into<StringOrInteger>,
   ) -> NoContentRequest<ActionsCreateWorkflowDispatchRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let workflow_id = workflow_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches");
 
@@ -2745,8 +2745,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     workflow_id: impl Into<StringOrInteger>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let workflow_id = workflow_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/workflows/{workflow_id}/enable");
 
@@ -2770,8 +2770,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     workflow_id: impl Into<StringOrInteger>,
   ) -> Request<(), ActionsListWorkflowRunsQuery, ActionsListWorkflowRunsResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let workflow_id = workflow_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs");
 
@@ -2799,8 +2799,8 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     workflow_id: impl Into<StringOrInteger>,
   ) -> Request<(), (), WorkflowUsage> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let workflow_id = workflow_id.into();
     let url = format!("/repos/{owner}/{repo}/actions/workflows/{workflow_id}/timing");
 
@@ -2825,9 +2825,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> Request<(), ActionsListEnvironmentSecretsQuery, ActionsListEnvironmentSecretsResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/secrets");
 
     Request::<(), ActionsListEnvironmentSecretsQuery, ActionsListEnvironmentSecretsResponse>::builder(&self.config)
@@ -2851,9 +2851,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> Request<(), (), ActionsPublicKey> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/secrets/public-key");
 
     Request::<(), (), ActionsPublicKey>::builder(&self.config)
@@ -2877,10 +2877,10 @@ impl GitHubActionsAPI {
     environment_name: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<(), (), ActionsSecret> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
+    let secret_name = encode_path_segment(secret_name);
     let url =
       format!("/repos/{owner}/{repo}/environments/{environment_name}/secrets/{secret_name}");
 
@@ -2906,10 +2906,10 @@ impl GitHubActionsAPI {
     environment_name: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<ActionsCreateOrUpdateEnvironmentSecretRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
+    let secret_name = encode_path_segment(secret_name);
     let url =
       format!("/repos/{owner}/{repo}/environments/{environment_name}/secrets/{secret_name}");
 
@@ -2934,10 +2934,10 @@ impl GitHubActionsAPI {
     environment_name: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
+    let secret_name = encode_path_segment(secret_name);
     let url =
       format!("/repos/{owner}/{repo}/environments/{environment_name}/secrets/{secret_name}");
 
@@ -2962,9 +2962,9 @@ impl GitHubActionsAPI {
     environment_name: impl Into<String>,
   ) -> Request<(), ActionsListEnvironmentVariablesQuery, ActionsListEnvironmentVariablesResponse>
   {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/variables");
 
     Request::<(), ActionsListEnvironmentVariablesQuery, ActionsListEnvironmentVariablesResponse>::builder(&self.config)
@@ -2987,9 +2987,9 @@ impl GitHubActionsAPI {
     repo: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> Request<ActionsCreateEnvironmentVariableRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/variables");
 
     Request::<ActionsCreateEnvironmentVariableRequest, (), EmptyObject>::builder(&self.config)
@@ -3013,10 +3013,10 @@ impl GitHubActionsAPI {
     environment_name: impl Into<String>,
     name: impl Into<String>,
   ) -> Request<(), (), ActionsVariable> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
-    let name = name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/variables/{name}");
 
     Request::<(), (), ActionsVariable>::builder(&self.config)
@@ -3040,10 +3040,10 @@ impl GitHubActionsAPI {
     name: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> NoContentRequest<ActionsUpdateEnvironmentVariableRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/variables/{name}");
 
     NoContentRequest::<ActionsUpdateEnvironmentVariableRequest, ()>::builder(&self.config)
@@ -3067,10 +3067,10 @@ impl GitHubActionsAPI {
     name: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/variables/{name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -61,8 +61,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActivityListPublicEventsForRepoNetworkQuery, EventArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/networks/{owner}/{repo}/events");
 
     Request::<(), ActivityListPublicEventsForRepoNetworkQuery, EventArray>::builder(&self.config)
@@ -207,7 +207,7 @@ impl GitHubActivityAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), ActivityListPublicOrgEventsQuery, EventArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/events");
 
     Request::<(), ActivityListPublicOrgEventsQuery, EventArray>::builder(&self.config)
@@ -226,8 +226,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActivityListRepoEventsQuery, EventArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/events");
 
     Request::<(), ActivityListRepoEventsQuery, EventArray>::builder(&self.config)
@@ -245,8 +245,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActivityListRepoNotificationsForAuthenticatedUserQuery, ThreadArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/notifications");
 
     Request::<(), ActivityListRepoNotificationsForAuthenticatedUserQuery, ThreadArray>::builder(
@@ -270,8 +270,8 @@ impl GitHubActivityAPI {
     (),
     ActivityMarkRepoNotificationsAsReadResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/notifications");
 
     Request::<
@@ -297,8 +297,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActivityListStargazersForRepoQuery, ActivityListStargazersForRepoResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/stargazers");
 
     Request::<(), ActivityListStargazersForRepoQuery, ActivityListStargazersForRepoResponse>::builder(&self.config)
@@ -316,8 +316,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ActivityListWatchersForRepoQuery, SimpleUserArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/subscribers");
 
     Request::<(), ActivityListWatchersForRepoQuery, SimpleUserArray>::builder(&self.config)
@@ -335,8 +335,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), RepositoryInvitation> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/subscription");
 
     Request::<(), (), RepositoryInvitation>::builder(&self.config)
@@ -354,8 +354,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ActivitySetRepoSubscriptionRequest, (), RepositoryInvitation> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/subscription");
 
     Request::<ActivitySetRepoSubscriptionRequest, (), RepositoryInvitation>::builder(&self.config)
@@ -373,8 +373,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/subscription");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -413,8 +413,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/user/starred/{owner}/{repo}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -432,8 +432,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/user/starred/{owner}/{repo}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -451,8 +451,8 @@ impl GitHubActivityAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/user/starred/{owner}/{repo}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -484,7 +484,7 @@ impl GitHubActivityAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ActivityListEventsForAuthenticatedUserQuery, EventArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/events");
 
     Request::<(), ActivityListEventsForAuthenticatedUserQuery, EventArray>::builder(&self.config)
@@ -502,8 +502,8 @@ impl GitHubActivityAPI {
     username: impl Into<String>,
     org: impl Into<String>,
   ) -> Request<(), ActivityListOrgEventsForAuthenticatedUserQuery, EventArray> {
-    let username = username.into();
-    let org = org.into();
+    let username = encode_path_segment(username);
+    let org = encode_path_segment(org);
     let url = format!("/users/{username}/events/orgs/{org}");
 
     Request::<(), ActivityListOrgEventsForAuthenticatedUserQuery, EventArray>::builder(&self.config)
@@ -519,7 +519,7 @@ impl GitHubActivityAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ActivityListPublicEventsForUserQuery, EventArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/events/public");
 
     Request::<(), ActivityListPublicEventsForUserQuery, EventArray>::builder(&self.config)
@@ -536,7 +536,7 @@ impl GitHubActivityAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ActivityListReceivedEventsForUserQuery, EventArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/received_events");
 
     Request::<(), ActivityListReceivedEventsForUserQuery, EventArray>::builder(&self.config)
@@ -552,7 +552,7 @@ impl GitHubActivityAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ActivityListReceivedPublicEventsForUserQuery, EventArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/received_events/public");
 
     Request::<(), ActivityListReceivedPublicEventsForUserQuery, EventArray>::builder(&self.config)
@@ -573,7 +573,7 @@ impl GitHubActivityAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ActivityListReposStarredByUserQuery, ActivityListReposStarredByUserResponse> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/starred");
 
     Request::<(), ActivityListReposStarredByUserQuery, ActivityListReposStarredByUserResponse>::builder(&self.config)
@@ -590,7 +590,7 @@ impl GitHubActivityAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ActivityListReposWatchedByUserQuery, MinimalRepositoryArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/subscriptions");
 
     Request::<(), ActivityListReposWatchedByUserQuery, MinimalRepositoryArray>::builder(
@@ -600,3 +600,33 @@ impl GitHubActivityAPI {
     .build()
   }
 }
+
+/// Custom media types of `list_stargazers_for_repo`, for `Request::media_type`
+pub struct ActivityListStargazersForRepoMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ActivityListStargazersForRepoMediaType {
+  /// Includes a timestamp of when the star was created.
+  pub const Star: MediaType<ActivityListStargazersForRepoResponse> =
+    MediaType::new("application/vnd.github.star+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_repos_starred_by_authenticated_user`, for `Request::media_type`
+pub struct ActivityListReposStarredByAuthenticatedUserMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ActivityListReposStarredByAuthenticatedUserMediaType {
+  /// Includes a timestamp of when the star was created.
+  pub const Star: MediaType<RepositoryArray> =
+    MediaType::new("application/vnd.github.star+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_repos_starred_by_user`, for `Request::media_type`
+pub struct ActivityListReposStarredByUserMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ActivityListReposStarredByUserMediaType {
+  /// Includes a timestamp of when the star was created.
+  pub const Star: MediaType<ActivityListReposStarredByUserResponse> =
+    MediaType::new("application/vnd.github.star+json", PayloadFormat::Json);
+}
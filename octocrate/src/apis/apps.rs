@@ -38,7 +38,7 @@ impl GitHubAppsAPI {
     &self,
     code: impl Into<String>,
   ) -> Request<(), (), AppsCreateFromManifestResponse> {
-    let code = code.into();
+    let code = encode_path_segment(code);
     let url = format!("/app-manifests/{code}/conversions");
 
     Request::<(), (), AppsCreateFromManifestResponse>::builder(&self.config)
@@ -273,14 +273,15 @@ impl GitHubAppsAPI {
   /// *Documentation*: [https://docs.github.com/rest/apps/oauth-applications#delete-an-app-authorization](https://docs.github.com/rest/apps/oauth-applications#delete-an-app-authorization)
   pub fn delete_authorization(
     &self,
-    client_id: impl Into<String>,
+    credentials: &ClientCredentials,
   ) -> NoContentRequest<AppsDeleteAuthorizationRequest, ()> {
-    let client_id = client_id.into();
+    let client_id = encode_path_segment(credentials.client_id());
     let url = format!("/applications/{client_id}/grant");
 
     NoContentRequest::<AppsDeleteAuthorizationRequest, ()>::builder(&self.config)
       .delete(url)
       .build()
+      .token(credentials.clone())
   }
 
   /// **Check a token**
@@ -290,14 +291,15 @@ impl GitHubAppsAPI {
   /// *Documentation*: [https://docs.github.com/rest/apps/oauth-applications#check-a-token](https://docs.github.com/rest/apps/oauth-applications#check-a-token)
   pub fn check_token(
     &self,
-    client_id: impl Into<String>,
+    credentials: &ClientCredentials,
   ) -> Request<AppsCheckTokenRequest, (), Authorization> {
-    let client_id = client_id.into();
+    let client_id = encode_path_segment(credentials.client_id());
     let url = format!("/applications/{client_id}/token");
 
     Request::<AppsCheckTokenRequest, (), Authorization>::builder(&self.config)
       .post(url)
       .build()
+      .token(credentials.clone())
   }
 
   /// **Reset a token**
@@ -307,14 +309,15 @@ impl GitHubAppsAPI {
   /// *Documentation*: [https://docs.github.com/rest/apps/oauth-applications#reset-a-token](https://docs.github.com/rest/apps/oauth-applications#reset-a-token)
   pub fn reset_token(
     &self,
-    client_id: impl Into<String>,
+    credentials: &ClientCredentials,
   ) -> Request<AppsResetTokenRequest, (), Authorization> {
-    let client_id = client_id.into();
+    let client_id = encode_path_segment(credentials.client_id());
     let url = format!("/applications/{client_id}/token");
 
     Request::<AppsResetTokenRequest, (), Authorization>::builder(&self.config)
       .patch(url)
       .build()
+      .token(credentials.clone())
   }
 
   /// **Delete an app token**
@@ -324,14 +327,15 @@ impl GitHubAppsAPI {
   /// *Documentation*: [https://docs.github.com/rest/apps/oauth-applications#delete-an-app-token](https://docs.github.com/rest/apps/oauth-applications#delete-an-app-token)
   pub fn delete_token(
     &self,
-    client_id: impl Into<String>,
+    credentials: &ClientCredentials,
   ) -> NoContentRequest<AppsDeleteTokenRequest, ()> {
-    let client_id = client_id.into();
+    let client_id = encode_path_segment(credentials.client_id());
     let url = format!("/applications/{client_id}/token");
 
     NoContentRequest::<AppsDeleteTokenRequest, ()>::builder(&self.config)
       .delete(url)
       .build()
+      .token(credentials.clone())
   }
 
   /// **Create a scoped access token**
@@ -349,14 +353,15 @@ impl GitHubAppsAPI {
   /// *Documentation*: [https://docs.github.com/rest/apps/apps#create-a-scoped-access-token](https://docs.github.com/rest/apps/apps#create-a-scoped-access-token)
   pub fn scope_token(
     &self,
-    client_id: impl Into<String>,
+    credentials: &ClientCredentials,
   ) -> Request<AppsScopeTokenRequest, (), Authorization> {
-    let client_id = client_id.into();
+    let client_id = encode_path_segment(credentials.client_id());
     let url = format!("/applications/{client_id}/token/scoped");
 
     Request::<AppsScopeTokenRequest, (), Authorization>::builder(&self.config)
       .post(url)
       .build()
+      .token(credentials.clone())
   }
 
   /// **Get an app**
@@ -365,7 +370,7 @@ impl GitHubAppsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/apps/apps#get-an-app](https://docs.github.com/rest/apps/apps#get-an-app)
   pub fn get_by_slug(&self, app_slug: impl Into<String>) -> Request<(), (), GitHubApp> {
-    let app_slug = app_slug.into();
+    let app_slug = encode_path_segment(app_slug);
     let url = format!("/apps/{app_slug}");
 
     Request::<(), (), GitHubApp>::builder(&self.config)
@@ -529,7 +534,7 @@ impl GitHubAppsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/apps/apps#get-an-organization-installation-for-the-authenticated-app](https://docs.github.com/rest/apps/apps#get-an-organization-installation-for-the-authenticated-app)
   pub fn get_org_installation(&self, org: impl Into<String>) -> Request<(), (), Installation> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/installation");
 
     Request::<(), (), Installation>::builder(&self.config)
@@ -549,8 +554,8 @@ impl GitHubAppsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), Installation> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/installation");
 
     Request::<(), (), Installation>::builder(&self.config)
@@ -697,7 +702,7 @@ impl GitHubAppsAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), (), Installation> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/installation");
 
     Request::<(), (), Installation>::builder(&self.config)
@@ -27,7 +27,7 @@ impl GitHubBillingAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), ActionsBillingUsage> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/settings/billing/actions");
 
     Request::<(), (), ActionsBillingUsage>::builder(&self.config)
@@ -48,7 +48,7 @@ impl GitHubBillingAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), PackagesBillingUsage> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/settings/billing/packages");
 
     Request::<(), (), PackagesBillingUsage>::builder(&self.config)
@@ -69,7 +69,7 @@ impl GitHubBillingAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), CombinedBillingUsage> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/settings/billing/shared-storage");
 
     Request::<(), (), CombinedBillingUsage>::builder(&self.config)
@@ -90,7 +90,7 @@ impl GitHubBillingAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), (), ActionsBillingUsage> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/settings/billing/actions");
 
     Request::<(), (), ActionsBillingUsage>::builder(&self.config)
@@ -111,7 +111,7 @@ impl GitHubBillingAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), (), PackagesBillingUsage> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/settings/billing/packages");
 
     Request::<(), (), PackagesBillingUsage>::builder(&self.config)
@@ -132,7 +132,7 @@ impl GitHubBillingAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), (), CombinedBillingUsage> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/settings/billing/shared-storage");
 
     Request::<(), (), CombinedBillingUsage>::builder(&self.config)
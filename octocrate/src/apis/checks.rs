@@ -30,8 +30,8 @@ impl GitHubChecksAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ChecksCreateRequest, (), CheckRun> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/check-runs");
 
     Request::<ChecksCreateRequest, (), CheckRun>::builder(&self.config)
@@ -54,8 +54,8 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     check_run_id: impl Into<i64>,
   ) -> Request<(), (), CheckRun> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let check_run_id = check_run_id.into();
     let url = format!("/repos/{owner}/{repo}/check-runs/{check_run_id}");
 
@@ -79,8 +79,8 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     check_run_id: impl Into<i64>,
   ) -> Request<ChecksUpdateRequest, (), CheckRun> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let check_run_id = check_run_id.into();
     let url = format!("/repos/{owner}/{repo}/check-runs/{check_run_id}");
 
@@ -102,8 +102,8 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     check_run_id: impl Into<i64>,
   ) -> Request<(), ChecksListAnnotationsQuery, CheckAnnotationArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let check_run_id = check_run_id.into();
     let url = format!("/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations");
 
@@ -127,8 +127,8 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     check_run_id: impl Into<i64>,
   ) -> Request<(), (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let check_run_id = check_run_id.into();
     let url = format!("/repos/{owner}/{repo}/check-runs/{check_run_id}/rerequest");
 
@@ -151,8 +151,8 @@ impl GitHubChecksAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ChecksCreateSuiteRequest, (), CheckSuite> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/check-suites");
 
     Request::<ChecksCreateSuiteRequest, (), CheckSuite>::builder(&self.config)
@@ -171,8 +171,8 @@ impl GitHubChecksAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ChecksSetSuitesPreferencesRequest, (), CheckSuitePreference> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/check-suites/preferences");
 
     Request::<ChecksSetSuitesPreferencesRequest, (), CheckSuitePreference>::builder(&self.config)
@@ -195,8 +195,8 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     check_suite_id: impl Into<i64>,
   ) -> Request<(), (), CheckSuite> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let check_suite_id = check_suite_id.into();
     let url = format!("/repos/{owner}/{repo}/check-suites/{check_suite_id}");
 
@@ -220,8 +220,8 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     check_suite_id: impl Into<i64>,
   ) -> Request<(), ChecksListForSuiteQuery, ChecksListForSuiteResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let check_suite_id = check_suite_id.into();
     let url = format!("/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs");
 
@@ -243,8 +243,8 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     check_suite_id: impl Into<i64>,
   ) -> Request<(), (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let check_suite_id = check_suite_id.into();
     let url = format!("/repos/{owner}/{repo}/check-suites/{check_suite_id}/rerequest");
 
@@ -270,9 +270,9 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<(), ChecksListForRefQuery, ChecksListForRefResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/commits/{ref_}/check-runs");
 
     Request::<(), ChecksListForRefQuery, ChecksListForRefResponse>::builder(&self.config)
@@ -295,9 +295,9 @@ impl GitHubChecksAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<(), ChecksListSuitesForRefQuery, ChecksListSuitesForRefResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/commits/{ref_}/check-suites");
 
     Request::<(), ChecksListSuitesForRefQuery, ChecksListSuitesForRefResponse>::builder(
@@ -26,7 +26,7 @@ impl GitHubCodeScanningAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), CodeScanningListAlertsForOrgQuery, CodeScanningOrganizationAlertItemsArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/code-scanning/alerts");
 
     Request::<(), CodeScanningListAlertsForOrgQuery, CodeScanningOrganizationAlertItemsArray>::builder(&self.config)
@@ -50,8 +50,8 @@ impl GitHubCodeScanningAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), CodeScanningListAlertsForRepoQuery, CodeScanningAlertItemsArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/code-scanning/alerts");
 
     Request::<(), CodeScanningListAlertsForRepoQuery, CodeScanningAlertItemsArray>::builder(
@@ -74,8 +74,8 @@ impl GitHubCodeScanningAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<(), (), CodeScanningAlert> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}");
 
@@ -96,8 +96,8 @@ impl GitHubCodeScanningAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<CodeScanningUpdateAlertRequest, (), CodeScanningAlert> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}");
 
@@ -119,8 +119,8 @@ impl GitHubCodeScanningAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<(), CodeScanningListAlertInstancesQuery, CodeScanningAlertInstanceArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances");
 
@@ -155,8 +155,8 @@ impl GitHubCodeScanningAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), CodeScanningListRecentAnalysesQuery, CodeScanningAnalysisArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/code-scanning/analyses");
 
     Request::<(), CodeScanningListRecentAnalysesQuery, CodeScanningAnalysisArray>::builder(
@@ -193,8 +193,8 @@ impl GitHubCodeScanningAPI {
     repo: impl Into<String>,
     analysis_id: impl Into<i64>,
   ) -> Request<(), (), CodeScanningAnalysis> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let analysis_id = analysis_id.into();
     let url = format!("/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}");
 
@@ -277,8 +277,8 @@ impl GitHubCodeScanningAPI {
     repo: impl Into<String>,
     analysis_id: impl Into<i64>,
   ) -> Request<(), CodeScanningDeleteAnalysisQuery, AnalysisDeletion> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let analysis_id = analysis_id.into();
     let url = format!("/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}");
 
@@ -299,8 +299,8 @@ impl GitHubCodeScanningAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), CodeQLDatabaseArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/code-scanning/codeql/databases");
 
     Request::<(), (), CodeQLDatabaseArray>::builder(&self.config)
@@ -327,9 +327,9 @@ impl GitHubCodeScanningAPI {
     repo: impl Into<String>,
     language: impl Into<String>,
   ) -> Request<(), (), CodeQLDatabase> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let language = language.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let language = encode_path_segment(language);
     let url = format!("/repos/{owner}/{repo}/code-scanning/codeql/databases/{language}");
 
     Request::<(), (), CodeQLDatabase>::builder(&self.config)
@@ -349,8 +349,8 @@ impl GitHubCodeScanningAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), CodeScanningDefaultSetup> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/code-scanning/default-setup");
 
     Request::<(), (), CodeScanningDefaultSetup>::builder(&self.config)
@@ -370,8 +370,8 @@ impl GitHubCodeScanningAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<CodeScanningDefaultSetupUpdate, (), CodeScanningUpdateDefaultSetupResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/code-scanning/default-setup");
 
     Request::<CodeScanningDefaultSetupUpdate, (), CodeScanningUpdateDefaultSetupResponse>::builder(
@@ -422,8 +422,8 @@ impl GitHubCodeScanningAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<CodeScanningUploadSarifRequest, (), CodeScanningSarifsReceipt> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/code-scanning/sarifs");
 
     Request::<CodeScanningUploadSarifRequest, (), CodeScanningSarifsReceipt>::builder(&self.config)
@@ -443,9 +443,9 @@ impl GitHubCodeScanningAPI {
     repo: impl Into<String>,
     sarif_id: impl Into<String>,
   ) -> Request<(), (), CodeScanningSarifsStatus> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let sarif_id = sarif_id.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let sarif_id = encode_path_segment(sarif_id);
     let url = format!("/repos/{owner}/{repo}/code-scanning/sarifs/{sarif_id}");
 
     Request::<(), (), CodeScanningSarifsStatus>::builder(&self.config)
@@ -453,3 +453,13 @@ impl GitHubCodeScanningAPI {
       .build()
   }
 }
+
+/// Custom media types of `get_analysis`, for `Request::media_type`
+pub struct CodeScanningGetAnalysisMediaType;
+
+#[allow(non_upper_case_globals)]
+impl CodeScanningGetAnalysisMediaType {
+  /// Instead of returning a summary of the analysis, this endpoint returns a subset of the analysis data that was uploaded. The data is formatted as [SARIF version 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/cs01/sarif-v2.1.0-cs01.html). It also returns additional data such as the `github/alertNumber` and `github/alertUrl` properties.
+  pub const Sarif: MediaType<String> =
+    MediaType::new("application/sarif+json", PayloadFormat::Text);
+}
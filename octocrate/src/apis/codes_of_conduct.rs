@@ -32,7 +32,7 @@ impl GitHubCodesOfConductAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/codes-of-conduct/codes-of-conduct#get-a-code-of-conduct](https://docs.github.com/rest/codes-of-conduct/codes-of-conduct#get-a-code-of-conduct)
   pub fn get_conduct_code(&self, key: impl Into<String>) -> Request<(), (), CodeOfConduct> {
-    let key = key.into();
+    let key = encode_path_segment(key);
     let url = format!("/codes_of_conduct/{key}");
 
     Request::<(), (), CodeOfConduct>::builder(&self.config)
@@ -25,7 +25,7 @@ impl GitHubCodespacesAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), CodespacesListInOrganizationQuery, CodespacesListInOrganizationResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/codespaces");
 
     Request::<(), CodespacesListInOrganizationQuery, CodespacesListInOrganizationResponse>::builder(
@@ -45,7 +45,7 @@ impl GitHubCodespacesAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<CodespacesSetCodespacesAccessRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/codespaces/access");
 
     NoContentRequest::<CodespacesSetCodespacesAccessRequest, ()>::builder(&self.config)
@@ -67,7 +67,7 @@ impl GitHubCodespacesAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<CodespacesSetCodespacesAccessUsersRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/codespaces/access/selected_users");
 
     NoContentRequest::<CodespacesSetCodespacesAccessUsersRequest, ()>::builder(&self.config)
@@ -89,7 +89,7 @@ impl GitHubCodespacesAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<CodespacesDeleteCodespacesAccessUsersRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/codespaces/access/selected_users");
 
     NoContentRequest::<CodespacesDeleteCodespacesAccessUsersRequest, ()>::builder(&self.config)
@@ -109,7 +109,7 @@ impl GitHubCodespacesAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), CodespacesListOrgSecretsQuery, CodespacesListOrgSecretsResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/codespaces/secrets");
 
     Request::<(), CodespacesListOrgSecretsQuery, CodespacesListOrgSecretsResponse>::builder(
@@ -126,7 +126,7 @@ impl GitHubCodespacesAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/codespaces/organization-secrets#get-an-organization-public-key](https://docs.github.com/rest/codespaces/organization-secrets#get-an-organization-public-key)
   pub fn get_org_public_key(&self, org: impl Into<String>) -> Request<(), (), CodespacesPublicKey> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/codespaces/secrets/public-key");
 
     Request::<(), (), CodespacesPublicKey>::builder(&self.config)
@@ -146,8 +146,8 @@ impl GitHubCodespacesAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<(), (), CodespacesSecret> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/codespaces/secrets/{secret_name}");
 
     Request::<(), (), CodespacesSecret>::builder(&self.config)
@@ -168,8 +168,8 @@ impl GitHubCodespacesAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<CodespacesCreateOrUpdateOrgSecretRequest, (), EmptyObject> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/codespaces/secrets/{secret_name}");
 
     Request::<CodespacesCreateOrUpdateOrgSecretRequest, (), EmptyObject>::builder(&self.config)
@@ -189,8 +189,8 @@ impl GitHubCodespacesAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/codespaces/secrets/{secret_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -215,8 +215,8 @@ impl GitHubCodespacesAPI {
     CodespacesListSelectedReposForOrgSecretQuery,
     CodespacesListSelectedReposForOrgSecretResponse,
   > {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/codespaces/secrets/{secret_name}/repositories");
 
     Request::<
@@ -242,8 +242,8 @@ impl GitHubCodespacesAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<CodespacesSetSelectedReposForOrgSecretRequest, ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/codespaces/secrets/{secret_name}/repositories");
 
     NoContentRequest::<CodespacesSetSelectedReposForOrgSecretRequest, ()>::builder(&self.config)
@@ -263,8 +263,8 @@ impl GitHubCodespacesAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/codespaces/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -288,8 +288,8 @@ impl GitHubCodespacesAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/codespaces/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -314,8 +314,8 @@ impl GitHubCodespacesAPI {
     CodespacesGetCodespacesForUserInOrgQuery,
     CodespacesGetCodespacesForUserInOrgResponse,
   > {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/members/{username}/codespaces");
 
     Request::<
@@ -340,9 +340,9 @@ impl GitHubCodespacesAPI {
     username: impl Into<String>,
     codespace_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
-    let codespace_name = codespace_name.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/orgs/{org}/members/{username}/codespaces/{codespace_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -363,9 +363,9 @@ impl GitHubCodespacesAPI {
     username: impl Into<String>,
     codespace_name: impl Into<String>,
   ) -> Request<(), (), Codespace> {
-    let org = org.into();
-    let username = username.into();
-    let codespace_name = codespace_name.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/orgs/{org}/members/{username}/codespaces/{codespace_name}/stop");
 
     Request::<(), (), Codespace>::builder(&self.config)
@@ -389,8 +389,8 @@ impl GitHubCodespacesAPI {
     CodespacesListInRepositoryForAuthenticatedUserQuery,
     CodespacesListInRepositoryForAuthenticatedUserResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces");
 
     Request::<
@@ -414,8 +414,8 @@ impl GitHubCodespacesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<CodespacesCreateWithRepoForAuthenticatedUserRequest, (), Codespace> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces");
 
     Request::<CodespacesCreateWithRepoForAuthenticatedUserRequest, (), Codespace>::builder(
@@ -442,8 +442,8 @@ impl GitHubCodespacesAPI {
     CodespacesListDevcontainersInRepositoryForAuthenticatedUserQuery,
     CodespacesListDevcontainersInRepositoryForAuthenticatedUserResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces/devcontainers");
 
     Request::<
@@ -471,8 +471,8 @@ impl GitHubCodespacesAPI {
     CodespacesRepoMachinesForAuthenticatedUserQuery,
     CodespacesRepoMachinesForAuthenticatedUserResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces/machines");
 
     Request::<
@@ -500,8 +500,8 @@ impl GitHubCodespacesAPI {
     CodespacesPreFlightWithRepoForAuthenticatedUserQuery,
     CodespacesPreFlightWithRepoForAuthenticatedUserResponse,
   > {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces/new");
 
     Request::<
@@ -525,8 +525,8 @@ impl GitHubCodespacesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), CodespacesCheckPermissionsForDevcontainerQuery, CodespacesPermissionsCheck> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces/permissions_check");
 
     Request::<(), CodespacesCheckPermissionsForDevcontainerQuery, CodespacesPermissionsCheck>::builder(&self.config)
@@ -547,8 +547,8 @@ impl GitHubCodespacesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), CodespacesListRepoSecretsQuery, CodespacesListRepoSecretsResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces/secrets");
 
     Request::<(), CodespacesListRepoSecretsQuery, CodespacesListRepoSecretsResponse>::builder(
@@ -573,8 +573,8 @@ impl GitHubCodespacesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), CodespacesPublicKey> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codespaces/secrets/public-key");
 
     Request::<(), (), CodespacesPublicKey>::builder(&self.config)
@@ -595,9 +595,9 @@ impl GitHubCodespacesAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<(), (), CodespacesSecret> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/codespaces/secrets/{secret_name}");
 
     Request::<(), (), CodespacesSecret>::builder(&self.config)
@@ -619,9 +619,9 @@ impl GitHubCodespacesAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<CodespacesCreateOrUpdateRepoSecretRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/codespaces/secrets/{secret_name}");
 
     Request::<CodespacesCreateOrUpdateRepoSecretRequest, (), EmptyObject>::builder(&self.config)
@@ -642,9 +642,9 @@ impl GitHubCodespacesAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/codespaces/secrets/{secret_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -665,8 +665,8 @@ impl GitHubCodespacesAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<CodespacesCreateWithPrForAuthenticatedUserRequest, (), Codespace> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/codespaces");
 
@@ -775,7 +775,7 @@ impl GitHubCodespacesAPI {
     &self,
     secret_name: impl Into<String>,
   ) -> Request<(), (), CodespacesSecret> {
-    let secret_name = secret_name.into();
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/user/codespaces/secrets/{secret_name}");
 
     Request::<(), (), CodespacesSecret>::builder(&self.config)
@@ -797,7 +797,7 @@ impl GitHubCodespacesAPI {
     &self,
     secret_name: impl Into<String>,
   ) -> Request<CodespacesCreateOrUpdateSecretForAuthenticatedUserRequest, (), EmptyObject> {
-    let secret_name = secret_name.into();
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/user/codespaces/secrets/{secret_name}");
 
     Request::<CodespacesCreateOrUpdateSecretForAuthenticatedUserRequest, (), EmptyObject>::builder(
@@ -820,7 +820,7 @@ impl GitHubCodespacesAPI {
     &self,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let secret_name = secret_name.into();
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/user/codespaces/secrets/{secret_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -841,7 +841,7 @@ impl GitHubCodespacesAPI {
     &self,
     secret_name: impl Into<String>,
   ) -> Request<(), (), CodespacesListRepositoriesForSecretForAuthenticatedUserResponse> {
-    let secret_name = secret_name.into();
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/user/codespaces/secrets/{secret_name}/repositories");
 
     Request::<(), (), CodespacesListRepositoriesForSecretForAuthenticatedUserResponse>::builder(
@@ -864,7 +864,7 @@ impl GitHubCodespacesAPI {
     &self,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<CodespacesSetRepositoriesForSecretForAuthenticatedUserRequest, ()> {
-    let secret_name = secret_name.into();
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/user/codespaces/secrets/{secret_name}/repositories");
 
     NoContentRequest::<CodespacesSetRepositoriesForSecretForAuthenticatedUserRequest, ()>::builder(
@@ -888,7 +888,7 @@ impl GitHubCodespacesAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let secret_name = secret_name.into();
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/user/codespaces/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -911,7 +911,7 @@ impl GitHubCodespacesAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let secret_name = secret_name.into();
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/user/codespaces/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -931,7 +931,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> Request<(), (), Codespace> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}");
 
     Request::<(), (), Codespace>::builder(&self.config)
@@ -952,7 +952,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> Request<CodespacesUpdateForAuthenticatedUserRequest, (), Codespace> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}");
 
     Request::<CodespacesUpdateForAuthenticatedUserRequest, (), Codespace>::builder(&self.config)
@@ -971,7 +971,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -992,7 +992,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> Request<(), (), FetchesInformationAboutAnExportOfACodespace> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}/exports");
 
     Request::<(), (), FetchesInformationAboutAnExportOfACodespace>::builder(&self.config)
@@ -1012,8 +1012,8 @@ impl GitHubCodespacesAPI {
     codespace_name: impl Into<String>,
     export_id: impl Into<String>,
   ) -> Request<(), (), FetchesInformationAboutAnExportOfACodespace> {
-    let codespace_name = codespace_name.into();
-    let export_id = export_id.into();
+    let codespace_name = encode_path_segment(codespace_name);
+    let export_id = encode_path_segment(export_id);
     let url = format!("/user/codespaces/{codespace_name}/exports/{export_id}");
 
     Request::<(), (), FetchesInformationAboutAnExportOfACodespace>::builder(&self.config)
@@ -1032,7 +1032,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> Request<(), (), CodespacesCodespaceMachinesForAuthenticatedUserResponse> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}/machines");
 
     Request::<(), (), CodespacesCodespaceMachinesForAuthenticatedUserResponse>::builder(
@@ -1057,7 +1057,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> Request<CodespacesPublishForAuthenticatedUserRequest, (), Codespace> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}/publish");
 
     Request::<CodespacesPublishForAuthenticatedUserRequest, (), Codespace>::builder(&self.config)
@@ -1076,7 +1076,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> Request<(), (), Codespace> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}/start");
 
     Request::<(), (), Codespace>::builder(&self.config)
@@ -1095,7 +1095,7 @@ impl GitHubCodespacesAPI {
     &self,
     codespace_name: impl Into<String>,
   ) -> Request<(), (), Codespace> {
-    let codespace_name = codespace_name.into();
+    let codespace_name = encode_path_segment(codespace_name);
     let url = format!("/user/codespaces/{codespace_name}/stop");
 
     Request::<(), (), Codespace>::builder(&self.config)
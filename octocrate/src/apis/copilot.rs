@@ -31,7 +31,7 @@ impl GitHubCopilotAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), CopilotBusinessOrganizationDetails> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/copilot/billing");
 
     Request::<(), (), CopilotBusinessOrganizationDetails>::builder(&self.config)
@@ -54,7 +54,7 @@ impl GitHubCopilotAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), CopilotListCopilotSeatsQuery, CopilotListCopilotSeatsResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/copilot/billing/seats");
 
     Request::<(), CopilotListCopilotSeatsQuery, CopilotListCopilotSeatsResponse>::builder(
@@ -85,7 +85,7 @@ impl GitHubCopilotAPI {
     org: impl Into<String>,
   ) -> Request<CopilotAddCopilotSeatsForTeamsRequest, (), CopilotAddCopilotSeatsForTeamsResponse>
   {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/copilot/billing/selected_teams");
 
     Request::<CopilotAddCopilotSeatsForTeamsRequest, (), CopilotAddCopilotSeatsForTeamsResponse>::builder(&self.config)
@@ -117,7 +117,7 @@ impl GitHubCopilotAPI {
     (),
     CopilotCancelCopilotSeatAssignmentForTeamsResponse,
   > {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/copilot/billing/selected_teams");
 
     Request::<
@@ -150,7 +150,7 @@ impl GitHubCopilotAPI {
     org: impl Into<String>,
   ) -> Request<CopilotAddCopilotSeatsForUsersRequest, (), CopilotAddCopilotSeatsForUsersResponse>
   {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/copilot/billing/selected_users");
 
     Request::<CopilotAddCopilotSeatsForUsersRequest, (), CopilotAddCopilotSeatsForUsersResponse>::builder(&self.config)
@@ -182,7 +182,7 @@ impl GitHubCopilotAPI {
     (),
     CopilotCancelCopilotSeatAssignmentForUsersResponse,
   > {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/copilot/billing/selected_users");
 
     Request::<
@@ -210,8 +210,8 @@ impl GitHubCopilotAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> Request<(), (), CopilotBusinessSeatDetail> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/members/{username}/copilot");
 
     Request::<(), (), CopilotBusinessSeatDetail>::builder(&self.config)
@@ -29,7 +29,7 @@ impl GitHubDependabotAPI {
     &self,
     enterprise: impl Into<String>,
   ) -> Request<(), DependabotListAlertsForEnterpriseQuery, DependabotAlertWithRepositoryArray> {
-    let enterprise = enterprise.into();
+    let enterprise = encode_path_segment(enterprise);
     let url = format!("/enterprises/{enterprise}/dependabot/alerts");
 
     Request::<(), DependabotListAlertsForEnterpriseQuery, DependabotAlertWithRepositoryArray>::builder(&self.config)
@@ -50,7 +50,7 @@ impl GitHubDependabotAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), DependabotListAlertsForOrgQuery, DependabotAlertWithRepositoryArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/dependabot/alerts");
 
     Request::<(), DependabotListAlertsForOrgQuery, DependabotAlertWithRepositoryArray>::builder(
@@ -72,7 +72,7 @@ impl GitHubDependabotAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), DependabotListOrgSecretsQuery, DependabotListOrgSecretsResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/dependabot/secrets");
 
     Request::<(), DependabotListOrgSecretsQuery, DependabotListOrgSecretsResponse>::builder(
@@ -91,7 +91,7 @@ impl GitHubDependabotAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/dependabot/secrets#get-an-organization-public-key](https://docs.github.com/rest/dependabot/secrets#get-an-organization-public-key)
   pub fn get_org_public_key(&self, org: impl Into<String>) -> Request<(), (), DependabotPublicKey> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/dependabot/secrets/public-key");
 
     Request::<(), (), DependabotPublicKey>::builder(&self.config)
@@ -111,8 +111,8 @@ impl GitHubDependabotAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<(), (), DependabotSecretForAnOrganization> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/dependabot/secrets/{secret_name}");
 
     Request::<(), (), DependabotSecretForAnOrganization>::builder(&self.config)
@@ -133,8 +133,8 @@ impl GitHubDependabotAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<DependabotCreateOrUpdateOrgSecretRequest, (), EmptyObject> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/dependabot/secrets/{secret_name}");
 
     Request::<DependabotCreateOrUpdateOrgSecretRequest, (), EmptyObject>::builder(&self.config)
@@ -154,8 +154,8 @@ impl GitHubDependabotAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/dependabot/secrets/{secret_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -180,8 +180,8 @@ impl GitHubDependabotAPI {
     DependabotListSelectedReposForOrgSecretQuery,
     DependabotListSelectedReposForOrgSecretResponse,
   > {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/dependabot/secrets/{secret_name}/repositories");
 
     Request::<
@@ -207,8 +207,8 @@ impl GitHubDependabotAPI {
     org: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<DependabotSetSelectedReposForOrgSecretRequest, ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/orgs/{org}/dependabot/secrets/{secret_name}/repositories");
 
     NoContentRequest::<DependabotSetSelectedReposForOrgSecretRequest, ()>::builder(&self.config)
@@ -231,8 +231,8 @@ impl GitHubDependabotAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -256,8 +256,8 @@ impl GitHubDependabotAPI {
     secret_name: impl Into<String>,
     repository_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let secret_name = secret_name.into();
+    let org = encode_path_segment(org);
+    let secret_name = encode_path_segment(secret_name);
     let repository_id = repository_id.into();
     let url = format!("/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}");
 
@@ -276,8 +276,8 @@ impl GitHubDependabotAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), DependabotListAlertsForRepoQuery, DependabotAlertArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/dependabot/alerts");
 
     Request::<(), DependabotListAlertsForRepoQuery, DependabotAlertArray>::builder(&self.config)
@@ -296,8 +296,8 @@ impl GitHubDependabotAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<(), (), DependabotAlert> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/dependabot/alerts/{alert_number}");
 
@@ -319,8 +319,8 @@ impl GitHubDependabotAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<DependabotUpdateAlertRequest, (), DependabotAlert> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/dependabot/alerts/{alert_number}");
 
@@ -342,8 +342,8 @@ impl GitHubDependabotAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), DependabotListRepoSecretsQuery, DependabotListRepoSecretsResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/dependabot/secrets");
 
     Request::<(), DependabotListRepoSecretsQuery, DependabotListRepoSecretsResponse>::builder(
@@ -367,8 +367,8 @@ impl GitHubDependabotAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), DependabotPublicKey> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/dependabot/secrets/public-key");
 
     Request::<(), (), DependabotPublicKey>::builder(&self.config)
@@ -389,9 +389,9 @@ impl GitHubDependabotAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<(), (), DependabotSecret> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/dependabot/secrets/{secret_name}");
 
     Request::<(), (), DependabotSecret>::builder(&self.config)
@@ -413,9 +413,9 @@ impl GitHubDependabotAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> Request<DependabotCreateOrUpdateRepoSecretRequest, (), EmptyObject> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/dependabot/secrets/{secret_name}");
 
     Request::<DependabotCreateOrUpdateRepoSecretRequest, (), EmptyObject>::builder(&self.config)
@@ -436,9 +436,9 @@ impl GitHubDependabotAPI {
     repo: impl Into<String>,
     secret_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let secret_name = secret_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let secret_name = encode_path_segment(secret_name);
     let url = format!("/repos/{owner}/{repo}/dependabot/secrets/{secret_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
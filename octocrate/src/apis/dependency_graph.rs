@@ -24,9 +24,9 @@ impl GitHubDependencyGraphAPI {
     repo: impl Into<String>,
     basehead: impl Into<String>,
   ) -> Request<(), DependencyGraphDiffRangeQuery, DependencyGraphDiff> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let basehead = basehead.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let basehead = encode_path(basehead);
     let url = format!("/repos/{owner}/{repo}/dependency-graph/compare/{basehead}");
 
     Request::<(), DependencyGraphDiffRangeQuery, DependencyGraphDiff>::builder(&self.config)
@@ -44,8 +44,8 @@ impl GitHubDependencyGraphAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), DependencyGraphSpdxSbom> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/dependency-graph/sbom");
 
     Request::<(), (), DependencyGraphSpdxSbom>::builder(&self.config)
@@ -67,8 +67,8 @@ impl GitHubDependencyGraphAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<Snapshot, (), DependencyGraphCreateRepositorySnapshotResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/dependency-graph/snapshots");
 
     Request::<Snapshot, (), DependencyGraphCreateRepositorySnapshotResponse>::builder(&self.config)
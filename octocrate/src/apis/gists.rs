@@ -81,7 +81,7 @@ impl GitHubGistsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gists/gists#get-a-gist](https://docs.github.com/rest/gists/gists#get-a-gist)
   pub fn get(&self, gist_id: impl Into<String>) -> Request<(), (), GistSimple> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}");
 
     Request::<(), (), GistSimple>::builder(&self.config)
@@ -104,7 +104,7 @@ impl GitHubGistsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gists/gists#update-a-gist](https://docs.github.com/rest/gists/gists#update-a-gist)
   pub fn update(&self, gist_id: impl Into<String>) -> Request<GistsUpdateRequest, (), GistSimple> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}");
 
     Request::<GistsUpdateRequest, (), GistSimple>::builder(&self.config)
@@ -117,7 +117,7 @@ impl GitHubGistsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gists/gists#delete-a-gist](https://docs.github.com/rest/gists/gists#delete-a-gist)
   pub fn delete(&self, gist_id: impl Into<String>) -> NoContentRequest<(), ()> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -139,7 +139,7 @@ impl GitHubGistsAPI {
     &self,
     gist_id: impl Into<String>,
   ) -> Request<(), GistsListCommentsQuery, GistCommentArray> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/comments");
 
     Request::<(), GistsListCommentsQuery, GistCommentArray>::builder(&self.config)
@@ -161,7 +161,7 @@ impl GitHubGistsAPI {
     &self,
     gist_id: impl Into<String>,
   ) -> Request<GistsCreateCommentRequest, (), GistComment> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/comments");
 
     Request::<GistsCreateCommentRequest, (), GistComment>::builder(&self.config)
@@ -184,7 +184,7 @@ impl GitHubGistsAPI {
     gist_id: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<(), (), GistComment> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let comment_id = comment_id.into();
     let url = format!("/gists/{gist_id}/comments/{comment_id}");
 
@@ -208,7 +208,7 @@ impl GitHubGistsAPI {
     gist_id: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<GistsUpdateCommentRequest, (), GistComment> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let comment_id = comment_id.into();
     let url = format!("/gists/{gist_id}/comments/{comment_id}");
 
@@ -226,7 +226,7 @@ impl GitHubGistsAPI {
     gist_id: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let comment_id = comment_id.into();
     let url = format!("/gists/{gist_id}/comments/{comment_id}");
 
@@ -243,7 +243,7 @@ impl GitHubGistsAPI {
     &self,
     gist_id: impl Into<String>,
   ) -> Request<(), GistsListCommitsQuery, GistCommitArray> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/commits");
 
     Request::<(), GistsListCommitsQuery, GistCommitArray>::builder(&self.config)
@@ -259,7 +259,7 @@ impl GitHubGistsAPI {
     &self,
     gist_id: impl Into<String>,
   ) -> Request<(), GistsListForksQuery, GistSimpleArray> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/forks");
 
     Request::<(), GistsListForksQuery, GistSimpleArray>::builder(&self.config)
@@ -272,7 +272,7 @@ impl GitHubGistsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gists/gists#fork-a-gist](https://docs.github.com/rest/gists/gists#fork-a-gist)
   pub fn fork(&self, gist_id: impl Into<String>) -> Request<(), (), BaseGist> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/forks");
 
     Request::<(), (), BaseGist>::builder(&self.config)
@@ -285,7 +285,7 @@ impl GitHubGistsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gists/gists#check-if-a-gist-is-starred](https://docs.github.com/rest/gists/gists#check-if-a-gist-is-starred)
   pub fn check_is_starred(&self, gist_id: impl Into<String>) -> NoContentRequest<(), ()> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/star");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -299,7 +299,7 @@ impl GitHubGistsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gists/gists#star-a-gist](https://docs.github.com/rest/gists/gists#star-a-gist)
   pub fn star(&self, gist_id: impl Into<String>) -> NoContentRequest<(), ()> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/star");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -312,7 +312,7 @@ impl GitHubGistsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gists/gists#unstar-a-gist](https://docs.github.com/rest/gists/gists#unstar-a-gist)
   pub fn unstar(&self, gist_id: impl Into<String>) -> NoContentRequest<(), ()> {
-    let gist_id = gist_id.into();
+    let gist_id = encode_path_segment(gist_id);
     let url = format!("/gists/{gist_id}/star");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -335,8 +335,8 @@ impl GitHubGistsAPI {
     gist_id: impl Into<String>,
     sha: impl Into<String>,
   ) -> Request<(), (), GistSimple> {
-    let gist_id = gist_id.into();
-    let sha = sha.into();
+    let gist_id = encode_path_segment(gist_id);
+    let sha = encode_path_segment(sha);
     let url = format!("/gists/{gist_id}/{sha}");
 
     Request::<(), (), GistSimple>::builder(&self.config)
@@ -353,7 +353,7 @@ impl GitHubGistsAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), GistsListForUserQuery, BaseGistArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/gists");
 
     Request::<(), GistsListForUserQuery, BaseGistArray>::builder(&self.config)
@@ -361,3 +361,101 @@ impl GitHubGistsAPI {
       .build()
   }
 }
+
+/// Custom media types of `get`, for `Request::media_type`
+pub struct GistsGetMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GistsGetMediaType {
+  /// Returns the raw markdown. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<GistSimple> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns the base64-encoded contents. This can be useful if your gist contains any invalid UTF-8 sequences.
+  pub const Base64: MediaType<GistSimple> =
+    MediaType::new("application/vnd.github.base64+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `update`, for `Request::media_type`
+pub struct GistsUpdateMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GistsUpdateMediaType {
+  /// Returns the raw markdown. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<GistSimple> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns the base64-encoded contents. This can be useful if your gist contains any invalid UTF-8 sequences.
+  pub const Base64: MediaType<GistSimple> =
+    MediaType::new("application/vnd.github.base64+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_comments`, for `Request::media_type`
+pub struct GistsListCommentsMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GistsListCommentsMediaType {
+  /// Returns the raw markdown. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<GistCommentArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns the base64-encoded contents. This can be useful if your gist contains any invalid UTF-8 sequences.
+  pub const Base64: MediaType<GistCommentArray> =
+    MediaType::new("application/vnd.github.base64+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `create_comment`, for `Request::media_type`
+pub struct GistsCreateCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GistsCreateCommentMediaType {
+  /// Returns the raw markdown. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<GistComment> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns the base64-encoded contents. This can be useful if your gist contains any invalid UTF-8 sequences.
+  pub const Base64: MediaType<GistComment> =
+    MediaType::new("application/vnd.github.base64+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `get_comment`, for `Request::media_type`
+pub struct GistsGetCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GistsGetCommentMediaType {
+  /// Returns the raw markdown. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<GistComment> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns the base64-encoded contents. This can be useful if your gist contains any invalid UTF-8 sequences.
+  pub const Base64: MediaType<GistComment> =
+    MediaType::new("application/vnd.github.base64+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `update_comment`, for `Request::media_type`
+pub struct GistsUpdateCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GistsUpdateCommentMediaType {
+  /// Returns the raw markdown. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<GistComment> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns the base64-encoded contents. This can be useful if your gist contains any invalid UTF-8 sequences.
+  pub const Base64: MediaType<GistComment> =
+    MediaType::new("application/vnd.github.base64+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `get_revision`, for `Request::media_type`
+pub struct GistsGetRevisionMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GistsGetRevisionMediaType {
+  /// Returns the raw markdown. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<GistSimple> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns the base64-encoded contents. This can be useful if your gist contains any invalid UTF-8 sequences.
+  pub const Base64: MediaType<GistSimple> =
+    MediaType::new("application/vnd.github.base64+json", PayloadFormat::Json);
+}
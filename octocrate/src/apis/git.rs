@@ -23,8 +23,8 @@ impl GitHubGitAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<GitCreateBlobRequest, (), ShortBlob> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/git/blobs");
 
     Request::<GitCreateBlobRequest, (), ShortBlob>::builder(&self.config)
@@ -50,9 +50,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     file_sha: impl Into<String>,
   ) -> Request<(), (), Blob> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let file_sha = file_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let file_sha = encode_path_segment(file_sha);
     let url = format!("/repos/{owner}/{repo}/git/blobs/{file_sha}");
 
     Request::<(), (), Blob>::builder(&self.config)
@@ -99,8 +99,8 @@ impl GitHubGitAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<GitCreateCommitRequest, (), GitCommit> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/git/commits");
 
     Request::<GitCreateCommitRequest, (), GitCommit>::builder(&self.config)
@@ -150,9 +150,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     commit_sha: impl Into<String>,
   ) -> Request<(), (), GitCommit> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let commit_sha = commit_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let commit_sha = encode_path_segment(commit_sha);
     let url = format!("/repos/{owner}/{repo}/git/commits/{commit_sha}");
 
     Request::<(), (), GitCommit>::builder(&self.config)
@@ -177,9 +177,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<(), (), GitReferenceArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/git/matching-refs/{ref_}");
 
     Request::<(), (), GitReferenceArray>::builder(&self.config)
@@ -200,9 +200,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<(), (), GitReference> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/git/ref/{ref_}");
 
     Request::<(), (), GitReference>::builder(&self.config)
@@ -220,8 +220,8 @@ impl GitHubGitAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<GitCreateRefRequest, (), GitReference> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/git/refs");
 
     Request::<GitCreateRefRequest, (), GitReference>::builder(&self.config)
@@ -240,9 +240,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<GitUpdateRefRequest, (), GitReference> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/git/refs/{ref_}");
 
     Request::<GitUpdateRefRequest, (), GitReference>::builder(&self.config)
@@ -261,9 +261,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/git/refs/{ref_}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -310,8 +310,8 @@ impl GitHubGitAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<GitCreateTagRequest, (), GitTag> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/git/tags");
 
     Request::<GitCreateTagRequest, (), GitTag>::builder(&self.config)
@@ -357,9 +357,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     tag_sha: impl Into<String>,
   ) -> Request<(), (), GitTag> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let tag_sha = tag_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let tag_sha = encode_path_segment(tag_sha);
     let url = format!("/repos/{owner}/{repo}/git/tags/{tag_sha}");
 
     Request::<(), (), GitTag>::builder(&self.config)
@@ -381,8 +381,8 @@ impl GitHubGitAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<GitCreateTreeRequest, (), GitTree> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/git/trees");
 
     Request::<GitCreateTreeRequest, (), GitTree>::builder(&self.config)
@@ -406,9 +406,9 @@ impl GitHubGitAPI {
     repo: impl Into<String>,
     tree_sha: impl Into<String>,
   ) -> Request<(), GitGetTreeQuery, GitTree> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let tree_sha = tree_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let tree_sha = encode_path_segment(tree_sha);
     let url = format!("/repos/{owner}/{repo}/git/trees/{tree_sha}");
 
     Request::<(), GitGetTreeQuery, GitTree>::builder(&self.config)
@@ -416,3 +416,17 @@ impl GitHubGitAPI {
       .build()
   }
 }
+
+/// Custom media types of `get_blob`, for `Request::media_type`
+pub struct GitGetBlobMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GitGetBlobMediaType {
+  /// Returns the raw blob data.
+  pub const Raw: MediaType<String> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Text);
+
+  /// Returns a JSON representation of the blob with `content` as a base64 encoded string. This is the default if no media type is specified.
+  pub const Json: MediaType<Blob> =
+    MediaType::new("application/vnd.github+json", PayloadFormat::Json);
+}
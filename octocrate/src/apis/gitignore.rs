@@ -37,7 +37,7 @@ impl GitHubGitignoreAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/gitignore/gitignore#get-a-gitignore-template](https://docs.github.com/rest/gitignore/gitignore#get-a-gitignore-template)
   pub fn get_template(&self, name: impl Into<String>) -> Request<(), (), GitignoreTemplate> {
-    let name = name.into();
+    let name = encode_path_segment(name);
     let url = format!("/gitignore/templates/{name}");
 
     Request::<(), (), GitignoreTemplate>::builder(&self.config)
@@ -45,3 +45,13 @@ impl GitHubGitignoreAPI {
       .build()
   }
 }
+
+/// Custom media types of `get_template`, for `Request::media_type`
+pub struct GitignoreGetTemplateMediaType;
+
+#[allow(non_upper_case_globals)]
+impl GitignoreGetTemplateMediaType {
+  /// Returns the raw .gitignore contents.
+  pub const Raw: MediaType<String> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Text);
+}
@@ -23,7 +23,7 @@ impl GitHubInteractionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), InteractionsGetRestrictionsForOrgResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/interaction-limits");
 
     Request::<(), (), InteractionsGetRestrictionsForOrgResponse>::builder(&self.config)
@@ -40,7 +40,7 @@ impl GitHubInteractionsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<InteractionRestrictions, (), InteractionLimits> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/interaction-limits");
 
     Request::<InteractionRestrictions, (), InteractionLimits>::builder(&self.config)
@@ -54,7 +54,7 @@ impl GitHubInteractionsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/interactions/orgs#remove-interaction-restrictions-for-an-organization](https://docs.github.com/rest/interactions/orgs#remove-interaction-restrictions-for-an-organization)
   pub fn remove_restrictions_for_org(&self, org: impl Into<String>) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/interaction-limits");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -72,8 +72,8 @@ impl GitHubInteractionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), InteractionsGetRestrictionsForRepoResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/interaction-limits");
 
     Request::<(), (), InteractionsGetRestrictionsForRepoResponse>::builder(&self.config)
@@ -91,8 +91,8 @@ impl GitHubInteractionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<InteractionRestrictions, (), InteractionLimits> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/interaction-limits");
 
     Request::<InteractionRestrictions, (), InteractionLimits>::builder(&self.config)
@@ -110,8 +110,8 @@ impl GitHubInteractionsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/interaction-limits");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -62,7 +62,7 @@ impl GitHubIssuesAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), IssuesListForOrgQuery, IssueArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/issues");
 
     Request::<(), IssuesListForOrgQuery, IssueArray>::builder(&self.config)
@@ -80,8 +80,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), IssuesListAssigneesQuery, SimpleUserArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/assignees");
 
     Request::<(), IssuesListAssigneesQuery, SimpleUserArray>::builder(&self.config)
@@ -104,9 +104,9 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     assignee: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let assignee = assignee.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let assignee = encode_path_segment(assignee);
     let url = format!("/repos/{owner}/{repo}/assignees/{assignee}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -136,8 +136,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), IssuesListForRepoQuery, IssueArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/issues");
 
     Request::<(), IssuesListForRepoQuery, IssueArray>::builder(&self.config)
@@ -165,8 +165,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<IssuesCreateRequest, (), Issue> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/issues");
 
     Request::<IssuesCreateRequest, (), Issue>::builder(&self.config)
@@ -193,8 +193,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), IssuesListCommentsForRepoQuery, IssueCommentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/issues/comments");
 
     Request::<(), IssuesListCommentsForRepoQuery, IssueCommentArray>::builder(&self.config)
@@ -220,8 +220,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<(), (), IssueComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/comments/{comment_id}");
 
@@ -248,8 +248,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<IssuesUpdateCommentRequest, (), IssueComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/comments/{comment_id}");
 
@@ -269,8 +269,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/comments/{comment_id}");
 
@@ -289,8 +289,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), IssuesListEventsForRepoQuery, IssueEventArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/issues/events");
 
     Request::<(), IssuesListEventsForRepoQuery, IssueEventArray>::builder(&self.config)
@@ -309,8 +309,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     event_id: impl Into<i64>,
   ) -> Request<(), (), IssueEvent> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let event_id = event_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/events/{event_id}");
 
@@ -347,8 +347,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<(), (), Issue> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}");
 
@@ -375,8 +375,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<IssuesUpdateRequest, (), Issue> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}");
 
@@ -396,8 +396,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<IssuesAddAssigneesRequest, (), Issue> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/assignees");
 
@@ -417,8 +417,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<IssuesRemoveAssigneesRequest, (), Issue> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/assignees");
 
@@ -443,10 +443,10 @@ impl GitHubIssuesAPI {
     issue_number: impl Into<i64>,
     assignee: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
-    let assignee = assignee.into();
+    let assignee = encode_path_segment(assignee);
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/assignees/{assignee}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -474,8 +474,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<(), IssuesListCommentsQuery, IssueCommentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/comments");
 
@@ -507,8 +507,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<IssuesCreateCommentRequest, (), IssueComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/comments");
 
@@ -528,8 +528,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<(), IssuesListEventsQuery, IssueEventForIssueArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/events");
 
@@ -549,8 +549,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<(), IssuesListLabelsOnIssueQuery, LabelArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/labels");
 
@@ -570,8 +570,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<IssuesAddLabelsRequest, (), LabelArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/labels");
 
@@ -591,8 +591,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<IssuesSetLabelsRequest, (), LabelArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/labels");
 
@@ -612,8 +612,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/labels");
 
@@ -634,10 +634,10 @@ impl GitHubIssuesAPI {
     issue_number: impl Into<i64>,
     name: impl Into<String>,
   ) -> Request<(), (), LabelArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
-    let name = name.into();
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}");
 
     Request::<(), (), LabelArray>::builder(&self.config)
@@ -658,8 +658,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> NoContentRequest<IssuesLockRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/lock");
 
@@ -679,8 +679,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/lock");
 
@@ -700,8 +700,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<(), IssuesListEventsForTimelineQuery, TimelineEventArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/timeline");
 
@@ -720,8 +720,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), IssuesListLabelsForRepoQuery, LabelArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/labels");
 
     Request::<(), IssuesListLabelsForRepoQuery, LabelArray>::builder(&self.config)
@@ -739,8 +739,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<IssuesCreateLabelRequest, (), Label> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/labels");
 
     Request::<IssuesCreateLabelRequest, (), Label>::builder(&self.config)
@@ -759,9 +759,9 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     name: impl Into<String>,
   ) -> Request<(), (), Label> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/labels/{name}");
 
     Request::<(), (), Label>::builder(&self.config)
@@ -780,9 +780,9 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     name: impl Into<String>,
   ) -> Request<IssuesUpdateLabelRequest, (), Label> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/labels/{name}");
 
     Request::<IssuesUpdateLabelRequest, (), Label>::builder(&self.config)
@@ -801,9 +801,9 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let name = name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let name = encode_path_segment(name);
     let url = format!("/repos/{owner}/{repo}/labels/{name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -821,8 +821,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), IssuesListMilestonesQuery, MilestoneArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/milestones");
 
     Request::<(), IssuesListMilestonesQuery, MilestoneArray>::builder(&self.config)
@@ -840,8 +840,8 @@ impl GitHubIssuesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<IssuesCreateMilestoneRequest, (), Milestone> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/milestones");
 
     Request::<IssuesCreateMilestoneRequest, (), Milestone>::builder(&self.config)
@@ -860,8 +860,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     milestone_number: impl Into<i64>,
   ) -> Request<(), (), Milestone> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let milestone_number = milestone_number.into();
     let url = format!("/repos/{owner}/{repo}/milestones/{milestone_number}");
 
@@ -880,8 +880,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     milestone_number: impl Into<i64>,
   ) -> Request<IssuesUpdateMilestoneRequest, (), Milestone> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let milestone_number = milestone_number.into();
     let url = format!("/repos/{owner}/{repo}/milestones/{milestone_number}");
 
@@ -901,8 +901,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     milestone_number: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let milestone_number = milestone_number.into();
     let url = format!("/repos/{owner}/{repo}/milestones/{milestone_number}");
 
@@ -922,8 +922,8 @@ impl GitHubIssuesAPI {
     repo: impl Into<String>,
     milestone_number: impl Into<i64>,
   ) -> Request<(), IssuesListLabelsForMilestoneQuery, LabelArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let milestone_number = milestone_number.into();
     let url = format!("/repos/{owner}/{repo}/milestones/{milestone_number}/labels");
 
@@ -959,3 +959,267 @@ impl GitHubIssuesAPI {
       .build()
   }
 }
+
+/// Custom media types of `list`, for `Request::media_type`
+pub struct IssuesListMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesListMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_for_org`, for `Request::media_type`
+pub struct IssuesListForOrgMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesListForOrgMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_for_repo`, for `Request::media_type`
+pub struct IssuesListForRepoMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesListForRepoMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `create`, for `Request::media_type`
+pub struct IssuesCreateMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesCreateMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<Issue> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<Issue> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<Issue> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<Issue> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_comments_for_repo`, for `Request::media_type`
+pub struct IssuesListCommentsForRepoMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesListCommentsForRepoMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `get_comment`, for `Request::media_type`
+pub struct IssuesGetCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesGetCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `update_comment`, for `Request::media_type`
+pub struct IssuesUpdateCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesUpdateCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `get`, for `Request::media_type`
+pub struct IssuesGetMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesGetMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<Issue> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<Issue> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<Issue> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<Issue> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `update`, for `Request::media_type`
+pub struct IssuesUpdateMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesUpdateMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<Issue> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<Issue> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<Issue> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<Issue> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_comments`, for `Request::media_type`
+pub struct IssuesListCommentsMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesListCommentsMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueCommentArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `create_comment`, for `Request::media_type`
+pub struct IssuesCreateCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesCreateCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueComment> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `list_for_authenticated_user`, for `Request::media_type`
+pub struct IssuesListForAuthenticatedUserMediaType;
+
+#[allow(non_upper_case_globals)]
+impl IssuesListForAuthenticatedUserMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<IssueArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+}
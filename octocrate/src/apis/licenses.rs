@@ -35,7 +35,7 @@ impl GitHubLicensesAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/licenses/licenses#get-a-license](https://docs.github.com/rest/licenses/licenses#get-a-license)
   pub fn get(&self, license: impl Into<String>) -> Request<(), (), License> {
-    let license = license.into();
+    let license = encode_path_segment(license);
     let url = format!("/licenses/{license}");
 
     Request::<(), (), License>::builder(&self.config)
@@ -58,8 +58,8 @@ impl GitHubLicensesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), LicensesGetForRepoQuery, LicenseContent> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/license");
 
     Request::<(), LicensesGetForRepoQuery, LicenseContent>::builder(&self.config)
@@ -67,3 +67,17 @@ impl GitHubLicensesAPI {
       .build()
   }
 }
+
+/// Custom media types of `get_for_repo`, for `Request::media_type`
+pub struct LicensesGetForRepoMediaType;
+
+#[allow(non_upper_case_globals)]
+impl LicensesGetForRepoMediaType {
+  /// Returns the raw contents of the license.
+  pub const Raw: MediaType<String> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Text);
+
+  /// Returns the license contents in HTML. Markup languages are rendered to HTML using GitHub's open-source [Markup library](https://github.com/github/markup).
+  pub const Html: MediaType<String> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Text);
+}
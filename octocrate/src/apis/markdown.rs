@@ -18,11 +18,12 @@ impl GitHubMarkdownAPI {
   ///
   ///
   /// *Documentation*: [https://docs.github.com/rest/markdown/markdown#render-a-markdown-document](https://docs.github.com/rest/markdown/markdown#render-a-markdown-document)
-  pub fn render(&self) -> NoContentRequest<MarkdownRenderRequest, ()> {
+  pub fn render(&self) -> Request<MarkdownRenderRequest, (), String> {
     let url = format!("/markdown");
 
-    NoContentRequest::<MarkdownRenderRequest, ()>::builder(&self.config)
+    Request::<MarkdownRenderRequest, (), String>::builder(&self.config)
       .post(url)
+      .response_format(PayloadFormat::Text)
       .build()
   }
 
@@ -31,11 +32,13 @@ impl GitHubMarkdownAPI {
   /// You must send Markdown as plain text (using a `Content-Type` header of `text/plain` or `text/x-markdown`) to this endpoint, rather than using JSON format. In raw mode, [GitHub Flavored Markdown](https://github.github.com/gfm/) is not supported and Markdown will be rendered in plain format like a README.md file. Markdown content must be 400 KB or less.
   ///
   /// *Documentation*: [https://docs.github.com/rest/markdown/markdown#render-a-markdown-document-in-raw-mode](https://docs.github.com/rest/markdown/markdown#render-a-markdown-document-in-raw-mode)
-  pub fn render_raw(&self) -> NoContentRequest<(), ()> {
+  pub fn render_raw(&self, body: impl Into<String>) -> Request<(), (), String> {
     let url = format!("/markdown/raw");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    Request::<(), (), String>::builder(&self.config)
       .post(url)
+      .response_format(PayloadFormat::Text)
       .build()
+      .raw_body(body.into(), PayloadFormat::Text.content_type())
   }
 }
@@ -51,11 +51,12 @@ impl GitHubMetaAPI {
   /// Get the octocat as ASCII art
   ///
   /// *Documentation*: [https://docs.github.com/rest/meta/meta#get-octocat](https://docs.github.com/rest/meta/meta#get-octocat)
-  pub fn get_octocat(&self) -> NoContentRequest<(), MetaGetOctocatQuery> {
+  pub fn get_octocat(&self) -> Request<(), MetaGetOctocatQuery, String> {
     let url = format!("/octocat");
 
-    NoContentRequest::<(), MetaGetOctocatQuery>::builder(&self.config)
+    Request::<(), MetaGetOctocatQuery, String>::builder(&self.config)
       .get(url)
+      .response_format(PayloadFormat::Text)
       .build()
   }
 
@@ -82,6 +83,7 @@ impl GitHubMetaAPI {
 
     Request::<(), (), String>::builder(&self.config)
       .get(url)
+      .response_format(PayloadFormat::Text)
       .build()
   }
 }
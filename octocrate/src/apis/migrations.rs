@@ -25,7 +25,7 @@ impl GitHubMigrationsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), MigrationsListForOrgQuery, MigrationArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/migrations");
 
     Request::<(), MigrationsListForOrgQuery, MigrationArray>::builder(&self.config)
@@ -42,7 +42,7 @@ impl GitHubMigrationsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<MigrationsStartForOrgRequest, (), Migration> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/migrations");
 
     Request::<MigrationsStartForOrgRequest, (), Migration>::builder(&self.config)
@@ -67,7 +67,7 @@ impl GitHubMigrationsAPI {
     org: impl Into<String>,
     migration_id: impl Into<i64>,
   ) -> Request<(), MigrationsGetStatusForOrgQuery, Migration> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let migration_id = migration_id.into();
     let url = format!("/orgs/{org}/migrations/{migration_id}");
 
@@ -85,12 +85,12 @@ impl GitHubMigrationsAPI {
     &self,
     org: impl Into<String>,
     migration_id: impl Into<i64>,
-  ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+  ) -> DownloadRequest<()> {
+    let org = encode_path_segment(org);
     let migration_id = migration_id.into();
     let url = format!("/orgs/{org}/migrations/{migration_id}/archive");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -105,7 +105,7 @@ impl GitHubMigrationsAPI {
     org: impl Into<String>,
     migration_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let migration_id = migration_id.into();
     let url = format!("/orgs/{org}/migrations/{migration_id}/archive");
 
@@ -125,9 +125,9 @@ impl GitHubMigrationsAPI {
     migration_id: impl Into<i64>,
     repo_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let migration_id = migration_id.into();
-    let repo_name = repo_name.into();
+    let repo_name = encode_path_segment(repo_name);
     let url = format!("/orgs/{org}/migrations/{migration_id}/repos/{repo_name}/lock");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -145,7 +145,7 @@ impl GitHubMigrationsAPI {
     org: impl Into<String>,
     migration_id: impl Into<i64>,
   ) -> Request<(), MigrationsListReposForOrgQuery, MinimalRepositoryArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let migration_id = migration_id.into();
     let url = format!("/orgs/{org}/migrations/{migration_id}/repositories");
 
@@ -199,8 +199,8 @@ impl GitHubMigrationsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), Import> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/import");
 
     Request::<(), (), Import>::builder(&self.config)
@@ -223,8 +223,8 @@ impl GitHubMigrationsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<MigrationsStartImportRequest, (), Import> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/import");
 
     Request::<MigrationsStartImportRequest, (), Import>::builder(&self.config)
@@ -249,8 +249,8 @@ impl GitHubMigrationsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<MigrationsUpdateImportRequest, (), Import> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/import");
 
     Request::<MigrationsUpdateImportRequest, (), Import>::builder(&self.config)
@@ -271,8 +271,8 @@ impl GitHubMigrationsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/import");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -294,8 +294,8 @@ impl GitHubMigrationsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), MigrationsGetCommitAuthorsQuery, PorterAuthorArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/import/authors");
 
     Request::<(), MigrationsGetCommitAuthorsQuery, PorterAuthorArray>::builder(&self.config)
@@ -318,8 +318,8 @@ impl GitHubMigrationsAPI {
     repo: impl Into<String>,
     author_id: impl Into<i64>,
   ) -> Request<MigrationsMapCommitAuthorRequest, (), PorterAuthor> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let author_id = author_id.into();
     let url = format!("/repos/{owner}/{repo}/import/authors/{author_id}");
 
@@ -341,8 +341,8 @@ impl GitHubMigrationsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), PorterLargeFileArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/import/large_files");
 
     Request::<(), (), PorterLargeFileArray>::builder(&self.config)
@@ -367,8 +367,8 @@ impl GitHubMigrationsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<MigrationsSetLfsPreferenceRequest, (), Import> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/import/lfs");
 
     Request::<MigrationsSetLfsPreferenceRequest, (), Import>::builder(&self.config)
@@ -458,11 +458,11 @@ impl GitHubMigrationsAPI {
   pub fn get_archive_for_authenticated_user(
     &self,
     migration_id: impl Into<i64>,
-  ) -> NoContentRequest<(), ()> {
+  ) -> DownloadRequest<()> {
     let migration_id = migration_id.into();
     let url = format!("/user/migrations/{migration_id}/archive");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -495,7 +495,7 @@ impl GitHubMigrationsAPI {
     repo_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let migration_id = migration_id.into();
-    let repo_name = repo_name.into();
+    let repo_name = encode_path_segment(repo_name);
     let url = format!("/user/migrations/{migration_id}/repos/{repo_name}/lock");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -69,6 +69,10 @@ pub mod teams;
 #[cfg(any(feature = "full", feature = "users"))]
 pub mod users;
 
+mod operations;
+
+pub use operations::*;
+
 use octocrate_core::SharedAPIConfig;
 
 pub struct GitHubAPI {
@@ -25,7 +25,7 @@ impl GitHubOidcAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), ActionsOidcSubjectCustomization> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/oidc/customization/sub");
 
     Request::<(), (), ActionsOidcSubjectCustomization>::builder(&self.config)
@@ -44,7 +44,7 @@ impl GitHubOidcAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<ActionsOidcSubjectCustomization, (), EmptyObject> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/actions/oidc/customization/sub");
 
     Request::<ActionsOidcSubjectCustomization, (), EmptyObject>::builder(&self.config)
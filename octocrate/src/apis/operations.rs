@@ -0,0 +1,4665 @@
+use octocrate_core::Operation;
+
+/// Every endpoint of the REST API, keyed by `module.function`.
+pub const OPERATIONS: &[Operation] = &[
+  Operation {
+    id: "meta.root",
+    method: "GET",
+    path: "/",
+  },
+  Operation {
+    id: "meta.get",
+    method: "GET",
+    path: "/meta",
+  },
+  Operation {
+    id: "meta.get_octocat",
+    method: "GET",
+    path: "/octocat",
+  },
+  Operation {
+    id: "meta.get_all_versions",
+    method: "GET",
+    path: "/versions",
+  },
+  Operation {
+    id: "meta.get_zen",
+    method: "GET",
+    path: "/zen",
+  },
+  Operation {
+    id: "security_advisories.list_global_advisories",
+    method: "GET",
+    path: "/advisories",
+  },
+  Operation {
+    id: "security_advisories.get_global_advisory",
+    method: "GET",
+    path: "/advisories/{ghsa_id}",
+  },
+  Operation {
+    id: "security_advisories.list_org_repository_advisories",
+    method: "GET",
+    path: "/orgs/{org}/security-advisories",
+  },
+  Operation {
+    id: "security_advisories.list_repository_advisories",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/security-advisories",
+  },
+  Operation {
+    id: "security_advisories.create_repository_advisory",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/security-advisories",
+  },
+  Operation {
+    id: "security_advisories.create_private_vulnerability_report",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/security-advisories/reports",
+  },
+  Operation {
+    id: "security_advisories.get_repository_advisory",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/security-advisories/{ghsa_id}",
+  },
+  Operation {
+    id: "security_advisories.update_repository_advisory",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/security-advisories/{ghsa_id}",
+  },
+  Operation {
+    id: "security_advisories.create_repository_advisory_cve_request",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/security-advisories/{ghsa_id}/cve",
+  },
+  Operation {
+    id: "security_advisories.create_fork",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/security-advisories/{ghsa_id}/forks",
+  },
+  Operation {
+    id: "apps.get_authenticated",
+    method: "GET",
+    path: "/app",
+  },
+  Operation {
+    id: "apps.create_from_manifest",
+    method: "POST",
+    path: "/app-manifests/{code}/conversions",
+  },
+  Operation {
+    id: "apps.get_webhook_config_for_app",
+    method: "GET",
+    path: "/app/hook/config",
+  },
+  Operation {
+    id: "apps.update_webhook_config_for_app",
+    method: "PATCH",
+    path: "/app/hook/config",
+  },
+  Operation {
+    id: "apps.list_webhook_deliveries",
+    method: "GET",
+    path: "/app/hook/deliveries",
+  },
+  Operation {
+    id: "apps.get_webhook_delivery",
+    method: "GET",
+    path: "/app/hook/deliveries/{delivery_id}",
+  },
+  Operation {
+    id: "apps.redeliver_webhook_delivery",
+    method: "POST",
+    path: "/app/hook/deliveries/{delivery_id}/attempts",
+  },
+  Operation {
+    id: "apps.list_installation_requests_for_authenticated_app",
+    method: "GET",
+    path: "/app/installation-requests",
+  },
+  Operation {
+    id: "apps.list_installations",
+    method: "GET",
+    path: "/app/installations",
+  },
+  Operation {
+    id: "apps.get_installation",
+    method: "GET",
+    path: "/app/installations/{installation_id}",
+  },
+  Operation {
+    id: "apps.delete_installation",
+    method: "DELETE",
+    path: "/app/installations/{installation_id}",
+  },
+  Operation {
+    id: "apps.create_installation_access_token",
+    method: "POST",
+    path: "/app/installations/{installation_id}/access_tokens",
+  },
+  Operation {
+    id: "apps.suspend_installation",
+    method: "PUT",
+    path: "/app/installations/{installation_id}/suspended",
+  },
+  Operation {
+    id: "apps.unsuspend_installation",
+    method: "DELETE",
+    path: "/app/installations/{installation_id}/suspended",
+  },
+  Operation {
+    id: "apps.delete_authorization",
+    method: "DELETE",
+    path: "/applications/{client_id}/grant",
+  },
+  Operation {
+    id: "apps.check_token",
+    method: "POST",
+    path: "/applications/{client_id}/token",
+  },
+  Operation {
+    id: "apps.reset_token",
+    method: "PATCH",
+    path: "/applications/{client_id}/token",
+  },
+  Operation {
+    id: "apps.delete_token",
+    method: "DELETE",
+    path: "/applications/{client_id}/token",
+  },
+  Operation {
+    id: "apps.scope_token",
+    method: "POST",
+    path: "/applications/{client_id}/token/scoped",
+  },
+  Operation {
+    id: "apps.get_by_slug",
+    method: "GET",
+    path: "/apps/{app_slug}",
+  },
+  Operation {
+    id: "apps.list_repos_accessible_to_installation",
+    method: "GET",
+    path: "/installation/repositories",
+  },
+  Operation {
+    id: "apps.revoke_installation_access_token",
+    method: "DELETE",
+    path: "/installation/token",
+  },
+  Operation {
+    id: "apps.get_subscription_plan_for_account",
+    method: "GET",
+    path: "/marketplace_listing/accounts/{account_id}",
+  },
+  Operation {
+    id: "apps.list_plans",
+    method: "GET",
+    path: "/marketplace_listing/plans",
+  },
+  Operation {
+    id: "apps.list_accounts_for_plan",
+    method: "GET",
+    path: "/marketplace_listing/plans/{plan_id}/accounts",
+  },
+  Operation {
+    id: "apps.get_subscription_plan_for_account_stubbed",
+    method: "GET",
+    path: "/marketplace_listing/stubbed/accounts/{account_id}",
+  },
+  Operation {
+    id: "apps.list_plans_stubbed",
+    method: "GET",
+    path: "/marketplace_listing/stubbed/plans",
+  },
+  Operation {
+    id: "apps.list_accounts_for_plan_stubbed",
+    method: "GET",
+    path: "/marketplace_listing/stubbed/plans/{plan_id}/accounts",
+  },
+  Operation {
+    id: "apps.get_org_installation",
+    method: "GET",
+    path: "/orgs/{org}/installation",
+  },
+  Operation {
+    id: "apps.get_repo_installation",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/installation",
+  },
+  Operation {
+    id: "apps.list_installations_for_authenticated_user",
+    method: "GET",
+    path: "/user/installations",
+  },
+  Operation {
+    id: "apps.list_installation_repos_for_authenticated_user",
+    method: "GET",
+    path: "/user/installations/{installation_id}/repositories",
+  },
+  Operation {
+    id: "apps.add_repo_to_installation_for_authenticated_user",
+    method: "PUT",
+    path: "/user/installations/{installation_id}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "apps.remove_repo_from_installation_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/installations/{installation_id}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "apps.list_subscriptions_for_authenticated_user",
+    method: "GET",
+    path: "/user/marketplace_purchases",
+  },
+  Operation {
+    id: "apps.list_subscriptions_for_authenticated_user_stubbed",
+    method: "GET",
+    path: "/user/marketplace_purchases/stubbed",
+  },
+  Operation {
+    id: "apps.get_user_installation",
+    method: "GET",
+    path: "/users/{username}/installation",
+  },
+  Operation {
+    id: "classroom.get_an_assignment",
+    method: "GET",
+    path: "/assignments/{assignment_id}",
+  },
+  Operation {
+    id: "classroom.list_accepted_assigments_for_an_assignment",
+    method: "GET",
+    path: "/assignments/{assignment_id}/accepted_assignments",
+  },
+  Operation {
+    id: "classroom.get_assignment_grades",
+    method: "GET",
+    path: "/assignments/{assignment_id}/grades",
+  },
+  Operation {
+    id: "classroom.list_classrooms",
+    method: "GET",
+    path: "/classrooms",
+  },
+  Operation {
+    id: "classroom.get_a_classroom",
+    method: "GET",
+    path: "/classrooms/{classroom_id}",
+  },
+  Operation {
+    id: "classroom.list_assignments_for_a_classroom",
+    method: "GET",
+    path: "/classrooms/{classroom_id}/assignments",
+  },
+  Operation {
+    id: "codes_of_conduct.get_all_codes_of_conduct",
+    method: "GET",
+    path: "/codes_of_conduct",
+  },
+  Operation {
+    id: "codes_of_conduct.get_conduct_code",
+    method: "GET",
+    path: "/codes_of_conduct/{key}",
+  },
+  Operation {
+    id: "emojis.get",
+    method: "GET",
+    path: "/emojis",
+  },
+  Operation {
+    id: "dependabot.list_alerts_for_enterprise",
+    method: "GET",
+    path: "/enterprises/{enterprise}/dependabot/alerts",
+  },
+  Operation {
+    id: "dependabot.list_alerts_for_org",
+    method: "GET",
+    path: "/orgs/{org}/dependabot/alerts",
+  },
+  Operation {
+    id: "dependabot.list_org_secrets",
+    method: "GET",
+    path: "/orgs/{org}/dependabot/secrets",
+  },
+  Operation {
+    id: "dependabot.get_org_public_key",
+    method: "GET",
+    path: "/orgs/{org}/dependabot/secrets/public-key",
+  },
+  Operation {
+    id: "dependabot.get_org_secret",
+    method: "GET",
+    path: "/orgs/{org}/dependabot/secrets/{secret_name}",
+  },
+  Operation {
+    id: "dependabot.create_or_update_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/dependabot/secrets/{secret_name}",
+  },
+  Operation {
+    id: "dependabot.delete_org_secret",
+    method: "DELETE",
+    path: "/orgs/{org}/dependabot/secrets/{secret_name}",
+  },
+  Operation {
+    id: "dependabot.list_selected_repos_for_org_secret",
+    method: "GET",
+    path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "dependabot.set_selected_repos_for_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "dependabot.add_selected_repo_to_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "dependabot.remove_selected_repo_from_org_secret",
+    method: "DELETE",
+    path: "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "dependabot.list_alerts_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/dependabot/alerts",
+  },
+  Operation {
+    id: "dependabot.get_alert",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/dependabot/alerts/{alert_number}",
+  },
+  Operation {
+    id: "dependabot.update_alert",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/dependabot/alerts/{alert_number}",
+  },
+  Operation {
+    id: "dependabot.list_repo_secrets",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/dependabot/secrets",
+  },
+  Operation {
+    id: "dependabot.get_repo_public_key",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/dependabot/secrets/public-key",
+  },
+  Operation {
+    id: "dependabot.get_repo_secret",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+  },
+  Operation {
+    id: "dependabot.create_or_update_repo_secret",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+  },
+  Operation {
+    id: "dependabot.delete_repo_secret",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+  },
+  Operation {
+    id: "secret_scanning.list_alerts_for_enterprise",
+    method: "GET",
+    path: "/enterprises/{enterprise}/secret-scanning/alerts",
+  },
+  Operation {
+    id: "secret_scanning.list_alerts_for_org",
+    method: "GET",
+    path: "/orgs/{org}/secret-scanning/alerts",
+  },
+  Operation {
+    id: "secret_scanning.list_alerts_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/secret-scanning/alerts",
+  },
+  Operation {
+    id: "secret_scanning.get_alert",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}",
+  },
+  Operation {
+    id: "secret_scanning.update_alert",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}",
+  },
+  Operation {
+    id: "secret_scanning.list_locations_for_alert",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}/locations",
+  },
+  Operation {
+    id: "activity.list_public_events",
+    method: "GET",
+    path: "/events",
+  },
+  Operation {
+    id: "activity.get_feeds",
+    method: "GET",
+    path: "/feeds",
+  },
+  Operation {
+    id: "activity.list_public_events_for_repo_network",
+    method: "GET",
+    path: "/networks/{owner}/{repo}/events",
+  },
+  Operation {
+    id: "activity.list_notifications_for_authenticated_user",
+    method: "GET",
+    path: "/notifications",
+  },
+  Operation {
+    id: "activity.mark_notifications_as_read",
+    method: "PUT",
+    path: "/notifications",
+  },
+  Operation {
+    id: "activity.get_thread",
+    method: "GET",
+    path: "/notifications/threads/{thread_id}",
+  },
+  Operation {
+    id: "activity.mark_thread_as_read",
+    method: "PATCH",
+    path: "/notifications/threads/{thread_id}",
+  },
+  Operation {
+    id: "activity.mark_thread_as_done",
+    method: "DELETE",
+    path: "/notifications/threads/{thread_id}",
+  },
+  Operation {
+    id: "activity.get_thread_subscription_for_authenticated_user",
+    method: "GET",
+    path: "/notifications/threads/{thread_id}/subscription",
+  },
+  Operation {
+    id: "activity.set_thread_subscription",
+    method: "PUT",
+    path: "/notifications/threads/{thread_id}/subscription",
+  },
+  Operation {
+    id: "activity.delete_thread_subscription",
+    method: "DELETE",
+    path: "/notifications/threads/{thread_id}/subscription",
+  },
+  Operation {
+    id: "activity.list_public_org_events",
+    method: "GET",
+    path: "/orgs/{org}/events",
+  },
+  Operation {
+    id: "activity.list_repo_events",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/events",
+  },
+  Operation {
+    id: "activity.list_repo_notifications_for_authenticated_user",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/notifications",
+  },
+  Operation {
+    id: "activity.mark_repo_notifications_as_read",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/notifications",
+  },
+  Operation {
+    id: "activity.list_stargazers_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/stargazers",
+  },
+  Operation {
+    id: "activity.list_watchers_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/subscribers",
+  },
+  Operation {
+    id: "activity.get_repo_subscription",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/subscription",
+  },
+  Operation {
+    id: "activity.set_repo_subscription",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/subscription",
+  },
+  Operation {
+    id: "activity.delete_repo_subscription",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/subscription",
+  },
+  Operation {
+    id: "activity.list_repos_starred_by_authenticated_user",
+    method: "GET",
+    path: "/user/starred",
+  },
+  Operation {
+    id: "activity.check_repo_is_starred_by_authenticated_user",
+    method: "GET",
+    path: "/user/starred/{owner}/{repo}",
+  },
+  Operation {
+    id: "activity.star_repo_for_authenticated_user",
+    method: "PUT",
+    path: "/user/starred/{owner}/{repo}",
+  },
+  Operation {
+    id: "activity.unstar_repo_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/starred/{owner}/{repo}",
+  },
+  Operation {
+    id: "activity.list_watched_repos_for_authenticated_user",
+    method: "GET",
+    path: "/user/subscriptions",
+  },
+  Operation {
+    id: "activity.list_events_for_authenticated_user",
+    method: "GET",
+    path: "/users/{username}/events",
+  },
+  Operation {
+    id: "activity.list_org_events_for_authenticated_user",
+    method: "GET",
+    path: "/users/{username}/events/orgs/{org}",
+  },
+  Operation {
+    id: "activity.list_public_events_for_user",
+    method: "GET",
+    path: "/users/{username}/events/public",
+  },
+  Operation {
+    id: "activity.list_received_events_for_user",
+    method: "GET",
+    path: "/users/{username}/received_events",
+  },
+  Operation {
+    id: "activity.list_received_public_events_for_user",
+    method: "GET",
+    path: "/users/{username}/received_events/public",
+  },
+  Operation {
+    id: "activity.list_repos_starred_by_user",
+    method: "GET",
+    path: "/users/{username}/starred",
+  },
+  Operation {
+    id: "activity.list_repos_watched_by_user",
+    method: "GET",
+    path: "/users/{username}/subscriptions",
+  },
+  Operation {
+    id: "gists.list",
+    method: "GET",
+    path: "/gists",
+  },
+  Operation {
+    id: "gists.create",
+    method: "POST",
+    path: "/gists",
+  },
+  Operation {
+    id: "gists.list_public",
+    method: "GET",
+    path: "/gists/public",
+  },
+  Operation {
+    id: "gists.list_starred",
+    method: "GET",
+    path: "/gists/starred",
+  },
+  Operation {
+    id: "gists.get",
+    method: "GET",
+    path: "/gists/{gist_id}",
+  },
+  Operation {
+    id: "gists.update",
+    method: "PATCH",
+    path: "/gists/{gist_id}",
+  },
+  Operation {
+    id: "gists.delete",
+    method: "DELETE",
+    path: "/gists/{gist_id}",
+  },
+  Operation {
+    id: "gists.list_comments",
+    method: "GET",
+    path: "/gists/{gist_id}/comments",
+  },
+  Operation {
+    id: "gists.create_comment",
+    method: "POST",
+    path: "/gists/{gist_id}/comments",
+  },
+  Operation {
+    id: "gists.get_comment",
+    method: "GET",
+    path: "/gists/{gist_id}/comments/{comment_id}",
+  },
+  Operation {
+    id: "gists.update_comment",
+    method: "PATCH",
+    path: "/gists/{gist_id}/comments/{comment_id}",
+  },
+  Operation {
+    id: "gists.delete_comment",
+    method: "DELETE",
+    path: "/gists/{gist_id}/comments/{comment_id}",
+  },
+  Operation {
+    id: "gists.list_commits",
+    method: "GET",
+    path: "/gists/{gist_id}/commits",
+  },
+  Operation {
+    id: "gists.list_forks",
+    method: "GET",
+    path: "/gists/{gist_id}/forks",
+  },
+  Operation {
+    id: "gists.fork",
+    method: "POST",
+    path: "/gists/{gist_id}/forks",
+  },
+  Operation {
+    id: "gists.check_is_starred",
+    method: "GET",
+    path: "/gists/{gist_id}/star",
+  },
+  Operation {
+    id: "gists.star",
+    method: "PUT",
+    path: "/gists/{gist_id}/star",
+  },
+  Operation {
+    id: "gists.unstar",
+    method: "DELETE",
+    path: "/gists/{gist_id}/star",
+  },
+  Operation {
+    id: "gists.get_revision",
+    method: "GET",
+    path: "/gists/{gist_id}/{sha}",
+  },
+  Operation {
+    id: "gists.list_for_user",
+    method: "GET",
+    path: "/users/{username}/gists",
+  },
+  Operation {
+    id: "gitignore.get_all_templates",
+    method: "GET",
+    path: "/gitignore/templates",
+  },
+  Operation {
+    id: "gitignore.get_template",
+    method: "GET",
+    path: "/gitignore/templates/{name}",
+  },
+  Operation {
+    id: "issues.list",
+    method: "GET",
+    path: "/issues",
+  },
+  Operation {
+    id: "issues.list_for_org",
+    method: "GET",
+    path: "/orgs/{org}/issues",
+  },
+  Operation {
+    id: "issues.list_assignees",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/assignees",
+  },
+  Operation {
+    id: "issues.check_user_can_be_assigned",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/assignees/{assignee}",
+  },
+  Operation {
+    id: "issues.list_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues",
+  },
+  Operation {
+    id: "issues.create",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/issues",
+  },
+  Operation {
+    id: "issues.list_comments_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/comments",
+  },
+  Operation {
+    id: "issues.get_comment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+  },
+  Operation {
+    id: "issues.update_comment",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+  },
+  Operation {
+    id: "issues.delete_comment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+  },
+  Operation {
+    id: "issues.list_events_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/events",
+  },
+  Operation {
+    id: "issues.get_event",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/events/{event_id}",
+  },
+  Operation {
+    id: "issues.get",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}",
+  },
+  Operation {
+    id: "issues.update",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}",
+  },
+  Operation {
+    id: "issues.add_assignees",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/assignees",
+  },
+  Operation {
+    id: "issues.remove_assignees",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/assignees",
+  },
+  Operation {
+    id: "issues.check_user_can_be_assigned_to_issue",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/assignees/{assignee}",
+  },
+  Operation {
+    id: "issues.list_comments",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/comments",
+  },
+  Operation {
+    id: "issues.create_comment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/comments",
+  },
+  Operation {
+    id: "issues.list_events",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/events",
+  },
+  Operation {
+    id: "issues.list_labels_on_issue",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  },
+  Operation {
+    id: "issues.add_labels",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  },
+  Operation {
+    id: "issues.set_labels",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  },
+  Operation {
+    id: "issues.remove_all_labels",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  },
+  Operation {
+    id: "issues.remove_label",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}",
+  },
+  Operation {
+    id: "issues.lock",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/lock",
+  },
+  Operation {
+    id: "issues.unlock",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/lock",
+  },
+  Operation {
+    id: "issues.list_events_for_timeline",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/timeline",
+  },
+  Operation {
+    id: "issues.list_labels_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/labels",
+  },
+  Operation {
+    id: "issues.create_label",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/labels",
+  },
+  Operation {
+    id: "issues.get_label",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/labels/{name}",
+  },
+  Operation {
+    id: "issues.update_label",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/labels/{name}",
+  },
+  Operation {
+    id: "issues.delete_label",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/labels/{name}",
+  },
+  Operation {
+    id: "issues.list_milestones",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/milestones",
+  },
+  Operation {
+    id: "issues.create_milestone",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/milestones",
+  },
+  Operation {
+    id: "issues.get_milestone",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/milestones/{milestone_number}",
+  },
+  Operation {
+    id: "issues.update_milestone",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/milestones/{milestone_number}",
+  },
+  Operation {
+    id: "issues.delete_milestone",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/milestones/{milestone_number}",
+  },
+  Operation {
+    id: "issues.list_labels_for_milestone",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/milestones/{milestone_number}/labels",
+  },
+  Operation {
+    id: "issues.list_for_authenticated_user",
+    method: "GET",
+    path: "/user/issues",
+  },
+  Operation {
+    id: "licenses.get_all_commonly_used",
+    method: "GET",
+    path: "/licenses",
+  },
+  Operation {
+    id: "licenses.get",
+    method: "GET",
+    path: "/licenses/{license}",
+  },
+  Operation {
+    id: "licenses.get_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/license",
+  },
+  Operation {
+    id: "markdown.render",
+    method: "POST",
+    path: "/markdown",
+  },
+  Operation {
+    id: "markdown.render_raw",
+    method: "POST",
+    path: "/markdown/raw",
+  },
+  Operation {
+    id: "orgs.list",
+    method: "GET",
+    path: "/organizations",
+  },
+  Operation {
+    id: "orgs.get",
+    method: "GET",
+    path: "/orgs/{org}",
+  },
+  Operation {
+    id: "orgs.update",
+    method: "PATCH",
+    path: "/orgs/{org}",
+  },
+  Operation {
+    id: "orgs.delete",
+    method: "DELETE",
+    path: "/orgs/{org}",
+  },
+  Operation {
+    id: "orgs.list_blocked_users",
+    method: "GET",
+    path: "/orgs/{org}/blocks",
+  },
+  Operation {
+    id: "orgs.check_blocked_user",
+    method: "GET",
+    path: "/orgs/{org}/blocks/{username}",
+  },
+  Operation {
+    id: "orgs.block_user",
+    method: "PUT",
+    path: "/orgs/{org}/blocks/{username}",
+  },
+  Operation {
+    id: "orgs.unblock_user",
+    method: "DELETE",
+    path: "/orgs/{org}/blocks/{username}",
+  },
+  Operation {
+    id: "orgs.list_failed_invitations",
+    method: "GET",
+    path: "/orgs/{org}/failed_invitations",
+  },
+  Operation {
+    id: "orgs.list_webhooks",
+    method: "GET",
+    path: "/orgs/{org}/hooks",
+  },
+  Operation {
+    id: "orgs.create_webhook",
+    method: "POST",
+    path: "/orgs/{org}/hooks",
+  },
+  Operation {
+    id: "orgs.get_webhook",
+    method: "GET",
+    path: "/orgs/{org}/hooks/{hook_id}",
+  },
+  Operation {
+    id: "orgs.update_webhook",
+    method: "PATCH",
+    path: "/orgs/{org}/hooks/{hook_id}",
+  },
+  Operation {
+    id: "orgs.delete_webhook",
+    method: "DELETE",
+    path: "/orgs/{org}/hooks/{hook_id}",
+  },
+  Operation {
+    id: "orgs.get_webhook_config_for_org",
+    method: "GET",
+    path: "/orgs/{org}/hooks/{hook_id}/config",
+  },
+  Operation {
+    id: "orgs.update_webhook_config_for_org",
+    method: "PATCH",
+    path: "/orgs/{org}/hooks/{hook_id}/config",
+  },
+  Operation {
+    id: "orgs.list_webhook_deliveries",
+    method: "GET",
+    path: "/orgs/{org}/hooks/{hook_id}/deliveries",
+  },
+  Operation {
+    id: "orgs.get_webhook_delivery",
+    method: "GET",
+    path: "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}",
+  },
+  Operation {
+    id: "orgs.redeliver_webhook_delivery",
+    method: "POST",
+    path: "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}/attempts",
+  },
+  Operation {
+    id: "orgs.ping_webhook",
+    method: "POST",
+    path: "/orgs/{org}/hooks/{hook_id}/pings",
+  },
+  Operation {
+    id: "orgs.list_app_installations",
+    method: "GET",
+    path: "/orgs/{org}/installations",
+  },
+  Operation {
+    id: "orgs.list_pending_invitations",
+    method: "GET",
+    path: "/orgs/{org}/invitations",
+  },
+  Operation {
+    id: "orgs.create_invitation",
+    method: "POST",
+    path: "/orgs/{org}/invitations",
+  },
+  Operation {
+    id: "orgs.cancel_invitation",
+    method: "DELETE",
+    path: "/orgs/{org}/invitations/{invitation_id}",
+  },
+  Operation {
+    id: "orgs.list_invitation_teams",
+    method: "GET",
+    path: "/orgs/{org}/invitations/{invitation_id}/teams",
+  },
+  Operation {
+    id: "orgs.list_members",
+    method: "GET",
+    path: "/orgs/{org}/members",
+  },
+  Operation {
+    id: "orgs.check_membership_for_user",
+    method: "GET",
+    path: "/orgs/{org}/members/{username}",
+  },
+  Operation {
+    id: "orgs.remove_member",
+    method: "DELETE",
+    path: "/orgs/{org}/members/{username}",
+  },
+  Operation {
+    id: "orgs.get_membership_for_user",
+    method: "GET",
+    path: "/orgs/{org}/memberships/{username}",
+  },
+  Operation {
+    id: "orgs.set_membership_for_user",
+    method: "PUT",
+    path: "/orgs/{org}/memberships/{username}",
+  },
+  Operation {
+    id: "orgs.remove_membership_for_user",
+    method: "DELETE",
+    path: "/orgs/{org}/memberships/{username}",
+  },
+  Operation {
+    id: "orgs.list_organization_fine_grained_permissions",
+    method: "GET",
+    path: "/orgs/{org}/organization-fine-grained-permissions",
+  },
+  Operation {
+    id: "orgs.list_org_roles",
+    method: "GET",
+    path: "/orgs/{org}/organization-roles",
+  },
+  Operation {
+    id: "orgs.create_custom_organization_role",
+    method: "POST",
+    path: "/orgs/{org}/organization-roles",
+  },
+  Operation {
+    id: "orgs.revoke_all_org_roles_team",
+    method: "DELETE",
+    path: "/orgs/{org}/organization-roles/teams/{team_slug}",
+  },
+  Operation {
+    id: "orgs.assign_team_to_org_role",
+    method: "PUT",
+    path: "/orgs/{org}/organization-roles/teams/{team_slug}/{role_id}",
+  },
+  Operation {
+    id: "orgs.revoke_org_role_team",
+    method: "DELETE",
+    path: "/orgs/{org}/organization-roles/teams/{team_slug}/{role_id}",
+  },
+  Operation {
+    id: "orgs.revoke_all_org_roles_user",
+    method: "DELETE",
+    path: "/orgs/{org}/organization-roles/users/{username}",
+  },
+  Operation {
+    id: "orgs.assign_user_to_org_role",
+    method: "PUT",
+    path: "/orgs/{org}/organization-roles/users/{username}/{role_id}",
+  },
+  Operation {
+    id: "orgs.revoke_org_role_user",
+    method: "DELETE",
+    path: "/orgs/{org}/organization-roles/users/{username}/{role_id}",
+  },
+  Operation {
+    id: "orgs.get_org_role",
+    method: "GET",
+    path: "/orgs/{org}/organization-roles/{role_id}",
+  },
+  Operation {
+    id: "orgs.patch_custom_organization_role",
+    method: "PATCH",
+    path: "/orgs/{org}/organization-roles/{role_id}",
+  },
+  Operation {
+    id: "orgs.delete_custom_organization_role",
+    method: "DELETE",
+    path: "/orgs/{org}/organization-roles/{role_id}",
+  },
+  Operation {
+    id: "orgs.list_org_role_teams",
+    method: "GET",
+    path: "/orgs/{org}/organization-roles/{role_id}/teams",
+  },
+  Operation {
+    id: "orgs.list_org_role_users",
+    method: "GET",
+    path: "/orgs/{org}/organization-roles/{role_id}/users",
+  },
+  Operation {
+    id: "orgs.list_outside_collaborators",
+    method: "GET",
+    path: "/orgs/{org}/outside_collaborators",
+  },
+  Operation {
+    id: "orgs.convert_member_to_outside_collaborator",
+    method: "PUT",
+    path: "/orgs/{org}/outside_collaborators/{username}",
+  },
+  Operation {
+    id: "orgs.remove_outside_collaborator",
+    method: "DELETE",
+    path: "/orgs/{org}/outside_collaborators/{username}",
+  },
+  Operation {
+    id: "orgs.list_pat_grant_requests",
+    method: "GET",
+    path: "/orgs/{org}/personal-access-token-requests",
+  },
+  Operation {
+    id: "orgs.review_pat_grant_requests_in_bulk",
+    method: "POST",
+    path: "/orgs/{org}/personal-access-token-requests",
+  },
+  Operation {
+    id: "orgs.review_pat_grant_request",
+    method: "POST",
+    path: "/orgs/{org}/personal-access-token-requests/{pat_request_id}",
+  },
+  Operation {
+    id: "orgs.list_pat_grant_request_repositories",
+    method: "GET",
+    path: "/orgs/{org}/personal-access-token-requests/{pat_request_id}/repositories",
+  },
+  Operation {
+    id: "orgs.list_pat_grants",
+    method: "GET",
+    path: "/orgs/{org}/personal-access-tokens",
+  },
+  Operation {
+    id: "orgs.update_pat_accesses",
+    method: "POST",
+    path: "/orgs/{org}/personal-access-tokens",
+  },
+  Operation {
+    id: "orgs.update_pat_access",
+    method: "POST",
+    path: "/orgs/{org}/personal-access-tokens/{pat_id}",
+  },
+  Operation {
+    id: "orgs.list_pat_grant_repositories",
+    method: "GET",
+    path: "/orgs/{org}/personal-access-tokens/{pat_id}/repositories",
+  },
+  Operation {
+    id: "orgs.get_all_custom_properties",
+    method: "GET",
+    path: "/orgs/{org}/properties/schema",
+  },
+  Operation {
+    id: "orgs.create_or_update_custom_properties",
+    method: "PATCH",
+    path: "/orgs/{org}/properties/schema",
+  },
+  Operation {
+    id: "orgs.get_custom_property",
+    method: "GET",
+    path: "/orgs/{org}/properties/schema/{custom_property_name}",
+  },
+  Operation {
+    id: "orgs.create_or_update_custom_property",
+    method: "PUT",
+    path: "/orgs/{org}/properties/schema/{custom_property_name}",
+  },
+  Operation {
+    id: "orgs.remove_custom_property",
+    method: "DELETE",
+    path: "/orgs/{org}/properties/schema/{custom_property_name}",
+  },
+  Operation {
+    id: "orgs.list_custom_properties_values_for_repos",
+    method: "GET",
+    path: "/orgs/{org}/properties/values",
+  },
+  Operation {
+    id: "orgs.create_or_update_custom_properties_values_for_repos",
+    method: "PATCH",
+    path: "/orgs/{org}/properties/values",
+  },
+  Operation {
+    id: "orgs.list_public_members",
+    method: "GET",
+    path: "/orgs/{org}/public_members",
+  },
+  Operation {
+    id: "orgs.check_public_membership_for_user",
+    method: "GET",
+    path: "/orgs/{org}/public_members/{username}",
+  },
+  Operation {
+    id: "orgs.set_public_membership_for_authenticated_user",
+    method: "PUT",
+    path: "/orgs/{org}/public_members/{username}",
+  },
+  Operation {
+    id: "orgs.remove_public_membership_for_authenticated_user",
+    method: "DELETE",
+    path: "/orgs/{org}/public_members/{username}",
+  },
+  Operation {
+    id: "orgs.list_security_manager_teams",
+    method: "GET",
+    path: "/orgs/{org}/security-managers",
+  },
+  Operation {
+    id: "orgs.add_security_manager_team",
+    method: "PUT",
+    path: "/orgs/{org}/security-managers/teams/{team_slug}",
+  },
+  Operation {
+    id: "orgs.remove_security_manager_team",
+    method: "DELETE",
+    path: "/orgs/{org}/security-managers/teams/{team_slug}",
+  },
+  Operation {
+    id: "orgs.enable_or_disable_security_product_on_all_org_repos",
+    method: "POST",
+    path: "/orgs/{org}/{security_product}/{enablement}",
+  },
+  Operation {
+    id: "orgs.list_memberships_for_authenticated_user",
+    method: "GET",
+    path: "/user/memberships/orgs",
+  },
+  Operation {
+    id: "orgs.get_membership_for_authenticated_user",
+    method: "GET",
+    path: "/user/memberships/orgs/{org}",
+  },
+  Operation {
+    id: "orgs.update_membership_for_authenticated_user",
+    method: "PATCH",
+    path: "/user/memberships/orgs/{org}",
+  },
+  Operation {
+    id: "orgs.list_for_authenticated_user",
+    method: "GET",
+    path: "/user/orgs",
+  },
+  Operation {
+    id: "orgs.list_for_user",
+    method: "GET",
+    path: "/users/{username}/orgs",
+  },
+  Operation {
+    id: "actions.get_actions_cache_usage_for_org",
+    method: "GET",
+    path: "/orgs/{org}/actions/cache/usage",
+  },
+  Operation {
+    id: "actions.get_actions_cache_usage_by_repo_for_org",
+    method: "GET",
+    path: "/orgs/{org}/actions/cache/usage-by-repository",
+  },
+  Operation {
+    id: "actions.get_github_actions_permissions_organization",
+    method: "GET",
+    path: "/orgs/{org}/actions/permissions",
+  },
+  Operation {
+    id: "actions.set_github_actions_permissions_organization",
+    method: "PUT",
+    path: "/orgs/{org}/actions/permissions",
+  },
+  Operation {
+    id: "actions.list_selected_repositories_enabled_github_actions_organization",
+    method: "GET",
+    path: "/orgs/{org}/actions/permissions/repositories",
+  },
+  Operation {
+    id: "actions.set_selected_repositories_enabled_github_actions_organization",
+    method: "PUT",
+    path: "/orgs/{org}/actions/permissions/repositories",
+  },
+  Operation {
+    id: "actions.enable_selected_repository_github_actions_organization",
+    method: "PUT",
+    path: "/orgs/{org}/actions/permissions/repositories/{repository_id}",
+  },
+  Operation {
+    id: "actions.disable_selected_repository_github_actions_organization",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/permissions/repositories/{repository_id}",
+  },
+  Operation {
+    id: "actions.get_allowed_actions_organization",
+    method: "GET",
+    path: "/orgs/{org}/actions/permissions/selected-actions",
+  },
+  Operation {
+    id: "actions.set_allowed_actions_organization",
+    method: "PUT",
+    path: "/orgs/{org}/actions/permissions/selected-actions",
+  },
+  Operation {
+    id: "actions.get_github_actions_default_workflow_permissions_organization",
+    method: "GET",
+    path: "/orgs/{org}/actions/permissions/workflow",
+  },
+  Operation {
+    id: "actions.set_github_actions_default_workflow_permissions_organization",
+    method: "PUT",
+    path: "/orgs/{org}/actions/permissions/workflow",
+  },
+  Operation {
+    id: "actions.list_self_hosted_runners_for_org",
+    method: "GET",
+    path: "/orgs/{org}/actions/runners",
+  },
+  Operation {
+    id: "actions.list_runner_applications_for_org",
+    method: "GET",
+    path: "/orgs/{org}/actions/runners/downloads",
+  },
+  Operation {
+    id: "actions.generate_runner_jitconfig_for_org",
+    method: "POST",
+    path: "/orgs/{org}/actions/runners/generate-jitconfig",
+  },
+  Operation {
+    id: "actions.create_registration_token_for_org",
+    method: "POST",
+    path: "/orgs/{org}/actions/runners/registration-token",
+  },
+  Operation {
+    id: "actions.create_remove_token_for_org",
+    method: "POST",
+    path: "/orgs/{org}/actions/runners/remove-token",
+  },
+  Operation {
+    id: "actions.get_self_hosted_runner_for_org",
+    method: "GET",
+    path: "/orgs/{org}/actions/runners/{runner_id}",
+  },
+  Operation {
+    id: "actions.delete_self_hosted_runner_from_org",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/runners/{runner_id}",
+  },
+  Operation {
+    id: "actions.list_labels_for_self_hosted_runner_for_org",
+    method: "GET",
+    path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.add_custom_labels_to_self_hosted_runner_for_org",
+    method: "POST",
+    path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.set_custom_labels_for_self_hosted_runner_for_org",
+    method: "PUT",
+    path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.remove_all_custom_labels_from_self_hosted_runner_for_org",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.remove_custom_label_from_self_hosted_runner_for_org",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/runners/{runner_id}/labels/{name}",
+  },
+  Operation {
+    id: "actions.list_org_secrets",
+    method: "GET",
+    path: "/orgs/{org}/actions/secrets",
+  },
+  Operation {
+    id: "actions.get_org_public_key",
+    method: "GET",
+    path: "/orgs/{org}/actions/secrets/public-key",
+  },
+  Operation {
+    id: "actions.get_org_secret",
+    method: "GET",
+    path: "/orgs/{org}/actions/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.create_or_update_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/actions/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.delete_org_secret",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.list_selected_repos_for_org_secret",
+    method: "GET",
+    path: "/orgs/{org}/actions/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "actions.set_selected_repos_for_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/actions/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "actions.add_selected_repo_to_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "actions.remove_selected_repo_from_org_secret",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "actions.list_org_variables",
+    method: "GET",
+    path: "/orgs/{org}/actions/variables",
+  },
+  Operation {
+    id: "actions.create_org_variable",
+    method: "POST",
+    path: "/orgs/{org}/actions/variables",
+  },
+  Operation {
+    id: "actions.get_org_variable",
+    method: "GET",
+    path: "/orgs/{org}/actions/variables/{name}",
+  },
+  Operation {
+    id: "actions.update_org_variable",
+    method: "PATCH",
+    path: "/orgs/{org}/actions/variables/{name}",
+  },
+  Operation {
+    id: "actions.delete_org_variable",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/variables/{name}",
+  },
+  Operation {
+    id: "actions.list_selected_repos_for_org_variable",
+    method: "GET",
+    path: "/orgs/{org}/actions/variables/{name}/repositories",
+  },
+  Operation {
+    id: "actions.set_selected_repos_for_org_variable",
+    method: "PUT",
+    path: "/orgs/{org}/actions/variables/{name}/repositories",
+  },
+  Operation {
+    id: "actions.add_selected_repo_to_org_variable",
+    method: "PUT",
+    path: "/orgs/{org}/actions/variables/{name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "actions.remove_selected_repo_from_org_variable",
+    method: "DELETE",
+    path: "/orgs/{org}/actions/variables/{name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "actions.list_artifacts_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/artifacts",
+  },
+  Operation {
+    id: "actions.get_artifact",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}",
+  },
+  Operation {
+    id: "actions.delete_artifact",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}",
+  },
+  Operation {
+    id: "actions.download_artifact",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}",
+  },
+  Operation {
+    id: "actions.get_actions_cache_usage",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/cache/usage",
+  },
+  Operation {
+    id: "actions.get_actions_cache_list",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/caches",
+  },
+  Operation {
+    id: "actions.delete_actions_cache_by_key",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/caches",
+  },
+  Operation {
+    id: "actions.delete_actions_cache_by_id",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/caches/{cache_id}",
+  },
+  Operation {
+    id: "actions.get_job_for_workflow_run",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/jobs/{job_id}",
+  },
+  Operation {
+    id: "actions.download_job_logs_for_workflow_run",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/jobs/{job_id}/logs",
+  },
+  Operation {
+    id: "actions.re_run_job_for_workflow_run",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/jobs/{job_id}/rerun",
+  },
+  Operation {
+    id: "actions.get_custom_oidc_sub_claim_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/oidc/customization/sub",
+  },
+  Operation {
+    id: "actions.set_custom_oidc_sub_claim_for_repo",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/oidc/customization/sub",
+  },
+  Operation {
+    id: "actions.list_repo_organization_secrets",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/organization-secrets",
+  },
+  Operation {
+    id: "actions.list_repo_organization_variables",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/organization-variables",
+  },
+  Operation {
+    id: "actions.get_github_actions_permissions_repository",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/permissions",
+  },
+  Operation {
+    id: "actions.set_github_actions_permissions_repository",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/permissions",
+  },
+  Operation {
+    id: "actions.get_workflow_access_to_repository",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/permissions/access",
+  },
+  Operation {
+    id: "actions.set_workflow_access_to_repository",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/permissions/access",
+  },
+  Operation {
+    id: "actions.get_allowed_actions_repository",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/permissions/selected-actions",
+  },
+  Operation {
+    id: "actions.set_allowed_actions_repository",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/permissions/selected-actions",
+  },
+  Operation {
+    id: "actions.get_github_actions_default_workflow_permissions_repository",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/permissions/workflow",
+  },
+  Operation {
+    id: "actions.set_github_actions_default_workflow_permissions_repository",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/permissions/workflow",
+  },
+  Operation {
+    id: "actions.list_self_hosted_runners_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runners",
+  },
+  Operation {
+    id: "actions.list_runner_applications_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runners/downloads",
+  },
+  Operation {
+    id: "actions.generate_runner_jitconfig_for_repo",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runners/generate-jitconfig",
+  },
+  Operation {
+    id: "actions.create_registration_token_for_repo",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runners/registration-token",
+  },
+  Operation {
+    id: "actions.create_remove_token_for_repo",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runners/remove-token",
+  },
+  Operation {
+    id: "actions.get_self_hosted_runner_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runners/{runner_id}",
+  },
+  Operation {
+    id: "actions.delete_self_hosted_runner_from_repo",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/runners/{runner_id}",
+  },
+  Operation {
+    id: "actions.list_labels_for_self_hosted_runner_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.add_custom_labels_to_self_hosted_runner_for_repo",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.set_custom_labels_for_self_hosted_runner_for_repo",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.remove_all_custom_labels_from_self_hosted_runner_for_repo",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  },
+  Operation {
+    id: "actions.remove_custom_label_from_self_hosted_runner_for_repo",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels/{name}",
+  },
+  Operation {
+    id: "actions.list_workflow_runs_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs",
+  },
+  Operation {
+    id: "actions.get_workflow_run",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}",
+  },
+  Operation {
+    id: "actions.delete_workflow_run",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}",
+  },
+  Operation {
+    id: "actions.get_reviews_for_run",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/approvals",
+  },
+  Operation {
+    id: "actions.approve_workflow_run",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/approve",
+  },
+  Operation {
+    id: "actions.list_workflow_run_artifacts",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts",
+  },
+  Operation {
+    id: "actions.get_workflow_run_attempt",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}",
+  },
+  Operation {
+    id: "actions.list_jobs_for_workflow_run_attempt",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/jobs",
+  },
+  Operation {
+    id: "actions.download_workflow_run_attempt_logs",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/logs",
+  },
+  Operation {
+    id: "actions.cancel_workflow_run",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/cancel",
+  },
+  Operation {
+    id: "actions.review_custom_gates_for_run",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/deployment_protection_rule",
+  },
+  Operation {
+    id: "actions.force_cancel_workflow_run",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/force-cancel",
+  },
+  Operation {
+    id: "actions.list_jobs_for_workflow_run",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/jobs",
+  },
+  Operation {
+    id: "actions.download_workflow_run_logs",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/logs",
+  },
+  Operation {
+    id: "actions.delete_workflow_run_logs",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/logs",
+  },
+  Operation {
+    id: "actions.get_pending_deployments_for_run",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments",
+  },
+  Operation {
+    id: "actions.review_pending_deployments_for_run",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments",
+  },
+  Operation {
+    id: "actions.re_run_workflow",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/rerun",
+  },
+  Operation {
+    id: "actions.re_run_workflow_failed_jobs",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/rerun-failed-jobs",
+  },
+  Operation {
+    id: "actions.get_workflow_run_usage",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/runs/{run_id}/timing",
+  },
+  Operation {
+    id: "actions.list_repo_secrets",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/secrets",
+  },
+  Operation {
+    id: "actions.get_repo_public_key",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/secrets/public-key",
+  },
+  Operation {
+    id: "actions.get_repo_secret",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.create_or_update_repo_secret",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.delete_repo_secret",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.list_repo_variables",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/variables",
+  },
+  Operation {
+    id: "actions.create_repo_variable",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/variables",
+  },
+  Operation {
+    id: "actions.get_repo_variable",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/variables/{name}",
+  },
+  Operation {
+    id: "actions.update_repo_variable",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/actions/variables/{name}",
+  },
+  Operation {
+    id: "actions.delete_repo_variable",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/actions/variables/{name}",
+  },
+  Operation {
+    id: "actions.list_repo_workflows",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/workflows",
+  },
+  Operation {
+    id: "actions.get_workflow",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}",
+  },
+  Operation {
+    id: "actions.disable_workflow",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/disable",
+  },
+  Operation {
+    id: "actions.create_workflow_dispatch",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches",
+  },
+  Operation {
+    id: "actions.enable_workflow",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/enable",
+  },
+  Operation {
+    id: "actions.list_workflow_runs",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs",
+  },
+  Operation {
+    id: "actions.get_workflow_usage",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/timing",
+  },
+  Operation {
+    id: "actions.list_environment_secrets",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/secrets",
+  },
+  Operation {
+    id: "actions.get_environment_public_key",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/secrets/public-key",
+  },
+  Operation {
+    id: "actions.get_environment_secret",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.create_or_update_environment_secret",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.delete_environment_secret",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/secrets/{secret_name}",
+  },
+  Operation {
+    id: "actions.list_environment_variables",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/variables",
+  },
+  Operation {
+    id: "actions.create_environment_variable",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/variables",
+  },
+  Operation {
+    id: "actions.get_environment_variable",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/variables/{name}",
+  },
+  Operation {
+    id: "actions.update_environment_variable",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/variables/{name}",
+  },
+  Operation {
+    id: "actions.delete_environment_variable",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/variables/{name}",
+  },
+  Operation {
+    id: "oidc.get_oidc_custom_sub_template_for_org",
+    method: "GET",
+    path: "/orgs/{org}/actions/oidc/customization/sub",
+  },
+  Operation {
+    id: "oidc.update_oidc_custom_sub_template_for_org",
+    method: "PUT",
+    path: "/orgs/{org}/actions/oidc/customization/sub",
+  },
+  Operation {
+    id: "code_scanning.list_alerts_for_org",
+    method: "GET",
+    path: "/orgs/{org}/code-scanning/alerts",
+  },
+  Operation {
+    id: "code_scanning.list_alerts_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/alerts",
+  },
+  Operation {
+    id: "code_scanning.get_alert",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}",
+  },
+  Operation {
+    id: "code_scanning.update_alert",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}",
+  },
+  Operation {
+    id: "code_scanning.list_alert_instances",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances",
+  },
+  Operation {
+    id: "code_scanning.list_recent_analyses",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/analyses",
+  },
+  Operation {
+    id: "code_scanning.get_analysis",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}",
+  },
+  Operation {
+    id: "code_scanning.delete_analysis",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}",
+  },
+  Operation {
+    id: "code_scanning.list_codeql_databases",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/codeql/databases",
+  },
+  Operation {
+    id: "code_scanning.get_codeql_database",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/codeql/databases/{language}",
+  },
+  Operation {
+    id: "code_scanning.get_default_setup",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/default-setup",
+  },
+  Operation {
+    id: "code_scanning.update_default_setup",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/code-scanning/default-setup",
+  },
+  Operation {
+    id: "code_scanning.upload_sarif",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/code-scanning/sarifs",
+  },
+  Operation {
+    id: "code_scanning.get_sarif",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/code-scanning/sarifs/{sarif_id}",
+  },
+  Operation {
+    id: "codespaces.list_in_organization",
+    method: "GET",
+    path: "/orgs/{org}/codespaces",
+  },
+  Operation {
+    id: "codespaces.set_codespaces_access",
+    method: "PUT",
+    path: "/orgs/{org}/codespaces/access",
+  },
+  Operation {
+    id: "codespaces.set_codespaces_access_users",
+    method: "POST",
+    path: "/orgs/{org}/codespaces/access/selected_users",
+  },
+  Operation {
+    id: "codespaces.delete_codespaces_access_users",
+    method: "DELETE",
+    path: "/orgs/{org}/codespaces/access/selected_users",
+  },
+  Operation {
+    id: "codespaces.list_org_secrets",
+    method: "GET",
+    path: "/orgs/{org}/codespaces/secrets",
+  },
+  Operation {
+    id: "codespaces.get_org_public_key",
+    method: "GET",
+    path: "/orgs/{org}/codespaces/secrets/public-key",
+  },
+  Operation {
+    id: "codespaces.get_org_secret",
+    method: "GET",
+    path: "/orgs/{org}/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.create_or_update_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.delete_org_secret",
+    method: "DELETE",
+    path: "/orgs/{org}/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.list_selected_repos_for_org_secret",
+    method: "GET",
+    path: "/orgs/{org}/codespaces/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "codespaces.set_selected_repos_for_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/codespaces/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "codespaces.add_selected_repo_to_org_secret",
+    method: "PUT",
+    path: "/orgs/{org}/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "codespaces.remove_selected_repo_from_org_secret",
+    method: "DELETE",
+    path: "/orgs/{org}/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "codespaces.get_codespaces_for_user_in_org",
+    method: "GET",
+    path: "/orgs/{org}/members/{username}/codespaces",
+  },
+  Operation {
+    id: "codespaces.delete_from_organization",
+    method: "DELETE",
+    path: "/orgs/{org}/members/{username}/codespaces/{codespace_name}",
+  },
+  Operation {
+    id: "codespaces.stop_in_organization",
+    method: "POST",
+    path: "/orgs/{org}/members/{username}/codespaces/{codespace_name}/stop",
+  },
+  Operation {
+    id: "codespaces.list_in_repository_for_authenticated_user",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces",
+  },
+  Operation {
+    id: "codespaces.create_with_repo_for_authenticated_user",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/codespaces",
+  },
+  Operation {
+    id: "codespaces.list_devcontainers_in_repository_for_authenticated_user",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces/devcontainers",
+  },
+  Operation {
+    id: "codespaces.repo_machines_for_authenticated_user",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces/machines",
+  },
+  Operation {
+    id: "codespaces.pre_flight_with_repo_for_authenticated_user",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces/new",
+  },
+  Operation {
+    id: "codespaces.check_permissions_for_devcontainer",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces/permissions_check",
+  },
+  Operation {
+    id: "codespaces.list_repo_secrets",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces/secrets",
+  },
+  Operation {
+    id: "codespaces.get_repo_public_key",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces/secrets/public-key",
+  },
+  Operation {
+    id: "codespaces.get_repo_secret",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.create_or_update_repo_secret",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.delete_repo_secret",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.create_with_pr_for_authenticated_user",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/codespaces",
+  },
+  Operation {
+    id: "codespaces.list_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces",
+  },
+  Operation {
+    id: "codespaces.create_for_authenticated_user",
+    method: "POST",
+    path: "/user/codespaces",
+  },
+  Operation {
+    id: "codespaces.list_secrets_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces/secrets",
+  },
+  Operation {
+    id: "codespaces.get_public_key_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces/secrets/public-key",
+  },
+  Operation {
+    id: "codespaces.get_secret_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.create_or_update_secret_for_authenticated_user",
+    method: "PUT",
+    path: "/user/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.delete_secret_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/codespaces/secrets/{secret_name}",
+  },
+  Operation {
+    id: "codespaces.list_repositories_for_secret_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "codespaces.set_repositories_for_secret_for_authenticated_user",
+    method: "PUT",
+    path: "/user/codespaces/secrets/{secret_name}/repositories",
+  },
+  Operation {
+    id: "codespaces.add_repository_for_secret_for_authenticated_user",
+    method: "PUT",
+    path: "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "codespaces.remove_repository_for_secret_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+  },
+  Operation {
+    id: "codespaces.get_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces/{codespace_name}",
+  },
+  Operation {
+    id: "codespaces.update_for_authenticated_user",
+    method: "PATCH",
+    path: "/user/codespaces/{codespace_name}",
+  },
+  Operation {
+    id: "codespaces.delete_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/codespaces/{codespace_name}",
+  },
+  Operation {
+    id: "codespaces.export_for_authenticated_user",
+    method: "POST",
+    path: "/user/codespaces/{codespace_name}/exports",
+  },
+  Operation {
+    id: "codespaces.get_export_details_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces/{codespace_name}/exports/{export_id}",
+  },
+  Operation {
+    id: "codespaces.codespace_machines_for_authenticated_user",
+    method: "GET",
+    path: "/user/codespaces/{codespace_name}/machines",
+  },
+  Operation {
+    id: "codespaces.publish_for_authenticated_user",
+    method: "POST",
+    path: "/user/codespaces/{codespace_name}/publish",
+  },
+  Operation {
+    id: "codespaces.start_for_authenticated_user",
+    method: "POST",
+    path: "/user/codespaces/{codespace_name}/start",
+  },
+  Operation {
+    id: "codespaces.stop_for_authenticated_user",
+    method: "POST",
+    path: "/user/codespaces/{codespace_name}/stop",
+  },
+  Operation {
+    id: "copilot.get_copilot_organization_details",
+    method: "GET",
+    path: "/orgs/{org}/copilot/billing",
+  },
+  Operation {
+    id: "copilot.list_copilot_seats",
+    method: "GET",
+    path: "/orgs/{org}/copilot/billing/seats",
+  },
+  Operation {
+    id: "copilot.add_copilot_seats_for_teams",
+    method: "POST",
+    path: "/orgs/{org}/copilot/billing/selected_teams",
+  },
+  Operation {
+    id: "copilot.cancel_copilot_seat_assignment_for_teams",
+    method: "DELETE",
+    path: "/orgs/{org}/copilot/billing/selected_teams",
+  },
+  Operation {
+    id: "copilot.add_copilot_seats_for_users",
+    method: "POST",
+    path: "/orgs/{org}/copilot/billing/selected_users",
+  },
+  Operation {
+    id: "copilot.cancel_copilot_seat_assignment_for_users",
+    method: "DELETE",
+    path: "/orgs/{org}/copilot/billing/selected_users",
+  },
+  Operation {
+    id: "copilot.get_copilot_seat_details_for_user",
+    method: "GET",
+    path: "/orgs/{org}/members/{username}/copilot",
+  },
+  Operation {
+    id: "packages.list_docker_migration_conflicting_packages_for_organization",
+    method: "GET",
+    path: "/orgs/{org}/docker/conflicts",
+  },
+  Operation {
+    id: "packages.list_packages_for_organization",
+    method: "GET",
+    path: "/orgs/{org}/packages",
+  },
+  Operation {
+    id: "packages.get_package_for_organization",
+    method: "GET",
+    path: "/orgs/{org}/packages/{package_type}/{package_name}",
+  },
+  Operation {
+    id: "packages.delete_package_for_org",
+    method: "DELETE",
+    path: "/orgs/{org}/packages/{package_type}/{package_name}",
+  },
+  Operation {
+    id: "packages.restore_package_for_org",
+    method: "POST",
+    path: "/orgs/{org}/packages/{package_type}/{package_name}/restore",
+  },
+  Operation {
+    id: "packages.get_all_package_versions_for_package_owned_by_org",
+    method: "GET",
+    path: "/orgs/{org}/packages/{package_type}/{package_name}/versions",
+  },
+  Operation {
+    id: "packages.get_package_version_for_organization",
+    method: "GET",
+    path: "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  },
+  Operation {
+    id: "packages.delete_package_version_for_org",
+    method: "DELETE",
+    path: "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  },
+  Operation {
+    id: "packages.restore_package_version_for_org",
+    method: "POST",
+    path: "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+  },
+  Operation {
+    id: "packages.list_docker_migration_conflicting_packages_for_authenticated_user",
+    method: "GET",
+    path: "/user/docker/conflicts",
+  },
+  Operation {
+    id: "packages.list_packages_for_authenticated_user",
+    method: "GET",
+    path: "/user/packages",
+  },
+  Operation {
+    id: "packages.get_package_for_authenticated_user",
+    method: "GET",
+    path: "/user/packages/{package_type}/{package_name}",
+  },
+  Operation {
+    id: "packages.delete_package_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/packages/{package_type}/{package_name}",
+  },
+  Operation {
+    id: "packages.restore_package_for_authenticated_user",
+    method: "POST",
+    path: "/user/packages/{package_type}/{package_name}/restore",
+  },
+  Operation {
+    id: "packages.get_all_package_versions_for_package_owned_by_authenticated_user",
+    method: "GET",
+    path: "/user/packages/{package_type}/{package_name}/versions",
+  },
+  Operation {
+    id: "packages.get_package_version_for_authenticated_user",
+    method: "GET",
+    path: "/user/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  },
+  Operation {
+    id: "packages.delete_package_version_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  },
+  Operation {
+    id: "packages.restore_package_version_for_authenticated_user",
+    method: "POST",
+    path: "/user/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+  },
+  Operation {
+    id: "packages.list_docker_migration_conflicting_packages_for_user",
+    method: "GET",
+    path: "/users/{username}/docker/conflicts",
+  },
+  Operation {
+    id: "packages.list_packages_for_user",
+    method: "GET",
+    path: "/users/{username}/packages",
+  },
+  Operation {
+    id: "packages.get_package_for_user",
+    method: "GET",
+    path: "/users/{username}/packages/{package_type}/{package_name}",
+  },
+  Operation {
+    id: "packages.delete_package_for_user",
+    method: "DELETE",
+    path: "/users/{username}/packages/{package_type}/{package_name}",
+  },
+  Operation {
+    id: "packages.restore_package_for_user",
+    method: "POST",
+    path: "/users/{username}/packages/{package_type}/{package_name}/restore",
+  },
+  Operation {
+    id: "packages.get_all_package_versions_for_package_owned_by_user",
+    method: "GET",
+    path: "/users/{username}/packages/{package_type}/{package_name}/versions",
+  },
+  Operation {
+    id: "packages.get_package_version_for_user",
+    method: "GET",
+    path: "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  },
+  Operation {
+    id: "packages.delete_package_version_for_user",
+    method: "DELETE",
+    path: "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  },
+  Operation {
+    id: "packages.restore_package_version_for_user",
+    method: "POST",
+    path: "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+  },
+  Operation {
+    id: "interactions.get_restrictions_for_org",
+    method: "GET",
+    path: "/orgs/{org}/interaction-limits",
+  },
+  Operation {
+    id: "interactions.set_restrictions_for_org",
+    method: "PUT",
+    path: "/orgs/{org}/interaction-limits",
+  },
+  Operation {
+    id: "interactions.remove_restrictions_for_org",
+    method: "DELETE",
+    path: "/orgs/{org}/interaction-limits",
+  },
+  Operation {
+    id: "interactions.get_restrictions_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/interaction-limits",
+  },
+  Operation {
+    id: "interactions.set_restrictions_for_repo",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/interaction-limits",
+  },
+  Operation {
+    id: "interactions.remove_restrictions_for_repo",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/interaction-limits",
+  },
+  Operation {
+    id: "interactions.get_restrictions_for_authenticated_user",
+    method: "GET",
+    path: "/user/interaction-limits",
+  },
+  Operation {
+    id: "interactions.set_restrictions_for_authenticated_user",
+    method: "PUT",
+    path: "/user/interaction-limits",
+  },
+  Operation {
+    id: "interactions.remove_restrictions_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/interaction-limits",
+  },
+  Operation {
+    id: "migrations.list_for_org",
+    method: "GET",
+    path: "/orgs/{org}/migrations",
+  },
+  Operation {
+    id: "migrations.start_for_org",
+    method: "POST",
+    path: "/orgs/{org}/migrations",
+  },
+  Operation {
+    id: "migrations.get_status_for_org",
+    method: "GET",
+    path: "/orgs/{org}/migrations/{migration_id}",
+  },
+  Operation {
+    id: "migrations.download_archive_for_org",
+    method: "GET",
+    path: "/orgs/{org}/migrations/{migration_id}/archive",
+  },
+  Operation {
+    id: "migrations.delete_archive_for_org",
+    method: "DELETE",
+    path: "/orgs/{org}/migrations/{migration_id}/archive",
+  },
+  Operation {
+    id: "migrations.unlock_repo_for_org",
+    method: "DELETE",
+    path: "/orgs/{org}/migrations/{migration_id}/repos/{repo_name}/lock",
+  },
+  Operation {
+    id: "migrations.list_repos_for_org",
+    method: "GET",
+    path: "/orgs/{org}/migrations/{migration_id}/repositories",
+  },
+  Operation {
+    id: "migrations.get_import_status",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/import",
+  },
+  Operation {
+    id: "migrations.start_import",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/import",
+  },
+  Operation {
+    id: "migrations.update_import",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/import",
+  },
+  Operation {
+    id: "migrations.cancel_import",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/import",
+  },
+  Operation {
+    id: "migrations.get_commit_authors",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/import/authors",
+  },
+  Operation {
+    id: "migrations.map_commit_author",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/import/authors/{author_id}",
+  },
+  Operation {
+    id: "migrations.get_large_files",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/import/large_files",
+  },
+  Operation {
+    id: "migrations.set_lfs_preference",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/import/lfs",
+  },
+  Operation {
+    id: "migrations.list_for_authenticated_user",
+    method: "GET",
+    path: "/user/migrations",
+  },
+  Operation {
+    id: "migrations.start_for_authenticated_user",
+    method: "POST",
+    path: "/user/migrations",
+  },
+  Operation {
+    id: "migrations.get_status_for_authenticated_user",
+    method: "GET",
+    path: "/user/migrations/{migration_id}",
+  },
+  Operation {
+    id: "migrations.get_archive_for_authenticated_user",
+    method: "GET",
+    path: "/user/migrations/{migration_id}/archive",
+  },
+  Operation {
+    id: "migrations.delete_archive_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/migrations/{migration_id}/archive",
+  },
+  Operation {
+    id: "migrations.unlock_repo_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/migrations/{migration_id}/repos/{repo_name}/lock",
+  },
+  Operation {
+    id: "migrations.list_repos_for_authenticated_user",
+    method: "GET",
+    path: "/user/migrations/{migration_id}/repositories",
+  },
+  Operation {
+    id: "projects.list_for_org",
+    method: "GET",
+    path: "/orgs/{org}/projects",
+  },
+  Operation {
+    id: "projects.create_for_org",
+    method: "POST",
+    path: "/orgs/{org}/projects",
+  },
+  Operation {
+    id: "projects.get_card",
+    method: "GET",
+    path: "/projects/columns/cards/{card_id}",
+  },
+  Operation {
+    id: "projects.update_card",
+    method: "PATCH",
+    path: "/projects/columns/cards/{card_id}",
+  },
+  Operation {
+    id: "projects.delete_card",
+    method: "DELETE",
+    path: "/projects/columns/cards/{card_id}",
+  },
+  Operation {
+    id: "projects.move_card",
+    method: "POST",
+    path: "/projects/columns/cards/{card_id}/moves",
+  },
+  Operation {
+    id: "projects.get_column",
+    method: "GET",
+    path: "/projects/columns/{column_id}",
+  },
+  Operation {
+    id: "projects.update_column",
+    method: "PATCH",
+    path: "/projects/columns/{column_id}",
+  },
+  Operation {
+    id: "projects.delete_column",
+    method: "DELETE",
+    path: "/projects/columns/{column_id}",
+  },
+  Operation {
+    id: "projects.list_cards",
+    method: "GET",
+    path: "/projects/columns/{column_id}/cards",
+  },
+  Operation {
+    id: "projects.create_card",
+    method: "POST",
+    path: "/projects/columns/{column_id}/cards",
+  },
+  Operation {
+    id: "projects.move_column",
+    method: "POST",
+    path: "/projects/columns/{column_id}/moves",
+  },
+  Operation {
+    id: "projects.get",
+    method: "GET",
+    path: "/projects/{project_id}",
+  },
+  Operation {
+    id: "projects.update",
+    method: "PATCH",
+    path: "/projects/{project_id}",
+  },
+  Operation {
+    id: "projects.delete",
+    method: "DELETE",
+    path: "/projects/{project_id}",
+  },
+  Operation {
+    id: "projects.list_collaborators",
+    method: "GET",
+    path: "/projects/{project_id}/collaborators",
+  },
+  Operation {
+    id: "projects.add_collaborator",
+    method: "PUT",
+    path: "/projects/{project_id}/collaborators/{username}",
+  },
+  Operation {
+    id: "projects.remove_collaborator",
+    method: "DELETE",
+    path: "/projects/{project_id}/collaborators/{username}",
+  },
+  Operation {
+    id: "projects.get_permission_for_user",
+    method: "GET",
+    path: "/projects/{project_id}/collaborators/{username}/permission",
+  },
+  Operation {
+    id: "projects.list_columns",
+    method: "GET",
+    path: "/projects/{project_id}/columns",
+  },
+  Operation {
+    id: "projects.create_column",
+    method: "POST",
+    path: "/projects/{project_id}/columns",
+  },
+  Operation {
+    id: "projects.list_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/projects",
+  },
+  Operation {
+    id: "projects.create_for_repo",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/projects",
+  },
+  Operation {
+    id: "projects.create_for_authenticated_user",
+    method: "POST",
+    path: "/user/projects",
+  },
+  Operation {
+    id: "projects.list_for_user",
+    method: "GET",
+    path: "/users/{username}/projects",
+  },
+  Operation {
+    id: "repos.list_for_org",
+    method: "GET",
+    path: "/orgs/{org}/repos",
+  },
+  Operation {
+    id: "repos.create_in_org",
+    method: "POST",
+    path: "/orgs/{org}/repos",
+  },
+  Operation {
+    id: "repos.get_org_rulesets",
+    method: "GET",
+    path: "/orgs/{org}/rulesets",
+  },
+  Operation {
+    id: "repos.create_org_ruleset",
+    method: "POST",
+    path: "/orgs/{org}/rulesets",
+  },
+  Operation {
+    id: "repos.get_org_rule_suites",
+    method: "GET",
+    path: "/orgs/{org}/rulesets/rule-suites",
+  },
+  Operation {
+    id: "repos.get_org_rule_suite",
+    method: "GET",
+    path: "/orgs/{org}/rulesets/rule-suites/{rule_suite_id}",
+  },
+  Operation {
+    id: "repos.get_org_ruleset",
+    method: "GET",
+    path: "/orgs/{org}/rulesets/{ruleset_id}",
+  },
+  Operation {
+    id: "repos.update_org_ruleset",
+    method: "PUT",
+    path: "/orgs/{org}/rulesets/{ruleset_id}",
+  },
+  Operation {
+    id: "repos.delete_org_ruleset",
+    method: "DELETE",
+    path: "/orgs/{org}/rulesets/{ruleset_id}",
+  },
+  Operation {
+    id: "repos.get",
+    method: "GET",
+    path: "/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "repos.update",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "repos.delete",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "repos.list_activities",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/activity",
+  },
+  Operation {
+    id: "repos.list_autolinks",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/autolinks",
+  },
+  Operation {
+    id: "repos.create_autolink",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/autolinks",
+  },
+  Operation {
+    id: "repos.get_autolink",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/autolinks/{autolink_id}",
+  },
+  Operation {
+    id: "repos.delete_autolink",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/autolinks/{autolink_id}",
+  },
+  Operation {
+    id: "repos.check_automated_security_fixes",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/automated-security-fixes",
+  },
+  Operation {
+    id: "repos.enable_automated_security_fixes",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/automated-security-fixes",
+  },
+  Operation {
+    id: "repos.disable_automated_security_fixes",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/automated-security-fixes",
+  },
+  Operation {
+    id: "repos.list_branches",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches",
+  },
+  Operation {
+    id: "repos.get_branch",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}",
+  },
+  Operation {
+    id: "repos.get_branch_protection",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection",
+  },
+  Operation {
+    id: "repos.update_branch_protection",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection",
+  },
+  Operation {
+    id: "repos.delete_branch_protection",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection",
+  },
+  Operation {
+    id: "repos.get_admin_branch_protection",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+  },
+  Operation {
+    id: "repos.set_admin_branch_protection",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+  },
+  Operation {
+    id: "repos.delete_admin_branch_protection",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+  },
+  Operation {
+    id: "repos.get_pull_request_review_protection",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+  },
+  Operation {
+    id: "repos.update_pull_request_review_protection",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+  },
+  Operation {
+    id: "repos.delete_pull_request_review_protection",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+  },
+  Operation {
+    id: "repos.get_commit_signature_protection",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+  },
+  Operation {
+    id: "repos.create_commit_signature_protection",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+  },
+  Operation {
+    id: "repos.delete_commit_signature_protection",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+  },
+  Operation {
+    id: "repos.get_status_checks_protection",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+  },
+  Operation {
+    id: "repos.update_status_check_protection",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+  },
+  Operation {
+    id: "repos.remove_status_check_protection",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+  },
+  Operation {
+    id: "repos.get_all_status_check_contexts",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  },
+  Operation {
+    id: "repos.add_status_check_contexts",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  },
+  Operation {
+    id: "repos.set_status_check_contexts",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  },
+  Operation {
+    id: "repos.remove_status_check_contexts",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  },
+  Operation {
+    id: "repos.get_access_restrictions",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions",
+  },
+  Operation {
+    id: "repos.delete_access_restrictions",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions",
+  },
+  Operation {
+    id: "repos.get_apps_with_access_to_protected_branch",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  },
+  Operation {
+    id: "repos.add_app_access_restrictions",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  },
+  Operation {
+    id: "repos.set_app_access_restrictions",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  },
+  Operation {
+    id: "repos.remove_app_access_restrictions",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  },
+  Operation {
+    id: "repos.get_teams_with_access_to_protected_branch",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  },
+  Operation {
+    id: "repos.add_team_access_restrictions",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  },
+  Operation {
+    id: "repos.set_team_access_restrictions",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  },
+  Operation {
+    id: "repos.remove_team_access_restrictions",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  },
+  Operation {
+    id: "repos.get_users_with_access_to_protected_branch",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  },
+  Operation {
+    id: "repos.add_user_access_restrictions",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  },
+  Operation {
+    id: "repos.set_user_access_restrictions",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  },
+  Operation {
+    id: "repos.remove_user_access_restrictions",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  },
+  Operation {
+    id: "repos.rename_branch",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/branches/{branch}/rename",
+  },
+  Operation {
+    id: "repos.codeowners_errors",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/codeowners/errors",
+  },
+  Operation {
+    id: "repos.list_collaborators",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/collaborators",
+  },
+  Operation {
+    id: "repos.check_collaborator",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/collaborators/{username}",
+  },
+  Operation {
+    id: "repos.add_collaborator",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/collaborators/{username}",
+  },
+  Operation {
+    id: "repos.remove_collaborator",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/collaborators/{username}",
+  },
+  Operation {
+    id: "repos.get_collaborator_permission_level",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/collaborators/{username}/permission",
+  },
+  Operation {
+    id: "repos.list_commit_comments_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/comments",
+  },
+  Operation {
+    id: "repos.get_commit_comment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/comments/{comment_id}",
+  },
+  Operation {
+    id: "repos.update_commit_comment",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/comments/{comment_id}",
+  },
+  Operation {
+    id: "repos.delete_commit_comment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/comments/{comment_id}",
+  },
+  Operation {
+    id: "repos.list_commits",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits",
+  },
+  Operation {
+    id: "repos.list_branches_for_head_commit",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head",
+  },
+  Operation {
+    id: "repos.list_comments_for_commit",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{commit_sha}/comments",
+  },
+  Operation {
+    id: "repos.create_commit_comment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/commits/{commit_sha}/comments",
+  },
+  Operation {
+    id: "repos.list_pull_requests_associated_with_commit",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{commit_sha}/pulls",
+  },
+  Operation {
+    id: "repos.get_commit",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{ref_}",
+  },
+  Operation {
+    id: "repos.get_combined_status_for_ref",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{ref_}/status",
+  },
+  Operation {
+    id: "repos.list_commit_statuses_for_ref",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{ref_}/statuses",
+  },
+  Operation {
+    id: "repos.get_community_profile_metrics",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/community/profile",
+  },
+  Operation {
+    id: "repos.compare_commits",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/compare/{basehead}",
+  },
+  Operation {
+    id: "repos.get_content",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/contents/{path}",
+  },
+  Operation {
+    id: "repos.create_or_update_file_contents",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/contents/{path}",
+  },
+  Operation {
+    id: "repos.delete_file",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/contents/{path}",
+  },
+  Operation {
+    id: "repos.list_contributors",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/contributors",
+  },
+  Operation {
+    id: "repos.list_deployments",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/deployments",
+  },
+  Operation {
+    id: "repos.create_deployment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/deployments",
+  },
+  Operation {
+    id: "repos.get_deployment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/deployments/{deployment_id}",
+  },
+  Operation {
+    id: "repos.delete_deployment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/deployments/{deployment_id}",
+  },
+  Operation {
+    id: "repos.list_deployment_statuses",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses",
+  },
+  Operation {
+    id: "repos.create_deployment_status",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses",
+  },
+  Operation {
+    id: "repos.get_deployment_status",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses/{status_id}",
+  },
+  Operation {
+    id: "repos.create_dispatch_event",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/dispatches",
+  },
+  Operation {
+    id: "repos.get_all_environments",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments",
+  },
+  Operation {
+    id: "repos.get_environment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}",
+  },
+  Operation {
+    id: "repos.create_or_update_environment",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}",
+  },
+  Operation {
+    id: "repos.delete_an_environment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}",
+  },
+  Operation {
+    id: "repos.list_deployment_branch_policies",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies",
+  },
+  Operation {
+    id: "repos.create_deployment_branch_policy",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies",
+  },
+  Operation {
+    id: "repos.get_deployment_branch_policy",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies/{branch_policy_id}",
+  },
+  Operation {
+    id: "repos.update_deployment_branch_policy",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies/{branch_policy_id}",
+  },
+  Operation {
+    id: "repos.delete_deployment_branch_policy",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies/{branch_policy_id}",
+  },
+  Operation {
+    id: "repos.get_all_deployment_protection_rules",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules",
+  },
+  Operation {
+    id: "repos.create_deployment_protection_rule",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules",
+  },
+  Operation {
+    id: "repos.list_custom_deployment_rule_integrations",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules/apps",
+  },
+  Operation {
+    id: "repos.get_custom_deployment_protection_rule",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules/{protection_rule_id}",
+  },
+  Operation {
+    id: "repos.disable_deployment_protection_rule",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules/{protection_rule_id}",
+  },
+  Operation {
+    id: "repos.list_forks",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/forks",
+  },
+  Operation {
+    id: "repos.create_fork",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/forks",
+  },
+  Operation {
+    id: "repos.list_webhooks",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/hooks",
+  },
+  Operation {
+    id: "repos.create_webhook",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/hooks",
+  },
+  Operation {
+    id: "repos.get_webhook",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}",
+  },
+  Operation {
+    id: "repos.update_webhook",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}",
+  },
+  Operation {
+    id: "repos.delete_webhook",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}",
+  },
+  Operation {
+    id: "repos.get_webhook_config_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}/config",
+  },
+  Operation {
+    id: "repos.update_webhook_config_for_repo",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}/config",
+  },
+  Operation {
+    id: "repos.list_webhook_deliveries",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries",
+  },
+  Operation {
+    id: "repos.get_webhook_delivery",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}",
+  },
+  Operation {
+    id: "repos.redeliver_webhook_delivery",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}/attempts",
+  },
+  Operation {
+    id: "repos.ping_webhook",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}/pings",
+  },
+  Operation {
+    id: "repos.test_push_webhook",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/hooks/{hook_id}/tests",
+  },
+  Operation {
+    id: "repos.list_invitations",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/invitations",
+  },
+  Operation {
+    id: "repos.update_invitation",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/invitations/{invitation_id}",
+  },
+  Operation {
+    id: "repos.delete_invitation",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/invitations/{invitation_id}",
+  },
+  Operation {
+    id: "repos.list_deploy_keys",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/keys",
+  },
+  Operation {
+    id: "repos.create_deploy_key",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/keys",
+  },
+  Operation {
+    id: "repos.get_deploy_key",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/keys/{key_id}",
+  },
+  Operation {
+    id: "repos.delete_deploy_key",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/keys/{key_id}",
+  },
+  Operation {
+    id: "repos.list_languages",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/languages",
+  },
+  Operation {
+    id: "repos.merge_upstream",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/merge-upstream",
+  },
+  Operation {
+    id: "repos.merge",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/merges",
+  },
+  Operation {
+    id: "repos.get_pages",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pages",
+  },
+  Operation {
+    id: "repos.create_pages_site",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pages",
+  },
+  Operation {
+    id: "repos.update_information_about_pages_site",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/pages",
+  },
+  Operation {
+    id: "repos.delete_pages_site",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/pages",
+  },
+  Operation {
+    id: "repos.list_pages_builds",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pages/builds",
+  },
+  Operation {
+    id: "repos.request_pages_build",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pages/builds",
+  },
+  Operation {
+    id: "repos.get_latest_pages_build",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pages/builds/latest",
+  },
+  Operation {
+    id: "repos.get_pages_build",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pages/builds/{build_id}",
+  },
+  Operation {
+    id: "repos.create_pages_deployment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pages/deployments",
+  },
+  Operation {
+    id: "repos.get_pages_deployment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pages/deployments/{pages_deployment_id}",
+  },
+  Operation {
+    id: "repos.cancel_pages_deployment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pages/deployments/{pages_deployment_id}/cancel",
+  },
+  Operation {
+    id: "repos.get_pages_health_check",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pages/health",
+  },
+  Operation {
+    id: "repos.check_private_vulnerability_reporting",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/private-vulnerability-reporting",
+  },
+  Operation {
+    id: "repos.enable_private_vulnerability_reporting",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/private-vulnerability-reporting",
+  },
+  Operation {
+    id: "repos.disable_private_vulnerability_reporting",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/private-vulnerability-reporting",
+  },
+  Operation {
+    id: "repos.get_custom_properties_values",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/properties/values",
+  },
+  Operation {
+    id: "repos.create_or_update_custom_properties_values",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/properties/values",
+  },
+  Operation {
+    id: "repos.get_readme",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/readme",
+  },
+  Operation {
+    id: "repos.get_readme_in_directory",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/readme/{dir}",
+  },
+  Operation {
+    id: "repos.list_releases",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/releases",
+  },
+  Operation {
+    id: "repos.create_release",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/releases",
+  },
+  Operation {
+    id: "repos.get_release_asset",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+  },
+  Operation {
+    id: "repos.update_release_asset",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+  },
+  Operation {
+    id: "repos.delete_release_asset",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+  },
+  Operation {
+    id: "repos.generate_release_notes",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/releases/generate-notes",
+  },
+  Operation {
+    id: "repos.get_latest_release",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/releases/latest",
+  },
+  Operation {
+    id: "repos.get_release_by_tag",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/releases/tags/{tag}",
+  },
+  Operation {
+    id: "repos.get_release",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/releases/{release_id}",
+  },
+  Operation {
+    id: "repos.update_release",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/releases/{release_id}",
+  },
+  Operation {
+    id: "repos.delete_release",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/releases/{release_id}",
+  },
+  Operation {
+    id: "repos.list_release_assets",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/releases/{release_id}/assets",
+  },
+  Operation {
+    id: "repos.upload_release_asset",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/releases/{release_id}/assets",
+  },
+  Operation {
+    id: "repos.get_branch_rules",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/rules/branches/{branch}",
+  },
+  Operation {
+    id: "repos.get_repo_rulesets",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/rulesets",
+  },
+  Operation {
+    id: "repos.create_repo_ruleset",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/rulesets",
+  },
+  Operation {
+    id: "repos.get_repo_rule_suites",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/rulesets/rule-suites",
+  },
+  Operation {
+    id: "repos.get_repo_rule_suite",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/rulesets/rule-suites/{rule_suite_id}",
+  },
+  Operation {
+    id: "repos.get_repo_ruleset",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/rulesets/{ruleset_id}",
+  },
+  Operation {
+    id: "repos.update_repo_ruleset",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/rulesets/{ruleset_id}",
+  },
+  Operation {
+    id: "repos.delete_repo_ruleset",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/rulesets/{ruleset_id}",
+  },
+  Operation {
+    id: "repos.get_code_frequency_stats",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/stats/code_frequency",
+  },
+  Operation {
+    id: "repos.get_commit_activity_stats",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/stats/commit_activity",
+  },
+  Operation {
+    id: "repos.get_contributors_stats",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/stats/contributors",
+  },
+  Operation {
+    id: "repos.get_participation_stats",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/stats/participation",
+  },
+  Operation {
+    id: "repos.get_punch_card_stats",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/stats/punch_card",
+  },
+  Operation {
+    id: "repos.create_commit_status",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/statuses/{sha}",
+  },
+  Operation {
+    id: "repos.list_tags",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/tags",
+  },
+  Operation {
+    id: "repos.list_tag_protection",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/tags/protection",
+  },
+  Operation {
+    id: "repos.create_tag_protection",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/tags/protection",
+  },
+  Operation {
+    id: "repos.delete_tag_protection",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/tags/protection/{tag_protection_id}",
+  },
+  Operation {
+    id: "repos.download_tarball_archive",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/tarball/{ref_}",
+  },
+  Operation {
+    id: "repos.list_teams",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/teams",
+  },
+  Operation {
+    id: "repos.get_all_topics",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/topics",
+  },
+  Operation {
+    id: "repos.replace_all_topics",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/topics",
+  },
+  Operation {
+    id: "repos.get_clones",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/traffic/clones",
+  },
+  Operation {
+    id: "repos.get_top_paths",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/traffic/popular/paths",
+  },
+  Operation {
+    id: "repos.get_top_referrers",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/traffic/popular/referrers",
+  },
+  Operation {
+    id: "repos.get_views",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/traffic/views",
+  },
+  Operation {
+    id: "repos.transfer",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/transfer",
+  },
+  Operation {
+    id: "repos.check_vulnerability_alerts",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/vulnerability-alerts",
+  },
+  Operation {
+    id: "repos.enable_vulnerability_alerts",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/vulnerability-alerts",
+  },
+  Operation {
+    id: "repos.disable_vulnerability_alerts",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/vulnerability-alerts",
+  },
+  Operation {
+    id: "repos.download_zipball_archive",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/zipball/{ref_}",
+  },
+  Operation {
+    id: "repos.create_using_template",
+    method: "POST",
+    path: "/repos/{template_owner}/{template_repo}/generate",
+  },
+  Operation {
+    id: "repos.list_public",
+    method: "GET",
+    path: "/repositories",
+  },
+  Operation {
+    id: "repos.list_for_authenticated_user",
+    method: "GET",
+    path: "/user/repos",
+  },
+  Operation {
+    id: "repos.create_for_authenticated_user",
+    method: "POST",
+    path: "/user/repos",
+  },
+  Operation {
+    id: "repos.list_invitations_for_authenticated_user",
+    method: "GET",
+    path: "/user/repository_invitations",
+  },
+  Operation {
+    id: "repos.accept_invitation_for_authenticated_user",
+    method: "PATCH",
+    path: "/user/repository_invitations/{invitation_id}",
+  },
+  Operation {
+    id: "repos.decline_invitation_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/repository_invitations/{invitation_id}",
+  },
+  Operation {
+    id: "repos.list_for_user",
+    method: "GET",
+    path: "/users/{username}/repos",
+  },
+  Operation {
+    id: "billing.get_github_actions_billing_org",
+    method: "GET",
+    path: "/orgs/{org}/settings/billing/actions",
+  },
+  Operation {
+    id: "billing.get_github_packages_billing_org",
+    method: "GET",
+    path: "/orgs/{org}/settings/billing/packages",
+  },
+  Operation {
+    id: "billing.get_shared_storage_billing_org",
+    method: "GET",
+    path: "/orgs/{org}/settings/billing/shared-storage",
+  },
+  Operation {
+    id: "billing.get_github_actions_billing_user",
+    method: "GET",
+    path: "/users/{username}/settings/billing/actions",
+  },
+  Operation {
+    id: "billing.get_github_packages_billing_user",
+    method: "GET",
+    path: "/users/{username}/settings/billing/packages",
+  },
+  Operation {
+    id: "billing.get_shared_storage_billing_user",
+    method: "GET",
+    path: "/users/{username}/settings/billing/shared-storage",
+  },
+  Operation {
+    id: "teams.list",
+    method: "GET",
+    path: "/orgs/{org}/teams",
+  },
+  Operation {
+    id: "teams.create",
+    method: "POST",
+    path: "/orgs/{org}/teams",
+  },
+  Operation {
+    id: "teams.get_by_name",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}",
+  },
+  Operation {
+    id: "teams.update_in_org",
+    method: "PATCH",
+    path: "/orgs/{org}/teams/{team_slug}",
+  },
+  Operation {
+    id: "teams.delete_in_org",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}",
+  },
+  Operation {
+    id: "teams.list_discussions_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/discussions",
+  },
+  Operation {
+    id: "teams.create_discussion_in_org",
+    method: "POST",
+    path: "/orgs/{org}/teams/{team_slug}/discussions",
+  },
+  Operation {
+    id: "teams.get_discussion_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+  },
+  Operation {
+    id: "teams.update_discussion_in_org",
+    method: "PATCH",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+  },
+  Operation {
+    id: "teams.delete_discussion_in_org",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+  },
+  Operation {
+    id: "teams.list_discussion_comments_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments",
+  },
+  Operation {
+    id: "teams.create_discussion_comment_in_org",
+    method: "POST",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments",
+  },
+  Operation {
+    id: "teams.get_discussion_comment_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+  },
+  Operation {
+    id: "teams.update_discussion_comment_in_org",
+    method: "PATCH",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+  },
+  Operation {
+    id: "teams.delete_discussion_comment_in_org",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+  },
+  Operation {
+    id: "teams.list_pending_invitations_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/invitations",
+  },
+  Operation {
+    id: "teams.list_members_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/members",
+  },
+  Operation {
+    id: "teams.get_membership_for_user_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+  },
+  Operation {
+    id: "teams.add_or_update_membership_for_user_in_org",
+    method: "PUT",
+    path: "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+  },
+  Operation {
+    id: "teams.remove_membership_for_user_in_org",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+  },
+  Operation {
+    id: "teams.list_projects_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/projects",
+  },
+  Operation {
+    id: "teams.check_permissions_for_project_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+  },
+  Operation {
+    id: "teams.add_or_update_project_permissions_in_org",
+    method: "PUT",
+    path: "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+  },
+  Operation {
+    id: "teams.remove_project_in_org",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+  },
+  Operation {
+    id: "teams.list_repos_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/repos",
+  },
+  Operation {
+    id: "teams.check_permissions_for_repo_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "teams.add_or_update_repo_permissions_in_org",
+    method: "PUT",
+    path: "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "teams.remove_repo_in_org",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "teams.list_child_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/teams",
+  },
+  Operation {
+    id: "teams.get_legacy",
+    method: "GET",
+    path: "/teams/{team_id}",
+  },
+  Operation {
+    id: "teams.update_legacy",
+    method: "PATCH",
+    path: "/teams/{team_id}",
+  },
+  Operation {
+    id: "teams.delete_legacy",
+    method: "DELETE",
+    path: "/teams/{team_id}",
+  },
+  Operation {
+    id: "teams.list_discussions_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/discussions",
+  },
+  Operation {
+    id: "teams.create_discussion_legacy",
+    method: "POST",
+    path: "/teams/{team_id}/discussions",
+  },
+  Operation {
+    id: "teams.get_discussion_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/discussions/{discussion_number}",
+  },
+  Operation {
+    id: "teams.update_discussion_legacy",
+    method: "PATCH",
+    path: "/teams/{team_id}/discussions/{discussion_number}",
+  },
+  Operation {
+    id: "teams.delete_discussion_legacy",
+    method: "DELETE",
+    path: "/teams/{team_id}/discussions/{discussion_number}",
+  },
+  Operation {
+    id: "teams.list_discussion_comments_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/discussions/{discussion_number}/comments",
+  },
+  Operation {
+    id: "teams.create_discussion_comment_legacy",
+    method: "POST",
+    path: "/teams/{team_id}/discussions/{discussion_number}/comments",
+  },
+  Operation {
+    id: "teams.get_discussion_comment_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+  },
+  Operation {
+    id: "teams.update_discussion_comment_legacy",
+    method: "PATCH",
+    path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+  },
+  Operation {
+    id: "teams.delete_discussion_comment_legacy",
+    method: "DELETE",
+    path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+  },
+  Operation {
+    id: "teams.list_pending_invitations_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/invitations",
+  },
+  Operation {
+    id: "teams.list_members_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/members",
+  },
+  Operation {
+    id: "teams.get_member_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/members/{username}",
+  },
+  Operation {
+    id: "teams.add_member_legacy",
+    method: "PUT",
+    path: "/teams/{team_id}/members/{username}",
+  },
+  Operation {
+    id: "teams.remove_member_legacy",
+    method: "DELETE",
+    path: "/teams/{team_id}/members/{username}",
+  },
+  Operation {
+    id: "teams.get_membership_for_user_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/memberships/{username}",
+  },
+  Operation {
+    id: "teams.add_or_update_membership_for_user_legacy",
+    method: "PUT",
+    path: "/teams/{team_id}/memberships/{username}",
+  },
+  Operation {
+    id: "teams.remove_membership_for_user_legacy",
+    method: "DELETE",
+    path: "/teams/{team_id}/memberships/{username}",
+  },
+  Operation {
+    id: "teams.list_projects_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/projects",
+  },
+  Operation {
+    id: "teams.check_permissions_for_project_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/projects/{project_id}",
+  },
+  Operation {
+    id: "teams.add_or_update_project_permissions_legacy",
+    method: "PUT",
+    path: "/teams/{team_id}/projects/{project_id}",
+  },
+  Operation {
+    id: "teams.remove_project_legacy",
+    method: "DELETE",
+    path: "/teams/{team_id}/projects/{project_id}",
+  },
+  Operation {
+    id: "teams.list_repos_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/repos",
+  },
+  Operation {
+    id: "teams.check_permissions_for_repo_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "teams.add_or_update_repo_permissions_legacy",
+    method: "PUT",
+    path: "/teams/{team_id}/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "teams.remove_repo_legacy",
+    method: "DELETE",
+    path: "/teams/{team_id}/repos/{owner}/{repo}",
+  },
+  Operation {
+    id: "teams.list_child_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/teams",
+  },
+  Operation {
+    id: "teams.list_for_authenticated_user",
+    method: "GET",
+    path: "/user/teams",
+  },
+  Operation {
+    id: "reactions.list_for_team_discussion_comment_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_team_discussion_comment_in_org",
+    method: "POST",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  },
+  Operation {
+    id: "reactions.delete_for_team_discussion_comment",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions/{reaction_id}",
+  },
+  Operation {
+    id: "reactions.list_for_team_discussion_in_org",
+    method: "GET",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_team_discussion_in_org",
+    method: "POST",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions",
+  },
+  Operation {
+    id: "reactions.delete_for_team_discussion",
+    method: "DELETE",
+    path: "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions/{reaction_id}",
+  },
+  Operation {
+    id: "reactions.list_for_commit_comment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/comments/{comment_id}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_commit_comment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/comments/{comment_id}/reactions",
+  },
+  Operation {
+    id: "reactions.delete_for_commit_comment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}",
+  },
+  Operation {
+    id: "reactions.list_for_issue_comment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_issue_comment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+  },
+  Operation {
+    id: "reactions.delete_for_issue_comment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}",
+  },
+  Operation {
+    id: "reactions.list_for_issue",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_issue",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+  },
+  Operation {
+    id: "reactions.delete_for_issue",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}",
+  },
+  Operation {
+    id: "reactions.list_for_pull_request_review_comment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_pull_request_review_comment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions",
+  },
+  Operation {
+    id: "reactions.delete_for_pull_request_comment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions/{reaction_id}",
+  },
+  Operation {
+    id: "reactions.list_for_release",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/releases/{release_id}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_release",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/releases/{release_id}/reactions",
+  },
+  Operation {
+    id: "reactions.delete_for_release",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/releases/{release_id}/reactions/{reaction_id}",
+  },
+  Operation {
+    id: "reactions.list_for_team_discussion_comment_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_team_discussion_comment_legacy",
+    method: "POST",
+    path: "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  },
+  Operation {
+    id: "reactions.list_for_team_discussion_legacy",
+    method: "GET",
+    path: "/teams/{team_id}/discussions/{discussion_number}/reactions",
+  },
+  Operation {
+    id: "reactions.create_for_team_discussion_legacy",
+    method: "POST",
+    path: "/teams/{team_id}/discussions/{discussion_number}/reactions",
+  },
+  Operation {
+    id: "rate_limit.get",
+    method: "GET",
+    path: "/rate_limit",
+  },
+  Operation {
+    id: "checks.create",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/check-runs",
+  },
+  Operation {
+    id: "checks.get",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/check-runs/{check_run_id}",
+  },
+  Operation {
+    id: "checks.update",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/check-runs/{check_run_id}",
+  },
+  Operation {
+    id: "checks.list_annotations",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations",
+  },
+  Operation {
+    id: "checks.rerequest_run",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/check-runs/{check_run_id}/rerequest",
+  },
+  Operation {
+    id: "checks.create_suite",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/check-suites",
+  },
+  Operation {
+    id: "checks.set_suites_preferences",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/check-suites/preferences",
+  },
+  Operation {
+    id: "checks.get_suite",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/check-suites/{check_suite_id}",
+  },
+  Operation {
+    id: "checks.list_for_suite",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs",
+  },
+  Operation {
+    id: "checks.rerequest_suite",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/check-suites/{check_suite_id}/rerequest",
+  },
+  Operation {
+    id: "checks.list_for_ref",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{ref_}/check-runs",
+  },
+  Operation {
+    id: "checks.list_suites_for_ref",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/commits/{ref_}/check-suites",
+  },
+  Operation {
+    id: "dependency_graph.diff_range",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/dependency-graph/compare/{basehead}",
+  },
+  Operation {
+    id: "dependency_graph.export_sbom",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/dependency-graph/sbom",
+  },
+  Operation {
+    id: "dependency_graph.create_repository_snapshot",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/dependency-graph/snapshots",
+  },
+  Operation {
+    id: "git.create_blob",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/git/blobs",
+  },
+  Operation {
+    id: "git.get_blob",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/git/blobs/{file_sha}",
+  },
+  Operation {
+    id: "git.create_commit",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/git/commits",
+  },
+  Operation {
+    id: "git.get_commit",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/git/commits/{commit_sha}",
+  },
+  Operation {
+    id: "git.list_matching_refs",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/git/matching-refs/{ref_}",
+  },
+  Operation {
+    id: "git.get_ref",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/git/ref/{ref_}",
+  },
+  Operation {
+    id: "git.create_ref",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/git/refs",
+  },
+  Operation {
+    id: "git.update_ref",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/git/refs/{ref_}",
+  },
+  Operation {
+    id: "git.delete_ref",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/git/refs/{ref_}",
+  },
+  Operation {
+    id: "git.create_tag",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/git/tags",
+  },
+  Operation {
+    id: "git.get_tag",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/git/tags/{tag_sha}",
+  },
+  Operation {
+    id: "git.create_tree",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/git/trees",
+  },
+  Operation {
+    id: "git.get_tree",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/git/trees/{tree_sha}",
+  },
+  Operation {
+    id: "pulls.list",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls",
+  },
+  Operation {
+    id: "pulls.create",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls",
+  },
+  Operation {
+    id: "pulls.list_review_comments_for_repo",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/comments",
+  },
+  Operation {
+    id: "pulls.get_review_comment",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+  },
+  Operation {
+    id: "pulls.update_review_comment",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+  },
+  Operation {
+    id: "pulls.delete_review_comment",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+  },
+  Operation {
+    id: "pulls.get",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}",
+  },
+  Operation {
+    id: "pulls.update",
+    method: "PATCH",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}",
+  },
+  Operation {
+    id: "pulls.list_review_comments",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/comments",
+  },
+  Operation {
+    id: "pulls.create_review_comment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/comments",
+  },
+  Operation {
+    id: "pulls.create_reply_for_review_comment",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies",
+  },
+  Operation {
+    id: "pulls.list_commits",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/commits",
+  },
+  Operation {
+    id: "pulls.list_files",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/files",
+  },
+  Operation {
+    id: "pulls.check_if_merged",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/merge",
+  },
+  Operation {
+    id: "pulls.merge",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/merge",
+  },
+  Operation {
+    id: "pulls.list_requested_reviewers",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+  },
+  Operation {
+    id: "pulls.request_reviewers",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+  },
+  Operation {
+    id: "pulls.remove_requested_reviewers",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+  },
+  Operation {
+    id: "pulls.list_reviews",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews",
+  },
+  Operation {
+    id: "pulls.create_review",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews",
+  },
+  Operation {
+    id: "pulls.get_review",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+  },
+  Operation {
+    id: "pulls.update_review",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+  },
+  Operation {
+    id: "pulls.delete_pending_review",
+    method: "DELETE",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+  },
+  Operation {
+    id: "pulls.list_comments_for_review",
+    method: "GET",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments",
+  },
+  Operation {
+    id: "pulls.dismiss_review",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/dismissals",
+  },
+  Operation {
+    id: "pulls.submit_review",
+    method: "POST",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events",
+  },
+  Operation {
+    id: "pulls.update_branch",
+    method: "PUT",
+    path: "/repos/{owner}/{repo}/pulls/{pull_number}/update-branch",
+  },
+  Operation {
+    id: "search.code",
+    method: "GET",
+    path: "/search/code",
+  },
+  Operation {
+    id: "search.commits",
+    method: "GET",
+    path: "/search/commits",
+  },
+  Operation {
+    id: "search.issues_and_pull_requests",
+    method: "GET",
+    path: "/search/issues",
+  },
+  Operation {
+    id: "search.labels",
+    method: "GET",
+    path: "/search/labels",
+  },
+  Operation {
+    id: "search.repos",
+    method: "GET",
+    path: "/search/repositories",
+  },
+  Operation {
+    id: "search.topics",
+    method: "GET",
+    path: "/search/topics",
+  },
+  Operation {
+    id: "search.users",
+    method: "GET",
+    path: "/search/users",
+  },
+  Operation {
+    id: "users.get_authenticated",
+    method: "GET",
+    path: "/user",
+  },
+  Operation {
+    id: "users.update_authenticated",
+    method: "PATCH",
+    path: "/user",
+  },
+  Operation {
+    id: "users.list_blocked_by_authenticated_user",
+    method: "GET",
+    path: "/user/blocks",
+  },
+  Operation {
+    id: "users.check_blocked",
+    method: "GET",
+    path: "/user/blocks/{username}",
+  },
+  Operation {
+    id: "users.block",
+    method: "PUT",
+    path: "/user/blocks/{username}",
+  },
+  Operation {
+    id: "users.unblock",
+    method: "DELETE",
+    path: "/user/blocks/{username}",
+  },
+  Operation {
+    id: "users.set_primary_email_visibility_for_authenticated_user",
+    method: "PATCH",
+    path: "/user/email/visibility",
+  },
+  Operation {
+    id: "users.list_emails_for_authenticated_user",
+    method: "GET",
+    path: "/user/emails",
+  },
+  Operation {
+    id: "users.add_email_for_authenticated_user",
+    method: "POST",
+    path: "/user/emails",
+  },
+  Operation {
+    id: "users.delete_email_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/emails",
+  },
+  Operation {
+    id: "users.list_followers_for_authenticated_user",
+    method: "GET",
+    path: "/user/followers",
+  },
+  Operation {
+    id: "users.list_followed_by_authenticated_user",
+    method: "GET",
+    path: "/user/following",
+  },
+  Operation {
+    id: "users.check_person_is_followed_by_authenticated",
+    method: "GET",
+    path: "/user/following/{username}",
+  },
+  Operation {
+    id: "users.follow",
+    method: "PUT",
+    path: "/user/following/{username}",
+  },
+  Operation {
+    id: "users.unfollow",
+    method: "DELETE",
+    path: "/user/following/{username}",
+  },
+  Operation {
+    id: "users.list_gpg_keys_for_authenticated_user",
+    method: "GET",
+    path: "/user/gpg_keys",
+  },
+  Operation {
+    id: "users.create_gpg_key_for_authenticated_user",
+    method: "POST",
+    path: "/user/gpg_keys",
+  },
+  Operation {
+    id: "users.get_gpg_key_for_authenticated_user",
+    method: "GET",
+    path: "/user/gpg_keys/{gpg_key_id}",
+  },
+  Operation {
+    id: "users.delete_gpg_key_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/gpg_keys/{gpg_key_id}",
+  },
+  Operation {
+    id: "users.list_public_ssh_keys_for_authenticated_user",
+    method: "GET",
+    path: "/user/keys",
+  },
+  Operation {
+    id: "users.create_public_ssh_key_for_authenticated_user",
+    method: "POST",
+    path: "/user/keys",
+  },
+  Operation {
+    id: "users.get_public_ssh_key_for_authenticated_user",
+    method: "GET",
+    path: "/user/keys/{key_id}",
+  },
+  Operation {
+    id: "users.delete_public_ssh_key_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/keys/{key_id}",
+  },
+  Operation {
+    id: "users.list_public_emails_for_authenticated_user",
+    method: "GET",
+    path: "/user/public_emails",
+  },
+  Operation {
+    id: "users.list_social_accounts_for_authenticated_user",
+    method: "GET",
+    path: "/user/social_accounts",
+  },
+  Operation {
+    id: "users.add_social_account_for_authenticated_user",
+    method: "POST",
+    path: "/user/social_accounts",
+  },
+  Operation {
+    id: "users.delete_social_account_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/social_accounts",
+  },
+  Operation {
+    id: "users.list_ssh_signing_keys_for_authenticated_user",
+    method: "GET",
+    path: "/user/ssh_signing_keys",
+  },
+  Operation {
+    id: "users.create_ssh_signing_key_for_authenticated_user",
+    method: "POST",
+    path: "/user/ssh_signing_keys",
+  },
+  Operation {
+    id: "users.get_ssh_signing_key_for_authenticated_user",
+    method: "GET",
+    path: "/user/ssh_signing_keys/{ssh_signing_key_id}",
+  },
+  Operation {
+    id: "users.delete_ssh_signing_key_for_authenticated_user",
+    method: "DELETE",
+    path: "/user/ssh_signing_keys/{ssh_signing_key_id}",
+  },
+  Operation {
+    id: "users.list",
+    method: "GET",
+    path: "/users",
+  },
+  Operation {
+    id: "users.get_by_username",
+    method: "GET",
+    path: "/users/{username}",
+  },
+  Operation {
+    id: "users.list_followers_for_user",
+    method: "GET",
+    path: "/users/{username}/followers",
+  },
+  Operation {
+    id: "users.list_following_for_user",
+    method: "GET",
+    path: "/users/{username}/following",
+  },
+  Operation {
+    id: "users.check_following_for_user",
+    method: "GET",
+    path: "/users/{username}/following/{target_user}",
+  },
+  Operation {
+    id: "users.list_gpg_keys_for_user",
+    method: "GET",
+    path: "/users/{username}/gpg_keys",
+  },
+  Operation {
+    id: "users.get_context_for_user",
+    method: "GET",
+    path: "/users/{username}/hovercard",
+  },
+  Operation {
+    id: "users.list_public_keys_for_user",
+    method: "GET",
+    path: "/users/{username}/keys",
+  },
+  Operation {
+    id: "users.list_social_accounts_for_user",
+    method: "GET",
+    path: "/users/{username}/social_accounts",
+  },
+  Operation {
+    id: "users.list_ssh_signing_keys_for_user",
+    method: "GET",
+    path: "/users/{username}/ssh_signing_keys",
+  },
+];
+
+/// Find an endpoint by its `module.function` id, e.g. `pulls.get`.
+pub fn find_operation(id: &str) -> Option<&'static Operation> {
+  OPERATIONS.iter().find(|operation| operation.id == id)
+}
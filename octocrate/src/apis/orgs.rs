@@ -54,7 +54,7 @@ impl GitHubOrgsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/orgs/orgs#get-an-organization](https://docs.github.com/rest/orgs/orgs#get-an-organization)
   pub fn get(&self, org: impl Into<String>) -> Request<(), (), OrganizationFull> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}");
 
     Request::<(), (), OrganizationFull>::builder(&self.config)
@@ -85,7 +85,7 @@ impl GitHubOrgsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/orgs/orgs#update-an-organization](https://docs.github.com/rest/orgs/orgs#update-an-organization)
   pub fn update(&self, org: impl Into<String>) -> Request<OrgsUpdateRequest, (), OrganizationFull> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}");
 
     Request::<OrgsUpdateRequest, (), OrganizationFull>::builder(&self.config)
@@ -105,7 +105,7 @@ impl GitHubOrgsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/orgs/orgs#delete-an-organization](https://docs.github.com/rest/orgs/orgs#delete-an-organization)
   pub fn delete(&self, org: impl Into<String>) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -122,7 +122,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListBlockedUsersQuery, SimpleUserArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/blocks");
 
     Request::<(), OrgsListBlockedUsersQuery, SimpleUserArray>::builder(&self.config)
@@ -140,8 +140,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/blocks/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -159,8 +159,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/blocks/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -178,8 +178,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/blocks/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -196,7 +196,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListFailedInvitationsQuery, OrganizationInvitationArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/failed_invitations");
 
     Request::<(), OrgsListFailedInvitationsQuery, OrganizationInvitationArray>::builder(
@@ -218,7 +218,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListWebhooksQuery, OrgHookArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/hooks");
 
     Request::<(), OrgsListWebhooksQuery, OrgHookArray>::builder(&self.config)
@@ -240,7 +240,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<OrgsCreateWebhookRequest, (), OrgHook> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/hooks");
 
     Request::<OrgsCreateWebhookRequest, (), OrgHook>::builder(&self.config)
@@ -264,7 +264,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<(), (), OrgHook> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}");
 
@@ -292,7 +292,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<OrgsUpdateWebhookRequest, (), OrgHook> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}");
 
@@ -314,7 +314,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}");
 
@@ -338,7 +338,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<(), (), WebhookConfiguration> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}/config");
 
@@ -362,7 +362,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<OrgsUpdateWebhookConfigForOrgRequest, (), WebhookConfiguration> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}/config");
 
@@ -386,7 +386,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<(), OrgsListWebhookDeliveriesQuery, SimpleWebhookDeliveryArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}/deliveries");
 
@@ -411,7 +411,7 @@ impl GitHubOrgsAPI {
     hook_id: impl Into<i64>,
     delivery_id: impl Into<i64>,
   ) -> Request<(), (), WebhookDelivery> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let delivery_id = delivery_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}");
@@ -437,7 +437,7 @@ impl GitHubOrgsAPI {
     hook_id: impl Into<i64>,
     delivery_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let delivery_id = delivery_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}/attempts");
@@ -463,7 +463,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let hook_id = hook_id.into();
     let url = format!("/orgs/{org}/hooks/{hook_id}/pings");
 
@@ -486,7 +486,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListAppInstallationsQuery, OrgsListAppInstallationsResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/installations");
 
     Request::<(), OrgsListAppInstallationsQuery, OrgsListAppInstallationsResponse>::builder(
@@ -505,7 +505,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListPendingInvitationsQuery, OrganizationInvitationArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/invitations");
 
     Request::<(), OrgsListPendingInvitationsQuery, OrganizationInvitationArray>::builder(
@@ -527,7 +527,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<OrgsCreateInvitationRequest, (), OrganizationInvitation> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/invitations");
 
     Request::<OrgsCreateInvitationRequest, (), OrganizationInvitation>::builder(&self.config)
@@ -547,7 +547,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     invitation_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let invitation_id = invitation_id.into();
     let url = format!("/orgs/{org}/invitations/{invitation_id}");
 
@@ -566,7 +566,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     invitation_id: impl Into<i64>,
   ) -> Request<(), OrgsListInvitationTeamsQuery, TeamArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let invitation_id = invitation_id.into();
     let url = format!("/orgs/{org}/invitations/{invitation_id}/teams");
 
@@ -584,7 +584,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListMembersQuery, SimpleUserArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/members");
 
     Request::<(), OrgsListMembersQuery, SimpleUserArray>::builder(&self.config)
@@ -602,8 +602,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -621,8 +621,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -640,8 +640,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> Request<(), (), OrgMembership> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/memberships/{username}");
 
     Request::<(), (), OrgMembership>::builder(&self.config)
@@ -667,8 +667,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> Request<OrgsSetMembershipForUserRequest, (), OrgMembership> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/memberships/{username}");
 
     Request::<OrgsSetMembershipForUserRequest, (), OrgMembership>::builder(&self.config)
@@ -688,8 +688,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/memberships/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -715,7 +715,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), OrganizationFineGrainedPermissionArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/organization-fine-grained-permissions");
 
     Request::<(), (), OrganizationFineGrainedPermissionArray>::builder(&self.config)
@@ -739,7 +739,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), OrgsListOrgRolesResponse> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/organization-roles");
 
     Request::<(), (), OrgsListOrgRolesResponse>::builder(&self.config)
@@ -763,7 +763,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<OrgsCreateCustomOrganizationRoleRequest, (), OrganizationRole> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/organization-roles");
 
     Request::<OrgsCreateCustomOrganizationRoleRequest, (), OrganizationRole>::builder(&self.config)
@@ -785,8 +785,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/organization-roles/teams/{team_slug}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -809,8 +809,8 @@ impl GitHubOrgsAPI {
     team_slug: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/teams/{team_slug}/{role_id}");
 
@@ -834,8 +834,8 @@ impl GitHubOrgsAPI {
     team_slug: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/teams/{team_slug}/{role_id}");
 
@@ -858,8 +858,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/organization-roles/users/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -882,8 +882,8 @@ impl GitHubOrgsAPI {
     username: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/users/{username}/{role_id}");
 
@@ -907,8 +907,8 @@ impl GitHubOrgsAPI {
     username: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/users/{username}/{role_id}");
 
@@ -934,7 +934,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> Request<(), (), OrganizationRole> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/{role_id}");
 
@@ -961,7 +961,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> Request<OrgsPatchCustomOrganizationRoleRequest, (), OrganizationRole> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/{role_id}");
 
@@ -987,7 +987,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/{role_id}");
 
@@ -1010,7 +1010,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> Request<(), OrgsListOrgRoleTeamsQuery, TeamArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/{role_id}/teams");
 
@@ -1033,7 +1033,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     role_id: impl Into<i64>,
   ) -> Request<(), OrgsListOrgRoleUsersQuery, SimpleUserArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let role_id = role_id.into();
     let url = format!("/orgs/{org}/organization-roles/{role_id}/users");
 
@@ -1051,7 +1051,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListOutsideCollaboratorsQuery, SimpleUserArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/outside_collaborators");
 
     Request::<(), OrgsListOutsideCollaboratorsQuery, SimpleUserArray>::builder(&self.config)
@@ -1073,8 +1073,8 @@ impl GitHubOrgsAPI {
     (),
     OrgsConvertMemberToOutsideCollaboratorResponse,
   > {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/outside_collaborators/{username}");
 
     Request::<
@@ -1096,8 +1096,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/outside_collaborators/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1120,7 +1120,7 @@ impl GitHubOrgsAPI {
     OrgsListPatGrantRequestsQuery,
     SimpleOrganizationProgrammaticAccessGrantRequestArray,
   > {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/personal-access-token-requests");
 
     Request::<
@@ -1143,7 +1143,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<OrgsReviewPatGrantRequestsInBulkRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/personal-access-token-requests");
 
     NoContentRequest::<OrgsReviewPatGrantRequestsInBulkRequest, ()>::builder(&self.config)
@@ -1163,7 +1163,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     pat_request_id: impl Into<i64>,
   ) -> NoContentRequest<OrgsReviewPatGrantRequestRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let pat_request_id = pat_request_id.into();
     let url = format!("/orgs/{org}/personal-access-token-requests/{pat_request_id}");
 
@@ -1184,7 +1184,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     pat_request_id: impl Into<i64>,
   ) -> Request<(), OrgsListPatGrantRequestRepositoriesQuery, MinimalRepositoryArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let pat_request_id = pat_request_id.into();
     let url = format!("/orgs/{org}/personal-access-token-requests/{pat_request_id}/repositories");
 
@@ -1206,7 +1206,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListPatGrantsQuery, OrganizationProgrammaticAccessGrantArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/personal-access-tokens");
 
     Request::<(), OrgsListPatGrantsQuery, OrganizationProgrammaticAccessGrantArray>::builder(
@@ -1227,7 +1227,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<OrgsUpdatePatAccessesRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/personal-access-tokens");
 
     NoContentRequest::<OrgsUpdatePatAccessesRequest, ()>::builder(&self.config)
@@ -1247,7 +1247,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     pat_id: impl Into<i64>,
   ) -> NoContentRequest<OrgsUpdatePatAccessRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let pat_id = pat_id.into();
     let url = format!("/orgs/{org}/personal-access-tokens/{pat_id}");
 
@@ -1268,7 +1268,7 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     pat_id: impl Into<i64>,
   ) -> Request<(), OrgsListPatGrantRepositoriesQuery, MinimalRepositoryArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let pat_id = pat_id.into();
     let url = format!("/orgs/{org}/personal-access-tokens/{pat_id}/repositories");
 
@@ -1287,7 +1287,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), OrganizationCustomPropertyArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/properties/schema");
 
     Request::<(), (), OrganizationCustomPropertyArray>::builder(&self.config)
@@ -1308,7 +1308,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<OrgsCreateOrUpdateCustomPropertiesRequest, (), OrganizationCustomPropertyArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/properties/schema");
 
     Request::<OrgsCreateOrUpdateCustomPropertiesRequest, (), OrganizationCustomPropertyArray>::builder(&self.config)
@@ -1327,8 +1327,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     custom_property_name: impl Into<String>,
   ) -> Request<(), (), OrganizationCustomProperty> {
-    let org = org.into();
-    let custom_property_name = custom_property_name.into();
+    let org = encode_path_segment(org);
+    let custom_property_name = encode_path_segment(custom_property_name);
     let url = format!("/orgs/{org}/properties/schema/{custom_property_name}");
 
     Request::<(), (), OrganizationCustomProperty>::builder(&self.config)
@@ -1350,8 +1350,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     custom_property_name: impl Into<String>,
   ) -> Request<OrgsCreateOrUpdateCustomPropertyRequest, (), OrganizationCustomProperty> {
-    let org = org.into();
-    let custom_property_name = custom_property_name.into();
+    let org = encode_path_segment(org);
+    let custom_property_name = encode_path_segment(custom_property_name);
     let url = format!("/orgs/{org}/properties/schema/{custom_property_name}");
 
     Request::<OrgsCreateOrUpdateCustomPropertyRequest, (), OrganizationCustomProperty>::builder(
@@ -1375,8 +1375,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     custom_property_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let custom_property_name = custom_property_name.into();
+    let org = encode_path_segment(org);
+    let custom_property_name = encode_path_segment(custom_property_name);
     let url = format!("/orgs/{org}/properties/schema/{custom_property_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1398,7 +1398,7 @@ impl GitHubOrgsAPI {
     OrgsListCustomPropertiesValuesForReposQuery,
     OrganizationRepositoryCustomPropertyValuesArray,
   > {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/properties/values");
 
     Request::<
@@ -1428,7 +1428,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> NoContentRequest<OrgsCreateOrUpdateCustomPropertiesValuesForReposRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/properties/values");
 
     NoContentRequest::<OrgsCreateOrUpdateCustomPropertiesValuesForReposRequest, ()>::builder(
@@ -1447,7 +1447,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), OrgsListPublicMembersQuery, SimpleUserArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/public_members");
 
     Request::<(), OrgsListPublicMembersQuery, SimpleUserArray>::builder(&self.config)
@@ -1465,8 +1465,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/public_members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1486,8 +1486,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/public_members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1505,8 +1505,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/public_members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1527,7 +1527,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), TeamSimpleArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/security-managers");
 
     Request::<(), (), TeamSimpleArray>::builder(&self.config)
@@ -1549,8 +1549,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/security-managers/teams/{team_slug}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1572,8 +1572,8 @@ impl GitHubOrgsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/security-managers/teams/{team_slug}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1598,7 +1598,7 @@ impl GitHubOrgsAPI {
     >,
     enablement: impl Into<OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersEnablement>,
   ) -> NoContentRequest<OrgsEnableOrDisableSecurityProductOnAllOrgReposRequest, ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let security_product = security_product.into();
     let enablement = enablement.into();
     let security_product = security_product.to_string();
@@ -1638,7 +1638,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), OrgMembership> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/user/memberships/orgs/{org}");
 
     Request::<(), (), OrgMembership>::builder(&self.config)
@@ -1655,7 +1655,7 @@ impl GitHubOrgsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<OrgsUpdateMembershipForAuthenticatedUserRequest, (), OrgMembership> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/user/memberships/orgs/{org}");
 
     Request::<OrgsUpdateMembershipForAuthenticatedUserRequest, (), OrgMembership>::builder(
@@ -1693,7 +1693,7 @@ impl GitHubOrgsAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), OrgsListForUserQuery, OrganizationSimpleArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/orgs");
 
     Request::<(), OrgsListForUserQuery, OrganizationSimpleArray>::builder(&self.config)
@@ -25,7 +25,7 @@ impl GitHubPackagesAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), (), PackageArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/docker/conflicts");
 
     Request::<(), (), PackageArray>::builder(&self.config)
@@ -44,7 +44,7 @@ impl GitHubPackagesAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), PackagesListPackagesForOrganizationQuery, PackageArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/packages");
 
     Request::<(), PackagesListPackagesForOrganizationQuery, PackageArray>::builder(&self.config)
@@ -66,8 +66,8 @@ impl GitHubPackagesAPI {
     org: impl Into<String>,
   ) -> Request<(), (), Package> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let org = org.into();
+    let package_name = encode_path_segment(package_name);
+    let org = encode_path_segment(org);
     let package_type = package_type.to_string();
     let url = format!("/orgs/{org}/packages/{package_type}/{package_name}");
 
@@ -92,8 +92,8 @@ impl GitHubPackagesAPI {
     org: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let org = org.into();
+    let package_name = encode_path_segment(package_name);
+    let org = encode_path_segment(org);
     let package_type = package_type.to_string();
     let url = format!("/orgs/{org}/packages/{package_type}/{package_name}");
 
@@ -122,8 +122,8 @@ impl GitHubPackagesAPI {
     org: impl Into<String>,
   ) -> NoContentRequest<(), PackagesRestorePackageForOrgQuery> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let org = org.into();
+    let package_name = encode_path_segment(package_name);
+    let org = encode_path_segment(org);
     let package_type = package_type.to_string();
     let url = format!("/orgs/{org}/packages/{package_type}/{package_name}/restore");
 
@@ -146,8 +146,8 @@ impl GitHubPackagesAPI {
     org: impl Into<String>,
   ) -> Request<(), PackagesGetAllPackageVersionsForPackageOwnedByOrgQuery, PackageVersionArray> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let org = org.into();
+    let package_name = encode_path_segment(package_name);
+    let org = encode_path_segment(org);
     let package_type = package_type.to_string();
     let url = format!("/orgs/{org}/packages/{package_type}/{package_name}/versions");
 
@@ -171,8 +171,8 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> Request<(), (), PackageVersion> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let org = org.into();
+    let package_name = encode_path_segment(package_name);
+    let org = encode_path_segment(org);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url =
@@ -200,8 +200,8 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let org = org.into();
+    let package_name = encode_path_segment(package_name);
+    let org = encode_path_segment(org);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url =
@@ -233,8 +233,8 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let org = org.into();
+    let package_name = encode_path_segment(package_name);
+    let org = encode_path_segment(org);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url = format!(
@@ -295,7 +295,7 @@ impl GitHubPackagesAPI {
     package_name: impl Into<String>,
   ) -> Request<(), (), Package> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_type = package_type.to_string();
     let url = format!("/user/packages/{package_type}/{package_name}");
 
@@ -317,7 +317,7 @@ impl GitHubPackagesAPI {
     package_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_type = package_type.to_string();
     let url = format!("/user/packages/{package_type}/{package_name}");
 
@@ -343,7 +343,7 @@ impl GitHubPackagesAPI {
     package_name: impl Into<String>,
   ) -> NoContentRequest<(), PackagesRestorePackageForAuthenticatedUserQuery> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_type = package_type.to_string();
     let url = format!("/user/packages/{package_type}/{package_name}/restore");
 
@@ -371,7 +371,7 @@ impl GitHubPackagesAPI {
     PackageVersionArray,
   > {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_type = package_type.to_string();
     let url = format!("/user/packages/{package_type}/{package_name}/versions");
 
@@ -398,7 +398,7 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> Request<(), (), PackageVersion> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url = format!("/user/packages/{package_type}/{package_name}/versions/{package_version_id}");
@@ -424,7 +424,7 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url = format!("/user/packages/{package_type}/{package_name}/versions/{package_version_id}");
@@ -452,7 +452,7 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url =
@@ -474,7 +474,7 @@ impl GitHubPackagesAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), (), PackageArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/docker/conflicts");
 
     Request::<(), (), PackageArray>::builder(&self.config)
@@ -493,7 +493,7 @@ impl GitHubPackagesAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), PackagesListPackagesForUserQuery, PackageArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/packages");
 
     Request::<(), PackagesListPackagesForUserQuery, PackageArray>::builder(&self.config)
@@ -515,8 +515,8 @@ impl GitHubPackagesAPI {
     username: impl Into<String>,
   ) -> Request<(), (), Package> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let username = username.into();
+    let package_name = encode_path_segment(package_name);
+    let username = encode_path_segment(username);
     let package_type = package_type.to_string();
     let url = format!("/users/{username}/packages/{package_type}/{package_name}");
 
@@ -541,8 +541,8 @@ impl GitHubPackagesAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let username = username.into();
+    let package_name = encode_path_segment(package_name);
+    let username = encode_path_segment(username);
     let package_type = package_type.to_string();
     let url = format!("/users/{username}/packages/{package_type}/{package_name}");
 
@@ -571,8 +571,8 @@ impl GitHubPackagesAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<(), PackagesRestorePackageForUserQuery> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let username = username.into();
+    let package_name = encode_path_segment(package_name);
+    let username = encode_path_segment(username);
     let package_type = package_type.to_string();
     let url = format!("/users/{username}/packages/{package_type}/{package_name}/restore");
 
@@ -595,8 +595,8 @@ impl GitHubPackagesAPI {
     username: impl Into<String>,
   ) -> Request<(), (), PackageVersionArray> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let username = username.into();
+    let package_name = encode_path_segment(package_name);
+    let username = encode_path_segment(username);
     let package_type = package_type.to_string();
     let url = format!("/users/{username}/packages/{package_type}/{package_name}/versions");
 
@@ -620,9 +620,9 @@ impl GitHubPackagesAPI {
     username: impl Into<String>,
   ) -> Request<(), (), PackageVersion> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
+    let package_name = encode_path_segment(package_name);
     let package_version_id = package_version_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let package_type = package_type.to_string();
     let url = format!(
       "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}"
@@ -650,8 +650,8 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let username = username.into();
+    let package_name = encode_path_segment(package_name);
+    let username = encode_path_segment(username);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url = format!(
@@ -684,8 +684,8 @@ impl GitHubPackagesAPI {
     package_version_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
     let package_type = package_type.into();
-    let package_name = package_name.into();
-    let username = username.into();
+    let package_name = encode_path_segment(package_name);
+    let username = encode_path_segment(username);
     let package_version_id = package_version_id.into();
     let package_type = package_type.to_string();
     let url = format!("/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore");
@@ -23,7 +23,7 @@ impl GitHubProjectsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), ProjectsListForOrgQuery, ProjectArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/projects");
 
     Request::<(), ProjectsListForOrgQuery, ProjectArray>::builder(&self.config)
@@ -40,7 +40,7 @@ impl GitHubProjectsAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<ProjectsCreateForOrgRequest, (), Project> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/projects");
 
     Request::<ProjectsCreateForOrgRequest, (), Project>::builder(&self.config)
@@ -271,7 +271,7 @@ impl GitHubProjectsAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<ProjectsAddCollaboratorRequest, ()> {
     let project_id = project_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/projects/{project_id}/collaborators/{username}");
 
     NoContentRequest::<ProjectsAddCollaboratorRequest, ()>::builder(&self.config)
@@ -290,7 +290,7 @@ impl GitHubProjectsAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let project_id = project_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/projects/{project_id}/collaborators/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -309,7 +309,7 @@ impl GitHubProjectsAPI {
     username: impl Into<String>,
   ) -> Request<(), (), ProjectCollaboratorPermission> {
     let project_id = project_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/projects/{project_id}/collaborators/{username}/permission");
 
     Request::<(), (), ProjectCollaboratorPermission>::builder(&self.config)
@@ -361,8 +361,8 @@ impl GitHubProjectsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ProjectsListForRepoQuery, ProjectArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/projects");
 
     Request::<(), ProjectsListForRepoQuery, ProjectArray>::builder(&self.config)
@@ -380,8 +380,8 @@ impl GitHubProjectsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ProjectsCreateForRepoRequest, (), Project> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/projects");
 
     Request::<ProjectsCreateForRepoRequest, (), Project>::builder(&self.config)
@@ -413,7 +413,7 @@ impl GitHubProjectsAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ProjectsListForUserQuery, ProjectArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/projects");
 
     Request::<(), ProjectsListForUserQuery, ProjectArray>::builder(&self.config)
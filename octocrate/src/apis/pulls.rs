@@ -39,8 +39,8 @@ impl GitHubPullsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), PullsListQuery, PullRequestSimpleArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pulls");
 
     Request::<(), PullsListQuery, PullRequestSimpleArray>::builder(&self.config)
@@ -70,8 +70,8 @@ impl GitHubPullsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<PullsCreateRequest, (), PullRequest> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pulls");
 
     Request::<PullsCreateRequest, (), PullRequest>::builder(&self.config)
@@ -97,8 +97,8 @@ impl GitHubPullsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), PullsListReviewCommentsForRepoQuery, PullRequestReviewCommentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pulls/comments");
 
     Request::<(), PullsListReviewCommentsForRepoQuery, PullRequestReviewCommentArray>::builder(
@@ -126,8 +126,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<(), (), PullRequestReviewComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/comments/{comment_id}");
 
@@ -154,8 +154,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<PullsUpdateReviewCommentRequest, (), PullRequestReviewComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/comments/{comment_id}");
 
@@ -175,8 +175,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/comments/{comment_id}");
 
@@ -218,8 +218,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<(), (), PullRequest> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}");
 
@@ -249,8 +249,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<PullsUpdateRequest, (), PullRequest> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}");
 
@@ -278,8 +278,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<(), PullsListReviewCommentsQuery, PullRequestReviewCommentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/comments");
 
@@ -315,8 +315,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<PullsCreateReviewCommentRequest, (), PullRequestReviewComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/comments");
 
@@ -347,8 +347,8 @@ impl GitHubPullsAPI {
     pull_number: impl Into<i64>,
     comment_id: impl Into<i64>,
   ) -> Request<PullsCreateReplyForReviewCommentRequest, (), PullRequestReviewComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies");
@@ -381,8 +381,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<(), PullsListCommitsQuery, CommitArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/commits");
 
@@ -413,8 +413,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<(), PullsListFilesQuery, DiffEntryArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/files");
 
@@ -434,8 +434,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/merge");
 
@@ -456,8 +456,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<PullsMergeRequest, (), PullRequestMergeResult> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/merge");
 
@@ -477,8 +477,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<(), (), PullRequestReviewRequest> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers");
 
@@ -499,8 +499,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<serde_json::Value, (), PullRequestSimple> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers");
 
@@ -520,8 +520,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<PullsRemoveRequestedReviewersRequest, (), PullRequestSimple> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers");
 
@@ -548,8 +548,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<(), PullsListReviewsQuery, PullRequestReviewArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews");
 
@@ -584,8 +584,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<PullsCreateReviewRequest, (), PullRequestReview> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews");
 
@@ -613,8 +613,8 @@ impl GitHubPullsAPI {
     pull_number: impl Into<i64>,
     review_id: impl Into<i64>,
   ) -> Request<(), (), PullRequestReview> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let review_id = review_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}");
@@ -643,8 +643,8 @@ impl GitHubPullsAPI {
     pull_number: impl Into<i64>,
     review_id: impl Into<i64>,
   ) -> Request<PullsUpdateReviewRequest, (), PullRequestReview> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let review_id = review_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}");
@@ -673,8 +673,8 @@ impl GitHubPullsAPI {
     pull_number: impl Into<i64>,
     review_id: impl Into<i64>,
   ) -> Request<(), (), PullRequestReview> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let review_id = review_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}");
@@ -703,8 +703,8 @@ impl GitHubPullsAPI {
     pull_number: impl Into<i64>,
     review_id: impl Into<i64>,
   ) -> Request<(), PullsListCommentsForReviewQuery, LegacyReviewCommentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let review_id = review_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments");
@@ -737,8 +737,8 @@ impl GitHubPullsAPI {
     pull_number: impl Into<i64>,
     review_id: impl Into<i64>,
   ) -> Request<PullsDismissReviewRequest, (), PullRequestReview> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let review_id = review_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/dismissals");
@@ -767,8 +767,8 @@ impl GitHubPullsAPI {
     pull_number: impl Into<i64>,
     review_id: impl Into<i64>,
   ) -> Request<PullsSubmitReviewRequest, (), PullRequestReview> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let review_id = review_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events");
@@ -789,8 +789,8 @@ impl GitHubPullsAPI {
     repo: impl Into<String>,
     pull_number: impl Into<i64>,
   ) -> Request<PullsUpdateBranchRequest, (), PullsUpdateBranchResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pull_number = pull_number.into();
     let url = format!("/repos/{owner}/{repo}/pulls/{pull_number}/update-branch");
 
@@ -799,3 +799,583 @@ impl GitHubPullsAPI {
       .build()
   }
 }
+
+/// Custom media types of `list`, for `Request::media_type`
+pub struct PullsListMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsListMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestSimpleArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestSimpleArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestSimpleArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestSimpleArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+
+  /// For more information, see "[git-diff](https://git-scm.com/docs/git-diff)" in the Git documentation. If a diff is corrupt, contact us through the [GitHub Support portal](https://support.github.com/). Include the repository name and pull request ID in your message.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+
+  /// For more information, see "[git-format-patch](https://git-scm.com/docs/git-format-patch)" in the Git documentation.
+  pub const Patch: MediaType<String> =
+    MediaType::new("application/vnd.github.patch", PayloadFormat::Text);
+}
+
+/// Custom media types of `create`, for `Request::media_type`
+pub struct PullsCreateMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsCreateMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+
+  /// For more information, see "[git-diff](https://git-scm.com/docs/git-diff)" in the Git documentation. If a diff is corrupt, contact us through the [GitHub Support portal](https://support.github.com/). Include the repository name and pull request ID in your message.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+}
+
+/// Custom media types of `list_review_comments_for_repo`, for `Request::media_type`
+pub struct PullsListReviewCommentsForRepoMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsListReviewCommentsForRepoMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `get_review_comment`, for `Request::media_type`
+pub struct PullsGetReviewCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsGetReviewCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `update_review_comment`, for `Request::media_type`
+pub struct PullsUpdateReviewCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsUpdateReviewCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `get`, for `Request::media_type`
+pub struct PullsGetMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsGetMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+
+  /// For more information, see "[git-diff](https://git-scm.com/docs/git-diff)" in the Git documentation. If a diff is corrupt, contact us through the [GitHub Support portal](https://support.github.com/). Include the repository name and pull request ID in your message.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+}
+
+/// Custom media types of `update`, for `Request::media_type`
+pub struct PullsUpdateMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsUpdateMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequest> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+
+  /// For more information, see "[git-diff](https://git-scm.com/docs/git-diff)" in the Git documentation. If a diff is corrupt, contact us through the [GitHub Support portal](https://support.github.com/). Include the repository name and pull request ID in your message.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+}
+
+/// Custom media types of `list_review_comments`, for `Request::media_type`
+pub struct PullsListReviewCommentsMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsListReviewCommentsMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `create_review_comment`, for `Request::media_type`
+pub struct PullsCreateReviewCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsCreateReviewCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `create_reply_for_review_comment`, for `Request::media_type`
+pub struct PullsCreateReplyForReviewCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsCreateReplyForReviewCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReviewComment> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `list_commits`, for `Request::media_type`
+pub struct PullsListCommitsMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsListCommitsMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<CommitArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<CommitArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<CommitArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<CommitArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+
+  /// For more information, see "[git-diff](https://git-scm.com/docs/git-diff)" in the Git documentation. If a diff is corrupt, contact us through the [GitHub Support portal](https://support.github.com/). Include the repository name and pull request ID in your message.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+}
+
+/// Custom media types of `list_files`, for `Request::media_type`
+pub struct PullsListFilesMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsListFilesMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<DiffEntryArray> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Json);
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<DiffEntryArray> =
+    MediaType::new("application/vnd.github.text+json", PayloadFormat::Json);
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<DiffEntryArray> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Json);
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<DiffEntryArray> =
+    MediaType::new("application/vnd.github.full+json", PayloadFormat::Json);
+
+  /// For more information, see "[git-diff](https://git-scm.com/docs/git-diff)" in the Git documentation. If a diff is corrupt, contact us through the [GitHub Support portal](https://support.github.com/). Include the repository name and pull request ID in your message.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+}
+
+/// Custom media types of `list_reviews`, for `Request::media_type`
+pub struct PullsListReviewsMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsListReviewsMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReviewArray> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReviewArray> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReviewArray> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReviewArray> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `create_review`, for `Request::media_type`
+pub struct PullsCreateReviewMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsCreateReviewMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `get_review`, for `Request::media_type`
+pub struct PullsGetReviewMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsGetReviewMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `update_review`, for `Request::media_type`
+pub struct PullsUpdateReviewMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsUpdateReviewMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `delete_pending_review`, for `Request::media_type`
+pub struct PullsDeletePendingReviewMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsDeletePendingReviewMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `list_comments_for_review`, for `Request::media_type`
+pub struct PullsListCommentsForReviewMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsListCommentsForReviewMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<LegacyReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<LegacyReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<LegacyReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<LegacyReviewCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `dismiss_review`, for `Request::media_type`
+pub struct PullsDismissReviewMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsDismissReviewMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `submit_review`, for `Request::media_type`
+pub struct PullsSubmitReviewMediaType;
+
+#[allow(non_upper_case_globals)]
+impl PullsSubmitReviewMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<PullRequestReview> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
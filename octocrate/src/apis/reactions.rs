@@ -30,8 +30,8 @@ impl GitHubReactionsAPI {
     discussion_number: impl Into<i64>,
     comment_number: impl Into<i64>,
   ) -> Request<(), ReactionsListForTeamDiscussionCommentInOrgQuery, ReactionArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let comment_number = comment_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions");
@@ -61,8 +61,8 @@ impl GitHubReactionsAPI {
     discussion_number: impl Into<i64>,
     comment_number: impl Into<i64>,
   ) -> Request<ReactionsCreateForTeamDiscussionCommentInOrgRequest, (), Reaction> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let comment_number = comment_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions");
@@ -91,8 +91,8 @@ impl GitHubReactionsAPI {
     comment_number: impl Into<i64>,
     reaction_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let comment_number = comment_number.into();
     let reaction_id = reaction_id.into();
@@ -118,8 +118,8 @@ impl GitHubReactionsAPI {
     team_slug: impl Into<String>,
     discussion_number: impl Into<i64>,
   ) -> Request<(), ReactionsListForTeamDiscussionInOrgQuery, ReactionArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions");
 
@@ -145,8 +145,8 @@ impl GitHubReactionsAPI {
     team_slug: impl Into<String>,
     discussion_number: impl Into<i64>,
   ) -> Request<ReactionsCreateForTeamDiscussionInOrgRequest, (), Reaction> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions");
 
@@ -171,8 +171,8 @@ impl GitHubReactionsAPI {
     discussion_number: impl Into<i64>,
     reaction_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let reaction_id = reaction_id.into();
     let url = format!(
@@ -195,8 +195,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<(), ReactionsListForCommitCommentQuery, ReactionArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/comments/{comment_id}/reactions");
 
@@ -216,8 +216,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<ReactionsCreateForCommitCommentRequest, (), Reaction> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/comments/{comment_id}/reactions");
 
@@ -240,8 +240,8 @@ impl GitHubReactionsAPI {
     comment_id: impl Into<i64>,
     reaction_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let reaction_id = reaction_id.into();
     let url = format!("/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}");
@@ -262,8 +262,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<(), ReactionsListForIssueCommentQuery, ReactionArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions");
 
@@ -283,8 +283,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<ReactionsCreateForIssueCommentRequest, (), Reaction> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions");
 
@@ -307,8 +307,8 @@ impl GitHubReactionsAPI {
     comment_id: impl Into<i64>,
     reaction_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let reaction_id = reaction_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}");
@@ -329,8 +329,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<(), ReactionsListForIssueQuery, ReactionArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/reactions");
 
@@ -350,8 +350,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     issue_number: impl Into<i64>,
   ) -> Request<ReactionsCreateForIssueRequest, (), Reaction> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/reactions");
 
@@ -374,8 +374,8 @@ impl GitHubReactionsAPI {
     issue_number: impl Into<i64>,
     reaction_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let issue_number = issue_number.into();
     let reaction_id = reaction_id.into();
     let url = format!("/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}");
@@ -396,8 +396,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<(), ReactionsListForPullRequestReviewCommentQuery, ReactionArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions");
 
@@ -419,8 +419,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<ReactionsCreateForPullRequestReviewCommentRequest, (), Reaction> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions");
 
@@ -445,8 +445,8 @@ impl GitHubReactionsAPI {
     comment_id: impl Into<i64>,
     reaction_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let reaction_id = reaction_id.into();
     let url = format!("/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions/{reaction_id}");
@@ -467,8 +467,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     release_id: impl Into<i64>,
   ) -> Request<(), ReactionsListForReleaseQuery, ReactionArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}/reactions");
 
@@ -488,8 +488,8 @@ impl GitHubReactionsAPI {
     repo: impl Into<String>,
     release_id: impl Into<i64>,
   ) -> Request<ReactionsCreateForReleaseRequest, (), Reaction> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}/reactions");
 
@@ -512,8 +512,8 @@ impl GitHubReactionsAPI {
     release_id: impl Into<i64>,
     reaction_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let reaction_id = reaction_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}/reactions/{reaction_id}");
@@ -25,7 +25,7 @@ impl GitHubReposAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), ReposListForOrgQuery, MinimalRepositoryArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/repos");
 
     Request::<(), ReposListForOrgQuery, MinimalRepositoryArray>::builder(&self.config)
@@ -44,7 +44,7 @@ impl GitHubReposAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<ReposCreateInOrgRequest, (), FullRepository> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/repos");
 
     Request::<ReposCreateInOrgRequest, (), FullRepository>::builder(&self.config)
@@ -61,7 +61,7 @@ impl GitHubReposAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), ReposGetOrgRulesetsQuery, RepositoryRulesetArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/rulesets");
 
     Request::<(), ReposGetOrgRulesetsQuery, RepositoryRulesetArray>::builder(&self.config)
@@ -78,7 +78,7 @@ impl GitHubReposAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<ReposCreateOrgRulesetRequest, (), RepositoryRuleset> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/rulesets");
 
     Request::<ReposCreateOrgRulesetRequest, (), RepositoryRuleset>::builder(&self.config)
@@ -96,7 +96,7 @@ impl GitHubReposAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), ReposGetOrgRuleSuitesQuery, RuleSuites> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/rulesets/rule-suites");
 
     Request::<(), ReposGetOrgRuleSuitesQuery, RuleSuites>::builder(&self.config)
@@ -115,7 +115,7 @@ impl GitHubReposAPI {
     org: impl Into<String>,
     rule_suite_id: impl Into<i64>,
   ) -> Request<(), (), RuleSuite> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let rule_suite_id = rule_suite_id.into();
     let url = format!("/orgs/{org}/rulesets/rule-suites/{rule_suite_id}");
 
@@ -134,7 +134,7 @@ impl GitHubReposAPI {
     org: impl Into<String>,
     ruleset_id: impl Into<i64>,
   ) -> Request<(), (), RepositoryRuleset> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let ruleset_id = ruleset_id.into();
     let url = format!("/orgs/{org}/rulesets/{ruleset_id}");
 
@@ -153,7 +153,7 @@ impl GitHubReposAPI {
     org: impl Into<String>,
     ruleset_id: impl Into<i64>,
   ) -> Request<ReposUpdateOrgRulesetRequest, (), RepositoryRuleset> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let ruleset_id = ruleset_id.into();
     let url = format!("/orgs/{org}/rulesets/{ruleset_id}");
 
@@ -172,7 +172,7 @@ impl GitHubReposAPI {
     org: impl Into<String>,
     ruleset_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let ruleset_id = ruleset_id.into();
     let url = format!("/orgs/{org}/rulesets/{ruleset_id}");
 
@@ -193,8 +193,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), FullRepository> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}");
 
     Request::<(), (), FullRepository>::builder(&self.config)
@@ -212,8 +212,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposUpdateRequest, (), FullRepository> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}");
 
     Request::<ReposUpdateRequest, (), FullRepository>::builder(&self.config)
@@ -236,8 +236,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -258,8 +258,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListActivitiesQuery, ActivityArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/activity");
 
     Request::<(), ReposListActivitiesQuery, ActivityArray>::builder(&self.config)
@@ -279,8 +279,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), AutolinkReferenceArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/autolinks");
 
     Request::<(), (), AutolinkReferenceArray>::builder(&self.config)
@@ -298,8 +298,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateAutolinkRequest, (), AutolinkReference> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/autolinks");
 
     Request::<ReposCreateAutolinkRequest, (), AutolinkReference>::builder(&self.config)
@@ -320,8 +320,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     autolink_id: impl Into<i64>,
   ) -> Request<(), (), AutolinkReference> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let autolink_id = autolink_id.into();
     let url = format!("/repos/{owner}/{repo}/autolinks/{autolink_id}");
 
@@ -343,8 +343,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     autolink_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let autolink_id = autolink_id.into();
     let url = format!("/repos/{owner}/{repo}/autolinks/{autolink_id}");
 
@@ -363,8 +363,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), CheckAutomatedSecurityFixes> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/automated-security-fixes");
 
     Request::<(), (), CheckAutomatedSecurityFixes>::builder(&self.config)
@@ -382,8 +382,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/automated-security-fixes");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -401,8 +401,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/automated-security-fixes");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -419,8 +419,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListBranchesQuery, ShortBranchArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/branches");
 
     Request::<(), ReposListBranchesQuery, ShortBranchArray>::builder(&self.config)
@@ -438,9 +438,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), BranchWithProtection> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}");
 
     Request::<(), (), BranchWithProtection>::builder(&self.config)
@@ -459,9 +459,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), BranchProtection> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection");
 
     Request::<(), (), BranchProtection>::builder(&self.config)
@@ -486,9 +486,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposUpdateBranchProtectionRequest, (), ProtectedBranch> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection");
 
     Request::<ReposUpdateBranchProtectionRequest, (), ProtectedBranch>::builder(&self.config)
@@ -507,9 +507,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -528,9 +528,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), ProtectedBranchAdminEnforced> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins");
 
     Request::<(), (), ProtectedBranchAdminEnforced>::builder(&self.config)
@@ -551,9 +551,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), ProtectedBranchAdminEnforced> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins");
 
     Request::<(), (), ProtectedBranchAdminEnforced>::builder(&self.config)
@@ -574,9 +574,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -595,9 +595,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), ProtectedBranchPullRequestReview> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url =
       format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews");
 
@@ -622,9 +622,9 @@ impl GitHubReposAPI {
     branch: impl Into<String>,
   ) -> Request<ReposUpdatePullRequestReviewProtectionRequest, (), ProtectedBranchPullRequestReview>
   {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url =
       format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews");
 
@@ -644,9 +644,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url =
       format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews");
 
@@ -670,9 +670,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), ProtectedBranchAdminEnforced> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures");
 
     Request::<(), (), ProtectedBranchAdminEnforced>::builder(&self.config)
@@ -693,9 +693,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), ProtectedBranchAdminEnforced> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures");
 
     Request::<(), (), ProtectedBranchAdminEnforced>::builder(&self.config)
@@ -716,9 +716,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -737,9 +737,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), StatusCheckPolicy> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks");
 
     Request::<(), (), StatusCheckPolicy>::builder(&self.config)
@@ -760,9 +760,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposUpdateStatusCheckProtectionRequest, (), StatusCheckPolicy> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks");
 
     Request::<ReposUpdateStatusCheckProtectionRequest, (), StatusCheckPolicy>::builder(&self.config)
@@ -781,9 +781,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -802,9 +802,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), StringArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url =
       format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts");
 
@@ -824,9 +824,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposAddStatusCheckContextsRequest, (), StringArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url =
       format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts");
 
@@ -846,9 +846,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposSetStatusCheckContextsRequest, (), StringArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url =
       format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts");
 
@@ -868,9 +868,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposRemoveStatusCheckContextsRequest, (), StringArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url =
       format!("/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts");
 
@@ -894,9 +894,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), BranchRestrictionPolicy> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions");
 
     Request::<(), (), BranchRestrictionPolicy>::builder(&self.config)
@@ -917,9 +917,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -940,9 +940,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), GitHubAppArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps");
 
     Request::<(), (), GitHubAppArray>::builder(&self.config)
@@ -963,9 +963,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposAddAppAccessRestrictionsRequest, (), GitHubAppArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps");
 
     Request::<ReposAddAppAccessRestrictionsRequest, (), GitHubAppArray>::builder(&self.config)
@@ -986,9 +986,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposSetAppAccessRestrictionsRequest, (), GitHubAppArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps");
 
     Request::<ReposSetAppAccessRestrictionsRequest, (), GitHubAppArray>::builder(&self.config)
@@ -1009,9 +1009,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposRemoveAppAccessRestrictionsRequest, (), GitHubAppArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps");
 
     Request::<ReposRemoveAppAccessRestrictionsRequest, (), GitHubAppArray>::builder(&self.config)
@@ -1032,9 +1032,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), TeamArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams");
 
     Request::<(), (), TeamArray>::builder(&self.config)
@@ -1055,9 +1055,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposAddTeamAccessRestrictionsRequest, (), TeamArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams");
 
     Request::<ReposAddTeamAccessRestrictionsRequest, (), TeamArray>::builder(&self.config)
@@ -1078,9 +1078,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposSetTeamAccessRestrictionsRequest, (), TeamArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams");
 
     Request::<ReposSetTeamAccessRestrictionsRequest, (), TeamArray>::builder(&self.config)
@@ -1101,9 +1101,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposRemoveTeamAccessRestrictionsRequest, (), TeamArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams");
 
     Request::<ReposRemoveTeamAccessRestrictionsRequest, (), TeamArray>::builder(&self.config)
@@ -1124,9 +1124,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), (), SimpleUserArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users");
 
     Request::<(), (), SimpleUserArray>::builder(&self.config)
@@ -1151,9 +1151,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposAddUserAccessRestrictionsRequest, (), SimpleUserArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users");
 
     Request::<ReposAddUserAccessRestrictionsRequest, (), SimpleUserArray>::builder(&self.config)
@@ -1178,9 +1178,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposSetUserAccessRestrictionsRequest, (), SimpleUserArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users");
 
     Request::<ReposSetUserAccessRestrictionsRequest, (), SimpleUserArray>::builder(&self.config)
@@ -1205,9 +1205,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposRemoveUserAccessRestrictionsRequest, (), SimpleUserArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users");
 
     Request::<ReposRemoveUserAccessRestrictionsRequest, (), SimpleUserArray>::builder(&self.config)
@@ -1232,9 +1232,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<ReposRenameBranchRequest, (), BranchWithProtection> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/branches/{branch}/rename");
 
     Request::<ReposRenameBranchRequest, (), BranchWithProtection>::builder(&self.config)
@@ -1256,8 +1256,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposCodeownersErrorsQuery, CodeownersErrors> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/codeowners/errors");
 
     Request::<(), ReposCodeownersErrorsQuery, CodeownersErrors>::builder(&self.config)
@@ -1282,8 +1282,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListCollaboratorsQuery, CollaboratorArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/collaborators");
 
     Request::<(), ReposListCollaboratorsQuery, CollaboratorArray>::builder(&self.config)
@@ -1308,9 +1308,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let username = username.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let username = encode_path_segment(username);
     let url = format!("/repos/{owner}/{repo}/collaborators/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1349,9 +1349,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     username: impl Into<String>,
   ) -> Request<ReposAddCollaboratorRequest, (), RepositoryInvitation> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let username = username.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let username = encode_path_segment(username);
     let url = format!("/repos/{owner}/{repo}/collaborators/{username}");
 
     Request::<ReposAddCollaboratorRequest, (), RepositoryInvitation>::builder(&self.config)
@@ -1390,9 +1390,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let username = username.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let username = encode_path_segment(username);
     let url = format!("/repos/{owner}/{repo}/collaborators/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1417,9 +1417,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     username: impl Into<String>,
   ) -> Request<(), (), RepositoryCollaboratorPermission> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let username = username.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let username = encode_path_segment(username);
     let url = format!("/repos/{owner}/{repo}/collaborators/{username}/permission");
 
     Request::<(), (), RepositoryCollaboratorPermission>::builder(&self.config)
@@ -1444,8 +1444,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListCommitCommentsForRepoQuery, CommitCommentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/comments");
 
     Request::<(), ReposListCommitCommentsForRepoQuery, CommitCommentArray>::builder(&self.config)
@@ -1471,8 +1471,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<(), (), CommitComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/comments/{comment_id}");
 
@@ -1499,8 +1499,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> Request<ReposUpdateCommitCommentRequest, (), CommitComment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/comments/{comment_id}");
 
@@ -1519,8 +1519,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     comment_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let comment_id = comment_id.into();
     let url = format!("/repos/{owner}/{repo}/comments/{comment_id}");
 
@@ -1566,8 +1566,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListCommitsQuery, CommitArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/commits");
 
     Request::<(), ReposListCommitsQuery, CommitArray>::builder(&self.config)
@@ -1588,9 +1588,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     commit_sha: impl Into<String>,
   ) -> Request<(), (), BranchShortArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let commit_sha = commit_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let commit_sha = encode_path_segment(commit_sha);
     let url = format!("/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head");
 
     Request::<(), (), BranchShortArray>::builder(&self.config)
@@ -1616,9 +1616,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     commit_sha: impl Into<String>,
   ) -> Request<(), ReposListCommentsForCommitQuery, CommitCommentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let commit_sha = commit_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let commit_sha = encode_path_segment(commit_sha);
     let url = format!("/repos/{owner}/{repo}/commits/{commit_sha}/comments");
 
     Request::<(), ReposListCommentsForCommitQuery, CommitCommentArray>::builder(&self.config)
@@ -1646,9 +1646,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     commit_sha: impl Into<String>,
   ) -> Request<ReposCreateCommitCommentRequest, (), CommitComment> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let commit_sha = commit_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let commit_sha = encode_path_segment(commit_sha);
     let url = format!("/repos/{owner}/{repo}/commits/{commit_sha}/comments");
 
     Request::<ReposCreateCommitCommentRequest, (), CommitComment>::builder(&self.config)
@@ -1669,9 +1669,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     commit_sha: impl Into<String>,
   ) -> Request<(), ReposListPullRequestsAssociatedWithCommitQuery, PullRequestSimpleArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let commit_sha = commit_sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let commit_sha = encode_path_segment(commit_sha);
     let url = format!("/repos/{owner}/{repo}/commits/{commit_sha}/pulls");
 
     Request::<(), ReposListPullRequestsAssociatedWithCommitQuery, PullRequestSimpleArray>::builder(
@@ -1729,9 +1729,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<(), ReposGetCommitQuery, Commit> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/commits/{ref_}");
 
     Request::<(), ReposGetCommitQuery, Commit>::builder(&self.config)
@@ -1757,9 +1757,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<(), ReposGetCombinedStatusForRefQuery, CombinedCommitStatus> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/commits/{ref_}/status");
 
     Request::<(), ReposGetCombinedStatusForRefQuery, CombinedCommitStatus>::builder(&self.config)
@@ -1780,9 +1780,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     ref_: impl Into<String>,
   ) -> Request<(), ReposListCommitStatusesForRefQuery, StatusArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/commits/{ref_}/statuses");
 
     Request::<(), ReposListCommitStatusesForRefQuery, StatusArray>::builder(&self.config)
@@ -1810,8 +1810,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), CommunityProfile> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/community/profile");
 
     Request::<(), (), CommunityProfile>::builder(&self.config)
@@ -1879,9 +1879,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     basehead: impl Into<String>,
   ) -> Request<(), ReposCompareCommitsQuery, CommitComparison> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let basehead = basehead.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let basehead = encode_path(basehead);
     let url = format!("/repos/{owner}/{repo}/compare/{basehead}");
 
     Request::<(), ReposCompareCommitsQuery, CommitComparison>::builder(&self.config)
@@ -1924,9 +1924,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     path: impl Into<String>,
   ) -> Request<(), ReposGetContentQuery, ReposGetContentResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let path = path.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let path = encode_path(path);
     let url = format!("/repos/{owner}/{repo}/contents/{path}");
 
     Request::<(), ReposGetContentQuery, ReposGetContentResponse>::builder(&self.config)
@@ -1949,9 +1949,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     path: impl Into<String>,
   ) -> Request<ReposCreateOrUpdateFileContentsRequest, (), FileCommit> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let path = path.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let path = encode_path(path);
     let url = format!("/repos/{owner}/{repo}/contents/{path}");
 
     Request::<ReposCreateOrUpdateFileContentsRequest, (), FileCommit>::builder(&self.config)
@@ -1978,9 +1978,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     path: impl Into<String>,
   ) -> Request<ReposDeleteFileRequest, (), FileCommit> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let path = path.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let path = encode_path(path);
     let url = format!("/repos/{owner}/{repo}/contents/{path}");
 
     Request::<ReposDeleteFileRequest, (), FileCommit>::builder(&self.config)
@@ -2000,8 +2000,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListContributorsQuery, ContributorArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/contributors");
 
     Request::<(), ReposListContributorsQuery, ContributorArray>::builder(&self.config)
@@ -2019,8 +2019,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListDeploymentsQuery, DeploymentArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/deployments");
 
     Request::<(), ReposListDeploymentsQuery, DeploymentArray>::builder(&self.config)
@@ -2085,8 +2085,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateDeploymentRequest, (), ReposCreateDeploymentResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/deployments");
 
     Request::<ReposCreateDeploymentRequest, (), ReposCreateDeploymentResponse>::builder(
@@ -2106,8 +2106,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     deployment_id: impl Into<i64>,
   ) -> Request<(), (), Deployment> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let deployment_id = deployment_id.into();
     let url = format!("/repos/{owner}/{repo}/deployments/{deployment_id}");
 
@@ -2136,8 +2136,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     deployment_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let deployment_id = deployment_id.into();
     let url = format!("/repos/{owner}/{repo}/deployments/{deployment_id}");
 
@@ -2157,8 +2157,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     deployment_id: impl Into<i64>,
   ) -> Request<(), ReposListDeploymentStatusesQuery, DeploymentStatusArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let deployment_id = deployment_id.into();
     let url = format!("/repos/{owner}/{repo}/deployments/{deployment_id}/statuses");
 
@@ -2180,8 +2180,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     deployment_id: impl Into<i64>,
   ) -> Request<ReposCreateDeploymentStatusRequest, (), DeploymentStatus> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let deployment_id = deployment_id.into();
     let url = format!("/repos/{owner}/{repo}/deployments/{deployment_id}/statuses");
 
@@ -2202,8 +2202,8 @@ impl GitHubReposAPI {
     deployment_id: impl Into<i64>,
     status_id: impl Into<i64>,
   ) -> Request<(), (), DeploymentStatus> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let deployment_id = deployment_id.into();
     let status_id = status_id.into();
     let url = format!("/repos/{owner}/{repo}/deployments/{deployment_id}/statuses/{status_id}");
@@ -2229,8 +2229,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<ReposCreateDispatchEventRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/dispatches");
 
     NoContentRequest::<ReposCreateDispatchEventRequest, ()>::builder(&self.config)
@@ -2252,8 +2252,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposGetAllEnvironmentsQuery, ReposGetAllEnvironmentsResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/environments");
 
     Request::<(), ReposGetAllEnvironmentsQuery, ReposGetAllEnvironmentsResponse>::builder(
@@ -2278,9 +2278,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> Request<(), (), Environment> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}");
 
     Request::<(), (), Environment>::builder(&self.config)
@@ -2305,9 +2305,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> Request<ReposCreateOrUpdateEnvironmentRequest, (), Environment> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}");
 
     Request::<ReposCreateOrUpdateEnvironmentRequest, (), Environment>::builder(&self.config)
@@ -2326,9 +2326,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -2352,9 +2352,9 @@ impl GitHubReposAPI {
     environment_name: impl Into<String>,
   ) -> Request<(), ReposListDeploymentBranchPoliciesQuery, ReposListDeploymentBranchPoliciesResponse>
   {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url =
       format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies");
 
@@ -2376,9 +2376,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     environment_name: impl Into<String>,
   ) -> Request<DeploymentBranchAndTagPolicyNamePattern, (), DeploymentBranchPolicy> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let url =
       format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies");
 
@@ -2405,9 +2405,9 @@ impl GitHubReposAPI {
     environment_name: impl Into<String>,
     branch_policy_id: impl Into<i64>,
   ) -> Request<(), (), DeploymentBranchPolicy> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let branch_policy_id = branch_policy_id.into();
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies/{branch_policy_id}");
 
@@ -2430,9 +2430,9 @@ impl GitHubReposAPI {
     environment_name: impl Into<String>,
     branch_policy_id: impl Into<i64>,
   ) -> Request<DeploymentBranchPolicyNamePattern, (), DeploymentBranchPolicy> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let branch_policy_id = branch_policy_id.into();
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies/{branch_policy_id}");
 
@@ -2455,9 +2455,9 @@ impl GitHubReposAPI {
     environment_name: impl Into<String>,
     branch_policy_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let branch_policy_id = branch_policy_id.into();
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment-branch-policies/{branch_policy_id}");
 
@@ -2481,9 +2481,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     owner: impl Into<String>,
   ) -> Request<(), (), ReposGetAllDeploymentProtectionRulesResponse> {
-    let environment_name = environment_name.into();
-    let repo = repo.into();
-    let owner = owner.into();
+    let environment_name = encode_path_segment(environment_name);
+    let repo = encode_path_segment(repo);
+    let owner = encode_path_segment(owner);
     let url =
       format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules");
 
@@ -2509,9 +2509,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     owner: impl Into<String>,
   ) -> Request<ReposCreateDeploymentProtectionRuleRequest, (), DeploymentProtectionRule> {
-    let environment_name = environment_name.into();
-    let repo = repo.into();
-    let owner = owner.into();
+    let environment_name = encode_path_segment(environment_name);
+    let repo = encode_path_segment(repo);
+    let owner = encode_path_segment(owner);
     let url =
       format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules");
 
@@ -2543,9 +2543,9 @@ impl GitHubReposAPI {
     ReposListCustomDeploymentRuleIntegrationsQuery,
     ReposListCustomDeploymentRuleIntegrationsResponse,
   > {
-    let environment_name = environment_name.into();
-    let repo = repo.into();
-    let owner = owner.into();
+    let environment_name = encode_path_segment(environment_name);
+    let repo = encode_path_segment(repo);
+    let owner = encode_path_segment(owner);
     let url = format!(
       "/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules/apps"
     );
@@ -2575,9 +2575,9 @@ impl GitHubReposAPI {
     environment_name: impl Into<String>,
     protection_rule_id: impl Into<i64>,
   ) -> Request<(), (), DeploymentProtectionRule> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let environment_name = environment_name.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let environment_name = encode_path_segment(environment_name);
     let protection_rule_id = protection_rule_id.into();
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules/{protection_rule_id}");
 
@@ -2602,9 +2602,9 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     protection_rule_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let environment_name = environment_name.into();
-    let repo = repo.into();
-    let owner = owner.into();
+    let environment_name = encode_path_segment(environment_name);
+    let repo = encode_path_segment(repo);
+    let owner = encode_path_segment(owner);
     let protection_rule_id = protection_rule_id.into();
     let url = format!("/repos/{owner}/{repo}/environments/{environment_name}/deployment_protection_rules/{protection_rule_id}");
 
@@ -2622,8 +2622,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListForksQuery, MinimalRepositoryArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/forks");
 
     Request::<(), ReposListForksQuery, MinimalRepositoryArray>::builder(&self.config)
@@ -2645,8 +2645,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateForkRequest, (), FullRepository> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/forks");
 
     Request::<ReposCreateForkRequest, (), FullRepository>::builder(&self.config)
@@ -2664,8 +2664,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListWebhooksQuery, WebhookArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/hooks");
 
     Request::<(), ReposListWebhooksQuery, WebhookArray>::builder(&self.config)
@@ -2684,8 +2684,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateWebhookRequest, (), Webhook> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/hooks");
 
     Request::<ReposCreateWebhookRequest, (), Webhook>::builder(&self.config)
@@ -2704,8 +2704,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<(), (), Webhook> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}");
 
@@ -2725,8 +2725,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<ReposUpdateWebhookRequest, (), Webhook> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}");
 
@@ -2745,8 +2745,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}");
 
@@ -2768,8 +2768,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<(), (), WebhookConfiguration> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}/config");
 
@@ -2791,8 +2791,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<ReposUpdateWebhookConfigForRepoRequest, (), WebhookConfiguration> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}/config");
 
@@ -2814,8 +2814,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> Request<(), ReposListWebhookDeliveriesQuery, SimpleWebhookDeliveryArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}/deliveries");
 
@@ -2838,8 +2838,8 @@ impl GitHubReposAPI {
     hook_id: impl Into<i64>,
     delivery_id: impl Into<i64>,
   ) -> Request<(), (), WebhookDelivery> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let delivery_id = delivery_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}");
@@ -2861,8 +2861,8 @@ impl GitHubReposAPI {
     hook_id: impl Into<i64>,
     delivery_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let delivery_id = delivery_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}/attempts");
@@ -2883,8 +2883,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}/pings");
 
@@ -2906,8 +2906,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     hook_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let hook_id = hook_id.into();
     let url = format!("/repos/{owner}/{repo}/hooks/{hook_id}/tests");
 
@@ -2926,8 +2926,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListInvitationsQuery, RepositoryInvitationArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/invitations");
 
     Request::<(), ReposListInvitationsQuery, RepositoryInvitationArray>::builder(&self.config)
@@ -2945,8 +2945,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     invitation_id: impl Into<i64>,
   ) -> Request<ReposUpdateInvitationRequest, (), RepositoryInvitation> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let invitation_id = invitation_id.into();
     let url = format!("/repos/{owner}/{repo}/invitations/{invitation_id}");
 
@@ -2965,8 +2965,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     invitation_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let invitation_id = invitation_id.into();
     let url = format!("/repos/{owner}/{repo}/invitations/{invitation_id}");
 
@@ -2984,8 +2984,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListDeployKeysQuery, DeployKeyArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/keys");
 
     Request::<(), ReposListDeployKeysQuery, DeployKeyArray>::builder(&self.config)
@@ -3003,8 +3003,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateDeployKeyRequest, (), DeployKey> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/keys");
 
     Request::<ReposCreateDeployKeyRequest, (), DeployKey>::builder(&self.config)
@@ -3022,8 +3022,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     key_id: impl Into<i64>,
   ) -> Request<(), (), DeployKey> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let key_id = key_id.into();
     let url = format!("/repos/{owner}/{repo}/keys/{key_id}");
 
@@ -3043,8 +3043,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     key_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let key_id = key_id.into();
     let url = format!("/repos/{owner}/{repo}/keys/{key_id}");
 
@@ -3063,8 +3063,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), serde_json::Value> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/languages");
 
     Request::<(), (), serde_json::Value>::builder(&self.config)
@@ -3082,8 +3082,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposMergeUpstreamRequest, (), MergedUpstream> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/merge-upstream");
 
     Request::<ReposMergeUpstreamRequest, (), MergedUpstream>::builder(&self.config)
@@ -3100,8 +3100,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposMergeRequest, (), Commit> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/merges");
 
     Request::<ReposMergeRequest, (), Commit>::builder(&self.config)
@@ -3121,8 +3121,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), GitHubPages> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages");
 
     Request::<(), (), GitHubPages>::builder(&self.config)
@@ -3144,8 +3144,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<serde_json::Value, (), GitHubPages> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages");
 
     Request::<serde_json::Value, (), GitHubPages>::builder(&self.config)
@@ -3167,8 +3167,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<serde_json::Value, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages");
 
     NoContentRequest::<serde_json::Value, ()>::builder(&self.config)
@@ -3190,8 +3190,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -3211,8 +3211,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListPagesBuildsQuery, PageBuildArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages/builds");
 
     Request::<(), ReposListPagesBuildsQuery, PageBuildArray>::builder(&self.config)
@@ -3232,8 +3232,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), PageBuildStatus> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages/builds");
 
     Request::<(), (), PageBuildStatus>::builder(&self.config)
@@ -3253,8 +3253,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), PageBuild> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages/builds/latest");
 
     Request::<(), (), PageBuild>::builder(&self.config)
@@ -3275,8 +3275,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     build_id: impl Into<i64>,
   ) -> Request<(), (), PageBuild> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let build_id = build_id.into();
     let url = format!("/repos/{owner}/{repo}/pages/builds/{build_id}");
 
@@ -3297,8 +3297,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreatePagesDeploymentRequest, (), GitHubPages> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages/deployments");
 
     Request::<ReposCreatePagesDeploymentRequest, (), GitHubPages>::builder(&self.config)
@@ -3319,8 +3319,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     pages_deployment_id: impl Into<StringOrInteger>,
   ) -> Request<(), (), GitHubPagesDeploymentStatus> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pages_deployment_id = pages_deployment_id.into();
     let url = format!("/repos/{owner}/{repo}/pages/deployments/{pages_deployment_id}");
 
@@ -3342,8 +3342,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     pages_deployment_id: impl Into<StringOrInteger>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let pages_deployment_id = pages_deployment_id.into();
     let url = format!("/repos/{owner}/{repo}/pages/deployments/{pages_deployment_id}/cancel");
 
@@ -3368,8 +3368,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ReposGetPagesHealthCheckResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/pages/health");
 
     Request::<(), (), ReposGetPagesHealthCheckResponse>::builder(&self.config)
@@ -3387,8 +3387,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ReposCheckPrivateVulnerabilityReportingResponse> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/private-vulnerability-reporting");
 
     Request::<(), (), ReposCheckPrivateVulnerabilityReportingResponse>::builder(&self.config)
@@ -3406,8 +3406,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/private-vulnerability-reporting");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -3425,8 +3425,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/private-vulnerability-reporting");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -3445,8 +3445,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), CustomPropertyValueArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/properties/values");
 
     Request::<(), (), CustomPropertyValueArray>::builder(&self.config)
@@ -3467,8 +3467,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<ReposCreateOrUpdateCustomPropertiesValuesRequest, ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/properties/values");
 
     NoContentRequest::<ReposCreateOrUpdateCustomPropertiesValuesRequest, ()>::builder(&self.config)
@@ -3491,8 +3491,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposGetReadmeQuery, ContentFile> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/readme");
 
     Request::<(), ReposGetReadmeQuery, ContentFile>::builder(&self.config)
@@ -3516,9 +3516,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     dir: impl Into<String>,
   ) -> Request<(), ReposGetReadmeInDirectoryQuery, ContentFile> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let dir = dir.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let dir = encode_path_segment(dir);
     let url = format!("/repos/{owner}/{repo}/readme/{dir}");
 
     Request::<(), ReposGetReadmeInDirectoryQuery, ContentFile>::builder(&self.config)
@@ -3538,8 +3538,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListReleasesQuery, ReleaseArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/releases");
 
     Request::<(), ReposListReleasesQuery, ReleaseArray>::builder(&self.config)
@@ -3559,8 +3559,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateReleaseRequest, (), Release> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/releases");
 
     Request::<ReposCreateReleaseRequest, (), Release>::builder(&self.config)
@@ -3579,8 +3579,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     asset_id: impl Into<i64>,
   ) -> Request<(), (), ReleaseAsset> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let asset_id = asset_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/assets/{asset_id}");
 
@@ -3600,8 +3600,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     asset_id: impl Into<i64>,
   ) -> Request<ReposUpdateReleaseAssetRequest, (), ReleaseAsset> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let asset_id = asset_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/assets/{asset_id}");
 
@@ -3620,8 +3620,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     asset_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let asset_id = asset_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/assets/{asset_id}");
 
@@ -3640,8 +3640,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposGenerateReleaseNotesRequest, (), GeneratedReleaseNotesContent> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/releases/generate-notes");
 
     Request::<ReposGenerateReleaseNotesRequest, (), GeneratedReleaseNotesContent>::builder(
@@ -3663,8 +3663,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), Release> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/releases/latest");
 
     Request::<(), (), Release>::builder(&self.config)
@@ -3683,9 +3683,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     tag: impl Into<String>,
   ) -> Request<(), (), Release> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let tag = tag.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let tag = encode_path_segment(tag);
     let url = format!("/repos/{owner}/{repo}/releases/tags/{tag}");
 
     Request::<(), (), Release>::builder(&self.config)
@@ -3708,8 +3708,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     release_id: impl Into<i64>,
   ) -> Request<(), (), Release> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}");
 
@@ -3729,8 +3729,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     release_id: impl Into<i64>,
   ) -> Request<ReposUpdateReleaseRequest, (), Release> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}");
 
@@ -3750,8 +3750,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     release_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}");
 
@@ -3770,8 +3770,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     release_id: impl Into<i64>,
   ) -> Request<(), ReposListReleaseAssetsQuery, ReleaseAssetArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}/assets");
 
@@ -3808,15 +3808,18 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
     release_id: impl Into<i64>,
+    body: impl Into<Vec<u8>>,
   ) -> Request<(), ReposUploadReleaseAssetQuery, ReleaseAsset> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let release_id = release_id.into();
     let url = format!("/repos/{owner}/{repo}/releases/{release_id}/assets");
 
     Request::<(), ReposUploadReleaseAssetQuery, ReleaseAsset>::builder(&self.config)
+      .uploads()
       .post(url)
       .build()
+      .raw_body(body.into(), PayloadFormat::Bytes.content_type())
   }
 
   /// **Get rules for a branch**
@@ -3833,9 +3836,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     branch: impl Into<String>,
   ) -> Request<(), ReposGetBranchRulesQuery, RepositoryRuleArray> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let branch = branch.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let branch = encode_path(branch);
     let url = format!("/repos/{owner}/{repo}/rules/branches/{branch}");
 
     Request::<(), ReposGetBranchRulesQuery, RepositoryRuleArray>::builder(&self.config)
@@ -3853,8 +3856,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposGetRepoRulesetsQuery, RepositoryRulesetArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/rulesets");
 
     Request::<(), ReposGetRepoRulesetsQuery, RepositoryRulesetArray>::builder(&self.config)
@@ -3872,8 +3875,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateRepoRulesetRequest, (), RepositoryRuleset> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/rulesets");
 
     Request::<ReposCreateRepoRulesetRequest, (), RepositoryRuleset>::builder(&self.config)
@@ -3892,8 +3895,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposGetRepoRuleSuitesQuery, RuleSuites> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/rulesets/rule-suites");
 
     Request::<(), ReposGetRepoRuleSuitesQuery, RuleSuites>::builder(&self.config)
@@ -3913,8 +3916,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     rule_suite_id: impl Into<i64>,
   ) -> Request<(), (), RuleSuite> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let rule_suite_id = rule_suite_id.into();
     let url = format!("/repos/{owner}/{repo}/rulesets/rule-suites/{rule_suite_id}");
 
@@ -3934,8 +3937,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     ruleset_id: impl Into<i64>,
   ) -> Request<(), ReposGetRepoRulesetQuery, RepositoryRuleset> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let ruleset_id = ruleset_id.into();
     let url = format!("/repos/{owner}/{repo}/rulesets/{ruleset_id}");
 
@@ -3955,8 +3958,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     ruleset_id: impl Into<i64>,
   ) -> Request<ReposUpdateRepoRulesetRequest, (), RepositoryRuleset> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let ruleset_id = ruleset_id.into();
     let url = format!("/repos/{owner}/{repo}/rulesets/{ruleset_id}");
 
@@ -3976,8 +3979,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     ruleset_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let ruleset_id = ruleset_id.into();
     let url = format!("/repos/{owner}/{repo}/rulesets/{ruleset_id}");
 
@@ -4001,8 +4004,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), Vec<Vec<i64>>> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/stats/code_frequency");
 
     Request::<(), (), Vec<Vec<i64>>>::builder(&self.config)
@@ -4020,8 +4023,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), CommitActivityArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/stats/commit_activity");
 
     Request::<(), (), CommitActivityArray>::builder(&self.config)
@@ -4047,8 +4050,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ContributorActivityArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/stats/contributors");
 
     Request::<(), (), ContributorActivityArray>::builder(&self.config)
@@ -4070,8 +4073,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ParticipationStats> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/stats/participation");
 
     Request::<(), (), ParticipationStats>::builder(&self.config)
@@ -4095,8 +4098,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), Vec<Vec<i64>>> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/stats/punch_card");
 
     Request::<(), (), Vec<Vec<i64>>>::builder(&self.config)
@@ -4117,9 +4120,9 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     sha: impl Into<String>,
   ) -> Request<ReposCreateCommitStatusRequest, (), Status> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let sha = sha.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let sha = encode_path_segment(sha);
     let url = format!("/repos/{owner}/{repo}/statuses/{sha}");
 
     Request::<ReposCreateCommitStatusRequest, (), Status>::builder(&self.config)
@@ -4136,8 +4139,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListTagsQuery, TagArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/tags");
 
     Request::<(), ReposListTagsQuery, TagArray>::builder(&self.config)
@@ -4157,8 +4160,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), TagProtectionArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/tags/protection");
 
     Request::<(), (), TagProtectionArray>::builder(&self.config)
@@ -4177,8 +4180,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposCreateTagProtectionRequest, (), TagProtection> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/tags/protection");
 
     Request::<ReposCreateTagProtectionRequest, (), TagProtection>::builder(&self.config)
@@ -4198,8 +4201,8 @@ impl GitHubReposAPI {
     repo: impl Into<String>,
     tag_protection_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let tag_protection_id = tag_protection_id.into();
     let url = format!("/repos/{owner}/{repo}/tags/protection/{tag_protection_id}");
 
@@ -4221,13 +4224,13 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
     ref_: impl Into<String>,
-  ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+  ) -> DownloadRequest<()> {
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/tarball/{ref_}");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -4246,8 +4249,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposListTeamsQuery, TeamArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/teams");
 
     Request::<(), ReposListTeamsQuery, TeamArray>::builder(&self.config)
@@ -4264,8 +4267,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposGetAllTopicsQuery, Topic> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/topics");
 
     Request::<(), ReposGetAllTopicsQuery, Topic>::builder(&self.config)
@@ -4282,8 +4285,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposReplaceAllTopicsRequest, (), Topic> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/topics");
 
     Request::<ReposReplaceAllTopicsRequest, (), Topic>::builder(&self.config)
@@ -4301,8 +4304,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposGetClonesQuery, CloneTraffic> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/traffic/clones");
 
     Request::<(), ReposGetClonesQuery, CloneTraffic>::builder(&self.config)
@@ -4320,8 +4323,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ContentTrafficArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/traffic/popular/paths");
 
     Request::<(), (), ContentTrafficArray>::builder(&self.config)
@@ -4339,8 +4342,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), ReferrerTrafficArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/traffic/popular/referrers");
 
     Request::<(), (), ReferrerTrafficArray>::builder(&self.config)
@@ -4358,8 +4361,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), ReposGetViewsQuery, ViewTraffic> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/traffic/views");
 
     Request::<(), ReposGetViewsQuery, ViewTraffic>::builder(&self.config)
@@ -4377,8 +4380,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<ReposTransferRequest, (), MinimalRepository> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/transfer");
 
     Request::<ReposTransferRequest, (), MinimalRepository>::builder(&self.config)
@@ -4396,8 +4399,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/vulnerability-alerts");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -4415,8 +4418,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/vulnerability-alerts");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -4436,8 +4439,8 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/vulnerability-alerts");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -4459,13 +4462,13 @@ impl GitHubReposAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
     ref_: impl Into<String>,
-  ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ref_ = ref_.into();
+  ) -> DownloadRequest<()> {
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ref_ = encode_path(ref_);
     let url = format!("/repos/{owner}/{repo}/zipball/{ref_}");
 
-    NoContentRequest::<(), ()>::builder(&self.config)
+    DownloadRequest::<()>::builder(&self.config)
       .get(url)
       .build()
   }
@@ -4482,8 +4485,8 @@ impl GitHubReposAPI {
     template_owner: impl Into<String>,
     template_repo: impl Into<String>,
   ) -> Request<ReposCreateUsingTemplateRequest, (), FullRepository> {
-    let template_owner = template_owner.into();
-    let template_repo = template_repo.into();
+    let template_owner = encode_path_segment(template_owner);
+    let template_repo = encode_path_segment(template_repo);
     let url = format!("/repos/{template_owner}/{template_repo}/generate");
 
     Request::<ReposCreateUsingTemplateRequest, (), FullRepository>::builder(&self.config)
@@ -4598,7 +4601,7 @@ impl GitHubReposAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), ReposListForUserQuery, MinimalRepositoryArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/repos");
 
     Request::<(), ReposListForUserQuery, MinimalRepositoryArray>::builder(&self.config)
@@ -4606,3 +4609,231 @@ impl GitHubReposAPI {
       .build()
   }
 }
+
+/// Custom media types of `list_commit_comments_for_repo`, for `Request::media_type`
+pub struct ReposListCommitCommentsForRepoMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposListCommitCommentsForRepoMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `get_commit_comment`, for `Request::media_type`
+pub struct ReposGetCommitCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposGetCommitCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `update_commit_comment`, for `Request::media_type`
+pub struct ReposUpdateCommitCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposUpdateCommitCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `list_comments_for_commit`, for `Request::media_type`
+pub struct ReposListCommentsForCommitMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposListCommentsForCommitMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<CommitCommentArray> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `create_commit_comment`, for `Request::media_type`
+pub struct ReposCreateCommitCommentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposCreateCommitCommentMediaType {
+  /// Returns the raw markdown body. Response will include `body`. This is the default if you do not pass any specific media type.
+  pub const Raw: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.raw+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns a text only representation of the markdown body. Response will include `body_text`.
+  pub const Text: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.text+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns HTML rendered from the body's markdown. Response will include `body_html`.
+  pub const Html: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.html+json",
+    PayloadFormat::Json,
+  );
+
+  /// Returns raw, text, and HTML representations. Response will include `body`, `body_text`, and `body_html`.
+  pub const Full: MediaType<CommitComment> = MediaType::new(
+    "application/vnd.github-commitcomment.full+json",
+    PayloadFormat::Json,
+  );
+}
+
+/// Custom media types of `get_commit`, for `Request::media_type`
+pub struct ReposGetCommitMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposGetCommitMediaType {
+  /// Returns the diff of the commit. Larger diffs may time out and return a 5xx status code.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+
+  /// Returns the patch of the commit. Diffs with binary data will have no `patch` property. Larger diffs may time out and return a 5xx status code.
+  pub const Patch: MediaType<String> =
+    MediaType::new("application/vnd.github.patch", PayloadFormat::Text);
+
+  /// Returns the commit's SHA-1 hash. You can use this endpoint to check if a remote reference's SHA-1 hash is the same as your local reference's SHA-1 hash by providing the local SHA-1 reference as the ETag.
+  pub const Sha: MediaType<String> =
+    MediaType::new("application/vnd.github.sha", PayloadFormat::Text);
+}
+
+/// Custom media types of `compare_commits`, for `Request::media_type`
+pub struct ReposCompareCommitsMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposCompareCommitsMediaType {
+  /// Returns the diff of the commit.
+  pub const Diff: MediaType<String> =
+    MediaType::new("application/vnd.github.diff", PayloadFormat::Text);
+
+  /// Returns the patch of the commit. Diffs with binary data will have no `patch` property.
+  pub const Patch: MediaType<String> =
+    MediaType::new("application/vnd.github.patch", PayloadFormat::Text);
+}
+
+/// Custom media types of `get_content`, for `Request::media_type`
+pub struct ReposGetContentMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposGetContentMediaType {
+  /// Returns the raw file contents for files and symlinks.
+  pub const Raw: MediaType<String> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Text);
+
+  /// Returns the file contents in HTML. Markup languages are rendered to HTML using GitHub's open-source [Markup library](https://github.com/github/markup).
+  pub const Html: MediaType<String> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Text);
+
+  /// Returns the contents in a consistent object format regardless of the content type. For example, instead of an array of objects for a directory, the response will be an object with an `entries` attribute containing the array of objects.
+  pub const Object: MediaType<ReposGetContentResponse> =
+    MediaType::new("application/vnd.github.object+json", PayloadFormat::Json);
+}
+
+/// Custom media types of `get_readme`, for `Request::media_type`
+pub struct ReposGetReadmeMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposGetReadmeMediaType {
+  /// Returns the raw file contents. This is the default if you do not specify a media type.
+  pub const Raw: MediaType<String> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Text);
+
+  /// Returns the README in HTML. Markup languages are rendered to HTML using GitHub's open-source [Markup library](https://github.com/github/markup).
+  pub const Html: MediaType<String> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Text);
+}
+
+/// Custom media types of `get_readme_in_directory`, for `Request::media_type`
+pub struct ReposGetReadmeInDirectoryMediaType;
+
+#[allow(non_upper_case_globals)]
+impl ReposGetReadmeInDirectoryMediaType {
+  /// Returns the raw file contents. This is the default if you do not specify a media type.
+  pub const Raw: MediaType<String> =
+    MediaType::new("application/vnd.github.raw+json", PayloadFormat::Text);
+
+  /// Returns the README in HTML. Markup languages are rendered to HTML using GitHub's open-source [Markup library](https://github.com/github/markup).
+  pub const Html: MediaType<String> =
+    MediaType::new("application/vnd.github.html+json", PayloadFormat::Text);
+}
@@ -29,7 +29,7 @@ impl GitHubSecretScanningAPI {
     enterprise: impl Into<String>,
   ) -> Request<(), SecretScanningListAlertsForEnterpriseQuery, OrganizationSecretScanningAlertArray>
   {
-    let enterprise = enterprise.into();
+    let enterprise = encode_path_segment(enterprise);
     let url = format!("/enterprises/{enterprise}/secret-scanning/alerts");
 
     Request::<(), SecretScanningListAlertsForEnterpriseQuery, OrganizationSecretScanningAlertArray>::builder(&self.config)
@@ -50,7 +50,7 @@ impl GitHubSecretScanningAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), SecretScanningListAlertsForOrgQuery, OrganizationSecretScanningAlertArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/secret-scanning/alerts");
 
     Request::<(), SecretScanningListAlertsForOrgQuery, OrganizationSecretScanningAlertArray>::builder(&self.config)
@@ -72,8 +72,8 @@ impl GitHubSecretScanningAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), SecretScanningListAlertsForRepoQuery, SecretScanningAlertArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/secret-scanning/alerts");
 
     Request::<(), SecretScanningListAlertsForRepoQuery, SecretScanningAlertArray>::builder(
@@ -98,8 +98,8 @@ impl GitHubSecretScanningAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<(), (), SecretScanningAlert> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}");
 
@@ -123,8 +123,8 @@ impl GitHubSecretScanningAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<SecretScanningUpdateAlertRequest, (), SecretScanningAlert> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}");
 
@@ -148,8 +148,8 @@ impl GitHubSecretScanningAPI {
     repo: impl Into<String>,
     alert_number: impl Into<serde_json::Value>,
   ) -> Request<(), SecretScanningListLocationsForAlertQuery, SecretScanningLocationArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let alert_number = alert_number.into();
     let url = format!("/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}/locations");
 
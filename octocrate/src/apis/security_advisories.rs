@@ -38,7 +38,7 @@ impl GitHubSecurityAdvisoriesAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/security-advisories/global-advisories#get-a-global-security-advisory](https://docs.github.com/rest/security-advisories/global-advisories#get-a-global-security-advisory)
   pub fn get_global_advisory(&self, ghsa_id: impl Into<String>) -> Request<(), (), GlobalAdvisory> {
-    let ghsa_id = ghsa_id.into();
+    let ghsa_id = encode_path_segment(ghsa_id);
     let url = format!("/advisories/{ghsa_id}");
 
     Request::<(), (), GlobalAdvisory>::builder(&self.config)
@@ -59,7 +59,7 @@ impl GitHubSecurityAdvisoriesAPI {
     &self,
     org: impl Into<String>,
   ) -> Request<(), SecurityAdvisoriesListOrgRepositoryAdvisoriesQuery, RepositoryAdvisoryArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/security-advisories");
 
     Request::<(), SecurityAdvisoriesListOrgRepositoryAdvisoriesQuery, RepositoryAdvisoryArray>::builder(&self.config)
@@ -81,8 +81,8 @@ impl GitHubSecurityAdvisoriesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), SecurityAdvisoriesListRepositoryAdvisoriesQuery, RepositoryAdvisoryArray> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/security-advisories");
 
     Request::<(), SecurityAdvisoriesListRepositoryAdvisoriesQuery, RepositoryAdvisoryArray>::builder(&self.config)
@@ -104,8 +104,8 @@ impl GitHubSecurityAdvisoriesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<RepositoryAdvisoryCreate, (), RepositoryAdvisory> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/security-advisories");
 
     Request::<RepositoryAdvisoryCreate, (), RepositoryAdvisory>::builder(&self.config)
@@ -124,8 +124,8 @@ impl GitHubSecurityAdvisoriesAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<PrivateVulnerabilityReportCreate, (), RepositoryAdvisory> {
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/repos/{owner}/{repo}/security-advisories/reports");
 
     Request::<PrivateVulnerabilityReportCreate, (), RepositoryAdvisory>::builder(&self.config)
@@ -151,9 +151,9 @@ impl GitHubSecurityAdvisoriesAPI {
     repo: impl Into<String>,
     ghsa_id: impl Into<String>,
   ) -> Request<(), (), RepositoryAdvisory> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ghsa_id = ghsa_id.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ghsa_id = encode_path_segment(ghsa_id);
     let url = format!("/repos/{owner}/{repo}/security-advisories/{ghsa_id}");
 
     Request::<(), (), RepositoryAdvisory>::builder(&self.config)
@@ -177,9 +177,9 @@ impl GitHubSecurityAdvisoriesAPI {
     repo: impl Into<String>,
     ghsa_id: impl Into<String>,
   ) -> Request<RepositoryAdvisoryUpdate, (), RepositoryAdvisory> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ghsa_id = ghsa_id.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ghsa_id = encode_path_segment(ghsa_id);
     let url = format!("/repos/{owner}/{repo}/security-advisories/{ghsa_id}");
 
     Request::<RepositoryAdvisoryUpdate, (), RepositoryAdvisory>::builder(&self.config)
@@ -204,9 +204,9 @@ impl GitHubSecurityAdvisoriesAPI {
     repo: impl Into<String>,
     ghsa_id: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ghsa_id = ghsa_id.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ghsa_id = encode_path_segment(ghsa_id);
     let url = format!("/repos/{owner}/{repo}/security-advisories/{ghsa_id}/cve");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -227,9 +227,9 @@ impl GitHubSecurityAdvisoriesAPI {
     repo: impl Into<String>,
     ghsa_id: impl Into<String>,
   ) -> Request<(), (), FullRepository> {
-    let owner = owner.into();
-    let repo = repo.into();
-    let ghsa_id = ghsa_id.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
+    let ghsa_id = encode_path_segment(ghsa_id);
     let url = format!("/repos/{owner}/{repo}/security-advisories/{ghsa_id}/forks");
 
     Request::<(), (), FullRepository>::builder(&self.config)
@@ -20,7 +20,7 @@ impl GitHubTeamsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/teams/teams#list-teams](https://docs.github.com/rest/teams/teams#list-teams)
   pub fn list(&self, org: impl Into<String>) -> Request<(), TeamsListQuery, TeamArray> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/teams");
 
     Request::<(), TeamsListQuery, TeamArray>::builder(&self.config)
@@ -36,7 +36,7 @@ impl GitHubTeamsAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/teams/teams#create-a-team](https://docs.github.com/rest/teams/teams#create-a-team)
   pub fn create(&self, org: impl Into<String>) -> Request<TeamsCreateRequest, (), FullTeam> {
-    let org = org.into();
+    let org = encode_path_segment(org);
     let url = format!("/orgs/{org}/teams");
 
     Request::<TeamsCreateRequest, (), FullTeam>::builder(&self.config)
@@ -56,8 +56,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<(), (), FullTeam> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}");
 
     Request::<(), (), FullTeam>::builder(&self.config)
@@ -77,8 +77,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<TeamsUpdateInOrgRequest, (), FullTeam> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}");
 
     Request::<TeamsUpdateInOrgRequest, (), FullTeam>::builder(&self.config)
@@ -100,8 +100,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -123,8 +123,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<(), TeamsListDiscussionsInOrgQuery, TeamDiscussionArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions");
 
     Request::<(), TeamsListDiscussionsInOrgQuery, TeamDiscussionArray>::builder(&self.config)
@@ -148,8 +148,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<TeamsCreateDiscussionInOrgRequest, (), TeamDiscussion> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions");
 
     Request::<TeamsCreateDiscussionInOrgRequest, (), TeamDiscussion>::builder(&self.config)
@@ -172,8 +172,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     discussion_number: impl Into<i64>,
   ) -> Request<(), (), TeamDiscussion> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}");
 
@@ -197,8 +197,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     discussion_number: impl Into<i64>,
   ) -> Request<TeamsUpdateDiscussionInOrgRequest, (), TeamDiscussion> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}");
 
@@ -222,8 +222,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     discussion_number: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}");
 
@@ -247,8 +247,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     discussion_number: impl Into<i64>,
   ) -> Request<(), TeamsListDiscussionCommentsInOrgQuery, TeamDiscussionCommentArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments");
 
@@ -276,8 +276,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     discussion_number: impl Into<i64>,
   ) -> Request<TeamsCreateDiscussionCommentInOrgRequest, (), TeamDiscussionComment> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments");
 
@@ -304,8 +304,8 @@ impl GitHubTeamsAPI {
     discussion_number: impl Into<i64>,
     comment_number: impl Into<i64>,
   ) -> Request<(), (), TeamDiscussionComment> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let comment_number = comment_number.into();
     let url = format!(
@@ -333,8 +333,8 @@ impl GitHubTeamsAPI {
     discussion_number: impl Into<i64>,
     comment_number: impl Into<i64>,
   ) -> Request<TeamsUpdateDiscussionCommentInOrgRequest, (), TeamDiscussionComment> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let comment_number = comment_number.into();
     let url = format!(
@@ -364,8 +364,8 @@ impl GitHubTeamsAPI {
     discussion_number: impl Into<i64>,
     comment_number: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let discussion_number = discussion_number.into();
     let comment_number = comment_number.into();
     let url = format!(
@@ -389,8 +389,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<(), TeamsListPendingInvitationsInOrgQuery, OrganizationInvitationArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}/invitations");
 
     Request::<(), TeamsListPendingInvitationsInOrgQuery, OrganizationInvitationArray>::builder(
@@ -412,8 +412,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<(), TeamsListMembersInOrgQuery, SimpleUserArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}/members");
 
     Request::<(), TeamsListMembersInOrgQuery, SimpleUserArray>::builder(&self.config)
@@ -441,9 +441,9 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     username: impl Into<String>,
   ) -> Request<(), (), TeamMembership> {
-    let org = org.into();
-    let team_slug = team_slug.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/teams/{team_slug}/memberships/{username}");
 
     Request::<(), (), TeamMembership>::builder(&self.config)
@@ -472,9 +472,9 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     username: impl Into<String>,
   ) -> Request<TeamsAddOrUpdateMembershipForUserInOrgRequest, (), TeamMembership> {
-    let org = org.into();
-    let team_slug = team_slug.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/teams/{team_slug}/memberships/{username}");
 
     Request::<TeamsAddOrUpdateMembershipForUserInOrgRequest, (), TeamMembership>::builder(
@@ -501,9 +501,9 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
-    let username = username.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
+    let username = encode_path_segment(username);
     let url = format!("/orgs/{org}/teams/{team_slug}/memberships/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -523,8 +523,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<(), TeamsListProjectsInOrgQuery, TeamProjectArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}/projects");
 
     Request::<(), TeamsListProjectsInOrgQuery, TeamProjectArray>::builder(&self.config)
@@ -545,8 +545,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     project_id: impl Into<i64>,
   ) -> Request<(), (), TeamProject> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let project_id = project_id.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/projects/{project_id}");
 
@@ -568,8 +568,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     project_id: impl Into<i64>,
   ) -> NoContentRequest<TeamsAddOrUpdateProjectPermissionsInOrgRequest, ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let project_id = project_id.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/projects/{project_id}");
 
@@ -591,8 +591,8 @@ impl GitHubTeamsAPI {
     team_slug: impl Into<String>,
     project_id: impl Into<i64>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let project_id = project_id.into();
     let url = format!("/orgs/{org}/teams/{team_slug}/projects/{project_id}");
 
@@ -613,8 +613,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<(), TeamsListReposInOrgQuery, MinimalRepositoryArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}/repos");
 
     Request::<(), TeamsListReposInOrgQuery, MinimalRepositoryArray>::builder(&self.config)
@@ -642,10 +642,10 @@ impl GitHubTeamsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> Request<(), (), TeamRepository> {
-    let org = org.into();
-    let team_slug = team_slug.into();
-    let owner = owner.into();
-    let repo = repo.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}");
 
     Request::<(), (), TeamRepository>::builder(&self.config)
@@ -669,10 +669,10 @@ impl GitHubTeamsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<TeamsAddOrUpdateRepoPermissionsInOrgRequest, ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
-    let owner = owner.into();
-    let repo = repo.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}");
 
     NoContentRequest::<TeamsAddOrUpdateRepoPermissionsInOrgRequest, ()>::builder(&self.config)
@@ -694,10 +694,10 @@ impl GitHubTeamsAPI {
     owner: impl Into<String>,
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let org = org.into();
-    let team_slug = team_slug.into();
-    let owner = owner.into();
-    let repo = repo.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -717,8 +717,8 @@ impl GitHubTeamsAPI {
     org: impl Into<String>,
     team_slug: impl Into<String>,
   ) -> Request<(), TeamsListChildInOrgQuery, TeamArray> {
-    let org = org.into();
-    let team_slug = team_slug.into();
+    let org = encode_path_segment(org);
+    let team_slug = encode_path_segment(team_slug);
     let url = format!("/orgs/{org}/teams/{team_slug}/teams");
 
     Request::<(), TeamsListChildInOrgQuery, TeamArray>::builder(&self.config)
@@ -1076,7 +1076,7 @@ impl GitHubTeamsAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let team_id = team_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/teams/{team_id}/members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1105,7 +1105,7 @@ impl GitHubTeamsAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let team_id = team_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/teams/{team_id}/members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1132,7 +1132,7 @@ impl GitHubTeamsAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let team_id = team_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/teams/{team_id}/members/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1160,7 +1160,7 @@ impl GitHubTeamsAPI {
     username: impl Into<String>,
   ) -> Request<(), (), TeamMembership> {
     let team_id = team_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/teams/{team_id}/memberships/{username}");
 
     Request::<(), (), TeamMembership>::builder(&self.config)
@@ -1189,7 +1189,7 @@ impl GitHubTeamsAPI {
     username: impl Into<String>,
   ) -> Request<TeamsAddOrUpdateMembershipForUserLegacyRequest, (), TeamMembership> {
     let team_id = team_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/teams/{team_id}/memberships/{username}");
 
     Request::<TeamsAddOrUpdateMembershipForUserLegacyRequest, (), TeamMembership>::builder(
@@ -1216,7 +1216,7 @@ impl GitHubTeamsAPI {
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let team_id = team_id.into();
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/teams/{team_id}/memberships/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -1339,8 +1339,8 @@ impl GitHubTeamsAPI {
     repo: impl Into<String>,
   ) -> Request<(), (), TeamRepository> {
     let team_id = team_id.into();
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/teams/{team_id}/repos/{owner}/{repo}");
 
     Request::<(), (), TeamRepository>::builder(&self.config)
@@ -1364,8 +1364,8 @@ impl GitHubTeamsAPI {
     repo: impl Into<String>,
   ) -> NoContentRequest<TeamsAddOrUpdateRepoPermissionsLegacyRequest, ()> {
     let team_id = team_id.into();
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/teams/{team_id}/repos/{owner}/{repo}");
 
     NoContentRequest::<TeamsAddOrUpdateRepoPermissionsLegacyRequest, ()>::builder(&self.config)
@@ -1387,8 +1387,8 @@ impl GitHubTeamsAPI {
     repo: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
     let team_id = team_id.into();
-    let owner = owner.into();
-    let repo = repo.into();
+    let owner = encode_path_segment(owner);
+    let repo = encode_path_segment(repo);
     let url = format!("/teams/{team_id}/repos/{owner}/{repo}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -61,7 +61,7 @@ impl GitHubUsersAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/users/blocking#check-if-a-user-is-blocked-by-the-authenticated-user](https://docs.github.com/rest/users/blocking#check-if-a-user-is-blocked-by-the-authenticated-user)
   pub fn check_blocked(&self, username: impl Into<String>) -> NoContentRequest<(), ()> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/user/blocks/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -75,7 +75,7 @@ impl GitHubUsersAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/users/blocking#block-a-user](https://docs.github.com/rest/users/blocking#block-a-user)
   pub fn block(&self, username: impl Into<String>) -> NoContentRequest<(), ()> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/user/blocks/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -89,7 +89,7 @@ impl GitHubUsersAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/users/blocking#unblock-a-user](https://docs.github.com/rest/users/blocking#unblock-a-user)
   pub fn unblock(&self, username: impl Into<String>) -> NoContentRequest<(), ()> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/user/blocks/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -202,7 +202,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/user/following/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -218,7 +218,7 @@ impl GitHubUsersAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/users/followers#follow-a-user](https://docs.github.com/rest/users/followers#follow-a-user)
   pub fn follow(&self, username: impl Into<String>) -> NoContentRequest<(), ()> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/user/following/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -232,7 +232,7 @@ impl GitHubUsersAPI {
   ///
   /// *Documentation*: [https://docs.github.com/rest/users/followers#unfollow-a-user](https://docs.github.com/rest/users/followers#unfollow-a-user)
   pub fn unfollow(&self, username: impl Into<String>) -> NoContentRequest<(), ()> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/user/following/{username}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -562,7 +562,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), (), UsersGetByUsernameResponse> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}");
 
     Request::<(), (), UsersGetByUsernameResponse>::builder(&self.config)
@@ -579,7 +579,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), UsersListFollowersForUserQuery, SimpleUserArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/followers");
 
     Request::<(), UsersListFollowersForUserQuery, SimpleUserArray>::builder(&self.config)
@@ -596,7 +596,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), UsersListFollowingForUserQuery, SimpleUserArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/following");
 
     Request::<(), UsersListFollowingForUserQuery, SimpleUserArray>::builder(&self.config)
@@ -613,8 +613,8 @@ impl GitHubUsersAPI {
     username: impl Into<String>,
     target_user: impl Into<String>,
   ) -> NoContentRequest<(), ()> {
-    let username = username.into();
-    let target_user = target_user.into();
+    let username = encode_path_segment(username);
+    let target_user = encode_path_segment(target_user);
     let url = format!("/users/{username}/following/{target_user}");
 
     NoContentRequest::<(), ()>::builder(&self.config)
@@ -631,7 +631,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), UsersListGpgKeysForUserQuery, GpgKeyArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/gpg_keys");
 
     Request::<(), UsersListGpgKeysForUserQuery, GpgKeyArray>::builder(&self.config)
@@ -652,7 +652,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), UsersGetContextForUserQuery, Hovercard> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/hovercard");
 
     Request::<(), UsersGetContextForUserQuery, Hovercard>::builder(&self.config)
@@ -669,7 +669,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), UsersListPublicKeysForUserQuery, KeySimpleArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/keys");
 
     Request::<(), UsersListPublicKeysForUserQuery, KeySimpleArray>::builder(&self.config)
@@ -686,7 +686,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), UsersListSocialAccountsForUserQuery, SocialAccountArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/social_accounts");
 
     Request::<(), UsersListSocialAccountsForUserQuery, SocialAccountArray>::builder(&self.config)
@@ -703,7 +703,7 @@ impl GitHubUsersAPI {
     &self,
     username: impl Into<String>,
   ) -> Request<(), UsersListSshSigningKeysForUserQuery, SshSigningKeyArray> {
-    let username = username.into();
+    let username = encode_path_segment(username);
     let url = format!("/users/{username}/ssh_signing_keys");
 
     Request::<(), UsersListSshSigningKeysForUserQuery, SshSigningKeyArray>::builder(&self.config)
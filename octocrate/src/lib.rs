@@ -1,6 +1,15 @@
 #![allow(unused_variables)]
 mod apis;
+#[cfg(any(feature = "full", feature = "repos"))]
+mod release_assets;
+mod targets;
+#[cfg(any(feature = "full", feature = "webhook_event"))]
+pub mod webhooks;
 
 pub use apis::*;
 pub use octocrate_core::*;
 pub use octocrate_types::*;
+#[cfg(any(feature = "full", feature = "repos"))]
+pub use release_assets::*;
+#[allow(unused_imports)]
+pub use targets::*;
@@ -0,0 +1,271 @@
+use octocrate_core::{reqwest::Body, Error, Request, SharedAPIConfig};
+use octocrate_types::{Release, ReleaseAsset, ReposUploadReleaseAssetQuery};
+use std::path::Path;
+use tokio_util::io::ReaderStream;
+
+/// Upload an asset to a release.
+///
+/// The asset is sent to the `upload_url` of the release, which is on
+/// `uploads.github.com`, or on the `/api/uploads` server of GitHub Enterprise Server.
+///
+/// ```rust,ignore
+/// let release = api
+///   .repos
+///   .get_release_by_tag("panghu-huang", "octocrate", "v0.3.5")
+///   .send()
+///   .await?;
+///
+/// let asset = ReleaseAssetUpload::new(&config, &release, "octocrate-linux.tar.gz")
+///   .label("Linux")
+///   .send_file("target/octocrate-linux.tar.gz")
+///   .await?;
+///
+/// println!("{}", asset.browser_download_url);
+/// ```
+pub struct ReleaseAssetUpload {
+  config: SharedAPIConfig,
+  url: String,
+  name: String,
+  label: Option<String>,
+  content_type: Option<String>,
+}
+
+impl ReleaseAssetUpload {
+  pub fn new(config: &SharedAPIConfig, release: &Release, name: impl Into<String>) -> Self {
+    ReleaseAssetUpload {
+      config: config.clone(),
+      url: expand_upload_url(&release.upload_url),
+      name: name.into(),
+      label: None,
+      content_type: None,
+    }
+  }
+
+  /// The name displayed instead of the file name.
+  pub fn label(mut self, label: impl Into<String>) -> Self {
+    self.label = Some(label.into());
+
+    self
+  }
+
+  /// The media type of the asset, guessed from its name when not set.
+  pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+    self.content_type = Some(content_type.into());
+
+    self
+  }
+
+  /// Upload the content of the asset, e.g. `Vec<u8>` or `Bytes`.
+  pub async fn send(self, body: impl Into<Body>) -> Result<ReleaseAsset, Error> {
+    self.upload(body.into(), None).await
+  }
+
+  /// Upload the content of a file, streamed from the disk instead of read into memory.
+  pub async fn send_file(self, path: impl AsRef<Path>) -> Result<ReleaseAsset, Error> {
+    let path = path.as_ref();
+    let read_error =
+      |err: std::io::Error| Error::Error(format!("Failed to read {}: {}", path.display(), err));
+
+    let file = tokio::fs::File::open(path).await.map_err(read_error)?;
+    let metadata = file.metadata().await.map_err(read_error)?;
+
+    // The upload server needs the size up front, a stream is sent chunked otherwise
+    self
+      .upload(
+        Body::wrap_stream(ReaderStream::new(file)),
+        Some(metadata.len()),
+      )
+      .await
+  }
+
+  async fn upload(self, body: Body, content_length: Option<u64>) -> Result<ReleaseAsset, Error> {
+    let content_type = self
+      .content_type
+      .unwrap_or_else(|| guess_content_type(&self.name).to_string());
+
+    let query = ReposUploadReleaseAssetQuery {
+      name: self.name,
+      label: self.label,
+    };
+
+    let request = Request::<(), ReposUploadReleaseAssetQuery, ReleaseAsset>::builder(&self.config)
+      .post(self.url)
+      .build()
+      .query(&query)
+      .raw_body(body, &content_type);
+
+    match content_length {
+      Some(content_length) => request.header("content-length", content_length),
+      None => request,
+    }
+    .send()
+    .await
+  }
+}
+
+/// The upload URL of a release is a template, e.g.
+/// `https://uploads.github.com/repos/o/r/releases/1/assets{?name,label}`, whose query
+/// is set from the query type instead.
+fn expand_upload_url(upload_url: &str) -> String {
+  match upload_url.split_once('{') {
+    Some((url, _)) => url.to_string(),
+    None => upload_url.to_string(),
+  }
+}
+
+fn guess_content_type(name: &str) -> &'static str {
+  let extension = name
+    .rsplit_once('.')
+    .map(|(_, extension)| extension.to_lowercase());
+
+  match extension.as_deref() {
+    Some("gz" | "tgz") => "application/gzip",
+    Some("zip") => "application/zip",
+    Some("tar") => "application/x-tar",
+    Some("xz") => "application/x-xz",
+    Some("zst") => "application/zstd",
+    Some("json") => "application/json",
+    Some("txt" | "sha256" | "sig" | "asc") => "text/plain",
+    Some("md") => "text/markdown",
+    Some("deb") => "application/vnd.debian.binary-package",
+    Some("rpm") => "application/x-rpm",
+    Some("dmg") => "application/x-apple-diskimage",
+    Some("msi") => "application/x-msi",
+    _ => "application/octet-stream",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use octocrate_core::APIConfig;
+  use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+  };
+
+  const ASSET: &str = r#"{
+    "browser_download_url": "https://github.com/panghu-huang/octocrate/releases/download/v0.3.5/octocrate.zip",
+    "content_type": "application/zip",
+    "created_at": "2024-04-01T00:00:00Z",
+    "download_count": 0,
+    "id": 1,
+    "label": null,
+    "name": "octocrate.zip",
+    "node_id": "RA_1",
+    "size": 4,
+    "state": "uploaded",
+    "updated_at": "2024-04-01T00:00:00Z",
+    "uploader": null,
+    "url": "https://api.github.com/repos/panghu-huang/octocrate/releases/assets/1"
+  }"#;
+
+  /// Answer one upload with `ASSET`, returning the head and the body of the request.
+  async fn receive_upload() -> (String, tokio::task::JoinHandle<(String, Vec<u8>)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let handle = tokio::spawn(async move {
+      let (mut stream, _) = listener.accept().await.unwrap();
+      let mut request = Vec::new();
+      let mut buffer = [0; 4096];
+
+      let (head, body) = loop {
+        let read = stream.read(&mut buffer).await.unwrap();
+
+        request.extend_from_slice(&buffer[..read]);
+
+        let Some(end) = request.windows(4).position(|window| window == b"\r\n\r\n") else {
+          continue;
+        };
+
+        let head = String::from_utf8_lossy(&request[..end]).to_lowercase();
+        let length = head
+          .lines()
+          .find_map(|line| line.strip_prefix("content-length: "))
+          .map(|length| length.trim().parse::<usize>().unwrap())
+          .unwrap_or_default();
+
+        while request.len() < end + 4 + length {
+          let read = stream.read(&mut buffer).await.unwrap();
+
+          request.extend_from_slice(&buffer[..read]);
+        }
+
+        break (head, request[end + 4..].to_vec());
+      };
+
+      let response = format!(
+        "HTTP/1.1 201 Created\r\ncontent-type: application/json\r\nconnection: close\r\ncontent-length: {}\r\n\r\n{}",
+        ASSET.len(),
+        ASSET
+      );
+
+      stream.write_all(response.as_bytes()).await.unwrap();
+      stream.shutdown().await.unwrap();
+
+      (head, body)
+    });
+
+    (format!("http://{}", address), handle)
+  }
+
+  #[tokio::test]
+  async fn test_send_file() {
+    let (base_url, upload) = receive_upload().await;
+    let path = std::env::temp_dir().join(format!("octocrate-{}.zip", std::process::id()));
+
+    tokio::fs::write(&path, [0x50, 0x4b, 0x03, 0x04])
+      .await
+      .unwrap();
+
+    let upload_url = format!(
+      "{}/repos/panghu-huang/octocrate/releases/1/assets",
+      base_url
+    );
+    let asset = ReleaseAssetUpload {
+      config: APIConfig::with_base_url(&base_url).shared(),
+      url: expand_upload_url(&format!("{}{{?name,label}}", upload_url)),
+      name: "octocrate.zip".to_string(),
+      label: None,
+      content_type: None,
+    }
+    .send_file(&path)
+    .await
+    .unwrap();
+
+    tokio::fs::remove_file(&path).await.unwrap();
+
+    assert_eq!(asset.name, "octocrate.zip");
+
+    let (head, body) = upload.await.unwrap();
+
+    assert!(head.starts_with(
+      "post /repos/panghu-huang/octocrate/releases/1/assets?name=octocrate.zip http/1.1"
+    ));
+    assert!(head.contains("content-type: application/zip"));
+    assert!(head.contains("content-length: 4"));
+    assert!(!head.contains("transfer-encoding"));
+    assert_eq!(body, vec![0x50, 0x4b, 0x03, 0x04]);
+  }
+
+  #[test]
+  fn test_expand_upload_url() {
+    assert_eq!(
+      expand_upload_url(
+        "https://uploads.github.com/repos/panghu-huang/octocrate/releases/1/assets{?name,label}"
+      ),
+      "https://uploads.github.com/repos/panghu-huang/octocrate/releases/1/assets"
+    );
+  }
+
+  #[test]
+  fn test_guess_content_type() {
+    assert_eq!(
+      guess_content_type("octocrate-linux.tar.gz"),
+      "application/gzip"
+    );
+    assert_eq!(guess_content_type("checksums.SHA256"), "text/plain");
+    assert_eq!(guess_content_type("octocrate"), "application/octet-stream");
+  }
+}
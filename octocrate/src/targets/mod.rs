@@ -0,0 +1,2 @@
+//! The operations of GitHub Enterprise Cloud and Server, generated from their descriptions
+//! in `codegen/resources`. Every target has its own `GitHubAPI` with the operations it has.
@@ -0,0 +1,351 @@
+use super::{parse, WebhookError};
+use chrono::{DateTime, Utc};
+use futures_util::{stream::BoxStream, StreamExt, TryStreamExt};
+use octocrate_core::{Error, SharedAPIConfig, Timestamp};
+use octocrate_types::*;
+use std::collections::HashSet;
+use thiserror::Error as ThisError;
+
+#[derive(ThisError, Debug)]
+pub enum DeliveryError {
+  #[error(transparent)]
+  Request(#[from] Error),
+  #[error("Delivery {0} has no stored payload")]
+  MissingPayload(i64),
+  #[error(transparent)]
+  Webhook(#[from] WebhookError),
+}
+
+/// The webhook whose deliveries are managed.
+#[derive(Debug, Clone)]
+enum Hook {
+  #[cfg(any(feature = "full", feature = "apps"))]
+  App,
+  #[cfg(any(feature = "full", feature = "repos"))]
+  Repository {
+    owner: String,
+    repo: String,
+    hook_id: i64,
+  },
+  #[cfg(any(feature = "full", feature = "orgs"))]
+  Organization { org: String, hook_id: i64 },
+}
+
+/// Recover the deliveries of a webhook after an outage of its receiver, either by
+/// asking GitHub to redeliver the failed ones, or by fetching their stored payload
+/// and replaying it locally.
+///
+/// ```rust,ignore
+/// let deliveries = WebhookDeliveries::app(&app_config);
+///
+/// // Everything that failed during the last 6 hours
+/// let since = Utc::now() - Duration::hours(6);
+///
+/// for delivery in deliveries.redeliver_failed_since(since).await? {
+///   println!("Redelivered {} {}", delivery.event, delivery.guid);
+/// }
+///
+/// // Or run a delivery through the local handlers
+/// let response = deliveries.replay(delivery_id, &server).await?;
+/// ```
+pub struct WebhookDeliveries {
+  config: SharedAPIConfig,
+  hook: Hook,
+}
+
+impl WebhookDeliveries {
+  /// The webhook of the GitHub App, the config must authenticate as the app.
+  #[cfg(any(feature = "full", feature = "apps"))]
+  pub fn app(config: &SharedAPIConfig) -> Self {
+    WebhookDeliveries {
+      config: config.clone(),
+      hook: Hook::App,
+    }
+  }
+
+  #[cfg(any(feature = "full", feature = "repos"))]
+  pub fn repository(
+    config: &SharedAPIConfig,
+    owner: impl Into<String>,
+    repo: impl Into<String>,
+    hook_id: impl Into<i64>,
+  ) -> Self {
+    WebhookDeliveries {
+      config: config.clone(),
+      hook: Hook::Repository {
+        owner: owner.into(),
+        repo: repo.into(),
+        hook_id: hook_id.into(),
+      },
+    }
+  }
+
+  #[cfg(any(feature = "full", feature = "orgs"))]
+  pub fn organization(
+    config: &SharedAPIConfig,
+    org: impl Into<String>,
+    hook_id: impl Into<i64>,
+  ) -> Self {
+    WebhookDeliveries {
+      config: config.clone(),
+      hook: Hook::Organization {
+        org: org.into(),
+        hook_id: hook_id.into(),
+      },
+    }
+  }
+
+  /// Every delivery made since `since`, newest first, including redeliveries.
+  pub async fn list_since(
+    &self,
+    since: DateTime<Utc>,
+  ) -> Result<Vec<SimpleWebhookDelivery>, Error> {
+    let mut stream = self.stream();
+    let mut deliveries = Vec::new();
+
+    while let Some(delivery) = stream.try_next().await? {
+      // The deliveries are listed from the newest to the oldest
+      if delivered_at(&delivery).is_some_and(|delivered_at| delivered_at < since) {
+        break;
+      }
+
+      deliveries.push(delivery);
+    }
+
+    Ok(deliveries)
+  }
+
+  /// The latest attempt of every event delivered since `since` that never got a
+  /// successful response, even after being redelivered.
+  pub async fn failed_since(
+    &self,
+    since: DateTime<Utc>,
+  ) -> Result<Vec<SimpleWebhookDelivery>, Error> {
+    Ok(failed(self.list_since(since).await?))
+  }
+
+  /// Ask GitHub to redeliver every event that failed since `since`, and return the
+  /// attempts that were redelivered.
+  pub async fn redeliver_failed_since(
+    &self,
+    since: DateTime<Utc>,
+  ) -> Result<Vec<SimpleWebhookDelivery>, Error> {
+    let failed = self.failed_since(since).await?;
+
+    for delivery in &failed {
+      self.redeliver(delivery.id).await?;
+    }
+
+    Ok(failed)
+  }
+
+  pub async fn get(&self, delivery_id: i64) -> Result<WebhookDelivery, Error> {
+    match &self.hook {
+      #[cfg(any(feature = "full", feature = "apps"))]
+      Hook::App => {
+        crate::apps::GitHubAppsAPI::new(&self.config)
+          .get_webhook_delivery(delivery_id)
+          .send()
+          .await
+      }
+      #[cfg(any(feature = "full", feature = "repos"))]
+      Hook::Repository {
+        owner,
+        repo,
+        hook_id,
+      } => {
+        crate::repos::GitHubReposAPI::new(&self.config)
+          .get_webhook_delivery(owner, repo, *hook_id, delivery_id)
+          .send()
+          .await
+      }
+      #[cfg(any(feature = "full", feature = "orgs"))]
+      Hook::Organization { org, hook_id } => {
+        crate::orgs::GitHubOrgsAPI::new(&self.config)
+          .get_webhook_delivery(org, *hook_id, delivery_id)
+          .send()
+          .await
+      }
+    }
+  }
+
+  pub async fn redeliver(&self, delivery_id: i64) -> Result<(), Error> {
+    match &self.hook {
+      #[cfg(any(feature = "full", feature = "apps"))]
+      Hook::App => {
+        crate::apps::GitHubAppsAPI::new(&self.config)
+          .redeliver_webhook_delivery(delivery_id)
+          .send()
+          .await
+      }
+      #[cfg(any(feature = "full", feature = "repos"))]
+      Hook::Repository {
+        owner,
+        repo,
+        hook_id,
+      } => {
+        crate::repos::GitHubReposAPI::new(&self.config)
+          .redeliver_webhook_delivery(owner, repo, *hook_id, delivery_id)
+          .send()
+          .await
+      }
+      #[cfg(any(feature = "full", feature = "orgs"))]
+      Hook::Organization { org, hook_id } => {
+        crate::orgs::GitHubOrgsAPI::new(&self.config)
+          .redeliver_webhook_delivery(org, *hook_id, delivery_id)
+          .send()
+          .await
+      }
+    }
+  }
+
+  /// Fetch the payload stored for a delivery and parse it into its event.
+  pub async fn fetch_event(&self, delivery_id: i64) -> Result<WebhookEvent, DeliveryError> {
+    let delivery = self.get(delivery_id).await?;
+
+    Ok(parse(&delivery.event, &payload(&delivery)?)?)
+  }
+
+  /// Fetch the payload stored for a delivery and run it through the handlers of a
+  /// local server, as if GitHub had delivered it again.
+  #[cfg(any(feature = "full", feature = "webhook_server"))]
+  pub async fn replay(
+    &self,
+    delivery_id: i64,
+    server: &super::WebhookServer,
+  ) -> Result<http::Response<String>, DeliveryError> {
+    let delivery = self.get(delivery_id).await?;
+    let body = payload(&delivery)?;
+
+    Ok(
+      server
+        .dispatch(&delivery.event, Some(&delivery.guid), &body)
+        .await,
+    )
+  }
+
+  fn stream(&self) -> BoxStream<'static, Result<SimpleWebhookDelivery, Error>> {
+    match &self.hook {
+      #[cfg(any(feature = "full", feature = "apps"))]
+      Hook::App => {
+        let query = AppsListWebhookDeliveriesQuery::builder()
+          .per_page(100)
+          .build();
+
+        crate::apps::GitHubAppsAPI::new(&self.config)
+          .list_webhook_deliveries()
+          .query(&query)
+          .into_stream()
+          .boxed()
+      }
+      #[cfg(any(feature = "full", feature = "repos"))]
+      Hook::Repository {
+        owner,
+        repo,
+        hook_id,
+      } => {
+        let query = ReposListWebhookDeliveriesQuery::builder()
+          .per_page(100)
+          .build();
+
+        crate::repos::GitHubReposAPI::new(&self.config)
+          .list_webhook_deliveries(owner, repo, *hook_id)
+          .query(&query)
+          .into_stream()
+          .boxed()
+      }
+      #[cfg(any(feature = "full", feature = "orgs"))]
+      Hook::Organization { org, hook_id } => {
+        let query = OrgsListWebhookDeliveriesQuery::builder()
+          .per_page(100)
+          .build();
+
+        crate::orgs::GitHubOrgsAPI::new(&self.config)
+          .list_webhook_deliveries(org, *hook_id)
+          .query(&query)
+          .into_stream()
+          .boxed()
+      }
+    }
+  }
+}
+
+fn delivered_at(delivery: &SimpleWebhookDelivery) -> Option<DateTime<Utc>> {
+  delivery.delivered_at.to_date_time()
+}
+
+fn is_success(delivery: &SimpleWebhookDelivery) -> bool {
+  (200..300).contains(&delivery.status_code)
+}
+
+/// The latest attempt of the events without any successful attempt, the deliveries
+/// being sorted from the newest to the oldest.
+fn failed(deliveries: Vec<SimpleWebhookDelivery>) -> Vec<SimpleWebhookDelivery> {
+  let delivered: HashSet<String> = deliveries
+    .iter()
+    .filter(|delivery| is_success(delivery))
+    .map(|delivery| delivery.guid.clone())
+    .collect();
+  let mut seen = HashSet::new();
+
+  deliveries
+    .into_iter()
+    .filter(|delivery| !delivered.contains(&delivery.guid) && seen.insert(delivery.guid.clone()))
+    .collect()
+}
+
+fn payload(delivery: &WebhookDelivery) -> Result<Vec<u8>, DeliveryError> {
+  let payload = delivery
+    .request
+    .payload
+    .as_ref()
+    .ok_or(DeliveryError::MissingPayload(delivery.id))?;
+
+  serde_json::to_vec(payload).map_err(|err| DeliveryError::Webhook(err.into()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn delivery(id: i64, guid: &str, status_code: i64) -> SimpleWebhookDelivery {
+    SimpleWebhookDelivery::builder()
+      .id(id)
+      .guid(guid)
+      .status_code(status_code)
+      .status("")
+      .event("push")
+      .delivered_at(
+        "2024-04-01T00:00:00Z"
+          .parse::<octocrate_types::DateTime>()
+          .unwrap(),
+      )
+      .duration(0.1)
+      .redelivery(false)
+      .build()
+  }
+
+  #[test]
+  fn test_failed() {
+    // Newest first: `a` succeeded when redelivered, `b` failed twice, `c` succeeded
+    let deliveries = vec![
+      delivery(5, "a", 200),
+      delivery(4, "b", 502),
+      delivery(3, "c", 202),
+      delivery(2, "b", 500),
+      delivery(1, "a", 500),
+    ];
+
+    let failed = failed(deliveries);
+
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].id, 4);
+  }
+
+  #[test]
+  fn test_delivered_at() {
+    let delivered_at = delivered_at(&delivery(1, "a", 200)).unwrap();
+
+    assert_eq!(delivered_at.to_rfc3339(), "2024-04-01T00:00:00+00:00");
+  }
+}
@@ -0,0 +1,186 @@
+//! Verify and parse the deliveries of webhooks.
+//!
+//! ```rust,ignore
+//! let event = webhooks::verify_and_parse(&secret, &headers, &body)?;
+//!
+//! if let WebhookEvent::PullRequestOpened(event) = event {
+//!   // ..
+//! }
+//! ```
+#[cfg(any(feature = "full", feature = "webhook_deliveries"))]
+mod deliveries;
+#[cfg(any(feature = "full", feature = "webhook_server"))]
+mod server;
+
+use hmac::{Hmac, Mac};
+use http::HeaderMap;
+use octocrate_types::WebhookEvent;
+use serde::Deserialize;
+use sha2::Sha256;
+use thiserror::Error as ThisError;
+
+#[cfg(any(feature = "full", feature = "webhook_deliveries"))]
+pub use deliveries::*;
+#[cfg(any(feature = "full", feature = "webhook_server"))]
+pub use server::*;
+
+/// The name of the event, e.g. `pull_request`.
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+/// The HMAC-SHA256 of the body with the secret of the webhook, e.g. `sha256=<hex>`.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+/// The unique id of the delivery, kept when it is redelivered.
+pub const DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+
+#[derive(ThisError, Debug)]
+pub enum WebhookError {
+  #[error("Missing header {0}")]
+  MissingHeader(&'static str),
+  #[error("Signature doesn't match the body")]
+  InvalidSignature,
+  #[error("Unknown event {event_name} with action {action:?}")]
+  UnknownEvent {
+    event_name: String,
+    action: Option<String>,
+  },
+  #[error("Failed to parse payload: {0}")]
+  InvalidPayload(#[from] serde_json::Error),
+}
+
+#[derive(Deserialize)]
+struct Action {
+  action: Option<String>,
+}
+
+/// The `X-Hub-Signature-256` header that GitHub sends with a body, e.g. to replay a
+/// delivery into a local receiver.
+pub fn sign(secret: impl AsRef<[u8]>, body: &[u8]) -> String {
+  let mut mac =
+    Hmac::<Sha256>::new_from_slice(secret.as_ref()).expect("HMAC accepts keys of any length");
+
+  mac.update(body);
+
+  format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Check the `X-Hub-Signature-256` header of a delivery against its raw body, in
+/// constant time.
+pub fn verify(secret: impl AsRef<[u8]>, signature: &str, body: &[u8]) -> Result<(), WebhookError> {
+  let signature = signature
+    .strip_prefix("sha256=")
+    .and_then(|signature| hex::decode(signature).ok())
+    .ok_or(WebhookError::InvalidSignature)?;
+
+  let mut mac =
+    Hmac::<Sha256>::new_from_slice(secret.as_ref()).expect("HMAC accepts keys of any length");
+
+  mac.update(body);
+
+  mac
+    .verify_slice(&signature)
+    .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Parse the body of a delivery into the payload of its `X-GitHub-Event` header and
+/// `action`.
+pub fn parse(event_name: &str, body: &[u8]) -> Result<WebhookEvent, WebhookError> {
+  let action = serde_json::from_slice::<Action>(body)?.action;
+
+  match WebhookEvent::from_slice(event_name, action.as_deref(), body) {
+    Some(event) => Ok(event?),
+    None => Err(WebhookError::UnknownEvent {
+      event_name: event_name.to_string(),
+      action,
+    }),
+  }
+}
+
+/// Verify the signature of a delivery from its headers, then parse it.
+pub fn verify_and_parse(
+  secret: impl AsRef<[u8]>,
+  headers: &HeaderMap,
+  body: &[u8],
+) -> Result<WebhookEvent, WebhookError> {
+  let signature = header(headers, SIGNATURE_HEADER)?;
+
+  verify(secret, signature, body)?;
+
+  parse(header(headers, EVENT_HEADER)?, body)
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &'static str) -> Result<&'a str, WebhookError> {
+  headers
+    .get(name)
+    .and_then(|value| value.to_str().ok())
+    .ok_or(WebhookError::MissingHeader(name))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_verify() {
+    // The example of the GitHub documentation
+    let secret = "It's a Secret to Everybody";
+    let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    assert_eq!(sign(secret, b"Hello, World!"), signature);
+    assert!(verify(secret, signature, b"Hello, World!").is_ok());
+    assert!(matches!(
+      verify(secret, signature, b"Hello, World?"),
+      Err(WebhookError::InvalidSignature)
+    ));
+    assert!(matches!(
+      verify(secret, "sha1=757107ea", b"Hello, World!"),
+      Err(WebhookError::InvalidSignature)
+    ));
+  }
+
+  #[test]
+  #[cfg(any(feature = "full", feature = "webhook_ping"))]
+  fn test_parse() {
+    let body = br#"{ "zen": "Keep it logically awesome.", "hook_id": 1 }"#;
+
+    let event = parse("ping", body).unwrap();
+
+    assert!(matches!(event, WebhookEvent::Ping(_)));
+    assert_eq!(event.event_name(), "ping");
+
+    assert!(matches!(
+      parse("new_event", br#"{"action":"created"}"#),
+      Err(WebhookError::UnknownEvent { .. })
+    ));
+  }
+
+  #[test]
+  #[cfg(all(
+    not(feature = "strict"),
+    any(feature = "full", feature = "webhook_check_suite")
+  ))]
+  fn test_parse_unknown_action() {
+    let body = br#"{ "action": "paused", "check_suite": { "id": 1 } }"#;
+
+    let event = match parse("check_suite", body).unwrap() {
+      WebhookEvent::Unknown(event) => event,
+      event => panic!("Expected an unknown action, got {:?}", event),
+    };
+
+    assert_eq!(event.event_name, "check_suite");
+    assert_eq!(event.action.as_deref(), Some("paused"));
+    assert_eq!(event.payload["check_suite"]["id"], 1);
+  }
+
+  #[test]
+  #[cfg(all(
+    feature = "strict",
+    any(feature = "full", feature = "webhook_check_suite")
+  ))]
+  fn test_parse_strict_action() {
+    let body = br#"{ "action": "paused", "check_suite": { "id": 1 } }"#;
+
+    assert!(matches!(
+      parse("check_suite", body),
+      Err(WebhookError::UnknownEvent { .. })
+    ));
+  }
+}
@@ -0,0 +1,469 @@
+use super::{parse, verify, WebhookError, DELIVERY_HEADER, EVENT_HEADER, SIGNATURE_HEADER};
+use http::{HeaderMap, Response, StatusCode};
+use octocrate_types::WebhookEvent;
+use std::{
+  collections::{HashMap, VecDeque},
+  future::Future,
+  pin::Pin,
+  sync::{Arc, Mutex},
+};
+
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+pub type HandlerFuture = Pin<Box<dyn Future<Output = Result<(), HandlerError>> + Send>>;
+
+type Handler = Box<dyn Fn(&WebhookEvent) -> Option<HandlerFuture> + Send + Sync>;
+
+pub type SharedWebhookServer = Arc<WebhookServer>;
+
+/// Receives the deliveries of a webhook, checks their signature and routes their
+/// payload to the handlers registered for its type.
+///
+/// The server isn't tied to a framework, `handle` takes the headers and raw body of a
+/// request and returns the response to send back, e.g. with axum:
+///
+/// ```rust,ignore
+/// let server = WebhookServer::new(secret)
+///   .on(|event: WebhookPullRequestOpened| async move {
+///     println!("#{} opened", event.number);
+///     Ok(())
+///   })
+///   .deduplicate(1000)
+///   .shared();
+///
+/// let app = Router::new()
+///   .route(
+///     "/webhooks",
+///     post(
+///       |State(server): State<SharedWebhookServer>, headers: HeaderMap, body: Bytes| async move {
+///         server.handle(&headers, &body).await
+///       },
+///     ),
+///   )
+///   .with_state(server);
+/// ```
+///
+/// The response is `200 OK` once every handler of the event succeeded, `202 Accepted`
+/// for events without handlers and for `ping`, `400 Bad Request` or `401 Unauthorized`
+/// for deliveries that can't be verified or parsed, and `500 Internal Server Error` if
+/// a handler failed, so that the delivery can be redelivered. The error of the handler
+/// is logged rather than sent back to GitHub.
+pub struct WebhookServer {
+  secret: Vec<u8>,
+  handlers: Vec<Handler>,
+  deliveries: Option<Mutex<Deliveries>>,
+}
+
+impl WebhookServer {
+  pub fn new(secret: impl AsRef<[u8]>) -> Self {
+    WebhookServer {
+      secret: secret.as_ref().to_vec(),
+      handlers: Vec::new(),
+      deliveries: None,
+    }
+  }
+
+  /// Handle the events with a payload of type `T`, e.g. `WebhookPullRequestOpened`.
+  pub fn on<T, F, Fut>(mut self, handler: F) -> Self
+  where
+    T: TryFrom<WebhookEvent, Error = WebhookEvent>,
+    F: Fn(T) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+  {
+    self.handlers.push(Box::new(move |event: &WebhookEvent| {
+      let event = T::try_from(event.clone()).ok()?;
+
+      Some(Box::pin(handler(event)) as HandlerFuture)
+    }));
+
+    self
+  }
+
+  /// Handle every event, e.g. to log or forward them.
+  pub fn on_any<F, Fut>(mut self, handler: F) -> Self
+  where
+    F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), HandlerError>> + Send + 'static,
+  {
+    self.handlers.push(Box::new(move |event: &WebhookEvent| {
+      Some(Box::pin(handler(event.clone())) as HandlerFuture)
+    }));
+
+    self
+  }
+
+  /// Skip the deliveries whose `X-GitHub-Delivery` id was already handled, among the
+  /// last `capacity` ones. Failed deliveries are forgotten so that they can be
+  /// redelivered, and a delivery that arrives again while it is still being handled
+  /// gets `409 Conflict`, so that GitHub keeps it as failed until it was handled.
+  pub fn deduplicate(mut self, capacity: usize) -> Self {
+    self.deliveries = Some(Mutex::new(Deliveries::new(capacity)));
+
+    self
+  }
+
+  pub fn shared(self) -> SharedWebhookServer {
+    Arc::new(self)
+  }
+
+  /// Verify, parse and route a delivery, and build the response to send to GitHub.
+  pub async fn handle(&self, headers: &HeaderMap, body: &[u8]) -> Response<String> {
+    let signature = match header(headers, SIGNATURE_HEADER) {
+      Some(signature) => signature,
+      None => return respond(StatusCode::UNAUTHORIZED, "Missing signature"),
+    };
+
+    if verify(&self.secret, signature, body).is_err() {
+      return respond(StatusCode::UNAUTHORIZED, "Invalid signature");
+    }
+
+    let event_name = match header(headers, EVENT_HEADER) {
+      Some(event_name) => event_name,
+      None => return respond(StatusCode::BAD_REQUEST, "Missing event"),
+    };
+
+    self
+      .dispatch(event_name, header(headers, DELIVERY_HEADER), body)
+      .await
+  }
+
+  /// Parse and route a delivery that is already trusted, e.g. a payload fetched back
+  /// from the API, without checking its signature.
+  pub async fn dispatch(
+    &self,
+    event_name: &str,
+    delivery: Option<&str>,
+    body: &[u8],
+  ) -> Response<String> {
+    let event = match parse(event_name, body) {
+      Ok(event) => event,
+      Err(WebhookError::UnknownEvent { .. }) if event_name == "ping" => {
+        return respond(StatusCode::ACCEPTED, "Pong");
+      }
+      Err(WebhookError::UnknownEvent { .. }) => {
+        return respond(StatusCode::ACCEPTED, "Ignored");
+      }
+      Err(err) => return respond(StatusCode::BAD_REQUEST, &err.to_string()),
+    };
+
+    let mut in_flight = None;
+
+    if let (Some(deliveries), Some(delivery)) = (&self.deliveries, delivery) {
+      match deliveries.lock().unwrap().start(delivery) {
+        None => {}
+        Some(DeliveryState::InFlight) => {
+          return respond(StatusCode::CONFLICT, "Delivery in progress");
+        }
+        Some(DeliveryState::Completed) => {
+          return respond(StatusCode::OK, "Already delivered");
+        }
+      }
+
+      // Forgets the delivery unless it completes, e.g. if the request is dropped
+      in_flight = Some(InFlight {
+        deliveries,
+        id: delivery,
+      });
+    }
+
+    let mut handled = false;
+
+    for handler in &self.handlers {
+      let future = match handler(&event) {
+        Some(future) => future,
+        None => continue,
+      };
+
+      handled = true;
+
+      if let Err(err) = future.await {
+        log::error!(
+          "Handler of webhook {} failed for delivery {:?}: {}",
+          event_name,
+          delivery,
+          err
+        );
+
+        return respond(StatusCode::INTERNAL_SERVER_ERROR, "Handler failed");
+      }
+    }
+
+    if let Some(in_flight) = in_flight {
+      in_flight.complete();
+    }
+
+    if handled {
+      respond(StatusCode::OK, "OK")
+    } else if event_name == "ping" {
+      respond(StatusCode::ACCEPTED, "Pong")
+    } else {
+      respond(StatusCode::ACCEPTED, "Ignored")
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DeliveryState {
+  InFlight,
+  Completed,
+}
+
+/// The ids of the last deliveries, oldest first.
+struct Deliveries {
+  capacity: usize,
+  ids: HashMap<String, DeliveryState>,
+  order: VecDeque<String>,
+}
+
+impl Deliveries {
+  fn new(capacity: usize) -> Self {
+    Deliveries {
+      capacity,
+      ids: HashMap::new(),
+      order: VecDeque::new(),
+    }
+  }
+
+  /// Mark the id as in flight, or return the state of the delivery if it isn't new.
+  fn start(&mut self, id: &str) -> Option<DeliveryState> {
+    if let Some(state) = self.ids.get(id) {
+      return Some(*state);
+    }
+
+    self.ids.insert(id.to_string(), DeliveryState::InFlight);
+    self.order.push_back(id.to_string());
+
+    while self.order.len() > self.capacity {
+      if let Some(oldest) = self.order.pop_front() {
+        self.ids.remove(&oldest);
+      }
+    }
+
+    None
+  }
+
+  fn complete(&mut self, id: &str) {
+    if let Some(state) = self.ids.get_mut(id) {
+      *state = DeliveryState::Completed;
+    }
+  }
+
+  fn remove(&mut self, id: &str) {
+    self.ids.remove(id);
+    self.order.retain(|delivery| delivery != id);
+  }
+}
+
+/// A delivery being handled, forgotten when dropped before it completes.
+struct InFlight<'a> {
+  deliveries: &'a Mutex<Deliveries>,
+  id: &'a str,
+}
+
+impl InFlight<'_> {
+  fn complete(self) {
+    self.deliveries.lock().unwrap().complete(self.id);
+
+    std::mem::forget(self);
+  }
+}
+
+impl Drop for InFlight<'_> {
+  fn drop(&mut self) {
+    if let Ok(mut deliveries) = self.deliveries.lock() {
+      deliveries.remove(self.id);
+    }
+  }
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+  headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn respond(status: StatusCode, message: &str) -> Response<String> {
+  let mut response = Response::new(message.to_string());
+
+  *response.status_mut() = status;
+
+  response
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::webhooks::sign;
+
+  const SECRET: &str = "secret";
+
+  fn headers(event_name: &str, delivery: &str, body: &[u8]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+
+    headers.insert(SIGNATURE_HEADER, sign(SECRET, body).parse().unwrap());
+    headers.insert(EVENT_HEADER, event_name.parse().unwrap());
+    headers.insert(DELIVERY_HEADER, delivery.parse().unwrap());
+
+    headers
+  }
+
+  #[test]
+  fn test_deliveries() {
+    let mut deliveries = Deliveries::new(2);
+
+    assert_eq!(deliveries.start("1"), None);
+    assert_eq!(deliveries.start("1"), Some(DeliveryState::InFlight));
+
+    deliveries.complete("1");
+
+    assert_eq!(deliveries.start("1"), Some(DeliveryState::Completed));
+    assert_eq!(deliveries.start("2"), None);
+    assert_eq!(deliveries.start("3"), None);
+    // The oldest id was dropped
+    assert_eq!(deliveries.start("1"), None);
+
+    deliveries.remove("3");
+
+    assert_eq!(deliveries.start("3"), None);
+  }
+
+  #[tokio::test]
+  async fn test_signature() {
+    let server = WebhookServer::new(SECRET);
+    let body = br#"{"zen":"Keep it logically awesome."}"#;
+
+    let mut headers = headers("ping", "1", body);
+
+    assert_eq!(
+      server.handle(&headers, body).await.status(),
+      StatusCode::ACCEPTED
+    );
+
+    headers.insert(SIGNATURE_HEADER, sign("other", body).parse().unwrap());
+
+    assert_eq!(
+      server.handle(&headers, body).await.status(),
+      StatusCode::UNAUTHORIZED
+    );
+
+    headers.remove(SIGNATURE_HEADER);
+
+    assert_eq!(
+      server.handle(&headers, body).await.status(),
+      StatusCode::UNAUTHORIZED
+    );
+  }
+
+  #[cfg(any(feature = "full", feature = "webhook_ping"))]
+  #[tokio::test]
+  async fn test_route() {
+    use octocrate_types::WebhookPing;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let pings = calls.clone();
+
+    let server = WebhookServer::new(SECRET)
+      .on(move |event: WebhookPing| {
+        let pings = pings.clone();
+
+        async move {
+          assert_eq!(event.hook_id, Some(1));
+
+          pings.fetch_add(1, Ordering::SeqCst);
+
+          Ok(())
+        }
+      })
+      .deduplicate(10);
+
+    let body = br#"{"hook_id":1}"#;
+
+    let response = server.handle(&headers("ping", "1", body), body).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // Redelivered
+    let response = server.handle(&headers("ping", "1", body), body).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.body(), "Already delivered");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let body = br#"{"hook_id":"one"}"#;
+
+    let response = server.handle(&headers("ping", "2", body), body).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+  }
+
+  #[cfg(any(feature = "full", feature = "webhook_ping"))]
+  #[tokio::test]
+  async fn test_failed_handler() {
+    let server = WebhookServer::new(SECRET)
+      .on_any(|_| async { Err("Unavailable".into()) })
+      .deduplicate(10);
+
+    let body = br#"{"hook_id":1}"#;
+
+    for _ in 0..2 {
+      let response = server.handle(&headers("ping", "1", body), body).await;
+
+      // Not marked as delivered, so it's handled again
+      assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+      assert_eq!(response.body(), "Handler failed");
+    }
+  }
+
+  #[cfg(any(feature = "full", feature = "webhook_ping"))]
+  #[tokio::test]
+  async fn test_delivery_in_progress() {
+    use tokio::sync::Notify;
+
+    let started = Arc::new(Notify::new());
+    let release = Arc::new(Notify::new());
+
+    let server = {
+      let started = started.clone();
+      let release = release.clone();
+
+      WebhookServer::new(SECRET)
+        .on_any(move |_| {
+          let started = started.clone();
+          let release = release.clone();
+
+          async move {
+            started.notify_one();
+            release.notified().await;
+
+            Ok(())
+          }
+        })
+        .deduplicate(10)
+        .shared()
+    };
+
+    let body: &[u8] = br#"{"hook_id":1}"#;
+
+    let first = tokio::spawn({
+      let server = server.clone();
+
+      async move { server.handle(&headers("ping", "1", body), body).await }
+    });
+
+    started.notified().await;
+
+    // Redelivered while the first attempt is still running
+    let response = server.handle(&headers("ping", "1", body), body).await;
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    release.notify_one();
+
+    assert_eq!(first.await.unwrap().status(), StatusCode::OK);
+
+    let response = server.handle(&headers("ping", "1", body), body).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.body(), "Already delivered");
+  }
+}
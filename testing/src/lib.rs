@@ -0,0 +1,27 @@
+//! A local mock of the GitHub REST API, to test code that uses [`octocrate`] without
+//! any network access.
+//!
+//! ```rust,ignore
+//! let server = MockServer::start().await;
+//!
+//! server
+//!   .mock("pulls.create")
+//!   .expect_body::<PullsCreateRequest>()
+//!   .respond_with(201, &pull_request);
+//!
+//! let api = GitHubAPI::new(&server.config().shared());
+//!
+//! // Calls the local server instead of api.github.com
+//! my_code_that_opens_a_pull_request(&api).await;
+//!
+//! assert_eq!(server.received("pulls.create").len(), 1);
+//! server.verify();
+//! ```
+
+mod mock;
+mod received_request;
+mod server;
+
+pub use mock::*;
+pub use received_request::*;
+pub use server::*;
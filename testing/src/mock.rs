@@ -0,0 +1,178 @@
+use crate::received_request::ReceivedRequest;
+use octocrate::Operation;
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::{Arc, Mutex};
+
+type Validator = Box<dyn Fn(&ReceivedRequest) -> Result<(), String> + Send + Sync>;
+
+/// A canned response to the requests of one operation.
+pub(crate) struct Mock {
+  pub operation: &'static Operation,
+  pub status: u16,
+  pub headers: Vec<(String, String)>,
+  pub content_type: &'static str,
+  pub body: Option<Vec<u8>>,
+  pub validators: Vec<Validator>,
+}
+
+impl Mock {
+  /// With `greedy`, the last parameter of the path may also match several segments.
+  pub fn matches(&self, method: &str, path: &str, greedy: bool) -> bool {
+    self.operation.method.eq_ignore_ascii_case(method)
+      && path_matches(self.operation.path, path, greedy)
+  }
+
+  pub fn validate(&self, request: &ReceivedRequest) -> Result<(), String> {
+    self
+      .validators
+      .iter()
+      .try_for_each(|validator| validator(request))
+  }
+}
+
+/// Registers a [`Mock`] with the server once a response is given.
+pub struct MockBuilder {
+  mock: Mock,
+  mocks: Arc<Mutex<Vec<Mock>>>,
+}
+
+impl MockBuilder {
+  pub(crate) fn new(operation: &'static Operation, mocks: Arc<Mutex<Vec<Mock>>>) -> Self {
+    MockBuilder {
+      mock: Mock {
+        operation,
+        status: 200,
+        headers: Vec::new(),
+        content_type: "application/json",
+        body: None,
+        validators: Vec::new(),
+      },
+      mocks,
+    }
+  }
+
+  /// Reject requests whose JSON body doesn't deserialize into `T`, usually the
+  /// generated request type of the operation.
+  pub fn expect_body<T: DeserializeOwned>(mut self) -> Self {
+    self.mock.validators.push(Box::new(|request| {
+      request
+        .json::<T>()
+        .map(|_| ())
+        .map_err(|err| format!("Body doesn't match {}: {}", std::any::type_name::<T>(), err))
+    }));
+
+    self
+  }
+
+  /// Reject requests whose query string doesn't deserialize into `T`, usually the
+  /// generated query type of the operation.
+  pub fn expect_query<T: DeserializeOwned>(mut self) -> Self {
+    self.mock.validators.push(Box::new(|request| {
+      request.query::<T>().map(|_| ()).map_err(|err| {
+        format!(
+          "Query doesn't match {}: {}",
+          std::any::type_name::<T>(),
+          err
+        )
+      })
+    }));
+
+    self
+  }
+
+  pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.mock.headers.push((name.into(), value.into()));
+
+    self
+  }
+
+  /// Respond with the given status and JSON body.
+  pub fn respond_with<T: Serialize>(mut self, status: u16, body: &T) {
+    self.mock.status = status;
+    self.mock.body = Some(serde_json::to_vec(body).expect("Failed to serialize mock response"));
+
+    self.mount();
+  }
+
+  /// Respond with the given status and text body, e.g. the HTML of `markdown.render`.
+  pub fn respond_with_text(mut self, status: u16, body: impl Into<String>) {
+    self.mock.status = status;
+    self.mock.content_type = "text/plain; charset=utf-8";
+    self.mock.body = Some(body.into().into_bytes());
+
+    self.mount();
+  }
+
+  /// Respond with the given status and binary body, e.g. an archive.
+  pub fn respond_with_bytes(mut self, status: u16, body: impl Into<Vec<u8>>) {
+    self.mock.status = status;
+    self.mock.content_type = "application/octet-stream";
+    self.mock.body = Some(body.into());
+
+    self.mount();
+  }
+
+  /// Respond with the given status and no body, e.g. `204 No Content`.
+  pub fn respond_with_status(mut self, status: u16) {
+    self.mock.status = status;
+
+    self.mount();
+  }
+
+  fn mount(self) {
+    self.mocks.lock().unwrap().push(self.mock);
+  }
+}
+
+/// Match a path against a template like `/repos/{owner}/{repo}/contents/{path}`, with
+/// `greedy` the last parameter also matches the remaining segments.
+fn path_matches(template: &str, path: &str, greedy: bool) -> bool {
+  let template = template.trim_matches('/').split('/').collect::<Vec<_>>();
+  let path = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+  let ends_with_parameter = greedy
+    && template
+      .last()
+      .is_some_and(|segment| segment.starts_with('{'));
+
+  if path.len() != template.len() && !(ends_with_parameter && path.len() > template.len()) {
+    return false;
+  }
+
+  template
+    .iter()
+    .zip(path.iter())
+    .all(|(template, path)| template.starts_with('{') || template == path)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_path_matches() {
+    let template = "/repos/{owner}/{repo}/pulls/{pull_number}";
+
+    assert!(path_matches(
+      template,
+      "/repos/panghu-huang/octocrate/pulls/1",
+      false
+    ));
+    assert!(!path_matches(
+      template,
+      "/repos/panghu-huang/octocrate/issues/1",
+      false
+    ));
+    assert!(!path_matches(
+      template,
+      "/repos/panghu-huang/octocrate/pulls",
+      true
+    ));
+
+    let template = "/repos/{owner}/{repo}/contents/{path}";
+    let path = "/repos/panghu-huang/octocrate/contents/core/src/lib.rs";
+
+    assert!(path_matches(template, path, true));
+    assert!(!path_matches(template, path, false));
+  }
+}
@@ -0,0 +1,31 @@
+use serde::de::DeserializeOwned;
+
+/// A request received by the [`MockServer`](crate::MockServer).
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+  pub method: String,
+  pub path: String,
+  pub query: Option<String>,
+  pub headers: Vec<(String, String)>,
+  pub body: Vec<u8>,
+}
+
+impl ReceivedRequest {
+  pub fn header(&self, name: &str) -> Option<&str> {
+    self
+      .headers
+      .iter()
+      .find(|(header, _)| header.eq_ignore_ascii_case(name))
+      .map(|(_, value)| value.as_str())
+  }
+
+  /// Deserialize the JSON body, e.g. into the generated request type of the operation.
+  pub fn json<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+    serde_json::from_slice(&self.body)
+  }
+
+  /// Deserialize the query string, e.g. into the generated query type of the operation.
+  pub fn query<T: DeserializeOwned>(&self) -> Result<T, serde_urlencoded::de::Error> {
+    serde_urlencoded::from_str(self.query.as_deref().unwrap_or_default())
+  }
+}
@@ -0,0 +1,409 @@
+use crate::{
+  mock::{Mock, MockBuilder},
+  received_request::ReceivedRequest,
+};
+use octocrate::{find_operation, APIConfig, Operation};
+use std::{
+  net::SocketAddr,
+  sync::{Arc, Mutex},
+};
+use tokio::{
+  io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader},
+  net::{TcpListener, TcpStream},
+};
+
+#[derive(Default)]
+struct State {
+  received: Vec<(Option<&'static Operation>, ReceivedRequest)>,
+  failures: Vec<String>,
+}
+
+/// A local HTTP server answering the operations of the REST API with registered mocks.
+///
+/// The server runs until it is dropped.
+pub struct MockServer {
+  address: SocketAddr,
+  mocks: Arc<Mutex<Vec<Mock>>>,
+  state: Arc<Mutex<State>>,
+  handle: tokio::task::JoinHandle<()>,
+}
+
+impl MockServer {
+  pub async fn start() -> Self {
+    let listener = TcpListener::bind("127.0.0.1:0")
+      .await
+      .expect("Failed to bind mock server");
+    let address = listener.local_addr().expect("Failed to bind mock server");
+
+    let mocks = Arc::new(Mutex::new(Vec::new()));
+    let state = Arc::new(Mutex::new(State::default()));
+
+    let handle = tokio::spawn({
+      let mocks = mocks.clone();
+      let state = state.clone();
+
+      async move {
+        while let Ok((stream, _)) = listener.accept().await {
+          tokio::spawn(handle_connection(stream, mocks.clone(), state.clone()));
+        }
+      }
+    });
+
+    MockServer {
+      address,
+      mocks,
+      state,
+      handle,
+    }
+  }
+
+  pub fn base_url(&self) -> String {
+    format!("http://{}", self.address)
+  }
+
+  /// A config sending every request to this server.
+  pub fn config(&self) -> APIConfig {
+    APIConfig::with_base_url(&self.base_url())
+  }
+
+  /// Mock the operation with the given `module.function` id, e.g. `pulls.get`.
+  pub fn mock(&self, operation_id: &str) -> MockBuilder {
+    let operation =
+      find_operation(operation_id).unwrap_or_else(|| panic!("Unknown operation {}", operation_id));
+
+    MockBuilder::new(operation, self.mocks.clone())
+  }
+
+  /// The requests received for the given operation, in order.
+  pub fn received(&self, operation_id: &str) -> Vec<ReceivedRequest> {
+    self
+      .state
+      .lock()
+      .unwrap()
+      .received
+      .iter()
+      .filter(|(operation, _)| operation.is_some_and(|operation| operation.id == operation_id))
+      .map(|(_, request)| request.clone())
+      .collect()
+  }
+
+  /// Every request received, including those no mock matched.
+  pub fn received_requests(&self) -> Vec<ReceivedRequest> {
+    let state = self.state.lock().unwrap();
+
+    state
+      .received
+      .iter()
+      .map(|(_, request)| request.clone())
+      .collect()
+  }
+
+  /// Panic if a request matched no mock or failed the checks of its mock.
+  pub fn verify(&self) {
+    let state = self.state.lock().unwrap();
+
+    if !state.failures.is_empty() {
+      panic!("Mock server failures:\n{}", state.failures.join("\n"));
+    }
+  }
+}
+
+impl Drop for MockServer {
+  fn drop(&mut self) {
+    self.handle.abort();
+  }
+}
+
+async fn handle_connection(
+  stream: TcpStream,
+  mocks: Arc<Mutex<Vec<Mock>>>,
+  state: Arc<Mutex<State>>,
+) {
+  let mut stream = BufReader::new(stream);
+
+  let Some(request) = read_request(&mut stream).await else {
+    return;
+  };
+
+  let response = {
+    let mocks = mocks.lock().unwrap();
+    let mut state = state.lock().unwrap();
+
+    // Prefer the mocks whose path has as many segments as the request, so that
+    // `repos.get` doesn't answer the requests of `pulls.list`
+    let mock = [false, true].into_iter().find_map(|greedy| {
+      mocks
+        .iter()
+        .find(|mock| mock.matches(&request.method, &request.path, greedy))
+    });
+
+    state
+      .received
+      .push((mock.map(|mock| mock.operation), request.clone()));
+
+    match mock {
+      None => {
+        let message = format!("No mock for {} {}", request.method, request.path);
+
+        state.failures.push(message.clone());
+
+        (501, Vec::new(), JSON, Some(error_body(&message)))
+      }
+      Some(mock) => match mock.validate(&request) {
+        Ok(()) => (
+          mock.status,
+          mock.headers.clone(),
+          mock.content_type,
+          mock.body.clone(),
+        ),
+        Err(message) => {
+          let message = format!("{}: {}", mock.operation.id, message);
+
+          state.failures.push(message.clone());
+
+          (400, Vec::new(), JSON, Some(error_body(&message)))
+        }
+      },
+    }
+  };
+
+  let (status, headers, content_type, body) = response;
+  let body = body.unwrap_or_default();
+
+  let mut raw = format!("HTTP/1.1 {} {}\r\n", status, reason(status));
+
+  for (name, value) in headers {
+    raw.push_str(&format!("{}: {}\r\n", name, value));
+  }
+
+  if !body.is_empty() {
+    raw.push_str(&format!("content-type: {}\r\n", content_type));
+  }
+
+  raw.push_str(&format!(
+    "content-length: {}\r\nconnection: close\r\n\r\n",
+    body.len(),
+  ));
+
+  let mut raw = raw.into_bytes();
+
+  raw.extend_from_slice(&body);
+
+  let stream = stream.get_mut();
+
+  let _ = stream.write_all(&raw).await;
+  let _ = stream.shutdown().await;
+}
+
+async fn read_request(stream: &mut BufReader<TcpStream>) -> Option<ReceivedRequest> {
+  let mut line = String::new();
+
+  stream.read_line(&mut line).await.ok()?;
+
+  let mut parts = line.split_whitespace();
+  let method = parts.next()?.to_string();
+  let target = parts.next()?.to_string();
+
+  let mut headers = Vec::new();
+
+  loop {
+    let mut line = String::new();
+
+    stream.read_line(&mut line).await.ok()?;
+
+    let line = line.trim_end();
+
+    if line.is_empty() {
+      break;
+    }
+
+    if let Some((name, value)) = line.split_once(':') {
+      headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+    }
+  }
+
+  let content_length = headers
+    .iter()
+    .find(|(name, _)| name == "content-length")
+    .and_then(|(_, value)| value.parse().ok())
+    .unwrap_or(0);
+
+  let mut body = vec![0; content_length];
+
+  stream.read_exact(&mut body).await.ok()?;
+
+  let (path, query) = match target.split_once('?') {
+    Some((path, query)) => (path.to_string(), Some(query.to_string())),
+    None => (target, None),
+  };
+
+  Some(ReceivedRequest {
+    method,
+    path,
+    query,
+    headers,
+    body,
+  })
+}
+
+const JSON: &str = "application/json";
+
+fn error_body(message: &str) -> Vec<u8> {
+  serde_json::json!({ "message": message })
+    .to_string()
+    .into_bytes()
+}
+
+fn reason(status: u16) -> &'static str {
+  match status {
+    200 => "OK",
+    201 => "Created",
+    202 => "Accepted",
+    204 => "No Content",
+    400 => "Bad Request",
+    401 => "Unauthorized",
+    403 => "Forbidden",
+    404 => "Not Found",
+    409 => "Conflict",
+    422 => "Unprocessable Entity",
+    429 => "Too Many Requests",
+    500 => "Internal Server Error",
+    501 => "Not Implemented",
+    502 => "Bad Gateway",
+    503 => "Service Unavailable",
+    _ => "",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use octocrate::{GitHubAPI, MarkdownRenderRequest, MetaGetOctocatQuery};
+  use serde::Deserialize;
+
+  #[tokio::test]
+  async fn test_respond_with() {
+    let server = MockServer::start().await;
+
+    server
+      .mock("meta.get_all_versions")
+      .respond_with(200, &vec!["2022-11-28"]);
+
+    let api = GitHubAPI::new(&server.config().shared());
+
+    let versions = api.meta.get_all_versions().send().await.unwrap();
+
+    assert_eq!(versions, vec!["2022-11-28".to_string()]);
+    assert_eq!(server.received("meta.get_all_versions").len(), 1);
+
+    server.verify();
+  }
+
+  #[tokio::test]
+  async fn test_respond_with_text() {
+    let server = MockServer::start().await;
+
+    server
+      .mock("meta.get_zen")
+      .respond_with_text(200, "Design for failure.");
+
+    let api = GitHubAPI::new(&server.config().shared());
+
+    let zen = api.meta.get_zen().send().await.unwrap();
+
+    assert_eq!(zen, "Design for failure.");
+
+    server.verify();
+  }
+
+  #[tokio::test]
+  async fn test_expect_body_and_query() {
+    let server = MockServer::start().await;
+
+    server
+      .mock("markdown.render")
+      .expect_body::<MarkdownRenderRequest>()
+      .respond_with_status(200);
+    server
+      .mock("meta.get_octocat")
+      .expect_query::<MetaGetOctocatQuery>()
+      .respond_with_status(200);
+
+    let api = GitHubAPI::new(&server.config().shared());
+
+    let request = MarkdownRenderRequest::builder()
+      .text("**Hello**".to_string())
+      .build();
+
+    api.markdown.render().body(&request).send().await.unwrap();
+
+    let query = MetaGetOctocatQuery::builder()
+      .s("Hello".to_string())
+      .build();
+
+    api.meta.get_octocat().query(&query).send().await.unwrap();
+
+    let received = server.received("markdown.render");
+
+    assert_eq!(received[0].method, "POST");
+    assert_eq!(
+      received[0].json::<MarkdownRenderRequest>().unwrap().text,
+      "**Hello**"
+    );
+
+    let received = server.received("meta.get_octocat");
+
+    assert_eq!(
+      received[0].query::<MetaGetOctocatQuery>().unwrap().s,
+      Some("Hello".to_string())
+    );
+
+    server.verify();
+  }
+
+  #[tokio::test]
+  async fn test_failures() {
+    #[derive(Deserialize)]
+    #[allow(dead_code)]
+    struct ContextRequired {
+      text: String,
+      context: String,
+    }
+
+    let server = MockServer::start().await;
+
+    server
+      .mock("markdown.render")
+      .expect_body::<ContextRequired>()
+      .respond_with_status(200);
+
+    let api = GitHubAPI::new(&server.config().shared());
+
+    let request = MarkdownRenderRequest::builder()
+      .text("**Hello**".to_string())
+      .build();
+
+    let error = api
+      .markdown
+      .render()
+      .body(&request)
+      .send()
+      .await
+      .unwrap_err();
+
+    assert_eq!(error.status().unwrap().as_u16(), 400);
+
+    let error = api.meta.get_all_versions().send().await.unwrap_err();
+
+    assert_eq!(error.status().unwrap().as_u16(), 501);
+    assert_eq!(server.received_requests().len(), 2);
+
+    let failures = std::panic::catch_unwind(|| server.verify())
+      .unwrap_err()
+      .downcast::<String>()
+      .unwrap();
+
+    assert!(failures.contains("markdown.render: Body doesn't match"));
+    assert!(failures.contains("No mock for GET /versions"));
+  }
+}
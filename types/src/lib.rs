@@ -1,6 +1,7 @@
 mod models;
 #[cfg(feature = "pagination")]
 mod pagination;
+mod webhook_events;
 mod webhooks;
 
 #[allow(unused_imports)]
@@ -9,8 +10,40 @@ pub use models::*;
 pub use pagination::*;
 use serde::{Deserialize, Serialize};
 #[allow(unused_imports)]
+pub use webhook_events::*;
+#[allow(unused_imports)]
 pub use webhooks::*;
 
+/// A `date-time` of the API, e.g. `2011-01-26T19:01:12Z`, which is a `chrono::DateTime<Utc>`
+/// with the `chrono_timestamps` feature and an RFC 3339 string without it
+#[cfg(feature = "chrono_timestamps")]
+pub type DateTime = chrono::DateTime<chrono::Utc>;
+#[cfg(not(feature = "chrono_timestamps"))]
+pub type DateTime = String;
+
+/// A `date` of the API, e.g. `2024-04-01`, which is a `chrono::NaiveDate` with the
+/// `chrono_timestamps` feature and a string without it
+#[cfg(feature = "chrono_timestamps")]
+pub type Date = chrono::NaiveDate;
+#[cfg(not(feature = "chrono_timestamps"))]
+pub type Date = String;
+
+/// Reject a value that isn't a variant of the enumeration, which is otherwise kept in
+/// its `Unknown(String)` or `Other(String)` variant, with the `strict` feature
+#[cfg(feature = "strict")]
+#[doc(hidden)]
+pub fn reject_unknown_variant<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+  D: serde::Deserializer<'de>,
+{
+  let value = String::deserialize(deserializer)?;
+
+  Err(serde::de::Error::custom(format!(
+    "unknown variant `{}`",
+    value
+  )))
+}
+
 /// Predefined types
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(untagged)]
@@ -565,6 +565,13 @@ pub enum SecurityAdvisoriesListGlobalAdvisoriesQueryType {
   Malware,
   #[serde(rename = "unreviewed")]
   Unreviewed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -574,6 +581,7 @@ impl ToString for SecurityAdvisoriesListGlobalAdvisoriesQueryType {
       SecurityAdvisoriesListGlobalAdvisoriesQueryType::Reviewed => "reviewed".to_string(),
       SecurityAdvisoriesListGlobalAdvisoriesQueryType::Malware => "malware".to_string(),
       SecurityAdvisoriesListGlobalAdvisoriesQueryType::Unreviewed => "unreviewed".to_string(),
+      SecurityAdvisoriesListGlobalAdvisoriesQueryType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -591,6 +599,13 @@ pub enum SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity {
   High,
   #[serde(rename = "critical")]
   Critical,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -602,6 +617,7 @@ impl ToString for SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity {
       SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity::Medium => "medium".to_string(),
       SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity::High => "high".to_string(),
       SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity::Critical => "critical".to_string(),
+      SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity::Other(value) => value.clone(),
     }
   }
 }
@@ -613,6 +629,13 @@ pub enum SecurityAdvisoriesListGlobalAdvisoriesQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -621,6 +644,7 @@ impl ToString for SecurityAdvisoriesListGlobalAdvisoriesQueryDirection {
     match self {
       SecurityAdvisoriesListGlobalAdvisoriesQueryDirection::Asc => "asc".to_string(),
       SecurityAdvisoriesListGlobalAdvisoriesQueryDirection::Desc => "desc".to_string(),
+      SecurityAdvisoriesListGlobalAdvisoriesQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -632,6 +656,13 @@ pub enum SecurityAdvisoriesListGlobalAdvisoriesQuerySort {
   Updated,
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -640,11 +671,16 @@ impl ToString for SecurityAdvisoriesListGlobalAdvisoriesQuerySort {
     match self {
       SecurityAdvisoriesListGlobalAdvisoriesQuerySort::Updated => "updated".to_string(),
       SecurityAdvisoriesListGlobalAdvisoriesQuerySort::Published => "published".to_string(),
+      SecurityAdvisoriesListGlobalAdvisoriesQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// The type of credit the user is receiving.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecurityAdvisoryCreditTypes {
@@ -668,9 +704,20 @@ pub enum SecurityAdvisoryCreditTypes {
   Sponsor,
   #[serde(rename = "other")]
   Other,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl ToString for SecurityAdvisoryCreditTypes {
   fn to_string(&self) -> String {
     match self {
@@ -684,6 +731,7 @@ impl ToString for SecurityAdvisoryCreditTypes {
       SecurityAdvisoryCreditTypes::Tool => "tool".to_string(),
       SecurityAdvisoryCreditTypes::Sponsor => "sponsor".to_string(),
       SecurityAdvisoryCreditTypes::Other => "other".to_string(),
+      SecurityAdvisoryCreditTypes::Unknown(value) => value.clone(),
     }
   }
 }
@@ -696,6 +744,13 @@ pub enum GlobalAdvisoryIdentifiersType {
   Cve,
   #[serde(rename = "GHSA")]
   Ghsa,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -704,6 +759,7 @@ impl ToString for GlobalAdvisoryIdentifiersType {
     match self {
       GlobalAdvisoryIdentifiersType::Cve => "CVE".to_string(),
       GlobalAdvisoryIdentifiersType::Ghsa => "GHSA".to_string(),
+      GlobalAdvisoryIdentifiersType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -722,6 +778,13 @@ pub enum GlobalAdvisorySeverity {
   Low,
   #[serde(rename = "unknown")]
   Unknown,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -733,6 +796,7 @@ impl ToString for GlobalAdvisorySeverity {
       GlobalAdvisorySeverity::Medium => "medium".to_string(),
       GlobalAdvisorySeverity::Low => "low".to_string(),
       GlobalAdvisorySeverity::Unknown => "unknown".to_string(),
+      GlobalAdvisorySeverity::Other(value) => value.clone(),
     }
   }
 }
@@ -747,6 +811,13 @@ pub enum GlobalAdvisoryType {
   Unreviewed,
   #[serde(rename = "malware")]
   Malware,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -756,11 +827,16 @@ impl ToString for GlobalAdvisoryType {
       GlobalAdvisoryType::Reviewed => "reviewed".to_string(),
       GlobalAdvisoryType::Unreviewed => "unreviewed".to_string(),
       GlobalAdvisoryType::Malware => "malware".to_string(),
+      GlobalAdvisoryType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// The package's language or package management ecosystem.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecurityAdvisoryEcosystems {
@@ -790,9 +866,20 @@ pub enum SecurityAdvisoryEcosystems {
   Other,
   #[serde(rename = "swift")]
   Swift,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl ToString for SecurityAdvisoryEcosystems {
   fn to_string(&self) -> String {
     match self {
@@ -809,6 +896,7 @@ impl ToString for SecurityAdvisoryEcosystems {
       SecurityAdvisoryEcosystems::Pub => "pub".to_string(),
       SecurityAdvisoryEcosystems::Other => "other".to_string(),
       SecurityAdvisoryEcosystems::Swift => "swift".to_string(),
+      SecurityAdvisoryEcosystems::Unknown(value) => value.clone(),
     }
   }
 }
@@ -836,7 +924,8 @@ pub enum IntegrationInstallationRequestAccount {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -851,7 +940,10 @@ pub enum InstallationAccount {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 /// The level of permission to grant the access token for custom property management.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -862,13 +954,23 @@ pub enum AppPermissionsOrganizationCustomProperties {
   Write,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 impl ToString for AppPermissionsOrganizationCustomProperties {
   fn to_string(&self) -> String {
@@ -876,6 +978,7 @@ impl ToString for AppPermissionsOrganizationCustomProperties {
       AppPermissionsOrganizationCustomProperties::Read => "read".to_string(),
       AppPermissionsOrganizationCustomProperties::Write => "write".to_string(),
       AppPermissionsOrganizationCustomProperties::Admin => "admin".to_string(),
+      AppPermissionsOrganizationCustomProperties::Unknown(value) => value.clone(),
     }
   }
 }
@@ -884,7 +987,10 @@ impl ToString for AppPermissionsOrganizationCustomProperties {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 /// The level of permission to grant the access token to manage organization projects and projects beta (where available).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -895,13 +1001,23 @@ pub enum AppPermissionsOrganizationProjects {
   Write,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 impl ToString for AppPermissionsOrganizationProjects {
   fn to_string(&self) -> String {
@@ -909,6 +1025,7 @@ impl ToString for AppPermissionsOrganizationProjects {
       AppPermissionsOrganizationProjects::Read => "read".to_string(),
       AppPermissionsOrganizationProjects::Write => "write".to_string(),
       AppPermissionsOrganizationProjects::Admin => "admin".to_string(),
+      AppPermissionsOrganizationProjects::Unknown(value) => value.clone(),
     }
   }
 }
@@ -917,7 +1034,10 @@ impl ToString for AppPermissionsOrganizationProjects {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 /// The level of permission to grant the access token to manage repository projects, columns, and cards.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -928,13 +1048,23 @@ pub enum AppPermissionsRepositoryProjects {
   Write,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 impl ToString for AppPermissionsRepositoryProjects {
   fn to_string(&self) -> String {
@@ -942,6 +1072,7 @@ impl ToString for AppPermissionsRepositoryProjects {
       AppPermissionsRepositoryProjects::Read => "read".to_string(),
       AppPermissionsRepositoryProjects::Write => "write".to_string(),
       AppPermissionsRepositoryProjects::Admin => "admin".to_string(),
+      AppPermissionsRepositoryProjects::Unknown(value) => value.clone(),
     }
   }
 }
@@ -950,7 +1081,8 @@ impl ToString for AppPermissionsRepositoryProjects {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 /// Describe whether all repositories have been selected or there's a selection involved
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -959,19 +1091,28 @@ pub enum InstallationRepositorySelection {
   All,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 impl ToString for InstallationRepositorySelection {
   fn to_string(&self) -> String {
     match self {
       InstallationRepositorySelection::All => "all".to_string(),
       InstallationRepositorySelection::Selected => "selected".to_string(),
+      InstallationRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -987,7 +1128,72 @@ impl ToString for InstallationRepositorySelection {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// The default value for a merge commit message.
 ///
@@ -1002,6 +1208,13 @@ pub enum RepositoryMergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -1015,7 +1228,72 @@ pub enum RepositoryMergeCommitMessage {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for RepositoryMergeCommitMessage {
   fn to_string(&self) -> String {
@@ -1023,6 +1301,7 @@ impl ToString for RepositoryMergeCommitMessage {
       RepositoryMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       RepositoryMergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       RepositoryMergeCommitMessage::Blank => "BLANK".to_string(),
+      RepositoryMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1038,7 +1317,72 @@ impl ToString for RepositoryMergeCommitMessage {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// The default value for a merge commit title.
 ///
@@ -1050,6 +1394,13 @@ pub enum RepositoryMergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -1063,13 +1414,79 @@ pub enum RepositoryMergeCommitTitle {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for RepositoryMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
       RepositoryMergeCommitTitle::PrTitle => "PR_TITLE".to_string(),
       RepositoryMergeCommitTitle::MergeMessage => "MERGE_MESSAGE".to_string(),
+      RepositoryMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1085,7 +1502,72 @@ impl ToString for RepositoryMergeCommitTitle {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// The default value for a squash merge commit message:
 ///
@@ -1100,6 +1582,13 @@ pub enum RepositorySquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -1113,7 +1602,72 @@ pub enum RepositorySquashMergeCommitMessage {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for RepositorySquashMergeCommitMessage {
   fn to_string(&self) -> String {
@@ -1121,6 +1675,7 @@ impl ToString for RepositorySquashMergeCommitMessage {
       RepositorySquashMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       RepositorySquashMergeCommitMessage::CommitMessages => "COMMIT_MESSAGES".to_string(),
       RepositorySquashMergeCommitMessage::Blank => "BLANK".to_string(),
+      RepositorySquashMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1136,7 +1691,72 @@ impl ToString for RepositorySquashMergeCommitMessage {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// The default value for a squash merge commit title:
 ///
@@ -1148,6 +1768,13 @@ pub enum RepositorySquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -1161,13 +1788,79 @@ pub enum RepositorySquashMergeCommitTitle {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for RepositorySquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
       RepositorySquashMergeCommitTitle::PrTitle => "PR_TITLE".to_string(),
       RepositorySquashMergeCommitTitle::CommitOrPrTitle => "COMMIT_OR_PR_TITLE".to_string(),
+      RepositorySquashMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1179,6 +1872,13 @@ pub enum InstallationTokenRepositorySelection {
   All,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "apps",))]
@@ -1187,6 +1887,7 @@ impl ToString for InstallationTokenRepositorySelection {
     match self {
       InstallationTokenRepositorySelection::All => "all".to_string(),
       InstallationTokenRepositorySelection::Selected => "selected".to_string(),
+      InstallationTokenRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1199,6 +1900,13 @@ pub enum ScopedInstallationRepositorySelection {
   All,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "apps",))]
@@ -1207,6 +1915,7 @@ impl ToString for ScopedInstallationRepositorySelection {
     match self {
       ScopedInstallationRepositorySelection::All => "all".to_string(),
       ScopedInstallationRepositorySelection::Selected => "selected".to_string(),
+      ScopedInstallationRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1219,6 +1928,13 @@ pub enum ClassroomAssignmentType {
   Individual,
   #[serde(rename = "group")]
   Group,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "classroom",))]
@@ -1227,6 +1943,7 @@ impl ToString for ClassroomAssignmentType {
     match self {
       ClassroomAssignmentType::Individual => "individual".to_string(),
       ClassroomAssignmentType::Group => "group".to_string(),
+      ClassroomAssignmentType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1239,6 +1956,13 @@ pub enum SimpleClassroomAssignmentType {
   Individual,
   #[serde(rename = "group")]
   Group,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "classroom",))]
@@ -1247,6 +1971,7 @@ impl ToString for SimpleClassroomAssignmentType {
     match self {
       SimpleClassroomAssignmentType::Individual => "individual".to_string(),
       SimpleClassroomAssignmentType::Group => "group".to_string(),
+      SimpleClassroomAssignmentType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1258,6 +1983,13 @@ pub enum DependabotListAlertsForEnterpriseQueryScope {
   Development,
   #[serde(rename = "runtime")]
   Runtime,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -1266,6 +1998,7 @@ impl ToString for DependabotListAlertsForEnterpriseQueryScope {
     match self {
       DependabotListAlertsForEnterpriseQueryScope::Development => "development".to_string(),
       DependabotListAlertsForEnterpriseQueryScope::Runtime => "runtime".to_string(),
+      DependabotListAlertsForEnterpriseQueryScope::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1277,6 +2010,13 @@ pub enum DependabotListAlertsForEnterpriseQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -1285,6 +2025,7 @@ impl ToString for DependabotListAlertsForEnterpriseQuerySort {
     match self {
       DependabotListAlertsForEnterpriseQuerySort::Created => "created".to_string(),
       DependabotListAlertsForEnterpriseQuerySort::Updated => "updated".to_string(),
+      DependabotListAlertsForEnterpriseQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1296,6 +2037,13 @@ pub enum DependabotListAlertsForEnterpriseQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -1304,6 +2052,7 @@ impl ToString for DependabotListAlertsForEnterpriseQueryDirection {
     match self {
       DependabotListAlertsForEnterpriseQueryDirection::Asc => "asc".to_string(),
       DependabotListAlertsForEnterpriseQueryDirection::Desc => "desc".to_string(),
+      DependabotListAlertsForEnterpriseQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1316,6 +2065,13 @@ pub enum DependabotAlertWithRepositoryDependencyScope {
   Development,
   #[serde(rename = "runtime")]
   Runtime,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -1324,6 +2080,7 @@ impl ToString for DependabotAlertWithRepositoryDependencyScope {
     match self {
       DependabotAlertWithRepositoryDependencyScope::Development => "development".to_string(),
       DependabotAlertWithRepositoryDependencyScope::Runtime => "runtime".to_string(),
+      DependabotAlertWithRepositoryDependencyScope::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1342,6 +2099,13 @@ pub enum DependabotAlertWithRepositoryDismissedReason {
   NotUsed,
   #[serde(rename = "tolerable_risk")]
   TolerableRisk,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -1353,11 +2117,16 @@ impl ToString for DependabotAlertWithRepositoryDismissedReason {
       DependabotAlertWithRepositoryDismissedReason::NoBandwidth => "no_bandwidth".to_string(),
       DependabotAlertWithRepositoryDismissedReason::NotUsed => "not_used".to_string(),
       DependabotAlertWithRepositoryDismissedReason::TolerableRisk => "tolerable_risk".to_string(),
+      DependabotAlertWithRepositoryDismissedReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// The type of advisory identifier.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertSecurityAdvisoryIdentifiersType {
@@ -1365,19 +2134,35 @@ pub enum DependabotAlertSecurityAdvisoryIdentifiersType {
   Cve,
   #[serde(rename = "GHSA")]
   Ghsa,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl ToString for DependabotAlertSecurityAdvisoryIdentifiersType {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertSecurityAdvisoryIdentifiersType::Cve => "CVE".to_string(),
       DependabotAlertSecurityAdvisoryIdentifiersType::Ghsa => "GHSA".to_string(),
+      DependabotAlertSecurityAdvisoryIdentifiersType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// The severity of the advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertSecurityAdvisorySeverity {
@@ -1389,9 +2174,20 @@ pub enum DependabotAlertSecurityAdvisorySeverity {
   High,
   #[serde(rename = "critical")]
   Critical,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl ToString for DependabotAlertSecurityAdvisorySeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1399,11 +2195,16 @@ impl ToString for DependabotAlertSecurityAdvisorySeverity {
       DependabotAlertSecurityAdvisorySeverity::Medium => "medium".to_string(),
       DependabotAlertSecurityAdvisorySeverity::High => "high".to_string(),
       DependabotAlertSecurityAdvisorySeverity::Critical => "critical".to_string(),
+      DependabotAlertSecurityAdvisorySeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// The severity of the vulnerability.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertSecurityVulnerabilitySeverity {
@@ -1415,9 +2216,20 @@ pub enum DependabotAlertSecurityVulnerabilitySeverity {
   High,
   #[serde(rename = "critical")]
   Critical,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl ToString for DependabotAlertSecurityVulnerabilitySeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1425,6 +2237,7 @@ impl ToString for DependabotAlertSecurityVulnerabilitySeverity {
       DependabotAlertSecurityVulnerabilitySeverity::Medium => "medium".to_string(),
       DependabotAlertSecurityVulnerabilitySeverity::High => "high".to_string(),
       DependabotAlertSecurityVulnerabilitySeverity::Critical => "critical".to_string(),
+      DependabotAlertSecurityVulnerabilitySeverity::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1441,6 +2254,13 @@ pub enum DependabotAlertWithRepositoryState {
   Fixed,
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -1451,6 +2271,7 @@ impl ToString for DependabotAlertWithRepositoryState {
       DependabotAlertWithRepositoryState::Dismissed => "dismissed".to_string(),
       DependabotAlertWithRepositoryState::Fixed => "fixed".to_string(),
       DependabotAlertWithRepositoryState::Open => "open".to_string(),
+      DependabotAlertWithRepositoryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1462,6 +2283,13 @@ pub enum SecretScanningListAlertsForEnterpriseQueryState {
   Open,
   #[serde(rename = "resolved")]
   Resolved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -1470,6 +2298,7 @@ impl ToString for SecretScanningListAlertsForEnterpriseQueryState {
     match self {
       SecretScanningListAlertsForEnterpriseQueryState::Open => "open".to_string(),
       SecretScanningListAlertsForEnterpriseQueryState::Resolved => "resolved".to_string(),
+      SecretScanningListAlertsForEnterpriseQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1481,6 +2310,13 @@ pub enum SecretScanningListAlertsForEnterpriseQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -1489,6 +2325,7 @@ impl ToString for SecretScanningListAlertsForEnterpriseQuerySort {
     match self {
       SecretScanningListAlertsForEnterpriseQuerySort::Created => "created".to_string(),
       SecretScanningListAlertsForEnterpriseQuerySort::Updated => "updated".to_string(),
+      SecretScanningListAlertsForEnterpriseQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1500,6 +2337,13 @@ pub enum SecretScanningListAlertsForEnterpriseQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -1508,6 +2352,7 @@ impl ToString for SecretScanningListAlertsForEnterpriseQueryDirection {
     match self {
       SecretScanningListAlertsForEnterpriseQueryDirection::Asc => "asc".to_string(),
       SecretScanningListAlertsForEnterpriseQueryDirection::Desc => "desc".to_string(),
+      SecretScanningListAlertsForEnterpriseQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1524,6 +2369,13 @@ pub enum SecretScanningAlertResolution {
   Revoked,
   #[serde(rename = "used_in_tests")]
   UsedInTests,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -1534,6 +2386,7 @@ impl ToString for SecretScanningAlertResolution {
       SecretScanningAlertResolution::WontFix => "wont_fix".to_string(),
       SecretScanningAlertResolution::Revoked => "revoked".to_string(),
       SecretScanningAlertResolution::UsedInTests => "used_in_tests".to_string(),
+      SecretScanningAlertResolution::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1546,6 +2399,13 @@ pub enum SecretScanningAlertState {
   Open,
   #[serde(rename = "resolved")]
   Resolved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -1554,6 +2414,7 @@ impl ToString for SecretScanningAlertState {
     match self {
       SecretScanningAlertState::Open => "open".to_string(),
       SecretScanningAlertState::Resolved => "resolved".to_string(),
+      SecretScanningAlertState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1568,6 +2429,13 @@ pub enum OrganizationSecretScanningAlertValidity {
   Inactive,
   #[serde(rename = "unknown")]
   Unknown,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -1577,6 +2445,7 @@ impl ToString for OrganizationSecretScanningAlertValidity {
       OrganizationSecretScanningAlertValidity::Active => "active".to_string(),
       OrganizationSecretScanningAlertValidity::Inactive => "inactive".to_string(),
       OrganizationSecretScanningAlertValidity::Unknown => "unknown".to_string(),
+      OrganizationSecretScanningAlertValidity::Other(value) => value.clone(),
     }
   }
 }
@@ -1589,7 +2458,12 @@ impl ToString for OrganizationSecretScanningAlertValidity {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1610,6 +2484,13 @@ pub enum AuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -1620,7 +2501,12 @@ pub enum AuthorAssociation {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl ToString for AuthorAssociation {
   fn to_string(&self) -> String {
@@ -1633,6 +2519,7 @@ impl ToString for AuthorAssociation {
       AuthorAssociation::Member => "MEMBER".to_string(),
       AuthorAssociation::None => "NONE".to_string(),
       AuthorAssociation::Owner => "OWNER".to_string(),
+      AuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1644,7 +2531,14 @@ impl ToString for AuthorAssociation {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_milestone",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// The state of the milestone.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1653,6 +2547,13 @@ pub enum MilestoneState {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -1662,13 +2563,21 @@ pub enum MilestoneState {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_milestone",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl ToString for MilestoneState {
   fn to_string(&self) -> String {
     match self {
       MilestoneState::Open => "open".to_string(),
       MilestoneState::Closed => "closed".to_string(),
+      MilestoneState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1677,7 +2586,8 @@ impl ToString for MilestoneState {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 /// The reason for the current state
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1688,13 +2598,21 @@ pub enum IssueStateReason {
   Reopened,
   #[serde(rename = "not_planned")]
   NotPlanned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 impl ToString for IssueStateReason {
   fn to_string(&self) -> String {
@@ -1702,6 +2620,7 @@ impl ToString for IssueStateReason {
       IssueStateReason::Completed => "completed".to_string(),
       IssueStateReason::Reopened => "reopened".to_string(),
       IssueStateReason::NotPlanned => "not_planned".to_string(),
+      IssueStateReason::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1713,6 +2632,13 @@ pub enum GistsCreateRequestPublicItem2 {
   True,
   #[serde(rename = "false")]
   False,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "gists",))]
@@ -1721,6 +2647,7 @@ impl ToString for GistsCreateRequestPublicItem2 {
     match self {
       GistsCreateRequestPublicItem2::True => "true".to_string(),
       GistsCreateRequestPublicItem2::False => "false".to_string(),
+      GistsCreateRequestPublicItem2::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1740,6 +2667,13 @@ pub enum IssuesListQueryFilter {
   Repos,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -1752,6 +2686,7 @@ impl ToString for IssuesListQueryFilter {
       IssuesListQueryFilter::Subscribed => "subscribed".to_string(),
       IssuesListQueryFilter::Repos => "repos".to_string(),
       IssuesListQueryFilter::All => "all".to_string(),
+      IssuesListQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1765,6 +2700,13 @@ pub enum IssuesListQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -1774,6 +2716,7 @@ impl ToString for IssuesListQueryState {
       IssuesListQueryState::Open => "open".to_string(),
       IssuesListQueryState::Closed => "closed".to_string(),
       IssuesListQueryState::All => "all".to_string(),
+      IssuesListQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1787,6 +2730,13 @@ pub enum IssuesListQuerySort {
   Updated,
   #[serde(rename = "comments")]
   Comments,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -1796,6 +2746,7 @@ impl ToString for IssuesListQuerySort {
       IssuesListQuerySort::Created => "created".to_string(),
       IssuesListQuerySort::Updated => "updated".to_string(),
       IssuesListQuerySort::Comments => "comments".to_string(),
+      IssuesListQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1807,6 +2758,13 @@ pub enum IssuesListQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -1815,6 +2773,7 @@ impl ToString for IssuesListQueryDirection {
     match self {
       IssuesListQueryDirection::Asc => "asc".to_string(),
       IssuesListQueryDirection::Desc => "desc".to_string(),
+      IssuesListQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1827,6 +2786,13 @@ pub enum MarkdownRenderRequestMode {
   Markdown,
   #[serde(rename = "gfm")]
   Gfm,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "markdown",))]
@@ -1835,11 +2801,16 @@ impl ToString for MarkdownRenderRequestMode {
     match self {
       MarkdownRenderRequestMode::Markdown => "markdown".to_string(),
       MarkdownRenderRequestMode::Gfm => "gfm".to_string(),
+      MarkdownRenderRequestMode::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MarketplaceListingPlanPriceModel {
   #[serde(rename = "FREE")]
@@ -1848,15 +2819,27 @@ pub enum MarketplaceListingPlanPriceModel {
   FlatRate,
   #[serde(rename = "PER_UNIT")]
   PerUnit,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 impl ToString for MarketplaceListingPlanPriceModel {
   fn to_string(&self) -> String {
     match self {
       MarketplaceListingPlanPriceModel::Free => "FREE".to_string(),
       MarketplaceListingPlanPriceModel::FlatRate => "FLAT_RATE".to_string(),
       MarketplaceListingPlanPriceModel::PerUnit => "PER_UNIT".to_string(),
+      MarketplaceListingPlanPriceModel::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1868,6 +2851,13 @@ pub enum AppsListAccountsForPlanQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "apps",))]
@@ -1876,6 +2866,7 @@ impl ToString for AppsListAccountsForPlanQuerySort {
     match self {
       AppsListAccountsForPlanQuerySort::Created => "created".to_string(),
       AppsListAccountsForPlanQuerySort::Updated => "updated".to_string(),
+      AppsListAccountsForPlanQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1887,6 +2878,13 @@ pub enum AppsListAccountsForPlanQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "apps",))]
@@ -1895,6 +2893,7 @@ impl ToString for AppsListAccountsForPlanQueryDirection {
     match self {
       AppsListAccountsForPlanQueryDirection::Asc => "asc".to_string(),
       AppsListAccountsForPlanQueryDirection::Desc => "desc".to_string(),
+      AppsListAccountsForPlanQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1906,6 +2905,13 @@ pub enum AppsListAccountsForPlanStubbedQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "apps",))]
@@ -1914,6 +2920,7 @@ impl ToString for AppsListAccountsForPlanStubbedQuerySort {
     match self {
       AppsListAccountsForPlanStubbedQuerySort::Created => "created".to_string(),
       AppsListAccountsForPlanStubbedQuerySort::Updated => "updated".to_string(),
+      AppsListAccountsForPlanStubbedQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1925,6 +2932,13 @@ pub enum AppsListAccountsForPlanStubbedQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "apps",))]
@@ -1933,6 +2947,7 @@ impl ToString for AppsListAccountsForPlanStubbedQueryDirection {
     match self {
       AppsListAccountsForPlanStubbedQueryDirection::Asc => "asc".to_string(),
       AppsListAccountsForPlanStubbedQueryDirection::Desc => "desc".to_string(),
+      AppsListAccountsForPlanStubbedQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -1951,7 +2966,8 @@ impl ToString for AppsListAccountsForPlanStubbedQueryDirection {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecurityAndAnalysisAdvancedSecurityStatus {
@@ -1959,6 +2975,13 @@ pub enum SecurityAndAnalysisAdvancedSecurityStatus {
   Enabled,
   #[serde(rename = "disabled")]
   Disabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -1975,13 +2998,15 @@ pub enum SecurityAndAnalysisAdvancedSecurityStatus {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for SecurityAndAnalysisAdvancedSecurityStatus {
   fn to_string(&self) -> String {
     match self {
       SecurityAndAnalysisAdvancedSecurityStatus::Enabled => "enabled".to_string(),
       SecurityAndAnalysisAdvancedSecurityStatus::Disabled => "disabled".to_string(),
+      SecurityAndAnalysisAdvancedSecurityStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2000,7 +3025,8 @@ impl ToString for SecurityAndAnalysisAdvancedSecurityStatus {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 /// The enablement status of Dependabot security updates for the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -2009,6 +3035,13 @@ pub enum SecurityAndAnalysisDependabotSecurityUpdatesStatus {
   Enabled,
   #[serde(rename = "disabled")]
   Disabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -2025,13 +3058,15 @@ pub enum SecurityAndAnalysisDependabotSecurityUpdatesStatus {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for SecurityAndAnalysisDependabotSecurityUpdatesStatus {
   fn to_string(&self) -> String {
     match self {
       SecurityAndAnalysisDependabotSecurityUpdatesStatus::Enabled => "enabled".to_string(),
       SecurityAndAnalysisDependabotSecurityUpdatesStatus::Disabled => "disabled".to_string(),
+      SecurityAndAnalysisDependabotSecurityUpdatesStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2050,7 +3085,8 @@ impl ToString for SecurityAndAnalysisDependabotSecurityUpdatesStatus {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecurityAndAnalysisSecretScanningStatus {
@@ -2058,6 +3094,13 @@ pub enum SecurityAndAnalysisSecretScanningStatus {
   Enabled,
   #[serde(rename = "disabled")]
   Disabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -2074,13 +3117,15 @@ pub enum SecurityAndAnalysisSecretScanningStatus {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for SecurityAndAnalysisSecretScanningStatus {
   fn to_string(&self) -> String {
     match self {
       SecurityAndAnalysisSecretScanningStatus::Enabled => "enabled".to_string(),
       SecurityAndAnalysisSecretScanningStatus::Disabled => "disabled".to_string(),
+      SecurityAndAnalysisSecretScanningStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2099,7 +3144,8 @@ impl ToString for SecurityAndAnalysisSecretScanningStatus {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecurityAndAnalysisSecretScanningPushProtectionStatus {
@@ -2107,6 +3153,13 @@ pub enum SecurityAndAnalysisSecretScanningPushProtectionStatus {
   Enabled,
   #[serde(rename = "disabled")]
   Disabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
@@ -2123,13 +3176,15 @@ pub enum SecurityAndAnalysisSecretScanningPushProtectionStatus {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl ToString for SecurityAndAnalysisSecretScanningPushProtectionStatus {
   fn to_string(&self) -> String {
     match self {
       SecurityAndAnalysisSecretScanningPushProtectionStatus::Enabled => "enabled".to_string(),
       SecurityAndAnalysisSecretScanningPushProtectionStatus::Disabled => "disabled".to_string(),
+      SecurityAndAnalysisSecretScanningPushProtectionStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2146,6 +3201,13 @@ pub enum OrgsUpdateRequestDefaultRepositoryPermission {
   Admin,
   #[serde(rename = "none")]
   None,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -2156,6 +3218,7 @@ impl ToString for OrgsUpdateRequestDefaultRepositoryPermission {
       OrgsUpdateRequestDefaultRepositoryPermission::Write => "write".to_string(),
       OrgsUpdateRequestDefaultRepositoryPermission::Admin => "admin".to_string(),
       OrgsUpdateRequestDefaultRepositoryPermission::None => "none".to_string(),
+      OrgsUpdateRequestDefaultRepositoryPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2171,6 +3234,13 @@ pub enum OrgsUpdateRequestMembersAllowedRepositoryCreationType {
   Private,
   #[serde(rename = "none")]
   None,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -2180,6 +3250,7 @@ impl ToString for OrgsUpdateRequestMembersAllowedRepositoryCreationType {
       OrgsUpdateRequestMembersAllowedRepositoryCreationType::All => "all".to_string(),
       OrgsUpdateRequestMembersAllowedRepositoryCreationType::Private => "private".to_string(),
       OrgsUpdateRequestMembersAllowedRepositoryCreationType::None => "none".to_string(),
+      OrgsUpdateRequestMembersAllowedRepositoryCreationType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2194,6 +3265,13 @@ pub enum AllowedActions {
   LocalOnly,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2203,6 +3281,7 @@ impl ToString for AllowedActions {
       AllowedActions::All => "all".to_string(),
       AllowedActions::LocalOnly => "local_only".to_string(),
       AllowedActions::Selected => "selected".to_string(),
+      AllowedActions::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2217,6 +3296,13 @@ pub enum EnabledRepositories {
   None,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2226,6 +3312,7 @@ impl ToString for EnabledRepositories {
       EnabledRepositories::All => "all".to_string(),
       EnabledRepositories::None => "none".to_string(),
       EnabledRepositories::Selected => "selected".to_string(),
+      EnabledRepositories::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2238,6 +3325,13 @@ pub enum SelfHostedRunnerLabelType {
   ReadOnly,
   #[serde(rename = "custom")]
   Custom,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2246,6 +3340,7 @@ impl ToString for SelfHostedRunnerLabelType {
     match self {
       SelfHostedRunnerLabelType::ReadOnly => "read-only".to_string(),
       SelfHostedRunnerLabelType::Custom => "custom".to_string(),
+      SelfHostedRunnerLabelType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2258,6 +3353,13 @@ pub enum AuthenticationTokenRepositorySelection {
   All,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2266,6 +3368,7 @@ impl ToString for AuthenticationTokenRepositorySelection {
     match self {
       AuthenticationTokenRepositorySelection::All => "all".to_string(),
       AuthenticationTokenRepositorySelection::Selected => "selected".to_string(),
+      AuthenticationTokenRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2280,6 +3383,13 @@ pub enum ActionsSecretForAnOrganizationVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2289,6 +3399,7 @@ impl ToString for ActionsSecretForAnOrganizationVisibility {
       ActionsSecretForAnOrganizationVisibility::All => "all".to_string(),
       ActionsSecretForAnOrganizationVisibility::Private => "private".to_string(),
       ActionsSecretForAnOrganizationVisibility::Selected => "selected".to_string(),
+      ActionsSecretForAnOrganizationVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2303,6 +3414,13 @@ pub enum ActionsCreateOrUpdateOrgSecretRequestVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2312,6 +3430,7 @@ impl ToString for ActionsCreateOrUpdateOrgSecretRequestVisibility {
       ActionsCreateOrUpdateOrgSecretRequestVisibility::All => "all".to_string(),
       ActionsCreateOrUpdateOrgSecretRequestVisibility::Private => "private".to_string(),
       ActionsCreateOrUpdateOrgSecretRequestVisibility::Selected => "selected".to_string(),
+      ActionsCreateOrUpdateOrgSecretRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2326,6 +3445,13 @@ pub enum ActionsVariableForAnOrganizationVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2335,6 +3461,7 @@ impl ToString for ActionsVariableForAnOrganizationVisibility {
       ActionsVariableForAnOrganizationVisibility::All => "all".to_string(),
       ActionsVariableForAnOrganizationVisibility::Private => "private".to_string(),
       ActionsVariableForAnOrganizationVisibility::Selected => "selected".to_string(),
+      ActionsVariableForAnOrganizationVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2349,6 +3476,13 @@ pub enum ActionsCreateOrgVariableRequestVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2358,6 +3492,7 @@ impl ToString for ActionsCreateOrgVariableRequestVisibility {
       ActionsCreateOrgVariableRequestVisibility::All => "all".to_string(),
       ActionsCreateOrgVariableRequestVisibility::Private => "private".to_string(),
       ActionsCreateOrgVariableRequestVisibility::Selected => "selected".to_string(),
+      ActionsCreateOrgVariableRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2372,6 +3507,13 @@ pub enum ActionsUpdateOrgVariableRequestVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -2381,6 +3523,7 @@ impl ToString for ActionsUpdateOrgVariableRequestVisibility {
       ActionsUpdateOrgVariableRequestVisibility::All => "all".to_string(),
       ActionsUpdateOrgVariableRequestVisibility::Private => "private".to_string(),
       ActionsUpdateOrgVariableRequestVisibility::Selected => "selected".to_string(),
+      ActionsUpdateOrgVariableRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2392,6 +3535,13 @@ pub enum CodeScanningListAlertsForOrgQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -2400,6 +3550,7 @@ impl ToString for CodeScanningListAlertsForOrgQueryDirection {
     match self {
       CodeScanningListAlertsForOrgQueryDirection::Asc => "asc".to_string(),
       CodeScanningListAlertsForOrgQueryDirection::Desc => "desc".to_string(),
+      CodeScanningListAlertsForOrgQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2411,6 +3562,13 @@ pub enum CodeScanningListAlertsForOrgQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -2419,6 +3577,7 @@ impl ToString for CodeScanningListAlertsForOrgQuerySort {
     match self {
       CodeScanningListAlertsForOrgQuerySort::Created => "created".to_string(),
       CodeScanningListAlertsForOrgQuerySort::Updated => "updated".to_string(),
+      CodeScanningListAlertsForOrgQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2433,6 +3592,13 @@ pub enum CodeScanningAlertDismissedReason {
   WonTFix,
   #[serde(rename = "used in tests")]
   UsedInTests,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -2442,6 +3608,7 @@ impl ToString for CodeScanningAlertDismissedReason {
       CodeScanningAlertDismissedReason::FalsePositive => "false positive".to_string(),
       CodeScanningAlertDismissedReason::WonTFix => "won't fix".to_string(),
       CodeScanningAlertDismissedReason::UsedInTests => "used in tests".to_string(),
+      CodeScanningAlertDismissedReason::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2458,6 +3625,13 @@ pub enum CodeScanningAlertClassification {
   Test,
   #[serde(rename = "library")]
   Library,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -2468,6 +3642,7 @@ impl ToString for CodeScanningAlertClassification {
       CodeScanningAlertClassification::Generated => "generated".to_string(),
       CodeScanningAlertClassification::Test => "test".to_string(),
       CodeScanningAlertClassification::Library => "library".to_string(),
+      CodeScanningAlertClassification::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2482,6 +3657,13 @@ pub enum CodeScanningAlertState {
   Dismissed,
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -2491,6 +3673,7 @@ impl ToString for CodeScanningAlertState {
       CodeScanningAlertState::Open => "open".to_string(),
       CodeScanningAlertState::Dismissed => "dismissed".to_string(),
       CodeScanningAlertState::Fixed => "fixed".to_string(),
+      CodeScanningAlertState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2507,6 +3690,13 @@ pub enum CodeScanningAlertRuleSummarySecuritySeverityLevel {
   High,
   #[serde(rename = "critical")]
   Critical,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -2517,6 +3707,7 @@ impl ToString for CodeScanningAlertRuleSummarySecuritySeverityLevel {
       CodeScanningAlertRuleSummarySecuritySeverityLevel::Medium => "medium".to_string(),
       CodeScanningAlertRuleSummarySecuritySeverityLevel::High => "high".to_string(),
       CodeScanningAlertRuleSummarySecuritySeverityLevel::Critical => "critical".to_string(),
+      CodeScanningAlertRuleSummarySecuritySeverityLevel::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2533,6 +3724,13 @@ pub enum CodeScanningAlertRuleSummarySeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -2543,6 +3741,7 @@ impl ToString for CodeScanningAlertRuleSummarySeverity {
       CodeScanningAlertRuleSummarySeverity::Note => "note".to_string(),
       CodeScanningAlertRuleSummarySeverity::Warning => "warning".to_string(),
       CodeScanningAlertRuleSummarySeverity::Error => "error".to_string(),
+      CodeScanningAlertRuleSummarySeverity::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2555,6 +3754,13 @@ pub enum CodespaceLocation {
   SouthEastAsia,
   WestEurope,
   WestUs2,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -2565,6 +3771,7 @@ impl ToString for CodespaceLocation {
       CodespaceLocation::SouthEastAsia => "SouthEastAsia".to_string(),
       CodespaceLocation::WestEurope => "WestEurope".to_string(),
       CodespaceLocation::WestUs2 => "WestUs2".to_string(),
+      CodespaceLocation::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2579,6 +3786,13 @@ pub enum CodespaceMachinePrebuildAvailability {
   Ready,
   #[serde(rename = "in_progress")]
   InProgress,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -2588,6 +3802,7 @@ impl ToString for CodespaceMachinePrebuildAvailability {
       CodespaceMachinePrebuildAvailability::None => "none".to_string(),
       CodespaceMachinePrebuildAvailability::Ready => "ready".to_string(),
       CodespaceMachinePrebuildAvailability::InProgress => "in_progress".to_string(),
+      CodespaceMachinePrebuildAvailability::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2613,6 +3828,13 @@ pub enum CodespaceState {
   Exporting,
   Updating,
   Rebuilding,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -2636,6 +3858,7 @@ impl ToString for CodespaceState {
       CodespaceState::Exporting => "Exporting".to_string(),
       CodespaceState::Updating => "Updating".to_string(),
       CodespaceState::Rebuilding => "Rebuilding".to_string(),
+      CodespaceState::Other(value) => value.clone(),
     }
   }
 }
@@ -2652,6 +3875,13 @@ pub enum CodespacesSetCodespacesAccessRequestVisibility {
   AllMembers,
   #[serde(rename = "all_members_and_outside_collaborators")]
   AllMembersAndOutsideCollaborators,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -2666,6 +3896,7 @@ impl ToString for CodespacesSetCodespacesAccessRequestVisibility {
       CodespacesSetCodespacesAccessRequestVisibility::AllMembersAndOutsideCollaborators => {
         "all_members_and_outside_collaborators".to_string()
       }
+      CodespacesSetCodespacesAccessRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2680,6 +3911,13 @@ pub enum CodespacesSecretVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -2689,6 +3927,7 @@ impl ToString for CodespacesSecretVisibility {
       CodespacesSecretVisibility::All => "all".to_string(),
       CodespacesSecretVisibility::Private => "private".to_string(),
       CodespacesSecretVisibility::Selected => "selected".to_string(),
+      CodespacesSecretVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2703,6 +3942,13 @@ pub enum CodespacesCreateOrUpdateOrgSecretRequestVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -2712,6 +3958,7 @@ impl ToString for CodespacesCreateOrUpdateOrgSecretRequestVisibility {
       CodespacesCreateOrUpdateOrgSecretRequestVisibility::All => "all".to_string(),
       CodespacesCreateOrUpdateOrgSecretRequestVisibility::Private => "private".to_string(),
       CodespacesCreateOrUpdateOrgSecretRequestVisibility::Selected => "selected".to_string(),
+      CodespacesCreateOrUpdateOrgSecretRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2726,6 +3973,13 @@ pub enum CopilotBusinessOrganizationDetailsCli {
   Disabled,
   #[serde(rename = "unconfigured")]
   Unconfigured,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "copilot",))]
@@ -2735,6 +3989,7 @@ impl ToString for CopilotBusinessOrganizationDetailsCli {
       CopilotBusinessOrganizationDetailsCli::Enabled => "enabled".to_string(),
       CopilotBusinessOrganizationDetailsCli::Disabled => "disabled".to_string(),
       CopilotBusinessOrganizationDetailsCli::Unconfigured => "unconfigured".to_string(),
+      CopilotBusinessOrganizationDetailsCli::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2749,6 +4004,13 @@ pub enum CopilotBusinessOrganizationDetailsIdeChat {
   Disabled,
   #[serde(rename = "unconfigured")]
   Unconfigured,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "copilot",))]
@@ -2758,6 +4020,7 @@ impl ToString for CopilotBusinessOrganizationDetailsIdeChat {
       CopilotBusinessOrganizationDetailsIdeChat::Enabled => "enabled".to_string(),
       CopilotBusinessOrganizationDetailsIdeChat::Disabled => "disabled".to_string(),
       CopilotBusinessOrganizationDetailsIdeChat::Unconfigured => "unconfigured".to_string(),
+      CopilotBusinessOrganizationDetailsIdeChat::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2772,6 +4035,13 @@ pub enum CopilotBusinessOrganizationDetailsPlatformChat {
   Disabled,
   #[serde(rename = "unconfigured")]
   Unconfigured,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "copilot",))]
@@ -2781,6 +4051,7 @@ impl ToString for CopilotBusinessOrganizationDetailsPlatformChat {
       CopilotBusinessOrganizationDetailsPlatformChat::Enabled => "enabled".to_string(),
       CopilotBusinessOrganizationDetailsPlatformChat::Disabled => "disabled".to_string(),
       CopilotBusinessOrganizationDetailsPlatformChat::Unconfigured => "unconfigured".to_string(),
+      CopilotBusinessOrganizationDetailsPlatformChat::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2797,6 +4068,13 @@ pub enum CopilotBusinessOrganizationDetailsPublicCodeSuggestions {
   Unconfigured,
   #[serde(rename = "unknown")]
   Unknown,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "copilot",))]
@@ -2809,6 +4087,7 @@ impl ToString for CopilotBusinessOrganizationDetailsPublicCodeSuggestions {
         "unconfigured".to_string()
       }
       CopilotBusinessOrganizationDetailsPublicCodeSuggestions::Unknown => "unknown".to_string(),
+      CopilotBusinessOrganizationDetailsPublicCodeSuggestions::Other(value) => value.clone(),
     }
   }
 }
@@ -2825,6 +4104,13 @@ pub enum CopilotBusinessOrganizationDetailsSeatManagementSetting {
   Disabled,
   #[serde(rename = "unconfigured")]
   Unconfigured,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "copilot",))]
@@ -2841,6 +4127,7 @@ impl ToString for CopilotBusinessOrganizationDetailsSeatManagementSetting {
       CopilotBusinessOrganizationDetailsSeatManagementSetting::Unconfigured => {
         "unconfigured".to_string()
       }
+      CopilotBusinessOrganizationDetailsSeatManagementSetting::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2864,6 +4151,13 @@ pub enum DependabotListAlertsForOrgQueryScope {
   Development,
   #[serde(rename = "runtime")]
   Runtime,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -2872,6 +4166,7 @@ impl ToString for DependabotListAlertsForOrgQueryScope {
     match self {
       DependabotListAlertsForOrgQueryScope::Development => "development".to_string(),
       DependabotListAlertsForOrgQueryScope::Runtime => "runtime".to_string(),
+      DependabotListAlertsForOrgQueryScope::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2883,6 +4178,13 @@ pub enum DependabotListAlertsForOrgQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -2891,6 +4193,7 @@ impl ToString for DependabotListAlertsForOrgQuerySort {
     match self {
       DependabotListAlertsForOrgQuerySort::Created => "created".to_string(),
       DependabotListAlertsForOrgQuerySort::Updated => "updated".to_string(),
+      DependabotListAlertsForOrgQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2902,6 +4205,13 @@ pub enum DependabotListAlertsForOrgQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -2910,6 +4220,7 @@ impl ToString for DependabotListAlertsForOrgQueryDirection {
     match self {
       DependabotListAlertsForOrgQueryDirection::Asc => "asc".to_string(),
       DependabotListAlertsForOrgQueryDirection::Desc => "desc".to_string(),
+      DependabotListAlertsForOrgQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2924,6 +4235,13 @@ pub enum DependabotSecretForAnOrganizationVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -2933,6 +4251,7 @@ impl ToString for DependabotSecretForAnOrganizationVisibility {
       DependabotSecretForAnOrganizationVisibility::All => "all".to_string(),
       DependabotSecretForAnOrganizationVisibility::Private => "private".to_string(),
       DependabotSecretForAnOrganizationVisibility::Selected => "selected".to_string(),
+      DependabotSecretForAnOrganizationVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2947,6 +4266,13 @@ pub enum DependabotCreateOrUpdateOrgSecretRequestVisibility {
   Private,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -2956,6 +4282,7 @@ impl ToString for DependabotCreateOrUpdateOrgSecretRequestVisibility {
       DependabotCreateOrUpdateOrgSecretRequestVisibility::All => "all".to_string(),
       DependabotCreateOrUpdateOrgSecretRequestVisibility::Private => "private".to_string(),
       DependabotCreateOrUpdateOrgSecretRequestVisibility::Selected => "selected".to_string(),
+      DependabotCreateOrUpdateOrgSecretRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2975,6 +4302,13 @@ pub enum PackagePackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -2987,6 +4321,7 @@ impl ToString for PackagePackageType {
       PackagePackageType::Docker => "docker".to_string(),
       PackagePackageType::Nuget => "nuget".to_string(),
       PackagePackageType::Container => "container".to_string(),
+      PackagePackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2998,6 +4333,13 @@ pub enum PackageVisibility {
   Private,
   #[serde(rename = "public")]
   Public,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3006,6 +4348,7 @@ impl ToString for PackageVisibility {
     match self {
       PackageVisibility::Private => "private".to_string(),
       PackageVisibility::Public => "public".to_string(),
+      PackageVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3020,6 +4363,13 @@ pub enum InteractionGroup {
   ContributorsOnly,
   #[serde(rename = "collaborators_only")]
   CollaboratorsOnly,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "interactions",))]
@@ -3029,6 +4379,7 @@ impl ToString for InteractionGroup {
       InteractionGroup::ExistingUsers => "existing_users".to_string(),
       InteractionGroup::ContributorsOnly => "contributors_only".to_string(),
       InteractionGroup::CollaboratorsOnly => "collaborators_only".to_string(),
+      InteractionGroup::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3056,6 +4407,13 @@ pub enum InteractionExpiry {
   OneMonth,
   #[serde(rename = "six_months")]
   SixMonths,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "interactions",))]
@@ -3067,6 +4425,7 @@ impl ToString for InteractionExpiry {
       InteractionExpiry::OneWeek => "one_week".to_string(),
       InteractionExpiry::OneMonth => "one_month".to_string(),
       InteractionExpiry::SixMonths => "six_months".to_string(),
+      InteractionExpiry::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3084,6 +4443,13 @@ pub enum OrgsListPendingInvitationsQueryRole {
   BillingManager,
   #[serde(rename = "hiring_manager")]
   HiringManager,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3095,6 +4461,7 @@ impl ToString for OrgsListPendingInvitationsQueryRole {
       OrgsListPendingInvitationsQueryRole::DirectMember => "direct_member".to_string(),
       OrgsListPendingInvitationsQueryRole::BillingManager => "billing_manager".to_string(),
       OrgsListPendingInvitationsQueryRole::HiringManager => "hiring_manager".to_string(),
+      OrgsListPendingInvitationsQueryRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3108,6 +4475,13 @@ pub enum OrgsListPendingInvitationsQueryInvitationSource {
   Member,
   #[serde(rename = "scim")]
   Scim,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3117,6 +4491,7 @@ impl ToString for OrgsListPendingInvitationsQueryInvitationSource {
       OrgsListPendingInvitationsQueryInvitationSource::All => "all".to_string(),
       OrgsListPendingInvitationsQueryInvitationSource::Member => "member".to_string(),
       OrgsListPendingInvitationsQueryInvitationSource::Scim => "scim".to_string(),
+      OrgsListPendingInvitationsQueryInvitationSource::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3137,6 +4512,13 @@ pub enum OrgsCreateInvitationRequestRole {
   BillingManager,
   #[serde(rename = "reinstate")]
   Reinstate,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3147,6 +4529,7 @@ impl ToString for OrgsCreateInvitationRequestRole {
       OrgsCreateInvitationRequestRole::DirectMember => "direct_member".to_string(),
       OrgsCreateInvitationRequestRole::BillingManager => "billing_manager".to_string(),
       OrgsCreateInvitationRequestRole::Reinstate => "reinstate".to_string(),
+      OrgsCreateInvitationRequestRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3166,6 +4549,13 @@ pub enum IssuesListForOrgQueryFilter {
   Repos,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -3178,6 +4568,7 @@ impl ToString for IssuesListForOrgQueryFilter {
       IssuesListForOrgQueryFilter::Subscribed => "subscribed".to_string(),
       IssuesListForOrgQueryFilter::Repos => "repos".to_string(),
       IssuesListForOrgQueryFilter::All => "all".to_string(),
+      IssuesListForOrgQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3191,6 +4582,13 @@ pub enum IssuesListForOrgQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -3200,6 +4598,7 @@ impl ToString for IssuesListForOrgQueryState {
       IssuesListForOrgQueryState::Open => "open".to_string(),
       IssuesListForOrgQueryState::Closed => "closed".to_string(),
       IssuesListForOrgQueryState::All => "all".to_string(),
+      IssuesListForOrgQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3213,6 +4612,13 @@ pub enum IssuesListForOrgQuerySort {
   Updated,
   #[serde(rename = "comments")]
   Comments,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -3222,6 +4628,7 @@ impl ToString for IssuesListForOrgQuerySort {
       IssuesListForOrgQuerySort::Created => "created".to_string(),
       IssuesListForOrgQuerySort::Updated => "updated".to_string(),
       IssuesListForOrgQuerySort::Comments => "comments".to_string(),
+      IssuesListForOrgQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3233,6 +4640,13 @@ pub enum IssuesListForOrgQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -3241,6 +4655,7 @@ impl ToString for IssuesListForOrgQueryDirection {
     match self {
       IssuesListForOrgQueryDirection::Asc => "asc".to_string(),
       IssuesListForOrgQueryDirection::Desc => "desc".to_string(),
+      IssuesListForOrgQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3252,6 +4667,13 @@ pub enum OrgsListMembersQueryFilter {
   TwoFADisabled,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3260,6 +4682,7 @@ impl ToString for OrgsListMembersQueryFilter {
     match self {
       OrgsListMembersQueryFilter::TwoFADisabled => "2fa_disabled".to_string(),
       OrgsListMembersQueryFilter::All => "all".to_string(),
+      OrgsListMembersQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3273,6 +4696,13 @@ pub enum OrgsListMembersQueryRole {
   Admin,
   #[serde(rename = "member")]
   Member,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3282,6 +4712,7 @@ impl ToString for OrgsListMembersQueryRole {
       OrgsListMembersQueryRole::All => "all".to_string(),
       OrgsListMembersQueryRole::Admin => "admin".to_string(),
       OrgsListMembersQueryRole::Member => "member".to_string(),
+      OrgsListMembersQueryRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3296,6 +4727,13 @@ pub enum OrgMembershipRole {
   Member,
   #[serde(rename = "billing_manager")]
   BillingManager,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3305,6 +4743,7 @@ impl ToString for OrgMembershipRole {
       OrgMembershipRole::Admin => "admin".to_string(),
       OrgMembershipRole::Member => "member".to_string(),
       OrgMembershipRole::BillingManager => "billing_manager".to_string(),
+      OrgMembershipRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3317,6 +4756,13 @@ pub enum OrgMembershipState {
   Active,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3325,6 +4771,7 @@ impl ToString for OrgMembershipState {
     match self {
       OrgMembershipState::Active => "active".to_string(),
       OrgMembershipState::Pending => "pending".to_string(),
+      OrgMembershipState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3339,6 +4786,13 @@ pub enum OrgsSetMembershipForUserRequestRole {
   Admin,
   #[serde(rename = "member")]
   Member,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3347,6 +4801,7 @@ impl ToString for OrgsSetMembershipForUserRequestRole {
     match self {
       OrgsSetMembershipForUserRequestRole::Admin => "admin".to_string(),
       OrgsSetMembershipForUserRequestRole::Member => "member".to_string(),
+      OrgsSetMembershipForUserRequestRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3357,6 +4812,13 @@ impl ToString for OrgsSetMembershipForUserRequestRole {
 pub enum MigrationsListForOrgQueryExclude {
   #[serde(rename = "repositories")]
   Repositories,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -3364,6 +4826,7 @@ impl ToString for MigrationsListForOrgQueryExclude {
   fn to_string(&self) -> String {
     match self {
       MigrationsListForOrgQueryExclude::Repositories => "repositories".to_string(),
+      MigrationsListForOrgQueryExclude::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3373,6 +4836,13 @@ impl ToString for MigrationsListForOrgQueryExclude {
 pub enum MigrationsStartForOrgRequestExclude {
   #[serde(rename = "repositories")]
   Repositories,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -3380,6 +4850,7 @@ impl ToString for MigrationsStartForOrgRequestExclude {
   fn to_string(&self) -> String {
     match self {
       MigrationsStartForOrgRequestExclude::Repositories => "repositories".to_string(),
+      MigrationsStartForOrgRequestExclude::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3390,6 +4861,13 @@ impl ToString for MigrationsStartForOrgRequestExclude {
 pub enum MigrationsGetStatusForOrgQueryExclude {
   #[serde(rename = "repositories")]
   Repositories,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -3397,6 +4875,7 @@ impl ToString for MigrationsGetStatusForOrgQueryExclude {
   fn to_string(&self) -> String {
     match self {
       MigrationsGetStatusForOrgQueryExclude::Repositories => "repositories".to_string(),
+      MigrationsGetStatusForOrgQueryExclude::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3408,6 +4887,13 @@ pub enum OrgsListOutsideCollaboratorsQueryFilter {
   TwoFADisabled,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3416,6 +4902,7 @@ impl ToString for OrgsListOutsideCollaboratorsQueryFilter {
     match self {
       OrgsListOutsideCollaboratorsQueryFilter::TwoFADisabled => "2fa_disabled".to_string(),
       OrgsListOutsideCollaboratorsQueryFilter::All => "all".to_string(),
+      OrgsListOutsideCollaboratorsQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3435,6 +4922,13 @@ pub enum PackagesListPackagesForOrganizationQueryPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3447,6 +4941,7 @@ impl ToString for PackagesListPackagesForOrganizationQueryPackageType {
       PackagesListPackagesForOrganizationQueryPackageType::Docker => "docker".to_string(),
       PackagesListPackagesForOrganizationQueryPackageType::Nuget => "nuget".to_string(),
       PackagesListPackagesForOrganizationQueryPackageType::Container => "container".to_string(),
+      PackagesListPackagesForOrganizationQueryPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3460,6 +4955,13 @@ pub enum PackagesListPackagesForOrganizationQueryVisibility {
   Private,
   #[serde(rename = "internal")]
   Internal,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3469,6 +4971,7 @@ impl ToString for PackagesListPackagesForOrganizationQueryVisibility {
       PackagesListPackagesForOrganizationQueryVisibility::Public => "public".to_string(),
       PackagesListPackagesForOrganizationQueryVisibility::Private => "private".to_string(),
       PackagesListPackagesForOrganizationQueryVisibility::Internal => "internal".to_string(),
+      PackagesListPackagesForOrganizationQueryVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3488,6 +4991,13 @@ pub enum PackagesGetPackageForOrganizationParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3500,6 +5010,7 @@ impl ToString for PackagesGetPackageForOrganizationParametersPackageType {
       PackagesGetPackageForOrganizationParametersPackageType::Docker => "docker".to_string(),
       PackagesGetPackageForOrganizationParametersPackageType::Nuget => "nuget".to_string(),
       PackagesGetPackageForOrganizationParametersPackageType::Container => "container".to_string(),
+      PackagesGetPackageForOrganizationParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3519,6 +5030,13 @@ pub enum PackagesDeletePackageForOrgParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3531,6 +5049,7 @@ impl ToString for PackagesDeletePackageForOrgParametersPackageType {
       PackagesDeletePackageForOrgParametersPackageType::Docker => "docker".to_string(),
       PackagesDeletePackageForOrgParametersPackageType::Nuget => "nuget".to_string(),
       PackagesDeletePackageForOrgParametersPackageType::Container => "container".to_string(),
+      PackagesDeletePackageForOrgParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3550,6 +5069,13 @@ pub enum PackagesRestorePackageForOrgParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3562,6 +5088,7 @@ impl ToString for PackagesRestorePackageForOrgParametersPackageType {
       PackagesRestorePackageForOrgParametersPackageType::Docker => "docker".to_string(),
       PackagesRestorePackageForOrgParametersPackageType::Nuget => "nuget".to_string(),
       PackagesRestorePackageForOrgParametersPackageType::Container => "container".to_string(),
+      PackagesRestorePackageForOrgParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3581,6 +5108,13 @@ pub enum PackagesGetAllPackageVersionsForPackageOwnedByOrgParametersPackageType
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3605,6 +5139,9 @@ impl ToString for PackagesGetAllPackageVersionsForPackageOwnedByOrgParametersPac
       PackagesGetAllPackageVersionsForPackageOwnedByOrgParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesGetAllPackageVersionsForPackageOwnedByOrgParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -3616,6 +5153,13 @@ pub enum PackagesGetAllPackageVersionsForPackageOwnedByOrgQueryState {
   Active,
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3624,6 +5168,7 @@ impl ToString for PackagesGetAllPackageVersionsForPackageOwnedByOrgQueryState {
     match self {
       PackagesGetAllPackageVersionsForPackageOwnedByOrgQueryState::Active => "active".to_string(),
       PackagesGetAllPackageVersionsForPackageOwnedByOrgQueryState::Deleted => "deleted".to_string(),
+      PackagesGetAllPackageVersionsForPackageOwnedByOrgQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3643,6 +5188,13 @@ pub enum PackageVersionMetadataPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3655,6 +5207,7 @@ impl ToString for PackageVersionMetadataPackageType {
       PackageVersionMetadataPackageType::Docker => "docker".to_string(),
       PackageVersionMetadataPackageType::Nuget => "nuget".to_string(),
       PackageVersionMetadataPackageType::Container => "container".to_string(),
+      PackageVersionMetadataPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3674,6 +5227,13 @@ pub enum PackagesGetPackageVersionForOrganizationParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3690,6 +5250,9 @@ impl ToString for PackagesGetPackageVersionForOrganizationParametersPackageType
       PackagesGetPackageVersionForOrganizationParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesGetPackageVersionForOrganizationParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -3709,6 +5272,13 @@ pub enum PackagesDeletePackageVersionForOrgParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3721,6 +5291,7 @@ impl ToString for PackagesDeletePackageVersionForOrgParametersPackageType {
       PackagesDeletePackageVersionForOrgParametersPackageType::Docker => "docker".to_string(),
       PackagesDeletePackageVersionForOrgParametersPackageType::Nuget => "nuget".to_string(),
       PackagesDeletePackageVersionForOrgParametersPackageType::Container => "container".to_string(),
+      PackagesDeletePackageVersionForOrgParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3740,6 +5311,13 @@ pub enum PackagesRestorePackageVersionForOrgParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -3754,6 +5332,7 @@ impl ToString for PackagesRestorePackageVersionForOrgParametersPackageType {
       PackagesRestorePackageVersionForOrgParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesRestorePackageVersionForOrgParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3763,6 +5342,13 @@ impl ToString for PackagesRestorePackageVersionForOrgParametersPackageType {
 pub enum OrgsListPatGrantRequestsQuerySort {
   #[serde(rename = "created_at")]
   CreatedAt,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3770,6 +5356,7 @@ impl ToString for OrgsListPatGrantRequestsQuerySort {
   fn to_string(&self) -> String {
     match self {
       OrgsListPatGrantRequestsQuerySort::CreatedAt => "created_at".to_string(),
+      OrgsListPatGrantRequestsQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3781,6 +5368,13 @@ pub enum OrgsListPatGrantRequestsQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3789,6 +5383,7 @@ impl ToString for OrgsListPatGrantRequestsQueryDirection {
     match self {
       OrgsListPatGrantRequestsQueryDirection::Asc => "asc".to_string(),
       OrgsListPatGrantRequestsQueryDirection::Desc => "desc".to_string(),
+      OrgsListPatGrantRequestsQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3803,6 +5398,13 @@ pub enum SimpleOrganizationProgrammaticAccessGrantRequestRepositorySelection {
   All,
   #[serde(rename = "subset")]
   Subset,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3816,6 +5418,9 @@ impl ToString for SimpleOrganizationProgrammaticAccessGrantRequestRepositorySele
       SimpleOrganizationProgrammaticAccessGrantRequestRepositorySelection::Subset => {
         "subset".to_string()
       }
+      SimpleOrganizationProgrammaticAccessGrantRequestRepositorySelection::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -3828,6 +5433,13 @@ pub enum OrgsReviewPatGrantRequestsInBulkRequestAction {
   Approve,
   #[serde(rename = "deny")]
   Deny,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3836,6 +5448,7 @@ impl ToString for OrgsReviewPatGrantRequestsInBulkRequestAction {
     match self {
       OrgsReviewPatGrantRequestsInBulkRequestAction::Approve => "approve".to_string(),
       OrgsReviewPatGrantRequestsInBulkRequestAction::Deny => "deny".to_string(),
+      OrgsReviewPatGrantRequestsInBulkRequestAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3848,6 +5461,13 @@ pub enum OrgsReviewPatGrantRequestRequestAction {
   Approve,
   #[serde(rename = "deny")]
   Deny,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3856,6 +5476,7 @@ impl ToString for OrgsReviewPatGrantRequestRequestAction {
     match self {
       OrgsReviewPatGrantRequestRequestAction::Approve => "approve".to_string(),
       OrgsReviewPatGrantRequestRequestAction::Deny => "deny".to_string(),
+      OrgsReviewPatGrantRequestRequestAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3865,6 +5486,13 @@ impl ToString for OrgsReviewPatGrantRequestRequestAction {
 pub enum OrgsListPatGrantsQuerySort {
   #[serde(rename = "created_at")]
   CreatedAt,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3872,6 +5500,7 @@ impl ToString for OrgsListPatGrantsQuerySort {
   fn to_string(&self) -> String {
     match self {
       OrgsListPatGrantsQuerySort::CreatedAt => "created_at".to_string(),
+      OrgsListPatGrantsQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3883,6 +5512,13 @@ pub enum OrgsListPatGrantsQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3891,6 +5527,7 @@ impl ToString for OrgsListPatGrantsQueryDirection {
     match self {
       OrgsListPatGrantsQueryDirection::Asc => "asc".to_string(),
       OrgsListPatGrantsQueryDirection::Desc => "desc".to_string(),
+      OrgsListPatGrantsQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3905,6 +5542,13 @@ pub enum OrganizationProgrammaticAccessGrantRepositorySelection {
   All,
   #[serde(rename = "subset")]
   Subset,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3914,6 +5558,7 @@ impl ToString for OrganizationProgrammaticAccessGrantRepositorySelection {
       OrganizationProgrammaticAccessGrantRepositorySelection::None => "none".to_string(),
       OrganizationProgrammaticAccessGrantRepositorySelection::All => "all".to_string(),
       OrganizationProgrammaticAccessGrantRepositorySelection::Subset => "subset".to_string(),
+      OrganizationProgrammaticAccessGrantRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3924,6 +5569,13 @@ impl ToString for OrganizationProgrammaticAccessGrantRepositorySelection {
 pub enum OrgsUpdatePatAccessesRequestAction {
   #[serde(rename = "revoke")]
   Revoke,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3931,6 +5583,7 @@ impl ToString for OrgsUpdatePatAccessesRequestAction {
   fn to_string(&self) -> String {
     match self {
       OrgsUpdatePatAccessesRequestAction::Revoke => "revoke".to_string(),
+      OrgsUpdatePatAccessesRequestAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3941,6 +5594,13 @@ impl ToString for OrgsUpdatePatAccessesRequestAction {
 pub enum OrgsUpdatePatAccessRequestAction {
   #[serde(rename = "revoke")]
   Revoke,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -3948,6 +5608,7 @@ impl ToString for OrgsUpdatePatAccessRequestAction {
   fn to_string(&self) -> String {
     match self {
       OrgsUpdatePatAccessRequestAction::Revoke => "revoke".to_string(),
+      OrgsUpdatePatAccessRequestAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3961,6 +5622,13 @@ pub enum ProjectsListForOrgQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "projects",))]
@@ -3970,11 +5638,12 @@ impl ToString for ProjectsListForOrgQueryState {
       ProjectsListForOrgQueryState::Open => "open".to_string(),
       ProjectsListForOrgQueryState::Closed => "closed".to_string(),
       ProjectsListForOrgQueryState::All => "all".to_string(),
+      ProjectsListForOrgQueryState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "projects", feature = "webhook_project",))]
 /// The baseline permission that all organization members have on this project. Only present if owner is an organization.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectOrganizationPermission {
@@ -3986,9 +5655,16 @@ pub enum ProjectOrganizationPermission {
   Admin,
   #[serde(rename = "none")]
   None,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "projects", feature = "webhook_project",))]
 impl ToString for ProjectOrganizationPermission {
   fn to_string(&self) -> String {
     match self {
@@ -3996,11 +5672,16 @@ impl ToString for ProjectOrganizationPermission {
       ProjectOrganizationPermission::Write => "write".to_string(),
       ProjectOrganizationPermission::Admin => "admin".to_string(),
       ProjectOrganizationPermission::None => "none".to_string(),
+      ProjectOrganizationPermission::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_custom_property",
+))]
 /// The type of the value for the property
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum OrganizationCustomPropertyValueType {
@@ -4008,19 +5689,35 @@ pub enum OrganizationCustomPropertyValueType {
   String,
   #[serde(rename = "single_select")]
   SingleSelect,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_custom_property",
+))]
 impl ToString for OrganizationCustomPropertyValueType {
   fn to_string(&self) -> String {
     match self {
       OrganizationCustomPropertyValueType::String => "string".to_string(),
       OrganizationCustomPropertyValueType::SingleSelect => "single_select".to_string(),
+      OrganizationCustomPropertyValueType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_custom_property",
+))]
 /// Who can edit the values of the property
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum OrganizationCustomPropertyValuesEditableBy {
@@ -4028,9 +5725,20 @@ pub enum OrganizationCustomPropertyValuesEditableBy {
   OrgActors,
   #[serde(rename = "org_and_repo_actors")]
   OrgAndRepoActors,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_custom_property",
+))]
 impl ToString for OrganizationCustomPropertyValuesEditableBy {
   fn to_string(&self) -> String {
     match self {
@@ -4038,6 +5746,7 @@ impl ToString for OrganizationCustomPropertyValuesEditableBy {
       OrganizationCustomPropertyValuesEditableBy::OrgAndRepoActors => {
         "org_and_repo_actors".to_string()
       }
+      OrganizationCustomPropertyValuesEditableBy::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4050,6 +5759,13 @@ pub enum OrgsCreateOrUpdateCustomPropertyRequestValueType {
   String,
   #[serde(rename = "single_select")]
   SingleSelect,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -4058,6 +5774,7 @@ impl ToString for OrgsCreateOrUpdateCustomPropertyRequestValueType {
     match self {
       OrgsCreateOrUpdateCustomPropertyRequestValueType::String => "string".to_string(),
       OrgsCreateOrUpdateCustomPropertyRequestValueType::SingleSelect => "single_select".to_string(),
+      OrgsCreateOrUpdateCustomPropertyRequestValueType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4077,6 +5794,13 @@ pub enum ReposListForOrgQueryType {
   Sources,
   #[serde(rename = "member")]
   Member,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4089,6 +5813,7 @@ impl ToString for ReposListForOrgQueryType {
       ReposListForOrgQueryType::Forks => "forks".to_string(),
       ReposListForOrgQueryType::Sources => "sources".to_string(),
       ReposListForOrgQueryType::Member => "member".to_string(),
+      ReposListForOrgQueryType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4104,6 +5829,13 @@ pub enum ReposListForOrgQuerySort {
   Pushed,
   #[serde(rename = "full_name")]
   FullName,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4114,6 +5846,7 @@ impl ToString for ReposListForOrgQuerySort {
       ReposListForOrgQuerySort::Updated => "updated".to_string(),
       ReposListForOrgQuerySort::Pushed => "pushed".to_string(),
       ReposListForOrgQuerySort::FullName => "full_name".to_string(),
+      ReposListForOrgQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4125,6 +5858,13 @@ pub enum ReposListForOrgQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4133,6 +5873,7 @@ impl ToString for ReposListForOrgQueryDirection {
     match self {
       ReposListForOrgQueryDirection::Asc => "asc".to_string(),
       ReposListForOrgQueryDirection::Desc => "desc".to_string(),
+      ReposListForOrgQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4151,6 +5892,13 @@ pub enum ReposCreateInOrgRequestMergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4160,6 +5908,7 @@ impl ToString for ReposCreateInOrgRequestMergeCommitMessage {
       ReposCreateInOrgRequestMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       ReposCreateInOrgRequestMergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       ReposCreateInOrgRequestMergeCommitMessage::Blank => "BLANK".to_string(),
+      ReposCreateInOrgRequestMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4175,6 +5924,13 @@ pub enum ReposCreateInOrgRequestMergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4183,6 +5939,7 @@ impl ToString for ReposCreateInOrgRequestMergeCommitTitle {
     match self {
       ReposCreateInOrgRequestMergeCommitTitle::PrTitle => "PR_TITLE".to_string(),
       ReposCreateInOrgRequestMergeCommitTitle::MergeMessage => "MERGE_MESSAGE".to_string(),
+      ReposCreateInOrgRequestMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4201,6 +5958,13 @@ pub enum ReposCreateInOrgRequestSquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4212,6 +5976,7 @@ impl ToString for ReposCreateInOrgRequestSquashMergeCommitMessage {
         "COMMIT_MESSAGES".to_string()
       }
       ReposCreateInOrgRequestSquashMergeCommitMessage::Blank => "BLANK".to_string(),
+      ReposCreateInOrgRequestSquashMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4227,6 +5992,13 @@ pub enum ReposCreateInOrgRequestSquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4237,6 +6009,7 @@ impl ToString for ReposCreateInOrgRequestSquashMergeCommitTitle {
       ReposCreateInOrgRequestSquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      ReposCreateInOrgRequestSquashMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4249,6 +6022,13 @@ pub enum ReposCreateInOrgRequestVisibility {
   Public,
   #[serde(rename = "private")]
   Private,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -4257,6 +6037,7 @@ impl ToString for ReposCreateInOrgRequestVisibility {
     match self {
       ReposCreateInOrgRequestVisibility::Public => "public".to_string(),
       ReposCreateInOrgRequestVisibility::Private => "private".to_string(),
+      ReposCreateInOrgRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4265,7 +6046,7 @@ impl ToString for ReposCreateInOrgRequestVisibility {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 /// The default value for a merge commit message.
 ///
@@ -4280,13 +6061,20 @@ pub enum FullRepositoryMergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 impl ToString for FullRepositoryMergeCommitMessage {
   fn to_string(&self) -> String {
@@ -4294,6 +6082,7 @@ impl ToString for FullRepositoryMergeCommitMessage {
       FullRepositoryMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       FullRepositoryMergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       FullRepositoryMergeCommitMessage::Blank => "BLANK".to_string(),
+      FullRepositoryMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4302,7 +6091,7 @@ impl ToString for FullRepositoryMergeCommitMessage {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 /// The default value for a merge commit title.
 ///
@@ -4314,19 +6103,27 @@ pub enum FullRepositoryMergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 impl ToString for FullRepositoryMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
       FullRepositoryMergeCommitTitle::PrTitle => "PR_TITLE".to_string(),
       FullRepositoryMergeCommitTitle::MergeMessage => "MERGE_MESSAGE".to_string(),
+      FullRepositoryMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4335,7 +6132,7 @@ impl ToString for FullRepositoryMergeCommitTitle {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 /// The default value for a squash merge commit message:
 ///
@@ -4350,13 +6147,20 @@ pub enum FullRepositorySquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 impl ToString for FullRepositorySquashMergeCommitMessage {
   fn to_string(&self) -> String {
@@ -4364,6 +6168,7 @@ impl ToString for FullRepositorySquashMergeCommitMessage {
       FullRepositorySquashMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       FullRepositorySquashMergeCommitMessage::CommitMessages => "COMMIT_MESSAGES".to_string(),
       FullRepositorySquashMergeCommitMessage::Blank => "BLANK".to_string(),
+      FullRepositorySquashMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4372,7 +6177,7 @@ impl ToString for FullRepositorySquashMergeCommitMessage {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 /// The default value for a squash merge commit title:
 ///
@@ -4384,24 +6189,36 @@ pub enum FullRepositorySquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 impl ToString for FullRepositorySquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
       FullRepositorySquashMergeCommitTitle::PrTitle => "PR_TITLE".to_string(),
       FullRepositorySquashMergeCommitTitle::CommitOrPrTitle => "COMMIT_OR_PR_TITLE".to_string(),
+      FullRepositorySquashMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The type of actor that can bypass a ruleset
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulesetBypassActorActorType {
@@ -4409,9 +6226,20 @@ pub enum RepositoryRulesetBypassActorActorType {
   Team,
   Integration,
   OrganizationAdmin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRulesetBypassActorActorType {
   fn to_string(&self) -> String {
     match self {
@@ -4419,11 +6247,16 @@ impl ToString for RepositoryRulesetBypassActorActorType {
       RepositoryRulesetBypassActorActorType::Team => "Team".to_string(),
       RepositoryRulesetBypassActorActorType::Integration => "Integration".to_string(),
       RepositoryRulesetBypassActorActorType::OrganizationAdmin => "OrganizationAdmin".to_string(),
+      RepositoryRulesetBypassActorActorType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// When the specified actor can bypass the ruleset. `pull_request` means that an actor can only bypass rules on pull requests.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulesetBypassActorBypassMode {
@@ -4431,19 +6264,35 @@ pub enum RepositoryRulesetBypassActorBypassMode {
   Always,
   #[serde(rename = "pull_request")]
   PullRequest,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRulesetBypassActorBypassMode {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulesetBypassActorBypassMode::Always => "always".to_string(),
       RepositoryRulesetBypassActorBypassMode::PullRequest => "pull_request".to_string(),
+      RepositoryRulesetBypassActorBypassMode::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OrganizationRulesetConditionsItem1 {
@@ -4453,7 +6302,11 @@ pub enum OrganizationRulesetConditionsItem1 {
   RepositoryRulesetConditionsForRepositoryNames(RepositoryRulesetConditionsForRepositoryNames),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OrganizationRulesetConditionsItem2 {
@@ -4463,7 +6316,11 @@ pub enum OrganizationRulesetConditionsItem2 {
   RepositoryRulesetConditionsForRepositoryIDs(RepositoryRulesetConditionsForRepositoryIDs),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OrganizationRulesetConditionsItem3 {
@@ -4475,7 +6332,11 @@ pub enum OrganizationRulesetConditionsItem3 {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum OrganizationRulesetConditions {
@@ -4484,7 +6345,11 @@ pub enum OrganizationRulesetConditions {
   OrganizationRulesetConditionsItem3(OrganizationRulesetConditionsItem3),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum RepositoryRulesetConditions {
@@ -4493,7 +6358,11 @@ pub enum RepositoryRulesetConditions {
   OrganizationRulesetConditions(OrganizationRulesetConditions),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The bypass type of the user making the API request for this ruleset. This field is only returned when
 /// querying the repository-level endpoint.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -4504,20 +6373,36 @@ pub enum RepositoryRulesetCurrentUserCanBypass {
   PullRequestsOnly,
   #[serde(rename = "never")]
   Never,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRulesetCurrentUserCanBypass {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulesetCurrentUserCanBypass::Always => "always".to_string(),
       RepositoryRulesetCurrentUserCanBypass::PullRequestsOnly => "pull_requests_only".to_string(),
       RepositoryRulesetCurrentUserCanBypass::Never => "never".to_string(),
+      RepositoryRulesetCurrentUserCanBypass::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The enforcement level of the ruleset. `evaluate` allows admins to test rules before enforcing them. Admins can view insights on the Rule Insights page (`evaluate` is only available with GitHub Enterprise).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleEnforcement {
@@ -4527,170 +6412,330 @@ pub enum RepositoryRuleEnforcement {
   Active,
   #[serde(rename = "evaluate")]
   Evaluate,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleEnforcement {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleEnforcement::Disabled => "disabled".to_string(),
       RepositoryRuleEnforcement::Active => "active".to_string(),
       RepositoryRuleEnforcement::Evaluate => "evaluate".to_string(),
+      RepositoryRuleEnforcement::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CreationType {
   #[serde(rename = "creation")]
   Creation,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for CreationType {
   fn to_string(&self) -> String {
     match self {
       CreationType::Creation => "creation".to_string(),
+      CreationType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum UpdateType {
   #[serde(rename = "update")]
   Update,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for UpdateType {
   fn to_string(&self) -> String {
     match self {
       UpdateType::Update => "update".to_string(),
+      UpdateType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DeletionType {
   #[serde(rename = "deletion")]
   Deletion,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for DeletionType {
   fn to_string(&self) -> String {
     match self {
       DeletionType::Deletion => "deletion".to_string(),
+      DeletionType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleRequiredLinearHistoryType {
   #[serde(rename = "required_linear_history")]
   RequiredLinearHistory,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleRequiredLinearHistoryType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleRequiredLinearHistoryType::RequiredLinearHistory => {
         "required_linear_history".to_string()
       }
+      RepositoryRuleRequiredLinearHistoryType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleRequiredDeploymentsType {
   #[serde(rename = "required_deployments")]
   RequiredDeployments,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleRequiredDeploymentsType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleRequiredDeploymentsType::RequiredDeployments => {
         "required_deployments".to_string()
       }
+      RepositoryRuleRequiredDeploymentsType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleRequiredSignaturesType {
   #[serde(rename = "required_signatures")]
   RequiredSignatures,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleRequiredSignaturesType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleRequiredSignaturesType::RequiredSignatures => "required_signatures".to_string(),
+      RepositoryRuleRequiredSignaturesType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulePullRequestType {
   #[serde(rename = "pull_request")]
   PullRequest,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRulePullRequestType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulePullRequestType::PullRequest => "pull_request".to_string(),
+      RepositoryRulePullRequestType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleRequiredStatusChecksType {
   #[serde(rename = "required_status_checks")]
   RequiredStatusChecks,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleRequiredStatusChecksType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleRequiredStatusChecksType::RequiredStatusChecks => {
         "required_status_checks".to_string()
       }
+      RepositoryRuleRequiredStatusChecksType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleNonFastForwardType {
   #[serde(rename = "non_fast_forward")]
   NonFastForward,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleNonFastForwardType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleNonFastForwardType::NonFastForward => "non_fast_forward".to_string(),
+      RepositoryRuleNonFastForwardType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The operator to use for matching.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleCommitMessagePatternParametersOperator {
@@ -4702,9 +6747,20 @@ pub enum RepositoryRuleCommitMessagePatternParametersOperator {
   Contains,
   #[serde(rename = "regex")]
   Regex,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleCommitMessagePatternParametersOperator {
   fn to_string(&self) -> String {
     match self {
@@ -4712,29 +6768,50 @@ impl ToString for RepositoryRuleCommitMessagePatternParametersOperator {
       RepositoryRuleCommitMessagePatternParametersOperator::EndsWith => "ends_with".to_string(),
       RepositoryRuleCommitMessagePatternParametersOperator::Contains => "contains".to_string(),
       RepositoryRuleCommitMessagePatternParametersOperator::Regex => "regex".to_string(),
+      RepositoryRuleCommitMessagePatternParametersOperator::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleCommitMessagePatternType {
   #[serde(rename = "commit_message_pattern")]
   CommitMessagePattern,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleCommitMessagePatternType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleCommitMessagePatternType::CommitMessagePattern => {
         "commit_message_pattern".to_string()
       }
+      RepositoryRuleCommitMessagePatternType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The operator to use for matching.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleCommitAuthorEmailPatternParametersOperator {
@@ -4746,9 +6823,20 @@ pub enum RepositoryRuleCommitAuthorEmailPatternParametersOperator {
   Contains,
   #[serde(rename = "regex")]
   Regex,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleCommitAuthorEmailPatternParametersOperator {
   fn to_string(&self) -> String {
     match self {
@@ -4758,29 +6846,50 @@ impl ToString for RepositoryRuleCommitAuthorEmailPatternParametersOperator {
       RepositoryRuleCommitAuthorEmailPatternParametersOperator::EndsWith => "ends_with".to_string(),
       RepositoryRuleCommitAuthorEmailPatternParametersOperator::Contains => "contains".to_string(),
       RepositoryRuleCommitAuthorEmailPatternParametersOperator::Regex => "regex".to_string(),
+      RepositoryRuleCommitAuthorEmailPatternParametersOperator::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleCommitAuthorEmailPatternType {
   #[serde(rename = "commit_author_email_pattern")]
   CommitAuthorEmailPattern,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleCommitAuthorEmailPatternType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleCommitAuthorEmailPatternType::CommitAuthorEmailPattern => {
         "commit_author_email_pattern".to_string()
       }
+      RepositoryRuleCommitAuthorEmailPatternType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The operator to use for matching.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleCommitterEmailPatternParametersOperator {
@@ -4792,9 +6901,20 @@ pub enum RepositoryRuleCommitterEmailPatternParametersOperator {
   Contains,
   #[serde(rename = "regex")]
   Regex,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleCommitterEmailPatternParametersOperator {
   fn to_string(&self) -> String {
     match self {
@@ -4804,29 +6924,50 @@ impl ToString for RepositoryRuleCommitterEmailPatternParametersOperator {
       RepositoryRuleCommitterEmailPatternParametersOperator::EndsWith => "ends_with".to_string(),
       RepositoryRuleCommitterEmailPatternParametersOperator::Contains => "contains".to_string(),
       RepositoryRuleCommitterEmailPatternParametersOperator::Regex => "regex".to_string(),
+      RepositoryRuleCommitterEmailPatternParametersOperator::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleCommitterEmailPatternType {
   #[serde(rename = "committer_email_pattern")]
   CommitterEmailPattern,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleCommitterEmailPatternType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleCommitterEmailPatternType::CommitterEmailPattern => {
         "committer_email_pattern".to_string()
       }
+      RepositoryRuleCommitterEmailPatternType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The operator to use for matching.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleBranchNamePatternParametersOperator {
@@ -4838,9 +6979,20 @@ pub enum RepositoryRuleBranchNamePatternParametersOperator {
   Contains,
   #[serde(rename = "regex")]
   Regex,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleBranchNamePatternParametersOperator {
   fn to_string(&self) -> String {
     match self {
@@ -4848,27 +7000,48 @@ impl ToString for RepositoryRuleBranchNamePatternParametersOperator {
       RepositoryRuleBranchNamePatternParametersOperator::EndsWith => "ends_with".to_string(),
       RepositoryRuleBranchNamePatternParametersOperator::Contains => "contains".to_string(),
       RepositoryRuleBranchNamePatternParametersOperator::Regex => "regex".to_string(),
+      RepositoryRuleBranchNamePatternParametersOperator::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleBranchNamePatternType {
   #[serde(rename = "branch_name_pattern")]
   BranchNamePattern,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleBranchNamePatternType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleBranchNamePatternType::BranchNamePattern => "branch_name_pattern".to_string(),
+      RepositoryRuleBranchNamePatternType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The operator to use for matching.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleTagNamePatternParametersOperator {
@@ -4880,9 +7053,20 @@ pub enum RepositoryRuleTagNamePatternParametersOperator {
   Contains,
   #[serde(rename = "regex")]
   Regex,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleTagNamePatternParametersOperator {
   fn to_string(&self) -> String {
     match self {
@@ -4890,43 +7074,80 @@ impl ToString for RepositoryRuleTagNamePatternParametersOperator {
       RepositoryRuleTagNamePatternParametersOperator::EndsWith => "ends_with".to_string(),
       RepositoryRuleTagNamePatternParametersOperator::Contains => "contains".to_string(),
       RepositoryRuleTagNamePatternParametersOperator::Regex => "regex".to_string(),
+      RepositoryRuleTagNamePatternParametersOperator::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRuleTagNamePatternType {
   #[serde(rename = "tag_name_pattern")]
   TagNamePattern,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRuleTagNamePatternType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRuleTagNamePatternType::TagNamePattern => "tag_name_pattern".to_string(),
+      RepositoryRuleTagNamePatternType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WorkflowsType {
   #[serde(rename = "workflows")]
   Workflows,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for WorkflowsType {
   fn to_string(&self) -> String {
     match self {
       WorkflowsType::Workflows => "workflows".to_string(),
+      WorkflowsType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum RepositoryRule {
@@ -4962,25 +7183,45 @@ pub enum RepositoryRule {
   Workflows(Workflows),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The type of the source of the ruleset
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulesetSourceType {
   Repository,
   Organization,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRulesetSourceType {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulesetSourceType::Repository => "Repository".to_string(),
       RepositoryRulesetSourceType::Organization => "Organization".to_string(),
+      RepositoryRulesetSourceType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// The target of the ruleset
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulesetTarget {
@@ -4988,14 +7229,26 @@ pub enum RepositoryRulesetTarget {
   Branch,
   #[serde(rename = "tag")]
   Tag,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl ToString for RepositoryRulesetTarget {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulesetTarget::Branch => "branch".to_string(),
       RepositoryRulesetTarget::Tag => "tag".to_string(),
+      RepositoryRulesetTarget::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5008,6 +7261,13 @@ pub enum ReposCreateOrgRulesetRequestTarget {
   Branch,
   #[serde(rename = "tag")]
   Tag,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5016,6 +7276,7 @@ impl ToString for ReposCreateOrgRulesetRequestTarget {
     match self {
       ReposCreateOrgRulesetRequestTarget::Branch => "branch".to_string(),
       ReposCreateOrgRulesetRequestTarget::Tag => "tag".to_string(),
+      ReposCreateOrgRulesetRequestTarget::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5031,6 +7292,13 @@ pub enum ReposGetOrgRuleSuitesQueryTimePeriod {
   Week,
   #[serde(rename = "month")]
   Month,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5041,6 +7309,7 @@ impl ToString for ReposGetOrgRuleSuitesQueryTimePeriod {
       ReposGetOrgRuleSuitesQueryTimePeriod::Day => "day".to_string(),
       ReposGetOrgRuleSuitesQueryTimePeriod::Week => "week".to_string(),
       ReposGetOrgRuleSuitesQueryTimePeriod::Month => "month".to_string(),
+      ReposGetOrgRuleSuitesQueryTimePeriod::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5056,6 +7325,13 @@ pub enum ReposGetOrgRuleSuitesQueryRuleSuiteResult {
   Bypass,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5066,6 +7342,7 @@ impl ToString for ReposGetOrgRuleSuitesQueryRuleSuiteResult {
       ReposGetOrgRuleSuitesQueryRuleSuiteResult::Fail => "fail".to_string(),
       ReposGetOrgRuleSuitesQueryRuleSuiteResult::Bypass => "bypass".to_string(),
       ReposGetOrgRuleSuitesQueryRuleSuiteResult::All => "all".to_string(),
+      ReposGetOrgRuleSuitesQueryRuleSuiteResult::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5078,6 +7355,13 @@ pub enum RuleSuitesItemEvaluationResult {
   Pass,
   #[serde(rename = "fail")]
   Fail,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5086,6 +7370,7 @@ impl ToString for RuleSuitesItemEvaluationResult {
     match self {
       RuleSuitesItemEvaluationResult::Pass => "pass".to_string(),
       RuleSuitesItemEvaluationResult::Fail => "fail".to_string(),
+      RuleSuitesItemEvaluationResult::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5100,6 +7385,13 @@ pub enum RuleSuitesItemResult {
   Fail,
   #[serde(rename = "bypass")]
   Bypass,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5109,6 +7401,7 @@ impl ToString for RuleSuitesItemResult {
       RuleSuitesItemResult::Pass => "pass".to_string(),
       RuleSuitesItemResult::Fail => "fail".to_string(),
       RuleSuitesItemResult::Bypass => "bypass".to_string(),
+      RuleSuitesItemResult::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5121,6 +7414,13 @@ pub enum RuleSuiteEvaluationResult {
   Pass,
   #[serde(rename = "fail")]
   Fail,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5129,6 +7429,7 @@ impl ToString for RuleSuiteEvaluationResult {
     match self {
       RuleSuiteEvaluationResult::Pass => "pass".to_string(),
       RuleSuiteEvaluationResult::Fail => "fail".to_string(),
+      RuleSuiteEvaluationResult::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5143,6 +7444,13 @@ pub enum RuleSuiteResult {
   Fail,
   #[serde(rename = "bypass")]
   Bypass,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5152,6 +7460,7 @@ impl ToString for RuleSuiteResult {
       RuleSuiteResult::Pass => "pass".to_string(),
       RuleSuiteResult::Fail => "fail".to_string(),
       RuleSuiteResult::Bypass => "bypass".to_string(),
+      RuleSuiteResult::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5166,6 +7475,13 @@ pub enum RuleSuiteRuleEvaluationsEnforcement {
   Evaluate,
   #[serde(rename = "deleted ruleset")]
   DeletedRuleset,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5175,6 +7491,7 @@ impl ToString for RuleSuiteRuleEvaluationsEnforcement {
       RuleSuiteRuleEvaluationsEnforcement::Active => "active".to_string(),
       RuleSuiteRuleEvaluationsEnforcement::Evaluate => "evaluate".to_string(),
       RuleSuiteRuleEvaluationsEnforcement::DeletedRuleset => "deleted ruleset".to_string(),
+      RuleSuiteRuleEvaluationsEnforcement::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5187,6 +7504,13 @@ pub enum RuleSuiteRuleEvaluationsResult {
   Pass,
   #[serde(rename = "fail")]
   Fail,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5195,6 +7519,7 @@ impl ToString for RuleSuiteRuleEvaluationsResult {
     match self {
       RuleSuiteRuleEvaluationsResult::Pass => "pass".to_string(),
       RuleSuiteRuleEvaluationsResult::Fail => "fail".to_string(),
+      RuleSuiteRuleEvaluationsResult::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5207,6 +7532,13 @@ pub enum ReposUpdateOrgRulesetRequestTarget {
   Branch,
   #[serde(rename = "tag")]
   Tag,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -5215,6 +7547,7 @@ impl ToString for ReposUpdateOrgRulesetRequestTarget {
     match self {
       ReposUpdateOrgRulesetRequestTarget::Branch => "branch".to_string(),
       ReposUpdateOrgRulesetRequestTarget::Tag => "tag".to_string(),
+      ReposUpdateOrgRulesetRequestTarget::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5226,6 +7559,13 @@ pub enum SecretScanningListAlertsForOrgQueryState {
   Open,
   #[serde(rename = "resolved")]
   Resolved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -5234,6 +7574,7 @@ impl ToString for SecretScanningListAlertsForOrgQueryState {
     match self {
       SecretScanningListAlertsForOrgQueryState::Open => "open".to_string(),
       SecretScanningListAlertsForOrgQueryState::Resolved => "resolved".to_string(),
+      SecretScanningListAlertsForOrgQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5245,6 +7586,13 @@ pub enum SecretScanningListAlertsForOrgQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -5253,6 +7601,7 @@ impl ToString for SecretScanningListAlertsForOrgQuerySort {
     match self {
       SecretScanningListAlertsForOrgQuerySort::Created => "created".to_string(),
       SecretScanningListAlertsForOrgQuerySort::Updated => "updated".to_string(),
+      SecretScanningListAlertsForOrgQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5264,6 +7613,13 @@ pub enum SecretScanningListAlertsForOrgQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -5272,6 +7628,7 @@ impl ToString for SecretScanningListAlertsForOrgQueryDirection {
     match self {
       SecretScanningListAlertsForOrgQueryDirection::Asc => "asc".to_string(),
       SecretScanningListAlertsForOrgQueryDirection::Desc => "desc".to_string(),
+      SecretScanningListAlertsForOrgQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5283,6 +7640,13 @@ pub enum SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -5291,6 +7655,7 @@ impl ToString for SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryDirection {
     match self {
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryDirection::Asc => "asc".to_string(),
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryDirection::Desc => "desc".to_string(),
+      SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5304,6 +7669,13 @@ pub enum SecurityAdvisoriesListOrgRepositoryAdvisoriesQuerySort {
   Updated,
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -5313,6 +7685,7 @@ impl ToString for SecurityAdvisoriesListOrgRepositoryAdvisoriesQuerySort {
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQuerySort::Created => "created".to_string(),
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQuerySort::Updated => "updated".to_string(),
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQuerySort::Published => "published".to_string(),
+      SecurityAdvisoriesListOrgRepositoryAdvisoriesQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5328,6 +7701,13 @@ pub enum SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryState {
   Published,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -5338,11 +7718,16 @@ impl ToString for SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryState {
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryState::Draft => "draft".to_string(),
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryState::Published => "published".to_string(),
       SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryState::Closed => "closed".to_string(),
+      SecurityAdvisoriesListOrgRepositoryAdvisoriesQueryState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// The state of the user's acceptance of the credit.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryAdvisoryCreditState {
@@ -5352,20 +7737,36 @@ pub enum RepositoryAdvisoryCreditState {
   Declined,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl ToString for RepositoryAdvisoryCreditState {
   fn to_string(&self) -> String {
     match self {
       RepositoryAdvisoryCreditState::Accepted => "accepted".to_string(),
       RepositoryAdvisoryCreditState::Declined => "declined".to_string(),
       RepositoryAdvisoryCreditState::Pending => "pending".to_string(),
+      RepositoryAdvisoryCreditState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// The type of identifier.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryAdvisoryIdentifiersType {
@@ -5373,19 +7774,35 @@ pub enum RepositoryAdvisoryIdentifiersType {
   Cve,
   #[serde(rename = "GHSA")]
   Ghsa,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl ToString for RepositoryAdvisoryIdentifiersType {
   fn to_string(&self) -> String {
     match self {
       RepositoryAdvisoryIdentifiersType::Cve => "CVE".to_string(),
       RepositoryAdvisoryIdentifiersType::Ghsa => "GHSA".to_string(),
+      RepositoryAdvisoryIdentifiersType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// The severity of the advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryAdvisorySeverity {
@@ -5397,9 +7814,20 @@ pub enum RepositoryAdvisorySeverity {
   Medium,
   #[serde(rename = "low")]
   Low,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl ToString for RepositoryAdvisorySeverity {
   fn to_string(&self) -> String {
     match self {
@@ -5407,11 +7835,16 @@ impl ToString for RepositoryAdvisorySeverity {
       RepositoryAdvisorySeverity::High => "high".to_string(),
       RepositoryAdvisorySeverity::Medium => "medium".to_string(),
       RepositoryAdvisorySeverity::Low => "low".to_string(),
+      RepositoryAdvisorySeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// The state of the advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryAdvisoryState {
@@ -5425,9 +7858,20 @@ pub enum RepositoryAdvisoryState {
   Draft,
   #[serde(rename = "triage")]
   Triage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl ToString for RepositoryAdvisoryState {
   fn to_string(&self) -> String {
     match self {
@@ -5436,6 +7880,7 @@ impl ToString for RepositoryAdvisoryState {
       RepositoryAdvisoryState::Withdrawn => "withdrawn".to_string(),
       RepositoryAdvisoryState::Draft => "draft".to_string(),
       RepositoryAdvisoryState::Triage => "triage".to_string(),
+      RepositoryAdvisoryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5451,6 +7896,13 @@ pub enum TeamsCreateRequestNotificationSetting {
   NotificationsEnabled,
   #[serde(rename = "notifications_disabled")]
   NotificationsDisabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5463,6 +7915,7 @@ impl ToString for TeamsCreateRequestNotificationSetting {
       TeamsCreateRequestNotificationSetting::NotificationsDisabled => {
         "notifications_disabled".to_string()
       }
+      TeamsCreateRequestNotificationSetting::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5475,6 +7928,13 @@ pub enum TeamsCreateRequestPermission {
   Pull,
   #[serde(rename = "push")]
   Push,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5483,6 +7943,7 @@ impl ToString for TeamsCreateRequestPermission {
     match self {
       TeamsCreateRequestPermission::Pull => "pull".to_string(),
       TeamsCreateRequestPermission::Push => "push".to_string(),
+      TeamsCreateRequestPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5502,6 +7963,13 @@ pub enum TeamsCreateRequestPrivacy {
   Secret,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5510,6 +7978,7 @@ impl ToString for TeamsCreateRequestPrivacy {
     match self {
       TeamsCreateRequestPrivacy::Secret => "secret".to_string(),
       TeamsCreateRequestPrivacy::Closed => "closed".to_string(),
+      TeamsCreateRequestPrivacy::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5522,6 +7991,13 @@ pub enum FullTeamNotificationSetting {
   NotificationsEnabled,
   #[serde(rename = "notifications_disabled")]
   NotificationsDisabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5530,6 +8006,7 @@ impl ToString for FullTeamNotificationSetting {
     match self {
       FullTeamNotificationSetting::NotificationsEnabled => "notifications_enabled".to_string(),
       FullTeamNotificationSetting::NotificationsDisabled => "notifications_disabled".to_string(),
+      FullTeamNotificationSetting::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5542,6 +8019,13 @@ pub enum FullTeamPrivacy {
   Closed,
   #[serde(rename = "secret")]
   Secret,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5550,6 +8034,7 @@ impl ToString for FullTeamPrivacy {
     match self {
       FullTeamPrivacy::Closed => "closed".to_string(),
       FullTeamPrivacy::Secret => "secret".to_string(),
+      FullTeamPrivacy::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5564,6 +8049,13 @@ pub enum TeamsUpdateInOrgRequestNotificationSetting {
   NotificationsEnabled,
   #[serde(rename = "notifications_disabled")]
   NotificationsDisabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5576,6 +8068,7 @@ impl ToString for TeamsUpdateInOrgRequestNotificationSetting {
       TeamsUpdateInOrgRequestNotificationSetting::NotificationsDisabled => {
         "notifications_disabled".to_string()
       }
+      TeamsUpdateInOrgRequestNotificationSetting::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5590,6 +8083,13 @@ pub enum TeamsUpdateInOrgRequestPermission {
   Push,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5599,6 +8099,7 @@ impl ToString for TeamsUpdateInOrgRequestPermission {
       TeamsUpdateInOrgRequestPermission::Pull => "pull".to_string(),
       TeamsUpdateInOrgRequestPermission::Push => "push".to_string(),
       TeamsUpdateInOrgRequestPermission::Admin => "admin".to_string(),
+      TeamsUpdateInOrgRequestPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5616,6 +8117,13 @@ pub enum TeamsUpdateInOrgRequestPrivacy {
   Secret,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5624,6 +8132,7 @@ impl ToString for TeamsUpdateInOrgRequestPrivacy {
     match self {
       TeamsUpdateInOrgRequestPrivacy::Secret => "secret".to_string(),
       TeamsUpdateInOrgRequestPrivacy::Closed => "closed".to_string(),
+      TeamsUpdateInOrgRequestPrivacy::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5635,6 +8144,13 @@ pub enum TeamsListDiscussionsInOrgQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5643,6 +8159,7 @@ impl ToString for TeamsListDiscussionsInOrgQueryDirection {
     match self {
       TeamsListDiscussionsInOrgQueryDirection::Asc => "asc".to_string(),
       TeamsListDiscussionsInOrgQueryDirection::Desc => "desc".to_string(),
+      TeamsListDiscussionsInOrgQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5654,6 +8171,13 @@ pub enum TeamsListDiscussionCommentsInOrgQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5662,6 +8186,7 @@ impl ToString for TeamsListDiscussionCommentsInOrgQueryDirection {
     match self {
       TeamsListDiscussionCommentsInOrgQueryDirection::Asc => "asc".to_string(),
       TeamsListDiscussionCommentsInOrgQueryDirection::Desc => "desc".to_string(),
+      TeamsListDiscussionCommentsInOrgQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5685,6 +8210,13 @@ pub enum ReactionsListForTeamDiscussionCommentInOrgQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -5699,6 +8231,7 @@ impl ToString for ReactionsListForTeamDiscussionCommentInOrgQueryContent {
       ReactionsListForTeamDiscussionCommentInOrgQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForTeamDiscussionCommentInOrgQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForTeamDiscussionCommentInOrgQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForTeamDiscussionCommentInOrgQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5723,6 +8256,13 @@ pub enum ReactionContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -5737,6 +8277,7 @@ impl ToString for ReactionContent {
       ReactionContent::Hooray => "hooray".to_string(),
       ReactionContent::Rocket => "rocket".to_string(),
       ReactionContent::Eyes => "eyes".to_string(),
+      ReactionContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5761,6 +8302,13 @@ pub enum ReactionsCreateForTeamDiscussionCommentInOrgRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -5777,6 +8325,7 @@ impl ToString for ReactionsCreateForTeamDiscussionCommentInOrgRequestContent {
       ReactionsCreateForTeamDiscussionCommentInOrgRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForTeamDiscussionCommentInOrgRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForTeamDiscussionCommentInOrgRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForTeamDiscussionCommentInOrgRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5800,6 +8349,13 @@ pub enum ReactionsListForTeamDiscussionInOrgQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -5814,6 +8370,7 @@ impl ToString for ReactionsListForTeamDiscussionInOrgQueryContent {
       ReactionsListForTeamDiscussionInOrgQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForTeamDiscussionInOrgQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForTeamDiscussionInOrgQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForTeamDiscussionInOrgQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5838,6 +8395,13 @@ pub enum ReactionsCreateForTeamDiscussionInOrgRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -5852,6 +8416,7 @@ impl ToString for ReactionsCreateForTeamDiscussionInOrgRequestContent {
       ReactionsCreateForTeamDiscussionInOrgRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForTeamDiscussionInOrgRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForTeamDiscussionInOrgRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForTeamDiscussionInOrgRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5865,6 +8430,13 @@ pub enum TeamsListMembersInOrgQueryRole {
   Maintainer,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5874,6 +8446,7 @@ impl ToString for TeamsListMembersInOrgQueryRole {
       TeamsListMembersInOrgQueryRole::Member => "member".to_string(),
       TeamsListMembersInOrgQueryRole::Maintainer => "maintainer".to_string(),
       TeamsListMembersInOrgQueryRole::All => "all".to_string(),
+      TeamsListMembersInOrgQueryRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5886,6 +8459,13 @@ pub enum TeamMembershipRole {
   Member,
   #[serde(rename = "maintainer")]
   Maintainer,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5894,6 +8474,7 @@ impl ToString for TeamMembershipRole {
     match self {
       TeamMembershipRole::Member => "member".to_string(),
       TeamMembershipRole::Maintainer => "maintainer".to_string(),
+      TeamMembershipRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5906,6 +8487,13 @@ pub enum TeamMembershipState {
   Active,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5914,6 +8502,7 @@ impl ToString for TeamMembershipState {
     match self {
       TeamMembershipState::Active => "active".to_string(),
       TeamMembershipState::Pending => "pending".to_string(),
+      TeamMembershipState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5926,6 +8515,13 @@ pub enum TeamsAddOrUpdateMembershipForUserInOrgRequestRole {
   Member,
   #[serde(rename = "maintainer")]
   Maintainer,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5934,6 +8530,7 @@ impl ToString for TeamsAddOrUpdateMembershipForUserInOrgRequestRole {
     match self {
       TeamsAddOrUpdateMembershipForUserInOrgRequestRole::Member => "member".to_string(),
       TeamsAddOrUpdateMembershipForUserInOrgRequestRole::Maintainer => "maintainer".to_string(),
+      TeamsAddOrUpdateMembershipForUserInOrgRequestRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5948,6 +8545,13 @@ pub enum TeamsAddOrUpdateProjectPermissionsInOrgRequestPermission {
   Write,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -5957,6 +8561,7 @@ impl ToString for TeamsAddOrUpdateProjectPermissionsInOrgRequestPermission {
       TeamsAddOrUpdateProjectPermissionsInOrgRequestPermission::Read => "read".to_string(),
       TeamsAddOrUpdateProjectPermissionsInOrgRequestPermission::Write => "write".to_string(),
       TeamsAddOrUpdateProjectPermissionsInOrgRequestPermission::Admin => "admin".to_string(),
+      TeamsAddOrUpdateProjectPermissionsInOrgRequestPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5978,6 +8583,13 @@ pub enum OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersSecurityProduc
   SecretScanning,
   #[serde(rename = "secret_scanning_push_protection")]
   SecretScanningPushProtection,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -5991,6 +8603,7 @@ impl ToString for OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersSecur
       OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersSecurityProduct::CodeScanningDefaultSetup => "code_scanning_default_setup".to_string(),
       OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersSecurityProduct::SecretScanning => "secret_scanning".to_string(),
       OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersSecurityProduct::SecretScanningPushProtection => "secret_scanning_push_protection".to_string(),
+      OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersSecurityProduct::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6002,6 +8615,13 @@ pub enum OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersEnablement {
   EnableAll,
   #[serde(rename = "disable_all")]
   DisableAll,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -6014,6 +8634,9 @@ impl ToString for OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersEnabl
       OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersEnablement::DisableAll => {
         "disable_all".to_string()
       }
+      OrgsEnableOrDisableSecurityProductOnAllOrgReposParametersEnablement::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -6027,6 +8650,13 @@ pub enum OrgsEnableOrDisableSecurityProductOnAllOrgReposRequestQuerySuite {
   Default,
   #[serde(rename = "extended")]
   Extended,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -6039,6 +8669,9 @@ impl ToString for OrgsEnableOrDisableSecurityProductOnAllOrgReposRequestQuerySui
       OrgsEnableOrDisableSecurityProductOnAllOrgReposRequestQuerySuite::Extended => {
         "extended".to_string()
       }
+      OrgsEnableOrDisableSecurityProductOnAllOrgReposRequestQuerySuite::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -6052,6 +8685,13 @@ pub enum ProjectsListCardsQueryArchivedState {
   Archived,
   #[serde(rename = "not_archived")]
   NotArchived,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "projects",))]
@@ -6061,6 +8701,7 @@ impl ToString for ProjectsListCardsQueryArchivedState {
       ProjectsListCardsQueryArchivedState::All => "all".to_string(),
       ProjectsListCardsQueryArchivedState::Archived => "archived".to_string(),
       ProjectsListCardsQueryArchivedState::NotArchived => "not_archived".to_string(),
+      ProjectsListCardsQueryArchivedState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6085,6 +8726,13 @@ pub enum ProjectsUpdateRequestOrganizationPermission {
   Admin,
   #[serde(rename = "none")]
   None,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "projects",))]
@@ -6095,6 +8743,7 @@ impl ToString for ProjectsUpdateRequestOrganizationPermission {
       ProjectsUpdateRequestOrganizationPermission::Write => "write".to_string(),
       ProjectsUpdateRequestOrganizationPermission::Admin => "admin".to_string(),
       ProjectsUpdateRequestOrganizationPermission::None => "none".to_string(),
+      ProjectsUpdateRequestOrganizationPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6108,6 +8757,13 @@ pub enum ProjectsListCollaboratorsQueryAffiliation {
   Direct,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "projects",))]
@@ -6117,6 +8773,7 @@ impl ToString for ProjectsListCollaboratorsQueryAffiliation {
       ProjectsListCollaboratorsQueryAffiliation::Outside => "outside".to_string(),
       ProjectsListCollaboratorsQueryAffiliation::Direct => "direct".to_string(),
       ProjectsListCollaboratorsQueryAffiliation::All => "all".to_string(),
+      ProjectsListCollaboratorsQueryAffiliation::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6131,6 +8788,13 @@ pub enum ProjectsAddCollaboratorRequestPermission {
   Write,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "projects",))]
@@ -6140,6 +8804,7 @@ impl ToString for ProjectsAddCollaboratorRequestPermission {
       ProjectsAddCollaboratorRequestPermission::Read => "read".to_string(),
       ProjectsAddCollaboratorRequestPermission::Write => "write".to_string(),
       ProjectsAddCollaboratorRequestPermission::Admin => "admin".to_string(),
+      ProjectsAddCollaboratorRequestPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6158,6 +8823,13 @@ pub enum ReposUpdateRequestMergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6167,6 +8839,7 @@ impl ToString for ReposUpdateRequestMergeCommitMessage {
       ReposUpdateRequestMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       ReposUpdateRequestMergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       ReposUpdateRequestMergeCommitMessage::Blank => "BLANK".to_string(),
+      ReposUpdateRequestMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6182,6 +8855,13 @@ pub enum ReposUpdateRequestMergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6190,6 +8870,7 @@ impl ToString for ReposUpdateRequestMergeCommitTitle {
     match self {
       ReposUpdateRequestMergeCommitTitle::PrTitle => "PR_TITLE".to_string(),
       ReposUpdateRequestMergeCommitTitle::MergeMessage => "MERGE_MESSAGE".to_string(),
+      ReposUpdateRequestMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6208,6 +8889,13 @@ pub enum ReposUpdateRequestSquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6217,6 +8905,7 @@ impl ToString for ReposUpdateRequestSquashMergeCommitMessage {
       ReposUpdateRequestSquashMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       ReposUpdateRequestSquashMergeCommitMessage::CommitMessages => "COMMIT_MESSAGES".to_string(),
       ReposUpdateRequestSquashMergeCommitMessage::Blank => "BLANK".to_string(),
+      ReposUpdateRequestSquashMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6232,6 +8921,13 @@ pub enum ReposUpdateRequestSquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6240,6 +8936,7 @@ impl ToString for ReposUpdateRequestSquashMergeCommitTitle {
     match self {
       ReposUpdateRequestSquashMergeCommitTitle::PrTitle => "PR_TITLE".to_string(),
       ReposUpdateRequestSquashMergeCommitTitle::CommitOrPrTitle => "COMMIT_OR_PR_TITLE".to_string(),
+      ReposUpdateRequestSquashMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6252,6 +8949,13 @@ pub enum ReposUpdateRequestVisibility {
   Public,
   #[serde(rename = "private")]
   Private,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6260,6 +8964,7 @@ impl ToString for ReposUpdateRequestVisibility {
     match self {
       ReposUpdateRequestVisibility::Public => "public".to_string(),
       ReposUpdateRequestVisibility::Private => "private".to_string(),
+      ReposUpdateRequestVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6273,6 +8978,13 @@ pub enum ActionsGetActionsCacheListQuerySort {
   LastAccessedAt,
   #[serde(rename = "size_in_bytes")]
   SizeInBytes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6282,6 +8994,7 @@ impl ToString for ActionsGetActionsCacheListQuerySort {
       ActionsGetActionsCacheListQuerySort::CreatedAt => "created_at".to_string(),
       ActionsGetActionsCacheListQuerySort::LastAccessedAt => "last_accessed_at".to_string(),
       ActionsGetActionsCacheListQuerySort::SizeInBytes => "size_in_bytes".to_string(),
+      ActionsGetActionsCacheListQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6293,6 +9006,13 @@ pub enum ActionsGetActionsCacheListQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6301,6 +9021,7 @@ impl ToString for ActionsGetActionsCacheListQueryDirection {
     match self {
       ActionsGetActionsCacheListQueryDirection::Asc => "asc".to_string(),
       ActionsGetActionsCacheListQueryDirection::Desc => "desc".to_string(),
+      ActionsGetActionsCacheListQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6323,6 +9044,13 @@ pub enum JobConclusion {
   TimedOut,
   #[serde(rename = "action_required")]
   ActionRequired,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6336,6 +9064,7 @@ impl ToString for JobConclusion {
       JobConclusion::Skipped => "skipped".to_string(),
       JobConclusion::TimedOut => "timed_out".to_string(),
       JobConclusion::ActionRequired => "action_required".to_string(),
+      JobConclusion::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6356,6 +9085,13 @@ pub enum JobStatus {
   Requested,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6368,6 +9104,7 @@ impl ToString for JobStatus {
       JobStatus::Waiting => "waiting".to_string(),
       JobStatus::Requested => "requested".to_string(),
       JobStatus::Pending => "pending".to_string(),
+      JobStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6382,6 +9119,13 @@ pub enum JobStepsStatus {
   InProgress,
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6391,6 +9135,7 @@ impl ToString for JobStepsStatus {
       JobStepsStatus::Queued => "queued".to_string(),
       JobStepsStatus::InProgress => "in_progress".to_string(),
       JobStepsStatus::Completed => "completed".to_string(),
+      JobStepsStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6408,6 +9153,13 @@ pub enum ActionsWorkflowAccessToRepositoryAccessLevel {
   User,
   #[serde(rename = "organization")]
   Organization,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6417,6 +9169,7 @@ impl ToString for ActionsWorkflowAccessToRepositoryAccessLevel {
       ActionsWorkflowAccessToRepositoryAccessLevel::None => "none".to_string(),
       ActionsWorkflowAccessToRepositoryAccessLevel::User => "user".to_string(),
       ActionsWorkflowAccessToRepositoryAccessLevel::Organization => "organization".to_string(),
+      ActionsWorkflowAccessToRepositoryAccessLevel::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6452,6 +9205,13 @@ pub enum ActionsListWorkflowRunsForRepoQueryStatus {
   Waiting,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6472,6 +9232,7 @@ impl ToString for ActionsListWorkflowRunsForRepoQueryStatus {
       ActionsListWorkflowRunsForRepoQueryStatus::Requested => "requested".to_string(),
       ActionsListWorkflowRunsForRepoQueryStatus::Waiting => "waiting".to_string(),
       ActionsListWorkflowRunsForRepoQueryStatus::Pending => "pending".to_string(),
+      ActionsListWorkflowRunsForRepoQueryStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6486,6 +9247,13 @@ pub enum EnvironmentApprovalState {
   Rejected,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6495,6 +9263,7 @@ impl ToString for EnvironmentApprovalState {
       EnvironmentApprovalState::Approved => "approved".to_string(),
       EnvironmentApprovalState::Rejected => "rejected".to_string(),
       EnvironmentApprovalState::Pending => "pending".to_string(),
+      EnvironmentApprovalState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6507,6 +9276,13 @@ pub enum ReviewCustomGatesStateRequiredState {
   Approved,
   #[serde(rename = "rejected")]
   Rejected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6515,6 +9291,7 @@ impl ToString for ReviewCustomGatesStateRequiredState {
     match self {
       ReviewCustomGatesStateRequiredState::Approved => "approved".to_string(),
       ReviewCustomGatesStateRequiredState::Rejected => "rejected".to_string(),
+      ReviewCustomGatesStateRequiredState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6534,6 +9311,13 @@ pub enum ActionsListJobsForWorkflowRunQueryFilter {
   Latest,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6542,6 +9326,7 @@ impl ToString for ActionsListJobsForWorkflowRunQueryFilter {
     match self {
       ActionsListJobsForWorkflowRunQueryFilter::Latest => "latest".to_string(),
       ActionsListJobsForWorkflowRunQueryFilter::All => "all".to_string(),
+      ActionsListJobsForWorkflowRunQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6562,6 +9347,13 @@ pub enum PendingDeploymentReviewersReviewer {
 pub enum DeploymentReviewerType {
   User,
   Team,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions", feature = "repos",))]
@@ -6570,6 +9362,7 @@ impl ToString for DeploymentReviewerType {
     match self {
       DeploymentReviewerType::User => "User".to_string(),
       DeploymentReviewerType::Team => "Team".to_string(),
+      DeploymentReviewerType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6582,6 +9375,13 @@ pub enum ActionsReviewPendingDeploymentsForRunRequestState {
   Approved,
   #[serde(rename = "rejected")]
   Rejected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6590,11 +9390,18 @@ impl ToString for ActionsReviewPendingDeploymentsForRunRequestState {
     match self {
       ActionsReviewPendingDeploymentsForRunRequestState::Approved => "approved".to_string(),
       ActionsReviewPendingDeploymentsForRunRequestState::Rejected => "rejected".to_string(),
+      ActionsReviewPendingDeploymentsForRunRequestState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_status",
+  feature = "webhook_workflow_run",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WorkflowState {
   #[serde(rename = "active")]
@@ -6607,9 +9414,22 @@ pub enum WorkflowState {
   DisabledInactivity,
   #[serde(rename = "disabled_manually")]
   DisabledManually,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_status",
+  feature = "webhook_workflow_run",
+))]
 impl ToString for WorkflowState {
   fn to_string(&self) -> String {
     match self {
@@ -6618,6 +9438,7 @@ impl ToString for WorkflowState {
       WorkflowState::DisabledFork => "disabled_fork".to_string(),
       WorkflowState::DisabledInactivity => "disabled_inactivity".to_string(),
       WorkflowState::DisabledManually => "disabled_manually".to_string(),
+      WorkflowState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6653,6 +9474,13 @@ pub enum ActionsListWorkflowRunsQueryStatus {
   Waiting,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -6673,6 +9501,7 @@ impl ToString for ActionsListWorkflowRunsQueryStatus {
       ActionsListWorkflowRunsQueryStatus::Requested => "requested".to_string(),
       ActionsListWorkflowRunsQueryStatus::Waiting => "waiting".to_string(),
       ActionsListWorkflowRunsQueryStatus::Pending => "pending".to_string(),
+      ActionsListWorkflowRunsQueryStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6684,6 +9513,13 @@ pub enum ReposListActivitiesQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6692,6 +9528,7 @@ impl ToString for ReposListActivitiesQueryDirection {
     match self {
       ReposListActivitiesQueryDirection::Asc => "asc".to_string(),
       ReposListActivitiesQueryDirection::Desc => "desc".to_string(),
+      ReposListActivitiesQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6709,6 +9546,13 @@ pub enum ReposListActivitiesQueryTimePeriod {
   Quarter,
   #[serde(rename = "year")]
   Year,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6720,6 +9564,7 @@ impl ToString for ReposListActivitiesQueryTimePeriod {
       ReposListActivitiesQueryTimePeriod::Month => "month".to_string(),
       ReposListActivitiesQueryTimePeriod::Quarter => "quarter".to_string(),
       ReposListActivitiesQueryTimePeriod::Year => "year".to_string(),
+      ReposListActivitiesQueryTimePeriod::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6739,6 +9584,13 @@ pub enum ReposListActivitiesQueryActivityType {
   PrMerge,
   #[serde(rename = "merge_queue_merge")]
   MergeQueueMerge,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6751,6 +9603,7 @@ impl ToString for ReposListActivitiesQueryActivityType {
       ReposListActivitiesQueryActivityType::BranchDeletion => "branch_deletion".to_string(),
       ReposListActivitiesQueryActivityType::PrMerge => "pr_merge".to_string(),
       ReposListActivitiesQueryActivityType::MergeQueueMerge => "merge_queue_merge".to_string(),
+      ReposListActivitiesQueryActivityType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6771,6 +9624,13 @@ pub enum ActivityActivityType {
   PrMerge,
   #[serde(rename = "merge_queue_merge")]
   MergeQueueMerge,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -6783,6 +9643,7 @@ impl ToString for ActivityActivityType {
       ActivityActivityType::BranchCreation => "branch_creation".to_string(),
       ActivityActivityType::PrMerge => "pr_merge".to_string(),
       ActivityActivityType::MergeQueueMerge => "merge_queue_merge".to_string(),
+      ActivityActivityType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6791,7 +9652,7 @@ impl ToString for ActivityActivityType {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiffEntryStatus {
@@ -6809,13 +9670,20 @@ pub enum DiffEntryStatus {
   Changed,
   #[serde(rename = "unchanged")]
   Unchanged,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl ToString for DiffEntryStatus {
   fn to_string(&self) -> String {
@@ -6827,6 +9695,7 @@ impl ToString for DiffEntryStatus {
       DiffEntryStatus::Copied => "copied".to_string(),
       DiffEntryStatus::Changed => "changed".to_string(),
       DiffEntryStatus::Unchanged => "unchanged".to_string(),
+      DiffEntryStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6932,6 +9801,13 @@ pub enum ReposRemoveUserAccessRestrictionsRequest {
 pub enum ChecksCreateRequestItem1Status {
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -6939,6 +9815,7 @@ impl ToString for ChecksCreateRequestItem1Status {
   fn to_string(&self) -> String {
     match self {
       ChecksCreateRequestItem1Status::Completed => "completed".to_string(),
+      ChecksCreateRequestItem1Status::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6950,6 +9827,13 @@ pub enum ChecksCreateRequestItem2Status {
   Queued,
   #[serde(rename = "in_progress")]
   InProgress,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -6958,6 +9842,7 @@ impl ToString for ChecksCreateRequestItem2Status {
     match self {
       ChecksCreateRequestItem2Status::Queued => "queued".to_string(),
       ChecksCreateRequestItem2Status::InProgress => "in_progress".to_string(),
+      ChecksCreateRequestItem2Status::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6970,7 +9855,7 @@ pub enum ChecksCreateRequest {
   ChecksCreateRequestItem2(ChecksCreateRequestItem2),
 }
 
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CheckRunConclusion {
   #[serde(rename = "success")]
@@ -6987,9 +9872,16 @@ pub enum CheckRunConclusion {
   TimedOut,
   #[serde(rename = "action_required")]
   ActionRequired,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 impl ToString for CheckRunConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -7000,11 +9892,12 @@ impl ToString for CheckRunConclusion {
       CheckRunConclusion::Skipped => "skipped".to_string(),
       CheckRunConclusion::TimedOut => "timed_out".to_string(),
       CheckRunConclusion::ActionRequired => "action_required".to_string(),
+      CheckRunConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 /// The phase of the lifecycle that the check is currently in. Statuses of waiting, requested, and pending are reserved for GitHub Actions check runs.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CheckRunStatus {
@@ -7020,9 +9913,16 @@ pub enum CheckRunStatus {
   Requested,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 impl ToString for CheckRunStatus {
   fn to_string(&self) -> String {
     match self {
@@ -7032,6 +9932,7 @@ impl ToString for CheckRunStatus {
       CheckRunStatus::Waiting => "waiting".to_string(),
       CheckRunStatus::Requested => "requested".to_string(),
       CheckRunStatus::Pending => "pending".to_string(),
+      CheckRunStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7041,6 +9942,13 @@ impl ToString for CheckRunStatus {
 pub enum ChecksUpdateRequestItem1Status {
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7048,6 +9956,7 @@ impl ToString for ChecksUpdateRequestItem1Status {
   fn to_string(&self) -> String {
     match self {
       ChecksUpdateRequestItem1Status::Completed => "completed".to_string(),
+      ChecksUpdateRequestItem1Status::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7059,6 +9968,13 @@ pub enum ChecksUpdateRequestItem2Status {
   Queued,
   #[serde(rename = "in_progress")]
   InProgress,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7067,6 +9983,7 @@ impl ToString for ChecksUpdateRequestItem2Status {
     match self {
       ChecksUpdateRequestItem2Status::Queued => "queued".to_string(),
       ChecksUpdateRequestItem2Status::InProgress => "in_progress".to_string(),
+      ChecksUpdateRequestItem2Status::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7100,6 +10017,13 @@ pub enum CheckSuiteConclusion {
   StartupFailure,
   #[serde(rename = "stale")]
   Stale,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7115,6 +10039,7 @@ impl ToString for CheckSuiteConclusion {
       CheckSuiteConclusion::ActionRequired => "action_required".to_string(),
       CheckSuiteConclusion::StartupFailure => "startup_failure".to_string(),
       CheckSuiteConclusion::Stale => "stale".to_string(),
+      CheckSuiteConclusion::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7135,6 +10060,13 @@ pub enum CheckSuiteStatus {
   Requested,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7147,6 +10079,7 @@ impl ToString for CheckSuiteStatus {
       CheckSuiteStatus::Waiting => "waiting".to_string(),
       CheckSuiteStatus::Requested => "requested".to_string(),
       CheckSuiteStatus::Pending => "pending".to_string(),
+      CheckSuiteStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7160,6 +10093,13 @@ pub enum ChecksListForSuiteQueryStatus {
   InProgress,
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7169,6 +10109,7 @@ impl ToString for ChecksListForSuiteQueryStatus {
       ChecksListForSuiteQueryStatus::Queued => "queued".to_string(),
       ChecksListForSuiteQueryStatus::InProgress => "in_progress".to_string(),
       ChecksListForSuiteQueryStatus::Completed => "completed".to_string(),
+      ChecksListForSuiteQueryStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7180,6 +10121,13 @@ pub enum ChecksListForSuiteQueryFilter {
   Latest,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7188,6 +10136,7 @@ impl ToString for ChecksListForSuiteQueryFilter {
     match self {
       ChecksListForSuiteQueryFilter::Latest => "latest".to_string(),
       ChecksListForSuiteQueryFilter::All => "all".to_string(),
+      ChecksListForSuiteQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7199,6 +10148,13 @@ pub enum CodeScanningListAlertsForRepoQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7207,6 +10163,7 @@ impl ToString for CodeScanningListAlertsForRepoQueryDirection {
     match self {
       CodeScanningListAlertsForRepoQueryDirection::Asc => "asc".to_string(),
       CodeScanningListAlertsForRepoQueryDirection::Desc => "desc".to_string(),
+      CodeScanningListAlertsForRepoQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7218,6 +10175,13 @@ pub enum CodeScanningListAlertsForRepoQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7226,6 +10190,7 @@ impl ToString for CodeScanningListAlertsForRepoQuerySort {
     match self {
       CodeScanningListAlertsForRepoQuerySort::Created => "created".to_string(),
       CodeScanningListAlertsForRepoQuerySort::Updated => "updated".to_string(),
+      CodeScanningListAlertsForRepoQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7242,6 +10207,13 @@ pub enum CodeScanningAlertRuleSecuritySeverityLevel {
   High,
   #[serde(rename = "critical")]
   Critical,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7252,6 +10224,7 @@ impl ToString for CodeScanningAlertRuleSecuritySeverityLevel {
       CodeScanningAlertRuleSecuritySeverityLevel::Medium => "medium".to_string(),
       CodeScanningAlertRuleSecuritySeverityLevel::High => "high".to_string(),
       CodeScanningAlertRuleSecuritySeverityLevel::Critical => "critical".to_string(),
+      CodeScanningAlertRuleSecuritySeverityLevel::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7268,6 +10241,13 @@ pub enum CodeScanningAlertRuleSeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7278,6 +10258,7 @@ impl ToString for CodeScanningAlertRuleSeverity {
       CodeScanningAlertRuleSeverity::Note => "note".to_string(),
       CodeScanningAlertRuleSeverity::Warning => "warning".to_string(),
       CodeScanningAlertRuleSeverity::Error => "error".to_string(),
+      CodeScanningAlertRuleSeverity::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7290,6 +10271,13 @@ pub enum CodeScanningAlertSetState {
   Open,
   #[serde(rename = "dismissed")]
   Dismissed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7298,6 +10286,7 @@ impl ToString for CodeScanningAlertSetState {
     match self {
       CodeScanningAlertSetState::Open => "open".to_string(),
       CodeScanningAlertSetState::Dismissed => "dismissed".to_string(),
+      CodeScanningAlertSetState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7309,6 +10298,13 @@ pub enum CodeScanningListRecentAnalysesQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7317,6 +10313,7 @@ impl ToString for CodeScanningListRecentAnalysesQueryDirection {
     match self {
       CodeScanningListRecentAnalysesQueryDirection::Asc => "asc".to_string(),
       CodeScanningListRecentAnalysesQueryDirection::Desc => "desc".to_string(),
+      CodeScanningListRecentAnalysesQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7326,6 +10323,13 @@ impl ToString for CodeScanningListRecentAnalysesQueryDirection {
 pub enum CodeScanningListRecentAnalysesQuerySort {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7333,6 +10337,7 @@ impl ToString for CodeScanningListRecentAnalysesQuerySort {
   fn to_string(&self) -> String {
     match self {
       CodeScanningListRecentAnalysesQuerySort::Created => "created".to_string(),
+      CodeScanningListRecentAnalysesQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7360,6 +10365,13 @@ pub enum CodeScanningDefaultSetupLanguages {
   Typescript,
   #[serde(rename = "swift")]
   Swift,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7378,6 +10390,7 @@ impl ToString for CodeScanningDefaultSetupLanguages {
       CodeScanningDefaultSetupLanguages::Ruby => "ruby".to_string(),
       CodeScanningDefaultSetupLanguages::Typescript => "typescript".to_string(),
       CodeScanningDefaultSetupLanguages::Swift => "swift".to_string(),
+      CodeScanningDefaultSetupLanguages::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7390,6 +10403,13 @@ pub enum CodeScanningDefaultSetupQuerySuite {
   Default,
   #[serde(rename = "extended")]
   Extended,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7398,6 +10418,7 @@ impl ToString for CodeScanningDefaultSetupQuerySuite {
     match self {
       CodeScanningDefaultSetupQuerySuite::Default => "default".to_string(),
       CodeScanningDefaultSetupQuerySuite::Extended => "extended".to_string(),
+      CodeScanningDefaultSetupQuerySuite::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7408,6 +10429,13 @@ impl ToString for CodeScanningDefaultSetupQuerySuite {
 pub enum CodeScanningDefaultSetupSchedule {
   #[serde(rename = "weekly")]
   Weekly,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7415,6 +10443,7 @@ impl ToString for CodeScanningDefaultSetupSchedule {
   fn to_string(&self) -> String {
     match self {
       CodeScanningDefaultSetupSchedule::Weekly => "weekly".to_string(),
+      CodeScanningDefaultSetupSchedule::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7427,6 +10456,13 @@ pub enum CodeScanningDefaultSetupState {
   Configured,
   #[serde(rename = "not-configured")]
   NotConfigured,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7435,6 +10471,7 @@ impl ToString for CodeScanningDefaultSetupState {
     match self {
       CodeScanningDefaultSetupState::Configured => "configured".to_string(),
       CodeScanningDefaultSetupState::NotConfigured => "not-configured".to_string(),
+      CodeScanningDefaultSetupState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7458,6 +10495,13 @@ pub enum CodeScanningDefaultSetupUpdateLanguages {
   Ruby,
   #[serde(rename = "swift")]
   Swift,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7474,6 +10518,7 @@ impl ToString for CodeScanningDefaultSetupUpdateLanguages {
       CodeScanningDefaultSetupUpdateLanguages::Python => "python".to_string(),
       CodeScanningDefaultSetupUpdateLanguages::Ruby => "ruby".to_string(),
       CodeScanningDefaultSetupUpdateLanguages::Swift => "swift".to_string(),
+      CodeScanningDefaultSetupUpdateLanguages::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7486,6 +10531,13 @@ pub enum CodeScanningDefaultSetupUpdateQuerySuite {
   Default,
   #[serde(rename = "extended")]
   Extended,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7494,6 +10546,7 @@ impl ToString for CodeScanningDefaultSetupUpdateQuerySuite {
     match self {
       CodeScanningDefaultSetupUpdateQuerySuite::Default => "default".to_string(),
       CodeScanningDefaultSetupUpdateQuerySuite::Extended => "extended".to_string(),
+      CodeScanningDefaultSetupUpdateQuerySuite::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7506,6 +10559,13 @@ pub enum CodeScanningDefaultSetupUpdateState {
   Configured,
   #[serde(rename = "not-configured")]
   NotConfigured,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7514,6 +10574,7 @@ impl ToString for CodeScanningDefaultSetupUpdateState {
     match self {
       CodeScanningDefaultSetupUpdateState::Configured => "configured".to_string(),
       CodeScanningDefaultSetupUpdateState::NotConfigured => "not-configured".to_string(),
+      CodeScanningDefaultSetupUpdateState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7535,6 +10596,13 @@ pub enum CodeScanningSarifsStatusProcessingStatus {
   Complete,
   #[serde(rename = "failed")]
   Failed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -7544,6 +10612,7 @@ impl ToString for CodeScanningSarifsStatusProcessingStatus {
       CodeScanningSarifsStatusProcessingStatus::Pending => "pending".to_string(),
       CodeScanningSarifsStatusProcessingStatus::Complete => "complete".to_string(),
       CodeScanningSarifsStatusProcessingStatus::Failed => "failed".to_string(),
+      CodeScanningSarifsStatusProcessingStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7556,6 +10625,13 @@ pub enum CodespacesCreateWithRepoForAuthenticatedUserRequestGeo {
   SoutheastAsia,
   UsEast,
   UsWest,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -7570,6 +10646,7 @@ impl ToString for CodespacesCreateWithRepoForAuthenticatedUserRequestGeo {
       }
       CodespacesCreateWithRepoForAuthenticatedUserRequestGeo::UsEast => "UsEast".to_string(),
       CodespacesCreateWithRepoForAuthenticatedUserRequestGeo::UsWest => "UsWest".to_string(),
+      CodespacesCreateWithRepoForAuthenticatedUserRequestGeo::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7583,6 +10660,13 @@ pub enum ReposListCollaboratorsQueryAffiliation {
   Direct,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -7592,6 +10676,7 @@ impl ToString for ReposListCollaboratorsQueryAffiliation {
       ReposListCollaboratorsQueryAffiliation::Outside => "outside".to_string(),
       ReposListCollaboratorsQueryAffiliation::Direct => "direct".to_string(),
       ReposListCollaboratorsQueryAffiliation::All => "all".to_string(),
+      ReposListCollaboratorsQueryAffiliation::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7609,6 +10694,13 @@ pub enum ReposListCollaboratorsQueryPermission {
   Maintain,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -7620,6 +10712,7 @@ impl ToString for ReposListCollaboratorsQueryPermission {
       ReposListCollaboratorsQueryPermission::Push => "push".to_string(),
       ReposListCollaboratorsQueryPermission::Maintain => "maintain".to_string(),
       ReposListCollaboratorsQueryPermission::Admin => "admin".to_string(),
+      ReposListCollaboratorsQueryPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7638,6 +10731,13 @@ pub enum RepositoryInvitationPermissions {
   Triage,
   #[serde(rename = "maintain")]
   Maintain,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos", feature = "activity",))]
@@ -7649,6 +10749,7 @@ impl ToString for RepositoryInvitationPermissions {
       RepositoryInvitationPermissions::Admin => "admin".to_string(),
       RepositoryInvitationPermissions::Triage => "triage".to_string(),
       RepositoryInvitationPermissions::Maintain => "maintain".to_string(),
+      RepositoryInvitationPermissions::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7672,6 +10773,13 @@ pub enum ReactionsListForCommitCommentQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -7686,6 +10794,7 @@ impl ToString for ReactionsListForCommitCommentQueryContent {
       ReactionsListForCommitCommentQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForCommitCommentQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForCommitCommentQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForCommitCommentQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7710,6 +10819,13 @@ pub enum ReactionsCreateForCommitCommentRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -7724,6 +10840,7 @@ impl ToString for ReactionsCreateForCommitCommentRequestContent {
       ReactionsCreateForCommitCommentRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForCommitCommentRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForCommitCommentRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForCommitCommentRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7732,7 +10849,8 @@ impl ToString for ReactionsCreateForCommitCommentRequestContent {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
 ))]
 /// The merge method to use.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -7743,13 +10861,21 @@ pub enum AutoMergeMergeMethod {
   Squash,
   #[serde(rename = "rebase")]
   Rebase,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
 ))]
 impl ToString for AutoMergeMergeMethod {
   fn to_string(&self) -> String {
@@ -7757,6 +10883,7 @@ impl ToString for AutoMergeMergeMethod {
       AutoMergeMergeMethod::Merge => "merge".to_string(),
       AutoMergeMergeMethod::Squash => "squash".to_string(),
       AutoMergeMergeMethod::Rebase => "rebase".to_string(),
+      AutoMergeMergeMethod::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7770,6 +10897,13 @@ pub enum ChecksListForRefQueryStatus {
   InProgress,
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7779,6 +10913,7 @@ impl ToString for ChecksListForRefQueryStatus {
       ChecksListForRefQueryStatus::Queued => "queued".to_string(),
       ChecksListForRefQueryStatus::InProgress => "in_progress".to_string(),
       ChecksListForRefQueryStatus::Completed => "completed".to_string(),
+      ChecksListForRefQueryStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7790,6 +10925,13 @@ pub enum ChecksListForRefQueryFilter {
   Latest,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "checks",))]
@@ -7798,6 +10940,7 @@ impl ToString for ChecksListForRefQueryFilter {
     match self {
       ChecksListForRefQueryFilter::Latest => "latest".to_string(),
       ChecksListForRefQueryFilter::All => "all".to_string(),
+      ChecksListForRefQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7813,6 +10956,13 @@ pub enum CommitComparisonStatus {
   Behind,
   #[serde(rename = "identical")]
   Identical,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -7823,6 +10973,7 @@ impl ToString for CommitComparisonStatus {
       CommitComparisonStatus::Ahead => "ahead".to_string(),
       CommitComparisonStatus::Behind => "behind".to_string(),
       CommitComparisonStatus::Identical => "identical".to_string(),
+      CommitComparisonStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7838,6 +10989,13 @@ pub enum ContentDirectoryItemType {
   Submodule,
   #[serde(rename = "symlink")]
   Symlink,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -7848,6 +11006,7 @@ impl ToString for ContentDirectoryItemType {
       ContentDirectoryItemType::File => "file".to_string(),
       ContentDirectoryItemType::Submodule => "submodule".to_string(),
       ContentDirectoryItemType::Symlink => "symlink".to_string(),
+      ContentDirectoryItemType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7857,6 +11016,13 @@ impl ToString for ContentDirectoryItemType {
 pub enum ContentFileType {
   #[serde(rename = "file")]
   File,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -7864,6 +11030,7 @@ impl ToString for ContentFileType {
   fn to_string(&self) -> String {
     match self {
       ContentFileType::File => "file".to_string(),
+      ContentFileType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7873,6 +11040,13 @@ impl ToString for ContentFileType {
 pub enum SymlinkContentType {
   #[serde(rename = "symlink")]
   Symlink,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -7880,6 +11054,7 @@ impl ToString for SymlinkContentType {
   fn to_string(&self) -> String {
     match self {
       SymlinkContentType::Symlink => "symlink".to_string(),
+      SymlinkContentType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7889,6 +11064,13 @@ impl ToString for SymlinkContentType {
 pub enum SubmoduleContentType {
   #[serde(rename = "submodule")]
   Submodule,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -7896,6 +11078,7 @@ impl ToString for SubmoduleContentType {
   fn to_string(&self) -> String {
     match self {
       SubmoduleContentType::Submodule => "submodule".to_string(),
+      SubmoduleContentType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7920,6 +11103,13 @@ pub enum DependabotListAlertsForRepoQueryScope {
   Development,
   #[serde(rename = "runtime")]
   Runtime,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -7928,6 +11118,7 @@ impl ToString for DependabotListAlertsForRepoQueryScope {
     match self {
       DependabotListAlertsForRepoQueryScope::Development => "development".to_string(),
       DependabotListAlertsForRepoQueryScope::Runtime => "runtime".to_string(),
+      DependabotListAlertsForRepoQueryScope::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7939,6 +11130,13 @@ pub enum DependabotListAlertsForRepoQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -7947,6 +11145,7 @@ impl ToString for DependabotListAlertsForRepoQuerySort {
     match self {
       DependabotListAlertsForRepoQuerySort::Created => "created".to_string(),
       DependabotListAlertsForRepoQuerySort::Updated => "updated".to_string(),
+      DependabotListAlertsForRepoQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -7958,6 +11157,13 @@ pub enum DependabotListAlertsForRepoQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -7966,11 +11172,16 @@ impl ToString for DependabotListAlertsForRepoQueryDirection {
     match self {
       DependabotListAlertsForRepoQueryDirection::Asc => "asc".to_string(),
       DependabotListAlertsForRepoQueryDirection::Desc => "desc".to_string(),
+      DependabotListAlertsForRepoQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// The execution scope of the vulnerable dependency.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertDependencyScope {
@@ -7978,19 +11189,35 @@ pub enum DependabotAlertDependencyScope {
   Development,
   #[serde(rename = "runtime")]
   Runtime,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl ToString for DependabotAlertDependencyScope {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertDependencyScope::Development => "development".to_string(),
       DependabotAlertDependencyScope::Runtime => "runtime".to_string(),
+      DependabotAlertDependencyScope::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// The reason that the alert was dismissed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertDismissedReason {
@@ -8004,9 +11231,20 @@ pub enum DependabotAlertDismissedReason {
   NotUsed,
   #[serde(rename = "tolerable_risk")]
   TolerableRisk,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl ToString for DependabotAlertDismissedReason {
   fn to_string(&self) -> String {
     match self {
@@ -8015,11 +11253,16 @@ impl ToString for DependabotAlertDismissedReason {
       DependabotAlertDismissedReason::NoBandwidth => "no_bandwidth".to_string(),
       DependabotAlertDismissedReason::NotUsed => "not_used".to_string(),
       DependabotAlertDismissedReason::TolerableRisk => "tolerable_risk".to_string(),
+      DependabotAlertDismissedReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// The state of the Dependabot alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertState {
@@ -8031,9 +11274,20 @@ pub enum DependabotAlertState {
   Fixed,
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl ToString for DependabotAlertState {
   fn to_string(&self) -> String {
     match self {
@@ -8041,6 +11295,7 @@ impl ToString for DependabotAlertState {
       DependabotAlertState::Dismissed => "dismissed".to_string(),
       DependabotAlertState::Fixed => "fixed".to_string(),
       DependabotAlertState::Open => "open".to_string(),
+      DependabotAlertState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8059,6 +11314,13 @@ pub enum DependabotUpdateAlertRequestDismissedReason {
   NotUsed,
   #[serde(rename = "tolerable_risk")]
   TolerableRisk,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -8070,6 +11332,7 @@ impl ToString for DependabotUpdateAlertRequestDismissedReason {
       DependabotUpdateAlertRequestDismissedReason::NoBandwidth => "no_bandwidth".to_string(),
       DependabotUpdateAlertRequestDismissedReason::NotUsed => "not_used".to_string(),
       DependabotUpdateAlertRequestDismissedReason::TolerableRisk => "tolerable_risk".to_string(),
+      DependabotUpdateAlertRequestDismissedReason::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8083,6 +11346,13 @@ pub enum DependabotUpdateAlertRequestState {
   Dismissed,
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -8091,6 +11361,7 @@ impl ToString for DependabotUpdateAlertRequestState {
     match self {
       DependabotUpdateAlertRequestState::Dismissed => "dismissed".to_string(),
       DependabotUpdateAlertRequestState::Open => "open".to_string(),
+      DependabotUpdateAlertRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8102,6 +11373,13 @@ pub enum DependencyGraphDiffItemChangeType {
   Added,
   #[serde(rename = "removed")]
   Removed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependency_graph",))]
@@ -8110,6 +11388,7 @@ impl ToString for DependencyGraphDiffItemChangeType {
     match self {
       DependencyGraphDiffItemChangeType::Added => "added".to_string(),
       DependencyGraphDiffItemChangeType::Removed => "removed".to_string(),
+      DependencyGraphDiffItemChangeType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8124,6 +11403,13 @@ pub enum DependencyGraphDiffItemScope {
   Runtime,
   #[serde(rename = "development")]
   Development,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "dependency_graph",))]
@@ -8133,6 +11419,7 @@ impl ToString for DependencyGraphDiffItemScope {
       DependencyGraphDiffItemScope::Unknown => "unknown".to_string(),
       DependencyGraphDiffItemScope::Runtime => "runtime".to_string(),
       DependencyGraphDiffItemScope::Development => "development".to_string(),
+      DependencyGraphDiffItemScope::Other(value) => value.clone(),
     }
   }
 }
@@ -8162,6 +11449,13 @@ pub enum DeploymentStatusState {
   Queued,
   #[serde(rename = "in_progress")]
   InProgress,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -8175,6 +11469,7 @@ impl ToString for DeploymentStatusState {
       DeploymentStatusState::Success => "success".to_string(),
       DeploymentStatusState::Queued => "queued".to_string(),
       DeploymentStatusState::InProgress => "in_progress".to_string(),
+      DeploymentStatusState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8197,6 +11492,13 @@ pub enum ReposCreateDeploymentStatusRequestState {
   Pending,
   #[serde(rename = "success")]
   Success,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -8210,6 +11512,7 @@ impl ToString for ReposCreateDeploymentStatusRequestState {
       ReposCreateDeploymentStatusRequestState::Queued => "queued".to_string(),
       ReposCreateDeploymentStatusRequestState::Pending => "pending".to_string(),
       ReposCreateDeploymentStatusRequestState::Success => "success".to_string(),
+      ReposCreateDeploymentStatusRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8241,6 +11544,13 @@ pub enum DeploymentBranchPolicyType {
   Branch,
   #[serde(rename = "tag")]
   Tag,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -8249,6 +11559,7 @@ impl ToString for DeploymentBranchPolicyType {
     match self {
       DeploymentBranchPolicyType::Branch => "branch".to_string(),
       DeploymentBranchPolicyType::Tag => "tag".to_string(),
+      DeploymentBranchPolicyType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8261,6 +11572,13 @@ pub enum DeploymentBranchAndTagPolicyNamePatternType {
   Branch,
   #[serde(rename = "tag")]
   Tag,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -8269,6 +11587,7 @@ impl ToString for DeploymentBranchAndTagPolicyNamePatternType {
     match self {
       DeploymentBranchAndTagPolicyNamePatternType::Branch => "branch".to_string(),
       DeploymentBranchAndTagPolicyNamePatternType::Tag => "tag".to_string(),
+      DeploymentBranchAndTagPolicyNamePatternType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8284,6 +11603,13 @@ pub enum ReposListForksQuerySort {
   Stargazers,
   #[serde(rename = "watchers")]
   Watchers,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -8294,6 +11620,7 @@ impl ToString for ReposListForksQuerySort {
       ReposListForksQuerySort::Oldest => "oldest".to_string(),
       ReposListForksQuerySort::Stargazers => "stargazers".to_string(),
       ReposListForksQuerySort::Watchers => "watchers".to_string(),
+      ReposListForksQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8308,6 +11635,13 @@ pub enum GitCreateTagRequestType {
   Tree,
   #[serde(rename = "blob")]
   Blob,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "git",))]
@@ -8317,6 +11651,7 @@ impl ToString for GitCreateTagRequestType {
       GitCreateTagRequestType::Commit => "commit".to_string(),
       GitCreateTagRequestType::Tree => "tree".to_string(),
       GitCreateTagRequestType::Blob => "blob".to_string(),
+      GitCreateTagRequestType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8335,6 +11670,13 @@ pub enum GitCreateTreeRequestTreeMode {
   _160000,
   #[serde(rename = "120000")]
   _120000,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "git",))]
@@ -8346,6 +11688,7 @@ impl ToString for GitCreateTreeRequestTreeMode {
       GitCreateTreeRequestTreeMode::_040000 => "040000".to_string(),
       GitCreateTreeRequestTreeMode::_160000 => "160000".to_string(),
       GitCreateTreeRequestTreeMode::_120000 => "120000".to_string(),
+      GitCreateTreeRequestTreeMode::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8360,6 +11703,13 @@ pub enum GitCreateTreeRequestTreeType {
   Tree,
   #[serde(rename = "commit")]
   Commit,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "git",))]
@@ -8369,6 +11719,7 @@ impl ToString for GitCreateTreeRequestTreeType {
       GitCreateTreeRequestTreeType::Blob => "blob".to_string(),
       GitCreateTreeRequestTreeType::Tree => "tree".to_string(),
       GitCreateTreeRequestTreeType::Commit => "commit".to_string(),
+      GitCreateTreeRequestTreeType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8408,6 +11759,13 @@ pub enum ImportStatus {
   DetectionFoundNothing,
   #[serde(rename = "detection_needs_auth")]
   DetectionNeedsAuth,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -8430,6 +11788,7 @@ impl ToString for ImportStatus {
       ImportStatus::DetectionFoundMultiple => "detection_found_multiple".to_string(),
       ImportStatus::DetectionFoundNothing => "detection_found_nothing".to_string(),
       ImportStatus::DetectionNeedsAuth => "detection_needs_auth".to_string(),
+      ImportStatus::Other(value) => value.clone(),
     }
   }
 }
@@ -8446,6 +11805,13 @@ pub enum MigrationsStartImportRequestVcs {
   Mercurial,
   #[serde(rename = "tfvc")]
   Tfvc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -8456,6 +11822,7 @@ impl ToString for MigrationsStartImportRequestVcs {
       MigrationsStartImportRequestVcs::Git => "git".to_string(),
       MigrationsStartImportRequestVcs::Mercurial => "mercurial".to_string(),
       MigrationsStartImportRequestVcs::Tfvc => "tfvc".to_string(),
+      MigrationsStartImportRequestVcs::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8472,6 +11839,13 @@ pub enum MigrationsUpdateImportRequestVcs {
   Git,
   #[serde(rename = "mercurial")]
   Mercurial,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -8482,6 +11856,7 @@ impl ToString for MigrationsUpdateImportRequestVcs {
       MigrationsUpdateImportRequestVcs::Tfvc => "tfvc".to_string(),
       MigrationsUpdateImportRequestVcs::Git => "git".to_string(),
       MigrationsUpdateImportRequestVcs::Mercurial => "mercurial".to_string(),
+      MigrationsUpdateImportRequestVcs::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8494,6 +11869,13 @@ pub enum MigrationsSetLfsPreferenceRequestUseLfs {
   OptIn,
   #[serde(rename = "opt_out")]
   OptOut,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -8502,6 +11884,7 @@ impl ToString for MigrationsSetLfsPreferenceRequestUseLfs {
     match self {
       MigrationsSetLfsPreferenceRequestUseLfs::OptIn => "opt_in".to_string(),
       MigrationsSetLfsPreferenceRequestUseLfs::OptOut => "opt_out".to_string(),
+      MigrationsSetLfsPreferenceRequestUseLfs::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8529,6 +11912,13 @@ pub enum ReposUpdateInvitationRequestPermissions {
   Triage,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -8540,6 +11930,7 @@ impl ToString for ReposUpdateInvitationRequestPermissions {
       ReposUpdateInvitationRequestPermissions::Maintain => "maintain".to_string(),
       ReposUpdateInvitationRequestPermissions::Triage => "triage".to_string(),
       ReposUpdateInvitationRequestPermissions::Admin => "admin".to_string(),
+      ReposUpdateInvitationRequestPermissions::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8553,6 +11944,13 @@ pub enum IssuesListForRepoQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8562,6 +11960,7 @@ impl ToString for IssuesListForRepoQueryState {
       IssuesListForRepoQueryState::Open => "open".to_string(),
       IssuesListForRepoQueryState::Closed => "closed".to_string(),
       IssuesListForRepoQueryState::All => "all".to_string(),
+      IssuesListForRepoQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8575,6 +11974,13 @@ pub enum IssuesListForRepoQuerySort {
   Updated,
   #[serde(rename = "comments")]
   Comments,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8584,6 +11990,7 @@ impl ToString for IssuesListForRepoQuerySort {
       IssuesListForRepoQuerySort::Created => "created".to_string(),
       IssuesListForRepoQuerySort::Updated => "updated".to_string(),
       IssuesListForRepoQuerySort::Comments => "comments".to_string(),
+      IssuesListForRepoQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8595,6 +12002,13 @@ pub enum IssuesListForRepoQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8603,6 +12017,7 @@ impl ToString for IssuesListForRepoQueryDirection {
     match self {
       IssuesListForRepoQueryDirection::Asc => "asc".to_string(),
       IssuesListForRepoQueryDirection::Desc => "desc".to_string(),
+      IssuesListForRepoQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8614,6 +12029,13 @@ pub enum IssuesListCommentsForRepoQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8622,6 +12044,7 @@ impl ToString for IssuesListCommentsForRepoQuerySort {
     match self {
       IssuesListCommentsForRepoQuerySort::Created => "created".to_string(),
       IssuesListCommentsForRepoQuerySort::Updated => "updated".to_string(),
+      IssuesListCommentsForRepoQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8633,6 +12056,13 @@ pub enum IssuesListCommentsForRepoQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8641,6 +12071,7 @@ impl ToString for IssuesListCommentsForRepoQueryDirection {
     match self {
       IssuesListCommentsForRepoQueryDirection::Asc => "asc".to_string(),
       IssuesListCommentsForRepoQueryDirection::Desc => "desc".to_string(),
+      IssuesListCommentsForRepoQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8664,6 +12095,13 @@ pub enum ReactionsListForIssueCommentQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -8678,6 +12116,7 @@ impl ToString for ReactionsListForIssueCommentQueryContent {
       ReactionsListForIssueCommentQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForIssueCommentQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForIssueCommentQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForIssueCommentQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8702,6 +12141,13 @@ pub enum ReactionsCreateForIssueCommentRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -8716,6 +12162,7 @@ impl ToString for ReactionsCreateForIssueCommentRequestContent {
       ReactionsCreateForIssueCommentRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForIssueCommentRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForIssueCommentRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForIssueCommentRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8728,6 +12175,13 @@ pub enum IssuesUpdateRequestState {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8736,6 +12190,7 @@ impl ToString for IssuesUpdateRequestState {
     match self {
       IssuesUpdateRequestState::Open => "open".to_string(),
       IssuesUpdateRequestState::Closed => "closed".to_string(),
+      IssuesUpdateRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8750,6 +12205,13 @@ pub enum IssuesUpdateRequestStateReason {
   NotPlanned,
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8759,6 +12221,7 @@ impl ToString for IssuesUpdateRequestStateReason {
       IssuesUpdateRequestStateReason::Completed => "completed".to_string(),
       IssuesUpdateRequestStateReason::NotPlanned => "not_planned".to_string(),
       IssuesUpdateRequestStateReason::Reopened => "reopened".to_string(),
+      IssuesUpdateRequestStateReason::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8837,6 +12300,13 @@ pub enum IssuesLockRequestLockReason {
   Resolved,
   #[serde(rename = "spam")]
   Spam,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -8847,6 +12317,7 @@ impl ToString for IssuesLockRequestLockReason {
       IssuesLockRequestLockReason::TooHeated => "too heated".to_string(),
       IssuesLockRequestLockReason::Resolved => "resolved".to_string(),
       IssuesLockRequestLockReason::Spam => "spam".to_string(),
+      IssuesLockRequestLockReason::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8870,6 +12341,13 @@ pub enum ReactionsListForIssueQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -8884,6 +12362,7 @@ impl ToString for ReactionsListForIssueQueryContent {
       ReactionsListForIssueQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForIssueQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForIssueQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForIssueQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8908,6 +12387,13 @@ pub enum ReactionsCreateForIssueRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -8922,6 +12408,7 @@ impl ToString for ReactionsCreateForIssueRequestContent {
       ReactionsCreateForIssueRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForIssueRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForIssueRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForIssueRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8930,7 +12417,8 @@ impl ToString for ReactionsCreateForIssueRequestContent {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// The side of the diff to which the comment applies. The side of the last line of the range for a multi-line comment
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -8939,19 +12427,28 @@ pub enum PullRequestReviewCommentSide {
   Left,
   #[serde(rename = "RIGHT")]
   Right,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl ToString for PullRequestReviewCommentSide {
   fn to_string(&self) -> String {
     match self {
       PullRequestReviewCommentSide::Left => "LEFT".to_string(),
       PullRequestReviewCommentSide::Right => "RIGHT".to_string(),
+      PullRequestReviewCommentSide::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8960,7 +12457,8 @@ impl ToString for PullRequestReviewCommentSide {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// The side of the first line of the range for a multi-line comment.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -8969,19 +12467,28 @@ pub enum PullRequestReviewCommentStartSide {
   Left,
   #[serde(rename = "RIGHT")]
   Right,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl ToString for PullRequestReviewCommentStartSide {
   fn to_string(&self) -> String {
     match self {
       PullRequestReviewCommentStartSide::Left => "LEFT".to_string(),
       PullRequestReviewCommentStartSide::Right => "RIGHT".to_string(),
+      PullRequestReviewCommentStartSide::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8990,7 +12497,8 @@ impl ToString for PullRequestReviewCommentStartSide {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// The level at which the comment is targeted, can be a diff line or a file.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -8999,19 +12507,28 @@ pub enum PullRequestReviewCommentSubjectType {
   Line,
   #[serde(rename = "file")]
   File,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl ToString for PullRequestReviewCommentSubjectType {
   fn to_string(&self) -> String {
     match self {
       PullRequestReviewCommentSubjectType::Line => "line".to_string(),
       PullRequestReviewCommentSubjectType::File => "file".to_string(),
+      PullRequestReviewCommentSubjectType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9075,6 +12592,13 @@ pub enum MergedUpstreamMergeType {
   FastForward,
   #[serde(rename = "none")]
   None,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9084,6 +12608,7 @@ impl ToString for MergedUpstreamMergeType {
       MergedUpstreamMergeType::Merge => "merge".to_string(),
       MergedUpstreamMergeType::FastForward => "fast-forward".to_string(),
       MergedUpstreamMergeType::None => "none".to_string(),
+      MergedUpstreamMergeType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9097,6 +12622,13 @@ pub enum IssuesListMilestonesQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -9106,6 +12638,7 @@ impl ToString for IssuesListMilestonesQueryState {
       IssuesListMilestonesQueryState::Open => "open".to_string(),
       IssuesListMilestonesQueryState::Closed => "closed".to_string(),
       IssuesListMilestonesQueryState::All => "all".to_string(),
+      IssuesListMilestonesQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9117,6 +12650,13 @@ pub enum IssuesListMilestonesQuerySort {
   DueOn,
   #[serde(rename = "completeness")]
   Completeness,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -9125,6 +12665,7 @@ impl ToString for IssuesListMilestonesQuerySort {
     match self {
       IssuesListMilestonesQuerySort::DueOn => "due_on".to_string(),
       IssuesListMilestonesQuerySort::Completeness => "completeness".to_string(),
+      IssuesListMilestonesQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9136,6 +12677,13 @@ pub enum IssuesListMilestonesQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -9144,6 +12692,7 @@ impl ToString for IssuesListMilestonesQueryDirection {
     match self {
       IssuesListMilestonesQueryDirection::Asc => "asc".to_string(),
       IssuesListMilestonesQueryDirection::Desc => "desc".to_string(),
+      IssuesListMilestonesQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9156,6 +12705,13 @@ pub enum IssuesCreateMilestoneRequestState {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -9164,6 +12720,7 @@ impl ToString for IssuesCreateMilestoneRequestState {
     match self {
       IssuesCreateMilestoneRequestState::Open => "open".to_string(),
       IssuesCreateMilestoneRequestState::Closed => "closed".to_string(),
+      IssuesCreateMilestoneRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9176,6 +12733,13 @@ pub enum IssuesUpdateMilestoneRequestState {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -9184,6 +12748,7 @@ impl ToString for IssuesUpdateMilestoneRequestState {
     match self {
       IssuesUpdateMilestoneRequestState::Open => "open".to_string(),
       IssuesUpdateMilestoneRequestState::Closed => "closed".to_string(),
+      IssuesUpdateMilestoneRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9196,6 +12761,13 @@ pub enum GitHubPagesBuildType {
   Legacy,
   #[serde(rename = "workflow")]
   Workflow,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9204,6 +12776,7 @@ impl ToString for GitHubPagesBuildType {
     match self {
       GitHubPagesBuildType::Legacy => "legacy".to_string(),
       GitHubPagesBuildType::Workflow => "workflow".to_string(),
+      GitHubPagesBuildType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9235,6 +12808,13 @@ pub enum PagesHttpsCertificateState {
   DestroyPending,
   #[serde(rename = "dns_changed")]
   DnsChanged,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9253,6 +12833,7 @@ impl ToString for PagesHttpsCertificateState {
       PagesHttpsCertificateState::BadAuthz => "bad_authz".to_string(),
       PagesHttpsCertificateState::DestroyPending => "destroy_pending".to_string(),
       PagesHttpsCertificateState::DnsChanged => "dns_changed".to_string(),
+      PagesHttpsCertificateState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9267,6 +12848,13 @@ pub enum GitHubPagesProtectedDomainState {
   Verified,
   #[serde(rename = "unverified")]
   Unverified,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9276,6 +12864,7 @@ impl ToString for GitHubPagesProtectedDomainState {
       GitHubPagesProtectedDomainState::Pending => "pending".to_string(),
       GitHubPagesProtectedDomainState::Verified => "verified".to_string(),
       GitHubPagesProtectedDomainState::Unverified => "unverified".to_string(),
+      GitHubPagesProtectedDomainState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9290,6 +12879,13 @@ pub enum GitHubPagesStatus {
   Building,
   #[serde(rename = "errored")]
   Errored,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9299,6 +12895,7 @@ impl ToString for GitHubPagesStatus {
       GitHubPagesStatus::Built => "built".to_string(),
       GitHubPagesStatus::Building => "building".to_string(),
       GitHubPagesStatus::Errored => "errored".to_string(),
+      GitHubPagesStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9329,6 +12926,13 @@ pub enum GitHubPagesDeploymentStatusStatus {
   DeploymentLost,
   #[serde(rename = "succeed")]
   Succeed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9352,6 +12956,7 @@ impl ToString for GitHubPagesDeploymentStatusStatus {
       }
       GitHubPagesDeploymentStatusStatus::DeploymentLost => "deployment_lost".to_string(),
       GitHubPagesDeploymentStatusStatus::Succeed => "succeed".to_string(),
+      GitHubPagesDeploymentStatusStatus::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9372,6 +12977,13 @@ pub enum ProjectsListForRepoQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "projects",))]
@@ -9381,6 +12993,7 @@ impl ToString for ProjectsListForRepoQueryState {
       ProjectsListForRepoQueryState::Open => "open".to_string(),
       ProjectsListForRepoQueryState::Closed => "closed".to_string(),
       ProjectsListForRepoQueryState::All => "all".to_string(),
+      ProjectsListForRepoQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9394,6 +13007,13 @@ pub enum PullsListQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9403,6 +13023,7 @@ impl ToString for PullsListQueryState {
       PullsListQueryState::Open => "open".to_string(),
       PullsListQueryState::Closed => "closed".to_string(),
       PullsListQueryState::All => "all".to_string(),
+      PullsListQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9418,6 +13039,13 @@ pub enum PullsListQuerySort {
   Popularity,
   #[serde(rename = "long-running")]
   LongRunning,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9428,6 +13056,7 @@ impl ToString for PullsListQuerySort {
       PullsListQuerySort::Updated => "updated".to_string(),
       PullsListQuerySort::Popularity => "popularity".to_string(),
       PullsListQuerySort::LongRunning => "long-running".to_string(),
+      PullsListQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9439,6 +13068,13 @@ pub enum PullsListQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9447,11 +13083,17 @@ impl ToString for PullsListQueryDirection {
     match self {
       PullsListQueryDirection::Asc => "asc".to_string(),
       PullsListQueryDirection::Desc => "desc".to_string(),
+      PullsListQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 /// State of this Pull Request. Either `open` or `closed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PullRequestState {
@@ -9459,14 +13101,27 @@ pub enum PullRequestState {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl ToString for PullRequestState {
   fn to_string(&self) -> String {
     match self {
       PullRequestState::Open => "open".to_string(),
       PullRequestState::Closed => "closed".to_string(),
+      PullRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9480,6 +13135,13 @@ pub enum PullsListReviewCommentsForRepoQuerySort {
   Updated,
   #[serde(rename = "created_at")]
   CreatedAt,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9489,6 +13151,7 @@ impl ToString for PullsListReviewCommentsForRepoQuerySort {
       PullsListReviewCommentsForRepoQuerySort::Created => "created".to_string(),
       PullsListReviewCommentsForRepoQuerySort::Updated => "updated".to_string(),
       PullsListReviewCommentsForRepoQuerySort::CreatedAt => "created_at".to_string(),
+      PullsListReviewCommentsForRepoQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9500,6 +13163,13 @@ pub enum PullsListReviewCommentsForRepoQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9508,6 +13178,7 @@ impl ToString for PullsListReviewCommentsForRepoQueryDirection {
     match self {
       PullsListReviewCommentsForRepoQueryDirection::Asc => "asc".to_string(),
       PullsListReviewCommentsForRepoQueryDirection::Desc => "desc".to_string(),
+      PullsListReviewCommentsForRepoQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9531,6 +13202,13 @@ pub enum ReactionsListForPullRequestReviewCommentQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -9545,6 +13223,7 @@ impl ToString for ReactionsListForPullRequestReviewCommentQueryContent {
       ReactionsListForPullRequestReviewCommentQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForPullRequestReviewCommentQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForPullRequestReviewCommentQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForPullRequestReviewCommentQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9569,6 +13248,13 @@ pub enum ReactionsCreateForPullRequestReviewCommentRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -9583,6 +13269,7 @@ impl ToString for ReactionsCreateForPullRequestReviewCommentRequestContent {
       ReactionsCreateForPullRequestReviewCommentRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForPullRequestReviewCommentRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForPullRequestReviewCommentRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForPullRequestReviewCommentRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9595,6 +13282,13 @@ pub enum PullsUpdateRequestState {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9603,6 +13297,7 @@ impl ToString for PullsUpdateRequestState {
     match self {
       PullsUpdateRequestState::Open => "open".to_string(),
       PullsUpdateRequestState::Closed => "closed".to_string(),
+      PullsUpdateRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9615,6 +13310,13 @@ pub enum CodespacesCreateWithPrForAuthenticatedUserRequestGeo {
   SoutheastAsia,
   UsEast,
   UsWest,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -9627,6 +13329,7 @@ impl ToString for CodespacesCreateWithPrForAuthenticatedUserRequestGeo {
       }
       CodespacesCreateWithPrForAuthenticatedUserRequestGeo::UsEast => "UsEast".to_string(),
       CodespacesCreateWithPrForAuthenticatedUserRequestGeo::UsWest => "UsWest".to_string(),
+      CodespacesCreateWithPrForAuthenticatedUserRequestGeo::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9638,6 +13341,13 @@ pub enum PullsListReviewCommentsQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9646,6 +13356,7 @@ impl ToString for PullsListReviewCommentsQuerySort {
     match self {
       PullsListReviewCommentsQuerySort::Created => "created".to_string(),
       PullsListReviewCommentsQuerySort::Updated => "updated".to_string(),
+      PullsListReviewCommentsQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9657,6 +13368,13 @@ pub enum PullsListReviewCommentsQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9665,6 +13383,7 @@ impl ToString for PullsListReviewCommentsQueryDirection {
     match self {
       PullsListReviewCommentsQueryDirection::Asc => "asc".to_string(),
       PullsListReviewCommentsQueryDirection::Desc => "desc".to_string(),
+      PullsListReviewCommentsQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9677,6 +13396,13 @@ pub enum PullsCreateReviewCommentRequestSide {
   Left,
   #[serde(rename = "RIGHT")]
   Right,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9685,6 +13411,7 @@ impl ToString for PullsCreateReviewCommentRequestSide {
     match self {
       PullsCreateReviewCommentRequestSide::Left => "LEFT".to_string(),
       PullsCreateReviewCommentRequestSide::Right => "RIGHT".to_string(),
+      PullsCreateReviewCommentRequestSide::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9699,6 +13426,13 @@ pub enum PullsCreateReviewCommentRequestStartSide {
   Right,
   #[serde(rename = "side")]
   Side,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9708,6 +13442,7 @@ impl ToString for PullsCreateReviewCommentRequestStartSide {
       PullsCreateReviewCommentRequestStartSide::Left => "LEFT".to_string(),
       PullsCreateReviewCommentRequestStartSide::Right => "RIGHT".to_string(),
       PullsCreateReviewCommentRequestStartSide::Side => "side".to_string(),
+      PullsCreateReviewCommentRequestStartSide::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9720,6 +13455,13 @@ pub enum PullsCreateReviewCommentRequestSubjectType {
   Line,
   #[serde(rename = "file")]
   File,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9728,6 +13470,7 @@ impl ToString for PullsCreateReviewCommentRequestSubjectType {
     match self {
       PullsCreateReviewCommentRequestSubjectType::Line => "line".to_string(),
       PullsCreateReviewCommentRequestSubjectType::File => "file".to_string(),
+      PullsCreateReviewCommentRequestSubjectType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9742,6 +13485,13 @@ pub enum PullsMergeRequestMergeMethod {
   Squash,
   #[serde(rename = "rebase")]
   Rebase,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9751,6 +13501,7 @@ impl ToString for PullsMergeRequestMergeMethod {
       PullsMergeRequestMergeMethod::Merge => "merge".to_string(),
       PullsMergeRequestMergeMethod::Squash => "squash".to_string(),
       PullsMergeRequestMergeMethod::Rebase => "rebase".to_string(),
+      PullsMergeRequestMergeMethod::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9765,6 +13516,13 @@ pub enum PullsCreateReviewRequestEvent {
   RequestChanges,
   #[serde(rename = "COMMENT")]
   Comment,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9774,6 +13532,7 @@ impl ToString for PullsCreateReviewRequestEvent {
       PullsCreateReviewRequestEvent::Approve => "APPROVE".to_string(),
       PullsCreateReviewRequestEvent::RequestChanges => "REQUEST_CHANGES".to_string(),
       PullsCreateReviewRequestEvent::Comment => "COMMENT".to_string(),
+      PullsCreateReviewRequestEvent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9786,6 +13545,13 @@ pub enum LegacyReviewCommentSide {
   Left,
   #[serde(rename = "RIGHT")]
   Right,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9794,6 +13560,7 @@ impl ToString for LegacyReviewCommentSide {
     match self {
       LegacyReviewCommentSide::Left => "LEFT".to_string(),
       LegacyReviewCommentSide::Right => "RIGHT".to_string(),
+      LegacyReviewCommentSide::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9806,6 +13573,13 @@ pub enum LegacyReviewCommentStartSide {
   Left,
   #[serde(rename = "RIGHT")]
   Right,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9814,6 +13588,7 @@ impl ToString for LegacyReviewCommentStartSide {
     match self {
       LegacyReviewCommentStartSide::Left => "LEFT".to_string(),
       LegacyReviewCommentStartSide::Right => "RIGHT".to_string(),
+      LegacyReviewCommentStartSide::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9823,6 +13598,13 @@ impl ToString for LegacyReviewCommentStartSide {
 pub enum PullsDismissReviewRequestEvent {
   #[serde(rename = "DISMISS")]
   Dismiss,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9830,6 +13612,7 @@ impl ToString for PullsDismissReviewRequestEvent {
   fn to_string(&self) -> String {
     match self {
       PullsDismissReviewRequestEvent::Dismiss => "DISMISS".to_string(),
+      PullsDismissReviewRequestEvent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9844,6 +13627,13 @@ pub enum PullsSubmitReviewRequestEvent {
   RequestChanges,
   #[serde(rename = "COMMENT")]
   Comment,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "pulls",))]
@@ -9853,11 +13643,12 @@ impl ToString for PullsSubmitReviewRequestEvent {
       PullsSubmitReviewRequestEvent::Approve => "APPROVE".to_string(),
       PullsSubmitReviewRequestEvent::RequestChanges => "REQUEST_CHANGES".to_string(),
       PullsSubmitReviewRequestEvent::Comment => "COMMENT".to_string(),
+      PullsSubmitReviewRequestEvent::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_release",))]
 /// State of the release asset.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleaseAssetState {
@@ -9865,14 +13656,22 @@ pub enum ReleaseAssetState {
   Uploaded,
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_release",))]
 impl ToString for ReleaseAssetState {
   fn to_string(&self) -> String {
     match self {
       ReleaseAssetState::Uploaded => "uploaded".to_string(),
       ReleaseAssetState::Open => "open".to_string(),
+      ReleaseAssetState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9887,6 +13686,13 @@ pub enum ReposCreateReleaseRequestMakeLatest {
   False,
   #[serde(rename = "legacy")]
   Legacy,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9896,6 +13702,7 @@ impl ToString for ReposCreateReleaseRequestMakeLatest {
       ReposCreateReleaseRequestMakeLatest::True => "true".to_string(),
       ReposCreateReleaseRequestMakeLatest::False => "false".to_string(),
       ReposCreateReleaseRequestMakeLatest::Legacy => "legacy".to_string(),
+      ReposCreateReleaseRequestMakeLatest::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9910,6 +13717,13 @@ pub enum ReposUpdateReleaseRequestMakeLatest {
   False,
   #[serde(rename = "legacy")]
   Legacy,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -9919,6 +13733,7 @@ impl ToString for ReposUpdateReleaseRequestMakeLatest {
       ReposUpdateReleaseRequestMakeLatest::True => "true".to_string(),
       ReposUpdateReleaseRequestMakeLatest::False => "false".to_string(),
       ReposUpdateReleaseRequestMakeLatest::Legacy => "legacy".to_string(),
+      ReposUpdateReleaseRequestMakeLatest::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9938,6 +13753,13 @@ pub enum ReactionsListForReleaseQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -9950,6 +13772,7 @@ impl ToString for ReactionsListForReleaseQueryContent {
       ReactionsListForReleaseQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForReleaseQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForReleaseQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForReleaseQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9970,6 +13793,13 @@ pub enum ReactionsCreateForReleaseRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -9982,6 +13812,7 @@ impl ToString for ReactionsCreateForReleaseRequestContent {
       ReactionsCreateForReleaseRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForReleaseRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForReleaseRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForReleaseRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -9992,6 +13823,13 @@ impl ToString for ReactionsCreateForReleaseRequestContent {
 pub enum RepositoryRulesetDataForRuleRulesetSourceType {
   Repository,
   Organization,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10000,6 +13838,7 @@ impl ToString for RepositoryRulesetDataForRuleRulesetSourceType {
     match self {
       RepositoryRulesetDataForRuleRulesetSourceType::Repository => "Repository".to_string(),
       RepositoryRulesetDataForRuleRulesetSourceType::Organization => "Organization".to_string(),
+      RepositoryRulesetDataForRuleRulesetSourceType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10162,6 +14001,13 @@ pub enum ReposCreateRepoRulesetRequestTarget {
   Branch,
   #[serde(rename = "tag")]
   Tag,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10170,6 +14016,7 @@ impl ToString for ReposCreateRepoRulesetRequestTarget {
     match self {
       ReposCreateRepoRulesetRequestTarget::Branch => "branch".to_string(),
       ReposCreateRepoRulesetRequestTarget::Tag => "tag".to_string(),
+      ReposCreateRepoRulesetRequestTarget::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10185,6 +14032,13 @@ pub enum ReposGetRepoRuleSuitesQueryTimePeriod {
   Week,
   #[serde(rename = "month")]
   Month,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10195,6 +14049,7 @@ impl ToString for ReposGetRepoRuleSuitesQueryTimePeriod {
       ReposGetRepoRuleSuitesQueryTimePeriod::Day => "day".to_string(),
       ReposGetRepoRuleSuitesQueryTimePeriod::Week => "week".to_string(),
       ReposGetRepoRuleSuitesQueryTimePeriod::Month => "month".to_string(),
+      ReposGetRepoRuleSuitesQueryTimePeriod::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10210,6 +14065,13 @@ pub enum ReposGetRepoRuleSuitesQueryRuleSuiteResult {
   Bypass,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10220,6 +14082,7 @@ impl ToString for ReposGetRepoRuleSuitesQueryRuleSuiteResult {
       ReposGetRepoRuleSuitesQueryRuleSuiteResult::Fail => "fail".to_string(),
       ReposGetRepoRuleSuitesQueryRuleSuiteResult::Bypass => "bypass".to_string(),
       ReposGetRepoRuleSuitesQueryRuleSuiteResult::All => "all".to_string(),
+      ReposGetRepoRuleSuitesQueryRuleSuiteResult::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10232,6 +14095,13 @@ pub enum ReposUpdateRepoRulesetRequestTarget {
   Branch,
   #[serde(rename = "tag")]
   Tag,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10240,6 +14110,7 @@ impl ToString for ReposUpdateRepoRulesetRequestTarget {
     match self {
       ReposUpdateRepoRulesetRequestTarget::Branch => "branch".to_string(),
       ReposUpdateRepoRulesetRequestTarget::Tag => "tag".to_string(),
+      ReposUpdateRepoRulesetRequestTarget::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10251,6 +14122,13 @@ pub enum SecretScanningListAlertsForRepoQueryState {
   Open,
   #[serde(rename = "resolved")]
   Resolved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -10259,6 +14137,7 @@ impl ToString for SecretScanningListAlertsForRepoQueryState {
     match self {
       SecretScanningListAlertsForRepoQueryState::Open => "open".to_string(),
       SecretScanningListAlertsForRepoQueryState::Resolved => "resolved".to_string(),
+      SecretScanningListAlertsForRepoQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10270,6 +14149,13 @@ pub enum SecretScanningListAlertsForRepoQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -10278,6 +14164,7 @@ impl ToString for SecretScanningListAlertsForRepoQuerySort {
     match self {
       SecretScanningListAlertsForRepoQuerySort::Created => "created".to_string(),
       SecretScanningListAlertsForRepoQuerySort::Updated => "updated".to_string(),
+      SecretScanningListAlertsForRepoQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10289,6 +14176,13 @@ pub enum SecretScanningListAlertsForRepoQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -10297,6 +14191,7 @@ impl ToString for SecretScanningListAlertsForRepoQueryDirection {
     match self {
       SecretScanningListAlertsForRepoQueryDirection::Asc => "asc".to_string(),
       SecretScanningListAlertsForRepoQueryDirection::Desc => "desc".to_string(),
+      SecretScanningListAlertsForRepoQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10311,6 +14206,13 @@ pub enum SecretScanningAlertValidity {
   Inactive,
   #[serde(rename = "unknown")]
   Unknown,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
 }
 
 #[cfg(any(feature = "full", feature = "secret_scanning",))]
@@ -10320,11 +14222,16 @@ impl ToString for SecretScanningAlertValidity {
       SecretScanningAlertValidity::Active => "active".to_string(),
       SecretScanningAlertValidity::Inactive => "inactive".to_string(),
       SecretScanningAlertValidity::Unknown => "unknown".to_string(),
+      SecretScanningAlertValidity::Other(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SecretScanningLocationDetails {
@@ -10356,7 +14263,11 @@ pub enum SecretScanningLocationDetails {
   SecretScanningLocationPullRequestReviewComment(SecretScanningLocationPullRequestReviewComment),
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// The location type. Because secrets may be found in different types of resources (ie. code, comments, issues, pull requests, discussions), this field identifies the type of resource where the secret was found.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecretScanningLocationType {
@@ -10386,9 +14297,20 @@ pub enum SecretScanningLocationType {
   PullRequestReview,
   #[serde(rename = "pull_request_review_comment")]
   PullRequestReviewComment,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl ToString for SecretScanningLocationType {
   fn to_string(&self) -> String {
     match self {
@@ -10407,6 +14329,7 @@ impl ToString for SecretScanningLocationType {
       SecretScanningLocationType::PullRequestReviewComment => {
         "pull_request_review_comment".to_string()
       }
+      SecretScanningLocationType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10418,6 +14341,13 @@ pub enum SecurityAdvisoriesListRepositoryAdvisoriesQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -10426,6 +14356,7 @@ impl ToString for SecurityAdvisoriesListRepositoryAdvisoriesQueryDirection {
     match self {
       SecurityAdvisoriesListRepositoryAdvisoriesQueryDirection::Asc => "asc".to_string(),
       SecurityAdvisoriesListRepositoryAdvisoriesQueryDirection::Desc => "desc".to_string(),
+      SecurityAdvisoriesListRepositoryAdvisoriesQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10439,6 +14370,13 @@ pub enum SecurityAdvisoriesListRepositoryAdvisoriesQuerySort {
   Updated,
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -10448,6 +14386,7 @@ impl ToString for SecurityAdvisoriesListRepositoryAdvisoriesQuerySort {
       SecurityAdvisoriesListRepositoryAdvisoriesQuerySort::Created => "created".to_string(),
       SecurityAdvisoriesListRepositoryAdvisoriesQuerySort::Updated => "updated".to_string(),
       SecurityAdvisoriesListRepositoryAdvisoriesQuerySort::Published => "published".to_string(),
+      SecurityAdvisoriesListRepositoryAdvisoriesQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10463,6 +14402,13 @@ pub enum SecurityAdvisoriesListRepositoryAdvisoriesQueryState {
   Published,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -10473,6 +14419,7 @@ impl ToString for SecurityAdvisoriesListRepositoryAdvisoriesQueryState {
       SecurityAdvisoriesListRepositoryAdvisoriesQueryState::Draft => "draft".to_string(),
       SecurityAdvisoriesListRepositoryAdvisoriesQueryState::Published => "published".to_string(),
       SecurityAdvisoriesListRepositoryAdvisoriesQueryState::Closed => "closed".to_string(),
+      SecurityAdvisoriesListRepositoryAdvisoriesQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10489,6 +14436,13 @@ pub enum RepositoryAdvisoryCreateSeverity {
   Medium,
   #[serde(rename = "low")]
   Low,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -10499,6 +14453,7 @@ impl ToString for RepositoryAdvisoryCreateSeverity {
       RepositoryAdvisoryCreateSeverity::High => "high".to_string(),
       RepositoryAdvisoryCreateSeverity::Medium => "medium".to_string(),
       RepositoryAdvisoryCreateSeverity::Low => "low".to_string(),
+      RepositoryAdvisoryCreateSeverity::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10515,6 +14470,13 @@ pub enum PrivateVulnerabilityReportCreateSeverity {
   Medium,
   #[serde(rename = "low")]
   Low,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -10525,6 +14487,7 @@ impl ToString for PrivateVulnerabilityReportCreateSeverity {
       PrivateVulnerabilityReportCreateSeverity::High => "high".to_string(),
       PrivateVulnerabilityReportCreateSeverity::Medium => "medium".to_string(),
       PrivateVulnerabilityReportCreateSeverity::Low => "low".to_string(),
+      PrivateVulnerabilityReportCreateSeverity::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10541,6 +14504,13 @@ pub enum RepositoryAdvisoryUpdateSeverity {
   Medium,
   #[serde(rename = "low")]
   Low,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -10551,6 +14521,7 @@ impl ToString for RepositoryAdvisoryUpdateSeverity {
       RepositoryAdvisoryUpdateSeverity::High => "high".to_string(),
       RepositoryAdvisoryUpdateSeverity::Medium => "medium".to_string(),
       RepositoryAdvisoryUpdateSeverity::Low => "low".to_string(),
+      RepositoryAdvisoryUpdateSeverity::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10565,6 +14536,13 @@ pub enum RepositoryAdvisoryUpdateState {
   Closed,
   #[serde(rename = "draft")]
   Draft,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -10574,6 +14552,7 @@ impl ToString for RepositoryAdvisoryUpdateState {
       RepositoryAdvisoryUpdateState::Published => "published".to_string(),
       RepositoryAdvisoryUpdateState::Closed => "closed".to_string(),
       RepositoryAdvisoryUpdateState::Draft => "draft".to_string(),
+      RepositoryAdvisoryUpdateState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10598,6 +14577,13 @@ pub enum ReposCreateCommitStatusRequestState {
   Pending,
   #[serde(rename = "success")]
   Success,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10608,6 +14594,7 @@ impl ToString for ReposCreateCommitStatusRequestState {
       ReposCreateCommitStatusRequestState::Failure => "failure".to_string(),
       ReposCreateCommitStatusRequestState::Pending => "pending".to_string(),
       ReposCreateCommitStatusRequestState::Success => "success".to_string(),
+      ReposCreateCommitStatusRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10619,6 +14606,13 @@ pub enum ReposGetClonesQueryPer {
   Day,
   #[serde(rename = "week")]
   Week,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10627,6 +14621,7 @@ impl ToString for ReposGetClonesQueryPer {
     match self {
       ReposGetClonesQueryPer::Day => "day".to_string(),
       ReposGetClonesQueryPer::Week => "week".to_string(),
+      ReposGetClonesQueryPer::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10638,6 +14633,13 @@ pub enum ReposGetViewsQueryPer {
   Day,
   #[serde(rename = "week")]
   Week,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -10646,6 +14648,7 @@ impl ToString for ReposGetViewsQueryPer {
     match self {
       ReposGetViewsQueryPer::Day => "day".to_string(),
       ReposGetViewsQueryPer::Week => "week".to_string(),
+      ReposGetViewsQueryPer::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10655,6 +14658,13 @@ impl ToString for ReposGetViewsQueryPer {
 pub enum SearchCodeQuerySort {
   #[serde(rename = "indexed")]
   Indexed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10662,6 +14672,7 @@ impl ToString for SearchCodeQuerySort {
   fn to_string(&self) -> String {
     match self {
       SearchCodeQuerySort::Indexed => "indexed".to_string(),
+      SearchCodeQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10673,6 +14684,13 @@ pub enum SearchCodeQueryOrder {
   Desc,
   #[serde(rename = "asc")]
   Asc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10681,6 +14699,7 @@ impl ToString for SearchCodeQueryOrder {
     match self {
       SearchCodeQueryOrder::Desc => "desc".to_string(),
       SearchCodeQueryOrder::Asc => "asc".to_string(),
+      SearchCodeQueryOrder::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10692,6 +14711,13 @@ pub enum SearchCommitsQuerySort {
   AuthorDate,
   #[serde(rename = "committer-date")]
   CommitterDate,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10700,6 +14726,7 @@ impl ToString for SearchCommitsQuerySort {
     match self {
       SearchCommitsQuerySort::AuthorDate => "author-date".to_string(),
       SearchCommitsQuerySort::CommitterDate => "committer-date".to_string(),
+      SearchCommitsQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10711,6 +14738,13 @@ pub enum SearchCommitsQueryOrder {
   Desc,
   #[serde(rename = "asc")]
   Asc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10719,6 +14753,7 @@ impl ToString for SearchCommitsQueryOrder {
     match self {
       SearchCommitsQueryOrder::Desc => "desc".to_string(),
       SearchCommitsQueryOrder::Asc => "asc".to_string(),
+      SearchCommitsQueryOrder::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10748,6 +14783,13 @@ pub enum SearchIssuesAndPullRequestsQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10767,6 +14809,7 @@ impl ToString for SearchIssuesAndPullRequestsQuerySort {
       SearchIssuesAndPullRequestsQuerySort::Interactions => "interactions".to_string(),
       SearchIssuesAndPullRequestsQuerySort::Created => "created".to_string(),
       SearchIssuesAndPullRequestsQuerySort::Updated => "updated".to_string(),
+      SearchIssuesAndPullRequestsQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10778,6 +14821,13 @@ pub enum SearchIssuesAndPullRequestsQueryOrder {
   Desc,
   #[serde(rename = "asc")]
   Asc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10786,6 +14836,7 @@ impl ToString for SearchIssuesAndPullRequestsQueryOrder {
     match self {
       SearchIssuesAndPullRequestsQueryOrder::Desc => "desc".to_string(),
       SearchIssuesAndPullRequestsQueryOrder::Asc => "asc".to_string(),
+      SearchIssuesAndPullRequestsQueryOrder::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10797,6 +14848,13 @@ pub enum SearchLabelsQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10805,6 +14863,7 @@ impl ToString for SearchLabelsQuerySort {
     match self {
       SearchLabelsQuerySort::Created => "created".to_string(),
       SearchLabelsQuerySort::Updated => "updated".to_string(),
+      SearchLabelsQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10816,6 +14875,13 @@ pub enum SearchLabelsQueryOrder {
   Desc,
   #[serde(rename = "asc")]
   Asc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10824,6 +14890,7 @@ impl ToString for SearchLabelsQueryOrder {
     match self {
       SearchLabelsQueryOrder::Desc => "desc".to_string(),
       SearchLabelsQueryOrder::Asc => "asc".to_string(),
+      SearchLabelsQueryOrder::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10839,6 +14906,13 @@ pub enum SearchReposQuerySort {
   HelpWantedIssues,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10849,6 +14923,7 @@ impl ToString for SearchReposQuerySort {
       SearchReposQuerySort::Forks => "forks".to_string(),
       SearchReposQuerySort::HelpWantedIssues => "help-wanted-issues".to_string(),
       SearchReposQuerySort::Updated => "updated".to_string(),
+      SearchReposQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10860,6 +14935,13 @@ pub enum SearchReposQueryOrder {
   Desc,
   #[serde(rename = "asc")]
   Asc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10868,6 +14950,7 @@ impl ToString for SearchReposQueryOrder {
     match self {
       SearchReposQueryOrder::Desc => "desc".to_string(),
       SearchReposQueryOrder::Asc => "asc".to_string(),
+      SearchReposQueryOrder::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10881,6 +14964,13 @@ pub enum SearchUsersQuerySort {
   Repositories,
   #[serde(rename = "joined")]
   Joined,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10890,6 +14980,7 @@ impl ToString for SearchUsersQuerySort {
       SearchUsersQuerySort::Followers => "followers".to_string(),
       SearchUsersQuerySort::Repositories => "repositories".to_string(),
       SearchUsersQuerySort::Joined => "joined".to_string(),
+      SearchUsersQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10901,6 +14992,13 @@ pub enum SearchUsersQueryOrder {
   Desc,
   #[serde(rename = "asc")]
   Asc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -10909,6 +15007,7 @@ impl ToString for SearchUsersQueryOrder {
     match self {
       SearchUsersQueryOrder::Desc => "desc".to_string(),
       SearchUsersQueryOrder::Asc => "asc".to_string(),
+      SearchUsersQueryOrder::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10923,6 +15022,13 @@ pub enum TeamsUpdateLegacyRequestNotificationSetting {
   NotificationsEnabled,
   #[serde(rename = "notifications_disabled")]
   NotificationsDisabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -10935,6 +15041,7 @@ impl ToString for TeamsUpdateLegacyRequestNotificationSetting {
       TeamsUpdateLegacyRequestNotificationSetting::NotificationsDisabled => {
         "notifications_disabled".to_string()
       }
+      TeamsUpdateLegacyRequestNotificationSetting::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10949,6 +15056,13 @@ pub enum TeamsUpdateLegacyRequestPermission {
   Push,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -10958,6 +15072,7 @@ impl ToString for TeamsUpdateLegacyRequestPermission {
       TeamsUpdateLegacyRequestPermission::Pull => "pull".to_string(),
       TeamsUpdateLegacyRequestPermission::Push => "push".to_string(),
       TeamsUpdateLegacyRequestPermission::Admin => "admin".to_string(),
+      TeamsUpdateLegacyRequestPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10975,6 +15090,13 @@ pub enum TeamsUpdateLegacyRequestPrivacy {
   Secret,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -10983,6 +15105,7 @@ impl ToString for TeamsUpdateLegacyRequestPrivacy {
     match self {
       TeamsUpdateLegacyRequestPrivacy::Secret => "secret".to_string(),
       TeamsUpdateLegacyRequestPrivacy::Closed => "closed".to_string(),
+      TeamsUpdateLegacyRequestPrivacy::Unknown(value) => value.clone(),
     }
   }
 }
@@ -10994,6 +15117,13 @@ pub enum TeamsListDiscussionsLegacyQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -11002,6 +15132,7 @@ impl ToString for TeamsListDiscussionsLegacyQueryDirection {
     match self {
       TeamsListDiscussionsLegacyQueryDirection::Asc => "asc".to_string(),
       TeamsListDiscussionsLegacyQueryDirection::Desc => "desc".to_string(),
+      TeamsListDiscussionsLegacyQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11013,6 +15144,13 @@ pub enum TeamsListDiscussionCommentsLegacyQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -11021,6 +15159,7 @@ impl ToString for TeamsListDiscussionCommentsLegacyQueryDirection {
     match self {
       TeamsListDiscussionCommentsLegacyQueryDirection::Asc => "asc".to_string(),
       TeamsListDiscussionCommentsLegacyQueryDirection::Desc => "desc".to_string(),
+      TeamsListDiscussionCommentsLegacyQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11044,6 +15183,13 @@ pub enum ReactionsListForTeamDiscussionCommentLegacyQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -11058,6 +15204,7 @@ impl ToString for ReactionsListForTeamDiscussionCommentLegacyQueryContent {
       ReactionsListForTeamDiscussionCommentLegacyQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForTeamDiscussionCommentLegacyQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForTeamDiscussionCommentLegacyQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForTeamDiscussionCommentLegacyQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11082,6 +15229,13 @@ pub enum ReactionsCreateForTeamDiscussionCommentLegacyRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -11098,6 +15252,7 @@ impl ToString for ReactionsCreateForTeamDiscussionCommentLegacyRequestContent {
       ReactionsCreateForTeamDiscussionCommentLegacyRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForTeamDiscussionCommentLegacyRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForTeamDiscussionCommentLegacyRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForTeamDiscussionCommentLegacyRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11121,6 +15276,13 @@ pub enum ReactionsListForTeamDiscussionLegacyQueryContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -11135,6 +15297,7 @@ impl ToString for ReactionsListForTeamDiscussionLegacyQueryContent {
       ReactionsListForTeamDiscussionLegacyQueryContent::Hooray => "hooray".to_string(),
       ReactionsListForTeamDiscussionLegacyQueryContent::Rocket => "rocket".to_string(),
       ReactionsListForTeamDiscussionLegacyQueryContent::Eyes => "eyes".to_string(),
+      ReactionsListForTeamDiscussionLegacyQueryContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11159,6 +15322,13 @@ pub enum ReactionsCreateForTeamDiscussionLegacyRequestContent {
   Rocket,
   #[serde(rename = "eyes")]
   Eyes,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "reactions",))]
@@ -11173,6 +15343,7 @@ impl ToString for ReactionsCreateForTeamDiscussionLegacyRequestContent {
       ReactionsCreateForTeamDiscussionLegacyRequestContent::Hooray => "hooray".to_string(),
       ReactionsCreateForTeamDiscussionLegacyRequestContent::Rocket => "rocket".to_string(),
       ReactionsCreateForTeamDiscussionLegacyRequestContent::Eyes => "eyes".to_string(),
+      ReactionsCreateForTeamDiscussionLegacyRequestContent::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11186,6 +15357,13 @@ pub enum TeamsListMembersLegacyQueryRole {
   Maintainer,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -11195,6 +15373,7 @@ impl ToString for TeamsListMembersLegacyQueryRole {
       TeamsListMembersLegacyQueryRole::Member => "member".to_string(),
       TeamsListMembersLegacyQueryRole::Maintainer => "maintainer".to_string(),
       TeamsListMembersLegacyQueryRole::All => "all".to_string(),
+      TeamsListMembersLegacyQueryRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11207,6 +15386,13 @@ pub enum TeamsAddOrUpdateMembershipForUserLegacyRequestRole {
   Member,
   #[serde(rename = "maintainer")]
   Maintainer,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -11215,6 +15401,7 @@ impl ToString for TeamsAddOrUpdateMembershipForUserLegacyRequestRole {
     match self {
       TeamsAddOrUpdateMembershipForUserLegacyRequestRole::Member => "member".to_string(),
       TeamsAddOrUpdateMembershipForUserLegacyRequestRole::Maintainer => "maintainer".to_string(),
+      TeamsAddOrUpdateMembershipForUserLegacyRequestRole::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11229,6 +15416,13 @@ pub enum TeamsAddOrUpdateProjectPermissionsLegacyRequestPermission {
   Write,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -11238,6 +15432,7 @@ impl ToString for TeamsAddOrUpdateProjectPermissionsLegacyRequestPermission {
       TeamsAddOrUpdateProjectPermissionsLegacyRequestPermission::Read => "read".to_string(),
       TeamsAddOrUpdateProjectPermissionsLegacyRequestPermission::Write => "write".to_string(),
       TeamsAddOrUpdateProjectPermissionsLegacyRequestPermission::Admin => "admin".to_string(),
+      TeamsAddOrUpdateProjectPermissionsLegacyRequestPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11252,6 +15447,13 @@ pub enum TeamsAddOrUpdateRepoPermissionsLegacyRequestPermission {
   Push,
   #[serde(rename = "admin")]
   Admin,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "teams",))]
@@ -11261,6 +15463,7 @@ impl ToString for TeamsAddOrUpdateRepoPermissionsLegacyRequestPermission {
       TeamsAddOrUpdateRepoPermissionsLegacyRequestPermission::Pull => "pull".to_string(),
       TeamsAddOrUpdateRepoPermissionsLegacyRequestPermission::Push => "push".to_string(),
       TeamsAddOrUpdateRepoPermissionsLegacyRequestPermission::Admin => "admin".to_string(),
+      TeamsAddOrUpdateRepoPermissionsLegacyRequestPermission::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11283,6 +15486,13 @@ pub enum CodespacesCreateForAuthenticatedUserRequestItem1Geo {
   SoutheastAsia,
   UsEast,
   UsWest,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -11295,6 +15505,7 @@ impl ToString for CodespacesCreateForAuthenticatedUserRequestItem1Geo {
       }
       CodespacesCreateForAuthenticatedUserRequestItem1Geo::UsEast => "UsEast".to_string(),
       CodespacesCreateForAuthenticatedUserRequestItem1Geo::UsWest => "UsWest".to_string(),
+      CodespacesCreateForAuthenticatedUserRequestItem1Geo::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11307,6 +15518,13 @@ pub enum CodespacesCreateForAuthenticatedUserRequestItem2Geo {
   SoutheastAsia,
   UsEast,
   UsWest,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "codespaces",))]
@@ -11319,6 +15537,7 @@ impl ToString for CodespacesCreateForAuthenticatedUserRequestItem2Geo {
       }
       CodespacesCreateForAuthenticatedUserRequestItem2Geo::UsEast => "UsEast".to_string(),
       CodespacesCreateForAuthenticatedUserRequestItem2Geo::UsWest => "UsWest".to_string(),
+      CodespacesCreateForAuthenticatedUserRequestItem2Geo::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11343,6 +15562,13 @@ pub enum UsersSetPrimaryEmailVisibilityForAuthenticatedUserRequestVisibility {
   Public,
   #[serde(rename = "private")]
   Private,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "users",))]
@@ -11355,6 +15581,9 @@ impl ToString for UsersSetPrimaryEmailVisibilityForAuthenticatedUserRequestVisib
       UsersSetPrimaryEmailVisibilityForAuthenticatedUserRequestVisibility::Private => {
         "private".to_string()
       }
+      UsersSetPrimaryEmailVisibilityForAuthenticatedUserRequestVisibility::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -11406,6 +15635,13 @@ pub enum IssuesListForAuthenticatedUserQueryFilter {
   Repos,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -11418,6 +15654,7 @@ impl ToString for IssuesListForAuthenticatedUserQueryFilter {
       IssuesListForAuthenticatedUserQueryFilter::Subscribed => "subscribed".to_string(),
       IssuesListForAuthenticatedUserQueryFilter::Repos => "repos".to_string(),
       IssuesListForAuthenticatedUserQueryFilter::All => "all".to_string(),
+      IssuesListForAuthenticatedUserQueryFilter::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11431,6 +15668,13 @@ pub enum IssuesListForAuthenticatedUserQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -11440,6 +15684,7 @@ impl ToString for IssuesListForAuthenticatedUserQueryState {
       IssuesListForAuthenticatedUserQueryState::Open => "open".to_string(),
       IssuesListForAuthenticatedUserQueryState::Closed => "closed".to_string(),
       IssuesListForAuthenticatedUserQueryState::All => "all".to_string(),
+      IssuesListForAuthenticatedUserQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11453,6 +15698,13 @@ pub enum IssuesListForAuthenticatedUserQuerySort {
   Updated,
   #[serde(rename = "comments")]
   Comments,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -11462,6 +15714,7 @@ impl ToString for IssuesListForAuthenticatedUserQuerySort {
       IssuesListForAuthenticatedUserQuerySort::Created => "created".to_string(),
       IssuesListForAuthenticatedUserQuerySort::Updated => "updated".to_string(),
       IssuesListForAuthenticatedUserQuerySort::Comments => "comments".to_string(),
+      IssuesListForAuthenticatedUserQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11473,6 +15726,13 @@ pub enum IssuesListForAuthenticatedUserQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -11481,6 +15741,7 @@ impl ToString for IssuesListForAuthenticatedUserQueryDirection {
     match self {
       IssuesListForAuthenticatedUserQueryDirection::Asc => "asc".to_string(),
       IssuesListForAuthenticatedUserQueryDirection::Desc => "desc".to_string(),
+      IssuesListForAuthenticatedUserQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11492,6 +15753,13 @@ pub enum OrgsListMembershipsForAuthenticatedUserQueryState {
   Active,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -11500,6 +15768,7 @@ impl ToString for OrgsListMembershipsForAuthenticatedUserQueryState {
     match self {
       OrgsListMembershipsForAuthenticatedUserQueryState::Active => "active".to_string(),
       OrgsListMembershipsForAuthenticatedUserQueryState::Pending => "pending".to_string(),
+      OrgsListMembershipsForAuthenticatedUserQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11510,6 +15779,13 @@ impl ToString for OrgsListMembershipsForAuthenticatedUserQueryState {
 pub enum OrgsUpdateMembershipForAuthenticatedUserRequestState {
   #[serde(rename = "active")]
   Active,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -11517,6 +15793,7 @@ impl ToString for OrgsUpdateMembershipForAuthenticatedUserRequestState {
   fn to_string(&self) -> String {
     match self {
       OrgsUpdateMembershipForAuthenticatedUserRequestState::Active => "active".to_string(),
+      OrgsUpdateMembershipForAuthenticatedUserRequestState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11527,6 +15804,13 @@ impl ToString for OrgsUpdateMembershipForAuthenticatedUserRequestState {
 pub enum MigrationsStartForAuthenticatedUserRequestExclude {
   #[serde(rename = "repositories")]
   Repositories,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "migrations",))]
@@ -11534,6 +15818,7 @@ impl ToString for MigrationsStartForAuthenticatedUserRequestExclude {
   fn to_string(&self) -> String {
     match self {
       MigrationsStartForAuthenticatedUserRequestExclude::Repositories => "repositories".to_string(),
+      MigrationsStartForAuthenticatedUserRequestExclude::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11553,6 +15838,13 @@ pub enum PackagesListPackagesForAuthenticatedUserQueryPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11567,6 +15859,7 @@ impl ToString for PackagesListPackagesForAuthenticatedUserQueryPackageType {
       PackagesListPackagesForAuthenticatedUserQueryPackageType::Container => {
         "container".to_string()
       }
+      PackagesListPackagesForAuthenticatedUserQueryPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11580,6 +15873,13 @@ pub enum PackagesListPackagesForAuthenticatedUserQueryVisibility {
   Private,
   #[serde(rename = "internal")]
   Internal,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11589,6 +15889,7 @@ impl ToString for PackagesListPackagesForAuthenticatedUserQueryVisibility {
       PackagesListPackagesForAuthenticatedUserQueryVisibility::Public => "public".to_string(),
       PackagesListPackagesForAuthenticatedUserQueryVisibility::Private => "private".to_string(),
       PackagesListPackagesForAuthenticatedUserQueryVisibility::Internal => "internal".to_string(),
+      PackagesListPackagesForAuthenticatedUserQueryVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11608,6 +15909,13 @@ pub enum PackagesGetPackageForAuthenticatedUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11624,6 +15932,7 @@ impl ToString for PackagesGetPackageForAuthenticatedUserParametersPackageType {
       PackagesGetPackageForAuthenticatedUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesGetPackageForAuthenticatedUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11643,6 +15952,13 @@ pub enum PackagesDeletePackageForAuthenticatedUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11661,6 +15977,9 @@ impl ToString for PackagesDeletePackageForAuthenticatedUserParametersPackageType
       PackagesDeletePackageForAuthenticatedUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesDeletePackageForAuthenticatedUserParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -11680,6 +15999,13 @@ pub enum PackagesRestorePackageForAuthenticatedUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11698,6 +16024,9 @@ impl ToString for PackagesRestorePackageForAuthenticatedUserParametersPackageTyp
       PackagesRestorePackageForAuthenticatedUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesRestorePackageForAuthenticatedUserParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -11717,6 +16046,13 @@ pub enum PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParamete
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11731,6 +16067,7 @@ impl ToString
       PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParametersPackageType::Docker => "docker".to_string(),
       PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParametersPackageType::Nuget => "nuget".to_string(),
       PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParametersPackageType::Container => "container".to_string(),
+      PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11742,6 +16079,13 @@ pub enum PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserQuerySta
   Active,
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11754,6 +16098,9 @@ impl ToString for PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUse
       PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserQueryState::Deleted => {
         "deleted".to_string()
       }
+      PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserQueryState::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -11773,6 +16120,13 @@ pub enum PackagesGetPackageVersionForAuthenticatedUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11795,6 +16149,9 @@ impl ToString for PackagesGetPackageVersionForAuthenticatedUserParametersPackage
       PackagesGetPackageVersionForAuthenticatedUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesGetPackageVersionForAuthenticatedUserParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -11814,6 +16171,13 @@ pub enum PackagesDeletePackageVersionForAuthenticatedUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11838,6 +16202,9 @@ impl ToString for PackagesDeletePackageVersionForAuthenticatedUserParametersPack
       PackagesDeletePackageVersionForAuthenticatedUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesDeletePackageVersionForAuthenticatedUserParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -11857,6 +16224,13 @@ pub enum PackagesRestorePackageVersionForAuthenticatedUserParametersPackageType
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -11881,6 +16255,9 @@ impl ToString for PackagesRestorePackageVersionForAuthenticatedUserParametersPac
       PackagesRestorePackageVersionForAuthenticatedUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesRestorePackageVersionForAuthenticatedUserParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -11894,6 +16271,13 @@ pub enum ReposListForAuthenticatedUserQueryVisibility {
   Public,
   #[serde(rename = "private")]
   Private,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -11903,6 +16287,7 @@ impl ToString for ReposListForAuthenticatedUserQueryVisibility {
       ReposListForAuthenticatedUserQueryVisibility::All => "all".to_string(),
       ReposListForAuthenticatedUserQueryVisibility::Public => "public".to_string(),
       ReposListForAuthenticatedUserQueryVisibility::Private => "private".to_string(),
+      ReposListForAuthenticatedUserQueryVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11920,6 +16305,13 @@ pub enum ReposListForAuthenticatedUserQueryType {
   Private,
   #[serde(rename = "member")]
   Member,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -11931,6 +16323,7 @@ impl ToString for ReposListForAuthenticatedUserQueryType {
       ReposListForAuthenticatedUserQueryType::Public => "public".to_string(),
       ReposListForAuthenticatedUserQueryType::Private => "private".to_string(),
       ReposListForAuthenticatedUserQueryType::Member => "member".to_string(),
+      ReposListForAuthenticatedUserQueryType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11946,6 +16339,13 @@ pub enum ReposListForAuthenticatedUserQuerySort {
   Pushed,
   #[serde(rename = "full_name")]
   FullName,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -11956,6 +16356,7 @@ impl ToString for ReposListForAuthenticatedUserQuerySort {
       ReposListForAuthenticatedUserQuerySort::Updated => "updated".to_string(),
       ReposListForAuthenticatedUserQuerySort::Pushed => "pushed".to_string(),
       ReposListForAuthenticatedUserQuerySort::FullName => "full_name".to_string(),
+      ReposListForAuthenticatedUserQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11967,6 +16368,13 @@ pub enum ReposListForAuthenticatedUserQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -11975,6 +16383,7 @@ impl ToString for ReposListForAuthenticatedUserQueryDirection {
     match self {
       ReposListForAuthenticatedUserQueryDirection::Asc => "asc".to_string(),
       ReposListForAuthenticatedUserQueryDirection::Desc => "desc".to_string(),
+      ReposListForAuthenticatedUserQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -11993,6 +16402,13 @@ pub enum ReposCreateForAuthenticatedUserRequestMergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -12002,6 +16418,7 @@ impl ToString for ReposCreateForAuthenticatedUserRequestMergeCommitMessage {
       ReposCreateForAuthenticatedUserRequestMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       ReposCreateForAuthenticatedUserRequestMergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       ReposCreateForAuthenticatedUserRequestMergeCommitMessage::Blank => "BLANK".to_string(),
+      ReposCreateForAuthenticatedUserRequestMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12017,6 +16434,13 @@ pub enum ReposCreateForAuthenticatedUserRequestMergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -12027,6 +16451,7 @@ impl ToString for ReposCreateForAuthenticatedUserRequestMergeCommitTitle {
       ReposCreateForAuthenticatedUserRequestMergeCommitTitle::MergeMessage => {
         "MERGE_MESSAGE".to_string()
       }
+      ReposCreateForAuthenticatedUserRequestMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12045,6 +16470,13 @@ pub enum ReposCreateForAuthenticatedUserRequestSquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -12058,6 +16490,9 @@ impl ToString for ReposCreateForAuthenticatedUserRequestSquashMergeCommitMessage
         "COMMIT_MESSAGES".to_string()
       }
       ReposCreateForAuthenticatedUserRequestSquashMergeCommitMessage::Blank => "BLANK".to_string(),
+      ReposCreateForAuthenticatedUserRequestSquashMergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -12073,6 +16508,13 @@ pub enum ReposCreateForAuthenticatedUserRequestSquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -12085,6 +16527,7 @@ impl ToString for ReposCreateForAuthenticatedUserRequestSquashMergeCommitTitle {
       ReposCreateForAuthenticatedUserRequestSquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      ReposCreateForAuthenticatedUserRequestSquashMergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12096,6 +16539,13 @@ pub enum ActivityListReposStarredByAuthenticatedUserQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "activity",))]
@@ -12104,6 +16554,7 @@ impl ToString for ActivityListReposStarredByAuthenticatedUserQuerySort {
     match self {
       ActivityListReposStarredByAuthenticatedUserQuerySort::Created => "created".to_string(),
       ActivityListReposStarredByAuthenticatedUserQuerySort::Updated => "updated".to_string(),
+      ActivityListReposStarredByAuthenticatedUserQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12115,6 +16566,13 @@ pub enum ActivityListReposStarredByAuthenticatedUserQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "activity",))]
@@ -12123,6 +16581,7 @@ impl ToString for ActivityListReposStarredByAuthenticatedUserQueryDirection {
     match self {
       ActivityListReposStarredByAuthenticatedUserQueryDirection::Asc => "asc".to_string(),
       ActivityListReposStarredByAuthenticatedUserQueryDirection::Desc => "desc".to_string(),
+      ActivityListReposStarredByAuthenticatedUserQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12148,6 +16607,13 @@ pub enum UsersGetContextForUserQuerySubjectType {
   Issue,
   #[serde(rename = "pull_request")]
   PullRequest,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "users",))]
@@ -12158,6 +16624,7 @@ impl ToString for UsersGetContextForUserQuerySubjectType {
       UsersGetContextForUserQuerySubjectType::Repository => "repository".to_string(),
       UsersGetContextForUserQuerySubjectType::Issue => "issue".to_string(),
       UsersGetContextForUserQuerySubjectType::PullRequest => "pull_request".to_string(),
+      UsersGetContextForUserQuerySubjectType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12177,6 +16644,13 @@ pub enum PackagesListPackagesForUserQueryPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12189,6 +16663,7 @@ impl ToString for PackagesListPackagesForUserQueryPackageType {
       PackagesListPackagesForUserQueryPackageType::Docker => "docker".to_string(),
       PackagesListPackagesForUserQueryPackageType::Nuget => "nuget".to_string(),
       PackagesListPackagesForUserQueryPackageType::Container => "container".to_string(),
+      PackagesListPackagesForUserQueryPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12202,6 +16677,13 @@ pub enum PackagesListPackagesForUserQueryVisibility {
   Private,
   #[serde(rename = "internal")]
   Internal,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12211,6 +16693,7 @@ impl ToString for PackagesListPackagesForUserQueryVisibility {
       PackagesListPackagesForUserQueryVisibility::Public => "public".to_string(),
       PackagesListPackagesForUserQueryVisibility::Private => "private".to_string(),
       PackagesListPackagesForUserQueryVisibility::Internal => "internal".to_string(),
+      PackagesListPackagesForUserQueryVisibility::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12230,6 +16713,13 @@ pub enum PackagesGetPackageForUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12242,6 +16732,7 @@ impl ToString for PackagesGetPackageForUserParametersPackageType {
       PackagesGetPackageForUserParametersPackageType::Docker => "docker".to_string(),
       PackagesGetPackageForUserParametersPackageType::Nuget => "nuget".to_string(),
       PackagesGetPackageForUserParametersPackageType::Container => "container".to_string(),
+      PackagesGetPackageForUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12261,6 +16752,13 @@ pub enum PackagesDeletePackageForUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12273,6 +16771,7 @@ impl ToString for PackagesDeletePackageForUserParametersPackageType {
       PackagesDeletePackageForUserParametersPackageType::Docker => "docker".to_string(),
       PackagesDeletePackageForUserParametersPackageType::Nuget => "nuget".to_string(),
       PackagesDeletePackageForUserParametersPackageType::Container => "container".to_string(),
+      PackagesDeletePackageForUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12292,6 +16791,13 @@ pub enum PackagesRestorePackageForUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12304,6 +16810,7 @@ impl ToString for PackagesRestorePackageForUserParametersPackageType {
       PackagesRestorePackageForUserParametersPackageType::Docker => "docker".to_string(),
       PackagesRestorePackageForUserParametersPackageType::Nuget => "nuget".to_string(),
       PackagesRestorePackageForUserParametersPackageType::Container => "container".to_string(),
+      PackagesRestorePackageForUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12323,6 +16830,13 @@ pub enum PackagesGetAllPackageVersionsForPackageOwnedByUserParametersPackageType
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12347,6 +16861,9 @@ impl ToString for PackagesGetAllPackageVersionsForPackageOwnedByUserParametersPa
       PackagesGetAllPackageVersionsForPackageOwnedByUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesGetAllPackageVersionsForPackageOwnedByUserParametersPackageType::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
@@ -12366,6 +16883,13 @@ pub enum PackagesGetPackageVersionForUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12378,6 +16902,7 @@ impl ToString for PackagesGetPackageVersionForUserParametersPackageType {
       PackagesGetPackageVersionForUserParametersPackageType::Docker => "docker".to_string(),
       PackagesGetPackageVersionForUserParametersPackageType::Nuget => "nuget".to_string(),
       PackagesGetPackageVersionForUserParametersPackageType::Container => "container".to_string(),
+      PackagesGetPackageVersionForUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12397,6 +16922,13 @@ pub enum PackagesDeletePackageVersionForUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12411,6 +16943,7 @@ impl ToString for PackagesDeletePackageVersionForUserParametersPackageType {
       PackagesDeletePackageVersionForUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesDeletePackageVersionForUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12430,6 +16963,13 @@ pub enum PackagesRestorePackageVersionForUserParametersPackageType {
   Nuget,
   #[serde(rename = "container")]
   Container,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "packages",))]
@@ -12444,6 +16984,7 @@ impl ToString for PackagesRestorePackageVersionForUserParametersPackageType {
       PackagesRestorePackageVersionForUserParametersPackageType::Container => {
         "container".to_string()
       }
+      PackagesRestorePackageVersionForUserParametersPackageType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12457,6 +16998,13 @@ pub enum ProjectsListForUserQueryState {
   Closed,
   #[serde(rename = "all")]
   All,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "projects",))]
@@ -12466,6 +17014,7 @@ impl ToString for ProjectsListForUserQueryState {
       ProjectsListForUserQueryState::Open => "open".to_string(),
       ProjectsListForUserQueryState::Closed => "closed".to_string(),
       ProjectsListForUserQueryState::All => "all".to_string(),
+      ProjectsListForUserQueryState::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12479,6 +17028,13 @@ pub enum ReposListForUserQueryType {
   Owner,
   #[serde(rename = "member")]
   Member,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -12488,6 +17044,7 @@ impl ToString for ReposListForUserQueryType {
       ReposListForUserQueryType::All => "all".to_string(),
       ReposListForUserQueryType::Owner => "owner".to_string(),
       ReposListForUserQueryType::Member => "member".to_string(),
+      ReposListForUserQueryType::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12503,6 +17060,13 @@ pub enum ReposListForUserQuerySort {
   Pushed,
   #[serde(rename = "full_name")]
   FullName,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -12513,6 +17077,7 @@ impl ToString for ReposListForUserQuerySort {
       ReposListForUserQuerySort::Updated => "updated".to_string(),
       ReposListForUserQuerySort::Pushed => "pushed".to_string(),
       ReposListForUserQuerySort::FullName => "full_name".to_string(),
+      ReposListForUserQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12524,6 +17089,13 @@ pub enum ReposListForUserQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -12532,6 +17104,7 @@ impl ToString for ReposListForUserQueryDirection {
     match self {
       ReposListForUserQueryDirection::Asc => "asc".to_string(),
       ReposListForUserQueryDirection::Desc => "desc".to_string(),
+      ReposListForUserQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12543,6 +17116,13 @@ pub enum ActivityListReposStarredByUserQuerySort {
   Created,
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "activity",))]
@@ -12551,6 +17131,7 @@ impl ToString for ActivityListReposStarredByUserQuerySort {
     match self {
       ActivityListReposStarredByUserQuerySort::Created => "created".to_string(),
       ActivityListReposStarredByUserQuerySort::Updated => "updated".to_string(),
+      ActivityListReposStarredByUserQuerySort::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12562,6 +17143,13 @@ pub enum ActivityListReposStarredByUserQueryDirection {
   Asc,
   #[serde(rename = "desc")]
   Desc,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "activity",))]
@@ -12570,6 +17158,7 @@ impl ToString for ActivityListReposStarredByUserQueryDirection {
     match self {
       ActivityListReposStarredByUserQueryDirection::Asc => "asc".to_string(),
       ActivityListReposStarredByUserQueryDirection::Desc => "desc".to_string(),
+      ActivityListReposStarredByUserQueryDirection::Unknown(value) => value.clone(),
     }
   }
 }
@@ -12747,7 +17336,77 @@ impl SecurityAdvisoriesListGlobalAdvisoriesQuery {
   feature = "pulls",
   feature = "search",
   feature = "users",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_github_app_authorization",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_personal_access_token_request",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_projects_v2",
+  feature = "webhook_projects_v2_item",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// A GitHub user.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -12777,7 +17436,7 @@ pub struct SimpleUser {
   pub site_admin: bool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub starred_at: Option<String>,
+  pub starred_at: Option<DateTime>,
   pub starred_url: String,
   pub subscriptions_url: String,
   #[serde(rename = "type")]
@@ -12809,7 +17468,77 @@ pub struct SimpleUser {
   feature = "pulls",
   feature = "search",
   feature = "users",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_github_app_authorization",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_personal_access_token_request",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_projects_v2",
+  feature = "webhook_projects_v2_item",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl SimpleUser {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -12965,7 +17694,7 @@ pub struct GlobalAdvisory {
   /// The date and time of when the advisory was reviewed by GitHub, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub github_reviewed_at: Option<String>,
+  pub github_reviewed_at: Option<DateTime>,
   /// The URL for the advisory.
   pub html_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -12975,9 +17704,9 @@ pub struct GlobalAdvisory {
   /// This field is only populated when the advisory is imported from the National Vulnerability Database.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub nvd_published_at: Option<String>,
+  pub nvd_published_at: Option<DateTime>,
   /// The date and time of when the advisory was published, in ISO 8601 format.
-  pub published_at: String,
+  pub published_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub references: Option<Vec<String>>,
@@ -12997,7 +17726,7 @@ pub struct GlobalAdvisory {
   #[serde(rename = "type")]
   pub type_: GlobalAdvisoryType,
   /// The date and time of when the advisory was last updated, in ISO 8601 format.
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// The API URL for the advisory.
   pub url: String,
   /// The products and respective version ranges affected by the advisory.
@@ -13007,7 +17736,7 @@ pub struct GlobalAdvisory {
   /// The date and time of when the advisory was withdrawn, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub withdrawn_at: Option<String>,
+  pub withdrawn_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "security_advisories",))]
@@ -13026,7 +17755,13 @@ impl GlobalAdvisory {
   feature = "repos",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_status",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_workflow_job",
 ))]
 /// The set of permissions for the GitHub app
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13058,7 +17793,13 @@ pub struct GitHubAppPermissions {
   feature = "repos",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_status",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_workflow_job",
 ))]
 impl GitHubAppPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13075,7 +17816,13 @@ impl GitHubAppPermissions {
   feature = "repos",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_status",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_workflow_job",
 ))]
 /// GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13087,7 +17834,7 @@ pub struct GitHubApp {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub client_secret: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -13116,7 +17863,7 @@ pub struct GitHubApp {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub slug: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub webhook_secret: Option<String>,
@@ -13131,7 +17878,13 @@ pub struct GitHubApp {
   feature = "repos",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_status",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_workflow_job",
 ))]
 impl GitHubApp {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13163,7 +17916,7 @@ impl AppsCreateFromManifestResponseItem2 {
   feature = "apps",
   feature = "orgs",
   feature = "repos",
-  feature = "webhook",
+  feature = "webhook_ping",
 ))]
 /// Configuration object of the webhook
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13188,7 +17941,7 @@ pub struct WebhookConfiguration {
   feature = "apps",
   feature = "orgs",
   feature = "repos",
-  feature = "webhook",
+  feature = "webhook_ping",
 ))]
 impl WebhookConfiguration {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13261,7 +18014,7 @@ pub struct SimpleWebhookDelivery {
   #[builder(default, setter(strip_option))]
   pub action: Option<String>,
   /// Time when the webhook delivery occurred.
-  pub delivered_at: String,
+  pub delivered_at: DateTime,
   /// Time spent delivering.
   pub duration: f64,
   /// The event that triggered the delivery.
@@ -13375,7 +18128,7 @@ pub struct WebhookDelivery {
   #[builder(default, setter(strip_option))]
   pub action: Option<String>,
   /// Time when the delivery was delivered.
-  pub delivered_at: String,
+  pub delivered_at: DateTime,
   /// Time spent delivering.
   pub duration: f64,
   /// The event that triggered the delivery.
@@ -13444,7 +18197,68 @@ impl AppsListInstallationRequestsForAuthenticatedAppQuery {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// An enterprise on GitHub.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13453,7 +18267,7 @@ pub struct Enterprise {
   pub avatar_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// A short description of the enterprise.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -13468,7 +18282,7 @@ pub struct Enterprise {
   pub slug: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   /// The enterprise's website URL.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -13479,7 +18293,68 @@ pub struct Enterprise {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl Enterprise {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13493,7 +18368,7 @@ impl Enterprise {
 #[builder(field_defaults(setter(into)))]
 pub struct IntegrationInstallationRequest {
   pub account: IntegrationInstallationRequestAccount,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// Unique identifier of the request installation.
   pub id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -13525,7 +18400,7 @@ pub struct AppsListInstallationsQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub outdated: Option<String>,
@@ -13542,7 +18417,10 @@ impl AppsListInstallationsQuery {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 /// The permissions granted to the user access token.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13746,7 +18624,10 @@ pub struct AppPermissions {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 impl AppPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13758,7 +18639,8 @@ impl AppPermissions {
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 /// Installation
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13771,7 +18653,7 @@ pub struct Installation {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub contact_email: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub events: Vec<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -13791,21 +18673,22 @@ pub struct Installation {
   pub single_file_paths: Option<Vec<String>>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub suspended_at: Option<String>,
+  pub suspended_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub suspended_by: Option<SimpleUser>,
   /// The ID of the user or organization this token is being scoped to.
   pub target_id: i64,
   pub target_type: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
 #[cfg(any(
   feature = "full",
   feature = "apps",
   feature = "orgs",
-  feature = "webhook",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
 ))]
 impl Installation {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13850,7 +18733,72 @@ impl AppsCreateInstallationAccessTokenRequest {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// License Simple
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13883,7 +18831,72 @@ pub struct LicenseSimple {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl LicenseSimple {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13902,7 +18915,72 @@ impl LicenseSimple {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -13929,7 +19007,72 @@ pub struct RepositoryPermissions {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl RepositoryPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -13948,7 +19091,72 @@ impl RepositoryPermissions {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 /// A repository on GitHub.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -13997,7 +19205,7 @@ pub struct Repository {
   pub contributors_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// The default branch of the repository.
   pub default_branch: String,
   /// Whether to delete head branches when pull requests are merged
@@ -14095,7 +19303,7 @@ pub struct Repository {
   pub pulls_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub pushed_at: Option<String>,
+  pub pushed_at: Option<DateTime>,
   pub releases_url: String,
   /// The size of the repository, in kilobytes. Size is calculated hourly. When a repository is initially created, the size is 0.
   pub size: i64,
@@ -14119,7 +19327,7 @@ pub struct Repository {
   pub stargazers_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub starred_at: Option<String>,
+  pub starred_at: Option<DateTime>,
   pub statuses_url: String,
   pub subscribers_url: String,
   pub subscription_url: String,
@@ -14135,7 +19343,7 @@ pub struct Repository {
   pub trees_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
   /// Whether a squash merge commit can use the pull request title as default. **This property has been deprecated. Please use `squash_merge_commit_title` instead.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -14164,7 +19372,72 @@ pub struct Repository {
   feature = "pulls",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
 ))]
 impl Repository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -14177,7 +19450,7 @@ impl Repository {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationToken {
-  pub expires_at: String,
+  pub expires_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub has_multiple_single_files: Option<bool>,
@@ -14285,10 +19558,10 @@ impl ScopedInstallation {
 #[builder(field_defaults(setter(into)))]
 pub struct Authorization {
   pub app: AuthorizationApp,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub expires_at: Option<String>,
+  pub expires_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub fingerprint: Option<String>,
@@ -14313,7 +19586,7 @@ pub struct Authorization {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub token_last_eight: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -14760,7 +20033,7 @@ impl ClassroomListAssignmentsForAClassroomQuery {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 /// Code Of Conduct
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -14791,7 +20064,7 @@ pub struct CodeOfConduct {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 impl CodeOfConduct {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -14873,7 +20146,11 @@ impl DependabotListAlertsForEnterpriseQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// Details for the vulnerable package.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -14884,7 +20161,11 @@ pub struct DependabotAlertPackage {
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -14922,7 +20203,7 @@ impl DependabotAlertWithRepositoryDependency {
   feature = "secret_scanning",
   feature = "code_scanning",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_repository_advisory",
 ))]
 /// A GitHub repository.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -15029,7 +20310,7 @@ pub struct SimpleRepository {
   feature = "secret_scanning",
   feature = "code_scanning",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_repository_advisory",
 ))]
 impl SimpleRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -15037,7 +20318,11 @@ impl SimpleRepository {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// Details for the advisory pertaining to the Common Vulnerability Scoring System.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15050,14 +20335,22 @@ pub struct DependabotAlertSecurityAdvisoryCvss {
   pub vector_string: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertSecurityAdvisoryCvss {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// A CWE weakness assigned to the advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15068,14 +20361,22 @@ pub struct DependabotAlertSecurityAdvisoryCwes {
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertSecurityAdvisoryCwes {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// An advisory identifier.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15087,14 +20388,22 @@ pub struct DependabotAlertSecurityAdvisoryIdentifiers {
   pub value: String,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertSecurityAdvisoryIdentifiers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// A link to additional advisory information.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15103,14 +20412,22 @@ pub struct DependabotAlertSecurityAdvisoryReferences {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertSecurityAdvisoryReferences {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// Details pertaining to the package version that patches this vulnerability.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15119,14 +20436,22 @@ pub struct DependabotAlertSecurityVulnerabilityFirstPatchedVersion {
   pub identifier: String,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertSecurityVulnerabilityFirstPatchedVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// Details pertaining to one vulnerable version range for the advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15142,14 +20467,22 @@ pub struct DependabotAlertSecurityVulnerability {
   pub vulnerable_version_range: String,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertSecurityVulnerability {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// Details for the GitHub Security Advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15169,7 +20502,7 @@ pub struct DependabotAlertSecurityAdvisory {
   /// Values that identify this advisory among security information sources.
   pub identifiers: Vec<DependabotAlertSecurityAdvisoryIdentifiers>,
   /// The time that the advisory was published in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
-  pub published_at: String,
+  pub published_at: DateTime,
   /// Links to additional advisory information.
   pub references: Vec<DependabotAlertSecurityAdvisoryReferences>,
   /// The severity of the advisory.
@@ -15177,16 +20510,20 @@ pub struct DependabotAlertSecurityAdvisory {
   /// A short, plain text summary of the advisory.
   pub summary: String,
   /// The time that the advisory was last modified in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// Vulnerable version range information for the advisory.
   pub vulnerabilities: Vec<DependabotAlertSecurityVulnerability>,
   /// The time that the advisory was withdrawn in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub withdrawn_at: Option<String>,
+  pub withdrawn_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertSecurityAdvisory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -15200,13 +20537,13 @@ impl DependabotAlertSecurityAdvisory {
 pub struct DependabotAlertWithRepository {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub auto_dismissed_at: Option<String>,
-  pub created_at: String,
+  pub auto_dismissed_at: Option<DateTime>,
+  pub created_at: DateTime,
   /// Details for the vulnerable dependency.
   pub dependency: DependabotAlertWithRepositoryDependency,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<SimpleUser>,
@@ -15220,7 +20557,7 @@ pub struct DependabotAlertWithRepository {
   pub dismissed_reason: Option<DependabotAlertWithRepositoryDismissedReason>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub fixed_at: Option<String>,
+  pub fixed_at: Option<DateTime>,
   pub html_url: String,
   pub number: i64,
   pub repository: SimpleRepository,
@@ -15228,7 +20565,7 @@ pub struct DependabotAlertWithRepository {
   pub security_vulnerability: DependabotAlertSecurityVulnerability,
   /// The state of the Dependabot alert.
   pub state: DependabotAlertWithRepositoryState,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -15297,7 +20634,7 @@ impl SecretScanningListAlertsForEnterpriseQuery {
 pub struct OrganizationSecretScanningAlert {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub html_url: Option<String>,
@@ -15315,7 +20652,7 @@ pub struct OrganizationSecretScanningAlert {
   /// The time that push protection was bypassed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub push_protection_bypassed_at: Option<String>,
+  pub push_protection_bypassed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub push_protection_bypassed_by: Option<SimpleUser>,
@@ -15332,7 +20669,7 @@ pub struct OrganizationSecretScanningAlert {
   /// The time that the alert was resolved in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub resolved_at: Option<String>,
+  pub resolved_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub resolved_by: Option<SimpleUser>,
@@ -15425,7 +20762,11 @@ impl Actor {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_release",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15452,7 +20793,11 @@ pub struct ReactionRollup {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_release",
 ))]
 impl ReactionRollup {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -15464,7 +20809,7 @@ impl ReactionRollup {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
 ))]
 /// Comments provide a way for people to collaborate on an issue.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -15481,7 +20826,7 @@ pub struct IssueComment {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub body_text: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub html_url: String,
   /// Unique identifier of the issue comment
   pub id: i64,
@@ -15493,7 +20838,7 @@ pub struct IssueComment {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub reactions: Option<ReactionRollup>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL for the issue comment
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -15505,7 +20850,7 @@ pub struct IssueComment {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
 ))]
 impl IssueComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -15517,7 +20862,8 @@ impl IssueComment {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15550,7 +20896,8 @@ pub struct IssueLabelsItem2 {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 impl IssueLabelsItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -15565,7 +20912,14 @@ impl IssueLabelsItem2 {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_milestone",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// A collection of related issues and pull requests.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -15573,9 +20927,9 @@ impl IssueLabelsItem2 {
 pub struct Milestone {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub closed_issues: i64,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<SimpleUser>,
@@ -15596,7 +20950,7 @@ pub struct Milestone {
   pub state: MilestoneState,
   /// The title of the milestone.
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -15607,7 +20961,14 @@ pub struct Milestone {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_milestone",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl Milestone {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -15619,7 +20980,8 @@ impl Milestone {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15632,7 +20994,7 @@ pub struct IssuePullRequest {
   pub html_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub patch_url: Option<String>,
@@ -15645,7 +21007,8 @@ pub struct IssuePullRequest {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 impl IssuePullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -15657,7 +21020,8 @@ impl IssuePullRequest {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 /// Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -15685,13 +21049,13 @@ pub struct Issue {
   pub body_text: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub closed_by: Option<SimpleUser>,
   pub comments: i64,
   pub comments_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub draft: Option<bool>,
@@ -15732,7 +21096,7 @@ pub struct Issue {
   pub timeline_url: Option<String>,
   /// Title of the issue
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL for the issue
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -15744,7 +21108,8 @@ pub struct Issue {
   feature = "full",
   feature = "activity",
   feature = "issues",
-  feature = "webhook",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
 ))]
 impl Issue {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -15832,7 +21197,7 @@ pub struct Event {
   pub actor: Actor,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   pub id: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -15961,7 +21326,7 @@ pub struct GistsListQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -15987,7 +21352,7 @@ pub struct BaseGist {
   pub comments: i64,
   pub comments_url: String,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -16011,7 +21376,7 @@ pub struct BaseGist {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub truncated: Option<bool>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -16055,7 +21420,7 @@ pub struct Gist {
   pub comments: i64,
   pub comments_url: String,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -16079,7 +21444,7 @@ pub struct Gist {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub truncated: Option<bool>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -16128,7 +21493,7 @@ pub struct PublicUser {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub company: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub disk_usage: Option<i64>,
@@ -16176,7 +21541,7 @@ pub struct PublicUser {
   pub subscriptions_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub suspended_at: Option<String>,
+  pub suspended_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub total_private_repos: Option<i64>,
@@ -16185,7 +21550,7 @@ pub struct PublicUser {
   pub twitter_username: Option<String>,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -16202,13 +21567,13 @@ impl PublicUser {
 pub struct GistSimpleForks {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub id: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -16256,7 +21621,7 @@ pub struct GistHistory {
   pub change_status: Option<GistHistoryChangeStatus>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub committed_at: Option<String>,
+  pub committed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -16291,7 +21656,7 @@ pub struct GistSimple {
   pub commits_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -16337,7 +21702,7 @@ pub struct GistSimple {
   pub truncated: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -16361,7 +21726,7 @@ pub struct GistsListPublicQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -16387,7 +21752,7 @@ pub struct GistsListStarredQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -16460,10 +21825,10 @@ pub struct GistComment {
   pub author_association: AuthorAssociation,
   /// The comment text.
   pub body: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub id: i64,
   pub node_id: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -16557,7 +21922,7 @@ impl GistCommitChangeStatus {
 #[builder(field_defaults(setter(into)))]
 pub struct GistCommit {
   pub change_status: GistCommitChangeStatus,
-  pub committed_at: String,
+  pub committed_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -16650,6 +22015,19 @@ impl AppsListReposAccessibleToInstallationResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "apps",)))]
+impl PaginatedItems for AppsListReposAccessibleToInstallationResponse {
+  type Item = Repository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "issues",))]
 /// Query for `List issues assigned to the authenticated user`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -16678,7 +22056,7 @@ pub struct IssuesListQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub collab: Option<bool>,
@@ -16787,7 +22165,11 @@ impl MarkdownRenderRequest {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 /// Marketplace Listing Plan
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -16809,14 +22191,22 @@ pub struct MarketplaceListingPlan {
   pub yearly_price_in_cents: i64,
 }
 
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 impl MarketplaceListingPlan {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MarketplacePurchaseMarketplacePendingChange {
@@ -16837,14 +22227,22 @@ pub struct MarketplacePurchaseMarketplacePendingChange {
   pub unit_count: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 impl MarketplacePurchaseMarketplacePendingChange {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MarketplacePurchaseMarketplacePurchase {
@@ -16871,17 +22269,25 @@ pub struct MarketplacePurchaseMarketplacePurchase {
   pub unit_count: Option<i64>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 impl MarketplacePurchaseMarketplacePurchase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 /// Marketplace Purchase
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -16903,7 +22309,11 @@ pub struct MarketplacePurchase {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "apps", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "apps",
+  feature = "webhook_marketplace_purchase",
+))]
 impl MarketplacePurchase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -17163,11 +22573,11 @@ pub struct ActivityListNotificationsForAuthenticatedUserQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub before: Option<String>,
+  pub before: Option<DateTime>,
   /// The page number of the results to fetch. For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -17198,7 +22608,7 @@ impl ActivityListNotificationsForAuthenticatedUserQuery {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17233,7 +22643,7 @@ pub struct MinimalRepositoryLicense {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 impl MinimalRepositoryLicense {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17254,7 +22664,7 @@ impl MinimalRepositoryLicense {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17289,7 +22699,7 @@ pub struct MinimalRepositoryPermissions {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 impl MinimalRepositoryPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17311,7 +22721,8 @@ impl MinimalRepositoryPermissions {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17335,7 +22746,8 @@ pub struct SecurityAndAnalysisAdvancedSecurity {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl SecurityAndAnalysisAdvancedSecurity {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17357,7 +22769,8 @@ impl SecurityAndAnalysisAdvancedSecurity {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 /// Enable or disable Dependabot security updates for the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -17383,7 +22796,8 @@ pub struct SecurityAndAnalysisDependabotSecurityUpdates {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl SecurityAndAnalysisDependabotSecurityUpdates {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17405,7 +22819,8 @@ impl SecurityAndAnalysisDependabotSecurityUpdates {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17429,7 +22844,8 @@ pub struct SecurityAndAnalysisSecretScanning {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl SecurityAndAnalysisSecretScanning {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17451,7 +22867,8 @@ impl SecurityAndAnalysisSecretScanning {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17475,7 +22892,8 @@ pub struct SecurityAndAnalysisSecretScanningPushProtection {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl SecurityAndAnalysisSecretScanningPushProtection {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17497,7 +22915,8 @@ impl SecurityAndAnalysisSecretScanningPushProtection {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17531,7 +22950,8 @@ pub struct SecurityAndAnalysis {
   feature = "checks",
   feature = "security_advisories",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_workflow_run",
 ))]
 impl SecurityAndAnalysis {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17552,7 +22972,7 @@ impl SecurityAndAnalysis {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 /// Minimal Repository
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -17582,7 +23002,7 @@ pub struct MinimalRepository {
   pub contributors_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub default_branch: Option<String>,
@@ -17677,7 +23097,7 @@ pub struct MinimalRepository {
   pub pulls_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub pushed_at: Option<String>,
+  pub pushed_at: Option<DateTime>,
   pub releases_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -17716,7 +23136,7 @@ pub struct MinimalRepository {
   pub trees_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -17745,7 +23165,7 @@ pub struct MinimalRepository {
   feature = "teams",
   feature = "checks",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_workflow_run",
 ))]
 impl MinimalRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -17779,13 +23199,13 @@ pub struct Thread {
   pub id: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_read_at: Option<String>,
+  pub last_read_at: Option<DateTime>,
   pub reason: String,
   pub repository: MinimalRepository,
   pub subject: ThreadSubject,
   pub subscription_url: String,
   pub unread: bool,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -17803,7 +23223,7 @@ pub struct ActivityMarkNotificationsAsReadRequest {
   /// Describes the last point that notifications were checked. Anything updated since this time will not be marked as read. If you omit this parameter, all notifications are marked as read. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`. Default: The current timestamp.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_read_at: Option<String>,
+  pub last_read_at: Option<DateTime>,
   /// Whether the notification has been read.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -17840,7 +23260,7 @@ impl ActivityMarkNotificationsAsReadResponse {
 pub struct ThreadSubscription {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   pub ignored: bool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -17919,7 +23339,80 @@ impl OrgsListQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_personal_access_token_request",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_projects_v2",
+  feature = "webhook_projects_v2_item",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
+))]
 /// A GitHub organization.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17940,7 +23433,80 @@ pub struct OrganizationSimple {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_personal_access_token_request",
+  feature = "webhook_ping",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_projects_v2",
+  feature = "webhook_projects_v2_item",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
+))]
 impl OrganizationSimple {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -17982,7 +23548,7 @@ pub struct OrganizationFull {
   pub advanced_security_enabled_for_new_repositories: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub archived_at: Option<String>,
+  pub archived_at: Option<DateTime>,
   pub avatar_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -17996,7 +23562,7 @@ pub struct OrganizationFull {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub company: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub default_repository_permission: Option<String>,
@@ -18124,7 +23690,7 @@ pub struct OrganizationFull {
   pub two_factor_requirement_enabled: Option<bool>,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -18366,6 +23932,19 @@ impl ActionsGetActionsCacheUsageByRepoForOrgResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsGetActionsCacheUsageByRepoForOrgResponse {
+  type Item = ActionsCacheUsageByRepository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repository_cache_usages
+  }
+}
 #[cfg(any(feature = "full", feature = "oidc",))]
 /// Actions OIDC Subject customization
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -18491,6 +24070,19 @@ impl ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationResponse {
+  type Item = Repository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count as i64)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -18661,6 +24253,19 @@ impl ActionsListSelfHostedRunnersForOrgResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListSelfHostedRunnersForOrgResponse {
+  type Item = SelfHostedRunners;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.runners
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 /// Runner Application
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -18715,7 +24320,7 @@ impl ActionsGenerateRunnerJitconfigForOrgRequest {
 #[builder(field_defaults(setter(into)))]
 pub struct AuthenticationToken {
   /// The time this token expires
-  pub expires_at: String,
+  pub expires_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub permissions: Option<serde_json::Value>,
@@ -18798,13 +24403,13 @@ impl ActionsListOrgSecretsQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ActionsSecretForAnOrganization {
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the secret.
   pub name: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub selected_repositories_url: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// Visibility of a secret
   pub visibility: ActionsSecretForAnOrganizationVisibility,
 }
@@ -18831,6 +24436,19 @@ impl ActionsListOrgSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListOrgSecretsResponse {
+  type Item = ActionsSecretForAnOrganization;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 /// The public key used for setting Actions Secrets.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -18838,7 +24456,7 @@ impl ActionsListOrgSecretsResponse {
 pub struct ActionsPublicKey {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub id: Option<i64>,
@@ -18925,6 +24543,19 @@ impl ActionsListSelectedReposForOrgSecretResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListSelectedReposForOrgSecretResponse {
+  type Item = MinimalRepository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -18968,14 +24599,14 @@ impl ActionsListOrgVariablesQuery {
 #[builder(field_defaults(setter(into)))]
 pub struct ActionsVariableForAnOrganization {
   /// The date and time at which the variable was created, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the variable.
   pub name: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub selected_repositories_url: Option<String>,
   /// The date and time at which the variable was last updated, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// The value of the variable.
   pub value: String,
   /// Visibility of a variable
@@ -19004,6 +24635,19 @@ impl ActionsListOrgVariablesResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListOrgVariablesResponse {
+  type Item = ActionsVariableForAnOrganization;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.variables
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19093,6 +24737,19 @@ impl ActionsListSelectedReposForOrgVariableResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListSelectedReposForOrgVariableResponse {
+  type Item = MinimalRepository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19338,10 +24995,10 @@ impl CodeScanningAnalysisTool {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CodeScanningOrganizationAlertItems {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<SimpleUser>,
@@ -19353,7 +25010,7 @@ pub struct CodeScanningOrganizationAlertItems {
   pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub fixed_at: Option<String>,
+  pub fixed_at: Option<DateTime>,
   pub html_url: String,
   pub instances_url: String,
   pub most_recent_instance: CodeScanningAlertInstance,
@@ -19364,7 +25021,7 @@ pub struct CodeScanningOrganizationAlertItems {
   pub tool: CodeScanningAnalysisTool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
 }
 
@@ -19485,7 +25142,7 @@ impl CodespaceRuntimeConstraints {
 #[builder(field_defaults(setter(into)))]
 pub struct Codespace {
   pub billable_owner: SimpleUser,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// Path to devcontainer.json from repo root used to create Codespace.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -19514,7 +25171,7 @@ pub struct Codespace {
   #[builder(default, setter(strip_option))]
   pub last_known_stop_notice: Option<String>,
   /// Last known time this codespace was started.
-  pub last_used_at: String,
+  pub last_used_at: DateTime,
   /// The initally assigned location of a new codespace.
   pub location: CodespaceLocation,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -19550,7 +25207,7 @@ pub struct Codespace {
   /// When a codespace will be auto-deleted based on the "retention_period_minutes" and "last_used_at"
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub retention_expires_at: Option<String>,
+  pub retention_expires_at: Option<DateTime>,
   /// Duration in minutes after codespace has gone idle in which it will be deleted. Must be integer minutes between 0 and 43200 (30 days).
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -19564,7 +25221,7 @@ pub struct Codespace {
   pub state: CodespaceState,
   /// API URL to stop this codespace.
   pub stop_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// API URL for this codespace.
   pub url: String,
   /// URL to access this codespace on the web.
@@ -19593,6 +25250,19 @@ impl CodespacesListInOrganizationResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListInOrganizationResponse {
+  type Item = Codespace;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.codespaces
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19670,7 +25340,7 @@ impl CodespacesListOrgSecretsQuery {
 #[builder(field_defaults(setter(into)))]
 pub struct CodespacesSecret {
   /// The date and time at which the secret was created, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the secret
   pub name: String,
   /// The API URL at which the list of repositories this secret is visible to can be retrieved
@@ -19678,7 +25348,7 @@ pub struct CodespacesSecret {
   #[builder(default, setter(strip_option))]
   pub selected_repositories_url: Option<String>,
   /// The date and time at which the secret was created, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// The type of repositories in the organization that the secret is visible to
   pub visibility: CodespacesSecretVisibility,
 }
@@ -19705,6 +25375,19 @@ impl CodespacesListOrgSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListOrgSecretsResponse {
+  type Item = CodespacesSecret;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 /// The public key used for setting Codespaces secrets.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -19712,7 +25395,7 @@ impl CodespacesListOrgSecretsResponse {
 pub struct CodespacesPublicKey {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub id: Option<i64>,
@@ -19799,6 +25482,19 @@ impl CodespacesListSelectedReposForOrgSecretResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListSelectedReposForOrgSecretResponse {
+  type Item = MinimalRepository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19915,7 +25611,15 @@ impl CopilotListCopilotSeatsQuery {
   feature = "repos",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_membership",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
 ))]
 /// Groups of organization members that gives permissions on specified repositories.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -19962,7 +25666,15 @@ pub struct TeamSimple {
   feature = "repos",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_membership",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
 ))]
 impl TeamSimple {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -19980,7 +25692,14 @@ impl TeamSimple {
   feature = "repos",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_membership",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -20002,7 +25721,14 @@ pub struct TeamPermissions {
   feature = "repos",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_membership",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
 ))]
 impl TeamPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -20020,7 +25746,14 @@ impl TeamPermissions {
   feature = "repos",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_membership",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
 ))]
 /// Groups of organization members that gives permissions on specified repositories.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -20062,7 +25795,14 @@ pub struct Team {
   feature = "repos",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_membership",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
 ))]
 impl Team {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -20070,7 +25810,7 @@ impl Team {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "copilot", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "copilot", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationPlan {
@@ -20091,14 +25831,14 @@ pub struct OrganizationPlan {
   pub space: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "copilot", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "copilot", feature = "webhook_repository",))]
 impl OrganizationPlan {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "copilot", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "copilot", feature = "webhook_repository",))]
 /// GitHub account for managing multiple users, teams, and repositories
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -20112,7 +25852,7 @@ pub struct Organization {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub company: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -20155,12 +25895,12 @@ pub struct Organization {
   pub repos_url: String,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL for the organization
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "copilot", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "copilot", feature = "webhook_repository",))]
 impl Organization {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -20179,11 +25919,11 @@ pub struct CopilotBusinessSeatDetail {
   #[builder(default, setter(strip_option))]
   pub assigning_team: Option<Team>,
   /// Timestamp of when the assignee was last granted access to GitHub Copilot, in ISO 8601 format.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// Timestamp of user's last GitHub Copilot activity, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_activity_at: Option<String>,
+  pub last_activity_at: Option<DateTime>,
   /// Last editor that was used by the user for a GitHub Copilot completion.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -20195,7 +25935,7 @@ pub struct CopilotBusinessSeatDetail {
   /// Timestamp of when the assignee's GitHub Copilot access was last updated, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "copilot",))]
@@ -20446,13 +26186,13 @@ impl DependabotListOrgSecretsQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotSecretForAnOrganization {
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the secret.
   pub name: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub selected_repositories_url: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// Visibility of a secret
   pub visibility: DependabotSecretForAnOrganizationVisibility,
 }
@@ -20479,6 +26219,19 @@ impl DependabotListOrgSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "dependabot",)))]
+impl PaginatedItems for DependabotListOrgSecretsResponse {
+  type Item = DependabotSecretForAnOrganization;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "dependabot",))]
 /// The public key used for setting Dependabot Secrets.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -20561,6 +26314,19 @@ impl DependabotListSelectedReposForOrgSecretResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "dependabot",)))]
+impl PaginatedItems for DependabotListSelectedReposForOrgSecretResponse {
+  type Item = MinimalRepository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "dependabot",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -20581,7 +26347,7 @@ impl DependabotSetSelectedReposForOrgSecretRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct Package {
-  pub created_at: String,
+  pub created_at: DateTime,
   pub html_url: String,
   /// Unique identifier of the package.
   pub id: i64,
@@ -20594,7 +26360,7 @@ pub struct Package {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub repository: Option<MinimalRepository>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   /// The number of versions of the package.
   pub version_count: i64,
@@ -20657,13 +26423,13 @@ impl OrgsListFailedInvitationsQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationInvitation {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub email: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub failed_at: Option<String>,
+  pub failed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub failed_reason: Option<String>,
@@ -20742,7 +26508,7 @@ impl OrgHookConfig {
 pub struct OrgHook {
   pub active: bool,
   pub config: OrgHookConfig,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub deliveries_url: Option<String>,
@@ -20752,7 +26518,7 @@ pub struct OrgHook {
   pub ping_url: String,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -20957,12 +26723,25 @@ impl OrgsListAppInstallationsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "orgs",)))]
+impl PaginatedItems for OrgsListAppInstallationsResponse {
+  type Item = Installation;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.installations
+  }
+}
 #[cfg(any(feature = "full", feature = "interactions",))]
 /// Interaction limit settings.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InteractionLimits {
-  pub expires_at: String,
+  pub expires_at: DateTime,
   pub limit: InteractionGroup,
   pub origin: String,
 }
@@ -21117,7 +26896,7 @@ pub struct IssuesListForOrgQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -21202,6 +26981,19 @@ impl CodespacesGetCodespacesForUserInOrgResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesGetCodespacesForUserInOrgResponse {
+  type Item = Codespace;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.codespaces
+  }
+}
 #[cfg(any(feature = "full", feature = "orgs",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -21294,7 +27086,7 @@ pub struct Migration {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub archive_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// Exclude related items from being returned in the response in order to improve performance of the request. The array can include any of: `"repositories"`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -21315,7 +27107,7 @@ pub struct Migration {
   /// The repositories included in the migration. Only returned for export migrations.
   pub repositories: Vec<Repository>,
   pub state: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -21433,7 +27225,7 @@ impl OrganizationFineGrainedPermission {
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationRole {
   /// The date and time the role was created.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// A short description about who this role is for or what permissions it grants.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -21448,7 +27240,7 @@ pub struct OrganizationRole {
   /// A list of permissions included in this role.
   pub permissions: Vec<String>,
   /// The date and time the role was last updated.
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -21479,6 +27271,19 @@ impl OrgsListOrgRolesResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "orgs",)))]
+impl PaginatedItems for OrgsListOrgRolesResponse {
+  type Item = OrganizationRole;
+
+  fn total_count(&self) -> Option<i64> {
+    self.total_count
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.roles.unwrap_or_default()
+  }
+}
 #[cfg(any(feature = "full", feature = "orgs",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -21755,10 +27560,10 @@ impl PackageVersionMetadata {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageVersion {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub deleted_at: Option<String>,
+  pub deleted_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -21776,7 +27581,7 @@ pub struct PackageVersion {
   /// The name of the package version.
   pub name: String,
   pub package_html_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -21821,11 +27626,11 @@ pub struct OrgsListPatGrantRequestsQuery {
   /// Only show fine-grained personal access tokens used before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_used_before: Option<String>,
+  pub last_used_before: Option<DateTime>,
   /// Only show fine-grained personal access tokens used after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_used_after: Option<String>,
+  pub last_used_after: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -21864,7 +27669,7 @@ impl SimpleOrganizationProgrammaticAccessGrantRequestPermissions {
 #[builder(field_defaults(setter(into)))]
 pub struct SimpleOrganizationProgrammaticAccessGrantRequest {
   /// Date and time when the request for access was created.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// Unique identifier of the request for access via fine-grained personal access token. The `pat_request_id` used to review PAT requests.
   pub id: i64,
   pub owner: SimpleUser,
@@ -21883,11 +27688,11 @@ pub struct SimpleOrganizationProgrammaticAccessGrantRequest {
   /// Date and time when the associated fine-grained personal access token expires.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub token_expires_at: Option<String>,
+  pub token_expires_at: Option<DateTime>,
   /// Date and time when the associated fine-grained personal access token was last used for authentication.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub token_last_used_at: Option<String>,
+  pub token_last_used_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -21995,11 +27800,11 @@ pub struct OrgsListPatGrantsQuery {
   /// Only show fine-grained personal access tokens used before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_used_before: Option<String>,
+  pub last_used_before: Option<DateTime>,
   /// Only show fine-grained personal access tokens used after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_used_after: Option<String>,
+  pub last_used_after: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -22038,7 +27843,7 @@ impl OrganizationProgrammaticAccessGrantPermissions {
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationProgrammaticAccessGrant {
   /// Date and time when the fine-grained personal access token was approved to access the organization.
-  pub access_granted_at: String,
+  pub access_granted_at: DateTime,
   /// Unique identifier of the fine-grained personal access token. The `pat_id` used to get details about an approved fine-grained personal access token.
   pub id: i64,
   pub owner: SimpleUser,
@@ -22053,11 +27858,11 @@ pub struct OrganizationProgrammaticAccessGrant {
   /// Date and time when the associated fine-grained personal access token expires.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub token_expires_at: Option<String>,
+  pub token_expires_at: Option<DateTime>,
   /// Date and time when the associated fine-grained personal access token was last used for authentication.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub token_last_used_at: Option<String>,
+  pub token_last_used_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "orgs",))]
@@ -22147,7 +27952,7 @@ impl ProjectsListForOrgQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "projects", feature = "webhook_project",))]
 /// Projects are a way to organize columns and cards of work.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22157,7 +27962,7 @@ pub struct Project {
   #[builder(default, setter(strip_option))]
   pub body: Option<String>,
   pub columns_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<SimpleUser>,
@@ -22178,11 +27983,11 @@ pub struct Project {
   pub private: Option<bool>,
   /// State of the project; either 'open' or 'closed'
   pub state: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "projects", feature = "webhook_project",))]
 impl Project {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -22208,7 +28013,11 @@ impl ProjectsCreateForOrgRequest {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_custom_property",
+))]
 /// Custom property defined on an organization
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22240,7 +28049,11 @@ pub struct OrganizationCustomProperty {
   pub values_editable_by: Option<OrganizationCustomPropertyValuesEditableBy>,
 }
 
-#[cfg(any(feature = "full", feature = "orgs", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "orgs",
+  feature = "webhook_custom_property",
+))]
 impl OrganizationCustomProperty {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -22324,7 +28137,7 @@ impl OrgsListCustomPropertiesValuesForReposQuery {
   feature = "full",
   feature = "orgs",
   feature = "repos",
-  feature = "webhook",
+  feature = "webhook_custom_property_values",
 ))]
 /// Custom property name and associated value
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -22340,7 +28153,7 @@ pub struct CustomPropertyValue {
   feature = "full",
   feature = "orgs",
   feature = "repos",
-  feature = "webhook",
+  feature = "webhook_custom_property_values",
 ))]
 impl CustomPropertyValue {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -22570,7 +28383,7 @@ impl ReposCreateInOrgRequest {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 /// Code of Conduct Simple
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -22588,7 +28401,7 @@ pub struct CodeOfConductSimple {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 impl CodeOfConductSimple {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -22600,7 +28413,7 @@ impl CodeOfConductSimple {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22620,7 +28433,7 @@ pub struct FullRepositoryPermissions {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 impl FullRepositoryPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -22632,7 +28445,7 @@ impl FullRepositoryPermissions {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 /// Full Repository
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -22675,7 +28488,7 @@ pub struct FullRepository {
   pub compare_url: String,
   pub contents_url: String,
   pub contributors_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The custom properties that were defined for the repository. The keys are the custom property names, and the values are the corresponding custom property values.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22771,7 +28584,7 @@ pub struct FullRepository {
   pub permissions: Option<FullRepositoryPermissions>,
   pub private: bool,
   pub pulls_url: String,
-  pub pushed_at: String,
+  pub pushed_at: DateTime,
   pub releases_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22816,7 +28629,7 @@ pub struct FullRepository {
   #[builder(default, setter(strip_option))]
   pub topics: Option<Vec<String>>,
   pub trees_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22836,7 +28649,7 @@ pub struct FullRepository {
   feature = "full",
   feature = "repos",
   feature = "security_advisories",
-  feature = "webhook",
+  feature = "webhook_security_and_analysis",
 ))]
 impl FullRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -22866,7 +28679,11 @@ impl ReposGetOrgRulesetsQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetLinksHtml {
@@ -22876,14 +28693,22 @@ pub struct RepositoryRulesetLinksHtml {
   pub href: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetLinksHtml {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetLinksSelf {
@@ -22893,14 +28718,22 @@ pub struct RepositoryRulesetLinksSelf {
   pub href: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetLinksSelf {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetLinks {
@@ -22913,14 +28746,22 @@ pub struct RepositoryRulesetLinks {
   pub self_: Option<RepositoryRulesetLinksSelf>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// An actor that can bypass rules in a ruleset
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22933,14 +28774,22 @@ pub struct RepositoryRulesetBypassActor {
   pub bypass_mode: RepositoryRulesetBypassActorBypassMode,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetBypassActor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetConditionsForRefNamesRefName {
@@ -22954,14 +28803,22 @@ pub struct RepositoryRulesetConditionsForRefNamesRefName {
   pub include: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRefNamesRefName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters for a repository ruleset ref name condition
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22971,14 +28828,22 @@ pub struct RepositoryRulesetConditionsForRefNames {
   pub ref_name: Option<RepositoryRulesetConditionsForRefNamesRefName>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRefNames {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetConditionsForRepositoryNamesRepositoryName {
@@ -22996,14 +28861,22 @@ pub struct RepositoryRulesetConditionsForRepositoryNamesRepositoryName {
   pub protected: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRepositoryNamesRepositoryName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters for a repository name condition
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23011,14 +28884,22 @@ pub struct RepositoryRulesetConditionsForRepositoryNames {
   pub repository_name: RepositoryRulesetConditionsForRepositoryNamesRepositoryName,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRepositoryNames {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetConditionsForRepositoryIDsRepositoryId {
@@ -23028,14 +28909,22 @@ pub struct RepositoryRulesetConditionsForRepositoryIDsRepositoryId {
   pub repository_ids: Option<Vec<i64>>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRepositoryIDsRepositoryId {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters for a repository ID condition
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23043,14 +28932,22 @@ pub struct RepositoryRulesetConditionsForRepositoryIDs {
   pub repository_id: RepositoryRulesetConditionsForRepositoryIDsRepositoryId,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRepositoryIDs {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters for a targeting a repository property
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23061,14 +28958,22 @@ pub struct RepositoryRulesetPropertyTargetingDefinition {
   pub property_values: Vec<String>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetPropertyTargetingDefinition {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetConditionsForRepositoryPropertiesRepositoryProperty {
@@ -23082,14 +28987,22 @@ pub struct RepositoryRulesetConditionsForRepositoryPropertiesRepositoryProperty
   pub include: Option<Vec<RepositoryRulesetPropertyTargetingDefinition>>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRepositoryPropertiesRepositoryProperty {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters for a repository property condition
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23097,14 +29010,22 @@ pub struct RepositoryRulesetConditionsForRepositoryProperties {
   pub repository_property: RepositoryRulesetConditionsForRepositoryPropertiesRepositoryProperty,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulesetConditionsForRepositoryProperties {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Only allow users with bypass permission to create matching refs.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23113,14 +29034,22 @@ pub struct Creation {
   pub type_: CreationType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl Creation {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct UpdateParameters {
@@ -23128,14 +29057,22 @@ pub struct UpdateParameters {
   pub update_allows_fetch_and_merge: bool,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl UpdateParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Only allow users with bypass permission to update matching refs.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23147,14 +29084,22 @@ pub struct Update {
   pub type_: UpdateType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl Update {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Only allow users with bypass permissions to delete matching refs.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23163,14 +29108,22 @@ pub struct Deletion {
   pub type_: DeletionType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl Deletion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Prevent merge commits from being pushed to matching refs.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23179,14 +29132,22 @@ pub struct RepositoryRuleRequiredLinearHistory {
   pub type_: RepositoryRuleRequiredLinearHistoryType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleRequiredLinearHistory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRuleRequiredDeploymentsParameters {
@@ -23194,14 +29155,22 @@ pub struct RepositoryRuleRequiredDeploymentsParameters {
   pub required_deployment_environments: Vec<String>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleRequiredDeploymentsParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Choose which environments must be successfully deployed to before refs can be pushed into a ref that matches this rule.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23213,14 +29182,22 @@ pub struct RepositoryRuleRequiredDeployments {
   pub type_: RepositoryRuleRequiredDeploymentsType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleRequiredDeployments {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Commits pushed to matching refs must have verified signatures.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23229,14 +29206,22 @@ pub struct RepositoryRuleRequiredSignatures {
   pub type_: RepositoryRuleRequiredSignaturesType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleRequiredSignatures {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulePullRequestParameters {
@@ -23252,14 +29237,22 @@ pub struct RepositoryRulePullRequestParameters {
   pub required_review_thread_resolution: bool,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulePullRequestParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Require all commits be made to a non-target branch and submitted via a pull request before they can be merged.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23271,14 +29264,22 @@ pub struct RepositoryRulePullRequest {
   pub type_: RepositoryRulePullRequestType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRulePullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Required status check
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23291,14 +29292,22 @@ pub struct StatusCheckConfiguration {
   pub integration_id: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl StatusCheckConfiguration {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRuleRequiredStatusChecksParameters {
@@ -23308,14 +29317,22 @@ pub struct RepositoryRuleRequiredStatusChecksParameters {
   pub strict_required_status_checks_policy: bool,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleRequiredStatusChecksParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Choose which status checks must pass before the ref is updated. When enabled, commits must first be pushed to another ref where the checks pass.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23327,14 +29344,22 @@ pub struct RepositoryRuleRequiredStatusChecks {
   pub type_: RepositoryRuleRequiredStatusChecksType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleRequiredStatusChecks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Prevent users with push access from force pushing to refs.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23343,14 +29368,22 @@ pub struct RepositoryRuleNonFastForward {
   pub type_: RepositoryRuleNonFastForwardType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleNonFastForward {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRuleCommitMessagePatternParameters {
@@ -23368,14 +29401,22 @@ pub struct RepositoryRuleCommitMessagePatternParameters {
   pub pattern: String,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleCommitMessagePatternParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters to be used for the commit_message_pattern rule
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23387,14 +29428,22 @@ pub struct RepositoryRuleCommitMessagePattern {
   pub type_: RepositoryRuleCommitMessagePatternType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleCommitMessagePattern {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRuleCommitAuthorEmailPatternParameters {
@@ -23412,14 +29461,22 @@ pub struct RepositoryRuleCommitAuthorEmailPatternParameters {
   pub pattern: String,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleCommitAuthorEmailPatternParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters to be used for the commit_author_email_pattern rule
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23431,14 +29488,22 @@ pub struct RepositoryRuleCommitAuthorEmailPattern {
   pub type_: RepositoryRuleCommitAuthorEmailPatternType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleCommitAuthorEmailPattern {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRuleCommitterEmailPatternParameters {
@@ -23456,14 +29521,22 @@ pub struct RepositoryRuleCommitterEmailPatternParameters {
   pub pattern: String,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleCommitterEmailPatternParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters to be used for the committer_email_pattern rule
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23475,14 +29548,22 @@ pub struct RepositoryRuleCommitterEmailPattern {
   pub type_: RepositoryRuleCommitterEmailPatternType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleCommitterEmailPattern {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRuleBranchNamePatternParameters {
@@ -23500,14 +29581,22 @@ pub struct RepositoryRuleBranchNamePatternParameters {
   pub pattern: String,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleBranchNamePatternParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters to be used for the branch_name_pattern rule
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23519,14 +29608,22 @@ pub struct RepositoryRuleBranchNamePattern {
   pub type_: RepositoryRuleBranchNamePatternType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleBranchNamePattern {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRuleTagNamePatternParameters {
@@ -23544,14 +29641,22 @@ pub struct RepositoryRuleTagNamePatternParameters {
   pub pattern: String,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleTagNamePatternParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Parameters to be used for the tag_name_pattern rule
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23563,14 +29668,22 @@ pub struct RepositoryRuleTagNamePattern {
   pub type_: RepositoryRuleTagNamePatternType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleTagNamePattern {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// A workflow that must run for this rule to pass
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23590,14 +29703,22 @@ pub struct WorkflowFileReference {
   pub sha: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl WorkflowFileReference {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WorkflowsParameters {
@@ -23605,14 +29726,22 @@ pub struct WorkflowsParameters {
   pub workflows: Vec<WorkflowFileReference>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl WorkflowsParameters {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// Require all changes made to a targeted branch to pass the specified workflows before they can be merged.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23624,14 +29753,22 @@ pub struct Workflows {
   pub type_: WorkflowsType,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl Workflows {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 /// A set of rules to apply when specified conditions are met.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23649,7 +29786,7 @@ pub struct RepositoryRuleset {
   pub conditions: Option<RepositoryRulesetConditions>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// The bypass type of the user making the API request for this ruleset. This field is only returned when
   /// querying the repository-level endpoint.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -23678,10 +29815,14 @@ pub struct RepositoryRuleset {
   pub target: Option<RepositoryRulesetTarget>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "repos",
+  feature = "webhook_repository_ruleset",
+))]
 impl RepositoryRuleset {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -23789,7 +29930,7 @@ pub struct RuleSuitesItem {
   pub id: Option<i64>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub pushed_at: Option<String>,
+  pub pushed_at: Option<DateTime>,
   /// The ref name that the evaluation ran on.
   #[serde(rename = "ref")]
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -23905,7 +30046,7 @@ pub struct RuleSuite {
   pub id: Option<i64>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub pushed_at: Option<String>,
+  pub pushed_at: Option<DateTime>,
   /// The ref name that the evaluation ran on.
   #[serde(rename = "ref")]
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -24065,7 +30206,11 @@ impl SecurityAdvisoriesListOrgRepositoryAdvisoriesQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryAdvisoryCredits {
@@ -24079,14 +30224,22 @@ pub struct RepositoryAdvisoryCredits {
   pub type_: Option<SecurityAdvisoryCreditTypes>,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisoryCredits {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// A credit given to a user for a repository security advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -24098,14 +30251,22 @@ pub struct RepositoryAdvisoryCredit {
   pub user: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisoryCredit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryAdvisoryCvss {
@@ -24119,14 +30280,22 @@ pub struct RepositoryAdvisoryCvss {
   pub vector_string: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisoryCvss {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryAdvisoryCwes {
@@ -24136,14 +30305,22 @@ pub struct RepositoryAdvisoryCwes {
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisoryCwes {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryAdvisoryIdentifiers {
@@ -24154,14 +30331,22 @@ pub struct RepositoryAdvisoryIdentifiers {
   pub value: String,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisoryIdentifiers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryAdvisorySubmission {
@@ -24169,14 +30354,22 @@ pub struct RepositoryAdvisorySubmission {
   pub accepted: bool,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisorySubmission {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// The name of the package affected by the vulnerability.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -24188,14 +30381,22 @@ pub struct RepositoryAdvisoryVulnerabilityPackage {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisoryVulnerabilityPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// A product affected by the vulnerability detailed in a repository security advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -24218,14 +30419,22 @@ pub struct RepositoryAdvisoryVulnerability {
   pub vulnerable_version_range: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisoryVulnerability {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 /// A repository security advisory.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -24237,7 +30446,7 @@ pub struct RepositoryAdvisory {
   /// The date and time of when the advisory was closed, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   /// A list of teams that collaborate on the advisory.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -24249,7 +30458,7 @@ pub struct RepositoryAdvisory {
   /// The date and time of when the advisory was created, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub credits: Option<Vec<RepositoryAdvisoryCredits>>,
@@ -24286,7 +30495,7 @@ pub struct RepositoryAdvisory {
   /// The date and time of when the advisory was published, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub published_at: Option<String>,
+  pub published_at: Option<DateTime>,
   /// The publisher of the advisory.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -24305,7 +30514,7 @@ pub struct RepositoryAdvisory {
   /// The date and time of when the advisory was last updated, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   /// The API URL for the advisory.
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -24314,10 +30523,14 @@ pub struct RepositoryAdvisory {
   /// The date and time of when the advisory was withdrawn, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub withdrawn_at: Option<String>,
+  pub withdrawn_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "security_advisories", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "security_advisories",
+  feature = "webhook_repository_advisory",
+))]
 impl RepositoryAdvisory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -24562,7 +30775,7 @@ impl TeamOrganizationPlan {
 pub struct TeamOrganization {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub archived_at: Option<String>,
+  pub archived_at: Option<DateTime>,
   pub avatar_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -24576,7 +30789,7 @@ pub struct TeamOrganization {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub company: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub default_repository_permission: Option<String>,
@@ -24661,7 +30874,7 @@ pub struct TeamOrganization {
   pub two_factor_requirement_enabled: Option<bool>,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -24680,7 +30893,7 @@ impl TeamOrganization {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct FullTeam {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -24713,7 +30926,7 @@ pub struct FullTeam {
   pub repos_count: i64,
   pub repositories_url: String,
   pub slug: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL for the team
   pub url: String,
 }
@@ -24814,11 +31027,11 @@ pub struct TeamDiscussion {
   pub body_version: String,
   pub comments_count: i64,
   pub comments_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub html_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_edited_at: Option<String>,
+  pub last_edited_at: Option<DateTime>,
   pub node_id: String,
   /// The unique sequence number of a team discussion.
   pub number: i64,
@@ -24832,7 +31045,7 @@ pub struct TeamDiscussion {
   pub team_url: String,
   /// The title of the discussion.
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -24924,19 +31137,19 @@ pub struct TeamDiscussionComment {
   pub body_html: String,
   /// The current version of the body content. If provided, this update operation will be rejected if the given version does not match the latest version on the server.
   pub body_version: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub discussion_url: String,
   pub html_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_edited_at: Option<String>,
+  pub last_edited_at: Option<DateTime>,
   pub node_id: String,
   /// The unique sequence number of a team discussion comment.
   pub number: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub reactions: Option<ReactionRollup>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -25010,7 +31223,7 @@ impl ReactionsListForTeamDiscussionCommentInOrgQuery {
 pub struct Reaction {
   /// The reaction to use
   pub content: ReactionContent,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub id: i64,
   pub node_id: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -25212,7 +31425,7 @@ pub struct TeamProject {
   #[builder(default, setter(strip_option))]
   pub body: Option<String>,
   pub columns_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub creator: SimpleUser,
   pub html_url: String,
   pub id: i64,
@@ -25230,7 +31443,7 @@ pub struct TeamProject {
   #[builder(default, setter(strip_option))]
   pub private: Option<bool>,
   pub state: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -25342,7 +31555,7 @@ pub struct TeamRepository {
   pub contributors_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// The default branch of the repository.
   pub default_branch: String,
   /// Whether to delete head branches when pull requests are merged
@@ -25426,7 +31639,7 @@ pub struct TeamRepository {
   pub pulls_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub pushed_at: Option<String>,
+  pub pushed_at: Option<DateTime>,
   pub releases_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -25453,7 +31666,7 @@ pub struct TeamRepository {
   pub trees_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
   /// The repository visibility: public, private, or internal.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -25531,7 +31744,11 @@ impl OrgsEnableOrDisableSecurityProductOnAllOrgReposRequest {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "projects",
+  feature = "webhook_project_card",
+))]
 /// Project cards represent a scope of work.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -25547,7 +31764,7 @@ pub struct ProjectCard {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub content_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<SimpleUser>,
@@ -25561,11 +31778,15 @@ pub struct ProjectCard {
   #[builder(default, setter(strip_option))]
   pub project_id: Option<String>,
   pub project_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "projects",
+  feature = "webhook_project_card",
+))]
 impl ProjectCard {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -25624,24 +31845,32 @@ impl ProjectsMoveCardResponse {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "projects",
+  feature = "webhook_project_column",
+))]
 /// Project columns contain cards of work.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectColumn {
   pub cards_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The unique identifier of the project column
   pub id: i64,
   /// Name of the project column
   pub name: String,
   pub node_id: String,
   pub project_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "projects", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "projects",
+  feature = "webhook_project_column",
+))]
 impl ProjectColumn {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -26238,12 +32467,12 @@ pub struct Artifact {
   pub archive_download_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// Whether or not the artifact has expired.
   pub expired: bool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub expires_at: Option<String>,
+  pub expires_at: Option<DateTime>,
   pub id: i64,
   /// The name of the artifact.
   pub name: String,
@@ -26252,7 +32481,7 @@ pub struct Artifact {
   pub size_in_bytes: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -26281,6 +32510,19 @@ impl ActionsListArtifactsForRepoResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListArtifactsForRepoResponse {
+  type Item = Artifact;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.artifacts
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 /// Query for `List GitHub Actions caches for a repository`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -26326,7 +32568,7 @@ impl ActionsGetActionsCacheListQuery {
 pub struct RepositoryActionsCachesActionsCaches {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub id: Option<i64>,
@@ -26335,7 +32577,7 @@ pub struct RepositoryActionsCachesActionsCaches {
   pub key: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_accessed_at: Option<String>,
+  pub last_accessed_at: Option<DateTime>,
   #[serde(rename = "ref")]
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -26401,7 +32643,7 @@ pub struct JobSteps {
   /// The time that the job finished, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   /// The outcome of the job.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -26412,7 +32654,7 @@ pub struct JobSteps {
   /// The time that the step started, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub started_at: Option<String>,
+  pub started_at: Option<DateTime>,
   /// The phase of the lifecycle that the job is currently in.
   pub status: JobStepsStatus,
 }
@@ -26433,13 +32675,13 @@ pub struct Job {
   /// The time that the job finished, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   /// The outcome of the job.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<JobConclusion>,
   /// The time that the job created, in ISO 8601 format.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the current branch.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -26480,7 +32722,7 @@ pub struct Job {
   #[builder(default, setter(strip_option))]
   pub runner_name: Option<String>,
   /// The time that the job started, in ISO 8601 format.
-  pub started_at: String,
+  pub started_at: DateTime,
   /// The phase of the lifecycle that the job is currently in.
   pub status: JobStatus,
   /// Steps in this job.
@@ -26565,10 +32807,10 @@ impl ActionsListRepoOrganizationSecretsQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ActionsSecret {
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the secret.
   pub name: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -26593,6 +32835,19 @@ impl ActionsListRepoOrganizationSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListRepoOrganizationSecretsResponse {
+  type Item = ActionsSecret;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 /// Query for `List repository organization variables`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -26620,11 +32875,11 @@ impl ActionsListRepoOrganizationVariablesQuery {
 #[builder(field_defaults(setter(into)))]
 pub struct ActionsVariable {
   /// The date and time at which the variable was created, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the variable.
   pub name: String,
   /// The date and time at which the variable was last updated, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// The value of the variable.
   pub value: String,
 }
@@ -26651,6 +32906,19 @@ impl ActionsListRepoOrganizationVariablesResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListRepoOrganizationVariablesResponse {
+  type Item = ActionsVariable;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.variables
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -26747,6 +33015,19 @@ impl ActionsListSelfHostedRunnersForRepoResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListSelfHostedRunnersForRepoResponse {
+  type Item = SelfHostedRunners;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.runners
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -26858,7 +33139,9 @@ impl ActionsListWorkflowRunsForRepoQuery {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_merge_group",
+  feature = "webhook_workflow_run",
 ))]
 /// Information about the Git author
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -26874,7 +33157,9 @@ pub struct SimpleCommitAuthor {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_merge_group",
+  feature = "webhook_workflow_run",
 ))]
 impl SimpleCommitAuthor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -26886,7 +33171,9 @@ impl SimpleCommitAuthor {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_merge_group",
+  feature = "webhook_workflow_run",
 ))]
 /// Information about the Git committer
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -26902,7 +33189,9 @@ pub struct SimpleCommitCommitter {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_merge_group",
+  feature = "webhook_workflow_run",
 ))]
 impl SimpleCommitCommitter {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -26914,7 +33203,9 @@ impl SimpleCommitCommitter {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_merge_group",
+  feature = "webhook_workflow_run",
 ))]
 /// A commit.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -26942,7 +33233,9 @@ pub struct SimpleCommit {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_suite",
+  feature = "webhook_merge_group",
+  feature = "webhook_workflow_run",
 ))]
 impl SimpleCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -26954,7 +33247,8 @@ impl SimpleCommit {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -26968,7 +33262,8 @@ pub struct PullRequestMinimalBaseRepo {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 impl PullRequestMinimalBaseRepo {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -26980,7 +33275,8 @@ impl PullRequestMinimalBaseRepo {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -26995,7 +33291,8 @@ pub struct PullRequestMinimalBase {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 impl PullRequestMinimalBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -27007,7 +33304,8 @@ impl PullRequestMinimalBase {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27021,7 +33319,8 @@ pub struct PullRequestMinimalHeadRepo {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 impl PullRequestMinimalHeadRepo {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -27033,7 +33332,8 @@ impl PullRequestMinimalHeadRepo {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27048,7 +33348,8 @@ pub struct PullRequestMinimalHead {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 impl PullRequestMinimalHead {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -27060,7 +33361,8 @@ impl PullRequestMinimalHead {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27076,7 +33378,8 @@ pub struct PullRequestMinimal {
   feature = "full",
   feature = "actions",
   feature = "checks",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_workflow_run",
 ))]
 impl PullRequestMinimal {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -27084,7 +33387,11 @@ impl PullRequestMinimal {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_workflow_run",
+))]
 /// A workflow referenced/reused by the initial caller workflow
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27097,14 +33404,22 @@ pub struct ReferencedWorkflow {
   pub sha: String,
 }
 
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_workflow_run",
+))]
 impl ReferencedWorkflow {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_workflow_run",
+))]
 /// An invocation of a workflow
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27129,7 +33444,7 @@ pub struct WorkflowRun {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The event-specific title associated with the run or the run-name if set, or the value of `run-name` if it is set in the workflow.
   pub display_title: String,
   pub event: String,
@@ -27182,14 +33497,14 @@ pub struct WorkflowRun {
   /// The start time of the latest run. Resets on re-run.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub run_started_at: Option<String>,
+  pub run_started_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub status: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub triggering_actor: Option<SimpleUser>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// The URL to the workflow run.
   pub url: String,
   /// The ID of the parent workflow.
@@ -27198,7 +33513,11 @@ pub struct WorkflowRun {
   pub workflow_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_workflow_run",
+))]
 impl WorkflowRun {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -27220,6 +33539,19 @@ impl ActionsListWorkflowRunsForRepoResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListWorkflowRunsForRepoResponse {
+  type Item = WorkflowRun;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.workflow_runs
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 /// Query for `Get a workflow run`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -27245,7 +33577,7 @@ pub struct EnvironmentApprovalEnvironments {
   /// The time that the environment was created, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub html_url: Option<String>,
@@ -27263,7 +33595,7 @@ pub struct EnvironmentApprovalEnvironments {
   /// The time that the environment was last updated, in ISO 8601 format.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -27338,6 +33670,19 @@ impl ActionsListWorkflowRunArtifactsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListWorkflowRunArtifactsResponse {
+  type Item = Artifact;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.artifacts
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 /// Query for `Get a workflow run attempt`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -27393,6 +33738,19 @@ impl ActionsListJobsForWorkflowRunAttemptResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListJobsForWorkflowRunAttemptResponse {
+  type Item = Job;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.jobs
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27472,6 +33830,19 @@ impl ActionsListJobsForWorkflowRunResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListJobsForWorkflowRunResponse {
+  type Item = Job;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.jobs
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27537,7 +33908,7 @@ pub struct PendingDeployment {
   /// The time that the wait timer began.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub wait_timer_started_at: Option<String>,
+  pub wait_timer_started_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "actions",))]
@@ -27571,13 +33942,17 @@ impl ActionsReviewPendingDeploymentsForRunRequest {
   feature = "actions",
   feature = "checks",
   feature = "repos",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_status",
+  feature = "webhook_workflow_job",
 ))]
 /// A request for a specific ref(branch,sha,tag) to be deployed
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct Deployment {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<SimpleUser>,
@@ -27612,7 +33987,7 @@ pub struct Deployment {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub transient_environment: Option<bool>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -27621,7 +33996,11 @@ pub struct Deployment {
   feature = "actions",
   feature = "checks",
   feature = "repos",
-  feature = "webhook",
+  feature = "webhook_check_run",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_status",
+  feature = "webhook_workflow_job",
 ))]
 impl Deployment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -27842,6 +34221,19 @@ impl ActionsListRepoSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListRepoSecretsResponse {
+  type Item = ActionsSecret;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27900,6 +34292,19 @@ impl ActionsListRepoVariablesResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListRepoVariablesResponse {
+  type Item = ActionsVariable;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.variables
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -27960,27 +34365,39 @@ impl ActionsListRepoWorkflowsQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_status",
+  feature = "webhook_workflow_run",
+))]
 /// A GitHub Actions workflow
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct Workflow {
   pub badge_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub deleted_at: Option<String>,
+  pub deleted_at: Option<DateTime>,
   pub html_url: String,
   pub id: i64,
   pub name: String,
   pub node_id: String,
   pub path: String,
   pub state: WorkflowState,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "actions", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "actions",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_status",
+  feature = "webhook_workflow_run",
+))]
 impl Workflow {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -28002,6 +34419,19 @@ impl ActionsListRepoWorkflowsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListRepoWorkflowsResponse {
+  type Item = Workflow;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.workflows
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -28091,6 +34521,19 @@ impl ActionsListWorkflowRunsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListWorkflowRunsResponse {
+  type Item = WorkflowRun;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.workflow_runs
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -28783,7 +35226,7 @@ impl BranchRestrictionPolicyAppsPermissions {
 pub struct BranchRestrictionPolicyApps {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -28816,7 +35259,7 @@ pub struct BranchRestrictionPolicyApps {
   pub slug: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -29075,7 +35518,7 @@ impl BranchWithProtectionLinks {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 /// Metaproperties for Git author/committer information.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -29097,7 +35540,7 @@ pub struct GitUser {
   feature = "repos",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl GitUser {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -29109,7 +35552,7 @@ impl GitUser {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -29122,7 +35565,7 @@ pub struct CommitCommitTree {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl CommitCommitTree {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -29136,7 +35579,7 @@ impl CommitCommitTree {
   feature = "git",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -29157,7 +35600,7 @@ pub struct Verification {
   feature = "git",
   feature = "pulls",
   feature = "search",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl Verification {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -29169,7 +35612,7 @@ impl Verification {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -29193,7 +35636,7 @@ pub struct CommitCommit {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl CommitCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -29205,7 +35648,7 @@ impl CommitCommit {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 /// Diff Entry
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -29232,7 +35675,7 @@ pub struct DiffEntry {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl DiffEntry {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -29244,7 +35687,7 @@ impl DiffEntry {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -29260,7 +35703,7 @@ pub struct CommitParents {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl CommitParents {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -29272,7 +35715,7 @@ impl CommitParents {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -29292,7 +35735,7 @@ pub struct CommitStats {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl CommitStats {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -29304,7 +35747,7 @@ impl CommitStats {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 /// Commit
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -29335,7 +35778,7 @@ pub struct Commit {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_push",
 ))]
 impl Commit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -30244,21 +36687,21 @@ impl ChecksCreateRequestItem2 {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunCheckSuite {
   pub id: i64,
 }
 
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 impl CheckRunCheckSuite {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunOutput {
@@ -30275,14 +36718,14 @@ pub struct CheckRunOutput {
   pub title: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 impl CheckRunOutput {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 /// A check performed on the code of a given code change
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -30295,7 +36738,7 @@ pub struct CheckRun {
   pub check_suite: Option<CheckRunCheckSuite>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<CheckRunConclusion>,
@@ -30323,13 +36766,13 @@ pub struct CheckRun {
   pub pull_requests: Vec<PullRequestMinimal>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub started_at: Option<String>,
+  pub started_at: Option<DateTime>,
   /// The phase of the lifecycle that the check is currently in. Statuses of waiting, requested, and pending are reserved for GitHub Actions check runs.
   pub status: CheckRunStatus,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "checks", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "checks", feature = "webhook_check_run",))]
 impl CheckRun {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -30461,7 +36904,7 @@ pub struct CheckSuite {
   pub conclusion: Option<CheckSuiteConclusion>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub head_branch: Option<String>,
@@ -30487,7 +36930,7 @@ pub struct CheckSuite {
   pub status: Option<CheckSuiteStatus>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -30630,6 +37073,19 @@ impl ChecksListForSuiteResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "checks",)))]
+impl PaginatedItems for ChecksListForSuiteResponse {
+  type Item = CheckRun;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.check_runs
+  }
+}
 #[cfg(any(feature = "full", feature = "code_scanning",))]
 /// Query for `List code scanning alerts for a repository`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -30685,10 +37141,10 @@ impl CodeScanningListAlertsForRepoQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CodeScanningAlertItems {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<SimpleUser>,
@@ -30700,7 +37156,7 @@ pub struct CodeScanningAlertItems {
   pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub fixed_at: Option<String>,
+  pub fixed_at: Option<DateTime>,
   pub html_url: String,
   pub instances_url: String,
   pub most_recent_instance: CodeScanningAlertInstance,
@@ -30710,7 +37166,7 @@ pub struct CodeScanningAlertItems {
   pub tool: CodeScanningAnalysisTool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
 }
 
@@ -30774,10 +37230,10 @@ impl CodeScanningAlertRule {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CodeScanningAlert {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<SimpleUser>,
@@ -30789,7 +37245,7 @@ pub struct CodeScanningAlert {
   pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub fixed_at: Option<String>,
+  pub fixed_at: Option<DateTime>,
   pub html_url: String,
   pub instances_url: String,
   pub most_recent_instance: CodeScanningAlertInstance,
@@ -30799,7 +37255,7 @@ pub struct CodeScanningAlert {
   pub tool: CodeScanningAnalysisTool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
 }
 
@@ -30913,7 +37369,7 @@ pub struct CodeScanningAnalysis {
   #[builder(default, setter(strip_option))]
   pub category: Option<String>,
   pub commit_sha: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub deletable: bool,
   pub environment: String,
   pub error: String,
@@ -30991,7 +37447,7 @@ pub struct CodeQLDatabase {
   /// The MIME type of the CodeQL database file.
   pub content_type: String,
   /// The date and time at which the CodeQL database was created, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The ID of the CodeQL database.
   pub id: i64,
   /// The language of the CodeQL database.
@@ -31001,7 +37457,7 @@ pub struct CodeQLDatabase {
   /// The size of the CodeQL database file in bytes.
   pub size: i64,
   /// The date and time at which the CodeQL database was last updated, in ISO 8601 format':' YYYY-MM-DDTHH:MM:SSZ.
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub uploader: SimpleUser,
   /// The URL at which to download the CodeQL database. The `Accept` header must be set to the value of the `content_type` property.
   pub url: String,
@@ -31038,7 +37494,7 @@ pub struct CodeScanningDefaultSetup {
   /// Timestamp of latest configuration update.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "code_scanning",))]
@@ -31113,7 +37569,7 @@ pub struct CodeScanningUploadSarifRequest {
   /// The time that the analysis run began. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub started_at: Option<String>,
+  pub started_at: Option<DateTime>,
   /// The name of the tool used to generate the code scanning analysis. If this parameter is not used, the tool name defaults to "API". If the uploaded SARIF contains a tool GUID, this will be available for filtering using the `tool_guid` parameter of operations such as `GET /repos/{owner}/{repo}/code-scanning/alerts`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -31279,6 +37735,19 @@ impl CodespacesListInRepositoryForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListInRepositoryForAuthenticatedUserResponse {
+  type Item = Codespace;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.codespaces
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -31395,6 +37864,19 @@ impl CodespacesListDevcontainersInRepositoryForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListDevcontainersInRepositoryForAuthenticatedUserResponse {
+  type Item = CodespacesListDevcontainersInRepositoryForAuthenticatedUserResponseDevcontainers;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.devcontainers
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 /// Query for `List available machine types for a repository`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -31437,6 +37919,19 @@ impl CodespacesRepoMachinesForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesRepoMachinesForAuthenticatedUserResponse {
+  type Item = CodespaceMachine;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.machines
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 /// Query for `Get default attributes for a codespace`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -31568,6 +38063,19 @@ impl CodespacesListRepoSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListRepoSecretsResponse {
+  type Item = CodespacesSecret;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -31708,7 +38216,7 @@ impl ReposAddCollaboratorRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryInvitation {
-  pub created_at: String,
+  pub created_at: DateTime,
   /// Whether or not the invitation has expired
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -31786,7 +38294,7 @@ pub struct CommitComment {
   pub author_association: AuthorAssociation,
   pub body: String,
   pub commit_id: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub html_url: String,
   pub id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -31802,7 +38310,7 @@ pub struct CommitComment {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub reactions: Option<ReactionRollup>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -31896,11 +38404,11 @@ pub struct ReposListCommitsQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// Only commits before this date will be returned. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub until: Option<String>,
+  pub until: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -32025,7 +38533,11 @@ impl ReposListPullRequestsAssociatedWithCommitQuery {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// Hypermedia Link
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -32038,7 +38550,11 @@ pub struct Link {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl Link {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -32072,7 +38588,8 @@ impl PullRequestSimpleLinks {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
 ))]
 /// The status of auto merging a pull request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -32091,7 +38608,8 @@ pub struct AutoMerge {
   feature = "full",
   feature = "repos",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
 ))]
 impl AutoMerge {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -32190,10 +38708,10 @@ pub struct PullRequestSimple {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub comments_url: String,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub diff_url: String,
   /// Indicates whether or not the pull request is a draft.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -32210,7 +38728,7 @@ pub struct PullRequestSimple {
   pub merge_commit_sha: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub milestone: Option<Milestone>,
@@ -32228,7 +38746,7 @@ pub struct PullRequestSimple {
   pub state: String,
   pub statuses_url: String,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -32316,6 +38834,19 @@ impl ChecksListForRefResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "checks",)))]
+impl PaginatedItems for ChecksListForRefResponse {
+  type Item = CheckRun;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.check_runs
+  }
+}
 #[cfg(any(feature = "full", feature = "checks",))]
 /// Query for `List check suites for a Git reference`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -32361,6 +38892,19 @@ impl ChecksListSuitesForRefResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "checks",)))]
+impl PaginatedItems for ChecksListSuitesForRefResponse {
+  type Item = CheckSuite;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.check_suites
+  }
+}
 #[cfg(any(feature = "full", feature = "repos",))]
 /// Query for `Get the combined status for a specific reference`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -32391,7 +38935,7 @@ pub struct SimpleCommitStatus {
   #[builder(default, setter(strip_option))]
   pub avatar_url: Option<String>,
   pub context: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -32404,7 +38948,7 @@ pub struct SimpleCommitStatus {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub target_url: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -32467,7 +39011,7 @@ pub struct Status {
   #[builder(default, setter(strip_option))]
   pub avatar_url: Option<String>,
   pub context: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<SimpleUser>,
@@ -32480,7 +39024,7 @@ pub struct Status {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub target_url: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -32558,7 +39102,7 @@ pub struct CommunityProfile {
   pub health_percentage: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -33431,7 +39975,11 @@ impl DependabotListAlertsForRepoQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// Details for the vulnerable dependency.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -33449,27 +39997,35 @@ pub struct DependabotAlertDependency {
   pub scope: Option<DependabotAlertDependencyScope>,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlertDependency {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 /// A Dependabot alert.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlert {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub auto_dismissed_at: Option<String>,
-  pub created_at: String,
+  pub auto_dismissed_at: Option<DateTime>,
+  pub created_at: DateTime,
   /// Details for the vulnerable dependency.
   pub dependency: DependabotAlertDependency,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<SimpleUser>,
@@ -33483,18 +40039,22 @@ pub struct DependabotAlert {
   pub dismissed_reason: Option<DependabotAlertDismissedReason>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub fixed_at: Option<String>,
+  pub fixed_at: Option<DateTime>,
   pub html_url: String,
   pub number: i64,
   pub security_advisory: DependabotAlertSecurityAdvisory,
   pub security_vulnerability: DependabotAlertSecurityVulnerability,
   /// The state of the Dependabot alert.
   pub state: DependabotAlertState,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "dependabot", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "dependabot",
+  feature = "webhook_dependabot_alert",
+))]
 impl DependabotAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -33552,10 +40112,10 @@ impl DependabotListRepoSecretsQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotSecret {
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the secret.
   pub name: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
 #[cfg(any(feature = "full", feature = "dependabot",))]
@@ -33580,6 +40140,19 @@ impl DependabotListRepoSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "dependabot",)))]
+impl PaginatedItems for DependabotListRepoSecretsResponse {
+  type Item = DependabotSecret;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "dependabot",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -33891,7 +40464,7 @@ impl Snapshot {
 #[builder(field_defaults(setter(into)))]
 pub struct DependencyGraphCreateRepositorySnapshotResponse {
   /// The time at which the snapshot was created.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// ID of the created snapshot.
   pub id: i64,
   /// A message providing further details about the result, such as why the dependencies were not updated.
@@ -34036,7 +40609,7 @@ impl ReposListDeploymentStatusesQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentStatus {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<SimpleUser>,
@@ -34065,7 +40638,7 @@ pub struct DeploymentStatus {
   pub state: DeploymentStatusState,
   /// Deprecated: the URL to associate with this status.
   pub target_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -34262,7 +40835,7 @@ impl EnvironmentProtectionRulesItem3 {
 #[builder(field_defaults(setter(into)))]
 pub struct Environment {
   /// The time that the environment was created, in ISO 8601 format.
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub deployment_branch_policy: Option<DeploymentBranchPolicySettings>,
@@ -34277,7 +40850,7 @@ pub struct Environment {
   #[builder(default, setter(strip_option))]
   pub protection_rules: Option<Vec<EnvironmentProtectionRules>>,
   /// The time that the environment was last updated, in ISO 8601 format.
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -34308,6 +40881,19 @@ impl ReposGetAllEnvironmentsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "repos",)))]
+impl PaginatedItems for ReposGetAllEnvironmentsResponse {
+  type Item = Environment;
+
+  fn total_count(&self) -> Option<i64> {
+    self.total_count
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.environments.unwrap_or_default()
+  }
+}
 #[cfg(any(feature = "full", feature = "repos",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -34423,6 +41009,19 @@ impl ReposListDeploymentBranchPoliciesResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "repos",)))]
+impl PaginatedItems for ReposListDeploymentBranchPoliciesResponse {
+  type Item = DeploymentBranchPolicy;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.branch_policies
+  }
+}
 #[cfg(any(feature = "full", feature = "repos",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -34527,6 +41126,19 @@ impl ReposGetAllDeploymentProtectionRulesResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "repos",)))]
+impl PaginatedItems for ReposGetAllDeploymentProtectionRulesResponse {
+  type Item = DeploymentProtectionRule;
+
+  fn total_count(&self) -> Option<i64> {
+    self.total_count
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.custom_deployment_protection_rules.unwrap_or_default()
+  }
+}
 #[cfg(any(feature = "full", feature = "repos",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -34587,6 +41199,21 @@ impl ReposListCustomDeploymentRuleIntegrationsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "repos",)))]
+impl PaginatedItems for ReposListCustomDeploymentRuleIntegrationsResponse {
+  type Item = CustomDeploymentProtectionRuleApp;
+
+  fn total_count(&self) -> Option<i64> {
+    self.total_count
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self
+      .available_custom_deployment_protection_rule_integrations
+      .unwrap_or_default()
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 /// Query for `List environment secrets`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -34624,6 +41251,19 @@ impl ActionsListEnvironmentSecretsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListEnvironmentSecretsResponse {
+  type Item = ActionsSecret;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -34678,6 +41318,19 @@ impl ActionsListEnvironmentVariablesResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "actions",)))]
+impl PaginatedItems for ActionsListEnvironmentVariablesResponse {
+  type Item = ActionsVariable;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.variables
+  }
+}
 #[cfg(any(feature = "full", feature = "actions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -35384,7 +42037,7 @@ impl ReposListWebhooksQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_ping",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct HookResponse {
@@ -35399,14 +42052,14 @@ pub struct HookResponse {
   pub status: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_ping",))]
 impl HookResponse {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_ping",))]
 /// Webhooks for repositories.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -35414,7 +42067,7 @@ pub struct Webhook {
   /// Determines whether the hook is actually triggered on pushes.
   pub active: bool,
   pub config: WebhookConfiguration,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub deliveries_url: Option<String>,
@@ -35429,11 +42082,11 @@ pub struct Webhook {
   pub test_url: String,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_ping",))]
 impl Webhook {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -35915,7 +42568,7 @@ pub struct IssuesListForRepoQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -36008,7 +42661,7 @@ pub struct IssuesListCommentsForRepoQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -36221,7 +42874,7 @@ pub struct IssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_review: Option<IssueEventDismissedReview>,
@@ -36379,7 +43032,7 @@ pub struct IssuesListCommentsQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -36461,7 +43114,7 @@ pub struct LabeledIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub label: LabeledIssueEventLabel,
@@ -36506,7 +43159,7 @@ pub struct UnlabeledIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub label: UnlabeledIssueEventLabel,
@@ -36538,7 +43191,7 @@ pub struct AssignedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -36567,7 +43220,7 @@ pub struct UnassignedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -36610,7 +43263,7 @@ pub struct MilestonedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub milestone: MilestonedIssueEventMilestone,
@@ -36654,7 +43307,7 @@ pub struct DemilestonedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub milestone: DemilestonedIssueEventMilestone,
@@ -36699,7 +43352,7 @@ pub struct RenamedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -36729,7 +43382,7 @@ pub struct ReviewRequestedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -36765,7 +43418,7 @@ pub struct ReviewRequestRemovedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -36822,7 +43475,7 @@ pub struct ReviewDismissedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub dismissed_review: ReviewDismissedIssueEventDismissedReview,
   pub event: String,
   pub id: i64,
@@ -36852,7 +43505,7 @@ pub struct LockedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -36905,7 +43558,7 @@ pub struct AddedToProjectIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -36958,7 +43611,7 @@ pub struct MovedColumnInProjectIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -37011,7 +43664,7 @@ pub struct RemovedFromProjectIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -37064,7 +43717,7 @@ pub struct ConvertedNoteToIssueIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -37104,7 +43757,18 @@ impl IssuesListLabelsOnIssueQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "issues", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "issues",
+  feature = "webhook_discussion",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 /// Color-coded labels help you categorize and filter your issues (just like labels in Gmail).
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -37124,7 +43788,18 @@ pub struct Label {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "issues", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "issues",
+  feature = "webhook_discussion",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl Label {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -37354,7 +44029,7 @@ pub struct TimelineCommentEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub body_text: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub html_url: String,
   /// Unique identifier of the issue comment
@@ -37367,7 +44042,7 @@ pub struct TimelineCommentEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub reactions: Option<ReactionRollup>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL for the issue comment
   pub url: String,
   pub user: SimpleUser,
@@ -37408,10 +44083,10 @@ pub struct TimelineCrossReferencedEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub actor: Option<SimpleUser>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub source: TimelineCrossReferencedEventSource,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
 #[cfg(any(feature = "full", feature = "issues",))]
@@ -37618,7 +44293,7 @@ pub struct TimelineReviewedEvent {
   pub state: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub submitted_at: Option<String>,
+  pub submitted_at: Option<DateTime>,
   pub user: SimpleUser,
 }
 
@@ -37633,7 +44308,8 @@ impl TimelineReviewedEvent {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -37645,7 +44321,8 @@ pub struct PullRequestReviewCommentLinksHtml {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl PullRequestReviewCommentLinksHtml {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -37657,7 +44334,8 @@ impl PullRequestReviewCommentLinksHtml {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -37669,7 +44347,8 @@ pub struct PullRequestReviewCommentLinksPullRequest {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl PullRequestReviewCommentLinksPullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -37681,7 +44360,8 @@ impl PullRequestReviewCommentLinksPullRequest {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -37693,7 +44373,8 @@ pub struct PullRequestReviewCommentLinksSelf {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl PullRequestReviewCommentLinksSelf {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -37705,7 +44386,8 @@ impl PullRequestReviewCommentLinksSelf {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -37720,7 +44402,8 @@ pub struct PullRequestReviewCommentLinks {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl PullRequestReviewCommentLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -37732,7 +44415,8 @@ impl PullRequestReviewCommentLinks {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 /// Pull Request Review Comments are comments on a portion of the Pull Request's diff.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -37751,7 +44435,7 @@ pub struct PullRequestReviewComment {
   pub body_text: Option<String>,
   /// The SHA of the commit to which the comment applies.
   pub commit_id: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The diff of the line that the comment refers to.
   pub diff_hunk: String,
   /// HTML URL for the pull request review comment.
@@ -37813,7 +44497,7 @@ pub struct PullRequestReviewComment {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub subject_type: Option<PullRequestReviewCommentSubjectType>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL for the pull request review comment
   pub url: String,
   pub user: SimpleUser,
@@ -37823,7 +44507,8 @@ pub struct PullRequestReviewComment {
   feature = "full",
   feature = "issues",
   feature = "pulls",
-  feature = "webhook",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review_thread",
 ))]
 impl PullRequestReviewComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
@@ -37893,7 +44578,7 @@ pub struct TimelineAssignedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -37923,7 +44608,7 @@ pub struct TimelineUnassignedIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -37952,7 +44637,7 @@ pub struct StateChangeIssueEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit_url: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub event: String,
   pub id: i64,
   pub node_id: String,
@@ -38002,7 +44687,7 @@ pub struct DeployKey {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub added_by: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub id: i64,
   pub key: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -38380,11 +45065,11 @@ pub struct ActivityListRepoNotificationsForAuthenticatedUserQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub before: Option<String>,
+  pub before: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -38409,7 +45094,7 @@ pub struct ActivityMarkRepoNotificationsAsReadRequest {
   /// Describes the last point that notifications were checked. Anything updated since this time will not be marked as read. If you omit this parameter, all notifications are marked as read. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`. Default: The current timestamp.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_read_at: Option<String>,
+  pub last_read_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "activity",))]
@@ -38447,7 +45132,7 @@ pub struct PagesHttpsCertificate {
   pub domains: Vec<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub expires_at: Option<String>,
+  pub expires_at: Option<DateTime>,
   pub state: PagesHttpsCertificateState,
 }
 
@@ -38502,7 +45187,7 @@ pub struct GitHubPages {
   /// The timestamp when a pending domain becomes unverified.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub pending_domain_unverified_at: Option<String>,
+  pub pending_domain_unverified_at: Option<DateTime>,
   /// The state if the domain is verified
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -38571,14 +45256,14 @@ impl PageBuildError {
 #[builder(field_defaults(setter(into)))]
 pub struct PageBuild {
   pub commit: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub duration: i64,
   pub error: PageBuildError,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub pusher: Option<SimpleUser>,
   pub status: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -39024,7 +45709,12 @@ impl PullsCreateRequest {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestLinks {
@@ -39039,14 +45729,24 @@ pub struct PullRequestLinks {
   pub statuses: Link,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestBaseRepoOwner {
@@ -39073,14 +45773,24 @@ pub struct PullRequestBaseRepoOwner {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestBaseRepoOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestBaseRepoPermissions {
@@ -39095,14 +45805,24 @@ pub struct PullRequestBaseRepoPermissions {
   pub triage: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestBaseRepoPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestBaseRepo {
@@ -39130,7 +45850,7 @@ pub struct PullRequestBaseRepo {
   pub compare_url: String,
   pub contents_url: String,
   pub contributors_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub default_branch: String,
   pub deployments_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -39194,7 +45914,7 @@ pub struct PullRequestBaseRepo {
   pub permissions: Option<PullRequestBaseRepoPermissions>,
   pub private: bool,
   pub pulls_url: String,
-  pub pushed_at: String,
+  pub pushed_at: DateTime,
   pub releases_url: String,
   pub size: i64,
   pub ssh_url: String,
@@ -39213,7 +45933,7 @@ pub struct PullRequestBaseRepo {
   #[builder(default, setter(strip_option))]
   pub topics: Option<Vec<String>>,
   pub trees_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   /// The repository visibility: public, private, or internal.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -39226,14 +45946,24 @@ pub struct PullRequestBaseRepo {
   pub web_commit_signoff_required: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestBaseRepo {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestBaseUser {
@@ -39260,14 +45990,24 @@ pub struct PullRequestBaseUser {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestBaseUser {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestBase {
@@ -39279,14 +46019,24 @@ pub struct PullRequestBase {
   pub user: PullRequestBaseUser,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestHeadRepoLicense {
@@ -39301,14 +46051,24 @@ pub struct PullRequestHeadRepoLicense {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestHeadRepoLicense {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestHeadRepoOwner {
@@ -39335,14 +46095,24 @@ pub struct PullRequestHeadRepoOwner {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestHeadRepoOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestHeadRepoPermissions {
@@ -39357,14 +46127,24 @@ pub struct PullRequestHeadRepoPermissions {
   pub triage: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestHeadRepoPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestHeadRepo {
@@ -39392,7 +46172,7 @@ pub struct PullRequestHeadRepo {
   pub compare_url: String,
   pub contents_url: String,
   pub contributors_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub default_branch: String,
   pub deployments_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -39456,7 +46236,7 @@ pub struct PullRequestHeadRepo {
   pub permissions: Option<PullRequestHeadRepoPermissions>,
   pub private: bool,
   pub pulls_url: String,
-  pub pushed_at: String,
+  pub pushed_at: DateTime,
   pub releases_url: String,
   pub size: i64,
   pub ssh_url: String,
@@ -39475,7 +46255,7 @@ pub struct PullRequestHeadRepo {
   #[builder(default, setter(strip_option))]
   pub topics: Option<Vec<String>>,
   pub trees_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   /// The repository visibility: public, private, or internal.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -39488,14 +46268,24 @@ pub struct PullRequestHeadRepo {
   pub web_commit_signoff_required: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestHeadRepo {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestHeadUser {
@@ -39522,14 +46312,24 @@ pub struct PullRequestHeadUser {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestHeadUser {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestHead {
@@ -39543,14 +46343,24 @@ pub struct PullRequestHead {
   pub user: PullRequestHeadUser,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestHead {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PullRequestLabels {
@@ -39566,14 +46376,24 @@ pub struct PullRequestLabels {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequestLabels {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 /// Pull requests let you tell others about changes you've pushed to a repository on GitHub. Once a pull request is sent, interested parties can review the set of changes, discuss potential modifications, and even push follow-up commits if necessary.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -39601,12 +46421,12 @@ pub struct PullRequest {
   pub changed_files: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub comments: i64,
   pub comments_url: String,
   pub commits: i64,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub deletions: i64,
   pub diff_url: String,
   /// Indicates whether or not the pull request is a draft.
@@ -39631,7 +46451,7 @@ pub struct PullRequest {
   pub merged: bool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub merged_by: Option<SimpleUser>,
@@ -39659,12 +46479,17 @@ pub struct PullRequest {
   pub statuses_url: String,
   /// The title of the pull request.
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   pub user: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "pulls", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "pulls",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_pull_request",
+))]
 impl PullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -39686,7 +46511,7 @@ pub struct PullsListReviewCommentsForRepoQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -39862,7 +46687,7 @@ pub struct PullsListReviewCommentsQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -40160,7 +46985,7 @@ pub struct PullRequestReview {
   pub state: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub submitted_at: Option<String>,
+  pub submitted_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<SimpleUser>,
@@ -40305,7 +47130,7 @@ pub struct LegacyReviewComment {
   #[builder(default, setter(strip_option))]
   pub body_text: Option<String>,
   pub commit_id: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub diff_hunk: String,
   pub html_url: String,
   pub id: i64,
@@ -40350,7 +47175,7 @@ pub struct LegacyReviewComment {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub start_side: Option<LegacyReviewCommentStartSide>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -40497,14 +47322,14 @@ impl ReposListReleasesQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_release",))]
 /// Data related to a release.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseAsset {
   pub browser_download_url: String,
   pub content_type: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub download_count: i64,
   pub id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -40516,21 +47341,21 @@ pub struct ReleaseAsset {
   pub size: i64,
   /// State of the release asset.
   pub state: ReleaseAssetState,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub uploader: Option<SimpleUser>,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_release",))]
 impl ReleaseAsset {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_release",))]
 /// A release.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -40547,7 +47372,7 @@ pub struct Release {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub body_text: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The URL of the release discussion.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -40567,7 +47392,7 @@ pub struct Release {
   pub prerelease: bool,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub published_at: Option<String>,
+  pub published_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub reactions: Option<ReactionRollup>,
@@ -40585,7 +47410,7 @@ pub struct Release {
   pub zipball_url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "repos", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "repos", feature = "webhook_release",))]
 impl Release {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -41095,7 +47920,7 @@ impl SecretScanningListAlertsForRepoQuery {
 pub struct SecretScanningAlert {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub html_url: Option<String>,
@@ -41113,7 +47938,7 @@ pub struct SecretScanningAlert {
   /// The time that push protection was bypassed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub push_protection_bypassed_at: Option<String>,
+  pub push_protection_bypassed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub push_protection_bypassed_by: Option<SimpleUser>,
@@ -41127,7 +47952,7 @@ pub struct SecretScanningAlert {
   /// The time that the alert was resolved in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub resolved_at: Option<String>,
+  pub resolved_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub resolved_by: Option<SimpleUser>,
@@ -41208,7 +48033,11 @@ impl SecretScanningListLocationsForAlertQuery {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'commit' secret scanning location type. This location type shows that a secret was detected inside a commit to a repository.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41233,14 +48062,22 @@ pub struct SecretScanningLocationCommit {
   pub start_line: f64,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'wiki_commit' secret scanning location type. This location type shows that a secret was detected inside a commit to a repository wiki.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41265,14 +48102,22 @@ pub struct SecretScanningLocationWikiCommit {
   pub start_line: f64,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationWikiCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents an 'issue_title' secret scanning location type. This location type shows that a secret was detected in the title of an issue.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41281,14 +48126,22 @@ pub struct SecretScanningLocationIssueTitle {
   pub issue_title_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationIssueTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents an 'issue_body' secret scanning location type. This location type shows that a secret was detected in the body of an issue.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41297,14 +48150,22 @@ pub struct SecretScanningLocationIssueBody {
   pub issue_body_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationIssueBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents an 'issue_comment' secret scanning location type. This location type shows that a secret was detected in a comment on an issue.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41313,14 +48174,22 @@ pub struct SecretScanningLocationIssueComment {
   pub issue_comment_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationIssueComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'discussion_title' secret scanning location type. This location type shows that a secret was detected in the title of a discussion.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41329,14 +48198,22 @@ pub struct SecretScanningLocationDiscussionTitle {
   pub discussion_title_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationDiscussionTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'discussion_body' secret scanning location type. This location type shows that a secret was detected in the body of a discussion.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41345,14 +48222,22 @@ pub struct SecretScanningLocationDiscussionBody {
   pub discussion_body_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationDiscussionBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'discussion_comment' secret scanning location type. This location type shows that a secret was detected in a comment on a discussion.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41361,14 +48246,22 @@ pub struct SecretScanningLocationDiscussionComment {
   pub discussion_comment_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationDiscussionComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'pull_request_title' secret scanning location type. This location type shows that a secret was detected in the title of a pull request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41377,14 +48270,22 @@ pub struct SecretScanningLocationPullRequestTitle {
   pub pull_request_title_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationPullRequestTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'pull_request_body' secret scanning location type. This location type shows that a secret was detected in the body of a pull request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41393,14 +48294,22 @@ pub struct SecretScanningLocationPullRequestBody {
   pub pull_request_body_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationPullRequestBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'pull_request_comment' secret scanning location type. This location type shows that a secret was detected in a comment on a pull request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41409,14 +48318,22 @@ pub struct SecretScanningLocationPullRequestComment {
   pub pull_request_comment_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationPullRequestComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'pull_request_review' secret scanning location type. This location type shows that a secret was detected in a review on a pull request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41425,14 +48342,22 @@ pub struct SecretScanningLocationPullRequestReview {
   pub pull_request_review_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationPullRequestReview {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// Represents a 'pull_request_review_comment' secret scanning location type. This location type shows that a secret was detected in a review comment on a pull request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -41441,14 +48366,22 @@ pub struct SecretScanningLocationPullRequestReviewComment {
   pub pull_request_review_comment_url: String,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocationPullRequestReviewComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SecretScanningLocation {
@@ -41462,7 +48395,11 @@ pub struct SecretScanningLocation {
   pub type_: Option<SecretScanningLocationType>,
 }
 
-#[cfg(any(feature = "full", feature = "secret_scanning", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "secret_scanning",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningLocation {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -41843,7 +48780,7 @@ impl ActivityListStargazersForRepoQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct Stargazer {
-  pub starred_at: String,
+  pub starred_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<SimpleUser>,
@@ -42067,7 +49004,7 @@ impl Tag {
 pub struct TagProtection {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub enabled: Option<bool>,
@@ -42077,7 +49014,7 @@ pub struct TagProtection {
   pub pattern: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -42463,7 +49400,7 @@ pub struct CodeSearchResultItem {
   pub language: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub last_modified_at: Option<String>,
+  pub last_modified_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub line_numbers: Option<Vec<String>>,
@@ -42501,6 +49438,19 @@ impl SearchCodeResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "search",)))]
+impl PaginatedItems for SearchCodeResponse {
+  type Item = CodeSearchResultItem;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
 #[cfg(any(feature = "full", feature = "search",))]
 /// Query for `Search commits`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -42658,6 +49608,19 @@ impl SearchCommitsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "search",)))]
+impl PaginatedItems for SearchCommitsResponse {
+  type Item = CommitSearchResultItem;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
 #[cfg(any(feature = "full", feature = "search",))]
 /// Query for `Search issues and pull requests`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -42737,7 +49700,7 @@ pub struct IssueSearchResultItemPullRequest {
   pub html_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub patch_url: Option<String>,
@@ -42779,10 +49742,10 @@ pub struct IssueSearchResultItem {
   pub body_text: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub comments: i64,
   pub comments_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub draft: Option<bool>,
@@ -42822,7 +49785,7 @@ pub struct IssueSearchResultItem {
   #[builder(default, setter(strip_option))]
   pub timeline_url: Option<String>,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -42852,6 +49815,19 @@ impl SearchIssuesAndPullRequestsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "search",)))]
+impl PaginatedItems for SearchIssuesAndPullRequestsResponse {
+  type Item = IssueSearchResultItem;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
 #[cfg(any(feature = "full", feature = "search",))]
 /// Query for `Search labels`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -42930,6 +49906,19 @@ impl SearchLabelsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "search",)))]
+impl PaginatedItems for SearchLabelsResponse {
+  type Item = LabelSearchResultItem;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
 #[cfg(any(feature = "full", feature = "search",))]
 /// Query for `Search repositories`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -43016,7 +50005,7 @@ pub struct RepoSearchResultItem {
   pub compare_url: String,
   pub contents_url: String,
   pub contributors_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub default_branch: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -43088,7 +50077,7 @@ pub struct RepoSearchResultItem {
   pub permissions: Option<RepoSearchResultItemPermissions>,
   pub private: bool,
   pub pulls_url: String,
-  pub pushed_at: String,
+  pub pushed_at: DateTime,
   pub releases_url: String,
   pub score: f64,
   pub size: i64,
@@ -43111,7 +50100,7 @@ pub struct RepoSearchResultItem {
   #[builder(default, setter(strip_option))]
   pub topics: Option<Vec<String>>,
   pub trees_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   /// The repository visibility: public, private, or internal.
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -43147,6 +50136,19 @@ impl SearchReposResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "search",)))]
+impl PaginatedItems for SearchReposResponse {
+  type Item = RepoSearchResultItem;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
 #[cfg(any(feature = "full", feature = "search",))]
 /// Query for `Search topics`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -43261,7 +50263,7 @@ pub struct TopicSearchResultItem {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub aliases: Option<Vec<TopicSearchResultItemAliases>>,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub created_by: Option<String>,
@@ -43293,7 +50295,7 @@ pub struct TopicSearchResultItem {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub text_matches: Option<Vec<SearchResultTextMatchesItem>>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
 #[cfg(any(feature = "full", feature = "search",))]
@@ -43319,6 +50321,19 @@ impl SearchTopicsResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "search",)))]
+impl PaginatedItems for SearchTopicsResponse {
+  type Item = TopicSearchResultItem;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
 #[cfg(any(feature = "full", feature = "search",))]
 /// Query for `Search users`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -43368,7 +50383,7 @@ pub struct UserSearchResultItem {
   pub company: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub email: Option<String>,
@@ -43413,7 +50428,7 @@ pub struct UserSearchResultItem {
   pub subscriptions_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub suspended_at: Option<String>,
+  pub suspended_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub text_matches: Option<Vec<SearchResultTextMatchesItem>>,
@@ -43421,7 +50436,7 @@ pub struct UserSearchResultItem {
   pub type_: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
 }
 
@@ -43448,6 +50463,19 @@ impl SearchUsersResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "search",)))]
+impl PaginatedItems for SearchUsersResponse {
+  type Item = UserSearchResultItem;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.items
+  }
+}
 #[cfg(any(feature = "full", feature = "teams",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -43897,7 +50925,7 @@ pub struct PrivateUser {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub company: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub disk_usage: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -43942,7 +50970,7 @@ pub struct PrivateUser {
   pub subscriptions_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub suspended_at: Option<String>,
+  pub suspended_at: Option<DateTime>,
   pub total_private_repos: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -43950,7 +50978,7 @@ pub struct PrivateUser {
   pub two_factor_authentication: bool,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
@@ -44069,6 +51097,19 @@ impl CodespacesListForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListForAuthenticatedUserResponse {
+  type Item = Codespace;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.codespaces
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -44223,6 +51264,19 @@ impl CodespacesListSecretsForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListSecretsForAuthenticatedUserResponse {
+  type Item = CodespacesSecret;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.secrets
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 /// The public key used for setting user Codespaces' Secrets.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -44279,6 +51333,19 @@ impl CodespacesListRepositoriesForSecretForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesListRepositoriesForSecretForAuthenticatedUserResponse {
+  type Item = MinimalRepository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -44331,7 +51398,7 @@ pub struct FetchesInformationAboutAnExportOfACodespace {
   /// Completion time of the last export operation
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   /// Url for fetching export details
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -44376,6 +51443,19 @@ impl CodespacesCodespaceMachinesForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "codespaces",)))]
+impl PaginatedItems for CodespacesCodespaceMachinesForAuthenticatedUserResponse {
+  type Item = CodespaceMachine;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.machines
+  }
+}
 #[cfg(any(feature = "full", feature = "codespaces",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -44607,13 +51687,13 @@ pub struct GpgKeySubkeys {
   pub can_sign: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub emails: Option<Vec<GpgKeySubkeysEmails>>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub expires_at: Option<String>,
+  pub expires_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub id: Option<i64>,
@@ -44653,11 +51733,11 @@ pub struct GpgKey {
   pub can_encrypt_comms: bool,
   pub can_encrypt_storage: bool,
   pub can_sign: bool,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub emails: Vec<GpgKeyEmails>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub expires_at: Option<String>,
+  pub expires_at: Option<DateTime>,
   pub id: i64,
   pub key_id: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -44737,6 +51817,19 @@ impl AppsListInstallationsForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "apps",)))]
+impl PaginatedItems for AppsListInstallationsForAuthenticatedUserResponse {
+  type Item = Installation;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.installations
+  }
+}
 #[cfg(any(feature = "full", feature = "apps",))]
 /// Query for `List repositories accessible to the user access token`
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -44777,6 +51870,19 @@ impl AppsListInstallationReposForAuthenticatedUserResponse {
     self
   }
 }
+
+#[cfg(all(feature = "pagination", any(feature = "full", feature = "apps",)))]
+impl PaginatedItems for AppsListInstallationReposForAuthenticatedUserResponse {
+  type Item = Repository;
+
+  fn total_count(&self) -> Option<i64> {
+    Some(self.total_count)
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self.repositories
+  }
+}
 #[cfg(any(feature = "full", feature = "interactions",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -44817,7 +51923,7 @@ pub struct IssuesListForAuthenticatedUserQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -44862,7 +51968,7 @@ impl UsersListPublicSshKeysForAuthenticatedUserQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct Key {
-  pub created_at: String,
+  pub created_at: DateTime,
   pub id: i64,
   pub key: String,
   pub read_only: bool,
@@ -44966,7 +52072,7 @@ pub struct UserMarketplacePurchase {
   pub unit_count: Option<i64>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "apps",))]
@@ -45322,11 +52428,11 @@ pub struct ReposListForAuthenticatedUserQuery {
   /// Only show repositories updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// Only show repositories updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub before: Option<String>,
+  pub before: Option<DateTime>,
 }
 
 #[cfg(any(feature = "full", feature = "repos",))]
@@ -45572,7 +52678,7 @@ impl UsersListSshSigningKeysForAuthenticatedUserQuery {
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SshSigningKey {
-  pub created_at: String,
+  pub created_at: DateTime,
   pub id: i64,
   pub key: String,
   pub title: String,
@@ -45818,7 +52924,7 @@ pub struct GistsListForUserQuery {
   /// Only show results that were last updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub since: Option<String>,
+  pub since: Option<DateTime>,
   /// The number of results per page (max 100). For more information, see "[Using pagination in the REST API](https://docs.github.com/rest/using-the-rest-api/using-pagination-in-the-rest-api)."
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -46204,7 +53310,7 @@ impl ActivityListReposStarredByUserQuery {
 #[builder(field_defaults(setter(into)))]
 pub struct StarredRepository {
   pub repo: Repository,
-  pub starred_at: String,
+  pub starred_at: DateTime,
 }
 
 #[cfg(any(feature = "full", feature = "activity",))]
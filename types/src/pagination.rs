@@ -65,6 +65,32 @@ pub struct PaginatedData<Data> {
   pub pages: LinkedPages,
 }
 
+/// The items of one page of a list response.
+///
+/// Most list endpoints return a bare array, but some wrap it in an object together with
+/// the `total_count`, e.g. `{ "total_count": 2, "items": [...] }` for the search endpoints
+/// or `{ "total_count": 2, "workflow_runs": [...] }` for workflow runs.
+pub trait PaginatedItems {
+  type Item;
+
+  /// The total number of items across all pages, when the response reports it.
+  fn total_count(&self) -> Option<i64>;
+
+  fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl<T> PaginatedItems for Vec<T> {
+  type Item = T;
+
+  fn total_count(&self) -> Option<i64> {
+    None
+  }
+
+  fn into_items(self) -> Vec<Self::Item> {
+    self
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
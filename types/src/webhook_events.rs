@@ -0,0 +1,6914 @@
+#[allow(unused_imports)]
+use super::*;
+#[allow(unused_imports)]
+use serde::{Deserialize, Serialize};
+
+/// A delivery of a known event with an `action` that GitHub added after this version,
+/// e.g. a new `pull_request` action, kept with its payload
+#[cfg(any(feature = "full", feature = "webhook_event",))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnknownWebhookEvent {
+  /// The `X-GitHub-Event` header of the delivery
+  #[serde(skip)]
+  pub event_name: &'static str,
+  pub action: Option<String>,
+  #[serde(flatten)]
+  pub payload: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(any(feature = "full", feature = "webhook_event",))]
+impl WebhookEvent {
+  /// Deserialize the payload of a delivery straight into the variant of its
+  /// `X-GitHub-Event` header and `action`, `None` if the event is unknown or its
+  /// feature is disabled. A new `action` of a known event is read as `Unknown`, unless
+  /// the `strict` feature is enabled.
+  #[allow(unused_variables)]
+  pub fn from_slice(
+    event_name: &str,
+    action: Option<&str>,
+    body: &[u8],
+  ) -> Option<Result<Self, serde_json::Error>> {
+    match (event_name, action) {
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+      ("branch_protection_configuration", Some("disabled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::BranchProtectionConfigurationDisabled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+      ("branch_protection_configuration", Some("enabled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::BranchProtectionConfigurationEnabled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+      ("branch_protection_rule", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::BranchProtectionRuleCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+      ("branch_protection_rule", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::BranchProtectionRuleDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+      ("branch_protection_rule", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::BranchProtectionRuleEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      ("check_run", Some("completed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CheckRunCompleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      ("check_run", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CheckRunCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      ("check_run", Some("requested_action")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CheckRunRequestedAction))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      ("check_run", Some("rerequested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CheckRunRerequested))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+      ("check_suite", Some("completed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CheckSuiteCompleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+      ("check_suite", Some("requested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CheckSuiteRequested))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+      ("check_suite", Some("rerequested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CheckSuiteRerequested))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      ("code_scanning_alert", Some("appeared_in_branch")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CodeScanningAlertAppearedInBranch))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      ("code_scanning_alert", Some("closed_by_user")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CodeScanningAlertClosedByUser))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      ("code_scanning_alert", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CodeScanningAlertCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      ("code_scanning_alert", Some("fixed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CodeScanningAlertFixed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      ("code_scanning_alert", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CodeScanningAlertReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      ("code_scanning_alert", Some("reopened_by_user")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CodeScanningAlertReopenedByUser))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
+      ("commit_comment", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CommitCommentCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_create",))]
+      ("create", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Create)),
+      #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+      ("custom_property", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CustomPropertyCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+      ("custom_property", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CustomPropertyDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+      ("custom_property", Some("updated")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CustomPropertyUpdated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
+      ("custom_property_values", Some("updated")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::CustomPropertyValuesUpdated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_delete",))]
+      ("delete", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Delete)),
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      ("dependabot_alert", Some("auto_dismissed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DependabotAlertAutoDismissed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      ("dependabot_alert", Some("auto_reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DependabotAlertAutoReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      ("dependabot_alert", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DependabotAlertCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      ("dependabot_alert", Some("dismissed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DependabotAlertDismissed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      ("dependabot_alert", Some("fixed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DependabotAlertFixed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      ("dependabot_alert", Some("reintroduced")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DependabotAlertReintroduced))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      ("dependabot_alert", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DependabotAlertReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+      ("deploy_key", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeployKeyCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+      ("deploy_key", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeployKeyDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deployment",))]
+      ("deployment", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeploymentCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
+      ("deployment_protection_rule", Some("requested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeploymentProtectionRuleRequested))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+      ("deployment_review", Some("approved")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeploymentReviewApproved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+      ("deployment_review", Some("rejected")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeploymentReviewRejected))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+      ("deployment_review", Some("requested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeploymentReviewRequested))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
+      ("deployment_status", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DeploymentStatusCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("answered")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionAnswered))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("category_changed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionCategoryChanged))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("closed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionClosed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+      ("discussion_comment", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionCommentCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+      ("discussion_comment", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionCommentDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+      ("discussion_comment", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionCommentEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("labeled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionLabeled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("locked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionLocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("pinned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionPinned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("transferred")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionTransferred))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("unanswered")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionUnanswered))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("unlabeled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionUnlabeled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("unlocked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionUnlocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      ("discussion", Some("unpinned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::DiscussionUnpinned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_fork",))]
+      ("fork", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Fork)),
+      #[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
+      ("github_app_authorization", Some("revoked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::GithubAppAuthorizationRevoked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_gollum",))]
+      ("gollum", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Gollum)),
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      ("installation", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      ("installation", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      ("installation", Some("new_permissions_accepted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationNewPermissionsAccepted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+      ("installation_repositories", Some("added")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationRepositoriesAdded))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+      ("installation_repositories", Some("removed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationRepositoriesRemoved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      ("installation", Some("suspend")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationSuspend))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_installation_target",))]
+      ("installation_target", Some("renamed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationTargetRenamed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      ("installation", Some("unsuspend")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::InstallationUnsuspend))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+      ("issue_comment", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssueCommentCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+      ("issue_comment", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssueCommentDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+      ("issue_comment", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssueCommentEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("assigned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesAssigned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("closed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesClosed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("demilestoned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesDemilestoned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("labeled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesLabeled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("locked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesLocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("milestoned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesMilestoned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("opened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesOpened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("pinned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesPinned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("transferred")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesTransferred))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("unassigned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesUnassigned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("unlabeled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesUnlabeled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("unlocked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesUnlocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      ("issues", Some("unpinned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::IssuesUnpinned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_label",))]
+      ("label", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::LabelCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_label",))]
+      ("label", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::LabelDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_label",))]
+      ("label", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::LabelEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      ("marketplace_purchase", Some("cancelled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MarketplacePurchaseCancelled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      ("marketplace_purchase", Some("changed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MarketplacePurchaseChanged))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      ("marketplace_purchase", Some("pending_change")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MarketplacePurchasePendingChange))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      ("marketplace_purchase", Some("pending_change_cancelled")) => Some(
+        serde_json::from_slice(body).map(WebhookEvent::MarketplacePurchasePendingChangeCancelled),
+      ),
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      ("marketplace_purchase", Some("purchased")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MarketplacePurchasePurchased))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_member",))]
+      ("member", Some("added")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MemberAdded))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_member",))]
+      ("member", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MemberEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_member",))]
+      ("member", Some("removed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MemberRemoved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_membership",))]
+      ("membership", Some("added")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MembershipAdded))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_membership",))]
+      ("membership", Some("removed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MembershipRemoved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+      ("merge_group", Some("checks_requested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MergeGroupChecksRequested))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+      ("merge_group", Some("destroyed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MergeGroupDestroyed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_meta",))]
+      ("meta", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MetaDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      ("milestone", Some("closed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MilestoneClosed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      ("milestone", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MilestoneCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      ("milestone", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MilestoneDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      ("milestone", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MilestoneEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      ("milestone", Some("opened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::MilestoneOpened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_org_block",))]
+      ("org_block", Some("blocked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::OrgBlockBlocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_org_block",))]
+      ("org_block", Some("unblocked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::OrgBlockUnblocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      ("organization", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::OrganizationDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      ("organization", Some("member_added")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::OrganizationMemberAdded))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      ("organization", Some("member_invited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::OrganizationMemberInvited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      ("organization", Some("member_removed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::OrganizationMemberRemoved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      ("organization", Some("renamed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::OrganizationRenamed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_package",))]
+      ("package", Some("published")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PackagePublished))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_package",))]
+      ("package", Some("updated")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PackageUpdated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_page_build",))]
+      ("page_build", _) => Some(serde_json::from_slice(body).map(WebhookEvent::PageBuild)),
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      ("personal_access_token_request", Some("approved")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PersonalAccessTokenRequestApproved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      ("personal_access_token_request", Some("cancelled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PersonalAccessTokenRequestCancelled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      ("personal_access_token_request", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PersonalAccessTokenRequestCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      ("personal_access_token_request", Some("denied")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PersonalAccessTokenRequestDenied))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_ping",))]
+      ("ping", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Ping)),
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      ("project_card", Some("converted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectCardConverted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      ("project_card", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectCardCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      ("project_card", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectCardDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      ("project_card", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectCardEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      ("project_card", Some("moved")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectCardMoved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      ("project", Some("closed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectClosed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      ("project_column", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectColumnCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      ("project_column", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectColumnDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      ("project_column", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectColumnEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      ("project_column", Some("moved")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectColumnMoved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      ("project", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      ("project", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      ("project", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      ("project", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      ("projects_v2", Some("closed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2Closed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      ("projects_v2", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2Created))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      ("projects_v2", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2Deleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      ("projects_v2", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2Edited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      ("projects_v2_item", Some("archived")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2ItemArchived))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      ("projects_v2_item", Some("converted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2ItemConverted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      ("projects_v2_item", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2ItemCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      ("projects_v2_item", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2ItemDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      ("projects_v2_item", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2ItemEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      ("projects_v2_item", Some("reordered")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2ItemReordered))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      ("projects_v2_item", Some("restored")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2ItemRestored))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      ("projects_v2", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ProjectsV2Reopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_public",))]
+      ("public", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Public)),
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("assigned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestAssigned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("auto_merge_disabled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestAutoMergeDisabled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("auto_merge_enabled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestAutoMergeEnabled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("closed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestClosed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("converted_to_draft")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestConvertedToDraft))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("demilestoned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestDemilestoned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("dequeued")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestDequeued))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("enqueued")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestEnqueued))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("labeled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestLabeled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("locked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestLocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("milestoned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestMilestoned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("opened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestOpened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("ready_for_review")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReadyForReview))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+      ("pull_request_review_comment", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewCommentCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+      ("pull_request_review_comment", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewCommentDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+      ("pull_request_review_comment", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewCommentEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+      ("pull_request_review", Some("dismissed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewDismissed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+      ("pull_request_review", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("review_request_removed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewRequestRemoved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("review_requested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewRequested))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+      ("pull_request_review", Some("submitted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewSubmitted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+      ("pull_request_review_thread", Some("resolved")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewThreadResolved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+      ("pull_request_review_thread", Some("unresolved")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestReviewThreadUnresolved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("synchronize")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestSynchronize))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("unassigned")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestUnassigned))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("unlabeled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestUnlabeled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      ("pull_request", Some("unlocked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::PullRequestUnlocked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_push",))]
+      ("push", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Push)),
+      #[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+      ("registry_package", Some("published")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RegistryPackagePublished))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+      ("registry_package", Some("updated")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RegistryPackageUpdated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      ("release", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ReleaseCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      ("release", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ReleaseDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      ("release", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ReleaseEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      ("release", Some("prereleased")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ReleasePrereleased))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      ("release", Some("published")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ReleasePublished))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      ("release", Some("released")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ReleaseReleased))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      ("release", Some("unpublished")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::ReleaseUnpublished))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+      ("repository_advisory", Some("published")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryAdvisoryPublished))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+      ("repository_advisory", Some("reported")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryAdvisoryReported))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("archived")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryArchived))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_dispatch",))]
+      ("repository_dispatch", _) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryDispatchSampleCollected))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_import",))]
+      ("repository_import", _) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryImport))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("privatized")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryPrivatized))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("publicized")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryPublicized))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("renamed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryRenamed))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+      ("repository_ruleset", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryRulesetCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+      ("repository_ruleset", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryRulesetDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+      ("repository_ruleset", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryRulesetEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("transferred")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryTransferred))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      ("repository", Some("unarchived")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryUnarchived))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      ("repository_vulnerability_alert", Some("create")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryVulnerabilityAlertCreate))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      ("repository_vulnerability_alert", Some("dismiss")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryVulnerabilityAlertDismiss))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      ("repository_vulnerability_alert", Some("reopen")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryVulnerabilityAlertReopen))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      ("repository_vulnerability_alert", Some("resolve")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::RepositoryVulnerabilityAlertResolve))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      ("secret_scanning_alert", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecretScanningAlertCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
+      ("secret_scanning_alert_location", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecretScanningAlertLocationCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      ("secret_scanning_alert", Some("reopened")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecretScanningAlertReopened))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      ("secret_scanning_alert", Some("resolved")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecretScanningAlertResolved))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      ("secret_scanning_alert", Some("revoked")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecretScanningAlertRevoked))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      ("secret_scanning_alert", Some("validated")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecretScanningAlertValidated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+      ("security_advisory", Some("published")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecurityAdvisoryPublished))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+      ("security_advisory", Some("updated")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecurityAdvisoryUpdated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+      ("security_advisory", Some("withdrawn")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecurityAdvisoryWithdrawn))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
+      ("security_and_analysis", _) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SecurityAndAnalysis))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      ("sponsorship", Some("cancelled")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SponsorshipCancelled))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      ("sponsorship", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SponsorshipCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      ("sponsorship", Some("edited")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SponsorshipEdited))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      ("sponsorship", Some("pending_cancellation")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SponsorshipPendingCancellation))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      ("sponsorship", Some("pending_tier_change")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SponsorshipPendingTierChange))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      ("sponsorship", Some("tier_changed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::SponsorshipTierChanged))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_star",))]
+      ("star", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::StarCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_star",))]
+      ("star", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::StarDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_status",))]
+      ("status", _) => Some(serde_json::from_slice(body).map(WebhookEvent::Status)),
+      #[cfg(any(feature = "full", feature = "webhook_team_add",))]
+      ("team_add", _) => Some(serde_json::from_slice(body).map(WebhookEvent::TeamAdd)),
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      ("team", Some("added_to_repository")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::TeamAddedToRepository))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      ("team", Some("created")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::TeamCreated))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      ("team", Some("deleted")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::TeamDeleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      ("team", Some("edited")) => Some(serde_json::from_slice(body).map(WebhookEvent::TeamEdited)),
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      ("team", Some("removed_from_repository")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::TeamRemovedFromRepository))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_watch",))]
+      ("watch", Some("started")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WatchStarted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_dispatch",))]
+      ("workflow_dispatch", _) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowDispatch))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      ("workflow_job", Some("completed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowJobCompleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      ("workflow_job", Some("in_progress")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowJobInProgress))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      ("workflow_job", Some("queued")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowJobQueued))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      ("workflow_job", Some("waiting")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowJobWaiting))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+      ("workflow_run", Some("completed")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowRunCompleted))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+      ("workflow_run", Some("in_progress")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowRunInProgress))
+      }
+      #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+      ("workflow_run", Some("requested")) => {
+        Some(serde_json::from_slice(body).map(WebhookEvent::WorkflowRunRequested))
+      }
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_branch_protection_configuration",)
+      ))]
+      ("branch_protection_configuration", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "branch_protection_configuration",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_branch_protection_rule",)
+      ))]
+      ("branch_protection_rule", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "branch_protection_rule",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_check_run",)
+      ))]
+      ("check_run", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "check_run",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_check_suite",)
+      ))]
+      ("check_suite", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "check_suite",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_code_scanning_alert",)
+      ))]
+      ("code_scanning_alert", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "code_scanning_alert",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_commit_comment",)
+      ))]
+      ("commit_comment", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "commit_comment",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_custom_property",)
+      ))]
+      ("custom_property", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "custom_property",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_custom_property_values",)
+      ))]
+      ("custom_property_values", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "custom_property_values",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_dependabot_alert",)
+      ))]
+      ("dependabot_alert", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "dependabot_alert",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_deploy_key",)
+      ))]
+      ("deploy_key", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "deploy_key",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_deployment",)
+      ))]
+      ("deployment", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "deployment",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_deployment_protection_rule",)
+      ))]
+      ("deployment_protection_rule", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "deployment_protection_rule",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_deployment_review",)
+      ))]
+      ("deployment_review", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "deployment_review",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_deployment_status",)
+      ))]
+      ("deployment_status", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "deployment_status",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_discussion",)
+      ))]
+      ("discussion", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "discussion",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_discussion_comment",)
+      ))]
+      ("discussion_comment", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "discussion_comment",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_github_app_authorization",)
+      ))]
+      ("github_app_authorization", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "github_app_authorization",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_installation",)
+      ))]
+      ("installation", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "installation",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_installation_repositories",)
+      ))]
+      ("installation_repositories", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "installation_repositories",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_installation_target",)
+      ))]
+      ("installation_target", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "installation_target",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_issue_comment",)
+      ))]
+      ("issue_comment", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "issue_comment",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_issues",)
+      ))]
+      ("issues", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "issues",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_label",)
+      ))]
+      ("label", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "label",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_marketplace_purchase",)
+      ))]
+      ("marketplace_purchase", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "marketplace_purchase",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_member",)
+      ))]
+      ("member", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "member",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_membership",)
+      ))]
+      ("membership", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "membership",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_merge_group",)
+      ))]
+      ("merge_group", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "merge_group",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_meta",)
+      ))]
+      ("meta", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "meta",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_milestone",)
+      ))]
+      ("milestone", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "milestone",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_org_block",)
+      ))]
+      ("org_block", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "org_block",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_organization",)
+      ))]
+      ("organization", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "organization",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_package",)
+      ))]
+      ("package", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "package",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_personal_access_token_request",)
+      ))]
+      ("personal_access_token_request", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "personal_access_token_request",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_project_card",)
+      ))]
+      ("project_card", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "project_card",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_project",)
+      ))]
+      ("project", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "project",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_project_column",)
+      ))]
+      ("project_column", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "project_column",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_pull_request",)
+      ))]
+      ("pull_request", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "pull_request",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_pull_request_review_comment",)
+      ))]
+      ("pull_request_review_comment", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "pull_request_review_comment",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_pull_request_review",)
+      ))]
+      ("pull_request_review", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "pull_request_review",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_pull_request_review_thread",)
+      ))]
+      ("pull_request_review_thread", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "pull_request_review_thread",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_registry_package",)
+      ))]
+      ("registry_package", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "registry_package",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_release",)
+      ))]
+      ("release", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "release",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_repository_advisory",)
+      ))]
+      ("repository_advisory", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "repository_advisory",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_repository",)
+      ))]
+      ("repository", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "repository",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_repository_ruleset",)
+      ))]
+      ("repository_ruleset", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "repository_ruleset",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_repository_vulnerability_alert",)
+      ))]
+      ("repository_vulnerability_alert", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "repository_vulnerability_alert",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_secret_scanning_alert",)
+      ))]
+      ("secret_scanning_alert", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "secret_scanning_alert",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_secret_scanning_alert_location",)
+      ))]
+      ("secret_scanning_alert_location", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "secret_scanning_alert_location",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_security_advisory",)
+      ))]
+      ("security_advisory", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "security_advisory",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_sponsorship",)
+      ))]
+      ("sponsorship", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "sponsorship",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_star",)
+      ))]
+      ("star", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "star",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_team",)
+      ))]
+      ("team", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "team",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_watch",)
+      ))]
+      ("watch", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "watch",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_workflow_job",)
+      ))]
+      ("workflow_job", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "workflow_job",
+          ..event
+        })
+      })),
+      #[cfg(all(
+        not(feature = "strict"),
+        any(feature = "full", feature = "webhook_workflow_run",)
+      ))]
+      ("workflow_run", _) => Some(serde_json::from_slice(body).map(|event| {
+        WebhookEvent::Unknown(UnknownWebhookEvent {
+          event_name: "workflow_run",
+          ..event
+        })
+      })),
+      _ => None,
+    }
+  }
+
+  /// The `X-GitHub-Event` header of deliveries of this event.
+  pub fn event_name(&self) -> &'static str {
+    match *self {
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+      WebhookEvent::BranchProtectionConfigurationDisabled(_) => "branch_protection_configuration",
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+      WebhookEvent::BranchProtectionConfigurationEnabled(_) => "branch_protection_configuration",
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+      WebhookEvent::BranchProtectionRuleCreated(_) => "branch_protection_rule",
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+      WebhookEvent::BranchProtectionRuleDeleted(_) => "branch_protection_rule",
+      #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+      WebhookEvent::BranchProtectionRuleEdited(_) => "branch_protection_rule",
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      WebhookEvent::CheckRunCompleted(_) => "check_run",
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      WebhookEvent::CheckRunCreated(_) => "check_run",
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      WebhookEvent::CheckRunRequestedAction(_) => "check_run",
+      #[cfg(any(feature = "full", feature = "webhook_check_run",))]
+      WebhookEvent::CheckRunRerequested(_) => "check_run",
+      #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+      WebhookEvent::CheckSuiteCompleted(_) => "check_suite",
+      #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+      WebhookEvent::CheckSuiteRequested(_) => "check_suite",
+      #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+      WebhookEvent::CheckSuiteRerequested(_) => "check_suite",
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      WebhookEvent::CodeScanningAlertAppearedInBranch(_) => "code_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      WebhookEvent::CodeScanningAlertClosedByUser(_) => "code_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      WebhookEvent::CodeScanningAlertCreated(_) => "code_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      WebhookEvent::CodeScanningAlertFixed(_) => "code_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      WebhookEvent::CodeScanningAlertReopened(_) => "code_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+      WebhookEvent::CodeScanningAlertReopenedByUser(_) => "code_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
+      WebhookEvent::CommitCommentCreated(_) => "commit_comment",
+      #[cfg(any(feature = "full", feature = "webhook_create",))]
+      WebhookEvent::Create(_) => "create",
+      #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+      WebhookEvent::CustomPropertyCreated(_) => "custom_property",
+      #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+      WebhookEvent::CustomPropertyDeleted(_) => "custom_property",
+      #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+      WebhookEvent::CustomPropertyUpdated(_) => "custom_property",
+      #[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
+      WebhookEvent::CustomPropertyValuesUpdated(_) => "custom_property_values",
+      #[cfg(any(feature = "full", feature = "webhook_delete",))]
+      WebhookEvent::Delete(_) => "delete",
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      WebhookEvent::DependabotAlertAutoDismissed(_) => "dependabot_alert",
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      WebhookEvent::DependabotAlertAutoReopened(_) => "dependabot_alert",
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      WebhookEvent::DependabotAlertCreated(_) => "dependabot_alert",
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      WebhookEvent::DependabotAlertDismissed(_) => "dependabot_alert",
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      WebhookEvent::DependabotAlertFixed(_) => "dependabot_alert",
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      WebhookEvent::DependabotAlertReintroduced(_) => "dependabot_alert",
+      #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+      WebhookEvent::DependabotAlertReopened(_) => "dependabot_alert",
+      #[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+      WebhookEvent::DeployKeyCreated(_) => "deploy_key",
+      #[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+      WebhookEvent::DeployKeyDeleted(_) => "deploy_key",
+      #[cfg(any(feature = "full", feature = "webhook_deployment",))]
+      WebhookEvent::DeploymentCreated(_) => "deployment",
+      #[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
+      WebhookEvent::DeploymentProtectionRuleRequested(_) => "deployment_protection_rule",
+      #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+      WebhookEvent::DeploymentReviewApproved(_) => "deployment_review",
+      #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+      WebhookEvent::DeploymentReviewRejected(_) => "deployment_review",
+      #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+      WebhookEvent::DeploymentReviewRequested(_) => "deployment_review",
+      #[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
+      WebhookEvent::DeploymentStatusCreated(_) => "deployment_status",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionAnswered(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionCategoryChanged(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionClosed(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+      WebhookEvent::DiscussionCommentCreated(_) => "discussion_comment",
+      #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+      WebhookEvent::DiscussionCommentDeleted(_) => "discussion_comment",
+      #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+      WebhookEvent::DiscussionCommentEdited(_) => "discussion_comment",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionCreated(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionDeleted(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionEdited(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionLabeled(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionLocked(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionPinned(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionReopened(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionTransferred(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionUnanswered(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionUnlabeled(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionUnlocked(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_discussion",))]
+      WebhookEvent::DiscussionUnpinned(_) => "discussion",
+      #[cfg(any(feature = "full", feature = "webhook_fork",))]
+      WebhookEvent::Fork(_) => "fork",
+      #[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
+      WebhookEvent::GithubAppAuthorizationRevoked(_) => "github_app_authorization",
+      #[cfg(any(feature = "full", feature = "webhook_gollum",))]
+      WebhookEvent::Gollum(_) => "gollum",
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      WebhookEvent::InstallationCreated(_) => "installation",
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      WebhookEvent::InstallationDeleted(_) => "installation",
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      WebhookEvent::InstallationNewPermissionsAccepted(_) => "installation",
+      #[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+      WebhookEvent::InstallationRepositoriesAdded(_) => "installation_repositories",
+      #[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+      WebhookEvent::InstallationRepositoriesRemoved(_) => "installation_repositories",
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      WebhookEvent::InstallationSuspend(_) => "installation",
+      #[cfg(any(feature = "full", feature = "webhook_installation_target",))]
+      WebhookEvent::InstallationTargetRenamed(_) => "installation_target",
+      #[cfg(any(feature = "full", feature = "webhook_installation",))]
+      WebhookEvent::InstallationUnsuspend(_) => "installation",
+      #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+      WebhookEvent::IssueCommentCreated(_) => "issue_comment",
+      #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+      WebhookEvent::IssueCommentDeleted(_) => "issue_comment",
+      #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+      WebhookEvent::IssueCommentEdited(_) => "issue_comment",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesAssigned(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesClosed(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesDeleted(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesDemilestoned(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesEdited(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesLabeled(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesLocked(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesMilestoned(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesOpened(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesPinned(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesReopened(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesTransferred(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesUnassigned(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesUnlabeled(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesUnlocked(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_issues",))]
+      WebhookEvent::IssuesUnpinned(_) => "issues",
+      #[cfg(any(feature = "full", feature = "webhook_label",))]
+      WebhookEvent::LabelCreated(_) => "label",
+      #[cfg(any(feature = "full", feature = "webhook_label",))]
+      WebhookEvent::LabelDeleted(_) => "label",
+      #[cfg(any(feature = "full", feature = "webhook_label",))]
+      WebhookEvent::LabelEdited(_) => "label",
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      WebhookEvent::MarketplacePurchaseCancelled(_) => "marketplace_purchase",
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      WebhookEvent::MarketplacePurchaseChanged(_) => "marketplace_purchase",
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      WebhookEvent::MarketplacePurchasePendingChange(_) => "marketplace_purchase",
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      WebhookEvent::MarketplacePurchasePendingChangeCancelled(_) => "marketplace_purchase",
+      #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+      WebhookEvent::MarketplacePurchasePurchased(_) => "marketplace_purchase",
+      #[cfg(any(feature = "full", feature = "webhook_member",))]
+      WebhookEvent::MemberAdded(_) => "member",
+      #[cfg(any(feature = "full", feature = "webhook_member",))]
+      WebhookEvent::MemberEdited(_) => "member",
+      #[cfg(any(feature = "full", feature = "webhook_member",))]
+      WebhookEvent::MemberRemoved(_) => "member",
+      #[cfg(any(feature = "full", feature = "webhook_membership",))]
+      WebhookEvent::MembershipAdded(_) => "membership",
+      #[cfg(any(feature = "full", feature = "webhook_membership",))]
+      WebhookEvent::MembershipRemoved(_) => "membership",
+      #[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+      WebhookEvent::MergeGroupChecksRequested(_) => "merge_group",
+      #[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+      WebhookEvent::MergeGroupDestroyed(_) => "merge_group",
+      #[cfg(any(feature = "full", feature = "webhook_meta",))]
+      WebhookEvent::MetaDeleted(_) => "meta",
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      WebhookEvent::MilestoneClosed(_) => "milestone",
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      WebhookEvent::MilestoneCreated(_) => "milestone",
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      WebhookEvent::MilestoneDeleted(_) => "milestone",
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      WebhookEvent::MilestoneEdited(_) => "milestone",
+      #[cfg(any(feature = "full", feature = "webhook_milestone",))]
+      WebhookEvent::MilestoneOpened(_) => "milestone",
+      #[cfg(any(feature = "full", feature = "webhook_org_block",))]
+      WebhookEvent::OrgBlockBlocked(_) => "org_block",
+      #[cfg(any(feature = "full", feature = "webhook_org_block",))]
+      WebhookEvent::OrgBlockUnblocked(_) => "org_block",
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      WebhookEvent::OrganizationDeleted(_) => "organization",
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      WebhookEvent::OrganizationMemberAdded(_) => "organization",
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      WebhookEvent::OrganizationMemberInvited(_) => "organization",
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      WebhookEvent::OrganizationMemberRemoved(_) => "organization",
+      #[cfg(any(feature = "full", feature = "webhook_organization",))]
+      WebhookEvent::OrganizationRenamed(_) => "organization",
+      #[cfg(any(feature = "full", feature = "webhook_package",))]
+      WebhookEvent::PackagePublished(_) => "package",
+      #[cfg(any(feature = "full", feature = "webhook_package",))]
+      WebhookEvent::PackageUpdated(_) => "package",
+      #[cfg(any(feature = "full", feature = "webhook_page_build",))]
+      WebhookEvent::PageBuild(_) => "page_build",
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      WebhookEvent::PersonalAccessTokenRequestApproved(_) => "personal_access_token_request",
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      WebhookEvent::PersonalAccessTokenRequestCancelled(_) => "personal_access_token_request",
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      WebhookEvent::PersonalAccessTokenRequestCreated(_) => "personal_access_token_request",
+      #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+      WebhookEvent::PersonalAccessTokenRequestDenied(_) => "personal_access_token_request",
+      #[cfg(any(feature = "full", feature = "webhook_ping",))]
+      WebhookEvent::Ping(_) => "ping",
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      WebhookEvent::ProjectCardConverted(_) => "project_card",
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      WebhookEvent::ProjectCardCreated(_) => "project_card",
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      WebhookEvent::ProjectCardDeleted(_) => "project_card",
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      WebhookEvent::ProjectCardEdited(_) => "project_card",
+      #[cfg(any(feature = "full", feature = "webhook_project_card",))]
+      WebhookEvent::ProjectCardMoved(_) => "project_card",
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      WebhookEvent::ProjectClosed(_) => "project",
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      WebhookEvent::ProjectColumnCreated(_) => "project_column",
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      WebhookEvent::ProjectColumnDeleted(_) => "project_column",
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      WebhookEvent::ProjectColumnEdited(_) => "project_column",
+      #[cfg(any(feature = "full", feature = "webhook_project_column",))]
+      WebhookEvent::ProjectColumnMoved(_) => "project_column",
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      WebhookEvent::ProjectCreated(_) => "project",
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      WebhookEvent::ProjectDeleted(_) => "project",
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      WebhookEvent::ProjectEdited(_) => "project",
+      #[cfg(any(feature = "full", feature = "webhook_project",))]
+      WebhookEvent::ProjectReopened(_) => "project",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      WebhookEvent::ProjectsV2Closed(_) => "projects_v2",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      WebhookEvent::ProjectsV2Created(_) => "projects_v2",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      WebhookEvent::ProjectsV2Deleted(_) => "projects_v2",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      WebhookEvent::ProjectsV2Edited(_) => "projects_v2",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      WebhookEvent::ProjectsV2ItemArchived(_) => "projects_v2_item",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      WebhookEvent::ProjectsV2ItemConverted(_) => "projects_v2_item",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      WebhookEvent::ProjectsV2ItemCreated(_) => "projects_v2_item",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      WebhookEvent::ProjectsV2ItemDeleted(_) => "projects_v2_item",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      WebhookEvent::ProjectsV2ItemEdited(_) => "projects_v2_item",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      WebhookEvent::ProjectsV2ItemReordered(_) => "projects_v2_item",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+      WebhookEvent::ProjectsV2ItemRestored(_) => "projects_v2_item",
+      #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+      WebhookEvent::ProjectsV2Reopened(_) => "projects_v2",
+      #[cfg(any(feature = "full", feature = "webhook_public",))]
+      WebhookEvent::Public(_) => "public",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestAssigned(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestAutoMergeDisabled(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestAutoMergeEnabled(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestClosed(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestConvertedToDraft(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestDemilestoned(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestDequeued(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestEdited(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestEnqueued(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestLabeled(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestLocked(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestMilestoned(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestOpened(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestReadyForReview(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestReopened(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+      WebhookEvent::PullRequestReviewCommentCreated(_) => "pull_request_review_comment",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+      WebhookEvent::PullRequestReviewCommentDeleted(_) => "pull_request_review_comment",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+      WebhookEvent::PullRequestReviewCommentEdited(_) => "pull_request_review_comment",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+      WebhookEvent::PullRequestReviewDismissed(_) => "pull_request_review",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+      WebhookEvent::PullRequestReviewEdited(_) => "pull_request_review",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestReviewRequestRemoved(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestReviewRequested(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+      WebhookEvent::PullRequestReviewSubmitted(_) => "pull_request_review",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+      WebhookEvent::PullRequestReviewThreadResolved(_) => "pull_request_review_thread",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+      WebhookEvent::PullRequestReviewThreadUnresolved(_) => "pull_request_review_thread",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestSynchronize(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestUnassigned(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestUnlabeled(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+      WebhookEvent::PullRequestUnlocked(_) => "pull_request",
+      #[cfg(any(feature = "full", feature = "webhook_push",))]
+      WebhookEvent::Push(_) => "push",
+      #[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+      WebhookEvent::RegistryPackagePublished(_) => "registry_package",
+      #[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+      WebhookEvent::RegistryPackageUpdated(_) => "registry_package",
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      WebhookEvent::ReleaseCreated(_) => "release",
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      WebhookEvent::ReleaseDeleted(_) => "release",
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      WebhookEvent::ReleaseEdited(_) => "release",
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      WebhookEvent::ReleasePrereleased(_) => "release",
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      WebhookEvent::ReleasePublished(_) => "release",
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      WebhookEvent::ReleaseReleased(_) => "release",
+      #[cfg(any(feature = "full", feature = "webhook_release",))]
+      WebhookEvent::ReleaseUnpublished(_) => "release",
+      #[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+      WebhookEvent::RepositoryAdvisoryPublished(_) => "repository_advisory",
+      #[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+      WebhookEvent::RepositoryAdvisoryReported(_) => "repository_advisory",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryArchived(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryCreated(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryDeleted(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository_dispatch",))]
+      WebhookEvent::RepositoryDispatchSampleCollected(_) => "repository_dispatch",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryEdited(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository_import",))]
+      WebhookEvent::RepositoryImport(_) => "repository_import",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryPrivatized(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryPublicized(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryRenamed(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+      WebhookEvent::RepositoryRulesetCreated(_) => "repository_ruleset",
+      #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+      WebhookEvent::RepositoryRulesetDeleted(_) => "repository_ruleset",
+      #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+      WebhookEvent::RepositoryRulesetEdited(_) => "repository_ruleset",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryTransferred(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository",))]
+      WebhookEvent::RepositoryUnarchived(_) => "repository",
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      WebhookEvent::RepositoryVulnerabilityAlertCreate(_) => "repository_vulnerability_alert",
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      WebhookEvent::RepositoryVulnerabilityAlertDismiss(_) => "repository_vulnerability_alert",
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      WebhookEvent::RepositoryVulnerabilityAlertReopen(_) => "repository_vulnerability_alert",
+      #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+      WebhookEvent::RepositoryVulnerabilityAlertResolve(_) => "repository_vulnerability_alert",
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      WebhookEvent::SecretScanningAlertCreated(_) => "secret_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
+      WebhookEvent::SecretScanningAlertLocationCreated(_) => "secret_scanning_alert_location",
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      WebhookEvent::SecretScanningAlertReopened(_) => "secret_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      WebhookEvent::SecretScanningAlertResolved(_) => "secret_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      WebhookEvent::SecretScanningAlertRevoked(_) => "secret_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+      WebhookEvent::SecretScanningAlertValidated(_) => "secret_scanning_alert",
+      #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+      WebhookEvent::SecurityAdvisoryPublished(_) => "security_advisory",
+      #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+      WebhookEvent::SecurityAdvisoryUpdated(_) => "security_advisory",
+      #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+      WebhookEvent::SecurityAdvisoryWithdrawn(_) => "security_advisory",
+      #[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
+      WebhookEvent::SecurityAndAnalysis(_) => "security_and_analysis",
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      WebhookEvent::SponsorshipCancelled(_) => "sponsorship",
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      WebhookEvent::SponsorshipCreated(_) => "sponsorship",
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      WebhookEvent::SponsorshipEdited(_) => "sponsorship",
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      WebhookEvent::SponsorshipPendingCancellation(_) => "sponsorship",
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      WebhookEvent::SponsorshipPendingTierChange(_) => "sponsorship",
+      #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+      WebhookEvent::SponsorshipTierChanged(_) => "sponsorship",
+      #[cfg(any(feature = "full", feature = "webhook_star",))]
+      WebhookEvent::StarCreated(_) => "star",
+      #[cfg(any(feature = "full", feature = "webhook_star",))]
+      WebhookEvent::StarDeleted(_) => "star",
+      #[cfg(any(feature = "full", feature = "webhook_status",))]
+      WebhookEvent::Status(_) => "status",
+      #[cfg(any(feature = "full", feature = "webhook_team_add",))]
+      WebhookEvent::TeamAdd(_) => "team_add",
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      WebhookEvent::TeamAddedToRepository(_) => "team",
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      WebhookEvent::TeamCreated(_) => "team",
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      WebhookEvent::TeamDeleted(_) => "team",
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      WebhookEvent::TeamEdited(_) => "team",
+      #[cfg(any(feature = "full", feature = "webhook_team",))]
+      WebhookEvent::TeamRemovedFromRepository(_) => "team",
+      #[cfg(any(feature = "full", feature = "webhook_watch",))]
+      WebhookEvent::WatchStarted(_) => "watch",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_dispatch",))]
+      WebhookEvent::WorkflowDispatch(_) => "workflow_dispatch",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      WebhookEvent::WorkflowJobCompleted(_) => "workflow_job",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      WebhookEvent::WorkflowJobInProgress(_) => "workflow_job",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      WebhookEvent::WorkflowJobQueued(_) => "workflow_job",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+      WebhookEvent::WorkflowJobWaiting(_) => "workflow_job",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+      WebhookEvent::WorkflowRunCompleted(_) => "workflow_run",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+      WebhookEvent::WorkflowRunInProgress(_) => "workflow_run",
+      #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+      WebhookEvent::WorkflowRunRequested(_) => "workflow_run",
+      WebhookEvent::Unknown(ref event) => event.event_name,
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+impl From<BranchProtectionConfigurationDisabledEvent> for WebhookEvent {
+  fn from(event: BranchProtectionConfigurationDisabledEvent) -> Self {
+    WebhookEvent::BranchProtectionConfigurationDisabled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+impl TryFrom<WebhookEvent> for BranchProtectionConfigurationDisabledEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::BranchProtectionConfigurationDisabled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+impl From<BranchProtectionConfigurationEnabledEvent> for WebhookEvent {
+  fn from(event: BranchProtectionConfigurationEnabledEvent) -> Self {
+    WebhookEvent::BranchProtectionConfigurationEnabled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
+impl TryFrom<WebhookEvent> for BranchProtectionConfigurationEnabledEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::BranchProtectionConfigurationEnabled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+impl From<BranchProtectionRuleCreatedEvent> for WebhookEvent {
+  fn from(event: BranchProtectionRuleCreatedEvent) -> Self {
+    WebhookEvent::BranchProtectionRuleCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+impl TryFrom<WebhookEvent> for BranchProtectionRuleCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::BranchProtectionRuleCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+impl From<BranchProtectionRuleDeletedEvent> for WebhookEvent {
+  fn from(event: BranchProtectionRuleDeletedEvent) -> Self {
+    WebhookEvent::BranchProtectionRuleDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+impl TryFrom<WebhookEvent> for BranchProtectionRuleDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::BranchProtectionRuleDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+impl From<BranchProtectionRuleEditedEvent> for WebhookEvent {
+  fn from(event: BranchProtectionRuleEditedEvent) -> Self {
+    WebhookEvent::BranchProtectionRuleEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
+impl TryFrom<WebhookEvent> for BranchProtectionRuleEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::BranchProtectionRuleEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl From<CheckRunCompletedEvent> for WebhookEvent {
+  fn from(event: CheckRunCompletedEvent) -> Self {
+    WebhookEvent::CheckRunCompleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl TryFrom<WebhookEvent> for CheckRunCompletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CheckRunCompleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl From<CheckRunCreatedEvent> for WebhookEvent {
+  fn from(event: CheckRunCreatedEvent) -> Self {
+    WebhookEvent::CheckRunCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl TryFrom<WebhookEvent> for CheckRunCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CheckRunCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl From<CheckRunRequestedActionEvent> for WebhookEvent {
+  fn from(event: CheckRunRequestedActionEvent) -> Self {
+    WebhookEvent::CheckRunRequestedAction(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl TryFrom<WebhookEvent> for CheckRunRequestedActionEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CheckRunRequestedAction(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl From<CheckRunReRequestedEvent> for WebhookEvent {
+  fn from(event: CheckRunReRequestedEvent) -> Self {
+    WebhookEvent::CheckRunRerequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
+impl TryFrom<WebhookEvent> for CheckRunReRequestedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CheckRunRerequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+impl From<WebhookCheckSuiteCompleted> for WebhookEvent {
+  fn from(event: WebhookCheckSuiteCompleted) -> Self {
+    WebhookEvent::CheckSuiteCompleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+impl TryFrom<WebhookEvent> for WebhookCheckSuiteCompleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CheckSuiteCompleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+impl From<WebhookCheckSuiteRequested> for WebhookEvent {
+  fn from(event: WebhookCheckSuiteRequested) -> Self {
+    WebhookEvent::CheckSuiteRequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+impl TryFrom<WebhookEvent> for WebhookCheckSuiteRequested {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CheckSuiteRequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+impl From<WebhookCheckSuiteRerequested> for WebhookEvent {
+  fn from(event: WebhookCheckSuiteRerequested) -> Self {
+    WebhookEvent::CheckSuiteRerequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
+impl TryFrom<WebhookEvent> for WebhookCheckSuiteRerequested {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CheckSuiteRerequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl From<WebhookCodeScanningAlertAppearedInBranch> for WebhookEvent {
+  fn from(event: WebhookCodeScanningAlertAppearedInBranch) -> Self {
+    WebhookEvent::CodeScanningAlertAppearedInBranch(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookCodeScanningAlertAppearedInBranch {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CodeScanningAlertAppearedInBranch(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl From<WebhookCodeScanningAlertClosedByUser> for WebhookEvent {
+  fn from(event: WebhookCodeScanningAlertClosedByUser) -> Self {
+    WebhookEvent::CodeScanningAlertClosedByUser(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookCodeScanningAlertClosedByUser {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CodeScanningAlertClosedByUser(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl From<WebhookCodeScanningAlertCreated> for WebhookEvent {
+  fn from(event: WebhookCodeScanningAlertCreated) -> Self {
+    WebhookEvent::CodeScanningAlertCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookCodeScanningAlertCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CodeScanningAlertCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl From<WebhookCodeScanningAlertFixed> for WebhookEvent {
+  fn from(event: WebhookCodeScanningAlertFixed) -> Self {
+    WebhookEvent::CodeScanningAlertFixed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookCodeScanningAlertFixed {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CodeScanningAlertFixed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl From<WebhookCodeScanningAlertReopened> for WebhookEvent {
+  fn from(event: WebhookCodeScanningAlertReopened) -> Self {
+    WebhookEvent::CodeScanningAlertReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookCodeScanningAlertReopened {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CodeScanningAlertReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl From<WebhookCodeScanningAlertReopenedByUser> for WebhookEvent {
+  fn from(event: WebhookCodeScanningAlertReopenedByUser) -> Self {
+    WebhookEvent::CodeScanningAlertReopenedByUser(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookCodeScanningAlertReopenedByUser {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CodeScanningAlertReopenedByUser(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
+impl From<WebhookCommitCommentCreated> for WebhookEvent {
+  fn from(event: WebhookCommitCommentCreated) -> Self {
+    WebhookEvent::CommitCommentCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
+impl TryFrom<WebhookEvent> for WebhookCommitCommentCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CommitCommentCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_create",))]
+impl From<CreateEvent> for WebhookEvent {
+  fn from(event: CreateEvent) -> Self {
+    WebhookEvent::Create(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_create",))]
+impl TryFrom<WebhookEvent> for CreateEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Create(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+impl From<CustomPropertyCreatedEvent> for WebhookEvent {
+  fn from(event: CustomPropertyCreatedEvent) -> Self {
+    WebhookEvent::CustomPropertyCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+impl TryFrom<WebhookEvent> for CustomPropertyCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CustomPropertyCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+impl From<CustomPropertyDeletedEvent> for WebhookEvent {
+  fn from(event: CustomPropertyDeletedEvent) -> Self {
+    WebhookEvent::CustomPropertyDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+impl TryFrom<WebhookEvent> for CustomPropertyDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CustomPropertyDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+impl From<CustomPropertyUpdatedEvent> for WebhookEvent {
+  fn from(event: CustomPropertyUpdatedEvent) -> Self {
+    WebhookEvent::CustomPropertyUpdated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
+impl TryFrom<WebhookEvent> for CustomPropertyUpdatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CustomPropertyUpdated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
+impl From<CustomPropertyValuesUpdatedEvent> for WebhookEvent {
+  fn from(event: CustomPropertyValuesUpdatedEvent) -> Self {
+    WebhookEvent::CustomPropertyValuesUpdated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
+impl TryFrom<WebhookEvent> for CustomPropertyValuesUpdatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::CustomPropertyValuesUpdated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_delete",))]
+impl From<DeleteEvent> for WebhookEvent {
+  fn from(event: DeleteEvent) -> Self {
+    WebhookEvent::Delete(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_delete",))]
+impl TryFrom<WebhookEvent> for DeleteEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Delete(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl From<DependabotAlertAutoDismissedEvent> for WebhookEvent {
+  fn from(event: DependabotAlertAutoDismissedEvent) -> Self {
+    WebhookEvent::DependabotAlertAutoDismissed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl TryFrom<WebhookEvent> for DependabotAlertAutoDismissedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DependabotAlertAutoDismissed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl From<DependabotAlertAutoReopenedEvent> for WebhookEvent {
+  fn from(event: DependabotAlertAutoReopenedEvent) -> Self {
+    WebhookEvent::DependabotAlertAutoReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl TryFrom<WebhookEvent> for DependabotAlertAutoReopenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DependabotAlertAutoReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl From<DependabotAlertCreatedEvent> for WebhookEvent {
+  fn from(event: DependabotAlertCreatedEvent) -> Self {
+    WebhookEvent::DependabotAlertCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl TryFrom<WebhookEvent> for DependabotAlertCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DependabotAlertCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl From<DependabotAlertDismissedEvent> for WebhookEvent {
+  fn from(event: DependabotAlertDismissedEvent) -> Self {
+    WebhookEvent::DependabotAlertDismissed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl TryFrom<WebhookEvent> for DependabotAlertDismissedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DependabotAlertDismissed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl From<DependabotAlertFixedEvent> for WebhookEvent {
+  fn from(event: DependabotAlertFixedEvent) -> Self {
+    WebhookEvent::DependabotAlertFixed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl TryFrom<WebhookEvent> for DependabotAlertFixedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DependabotAlertFixed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl From<DependabotAlertReintroducedEvent> for WebhookEvent {
+  fn from(event: DependabotAlertReintroducedEvent) -> Self {
+    WebhookEvent::DependabotAlertReintroduced(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl TryFrom<WebhookEvent> for DependabotAlertReintroducedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DependabotAlertReintroduced(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl From<DependabotAlertReopenedEvent> for WebhookEvent {
+  fn from(event: DependabotAlertReopenedEvent) -> Self {
+    WebhookEvent::DependabotAlertReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
+impl TryFrom<WebhookEvent> for DependabotAlertReopenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DependabotAlertReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+impl From<WebhookDeployKeyCreated> for WebhookEvent {
+  fn from(event: WebhookDeployKeyCreated) -> Self {
+    WebhookEvent::DeployKeyCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+impl TryFrom<WebhookEvent> for WebhookDeployKeyCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeployKeyCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+impl From<WebhookDeployKeyDeleted> for WebhookEvent {
+  fn from(event: WebhookDeployKeyDeleted) -> Self {
+    WebhookEvent::DeployKeyDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
+impl TryFrom<WebhookEvent> for WebhookDeployKeyDeleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeployKeyDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment",))]
+impl From<DeploymentCreatedEvent> for WebhookEvent {
+  fn from(event: DeploymentCreatedEvent) -> Self {
+    WebhookEvent::DeploymentCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment",))]
+impl TryFrom<WebhookEvent> for DeploymentCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeploymentCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
+impl From<DeploymentProtectionRuleRequestedEvent> for WebhookEvent {
+  fn from(event: DeploymentProtectionRuleRequestedEvent) -> Self {
+    WebhookEvent::DeploymentProtectionRuleRequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
+impl TryFrom<WebhookEvent> for DeploymentProtectionRuleRequestedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeploymentProtectionRuleRequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+impl From<WebhookDeploymentReviewApproved> for WebhookEvent {
+  fn from(event: WebhookDeploymentReviewApproved) -> Self {
+    WebhookEvent::DeploymentReviewApproved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+impl TryFrom<WebhookEvent> for WebhookDeploymentReviewApproved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeploymentReviewApproved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+impl From<WebhookDeploymentReviewRejected> for WebhookEvent {
+  fn from(event: WebhookDeploymentReviewRejected) -> Self {
+    WebhookEvent::DeploymentReviewRejected(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+impl TryFrom<WebhookEvent> for WebhookDeploymentReviewRejected {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeploymentReviewRejected(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+impl From<WebhookDeploymentReviewRequested> for WebhookEvent {
+  fn from(event: WebhookDeploymentReviewRequested) -> Self {
+    WebhookEvent::DeploymentReviewRequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
+impl TryFrom<WebhookEvent> for WebhookDeploymentReviewRequested {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeploymentReviewRequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
+impl From<WebhookDeploymentStatusCreated> for WebhookEvent {
+  fn from(event: WebhookDeploymentStatusCreated) -> Self {
+    WebhookEvent::DeploymentStatusCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
+impl TryFrom<WebhookEvent> for WebhookDeploymentStatusCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DeploymentStatusCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionAnsweredEvent> for WebhookEvent {
+  fn from(event: DiscussionAnsweredEvent) -> Self {
+    WebhookEvent::DiscussionAnswered(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionAnsweredEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionAnswered(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionCategoryChangedEvent> for WebhookEvent {
+  fn from(event: DiscussionCategoryChangedEvent) -> Self {
+    WebhookEvent::DiscussionCategoryChanged(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionCategoryChangedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionCategoryChanged(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionClosedEvent> for WebhookEvent {
+  fn from(event: DiscussionClosedEvent) -> Self {
+    WebhookEvent::DiscussionClosed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionClosedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionClosed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+impl From<WebhookDiscussionCommentCreated> for WebhookEvent {
+  fn from(event: WebhookDiscussionCommentCreated) -> Self {
+    WebhookEvent::DiscussionCommentCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+impl TryFrom<WebhookEvent> for WebhookDiscussionCommentCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionCommentCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+impl From<WebhookDiscussionCommentDeleted> for WebhookEvent {
+  fn from(event: WebhookDiscussionCommentDeleted) -> Self {
+    WebhookEvent::DiscussionCommentDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+impl TryFrom<WebhookEvent> for WebhookDiscussionCommentDeleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionCommentDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+impl From<WebhookDiscussionCommentEdited> for WebhookEvent {
+  fn from(event: WebhookDiscussionCommentEdited) -> Self {
+    WebhookEvent::DiscussionCommentEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
+impl TryFrom<WebhookEvent> for WebhookDiscussionCommentEdited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionCommentEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionCreatedEvent> for WebhookEvent {
+  fn from(event: DiscussionCreatedEvent) -> Self {
+    WebhookEvent::DiscussionCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionDeletedEvent> for WebhookEvent {
+  fn from(event: DiscussionDeletedEvent) -> Self {
+    WebhookEvent::DiscussionDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionEditedEvent> for WebhookEvent {
+  fn from(event: DiscussionEditedEvent) -> Self {
+    WebhookEvent::DiscussionEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionLabeledEvent> for WebhookEvent {
+  fn from(event: DiscussionLabeledEvent) -> Self {
+    WebhookEvent::DiscussionLabeled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionLabeledEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionLabeled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionLockedEvent> for WebhookEvent {
+  fn from(event: DiscussionLockedEvent) -> Self {
+    WebhookEvent::DiscussionLocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionLockedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionLocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionPinnedEvent> for WebhookEvent {
+  fn from(event: DiscussionPinnedEvent) -> Self {
+    WebhookEvent::DiscussionPinned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionPinnedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionPinned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionReopenedEvent> for WebhookEvent {
+  fn from(event: DiscussionReopenedEvent) -> Self {
+    WebhookEvent::DiscussionReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionReopenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionTransferredEvent> for WebhookEvent {
+  fn from(event: DiscussionTransferredEvent) -> Self {
+    WebhookEvent::DiscussionTransferred(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionTransferredEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionTransferred(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionUnansweredEvent> for WebhookEvent {
+  fn from(event: DiscussionUnansweredEvent) -> Self {
+    WebhookEvent::DiscussionUnanswered(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionUnansweredEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionUnanswered(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionUnlabeledEvent> for WebhookEvent {
+  fn from(event: DiscussionUnlabeledEvent) -> Self {
+    WebhookEvent::DiscussionUnlabeled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionUnlabeledEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionUnlabeled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionUnlockedEvent> for WebhookEvent {
+  fn from(event: DiscussionUnlockedEvent) -> Self {
+    WebhookEvent::DiscussionUnlocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionUnlockedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionUnlocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl From<DiscussionUnpinnedEvent> for WebhookEvent {
+  fn from(event: DiscussionUnpinnedEvent) -> Self {
+    WebhookEvent::DiscussionUnpinned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
+impl TryFrom<WebhookEvent> for DiscussionUnpinnedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::DiscussionUnpinned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
+impl From<ForkEvent> for WebhookEvent {
+  fn from(event: ForkEvent) -> Self {
+    WebhookEvent::Fork(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
+impl TryFrom<WebhookEvent> for ForkEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Fork(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
+impl From<WebhookGithubAppAuthorizationRevoked> for WebhookEvent {
+  fn from(event: WebhookGithubAppAuthorizationRevoked) -> Self {
+    WebhookEvent::GithubAppAuthorizationRevoked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
+impl TryFrom<WebhookEvent> for WebhookGithubAppAuthorizationRevoked {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::GithubAppAuthorizationRevoked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
+impl From<GollumEvent> for WebhookEvent {
+  fn from(event: GollumEvent) -> Self {
+    WebhookEvent::Gollum(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
+impl TryFrom<WebhookEvent> for GollumEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Gollum(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl From<InstallationCreatedEvent> for WebhookEvent {
+  fn from(event: InstallationCreatedEvent) -> Self {
+    WebhookEvent::InstallationCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl TryFrom<WebhookEvent> for InstallationCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl From<InstallationDeletedEvent> for WebhookEvent {
+  fn from(event: InstallationDeletedEvent) -> Self {
+    WebhookEvent::InstallationDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl TryFrom<WebhookEvent> for InstallationDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl From<WebhookInstallationNewPermissionsAccepted> for WebhookEvent {
+  fn from(event: WebhookInstallationNewPermissionsAccepted) -> Self {
+    WebhookEvent::InstallationNewPermissionsAccepted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl TryFrom<WebhookEvent> for WebhookInstallationNewPermissionsAccepted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationNewPermissionsAccepted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+impl From<WebhookInstallationRepositoriesAdded> for WebhookEvent {
+  fn from(event: WebhookInstallationRepositoriesAdded) -> Self {
+    WebhookEvent::InstallationRepositoriesAdded(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+impl TryFrom<WebhookEvent> for WebhookInstallationRepositoriesAdded {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationRepositoriesAdded(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+impl From<WebhookInstallationRepositoriesRemoved> for WebhookEvent {
+  fn from(event: WebhookInstallationRepositoriesRemoved) -> Self {
+    WebhookEvent::InstallationRepositoriesRemoved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
+impl TryFrom<WebhookEvent> for WebhookInstallationRepositoriesRemoved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationRepositoriesRemoved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl From<InstallationSuspendEvent> for WebhookEvent {
+  fn from(event: InstallationSuspendEvent) -> Self {
+    WebhookEvent::InstallationSuspend(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl TryFrom<WebhookEvent> for InstallationSuspendEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationSuspend(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
+impl From<WebhookInstallationTargetRenamed> for WebhookEvent {
+  fn from(event: WebhookInstallationTargetRenamed) -> Self {
+    WebhookEvent::InstallationTargetRenamed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
+impl TryFrom<WebhookEvent> for WebhookInstallationTargetRenamed {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationTargetRenamed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl From<InstallationUnsuspendEvent> for WebhookEvent {
+  fn from(event: InstallationUnsuspendEvent) -> Self {
+    WebhookEvent::InstallationUnsuspend(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
+impl TryFrom<WebhookEvent> for InstallationUnsuspendEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::InstallationUnsuspend(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+impl From<WebhookIssueCommentCreated> for WebhookEvent {
+  fn from(event: WebhookIssueCommentCreated) -> Self {
+    WebhookEvent::IssueCommentCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+impl TryFrom<WebhookEvent> for WebhookIssueCommentCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssueCommentCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+impl From<WebhookIssueCommentDeleted> for WebhookEvent {
+  fn from(event: WebhookIssueCommentDeleted) -> Self {
+    WebhookEvent::IssueCommentDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+impl TryFrom<WebhookEvent> for WebhookIssueCommentDeleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssueCommentDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+impl From<WebhookIssueCommentEdited> for WebhookEvent {
+  fn from(event: WebhookIssueCommentEdited) -> Self {
+    WebhookEvent::IssueCommentEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
+impl TryFrom<WebhookEvent> for WebhookIssueCommentEdited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssueCommentEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesAssignedEvent> for WebhookEvent {
+  fn from(event: IssuesAssignedEvent) -> Self {
+    WebhookEvent::IssuesAssigned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesAssignedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesAssigned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesClosedEvent> for WebhookEvent {
+  fn from(event: IssuesClosedEvent) -> Self {
+    WebhookEvent::IssuesClosed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesClosedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesClosed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesDeletedEvent> for WebhookEvent {
+  fn from(event: IssuesDeletedEvent) -> Self {
+    WebhookEvent::IssuesDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesDemilestonedEvent> for WebhookEvent {
+  fn from(event: IssuesDemilestonedEvent) -> Self {
+    WebhookEvent::IssuesDemilestoned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesDemilestonedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesDemilestoned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesEditedEvent> for WebhookEvent {
+  fn from(event: IssuesEditedEvent) -> Self {
+    WebhookEvent::IssuesEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesLabeledEvent> for WebhookEvent {
+  fn from(event: IssuesLabeledEvent) -> Self {
+    WebhookEvent::IssuesLabeled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesLabeledEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesLabeled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesLockedEvent> for WebhookEvent {
+  fn from(event: IssuesLockedEvent) -> Self {
+    WebhookEvent::IssuesLocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesLockedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesLocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesMilestonedEvent> for WebhookEvent {
+  fn from(event: IssuesMilestonedEvent) -> Self {
+    WebhookEvent::IssuesMilestoned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesMilestonedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesMilestoned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesOpenedEvent> for WebhookEvent {
+  fn from(event: IssuesOpenedEvent) -> Self {
+    WebhookEvent::IssuesOpened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesOpenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesOpened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesPinnedEvent> for WebhookEvent {
+  fn from(event: IssuesPinnedEvent) -> Self {
+    WebhookEvent::IssuesPinned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesPinnedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesPinned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesReopenedEvent> for WebhookEvent {
+  fn from(event: IssuesReopenedEvent) -> Self {
+    WebhookEvent::IssuesReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesReopenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesTransferredEvent> for WebhookEvent {
+  fn from(event: IssuesTransferredEvent) -> Self {
+    WebhookEvent::IssuesTransferred(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesTransferredEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesTransferred(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesUnassignedEvent> for WebhookEvent {
+  fn from(event: IssuesUnassignedEvent) -> Self {
+    WebhookEvent::IssuesUnassigned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesUnassignedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesUnassigned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesUnlabeledEvent> for WebhookEvent {
+  fn from(event: IssuesUnlabeledEvent) -> Self {
+    WebhookEvent::IssuesUnlabeled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesUnlabeledEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesUnlabeled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesUnlockedEvent> for WebhookEvent {
+  fn from(event: IssuesUnlockedEvent) -> Self {
+    WebhookEvent::IssuesUnlocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesUnlockedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesUnlocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl From<IssuesUnpinnedEvent> for WebhookEvent {
+  fn from(event: IssuesUnpinnedEvent) -> Self {
+    WebhookEvent::IssuesUnpinned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
+impl TryFrom<WebhookEvent> for IssuesUnpinnedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::IssuesUnpinned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_label",))]
+impl From<LabelCreatedEvent> for WebhookEvent {
+  fn from(event: LabelCreatedEvent) -> Self {
+    WebhookEvent::LabelCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_label",))]
+impl TryFrom<WebhookEvent> for LabelCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::LabelCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_label",))]
+impl From<LabelDeletedEvent> for WebhookEvent {
+  fn from(event: LabelDeletedEvent) -> Self {
+    WebhookEvent::LabelDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_label",))]
+impl TryFrom<WebhookEvent> for LabelDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::LabelDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_label",))]
+impl From<LabelEditedEvent> for WebhookEvent {
+  fn from(event: LabelEditedEvent) -> Self {
+    WebhookEvent::LabelEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_label",))]
+impl TryFrom<WebhookEvent> for LabelEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::LabelEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl From<WebhookMarketplacePurchaseCancelled> for WebhookEvent {
+  fn from(event: WebhookMarketplacePurchaseCancelled) -> Self {
+    WebhookEvent::MarketplacePurchaseCancelled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl TryFrom<WebhookEvent> for WebhookMarketplacePurchaseCancelled {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MarketplacePurchaseCancelled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl From<WebhookMarketplacePurchaseChanged> for WebhookEvent {
+  fn from(event: WebhookMarketplacePurchaseChanged) -> Self {
+    WebhookEvent::MarketplacePurchaseChanged(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl TryFrom<WebhookEvent> for WebhookMarketplacePurchaseChanged {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MarketplacePurchaseChanged(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl From<WebhookMarketplacePurchasePendingChange> for WebhookEvent {
+  fn from(event: WebhookMarketplacePurchasePendingChange) -> Self {
+    WebhookEvent::MarketplacePurchasePendingChange(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl TryFrom<WebhookEvent> for WebhookMarketplacePurchasePendingChange {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MarketplacePurchasePendingChange(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl From<WebhookMarketplacePurchasePendingChangeCancelled> for WebhookEvent {
+  fn from(event: WebhookMarketplacePurchasePendingChangeCancelled) -> Self {
+    WebhookEvent::MarketplacePurchasePendingChangeCancelled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl TryFrom<WebhookEvent> for WebhookMarketplacePurchasePendingChangeCancelled {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MarketplacePurchasePendingChangeCancelled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl From<WebhookMarketplacePurchasePurchased> for WebhookEvent {
+  fn from(event: WebhookMarketplacePurchasePurchased) -> Self {
+    WebhookEvent::MarketplacePurchasePurchased(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
+impl TryFrom<WebhookEvent> for WebhookMarketplacePurchasePurchased {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MarketplacePurchasePurchased(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_member",))]
+impl From<MemberAddedEvent> for WebhookEvent {
+  fn from(event: MemberAddedEvent) -> Self {
+    WebhookEvent::MemberAdded(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_member",))]
+impl TryFrom<WebhookEvent> for MemberAddedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MemberAdded(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_member",))]
+impl From<MemberEditedEvent> for WebhookEvent {
+  fn from(event: MemberEditedEvent) -> Self {
+    WebhookEvent::MemberEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_member",))]
+impl TryFrom<WebhookEvent> for MemberEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MemberEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_member",))]
+impl From<MemberRemovedEvent> for WebhookEvent {
+  fn from(event: MemberRemovedEvent) -> Self {
+    WebhookEvent::MemberRemoved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_member",))]
+impl TryFrom<WebhookEvent> for MemberRemovedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MemberRemoved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
+impl From<MembershipAddedEvent> for WebhookEvent {
+  fn from(event: MembershipAddedEvent) -> Self {
+    WebhookEvent::MembershipAdded(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
+impl TryFrom<WebhookEvent> for MembershipAddedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MembershipAdded(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
+impl From<MembershipRemovedEvent> for WebhookEvent {
+  fn from(event: MembershipRemovedEvent) -> Self {
+    WebhookEvent::MembershipRemoved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
+impl TryFrom<WebhookEvent> for MembershipRemovedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MembershipRemoved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+impl From<WebhookMergeGroupChecksRequested> for WebhookEvent {
+  fn from(event: WebhookMergeGroupChecksRequested) -> Self {
+    WebhookEvent::MergeGroupChecksRequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+impl TryFrom<WebhookEvent> for WebhookMergeGroupChecksRequested {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MergeGroupChecksRequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+impl From<WebhookMergeGroupDestroyed> for WebhookEvent {
+  fn from(event: WebhookMergeGroupDestroyed) -> Self {
+    WebhookEvent::MergeGroupDestroyed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
+impl TryFrom<WebhookEvent> for WebhookMergeGroupDestroyed {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MergeGroupDestroyed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
+impl From<MetaDeletedEvent> for WebhookEvent {
+  fn from(event: MetaDeletedEvent) -> Self {
+    WebhookEvent::MetaDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
+impl TryFrom<WebhookEvent> for MetaDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MetaDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl From<MilestoneClosedEvent> for WebhookEvent {
+  fn from(event: MilestoneClosedEvent) -> Self {
+    WebhookEvent::MilestoneClosed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl TryFrom<WebhookEvent> for MilestoneClosedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MilestoneClosed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl From<MilestoneCreatedEvent> for WebhookEvent {
+  fn from(event: MilestoneCreatedEvent) -> Self {
+    WebhookEvent::MilestoneCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl TryFrom<WebhookEvent> for MilestoneCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MilestoneCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl From<MilestoneDeletedEvent> for WebhookEvent {
+  fn from(event: MilestoneDeletedEvent) -> Self {
+    WebhookEvent::MilestoneDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl TryFrom<WebhookEvent> for MilestoneDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MilestoneDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl From<MilestoneEditedEvent> for WebhookEvent {
+  fn from(event: MilestoneEditedEvent) -> Self {
+    WebhookEvent::MilestoneEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl TryFrom<WebhookEvent> for MilestoneEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MilestoneEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl From<MilestoneOpenedEvent> for WebhookEvent {
+  fn from(event: MilestoneOpenedEvent) -> Self {
+    WebhookEvent::MilestoneOpened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
+impl TryFrom<WebhookEvent> for MilestoneOpenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::MilestoneOpened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
+impl From<WebhookOrgBlockBlocked> for WebhookEvent {
+  fn from(event: WebhookOrgBlockBlocked) -> Self {
+    WebhookEvent::OrgBlockBlocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
+impl TryFrom<WebhookEvent> for WebhookOrgBlockBlocked {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::OrgBlockBlocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
+impl From<WebhookOrgBlockUnblocked> for WebhookEvent {
+  fn from(event: WebhookOrgBlockUnblocked) -> Self {
+    WebhookEvent::OrgBlockUnblocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
+impl TryFrom<WebhookEvent> for WebhookOrgBlockUnblocked {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::OrgBlockUnblocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl From<OrganizationDeletedEvent> for WebhookEvent {
+  fn from(event: OrganizationDeletedEvent) -> Self {
+    WebhookEvent::OrganizationDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl TryFrom<WebhookEvent> for OrganizationDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::OrganizationDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl From<WebhookOrganizationMemberAdded> for WebhookEvent {
+  fn from(event: WebhookOrganizationMemberAdded) -> Self {
+    WebhookEvent::OrganizationMemberAdded(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl TryFrom<WebhookEvent> for WebhookOrganizationMemberAdded {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::OrganizationMemberAdded(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl From<WebhookOrganizationMemberInvited> for WebhookEvent {
+  fn from(event: WebhookOrganizationMemberInvited) -> Self {
+    WebhookEvent::OrganizationMemberInvited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl TryFrom<WebhookEvent> for WebhookOrganizationMemberInvited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::OrganizationMemberInvited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl From<WebhookOrganizationMemberRemoved> for WebhookEvent {
+  fn from(event: WebhookOrganizationMemberRemoved) -> Self {
+    WebhookEvent::OrganizationMemberRemoved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl TryFrom<WebhookEvent> for WebhookOrganizationMemberRemoved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::OrganizationMemberRemoved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl From<OrganizationRenamedEvent> for WebhookEvent {
+  fn from(event: OrganizationRenamedEvent) -> Self {
+    WebhookEvent::OrganizationRenamed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
+impl TryFrom<WebhookEvent> for OrganizationRenamedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::OrganizationRenamed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_package",))]
+impl From<PackagePublishedEvent> for WebhookEvent {
+  fn from(event: PackagePublishedEvent) -> Self {
+    WebhookEvent::PackagePublished(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_package",))]
+impl TryFrom<WebhookEvent> for PackagePublishedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PackagePublished(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_package",))]
+impl From<PackageUpdatedEvent> for WebhookEvent {
+  fn from(event: PackageUpdatedEvent) -> Self {
+    WebhookEvent::PackageUpdated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_package",))]
+impl TryFrom<WebhookEvent> for PackageUpdatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PackageUpdated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
+impl From<WebhookPageBuild> for WebhookEvent {
+  fn from(event: WebhookPageBuild) -> Self {
+    WebhookEvent::PageBuild(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
+impl TryFrom<WebhookEvent> for WebhookPageBuild {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PageBuild(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl From<WebhookPersonalAccessTokenRequestApproved> for WebhookEvent {
+  fn from(event: WebhookPersonalAccessTokenRequestApproved) -> Self {
+    WebhookEvent::PersonalAccessTokenRequestApproved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl TryFrom<WebhookEvent> for WebhookPersonalAccessTokenRequestApproved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PersonalAccessTokenRequestApproved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl From<WebhookPersonalAccessTokenRequestCancelled> for WebhookEvent {
+  fn from(event: WebhookPersonalAccessTokenRequestCancelled) -> Self {
+    WebhookEvent::PersonalAccessTokenRequestCancelled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl TryFrom<WebhookEvent> for WebhookPersonalAccessTokenRequestCancelled {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PersonalAccessTokenRequestCancelled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl From<WebhookPersonalAccessTokenRequestCreated> for WebhookEvent {
+  fn from(event: WebhookPersonalAccessTokenRequestCreated) -> Self {
+    WebhookEvent::PersonalAccessTokenRequestCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl TryFrom<WebhookEvent> for WebhookPersonalAccessTokenRequestCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PersonalAccessTokenRequestCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl From<WebhookPersonalAccessTokenRequestDenied> for WebhookEvent {
+  fn from(event: WebhookPersonalAccessTokenRequestDenied) -> Self {
+    WebhookEvent::PersonalAccessTokenRequestDenied(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
+impl TryFrom<WebhookEvent> for WebhookPersonalAccessTokenRequestDenied {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PersonalAccessTokenRequestDenied(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_ping",))]
+impl From<WebhookPing> for WebhookEvent {
+  fn from(event: WebhookPing) -> Self {
+    WebhookEvent::Ping(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_ping",))]
+impl TryFrom<WebhookEvent> for WebhookPing {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Ping(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl From<WebhookProjectCardConverted> for WebhookEvent {
+  fn from(event: WebhookProjectCardConverted) -> Self {
+    WebhookEvent::ProjectCardConverted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl TryFrom<WebhookEvent> for WebhookProjectCardConverted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectCardConverted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl From<WebhookProjectCardCreated> for WebhookEvent {
+  fn from(event: WebhookProjectCardCreated) -> Self {
+    WebhookEvent::ProjectCardCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl TryFrom<WebhookEvent> for WebhookProjectCardCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectCardCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl From<WebhookProjectCardDeleted> for WebhookEvent {
+  fn from(event: WebhookProjectCardDeleted) -> Self {
+    WebhookEvent::ProjectCardDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl TryFrom<WebhookEvent> for WebhookProjectCardDeleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectCardDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl From<WebhookProjectCardEdited> for WebhookEvent {
+  fn from(event: WebhookProjectCardEdited) -> Self {
+    WebhookEvent::ProjectCardEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl TryFrom<WebhookEvent> for WebhookProjectCardEdited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectCardEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl From<WebhookProjectCardMoved> for WebhookEvent {
+  fn from(event: WebhookProjectCardMoved) -> Self {
+    WebhookEvent::ProjectCardMoved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
+impl TryFrom<WebhookEvent> for WebhookProjectCardMoved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectCardMoved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl From<ProjectClosedEvent> for WebhookEvent {
+  fn from(event: ProjectClosedEvent) -> Self {
+    WebhookEvent::ProjectClosed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl TryFrom<WebhookEvent> for ProjectClosedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectClosed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl From<WebhookProjectColumnCreated> for WebhookEvent {
+  fn from(event: WebhookProjectColumnCreated) -> Self {
+    WebhookEvent::ProjectColumnCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl TryFrom<WebhookEvent> for WebhookProjectColumnCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectColumnCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl From<WebhookProjectColumnDeleted> for WebhookEvent {
+  fn from(event: WebhookProjectColumnDeleted) -> Self {
+    WebhookEvent::ProjectColumnDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl TryFrom<WebhookEvent> for WebhookProjectColumnDeleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectColumnDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl From<WebhookProjectColumnEdited> for WebhookEvent {
+  fn from(event: WebhookProjectColumnEdited) -> Self {
+    WebhookEvent::ProjectColumnEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl TryFrom<WebhookEvent> for WebhookProjectColumnEdited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectColumnEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl From<WebhookProjectColumnMoved> for WebhookEvent {
+  fn from(event: WebhookProjectColumnMoved) -> Self {
+    WebhookEvent::ProjectColumnMoved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
+impl TryFrom<WebhookEvent> for WebhookProjectColumnMoved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectColumnMoved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl From<ProjectCreatedEvent> for WebhookEvent {
+  fn from(event: ProjectCreatedEvent) -> Self {
+    WebhookEvent::ProjectCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl TryFrom<WebhookEvent> for ProjectCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl From<ProjectDeletedEvent> for WebhookEvent {
+  fn from(event: ProjectDeletedEvent) -> Self {
+    WebhookEvent::ProjectDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl TryFrom<WebhookEvent> for ProjectDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl From<ProjectEditedEvent> for WebhookEvent {
+  fn from(event: ProjectEditedEvent) -> Self {
+    WebhookEvent::ProjectEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl TryFrom<WebhookEvent> for ProjectEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl From<ProjectReopenedEvent> for WebhookEvent {
+  fn from(event: ProjectReopenedEvent) -> Self {
+    WebhookEvent::ProjectReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_project",))]
+impl TryFrom<WebhookEvent> for ProjectReopenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl From<ProjectsV2ProjectClosedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ProjectClosedEvent) -> Self {
+    WebhookEvent::ProjectsV2Closed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ProjectClosedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2Closed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl From<WebhookProjectsV2ProjectCreated> for WebhookEvent {
+  fn from(event: WebhookProjectsV2ProjectCreated) -> Self {
+    WebhookEvent::ProjectsV2Created(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl TryFrom<WebhookEvent> for WebhookProjectsV2ProjectCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2Created(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl From<ProjectsV2ProjectDeletedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ProjectDeletedEvent) -> Self {
+    WebhookEvent::ProjectsV2Deleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ProjectDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2Deleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl From<ProjectsV2ProjectEditedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ProjectEditedEvent) -> Self {
+    WebhookEvent::ProjectsV2Edited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ProjectEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2Edited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl From<ProjectsV2ItemArchivedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ItemArchivedEvent) -> Self {
+    WebhookEvent::ProjectsV2ItemArchived(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ItemArchivedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2ItemArchived(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl From<ProjectsV2ItemConvertedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ItemConvertedEvent) -> Self {
+    WebhookEvent::ProjectsV2ItemConverted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ItemConvertedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2ItemConverted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl From<ProjectsV2ItemCreatedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ItemCreatedEvent) -> Self {
+    WebhookEvent::ProjectsV2ItemCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ItemCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2ItemCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl From<ProjectsV2ItemDeletedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ItemDeletedEvent) -> Self {
+    WebhookEvent::ProjectsV2ItemDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ItemDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2ItemDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl From<ProjectsV2ItemEditedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ItemEditedEvent) -> Self {
+    WebhookEvent::ProjectsV2ItemEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ItemEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2ItemEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl From<ProjectsV2ItemReorderedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ItemReorderedEvent) -> Self {
+    WebhookEvent::ProjectsV2ItemReordered(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ItemReorderedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2ItemReordered(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl From<ProjectsV2ItemRestoredEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ItemRestoredEvent) -> Self {
+    WebhookEvent::ProjectsV2ItemRestored(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ItemRestoredEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2ItemRestored(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl From<ProjectsV2ProjectReopenedEvent> for WebhookEvent {
+  fn from(event: ProjectsV2ProjectReopenedEvent) -> Self {
+    WebhookEvent::ProjectsV2Reopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
+impl TryFrom<WebhookEvent> for ProjectsV2ProjectReopenedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ProjectsV2Reopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_public",))]
+impl From<PublicEvent> for WebhookEvent {
+  fn from(event: PublicEvent) -> Self {
+    WebhookEvent::Public(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_public",))]
+impl TryFrom<WebhookEvent> for PublicEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Public(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestAssigned> for WebhookEvent {
+  fn from(event: WebhookPullRequestAssigned) -> Self {
+    WebhookEvent::PullRequestAssigned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestAssigned {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestAssigned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestAutoMergeDisabled> for WebhookEvent {
+  fn from(event: WebhookPullRequestAutoMergeDisabled) -> Self {
+    WebhookEvent::PullRequestAutoMergeDisabled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestAutoMergeDisabled {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestAutoMergeDisabled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestAutoMergeEnabled> for WebhookEvent {
+  fn from(event: WebhookPullRequestAutoMergeEnabled) -> Self {
+    WebhookEvent::PullRequestAutoMergeEnabled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestAutoMergeEnabled {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestAutoMergeEnabled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestClosed> for WebhookEvent {
+  fn from(event: WebhookPullRequestClosed) -> Self {
+    WebhookEvent::PullRequestClosed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestClosed {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestClosed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestConvertedToDraft> for WebhookEvent {
+  fn from(event: WebhookPullRequestConvertedToDraft) -> Self {
+    WebhookEvent::PullRequestConvertedToDraft(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestConvertedToDraft {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestConvertedToDraft(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestDemilestoned> for WebhookEvent {
+  fn from(event: WebhookPullRequestDemilestoned) -> Self {
+    WebhookEvent::PullRequestDemilestoned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestDemilestoned {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestDemilestoned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestDequeued> for WebhookEvent {
+  fn from(event: WebhookPullRequestDequeued) -> Self {
+    WebhookEvent::PullRequestDequeued(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestDequeued {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestDequeued(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestEdited> for WebhookEvent {
+  fn from(event: WebhookPullRequestEdited) -> Self {
+    WebhookEvent::PullRequestEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestEdited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestEnqueued> for WebhookEvent {
+  fn from(event: WebhookPullRequestEnqueued) -> Self {
+    WebhookEvent::PullRequestEnqueued(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestEnqueued {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestEnqueued(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestLabeled> for WebhookEvent {
+  fn from(event: WebhookPullRequestLabeled) -> Self {
+    WebhookEvent::PullRequestLabeled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestLabeled {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestLabeled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestLocked> for WebhookEvent {
+  fn from(event: WebhookPullRequestLocked) -> Self {
+    WebhookEvent::PullRequestLocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestLocked {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestLocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestMilestoned> for WebhookEvent {
+  fn from(event: WebhookPullRequestMilestoned) -> Self {
+    WebhookEvent::PullRequestMilestoned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestMilestoned {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestMilestoned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestOpened> for WebhookEvent {
+  fn from(event: WebhookPullRequestOpened) -> Self {
+    WebhookEvent::PullRequestOpened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestOpened {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestOpened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestReadyForReview> for WebhookEvent {
+  fn from(event: WebhookPullRequestReadyForReview) -> Self {
+    WebhookEvent::PullRequestReadyForReview(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReadyForReview {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReadyForReview(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestReopened> for WebhookEvent {
+  fn from(event: WebhookPullRequestReopened) -> Self {
+    WebhookEvent::PullRequestReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReopened {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+impl From<WebhookPullRequestReviewCommentCreated> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewCommentCreated) -> Self {
+    WebhookEvent::PullRequestReviewCommentCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewCommentCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewCommentCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+impl From<WebhookPullRequestReviewCommentDeleted> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewCommentDeleted) -> Self {
+    WebhookEvent::PullRequestReviewCommentDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewCommentDeleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewCommentDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+impl From<WebhookPullRequestReviewCommentEdited> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewCommentEdited) -> Self {
+    WebhookEvent::PullRequestReviewCommentEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewCommentEdited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewCommentEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+impl From<WebhookPullRequestReviewDismissed> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewDismissed) -> Self {
+    WebhookEvent::PullRequestReviewDismissed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewDismissed {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewDismissed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+impl From<WebhookPullRequestReviewEdited> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewEdited) -> Self {
+    WebhookEvent::PullRequestReviewEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewEdited {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestReviewRequestRemoved> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewRequestRemoved) -> Self {
+    WebhookEvent::PullRequestReviewRequestRemoved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewRequestRemoved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewRequestRemoved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestReviewRequested> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewRequested) -> Self {
+    WebhookEvent::PullRequestReviewRequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewRequested {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewRequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+impl From<WebhookPullRequestReviewSubmitted> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewSubmitted) -> Self {
+    WebhookEvent::PullRequestReviewSubmitted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewSubmitted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewSubmitted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+impl From<WebhookPullRequestReviewThreadResolved> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewThreadResolved) -> Self {
+    WebhookEvent::PullRequestReviewThreadResolved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewThreadResolved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewThreadResolved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+impl From<WebhookPullRequestReviewThreadUnresolved> for WebhookEvent {
+  fn from(event: WebhookPullRequestReviewThreadUnresolved) -> Self {
+    WebhookEvent::PullRequestReviewThreadUnresolved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestReviewThreadUnresolved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestReviewThreadUnresolved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestSynchronize> for WebhookEvent {
+  fn from(event: WebhookPullRequestSynchronize) -> Self {
+    WebhookEvent::PullRequestSynchronize(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestSynchronize {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestSynchronize(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestUnassigned> for WebhookEvent {
+  fn from(event: WebhookPullRequestUnassigned) -> Self {
+    WebhookEvent::PullRequestUnassigned(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestUnassigned {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestUnassigned(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestUnlabeled> for WebhookEvent {
+  fn from(event: WebhookPullRequestUnlabeled) -> Self {
+    WebhookEvent::PullRequestUnlabeled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestUnlabeled {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestUnlabeled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl From<WebhookPullRequestUnlocked> for WebhookEvent {
+  fn from(event: WebhookPullRequestUnlocked) -> Self {
+    WebhookEvent::PullRequestUnlocked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
+impl TryFrom<WebhookEvent> for WebhookPullRequestUnlocked {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::PullRequestUnlocked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_push",))]
+impl From<PushEvent> for WebhookEvent {
+  fn from(event: PushEvent) -> Self {
+    WebhookEvent::Push(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_push",))]
+impl TryFrom<WebhookEvent> for PushEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Push(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+impl From<WebhookRegistryPackagePublished> for WebhookEvent {
+  fn from(event: WebhookRegistryPackagePublished) -> Self {
+    WebhookEvent::RegistryPackagePublished(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+impl TryFrom<WebhookEvent> for WebhookRegistryPackagePublished {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RegistryPackagePublished(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+impl From<WebhookRegistryPackageUpdated> for WebhookEvent {
+  fn from(event: WebhookRegistryPackageUpdated) -> Self {
+    WebhookEvent::RegistryPackageUpdated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
+impl TryFrom<WebhookEvent> for WebhookRegistryPackageUpdated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RegistryPackageUpdated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl From<ReleaseCreatedEvent> for WebhookEvent {
+  fn from(event: ReleaseCreatedEvent) -> Self {
+    WebhookEvent::ReleaseCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl TryFrom<WebhookEvent> for ReleaseCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ReleaseCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl From<ReleaseDeletedEvent> for WebhookEvent {
+  fn from(event: ReleaseDeletedEvent) -> Self {
+    WebhookEvent::ReleaseDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl TryFrom<WebhookEvent> for ReleaseDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ReleaseDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl From<ReleaseEditedEvent> for WebhookEvent {
+  fn from(event: ReleaseEditedEvent) -> Self {
+    WebhookEvent::ReleaseEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl TryFrom<WebhookEvent> for ReleaseEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ReleaseEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl From<ReleasePrereleasedEvent> for WebhookEvent {
+  fn from(event: ReleasePrereleasedEvent) -> Self {
+    WebhookEvent::ReleasePrereleased(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl TryFrom<WebhookEvent> for ReleasePrereleasedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ReleasePrereleased(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl From<ReleasePublishedEvent> for WebhookEvent {
+  fn from(event: ReleasePublishedEvent) -> Self {
+    WebhookEvent::ReleasePublished(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl TryFrom<WebhookEvent> for ReleasePublishedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ReleasePublished(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl From<ReleaseReleasedEvent> for WebhookEvent {
+  fn from(event: ReleaseReleasedEvent) -> Self {
+    WebhookEvent::ReleaseReleased(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl TryFrom<WebhookEvent> for ReleaseReleasedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ReleaseReleased(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl From<ReleaseUnpublishedEvent> for WebhookEvent {
+  fn from(event: ReleaseUnpublishedEvent) -> Self {
+    WebhookEvent::ReleaseUnpublished(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_release",))]
+impl TryFrom<WebhookEvent> for ReleaseUnpublishedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::ReleaseUnpublished(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+impl From<RepositoryAdvisoryPublishedEvent> for WebhookEvent {
+  fn from(event: RepositoryAdvisoryPublishedEvent) -> Self {
+    WebhookEvent::RepositoryAdvisoryPublished(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+impl TryFrom<WebhookEvent> for RepositoryAdvisoryPublishedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryAdvisoryPublished(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+impl From<RepositoryAdvisoryReportedEvent> for WebhookEvent {
+  fn from(event: RepositoryAdvisoryReportedEvent) -> Self {
+    WebhookEvent::RepositoryAdvisoryReported(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
+impl TryFrom<WebhookEvent> for RepositoryAdvisoryReportedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryAdvisoryReported(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryArchivedEvent> for WebhookEvent {
+  fn from(event: RepositoryArchivedEvent) -> Self {
+    WebhookEvent::RepositoryArchived(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryArchivedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryArchived(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryCreatedEvent> for WebhookEvent {
+  fn from(event: RepositoryCreatedEvent) -> Self {
+    WebhookEvent::RepositoryCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryDeletedEvent> for WebhookEvent {
+  fn from(event: RepositoryDeletedEvent) -> Self {
+    WebhookEvent::RepositoryDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_dispatch",))]
+impl From<WebhookRepositoryDispatchSample> for WebhookEvent {
+  fn from(event: WebhookRepositoryDispatchSample) -> Self {
+    WebhookEvent::RepositoryDispatchSampleCollected(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_dispatch",))]
+impl TryFrom<WebhookEvent> for WebhookRepositoryDispatchSample {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryDispatchSampleCollected(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryEditedEvent> for WebhookEvent {
+  fn from(event: RepositoryEditedEvent) -> Self {
+    WebhookEvent::RepositoryEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_import",))]
+impl From<WebhookRepositoryImport> for WebhookEvent {
+  fn from(event: WebhookRepositoryImport) -> Self {
+    WebhookEvent::RepositoryImport(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_import",))]
+impl TryFrom<WebhookEvent> for WebhookRepositoryImport {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryImport(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryPrivatizedEvent> for WebhookEvent {
+  fn from(event: RepositoryPrivatizedEvent) -> Self {
+    WebhookEvent::RepositoryPrivatized(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryPrivatizedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryPrivatized(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryPublicizedEvent> for WebhookEvent {
+  fn from(event: RepositoryPublicizedEvent) -> Self {
+    WebhookEvent::RepositoryPublicized(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryPublicizedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryPublicized(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryRenamedEvent> for WebhookEvent {
+  fn from(event: RepositoryRenamedEvent) -> Self {
+    WebhookEvent::RepositoryRenamed(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryRenamedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryRenamed(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+impl From<RepositoryRulesetCreatedEvent> for WebhookEvent {
+  fn from(event: RepositoryRulesetCreatedEvent) -> Self {
+    WebhookEvent::RepositoryRulesetCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+impl TryFrom<WebhookEvent> for RepositoryRulesetCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryRulesetCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+impl From<RepositoryRulesetDeletedEvent> for WebhookEvent {
+  fn from(event: RepositoryRulesetDeletedEvent) -> Self {
+    WebhookEvent::RepositoryRulesetDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+impl TryFrom<WebhookEvent> for RepositoryRulesetDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryRulesetDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+impl From<RepositoryRulesetEditedEvent> for WebhookEvent {
+  fn from(event: RepositoryRulesetEditedEvent) -> Self {
+    WebhookEvent::RepositoryRulesetEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
+impl TryFrom<WebhookEvent> for RepositoryRulesetEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryRulesetEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryTransferredEvent> for WebhookEvent {
+  fn from(event: RepositoryTransferredEvent) -> Self {
+    WebhookEvent::RepositoryTransferred(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryTransferredEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryTransferred(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl From<RepositoryUnarchivedEvent> for WebhookEvent {
+  fn from(event: RepositoryUnarchivedEvent) -> Self {
+    WebhookEvent::RepositoryUnarchived(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
+impl TryFrom<WebhookEvent> for RepositoryUnarchivedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryUnarchived(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl From<WebhookRepositoryVulnerabilityAlertCreate> for WebhookEvent {
+  fn from(event: WebhookRepositoryVulnerabilityAlertCreate) -> Self {
+    WebhookEvent::RepositoryVulnerabilityAlertCreate(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl TryFrom<WebhookEvent> for WebhookRepositoryVulnerabilityAlertCreate {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryVulnerabilityAlertCreate(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl From<WebhookRepositoryVulnerabilityAlertDismiss> for WebhookEvent {
+  fn from(event: WebhookRepositoryVulnerabilityAlertDismiss) -> Self {
+    WebhookEvent::RepositoryVulnerabilityAlertDismiss(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl TryFrom<WebhookEvent> for WebhookRepositoryVulnerabilityAlertDismiss {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryVulnerabilityAlertDismiss(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl From<WebhookRepositoryVulnerabilityAlertReopen> for WebhookEvent {
+  fn from(event: WebhookRepositoryVulnerabilityAlertReopen) -> Self {
+    WebhookEvent::RepositoryVulnerabilityAlertReopen(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl TryFrom<WebhookEvent> for WebhookRepositoryVulnerabilityAlertReopen {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryVulnerabilityAlertReopen(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl From<WebhookRepositoryVulnerabilityAlertResolve> for WebhookEvent {
+  fn from(event: WebhookRepositoryVulnerabilityAlertResolve) -> Self {
+    WebhookEvent::RepositoryVulnerabilityAlertResolve(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
+impl TryFrom<WebhookEvent> for WebhookRepositoryVulnerabilityAlertResolve {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::RepositoryVulnerabilityAlertResolve(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl From<WebhookSecretScanningAlertCreated> for WebhookEvent {
+  fn from(event: WebhookSecretScanningAlertCreated) -> Self {
+    WebhookEvent::SecretScanningAlertCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookSecretScanningAlertCreated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecretScanningAlertCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
+impl From<SecretScanningAlertLocationCreatedEvent> for WebhookEvent {
+  fn from(event: SecretScanningAlertLocationCreatedEvent) -> Self {
+    WebhookEvent::SecretScanningAlertLocationCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
+impl TryFrom<WebhookEvent> for SecretScanningAlertLocationCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecretScanningAlertLocationCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl From<WebhookSecretScanningAlertReopened> for WebhookEvent {
+  fn from(event: WebhookSecretScanningAlertReopened) -> Self {
+    WebhookEvent::SecretScanningAlertReopened(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookSecretScanningAlertReopened {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecretScanningAlertReopened(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl From<WebhookSecretScanningAlertResolved> for WebhookEvent {
+  fn from(event: WebhookSecretScanningAlertResolved) -> Self {
+    WebhookEvent::SecretScanningAlertResolved(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookSecretScanningAlertResolved {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecretScanningAlertResolved(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl From<WebhookSecretScanningAlertRevoked> for WebhookEvent {
+  fn from(event: WebhookSecretScanningAlertRevoked) -> Self {
+    WebhookEvent::SecretScanningAlertRevoked(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookSecretScanningAlertRevoked {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecretScanningAlertRevoked(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl From<WebhookSecretScanningAlertValidated> for WebhookEvent {
+  fn from(event: WebhookSecretScanningAlertValidated) -> Self {
+    WebhookEvent::SecretScanningAlertValidated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
+impl TryFrom<WebhookEvent> for WebhookSecretScanningAlertValidated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecretScanningAlertValidated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+impl From<WebhookSecurityAdvisoryPublished> for WebhookEvent {
+  fn from(event: WebhookSecurityAdvisoryPublished) -> Self {
+    WebhookEvent::SecurityAdvisoryPublished(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+impl TryFrom<WebhookEvent> for WebhookSecurityAdvisoryPublished {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecurityAdvisoryPublished(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+impl From<WebhookSecurityAdvisoryUpdated> for WebhookEvent {
+  fn from(event: WebhookSecurityAdvisoryUpdated) -> Self {
+    WebhookEvent::SecurityAdvisoryUpdated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+impl TryFrom<WebhookEvent> for WebhookSecurityAdvisoryUpdated {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecurityAdvisoryUpdated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+impl From<WebhookSecurityAdvisoryWithdrawn> for WebhookEvent {
+  fn from(event: WebhookSecurityAdvisoryWithdrawn) -> Self {
+    WebhookEvent::SecurityAdvisoryWithdrawn(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
+impl TryFrom<WebhookEvent> for WebhookSecurityAdvisoryWithdrawn {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecurityAdvisoryWithdrawn(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
+impl From<WebhookSecurityAndAnalysis> for WebhookEvent {
+  fn from(event: WebhookSecurityAndAnalysis) -> Self {
+    WebhookEvent::SecurityAndAnalysis(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
+impl TryFrom<WebhookEvent> for WebhookSecurityAndAnalysis {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SecurityAndAnalysis(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl From<SponsorshipCancelledEvent> for WebhookEvent {
+  fn from(event: SponsorshipCancelledEvent) -> Self {
+    WebhookEvent::SponsorshipCancelled(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl TryFrom<WebhookEvent> for SponsorshipCancelledEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SponsorshipCancelled(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl From<SponsorshipCreatedEvent> for WebhookEvent {
+  fn from(event: SponsorshipCreatedEvent) -> Self {
+    WebhookEvent::SponsorshipCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl TryFrom<WebhookEvent> for SponsorshipCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SponsorshipCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl From<SponsorshipEditedEvent> for WebhookEvent {
+  fn from(event: SponsorshipEditedEvent) -> Self {
+    WebhookEvent::SponsorshipEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl TryFrom<WebhookEvent> for SponsorshipEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SponsorshipEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl From<WebhookSponsorshipPendingCancellation> for WebhookEvent {
+  fn from(event: WebhookSponsorshipPendingCancellation) -> Self {
+    WebhookEvent::SponsorshipPendingCancellation(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl TryFrom<WebhookEvent> for WebhookSponsorshipPendingCancellation {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SponsorshipPendingCancellation(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl From<WebhookSponsorshipPendingTierChange> for WebhookEvent {
+  fn from(event: WebhookSponsorshipPendingTierChange) -> Self {
+    WebhookEvent::SponsorshipPendingTierChange(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl TryFrom<WebhookEvent> for WebhookSponsorshipPendingTierChange {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SponsorshipPendingTierChange(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl From<WebhookSponsorshipTierChanged> for WebhookEvent {
+  fn from(event: WebhookSponsorshipTierChanged) -> Self {
+    WebhookEvent::SponsorshipTierChanged(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
+impl TryFrom<WebhookEvent> for WebhookSponsorshipTierChanged {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::SponsorshipTierChanged(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_star",))]
+impl From<StarCreatedEvent> for WebhookEvent {
+  fn from(event: StarCreatedEvent) -> Self {
+    WebhookEvent::StarCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_star",))]
+impl TryFrom<WebhookEvent> for StarCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::StarCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_star",))]
+impl From<StarDeletedEvent> for WebhookEvent {
+  fn from(event: StarDeletedEvent) -> Self {
+    WebhookEvent::StarDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_star",))]
+impl TryFrom<WebhookEvent> for StarDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::StarDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_status",))]
+impl From<StatusEvent> for WebhookEvent {
+  fn from(event: StatusEvent) -> Self {
+    WebhookEvent::Status(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_status",))]
+impl TryFrom<WebhookEvent> for StatusEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::Status(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team_add",))]
+impl From<WebhookTeamAdd> for WebhookEvent {
+  fn from(event: WebhookTeamAdd) -> Self {
+    WebhookEvent::TeamAdd(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team_add",))]
+impl TryFrom<WebhookEvent> for WebhookTeamAdd {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::TeamAdd(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl From<WebhookTeamAddedToRepository> for WebhookEvent {
+  fn from(event: WebhookTeamAddedToRepository) -> Self {
+    WebhookEvent::TeamAddedToRepository(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl TryFrom<WebhookEvent> for WebhookTeamAddedToRepository {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::TeamAddedToRepository(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl From<TeamCreatedEvent> for WebhookEvent {
+  fn from(event: TeamCreatedEvent) -> Self {
+    WebhookEvent::TeamCreated(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl TryFrom<WebhookEvent> for TeamCreatedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::TeamCreated(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl From<TeamDeletedEvent> for WebhookEvent {
+  fn from(event: TeamDeletedEvent) -> Self {
+    WebhookEvent::TeamDeleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl TryFrom<WebhookEvent> for TeamDeletedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::TeamDeleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl From<TeamEditedEvent> for WebhookEvent {
+  fn from(event: TeamEditedEvent) -> Self {
+    WebhookEvent::TeamEdited(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl TryFrom<WebhookEvent> for TeamEditedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::TeamEdited(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl From<WebhookTeamRemovedFromRepository> for WebhookEvent {
+  fn from(event: WebhookTeamRemovedFromRepository) -> Self {
+    WebhookEvent::TeamRemovedFromRepository(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_team",))]
+impl TryFrom<WebhookEvent> for WebhookTeamRemovedFromRepository {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::TeamRemovedFromRepository(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_watch",))]
+impl From<WatchStartedEvent> for WebhookEvent {
+  fn from(event: WatchStartedEvent) -> Self {
+    WebhookEvent::WatchStarted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_watch",))]
+impl TryFrom<WebhookEvent> for WatchStartedEvent {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WatchStarted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_dispatch",))]
+impl From<WebhookWorkflowDispatch> for WebhookEvent {
+  fn from(event: WebhookWorkflowDispatch) -> Self {
+    WebhookEvent::WorkflowDispatch(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_dispatch",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowDispatch {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowDispatch(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl From<WebhookWorkflowJobCompleted> for WebhookEvent {
+  fn from(event: WebhookWorkflowJobCompleted) -> Self {
+    WebhookEvent::WorkflowJobCompleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowJobCompleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowJobCompleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl From<WebhookWorkflowJobInProgress> for WebhookEvent {
+  fn from(event: WebhookWorkflowJobInProgress) -> Self {
+    WebhookEvent::WorkflowJobInProgress(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowJobInProgress {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowJobInProgress(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl From<WebhookWorkflowJobQueued> for WebhookEvent {
+  fn from(event: WebhookWorkflowJobQueued) -> Self {
+    WebhookEvent::WorkflowJobQueued(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowJobQueued {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowJobQueued(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl From<WebhookWorkflowJobWaiting> for WebhookEvent {
+  fn from(event: WebhookWorkflowJobWaiting) -> Self {
+    WebhookEvent::WorkflowJobWaiting(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowJobWaiting {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowJobWaiting(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+impl From<WebhookWorkflowRunCompleted> for WebhookEvent {
+  fn from(event: WebhookWorkflowRunCompleted) -> Self {
+    WebhookEvent::WorkflowRunCompleted(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowRunCompleted {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowRunCompleted(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+impl From<WebhookWorkflowRunInProgress> for WebhookEvent {
+  fn from(event: WebhookWorkflowRunInProgress) -> Self {
+    WebhookEvent::WorkflowRunInProgress(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowRunInProgress {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowRunInProgress(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+impl From<WebhookWorkflowRunRequested> for WebhookEvent {
+  fn from(event: WebhookWorkflowRunRequested) -> Self {
+    WebhookEvent::WorkflowRunRequested(event)
+  }
+}
+
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
+impl TryFrom<WebhookEvent> for WebhookWorkflowRunRequested {
+  type Error = WebhookEvent;
+
+  #[allow(unreachable_patterns)]
+  fn try_from(event: WebhookEvent) -> Result<Self, Self::Error> {
+    match event {
+      WebhookEvent::WorkflowRunRequested(event) => Ok(event),
+      event => Err(event),
+    }
+  }
+}
@@ -131,55 +131,79 @@ pub type WorkflowStepArray = Vec<WorkflowStep>;
 pub type WorkflowRunPullRequestsArray = Vec<WorkflowRunPullRequests>;
 
 /// Enumerations defination
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionConfigurationDisabledEventAction {
   #[serde(rename = "disabled")]
   Disabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 impl ToString for BranchProtectionConfigurationDisabledEventAction {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionConfigurationDisabledEventAction::Disabled => "disabled".to_string(),
+      BranchProtectionConfigurationDisabledEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionConfigurationEnabledEventAction {
   #[serde(rename = "enabled")]
   Enabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 impl ToString for BranchProtectionConfigurationEnabledEventAction {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionConfigurationEnabledEventAction::Enabled => "enabled".to_string(),
+      BranchProtectionConfigurationEnabledEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionRuleCreatedEventAction::Created => "created".to_string(),
+      BranchProtectionRuleCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleAllowDeletionsEnforcementLevel {
   #[serde(rename = "off")]
@@ -188,20 +212,28 @@ pub enum BranchProtectionRuleAllowDeletionsEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleAllowDeletionsEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionRuleAllowDeletionsEnforcementLevel::Off => "off".to_string(),
       BranchProtectionRuleAllowDeletionsEnforcementLevel::NonAdmins => "non_admins".to_string(),
       BranchProtectionRuleAllowDeletionsEnforcementLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRuleAllowDeletionsEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleAllowForcePushesEnforcementLevel {
   #[serde(rename = "off")]
@@ -210,20 +242,28 @@ pub enum BranchProtectionRuleAllowForcePushesEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleAllowForcePushesEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionRuleAllowForcePushesEnforcementLevel::Off => "off".to_string(),
       BranchProtectionRuleAllowForcePushesEnforcementLevel::NonAdmins => "non_admins".to_string(),
       BranchProtectionRuleAllowForcePushesEnforcementLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRuleAllowForcePushesEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleLinearHistoryRequirementEnforcementLevel {
   #[serde(rename = "off")]
@@ -232,9 +272,16 @@ pub enum BranchProtectionRuleLinearHistoryRequirementEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleLinearHistoryRequirementEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
@@ -245,11 +292,12 @@ impl ToString for BranchProtectionRuleLinearHistoryRequirementEnforcementLevel {
       BranchProtectionRuleLinearHistoryRequirementEnforcementLevel::Everyone => {
         "everyone".to_string()
       }
+      BranchProtectionRuleLinearHistoryRequirementEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleMergeQueueEnforcementLevel {
   #[serde(rename = "off")]
@@ -258,20 +306,28 @@ pub enum BranchProtectionRuleMergeQueueEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleMergeQueueEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionRuleMergeQueueEnforcementLevel::Off => "off".to_string(),
       BranchProtectionRuleMergeQueueEnforcementLevel::NonAdmins => "non_admins".to_string(),
       BranchProtectionRuleMergeQueueEnforcementLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRuleMergeQueueEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRulePullRequestReviewsEnforcementLevel {
   #[serde(rename = "off")]
@@ -280,20 +336,28 @@ pub enum BranchProtectionRulePullRequestReviewsEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRulePullRequestReviewsEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionRulePullRequestReviewsEnforcementLevel::Off => "off".to_string(),
       BranchProtectionRulePullRequestReviewsEnforcementLevel::NonAdmins => "non_admins".to_string(),
       BranchProtectionRulePullRequestReviewsEnforcementLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRulePullRequestReviewsEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleRequiredConversationResolutionLevel {
   #[serde(rename = "off")]
@@ -302,9 +366,16 @@ pub enum BranchProtectionRuleRequiredConversationResolutionLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleRequiredConversationResolutionLevel {
   fn to_string(&self) -> String {
     match self {
@@ -313,11 +384,12 @@ impl ToString for BranchProtectionRuleRequiredConversationResolutionLevel {
         "non_admins".to_string()
       }
       BranchProtectionRuleRequiredConversationResolutionLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRuleRequiredConversationResolutionLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleRequiredDeploymentsEnforcementLevel {
   #[serde(rename = "off")]
@@ -326,9 +398,16 @@ pub enum BranchProtectionRuleRequiredDeploymentsEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleRequiredDeploymentsEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
@@ -337,11 +416,12 @@ impl ToString for BranchProtectionRuleRequiredDeploymentsEnforcementLevel {
         "non_admins".to_string()
       }
       BranchProtectionRuleRequiredDeploymentsEnforcementLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRuleRequiredDeploymentsEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleRequiredStatusChecksEnforcementLevel {
   #[serde(rename = "off")]
@@ -350,9 +430,16 @@ pub enum BranchProtectionRuleRequiredStatusChecksEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleRequiredStatusChecksEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
@@ -361,11 +448,12 @@ impl ToString for BranchProtectionRuleRequiredStatusChecksEnforcementLevel {
         "non_admins".to_string()
       }
       BranchProtectionRuleRequiredStatusChecksEnforcementLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRuleRequiredStatusChecksEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleSignatureRequirementEnforcementLevel {
   #[serde(rename = "off")]
@@ -374,9 +462,16 @@ pub enum BranchProtectionRuleSignatureRequirementEnforcementLevel {
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleSignatureRequirementEnforcementLevel {
   fn to_string(&self) -> String {
     match self {
@@ -385,43 +480,60 @@ impl ToString for BranchProtectionRuleSignatureRequirementEnforcementLevel {
         "non_admins".to_string()
       }
       BranchProtectionRuleSignatureRequirementEnforcementLevel::Everyone => "everyone".to_string(),
+      BranchProtectionRuleSignatureRequirementEnforcementLevel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionRuleDeletedEventAction::Deleted => "deleted".to_string(),
+      BranchProtectionRuleDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       BranchProtectionRuleEditedEventAction::Edited => "edited".to_string(),
+      BranchProtectionRuleEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevelFrom {
   #[serde(rename = "off")]
@@ -430,9 +542,16 @@ pub enum BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforceme
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString
   for BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevelFrom
 {
@@ -441,11 +560,12 @@ impl ToString
       BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevelFrom::Off => "off".to_string(),
       BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevelFrom::NonAdmins => "non_admins".to_string(),
       BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevelFrom::Everyone => "everyone".to_string(),
+      BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevelFrom::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevelFrom {
   #[serde(rename = "off")]
@@ -454,9 +574,16 @@ pub enum BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLe
   NonAdmins,
   #[serde(rename = "everyone")]
   Everyone,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl ToString for BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevelFrom {
   fn to_string(&self) -> String {
     match self {
@@ -469,91 +596,138 @@ impl ToString for BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnfo
       BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevelFrom::Everyone => {
         "everyone".to_string()
       }
+      BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevelFrom::Unknown(
+        value,
+      ) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CheckRunCompletedEventAction {
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl ToString for CheckRunCompletedEventAction {
   fn to_string(&self) -> String {
     match self {
       CheckRunCompletedEventAction::Completed => "completed".to_string(),
+      CheckRunCompletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CheckRunCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl ToString for CheckRunCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       CheckRunCreatedEventAction::Created => "created".to_string(),
+      CheckRunCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CheckRunRequestedActionEventAction {
   #[serde(rename = "requested_action")]
   RequestedAction,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl ToString for CheckRunRequestedActionEventAction {
   fn to_string(&self) -> String {
     match self {
       CheckRunRequestedActionEventAction::RequestedAction => "requested_action".to_string(),
+      CheckRunRequestedActionEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CheckRunReRequestedEventAction {
   #[serde(rename = "rerequested")]
   Rerequested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl ToString for CheckRunReRequestedEventAction {
   fn to_string(&self) -> String {
     match self {
       CheckRunReRequestedEventAction::Rerequested => "rerequested".to_string(),
+      CheckRunReRequestedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteCompletedAction {
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteCompletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCheckSuiteCompletedAction::Completed => "completed".to_string(),
+      WebhookCheckSuiteCompletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AppEvents {
   #[serde(rename = "branch_protection_rule")]
@@ -660,9 +834,20 @@ pub enum AppEvents {
   ProjectsV2Item,
   #[serde(rename = "secret_scanning_alert_location")]
   SecretScanningAlertLocation,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+))]
 impl ToString for AppEvents {
   fn to_string(&self) -> String {
     match self {
@@ -718,30 +903,95 @@ impl ToString for AppEvents {
       AppEvents::SecurityAndAnalysis => "security_and_analysis".to_string(),
       AppEvents::ProjectsV2Item => "projects_v2_item".to_string(),
       AppEvents::SecretScanningAlertLocation => "secret_scanning_alert_location".to_string(),
-    }
-  }
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+      AppEvents::Unknown(value) => value.clone(),
+    }
+  }
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_sponsorship",
+  feature = "webhook_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum UserType {
   Bot,
   User,
   Organization,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_sponsorship",
+  feature = "webhook_status",
+))]
 impl ToString for UserType {
   fn to_string(&self) -> String {
     match self {
       UserType::Bot => "Bot".to_string(),
       UserType::User => "User".to_string(),
       UserType::Organization => "Organization".to_string(),
+      UserType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The summary conclusion for all check runs that are part of the check suite. This value will be `null` until the check run has `completed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteCompletedCheckSuiteConclusion {
@@ -763,9 +1013,16 @@ pub enum WebhookCheckSuiteCompletedCheckSuiteConclusion {
   Skipped,
   #[serde(rename = "startup_failure")]
   StartupFailure,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteCompletedCheckSuiteConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -782,11 +1039,12 @@ impl ToString for WebhookCheckSuiteCompletedCheckSuiteConclusion {
       WebhookCheckSuiteCompletedCheckSuiteConclusion::StartupFailure => {
         "startup_failure".to_string()
       }
+      WebhookCheckSuiteCompletedCheckSuiteConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The summary status for all check runs that are part of the check suite. Can be `requested`, `in_progress`, or `completed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteCompletedCheckSuiteStatus {
@@ -800,9 +1058,16 @@ pub enum WebhookCheckSuiteCompletedCheckSuiteStatus {
   Queued,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteCompletedCheckSuiteStatus {
   fn to_string(&self) -> String {
     match self {
@@ -811,27 +1076,36 @@ impl ToString for WebhookCheckSuiteCompletedCheckSuiteStatus {
       WebhookCheckSuiteCompletedCheckSuiteStatus::Completed => "completed".to_string(),
       WebhookCheckSuiteCompletedCheckSuiteStatus::Queued => "queued".to_string(),
       WebhookCheckSuiteCompletedCheckSuiteStatus::Pending => "pending".to_string(),
+      WebhookCheckSuiteCompletedCheckSuiteStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteRequestedAction {
   #[serde(rename = "requested")]
   Requested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteRequestedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCheckSuiteRequestedAction::Requested => "requested".to_string(),
+      WebhookCheckSuiteRequestedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The summary conclusion for all check runs that are part of the check suite. This value will be `null` until the check run has completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteRequestedCheckSuiteConclusion {
@@ -851,9 +1125,16 @@ pub enum WebhookCheckSuiteRequestedCheckSuiteConclusion {
   Stale,
   #[serde(rename = "skipped")]
   Skipped,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteRequestedCheckSuiteConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -867,11 +1148,12 @@ impl ToString for WebhookCheckSuiteRequestedCheckSuiteConclusion {
       }
       WebhookCheckSuiteRequestedCheckSuiteConclusion::Stale => "stale".to_string(),
       WebhookCheckSuiteRequestedCheckSuiteConclusion::Skipped => "skipped".to_string(),
+      WebhookCheckSuiteRequestedCheckSuiteConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The summary status for all check runs that are part of the check suite. Can be `requested`, `in_progress`, or `completed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteRequestedCheckSuiteStatus {
@@ -883,9 +1165,16 @@ pub enum WebhookCheckSuiteRequestedCheckSuiteStatus {
   Completed,
   #[serde(rename = "queued")]
   Queued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteRequestedCheckSuiteStatus {
   fn to_string(&self) -> String {
     match self {
@@ -893,27 +1182,36 @@ impl ToString for WebhookCheckSuiteRequestedCheckSuiteStatus {
       WebhookCheckSuiteRequestedCheckSuiteStatus::InProgress => "in_progress".to_string(),
       WebhookCheckSuiteRequestedCheckSuiteStatus::Completed => "completed".to_string(),
       WebhookCheckSuiteRequestedCheckSuiteStatus::Queued => "queued".to_string(),
+      WebhookCheckSuiteRequestedCheckSuiteStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteRerequestedAction {
   #[serde(rename = "rerequested")]
   Rerequested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteRerequestedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCheckSuiteRerequestedAction::Rerequested => "rerequested".to_string(),
+      WebhookCheckSuiteRerequestedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The summary conclusion for all check runs that are part of the check suite. This value will be `null` until the check run has completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteRerequestedCheckSuiteConclusion {
@@ -931,9 +1229,16 @@ pub enum WebhookCheckSuiteRerequestedCheckSuiteConclusion {
   ActionRequired,
   #[serde(rename = "stale")]
   Stale,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteRerequestedCheckSuiteConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -946,11 +1251,12 @@ impl ToString for WebhookCheckSuiteRerequestedCheckSuiteConclusion {
         "action_required".to_string()
       }
       WebhookCheckSuiteRerequestedCheckSuiteConclusion::Stale => "stale".to_string(),
+      WebhookCheckSuiteRerequestedCheckSuiteConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The summary status for all check runs that are part of the check suite. Can be `requested`, `in_progress`, or `completed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCheckSuiteRerequestedCheckSuiteStatus {
@@ -962,9 +1268,16 @@ pub enum WebhookCheckSuiteRerequestedCheckSuiteStatus {
   Completed,
   #[serde(rename = "queued")]
   Queued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl ToString for WebhookCheckSuiteRerequestedCheckSuiteStatus {
   fn to_string(&self) -> String {
     match self {
@@ -972,29 +1285,38 @@ impl ToString for WebhookCheckSuiteRerequestedCheckSuiteStatus {
       WebhookCheckSuiteRerequestedCheckSuiteStatus::InProgress => "in_progress".to_string(),
       WebhookCheckSuiteRerequestedCheckSuiteStatus::Completed => "completed".to_string(),
       WebhookCheckSuiteRerequestedCheckSuiteStatus::Queued => "queued".to_string(),
+      WebhookCheckSuiteRerequestedCheckSuiteStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertAppearedInBranchAction {
   #[serde(rename = "appeared_in_branch")]
   AppearedInBranch,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertAppearedInBranchAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertAppearedInBranchAction::AppearedInBranch => {
         "appeared_in_branch".to_string()
       }
+      WebhookCodeScanningAlertAppearedInBranchAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The reason for dismissing or closing the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertAppearedInBranchAlertDismissedReason {
@@ -1004,9 +1326,16 @@ pub enum WebhookCodeScanningAlertAppearedInBranchAlertDismissedReason {
   WonTFix,
   #[serde(rename = "used in tests")]
   UsedInTests,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertAppearedInBranchAlertDismissedReason {
   fn to_string(&self) -> String {
     match self {
@@ -1019,11 +1348,12 @@ impl ToString for WebhookCodeScanningAlertAppearedInBranchAlertDismissedReason {
       WebhookCodeScanningAlertAppearedInBranchAlertDismissedReason::UsedInTests => {
         "used in tests".to_string()
       }
+      WebhookCodeScanningAlertAppearedInBranchAlertDismissedReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// State of a code scanning alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AlertInstanceState {
@@ -1033,20 +1363,28 @@ pub enum AlertInstanceState {
   Dismissed,
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for AlertInstanceState {
   fn to_string(&self) -> String {
     match self {
       AlertInstanceState::Open => "open".to_string(),
       AlertInstanceState::Dismissed => "dismissed".to_string(),
       AlertInstanceState::Fixed => "fixed".to_string(),
+      AlertInstanceState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The severity of the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity {
@@ -1058,9 +1396,16 @@ pub enum WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1068,11 +1413,12 @@ impl ToString for WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity {
       WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity::Note => "note".to_string(),
       WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity::Warning => "warning".to_string(),
       WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity::Error => "error".to_string(),
+      WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// State of a code scanning alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertAppearedInBranchAlertState {
@@ -1082,36 +1428,52 @@ pub enum WebhookCodeScanningAlertAppearedInBranchAlertState {
   Dismissed,
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertAppearedInBranchAlertState {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertAppearedInBranchAlertState::Open => "open".to_string(),
       WebhookCodeScanningAlertAppearedInBranchAlertState::Dismissed => "dismissed".to_string(),
       WebhookCodeScanningAlertAppearedInBranchAlertState::Fixed => "fixed".to_string(),
+      WebhookCodeScanningAlertAppearedInBranchAlertState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertClosedByUserAction {
   #[serde(rename = "closed_by_user")]
   ClosedByUser,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertClosedByUserAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertClosedByUserAction::ClosedByUser => "closed_by_user".to_string(),
+      WebhookCodeScanningAlertClosedByUserAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The reason for dismissing or closing the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertClosedByUserAlertDismissedReason {
@@ -1121,9 +1483,16 @@ pub enum WebhookCodeScanningAlertClosedByUserAlertDismissedReason {
   WonTFix,
   #[serde(rename = "used in tests")]
   UsedInTests,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertClosedByUserAlertDismissedReason {
   fn to_string(&self) -> String {
     match self {
@@ -1134,11 +1503,12 @@ impl ToString for WebhookCodeScanningAlertClosedByUserAlertDismissedReason {
       WebhookCodeScanningAlertClosedByUserAlertDismissedReason::UsedInTests => {
         "used in tests".to_string()
       }
+      WebhookCodeScanningAlertClosedByUserAlertDismissedReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The severity of the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertClosedByUserAlertRuleSeverity {
@@ -1150,9 +1520,16 @@ pub enum WebhookCodeScanningAlertClosedByUserAlertRuleSeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertClosedByUserAlertRuleSeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1160,11 +1537,12 @@ impl ToString for WebhookCodeScanningAlertClosedByUserAlertRuleSeverity {
       WebhookCodeScanningAlertClosedByUserAlertRuleSeverity::Note => "note".to_string(),
       WebhookCodeScanningAlertClosedByUserAlertRuleSeverity::Warning => "warning".to_string(),
       WebhookCodeScanningAlertClosedByUserAlertRuleSeverity::Error => "error".to_string(),
+      WebhookCodeScanningAlertClosedByUserAlertRuleSeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// State of a code scanning alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertClosedByUserAlertState {
@@ -1172,35 +1550,51 @@ pub enum WebhookCodeScanningAlertClosedByUserAlertState {
   Dismissed,
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertClosedByUserAlertState {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertClosedByUserAlertState::Dismissed => "dismissed".to_string(),
       WebhookCodeScanningAlertClosedByUserAlertState::Fixed => "fixed".to_string(),
+      WebhookCodeScanningAlertClosedByUserAlertState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertCreatedAction::Created => "created".to_string(),
+      WebhookCodeScanningAlertCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The severity of the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertCreatedAlertRuleSeverity {
@@ -1212,9 +1606,16 @@ pub enum WebhookCodeScanningAlertCreatedAlertRuleSeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertCreatedAlertRuleSeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1222,11 +1623,12 @@ impl ToString for WebhookCodeScanningAlertCreatedAlertRuleSeverity {
       WebhookCodeScanningAlertCreatedAlertRuleSeverity::Note => "note".to_string(),
       WebhookCodeScanningAlertCreatedAlertRuleSeverity::Warning => "warning".to_string(),
       WebhookCodeScanningAlertCreatedAlertRuleSeverity::Error => "error".to_string(),
+      WebhookCodeScanningAlertCreatedAlertRuleSeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// State of a code scanning alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertCreatedAlertState {
@@ -1234,35 +1636,51 @@ pub enum WebhookCodeScanningAlertCreatedAlertState {
   Open,
   #[serde(rename = "dismissed")]
   Dismissed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertCreatedAlertState {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertCreatedAlertState::Open => "open".to_string(),
       WebhookCodeScanningAlertCreatedAlertState::Dismissed => "dismissed".to_string(),
+      WebhookCodeScanningAlertCreatedAlertState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertFixedAction {
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertFixedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertFixedAction::Fixed => "fixed".to_string(),
+      WebhookCodeScanningAlertFixedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The reason for dismissing or closing the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertFixedAlertDismissedReason {
@@ -1272,9 +1690,16 @@ pub enum WebhookCodeScanningAlertFixedAlertDismissedReason {
   WonTFix,
   #[serde(rename = "used in tests")]
   UsedInTests,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertFixedAlertDismissedReason {
   fn to_string(&self) -> String {
     match self {
@@ -1283,11 +1708,12 @@ impl ToString for WebhookCodeScanningAlertFixedAlertDismissedReason {
       }
       WebhookCodeScanningAlertFixedAlertDismissedReason::WonTFix => "won't fix".to_string(),
       WebhookCodeScanningAlertFixedAlertDismissedReason::UsedInTests => "used in tests".to_string(),
+      WebhookCodeScanningAlertFixedAlertDismissedReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The severity of the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertFixedAlertRuleSeverity {
@@ -1299,9 +1725,16 @@ pub enum WebhookCodeScanningAlertFixedAlertRuleSeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertFixedAlertRuleSeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1309,44 +1742,61 @@ impl ToString for WebhookCodeScanningAlertFixedAlertRuleSeverity {
       WebhookCodeScanningAlertFixedAlertRuleSeverity::Note => "note".to_string(),
       WebhookCodeScanningAlertFixedAlertRuleSeverity::Warning => "warning".to_string(),
       WebhookCodeScanningAlertFixedAlertRuleSeverity::Error => "error".to_string(),
+      WebhookCodeScanningAlertFixedAlertRuleSeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// State of a code scanning alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertFixedAlertState {
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertFixedAlertState {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertFixedAlertState::Fixed => "fixed".to_string(),
+      WebhookCodeScanningAlertFixedAlertState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertReopenedAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertReopenedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertReopenedAction::Reopened => "reopened".to_string(),
+      WebhookCodeScanningAlertReopenedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The severity of the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertReopenedAlertRuleSeverity {
@@ -1358,9 +1808,16 @@ pub enum WebhookCodeScanningAlertReopenedAlertRuleSeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertReopenedAlertRuleSeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1368,11 +1825,12 @@ impl ToString for WebhookCodeScanningAlertReopenedAlertRuleSeverity {
       WebhookCodeScanningAlertReopenedAlertRuleSeverity::Note => "note".to_string(),
       WebhookCodeScanningAlertReopenedAlertRuleSeverity::Warning => "warning".to_string(),
       WebhookCodeScanningAlertReopenedAlertRuleSeverity::Error => "error".to_string(),
+      WebhookCodeScanningAlertReopenedAlertRuleSeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// State of a code scanning alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertReopenedAlertState {
@@ -1382,38 +1840,54 @@ pub enum WebhookCodeScanningAlertReopenedAlertState {
   Dismissed,
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertReopenedAlertState {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertReopenedAlertState::Open => "open".to_string(),
       WebhookCodeScanningAlertReopenedAlertState::Dismissed => "dismissed".to_string(),
       WebhookCodeScanningAlertReopenedAlertState::Fixed => "fixed".to_string(),
+      WebhookCodeScanningAlertReopenedAlertState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertReopenedByUserAction {
   #[serde(rename = "reopened_by_user")]
   ReopenedByUser,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertReopenedByUserAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertReopenedByUserAction::ReopenedByUser => {
         "reopened_by_user".to_string()
       }
+      WebhookCodeScanningAlertReopenedByUserAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The severity of the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity {
@@ -1425,9 +1899,16 @@ pub enum WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity {
   Warning,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity {
   fn to_string(&self) -> String {
     match self {
@@ -1435,11 +1916,12 @@ impl ToString for WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity {
       WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity::Note => "note".to_string(),
       WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity::Warning => "warning".to_string(),
       WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity::Error => "error".to_string(),
+      WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// State of a code scanning alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCodeScanningAlertReopenedByUserAlertState {
@@ -1447,36 +1929,52 @@ pub enum WebhookCodeScanningAlertReopenedByUserAlertState {
   Open,
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl ToString for WebhookCodeScanningAlertReopenedByUserAlertState {
   fn to_string(&self) -> String {
     match self {
       WebhookCodeScanningAlertReopenedByUserAlertState::Open => "open".to_string(),
       WebhookCodeScanningAlertReopenedByUserAlertState::Fixed => "fixed".to_string(),
+      WebhookCodeScanningAlertReopenedByUserAlertState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 /// The action performed. Can be `created`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCommitCommentCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 impl ToString for WebhookCommitCommentCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookCommitCommentCreatedAction::Created => "created".to_string(),
+      WebhookCommitCommentCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookCommitCommentCreatedCommentAuthorAssociation {
@@ -1496,9 +1994,16 @@ pub enum WebhookCommitCommentCreatedCommentAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 impl ToString for WebhookCommitCommentCreatedCommentAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -1514,11 +2019,12 @@ impl ToString for WebhookCommitCommentCreatedCommentAuthorAssociation {
       WebhookCommitCommentCreatedCommentAuthorAssociation::Member => "MEMBER".to_string(),
       WebhookCommitCommentCreatedCommentAuthorAssociation::None => "NONE".to_string(),
       WebhookCommitCommentCreatedCommentAuthorAssociation::Owner => "OWNER".to_string(),
+      WebhookCommitCommentCreatedCommentAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_create",))]
 /// The type of Git ref object created in the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CreateEventRefType {
@@ -1526,83 +2032,123 @@ pub enum CreateEventRefType {
   Tag,
   #[serde(rename = "branch")]
   Branch,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_create",))]
 impl ToString for CreateEventRefType {
   fn to_string(&self) -> String {
     match self {
       CreateEventRefType::Tag => "tag".to_string(),
       CreateEventRefType::Branch => "branch".to_string(),
+      CreateEventRefType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CustomPropertyCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 impl ToString for CustomPropertyCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       CustomPropertyCreatedEventAction::Created => "created".to_string(),
+      CustomPropertyCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CustomPropertyDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 impl ToString for CustomPropertyDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       CustomPropertyDeletedEventAction::Deleted => "deleted".to_string(),
+      CustomPropertyDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CustomPropertyUpdatedEventAction {
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 impl ToString for CustomPropertyUpdatedEventAction {
   fn to_string(&self) -> String {
     match self {
       CustomPropertyUpdatedEventAction::Updated => "updated".to_string(),
+      CustomPropertyUpdatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum CustomPropertyValuesUpdatedEventAction {
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
 impl ToString for CustomPropertyValuesUpdatedEventAction {
   fn to_string(&self) -> String {
     match self {
       CustomPropertyValuesUpdatedEventAction::Updated => "updated".to_string(),
+      CustomPropertyValuesUpdatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_delete",))]
 /// The type of Git ref object deleted in the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DeleteEventRefType {
@@ -1610,179 +2156,272 @@ pub enum DeleteEventRefType {
   Tag,
   #[serde(rename = "branch")]
   Branch,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_delete",))]
 impl ToString for DeleteEventRefType {
   fn to_string(&self) -> String {
     match self {
       DeleteEventRefType::Tag => "tag".to_string(),
       DeleteEventRefType::Branch => "branch".to_string(),
+      DeleteEventRefType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertAutoDismissedEventAction {
   #[serde(rename = "auto_dismissed")]
   AutoDismissed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl ToString for DependabotAlertAutoDismissedEventAction {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertAutoDismissedEventAction::AutoDismissed => "auto_dismissed".to_string(),
+      DependabotAlertAutoDismissedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertAutoReopenedEventAction {
   #[serde(rename = "auto_reopened")]
   AutoReopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl ToString for DependabotAlertAutoReopenedEventAction {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertAutoReopenedEventAction::AutoReopened => "auto_reopened".to_string(),
+      DependabotAlertAutoReopenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl ToString for DependabotAlertCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertCreatedEventAction::Created => "created".to_string(),
+      DependabotAlertCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertDismissedEventAction {
   #[serde(rename = "dismissed")]
   Dismissed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl ToString for DependabotAlertDismissedEventAction {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertDismissedEventAction::Dismissed => "dismissed".to_string(),
+      DependabotAlertDismissedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertFixedEventAction {
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl ToString for DependabotAlertFixedEventAction {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertFixedEventAction::Fixed => "fixed".to_string(),
+      DependabotAlertFixedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertReintroducedEventAction {
   #[serde(rename = "reintroduced")]
   Reintroduced,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl ToString for DependabotAlertReintroducedEventAction {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertReintroducedEventAction::Reintroduced => "reintroduced".to_string(),
+      DependabotAlertReintroducedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DependabotAlertReopenedEventAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl ToString for DependabotAlertReopenedEventAction {
   fn to_string(&self) -> String {
     match self {
       DependabotAlertReopenedEventAction::Reopened => "reopened".to_string(),
+      DependabotAlertReopenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeployKeyCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 impl ToString for WebhookDeployKeyCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDeployKeyCreatedAction::Created => "created".to_string(),
+      WebhookDeployKeyCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeployKeyDeletedAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 impl ToString for WebhookDeployKeyDeletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDeployKeyDeletedAction::Deleted => "deleted".to_string(),
+      WebhookDeployKeyDeletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DeploymentCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment",))]
 impl ToString for DeploymentCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       DeploymentCreatedEventAction::Created => "created".to_string(),
+      DeploymentCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DeploymentWorkflowRunConclusion {
   #[serde(rename = "success")]
@@ -1799,9 +2438,21 @@ pub enum DeploymentWorkflowRunConclusion {
   ActionRequired,
   #[serde(rename = "stale")]
   Stale,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl ToString for DeploymentWorkflowRunConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -1812,11 +2463,17 @@ impl ToString for DeploymentWorkflowRunConclusion {
       DeploymentWorkflowRunConclusion::TimedOut => "timed_out".to_string(),
       DeploymentWorkflowRunConclusion::ActionRequired => "action_required".to_string(),
       DeploymentWorkflowRunConclusion::Stale => "stale".to_string(),
+      DeploymentWorkflowRunConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DeploymentWorkflowRunStatus {
   #[serde(rename = "requested")]
@@ -1831,9 +2488,21 @@ pub enum DeploymentWorkflowRunStatus {
   Waiting,
   #[serde(rename = "pending")]
   Pending,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl ToString for DeploymentWorkflowRunStatus {
   fn to_string(&self) -> String {
     match self {
@@ -1843,138 +2512,203 @@ impl ToString for DeploymentWorkflowRunStatus {
       DeploymentWorkflowRunStatus::Queued => "queued".to_string(),
       DeploymentWorkflowRunStatus::Waiting => "waiting".to_string(),
       DeploymentWorkflowRunStatus::Pending => "pending".to_string(),
+      DeploymentWorkflowRunStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DeploymentProtectionRuleRequestedEventAction {
   #[serde(rename = "requested")]
   Requested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
 impl ToString for DeploymentProtectionRuleRequestedEventAction {
   fn to_string(&self) -> String {
     match self {
       DeploymentProtectionRuleRequestedEventAction::Requested => "requested".to_string(),
+      DeploymentProtectionRuleRequestedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentReviewApprovedAction {
   #[serde(rename = "approved")]
   Approved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl ToString for WebhookDeploymentReviewApprovedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDeploymentReviewApprovedAction::Approved => "approved".to_string(),
+      WebhookDeploymentReviewApprovedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentReviewApprovedReviewersType {
   User,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl ToString for WebhookDeploymentReviewApprovedReviewersType {
   fn to_string(&self) -> String {
     match self {
       WebhookDeploymentReviewApprovedReviewersType::User => "User".to_string(),
+      WebhookDeploymentReviewApprovedReviewersType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentReviewRejectedAction {
   #[serde(rename = "rejected")]
   Rejected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl ToString for WebhookDeploymentReviewRejectedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDeploymentReviewRejectedAction::Rejected => "rejected".to_string(),
+      WebhookDeploymentReviewRejectedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentReviewRejectedReviewersType {
   User,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl ToString for WebhookDeploymentReviewRejectedReviewersType {
   fn to_string(&self) -> String {
     match self {
       WebhookDeploymentReviewRejectedReviewersType::User => "User".to_string(),
+      WebhookDeploymentReviewRejectedReviewersType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentReviewRequestedAction {
   #[serde(rename = "requested")]
   Requested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl ToString for WebhookDeploymentReviewRequestedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDeploymentReviewRequestedAction::Requested => "requested".to_string(),
+      WebhookDeploymentReviewRequestedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentReviewRequestedReviewersType {
   User,
   Team,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl ToString for WebhookDeploymentReviewRequestedReviewersType {
   fn to_string(&self) -> String {
     match self {
       WebhookDeploymentReviewRequestedReviewersType::User => "User".to_string(),
       WebhookDeploymentReviewRequestedReviewersType::Team => "Team".to_string(),
+      WebhookDeploymentReviewRequestedReviewersType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentStatusCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 impl ToString for WebhookDeploymentStatusCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDeploymentStatusCreatedAction::Created => "created".to_string(),
+      WebhookDeploymentStatusCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 /// The result of the completed check run. This value will be `null` until the check run has completed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentStatusCreatedCheckRunConclusion {
@@ -1994,9 +2728,16 @@ pub enum WebhookDeploymentStatusCreatedCheckRunConclusion {
   Stale,
   #[serde(rename = "skipped")]
   Skipped,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 impl ToString for WebhookDeploymentStatusCreatedCheckRunConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -2010,11 +2751,12 @@ impl ToString for WebhookDeploymentStatusCreatedCheckRunConclusion {
       }
       WebhookDeploymentStatusCreatedCheckRunConclusion::Stale => "stale".to_string(),
       WebhookDeploymentStatusCreatedCheckRunConclusion::Skipped => "skipped".to_string(),
+      WebhookDeploymentStatusCreatedCheckRunConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 /// The current status of the check run. Can be `queued`, `in_progress`, or `completed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDeploymentStatusCreatedCheckRunStatus {
@@ -2028,9 +2770,16 @@ pub enum WebhookDeploymentStatusCreatedCheckRunStatus {
   Waiting,
   #[serde(rename = "pending")]
   Pending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 impl ToString for WebhookDeploymentStatusCreatedCheckRunStatus {
   fn to_string(&self) -> String {
     match self {
@@ -2039,27 +2788,36 @@ impl ToString for WebhookDeploymentStatusCreatedCheckRunStatus {
       WebhookDeploymentStatusCreatedCheckRunStatus::Completed => "completed".to_string(),
       WebhookDeploymentStatusCreatedCheckRunStatus::Waiting => "waiting".to_string(),
       WebhookDeploymentStatusCreatedCheckRunStatus::Pending => "pending".to_string(),
+      WebhookDeploymentStatusCreatedCheckRunStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionAnsweredEventAction {
   #[serde(rename = "answered")]
   Answered,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionAnsweredEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionAnsweredEventAction::Answered => "answered".to_string(),
+      DiscussionAnsweredEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionAnsweredEventAnswerAuthorAssociation {
@@ -2079,9 +2837,16 @@ pub enum DiscussionAnsweredEventAnswerAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionAnsweredEventAnswerAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -2095,11 +2860,16 @@ impl ToString for DiscussionAnsweredEventAnswerAuthorAssociation {
       DiscussionAnsweredEventAnswerAuthorAssociation::Member => "MEMBER".to_string(),
       DiscussionAnsweredEventAnswerAuthorAssociation::None => "NONE".to_string(),
       DiscussionAnsweredEventAnswerAuthorAssociation::Owner => "OWNER".to_string(),
+      DiscussionAnsweredEventAnswerAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionAuthorAssociation {
@@ -2119,9 +2889,20 @@ pub enum DiscussionAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 impl ToString for DiscussionAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -2133,11 +2914,16 @@ impl ToString for DiscussionAuthorAssociation {
       DiscussionAuthorAssociation::Member => "MEMBER".to_string(),
       DiscussionAuthorAssociation::None => "NONE".to_string(),
       DiscussionAuthorAssociation::Owner => "OWNER".to_string(),
+      DiscussionAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 /// The current state of the discussion.
 /// `converting` means that the discussion is being converted from an issue.
 /// `transferring` means that the discussion is being transferred from another repository.
@@ -2153,9 +2939,20 @@ pub enum DiscussionState {
   Converting,
   #[serde(rename = "transferring")]
   Transferring,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 impl ToString for DiscussionState {
   fn to_string(&self) -> String {
     match self {
@@ -2164,11 +2961,16 @@ impl ToString for DiscussionState {
       DiscussionState::Locked => "locked".to_string(),
       DiscussionState::Converting => "converting".to_string(),
       DiscussionState::Transferring => "transferring".to_string(),
+      DiscussionState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 /// The reason for the current state
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionStateReason {
@@ -2180,9 +2982,20 @@ pub enum DiscussionStateReason {
   Duplicate,
   #[serde(rename = "reopened")]
   Reopened,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 impl ToString for DiscussionStateReason {
   fn to_string(&self) -> String {
     match self {
@@ -2190,59 +3003,84 @@ impl ToString for DiscussionStateReason {
       DiscussionStateReason::Outdated => "outdated".to_string(),
       DiscussionStateReason::Duplicate => "duplicate".to_string(),
       DiscussionStateReason::Reopened => "reopened".to_string(),
+      DiscussionStateReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionCategoryChangedEventAction {
   #[serde(rename = "category_changed")]
   CategoryChanged,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionCategoryChangedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionCategoryChangedEventAction::CategoryChanged => "category_changed".to_string(),
+      DiscussionCategoryChangedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionClosedEventAction {
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionClosedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionClosedEventAction::Closed => "closed".to_string(),
+      DiscussionClosedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDiscussionCommentCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl ToString for WebhookDiscussionCommentCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDiscussionCommentCreatedAction::Created => "created".to_string(),
+      WebhookDiscussionCommentCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDiscussionCommentCreatedCommentAuthorAssociation {
@@ -2262,9 +3100,16 @@ pub enum WebhookDiscussionCommentCreatedCommentAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl ToString for WebhookDiscussionCommentCreatedCommentAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -2284,27 +3129,36 @@ impl ToString for WebhookDiscussionCommentCreatedCommentAuthorAssociation {
       WebhookDiscussionCommentCreatedCommentAuthorAssociation::Member => "MEMBER".to_string(),
       WebhookDiscussionCommentCreatedCommentAuthorAssociation::None => "NONE".to_string(),
       WebhookDiscussionCommentCreatedCommentAuthorAssociation::Owner => "OWNER".to_string(),
+      WebhookDiscussionCommentCreatedCommentAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDiscussionCommentDeletedAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl ToString for WebhookDiscussionCommentDeletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDiscussionCommentDeletedAction::Deleted => "deleted".to_string(),
+      WebhookDiscussionCommentDeletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDiscussionCommentDeletedCommentAuthorAssociation {
@@ -2324,9 +3178,16 @@ pub enum WebhookDiscussionCommentDeletedCommentAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl ToString for WebhookDiscussionCommentDeletedCommentAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -2346,27 +3207,36 @@ impl ToString for WebhookDiscussionCommentDeletedCommentAuthorAssociation {
       WebhookDiscussionCommentDeletedCommentAuthorAssociation::Member => "MEMBER".to_string(),
       WebhookDiscussionCommentDeletedCommentAuthorAssociation::None => "NONE".to_string(),
       WebhookDiscussionCommentDeletedCommentAuthorAssociation::Owner => "OWNER".to_string(),
+      WebhookDiscussionCommentDeletedCommentAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDiscussionCommentEditedAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl ToString for WebhookDiscussionCommentEditedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookDiscussionCommentEditedAction::Edited => "edited".to_string(),
+      WebhookDiscussionCommentEditedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookDiscussionCommentEditedCommentAuthorAssociation {
@@ -2386,9 +3256,16 @@ pub enum WebhookDiscussionCommentEditedCommentAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl ToString for WebhookDiscussionCommentEditedCommentAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -2408,33 +3285,42 @@ impl ToString for WebhookDiscussionCommentEditedCommentAuthorAssociation {
       WebhookDiscussionCommentEditedCommentAuthorAssociation::Member => "MEMBER".to_string(),
       WebhookDiscussionCommentEditedCommentAuthorAssociation::None => "NONE".to_string(),
       WebhookDiscussionCommentEditedCommentAuthorAssociation::Owner => "OWNER".to_string(),
+      WebhookDiscussionCommentEditedCommentAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionCreatedEventAction::Created => "created".to_string(),
+      DiscussionCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DiscussionCreatedEventDiscussionItem2Locked {
   Boolean(bool),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionCreatedEventDiscussionItem2State {
   #[serde(rename = "open")]
@@ -2443,20 +3329,28 @@ pub enum DiscussionCreatedEventDiscussionItem2State {
   Converting,
   #[serde(rename = "transferring")]
   Transferring,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionCreatedEventDiscussionItem2State {
   fn to_string(&self) -> String {
     match self {
       DiscussionCreatedEventDiscussionItem2State::Open => "open".to_string(),
       DiscussionCreatedEventDiscussionItem2State::Converting => "converting".to_string(),
       DiscussionCreatedEventDiscussionItem2State::Transferring => "transferring".to_string(),
+      DiscussionCreatedEventDiscussionItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum DiscussionCreatedEventDiscussion {
@@ -2465,135 +3359,199 @@ pub enum DiscussionCreatedEventDiscussion {
   DiscussionCreatedEventDiscussionItem2(DiscussionCreatedEventDiscussionItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionDeletedEventAction::Deleted => "deleted".to_string(),
+      DiscussionDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionEditedEventAction::Edited => "edited".to_string(),
+      DiscussionEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionLabeledEventAction {
   #[serde(rename = "labeled")]
   Labeled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionLabeledEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionLabeledEventAction::Labeled => "labeled".to_string(),
+      DiscussionLabeledEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionLockedEventAction {
   #[serde(rename = "locked")]
   Locked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionLockedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionLockedEventAction::Locked => "locked".to_string(),
+      DiscussionLockedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionPinnedEventAction {
   #[serde(rename = "pinned")]
   Pinned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionPinnedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionPinnedEventAction::Pinned => "pinned".to_string(),
+      DiscussionPinnedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionReopenedEventAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionReopenedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionReopenedEventAction::Reopened => "reopened".to_string(),
+      DiscussionReopenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionTransferredEventAction {
   #[serde(rename = "transferred")]
   Transferred,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionTransferredEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionTransferredEventAction::Transferred => "transferred".to_string(),
+      DiscussionTransferredEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionUnansweredEventAction {
   #[serde(rename = "unanswered")]
   Unanswered,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionUnansweredEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionUnansweredEventAction::Unanswered => "unanswered".to_string(),
+      DiscussionUnansweredEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionUnansweredEventOldAnswerAuthorAssociation {
@@ -2613,9 +3571,16 @@ pub enum DiscussionUnansweredEventOldAnswerAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionUnansweredEventOldAnswerAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -2631,65 +3596,90 @@ impl ToString for DiscussionUnansweredEventOldAnswerAuthorAssociation {
       DiscussionUnansweredEventOldAnswerAuthorAssociation::Member => "MEMBER".to_string(),
       DiscussionUnansweredEventOldAnswerAuthorAssociation::None => "NONE".to_string(),
       DiscussionUnansweredEventOldAnswerAuthorAssociation::Owner => "OWNER".to_string(),
+      DiscussionUnansweredEventOldAnswerAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionUnlabeledEventAction {
   #[serde(rename = "unlabeled")]
   Unlabeled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionUnlabeledEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionUnlabeledEventAction::Unlabeled => "unlabeled".to_string(),
+      DiscussionUnlabeledEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionUnlockedEventAction {
   #[serde(rename = "unlocked")]
   Unlocked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionUnlockedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionUnlockedEventAction::Unlocked => "unlocked".to_string(),
+      DiscussionUnlockedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum DiscussionUnpinnedEventAction {
   #[serde(rename = "unpinned")]
   Unpinned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl ToString for DiscussionUnpinnedEventAction {
   fn to_string(&self) -> String {
     match self {
       DiscussionUnpinnedEventAction::Unpinned => "unpinned".to_string(),
+      DiscussionUnpinnedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ForkEventForkeeItem2Fork {
   Boolean(bool),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ForkEventForkee {
@@ -2698,23 +3688,31 @@ pub enum ForkEventForkee {
   ForkEventForkeeItem2(ForkEventForkeeItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookGithubAppAuthorizationRevokedAction {
   #[serde(rename = "revoked")]
   Revoked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
 impl ToString for WebhookGithubAppAuthorizationRevokedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookGithubAppAuthorizationRevokedAction::Revoked => "revoked".to_string(),
+      WebhookGithubAppAuthorizationRevokedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
 /// The action that was performed on the page. Can be `created` or `edited`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum GollumEventPagesAction {
@@ -2722,85 +3720,125 @@ pub enum GollumEventPagesAction {
   Created,
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
 impl ToString for GollumEventPagesAction {
   fn to_string(&self) -> String {
     match self {
       GollumEventPagesAction::Created => "created".to_string(),
       GollumEventPagesAction::Edited => "edited".to_string(),
+      GollumEventPagesAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum InstallationCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl ToString for InstallationCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       InstallationCreatedEventAction::Created => "created".to_string(),
+      InstallationCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum InstallationDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl ToString for InstallationDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       InstallationDeletedEventAction::Deleted => "deleted".to_string(),
+      InstallationDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookInstallationNewPermissionsAcceptedAction {
   #[serde(rename = "new_permissions_accepted")]
   NewPermissionsAccepted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl ToString for WebhookInstallationNewPermissionsAcceptedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookInstallationNewPermissionsAcceptedAction::NewPermissionsAccepted => {
         "new_permissions_accepted".to_string()
       }
+      WebhookInstallationNewPermissionsAcceptedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookInstallationRepositoriesAddedAction {
   #[serde(rename = "added")]
   Added,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl ToString for WebhookInstallationRepositoriesAddedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookInstallationRepositoriesAddedAction::Added => "added".to_string(),
+      WebhookInstallationRepositoriesAddedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 /// Describe whether all repositories have been selected or there's a selection involved
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookInstallationRepositoriesAddedRepositorySelection {
@@ -2808,35 +3846,51 @@ pub enum WebhookInstallationRepositoriesAddedRepositorySelection {
   All,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl ToString for WebhookInstallationRepositoriesAddedRepositorySelection {
   fn to_string(&self) -> String {
     match self {
       WebhookInstallationRepositoriesAddedRepositorySelection::All => "all".to_string(),
       WebhookInstallationRepositoriesAddedRepositorySelection::Selected => "selected".to_string(),
+      WebhookInstallationRepositoriesAddedRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookInstallationRepositoriesRemovedAction {
   #[serde(rename = "removed")]
   Removed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl ToString for WebhookInstallationRepositoriesRemovedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookInstallationRepositoriesRemovedAction::Removed => "removed".to_string(),
+      WebhookInstallationRepositoriesRemovedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 /// Describe whether all repositories have been selected or there's a selection involved
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookInstallationRepositoriesRemovedRepositorySelection {
@@ -2844,78 +3898,118 @@ pub enum WebhookInstallationRepositoriesRemovedRepositorySelection {
   All,
   #[serde(rename = "selected")]
   Selected,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl ToString for WebhookInstallationRepositoriesRemovedRepositorySelection {
   fn to_string(&self) -> String {
     match self {
       WebhookInstallationRepositoriesRemovedRepositorySelection::All => "all".to_string(),
       WebhookInstallationRepositoriesRemovedRepositorySelection::Selected => "selected".to_string(),
+      WebhookInstallationRepositoriesRemovedRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum InstallationSuspendEventAction {
   #[serde(rename = "suspend")]
   Suspend,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl ToString for InstallationSuspendEventAction {
   fn to_string(&self) -> String {
     match self {
       InstallationSuspendEventAction::Suspend => "suspend".to_string(),
+      InstallationSuspendEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookInstallationTargetRenamedAction {
   #[serde(rename = "renamed")]
   Renamed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 impl ToString for WebhookInstallationTargetRenamedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookInstallationTargetRenamedAction::Renamed => "renamed".to_string(),
+      WebhookInstallationTargetRenamedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum InstallationUnsuspendEventAction {
   #[serde(rename = "unsuspend")]
   Unsuspend,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl ToString for InstallationUnsuspendEventAction {
   fn to_string(&self) -> String {
     match self {
       InstallationUnsuspendEventAction::Unsuspend => "unsuspend".to_string(),
+      InstallationUnsuspendEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookIssueCommentCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl ToString for WebhookIssueCommentCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookIssueCommentCreatedAction::Created => "created".to_string(),
+      WebhookIssueCommentCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -2940,6 +4034,13 @@ pub enum IssueCommentAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "webhook",))]
@@ -2954,11 +4055,12 @@ impl ToString for IssueCommentAuthorAssociation {
       IssueCommentAuthorAssociation::Member => "MEMBER".to_string(),
       IssueCommentAuthorAssociation::None => "NONE".to_string(),
       IssueCommentAuthorAssociation::Owner => "OWNER".to_string(),
+      IssueCommentAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 /// State of the issue; either 'open' or 'closed'
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookIssueCommentCreatedIssueItem2State {
@@ -2966,19 +4068,27 @@ pub enum WebhookIssueCommentCreatedIssueItem2State {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl ToString for WebhookIssueCommentCreatedIssueItem2State {
   fn to_string(&self) -> String {
     match self {
       WebhookIssueCommentCreatedIssueItem2State::Open => "open".to_string(),
       WebhookIssueCommentCreatedIssueItem2State::Closed => "closed".to_string(),
+      WebhookIssueCommentCreatedIssueItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookIssueCommentCreatedIssue {
@@ -2987,23 +4097,31 @@ pub enum WebhookIssueCommentCreatedIssue {
   WebhookIssueCommentCreatedIssueItem2(WebhookIssueCommentCreatedIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookIssueCommentDeletedAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl ToString for WebhookIssueCommentDeletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookIssueCommentDeletedAction::Deleted => "deleted".to_string(),
+      WebhookIssueCommentDeletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 /// State of the issue; either 'open' or 'closed'
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookIssueCommentDeletedIssueItem2State {
@@ -3011,19 +4129,27 @@ pub enum WebhookIssueCommentDeletedIssueItem2State {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl ToString for WebhookIssueCommentDeletedIssueItem2State {
   fn to_string(&self) -> String {
     match self {
       WebhookIssueCommentDeletedIssueItem2State::Open => "open".to_string(),
       WebhookIssueCommentDeletedIssueItem2State::Closed => "closed".to_string(),
+      WebhookIssueCommentDeletedIssueItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookIssueCommentDeletedIssue {
@@ -3032,23 +4158,31 @@ pub enum WebhookIssueCommentDeletedIssue {
   WebhookIssueCommentDeletedIssueItem2(WebhookIssueCommentDeletedIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookIssueCommentEditedAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl ToString for WebhookIssueCommentEditedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookIssueCommentEditedAction::Edited => "edited".to_string(),
+      WebhookIssueCommentEditedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 /// State of the issue; either 'open' or 'closed'
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookIssueCommentEditedIssueItem2State {
@@ -3056,19 +4190,27 @@ pub enum WebhookIssueCommentEditedIssueItem2State {
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl ToString for WebhookIssueCommentEditedIssueItem2State {
   fn to_string(&self) -> String {
     match self {
       WebhookIssueCommentEditedIssueItem2State::Open => "open".to_string(),
       WebhookIssueCommentEditedIssueItem2State::Closed => "closed".to_string(),
+      WebhookIssueCommentEditedIssueItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookIssueCommentEditedIssue {
@@ -3077,60 +4219,84 @@ pub enum WebhookIssueCommentEditedIssue {
   WebhookIssueCommentEditedIssueItem2(WebhookIssueCommentEditedIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 /// The action that was performed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesAssignedEventAction {
   #[serde(rename = "assigned")]
   Assigned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesAssignedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesAssignedEventAction::Assigned => "assigned".to_string(),
+      IssuesAssignedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 /// The action that was performed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesClosedEventAction {
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesClosedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesClosedEventAction::Closed => "closed".to_string(),
+      IssuesClosedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesClosedEventIssueItem2State {
   #[serde(rename = "closed")]
   Closed,
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesClosedEventIssueItem2State {
   fn to_string(&self) -> String {
     match self {
       IssuesClosedEventIssueItem2State::Closed => "closed".to_string(),
       IssuesClosedEventIssueItem2State::Open => "open".to_string(),
+      IssuesClosedEventIssueItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum IssuesClosedEventIssue {
@@ -3139,39 +4305,55 @@ pub enum IssuesClosedEventIssue {
   IssuesClosedEventIssueItem2(IssuesClosedEventIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesDeletedEventAction::Deleted => "deleted".to_string(),
+      IssuesDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesDemilestonedEventAction {
   #[serde(rename = "demilestoned")]
   Demilestoned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesDemilestonedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesDemilestonedEventAction::Demilestoned => "demilestoned".to_string(),
+      IssuesDemilestonedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum IssuesDemilestonedEventIssue {
@@ -3180,55 +4362,79 @@ pub enum IssuesDemilestonedEventIssue {
   IssuesDemilestonedEventIssueItem2(IssuesDemilestonedEventIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesEditedEventAction::Edited => "edited".to_string(),
+      IssuesEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesLabeledEventAction {
   #[serde(rename = "labeled")]
   Labeled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesLabeledEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesLabeledEventAction::Labeled => "labeled".to_string(),
+      IssuesLabeledEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesLockedEventAction {
   #[serde(rename = "locked")]
   Locked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesLockedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesLockedEventAction::Locked => "locked".to_string(),
+      IssuesLockedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesLockedEventIssueItem2ActiveLockReason {
   #[serde(rename = "resolved")]
@@ -3239,9 +4445,16 @@ pub enum IssuesLockedEventIssueItem2ActiveLockReason {
   TooHeated,
   #[serde(rename = "spam")]
   Spam,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesLockedEventIssueItem2ActiveLockReason {
   fn to_string(&self) -> String {
     match self {
@@ -3249,17 +4462,18 @@ impl ToString for IssuesLockedEventIssueItem2ActiveLockReason {
       IssuesLockedEventIssueItem2ActiveLockReason::OffTopic => "off-topic".to_string(),
       IssuesLockedEventIssueItem2ActiveLockReason::TooHeated => "too heated".to_string(),
       IssuesLockedEventIssueItem2ActiveLockReason::Spam => "spam".to_string(),
+      IssuesLockedEventIssueItem2ActiveLockReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IssuesLockedEventIssueItem2Locked {
   Boolean(bool),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum IssuesLockedEventIssue {
@@ -3268,23 +4482,31 @@ pub enum IssuesLockedEventIssue {
   IssuesLockedEventIssueItem2(IssuesLockedEventIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesMilestonedEventAction {
   #[serde(rename = "milestoned")]
   Milestoned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesMilestonedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesMilestonedEventAction::Milestoned => "milestoned".to_string(),
+      IssuesMilestonedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum IssuesMilestonedEventIssue {
@@ -3293,74 +4515,106 @@ pub enum IssuesMilestonedEventIssue {
   IssuesMilestonedEventIssueItem2(IssuesMilestonedEventIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesOpenedEventAction {
   #[serde(rename = "opened")]
   Opened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesOpenedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesOpenedEventAction::Opened => "opened".to_string(),
+      IssuesOpenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesPinnedEventAction {
   #[serde(rename = "pinned")]
   Pinned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesPinnedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesPinnedEventAction::Pinned => "pinned".to_string(),
+      IssuesPinnedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesReopenedEventAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesReopenedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesReopenedEventAction::Reopened => "reopened".to_string(),
+      IssuesReopenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesReopenedEventIssueItem2State {
   #[serde(rename = "open")]
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesReopenedEventIssueItem2State {
   fn to_string(&self) -> String {
     match self {
       IssuesReopenedEventIssueItem2State::Open => "open".to_string(),
       IssuesReopenedEventIssueItem2State::Closed => "closed".to_string(),
+      IssuesReopenedEventIssueItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum IssuesReopenedEventIssue {
@@ -3369,78 +4623,110 @@ pub enum IssuesReopenedEventIssue {
   IssuesReopenedEventIssueItem2(IssuesReopenedEventIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesTransferredEventAction {
   #[serde(rename = "transferred")]
   Transferred,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesTransferredEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesTransferredEventAction::Transferred => "transferred".to_string(),
+      IssuesTransferredEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 /// The action that was performed.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesUnassignedEventAction {
   #[serde(rename = "unassigned")]
   Unassigned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesUnassignedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesUnassignedEventAction::Unassigned => "unassigned".to_string(),
+      IssuesUnassignedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesUnlabeledEventAction {
   #[serde(rename = "unlabeled")]
   Unlabeled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesUnlabeledEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesUnlabeledEventAction::Unlabeled => "unlabeled".to_string(),
+      IssuesUnlabeledEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesUnlockedEventAction {
   #[serde(rename = "unlocked")]
   Unlocked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesUnlockedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesUnlockedEventAction::Unlocked => "unlocked".to_string(),
+      IssuesUnlockedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum IssuesUnlockedEventIssueItem2Locked {
   Boolean(bool),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum IssuesUnlockedEventIssue {
@@ -3449,82 +4735,122 @@ pub enum IssuesUnlockedEventIssue {
   IssuesUnlockedEventIssueItem2(IssuesUnlockedEventIssueItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum IssuesUnpinnedEventAction {
   #[serde(rename = "unpinned")]
   Unpinned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl ToString for IssuesUnpinnedEventAction {
   fn to_string(&self) -> String {
     match self {
       IssuesUnpinnedEventAction::Unpinned => "unpinned".to_string(),
+      IssuesUnpinnedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum LabelCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl ToString for LabelCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       LabelCreatedEventAction::Created => "created".to_string(),
+      LabelCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum LabelDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl ToString for LabelDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       LabelDeletedEventAction::Deleted => "deleted".to_string(),
+      LabelDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum LabelEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl ToString for LabelEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       LabelEditedEventAction::Edited => "edited".to_string(),
+      LabelEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchaseCancelledAction {
   #[serde(rename = "cancelled")]
   Cancelled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchaseCancelledAction {
   fn to_string(&self) -> String {
     match self {
       WebhookMarketplacePurchaseCancelledAction::Cancelled => "cancelled".to_string(),
+      WebhookMarketplacePurchaseCancelledAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -3538,6 +4864,13 @@ pub enum MarketplacePurchasePlanPriceModel {
   FlatRate,
   #[serde(rename = "PER_UNIT")]
   PerUnit,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "webhook",))]
@@ -3547,11 +4880,12 @@ impl ToString for MarketplacePurchasePlanPriceModel {
       MarketplacePurchasePlanPriceModel::Free => "FREE".to_string(),
       MarketplacePurchasePlanPriceModel::FlatRate => "FLAT_RATE".to_string(),
       MarketplacePurchasePlanPriceModel::PerUnit => "PER_UNIT".to_string(),
+      MarketplacePurchasePlanPriceModel::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2PlanPriceModel {
   #[serde(rename = "FREE")]
@@ -3560,9 +4894,16 @@ pub enum WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2PlanPriceMod
   FlatRate,
   #[serde(rename = "PER_UNIT")]
   PerUnit,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2PlanPriceModel {
   fn to_string(&self) -> String {
     match self {
@@ -3575,11 +4916,14 @@ impl ToString for WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2Pla
       WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2PlanPriceModel::PerUnit => {
         "PER_UNIT".to_string()
       }
+      WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2PlanPriceModel::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookMarketplacePurchaseCancelledMarketplacePurchase {
@@ -3589,23 +4933,31 @@ pub enum WebhookMarketplacePurchaseCancelledMarketplacePurchase {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchaseChangedAction {
   #[serde(rename = "changed")]
   Changed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchaseChangedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookMarketplacePurchaseChangedAction::Changed => "changed".to_string(),
+      WebhookMarketplacePurchaseChangedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2PlanPriceModel {
   #[serde(rename = "FREE")]
@@ -3614,9 +4966,16 @@ pub enum WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2PlanPriceModel
   FlatRate,
   #[serde(rename = "PER_UNIT")]
   PerUnit,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2PlanPriceModel {
   fn to_string(&self) -> String {
     match self {
@@ -3629,11 +4988,14 @@ impl ToString for WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2PlanP
       WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2PlanPriceModel::PerUnit => {
         "PER_UNIT".to_string()
       }
+      WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2PlanPriceModel::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookMarketplacePurchaseChangedMarketplacePurchase {
@@ -3643,23 +5005,31 @@ pub enum WebhookMarketplacePurchaseChangedMarketplacePurchase {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchasePendingChangeAction {
   #[serde(rename = "pending_change")]
   PendingChange,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchasePendingChangeAction {
   fn to_string(&self) -> String {
     match self {
       WebhookMarketplacePurchasePendingChangeAction::PendingChange => "pending_change".to_string(),
+      WebhookMarketplacePurchasePendingChangeAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2PlanPriceModel {
   #[serde(rename = "FREE")]
@@ -3668,9 +5038,16 @@ pub enum WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2PlanPric
   FlatRate,
   #[serde(rename = "PER_UNIT")]
   PerUnit,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2PlanPriceModel {
   fn to_string(&self) -> String {
     match self {
@@ -3683,11 +5060,14 @@ impl ToString for WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem
       WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2PlanPriceModel::PerUnit => {
         "PER_UNIT".to_string()
       }
+      WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2PlanPriceModel::Unknown(
+        value,
+      ) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookMarketplacePurchasePendingChangeMarketplacePurchase {
@@ -3697,25 +5077,33 @@ pub enum WebhookMarketplacePurchasePendingChangeMarketplacePurchase {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchasePendingChangeCancelledAction {
   #[serde(rename = "pending_change_cancelled")]
   PendingChangeCancelled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchasePendingChangeCancelledAction {
   fn to_string(&self) -> String {
     match self {
       WebhookMarketplacePurchasePendingChangeCancelledAction::PendingChangeCancelled => {
         "pending_change_cancelled".to_string()
       }
+      WebhookMarketplacePurchasePendingChangeCancelledAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookMarketplacePurchasePendingChangeCancelledMarketplacePurchase {
@@ -3725,23 +5113,31 @@ pub enum WebhookMarketplacePurchasePendingChangeCancelledMarketplacePurchase {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchasePurchasedAction {
   #[serde(rename = "purchased")]
   Purchased,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchasePurchasedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookMarketplacePurchasePurchasedAction::Purchased => "purchased".to_string(),
+      WebhookMarketplacePurchasePurchasedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2PlanPriceModel {
   #[serde(rename = "FREE")]
@@ -3750,9 +5146,16 @@ pub enum WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2PlanPriceMod
   FlatRate,
   #[serde(rename = "PER_UNIT")]
   PerUnit,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl ToString for WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2PlanPriceModel {
   fn to_string(&self) -> String {
     match self {
@@ -3765,11 +5168,14 @@ impl ToString for WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2Pla
       WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2PlanPriceModel::PerUnit => {
         "PER_UNIT".to_string()
       }
+      WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2PlanPriceModel::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookMarketplacePurchasePurchasedMarketplacePurchase {
@@ -3779,23 +5185,31 @@ pub enum WebhookMarketplacePurchasePurchasedMarketplacePurchase {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MemberAddedEventAction {
   #[serde(rename = "added")]
   Added,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl ToString for MemberAddedEventAction {
   fn to_string(&self) -> String {
     match self {
       MemberAddedEventAction::Added => "added".to_string(),
+      MemberAddedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MemberAddedEventChangesPermissionTo {
   #[serde(rename = "write")]
@@ -3804,101 +5218,149 @@ pub enum MemberAddedEventChangesPermissionTo {
   Admin,
   #[serde(rename = "read")]
   Read,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl ToString for MemberAddedEventChangesPermissionTo {
   fn to_string(&self) -> String {
     match self {
       MemberAddedEventChangesPermissionTo::Write => "write".to_string(),
       MemberAddedEventChangesPermissionTo::Admin => "admin".to_string(),
       MemberAddedEventChangesPermissionTo::Read => "read".to_string(),
+      MemberAddedEventChangesPermissionTo::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MemberEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl ToString for MemberEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       MemberEditedEventAction::Edited => "edited".to_string(),
+      MemberEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MemberRemovedEventAction {
   #[serde(rename = "removed")]
   Removed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl ToString for MemberRemovedEventAction {
   fn to_string(&self) -> String {
     match self {
       MemberRemovedEventAction::Removed => "removed".to_string(),
+      MemberRemovedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MembershipAddedEventAction {
   #[serde(rename = "added")]
   Added,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 impl ToString for MembershipAddedEventAction {
   fn to_string(&self) -> String {
     match self {
       MembershipAddedEventAction::Added => "added".to_string(),
+      MembershipAddedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 /// The scope of the membership. Currently, can only be `team`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MembershipAddedEventScope {
   #[serde(rename = "team")]
   Team,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 impl ToString for MembershipAddedEventScope {
   fn to_string(&self) -> String {
     match self {
       MembershipAddedEventScope::Team => "team".to_string(),
+      MembershipAddedEventScope::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MembershipRemovedEventAction {
   #[serde(rename = "removed")]
   Removed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 impl ToString for MembershipRemovedEventAction {
   fn to_string(&self) -> String {
     match self {
       MembershipRemovedEventAction::Removed => "removed".to_string(),
+      MembershipRemovedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 /// The scope of the membership. Currently, can only be `team`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MembershipRemovedEventScope {
@@ -3906,51 +5368,75 @@ pub enum MembershipRemovedEventScope {
   Team,
   #[serde(rename = "organization")]
   Organization,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 impl ToString for MembershipRemovedEventScope {
   fn to_string(&self) -> String {
     match self {
       MembershipRemovedEventScope::Team => "team".to_string(),
       MembershipRemovedEventScope::Organization => "organization".to_string(),
+      MembershipRemovedEventScope::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMergeGroupChecksRequestedAction {
   #[serde(rename = "checks_requested")]
   ChecksRequested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 impl ToString for WebhookMergeGroupChecksRequestedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookMergeGroupChecksRequestedAction::ChecksRequested => "checks_requested".to_string(),
+      WebhookMergeGroupChecksRequestedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMergeGroupDestroyedAction {
   #[serde(rename = "destroyed")]
   Destroyed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 impl ToString for WebhookMergeGroupDestroyedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookMergeGroupDestroyedAction::Destroyed => "destroyed".to_string(),
+      WebhookMergeGroupDestroyedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 /// Explains why the merge group is being destroyed. The group could have been merged, removed from the queue (dequeued), or invalidated by an earlier queue entry being dequeued (invalidated).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookMergeGroupDestroyedReason {
@@ -3960,55 +5446,79 @@ pub enum WebhookMergeGroupDestroyedReason {
   Invalidated,
   #[serde(rename = "dequeued")]
   Dequeued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 impl ToString for WebhookMergeGroupDestroyedReason {
   fn to_string(&self) -> String {
     match self {
       WebhookMergeGroupDestroyedReason::Merged => "merged".to_string(),
       WebhookMergeGroupDestroyedReason::Invalidated => "invalidated".to_string(),
       WebhookMergeGroupDestroyedReason::Dequeued => "dequeued".to_string(),
+      WebhookMergeGroupDestroyedReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MetaDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 impl ToString for MetaDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       MetaDeletedEventAction::Deleted => "deleted".to_string(),
+      MetaDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MetaDeletedEventHookConfigContentType {
   #[serde(rename = "json")]
   Json,
   #[serde(rename = "form")]
   Form,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 impl ToString for MetaDeletedEventHookConfigContentType {
   fn to_string(&self) -> String {
     match self {
       MetaDeletedEventHookConfigContentType::Json => "json".to_string(),
       MetaDeletedEventHookConfigContentType::Form => "form".to_string(),
+      MetaDeletedEventHookConfigContentType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MetaDeletedEventHookEvents {
   #[serde(rename = "*")]
@@ -4115,9 +5625,16 @@ pub enum MetaDeletedEventHookEvents {
   RepositoryDispatch,
   #[serde(rename = "projects_v2_item")]
   ProjectsV2Item,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 impl ToString for MetaDeletedEventHookEvents {
   fn to_string(&self) -> String {
     match self {
@@ -4181,251 +5698,372 @@ impl ToString for MetaDeletedEventHookEvents {
       MetaDeletedEventHookEvents::WorkflowRun => "workflow_run".to_string(),
       MetaDeletedEventHookEvents::RepositoryDispatch => "repository_dispatch".to_string(),
       MetaDeletedEventHookEvents::ProjectsV2Item => "projects_v2_item".to_string(),
+      MetaDeletedEventHookEvents::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MilestoneClosedEventAction {
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl ToString for MilestoneClosedEventAction {
   fn to_string(&self) -> String {
     match self {
       MilestoneClosedEventAction::Closed => "closed".to_string(),
+      MilestoneClosedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MilestoneCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl ToString for MilestoneCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       MilestoneCreatedEventAction::Created => "created".to_string(),
+      MilestoneCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MilestoneDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl ToString for MilestoneDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       MilestoneDeletedEventAction::Deleted => "deleted".to_string(),
+      MilestoneDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MilestoneEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl ToString for MilestoneEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       MilestoneEditedEventAction::Edited => "edited".to_string(),
+      MilestoneEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MilestoneOpenedEventAction {
   #[serde(rename = "opened")]
   Opened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl ToString for MilestoneOpenedEventAction {
   fn to_string(&self) -> String {
     match self {
       MilestoneOpenedEventAction::Opened => "opened".to_string(),
+      MilestoneOpenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookOrgBlockBlockedAction {
   #[serde(rename = "blocked")]
   Blocked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 impl ToString for WebhookOrgBlockBlockedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookOrgBlockBlockedAction::Blocked => "blocked".to_string(),
+      WebhookOrgBlockBlockedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookOrgBlockUnblockedAction {
   #[serde(rename = "unblocked")]
   Unblocked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 impl ToString for WebhookOrgBlockUnblockedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookOrgBlockUnblockedAction::Unblocked => "unblocked".to_string(),
+      WebhookOrgBlockUnblockedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum OrganizationDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl ToString for OrganizationDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       OrganizationDeletedEventAction::Deleted => "deleted".to_string(),
+      OrganizationDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookOrganizationMemberAddedAction {
   #[serde(rename = "member_added")]
   MemberAdded,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl ToString for WebhookOrganizationMemberAddedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookOrganizationMemberAddedAction::MemberAdded => "member_added".to_string(),
+      WebhookOrganizationMemberAddedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookOrganizationMemberInvitedAction {
   #[serde(rename = "member_invited")]
   MemberInvited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl ToString for WebhookOrganizationMemberInvitedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookOrganizationMemberInvitedAction::MemberInvited => "member_invited".to_string(),
+      WebhookOrganizationMemberInvitedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookOrganizationMemberRemovedAction {
   #[serde(rename = "member_removed")]
   MemberRemoved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl ToString for WebhookOrganizationMemberRemovedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookOrganizationMemberRemovedAction::MemberRemoved => "member_removed".to_string(),
+      WebhookOrganizationMemberRemovedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum OrganizationRenamedEventAction {
   #[serde(rename = "renamed")]
   Renamed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl ToString for OrganizationRenamedEventAction {
   fn to_string(&self) -> String {
     match self {
       OrganizationRenamedEventAction::Renamed => "renamed".to_string(),
+      OrganizationRenamedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PackagePublishedEventAction {
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl ToString for PackagePublishedEventAction {
   fn to_string(&self) -> String {
     match self {
       PackagePublishedEventAction::Published => "published".to_string(),
+      PackagePublishedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PackageUpdatedEventAction {
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl ToString for PackageUpdatedEventAction {
   fn to_string(&self) -> String {
     match self {
       PackageUpdatedEventAction::Updated => "updated".to_string(),
+      PackageUpdatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPersonalAccessTokenRequestApprovedAction {
   #[serde(rename = "approved")]
   Approved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl ToString for WebhookPersonalAccessTokenRequestApprovedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPersonalAccessTokenRequestApprovedAction::Approved => "approved".to_string(),
+      WebhookPersonalAccessTokenRequestApprovedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 /// Type of repository selection requested.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PersonalAccessTokenRequestRepositorySelection {
@@ -4435,148 +6073,220 @@ pub enum PersonalAccessTokenRequestRepositorySelection {
   All,
   #[serde(rename = "subset")]
   Subset,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl ToString for PersonalAccessTokenRequestRepositorySelection {
   fn to_string(&self) -> String {
     match self {
       PersonalAccessTokenRequestRepositorySelection::None => "none".to_string(),
       PersonalAccessTokenRequestRepositorySelection::All => "all".to_string(),
       PersonalAccessTokenRequestRepositorySelection::Subset => "subset".to_string(),
+      PersonalAccessTokenRequestRepositorySelection::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPersonalAccessTokenRequestCancelledAction {
   #[serde(rename = "cancelled")]
   Cancelled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl ToString for WebhookPersonalAccessTokenRequestCancelledAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPersonalAccessTokenRequestCancelledAction::Cancelled => "cancelled".to_string(),
+      WebhookPersonalAccessTokenRequestCancelledAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPersonalAccessTokenRequestCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl ToString for WebhookPersonalAccessTokenRequestCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPersonalAccessTokenRequestCreatedAction::Created => "created".to_string(),
+      WebhookPersonalAccessTokenRequestCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPersonalAccessTokenRequestDeniedAction {
   #[serde(rename = "denied")]
   Denied,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl ToString for WebhookPersonalAccessTokenRequestDeniedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPersonalAccessTokenRequestDeniedAction::Denied => "denied".to_string(),
+      WebhookPersonalAccessTokenRequestDeniedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectCardConvertedAction {
   #[serde(rename = "converted")]
   Converted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl ToString for WebhookProjectCardConvertedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectCardConvertedAction::Converted => "converted".to_string(),
+      WebhookProjectCardConvertedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectCardCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl ToString for WebhookProjectCardCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectCardCreatedAction::Created => "created".to_string(),
+      WebhookProjectCardCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectCardDeletedAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl ToString for WebhookProjectCardDeletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectCardDeletedAction::Deleted => "deleted".to_string(),
+      WebhookProjectCardDeletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectCardEditedAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl ToString for WebhookProjectCardEditedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectCardEditedAction::Edited => "edited".to_string(),
+      WebhookProjectCardEditedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectCardMovedAction {
   #[serde(rename = "moved")]
   Moved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl ToString for WebhookProjectCardMovedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectCardMovedAction::Moved => "moved".to_string(),
+      WebhookProjectCardMovedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookProjectCardMovedProjectCard {
@@ -4584,315 +6294,467 @@ pub enum WebhookProjectCardMovedProjectCard {
   WebhookProjectCardMovedProjectCardItem2(WebhookProjectCardMovedProjectCardItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectClosedEventAction {
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ToString for ProjectClosedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectClosedEventAction::Closed => "closed".to_string(),
+      ProjectClosedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectColumnCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl ToString for WebhookProjectColumnCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectColumnCreatedAction::Created => "created".to_string(),
+      WebhookProjectColumnCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectColumnDeletedAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl ToString for WebhookProjectColumnDeletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectColumnDeletedAction::Deleted => "deleted".to_string(),
+      WebhookProjectColumnDeletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectColumnEditedAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl ToString for WebhookProjectColumnEditedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectColumnEditedAction::Edited => "edited".to_string(),
+      WebhookProjectColumnEditedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectColumnMovedAction {
   #[serde(rename = "moved")]
   Moved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl ToString for WebhookProjectColumnMovedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectColumnMovedAction::Moved => "moved".to_string(),
+      WebhookProjectColumnMovedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ToString for ProjectCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectCreatedEventAction::Created => "created".to_string(),
+      ProjectCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ToString for ProjectDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectDeletedEventAction::Deleted => "deleted".to_string(),
+      ProjectDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ToString for ProjectEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectEditedEventAction::Edited => "edited".to_string(),
+      ProjectEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectReopenedEventAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ToString for ProjectReopenedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectReopenedEventAction::Reopened => "reopened".to_string(),
+      ProjectReopenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ProjectClosedEventAction {
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ToString for ProjectsV2ProjectClosedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ProjectClosedEventAction::Closed => "closed".to_string(),
+      ProjectsV2ProjectClosedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookProjectsV2ProjectCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ToString for WebhookProjectsV2ProjectCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookProjectsV2ProjectCreatedAction::Created => "created".to_string(),
+      WebhookProjectsV2ProjectCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ProjectDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ToString for ProjectsV2ProjectDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ProjectDeletedEventAction::Deleted => "deleted".to_string(),
+      ProjectsV2ProjectDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ProjectEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ToString for ProjectsV2ProjectEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ProjectEditedEventAction::Edited => "edited".to_string(),
+      ProjectsV2ProjectEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemArchivedEventAction {
   #[serde(rename = "archived")]
   Archived,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemArchivedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemArchivedEventAction::Archived => "archived".to_string(),
+      ProjectsV2ItemArchivedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 /// The type of content tracked in a project item
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemContentType {
   Issue,
   PullRequest,
   DraftIssue,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemContentType {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemContentType::Issue => "Issue".to_string(),
       ProjectsV2ItemContentType::PullRequest => "PullRequest".to_string(),
       ProjectsV2ItemContentType::DraftIssue => "DraftIssue".to_string(),
+      ProjectsV2ItemContentType::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemConvertedEventAction {
   #[serde(rename = "converted")]
   Converted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemConvertedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemConvertedEventAction::Converted => "converted".to_string(),
+      ProjectsV2ItemConvertedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemCreatedEventAction::Created => "created".to_string(),
+      ProjectsV2ItemCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemDeletedEventAction::Deleted => "deleted".to_string(),
+      ProjectsV2ItemDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemEditedEventAction::Edited => "edited".to_string(),
+      ProjectsV2ItemEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ProjectsV2ItemEditedEventChanges {
@@ -4900,66 +6762,98 @@ pub enum ProjectsV2ItemEditedEventChanges {
   ProjectsV2ItemEditedEventChangesItem2(ProjectsV2ItemEditedEventChangesItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemReorderedEventAction {
   #[serde(rename = "reordered")]
   Reordered,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemReorderedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemReorderedEventAction::Reordered => "reordered".to_string(),
+      ProjectsV2ItemReorderedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ItemRestoredEventAction {
   #[serde(rename = "restored")]
   Restored,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ToString for ProjectsV2ItemRestoredEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ItemRestoredEventAction::Restored => "restored".to_string(),
+      ProjectsV2ItemRestoredEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ProjectsV2ProjectReopenedEventAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ToString for ProjectsV2ProjectReopenedEventAction {
   fn to_string(&self) -> String {
     match self {
       ProjectsV2ProjectReopenedEventAction::Reopened => "reopened".to_string(),
+      ProjectsV2ProjectReopenedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestAssignedAction {
   #[serde(rename = "assigned")]
   Assigned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestAssignedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestAssignedAction::Assigned => "assigned".to_string(),
+      WebhookPullRequestAssignedAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -4975,6 +6869,13 @@ pub enum PullRequestActiveLockReason {
   TooHeated,
   #[serde(rename = "spam")]
   Spam,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "webhook",))]
@@ -4985,6 +6886,7 @@ impl ToString for PullRequestActiveLockReason {
       PullRequestActiveLockReason::OffTopic => "off-topic".to_string(),
       PullRequestActiveLockReason::TooHeated => "too heated".to_string(),
       PullRequestActiveLockReason::Spam => "spam".to_string(),
+      PullRequestActiveLockReason::Unknown(value) => value.clone(),
     }
   }
 }
@@ -5009,6 +6911,13 @@ pub enum PullRequestAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "webhook",))]
@@ -5023,11 +6932,17 @@ impl ToString for PullRequestAuthorAssociation {
       PullRequestAuthorAssociation::Member => "MEMBER".to_string(),
       PullRequestAuthorAssociation::None => "NONE".to_string(),
       PullRequestAuthorAssociation::Owner => "OWNER".to_string(),
+      PullRequestAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 /// The merge method to use.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum PullRequestAutoMergeMergeMethod {
@@ -5037,72 +6952,109 @@ pub enum PullRequestAutoMergeMergeMethod {
   Squash,
   #[serde(rename = "rebase")]
   Rebase,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl ToString for PullRequestAutoMergeMergeMethod {
   fn to_string(&self) -> String {
     match self {
       PullRequestAutoMergeMergeMethod::Merge => "merge".to_string(),
       PullRequestAutoMergeMergeMethod::Squash => "squash".to_string(),
       PullRequestAutoMergeMergeMethod::Rebase => "rebase".to_string(),
+      PullRequestAutoMergeMergeMethod::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestAutoMergeDisabledAction {
   #[serde(rename = "auto_merge_disabled")]
   AutoMergeDisabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestAutoMergeDisabledAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestAutoMergeDisabledAction::AutoMergeDisabled => {
         "auto_merge_disabled".to_string()
       }
+      WebhookPullRequestAutoMergeDisabledAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestAutoMergeEnabledAction {
   #[serde(rename = "auto_merge_enabled")]
   AutoMergeEnabled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestAutoMergeEnabledAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestAutoMergeEnabledAction::AutoMergeEnabled => {
         "auto_merge_enabled".to_string()
       }
+      WebhookPullRequestAutoMergeEnabledAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestClosedAction {
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestClosedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestClosedAction::Closed => "closed".to_string(),
+      WebhookPullRequestClosedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit message.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `PR_BODY` - default to the pull request's body.
@@ -5115,20 +7067,28 @@ pub enum WebhookPullRequestClosedPullRequestItem2MergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestClosedPullRequestItem2MergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestClosedPullRequestItem2MergeCommitMessage::PrBody => "PR_BODY".to_string(),
       WebhookPullRequestClosedPullRequestItem2MergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       WebhookPullRequestClosedPullRequestItem2MergeCommitMessage::Blank => "BLANK".to_string(),
+      WebhookPullRequestClosedPullRequestItem2MergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit title.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `MERGE_MESSAGE` - default to the classic title for a merge message (e.g., "Merge pull request #123 from branch-name").
@@ -5138,9 +7098,16 @@ pub enum WebhookPullRequestClosedPullRequestItem2MergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestClosedPullRequestItem2MergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5148,11 +7115,12 @@ impl ToString for WebhookPullRequestClosedPullRequestItem2MergeCommitTitle {
       WebhookPullRequestClosedPullRequestItem2MergeCommitTitle::MergeMessage => {
         "MERGE_MESSAGE".to_string()
       }
+      WebhookPullRequestClosedPullRequestItem2MergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit message:
 /// - `PR_BODY` - default to the pull request's body.
 /// - `COMMIT_MESSAGES` - default to the branch's commit messages.
@@ -5165,9 +7133,16 @@ pub enum WebhookPullRequestClosedPullRequestItem2SquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestClosedPullRequestItem2SquashMergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5180,11 +7155,14 @@ impl ToString for WebhookPullRequestClosedPullRequestItem2SquashMergeCommitMessa
       WebhookPullRequestClosedPullRequestItem2SquashMergeCommitMessage::Blank => {
         "BLANK".to_string()
       }
+      WebhookPullRequestClosedPullRequestItem2SquashMergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit title:
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `COMMIT_OR_PR_TITLE` - default to the commit's title (if only one commit) or the pull request's title (when more than one commit).
@@ -5194,9 +7172,16 @@ pub enum WebhookPullRequestClosedPullRequestItem2SquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestClosedPullRequestItem2SquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5206,11 +7191,14 @@ impl ToString for WebhookPullRequestClosedPullRequestItem2SquashMergeCommitTitle
       WebhookPullRequestClosedPullRequestItem2SquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      WebhookPullRequestClosedPullRequestItem2SquashMergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestClosedPullRequest {
@@ -5219,25 +7207,33 @@ pub enum WebhookPullRequestClosedPullRequest {
   WebhookPullRequestClosedPullRequestItem2(WebhookPullRequestClosedPullRequestItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestConvertedToDraftAction {
   #[serde(rename = "converted_to_draft")]
   ConvertedToDraft,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestConvertedToDraftAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestConvertedToDraftAction::ConvertedToDraft => {
         "converted_to_draft".to_string()
       }
+      WebhookPullRequestConvertedToDraftAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit message.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `PR_BODY` - default to the pull request's body.
@@ -5250,9 +7246,16 @@ pub enum WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5265,11 +7268,14 @@ impl ToString for WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitM
       WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitMessage::Blank => {
         "BLANK".to_string()
       }
+      WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit title.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `MERGE_MESSAGE` - default to the classic title for a merge message (e.g., "Merge pull request #123 from branch-name").
@@ -5279,9 +7285,16 @@ pub enum WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5291,11 +7304,14 @@ impl ToString for WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitT
       WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitTitle::MergeMessage => {
         "MERGE_MESSAGE".to_string()
       }
+      WebhookPullRequestConvertedToDraftPullRequestItem2MergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit message:
 /// - `PR_BODY` - default to the pull request's body.
 /// - `COMMIT_MESSAGES` - default to the branch's commit messages.
@@ -5308,20 +7324,28 @@ pub enum WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitMess
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitMessage::PrBody => "PR_BODY".to_string(),
       WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitMessage::CommitMessages => "COMMIT_MESSAGES".to_string(),
       WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitMessage::Blank => "BLANK".to_string(),
+      WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit title:
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `COMMIT_OR_PR_TITLE` - default to the commit's title (if only one commit) or the pull request's title (when more than one commit).
@@ -5331,9 +7355,16 @@ pub enum WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitTitl
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5343,11 +7374,14 @@ impl ToString for WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeC
       WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      WebhookPullRequestConvertedToDraftPullRequestItem2SquashMergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestConvertedToDraftPullRequest {
@@ -5358,55 +7392,79 @@ pub enum WebhookPullRequestConvertedToDraftPullRequest {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestDemilestonedAction {
   #[serde(rename = "demilestoned")]
   Demilestoned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestDemilestonedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestDemilestonedAction::Demilestoned => "demilestoned".to_string(),
+      WebhookPullRequestDemilestonedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestDequeuedAction {
   #[serde(rename = "dequeued")]
   Dequeued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestDequeuedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestDequeuedAction::Dequeued => "dequeued".to_string(),
+      WebhookPullRequestDequeuedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestEditedAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestEditedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestEditedAction::Edited => "edited".to_string(),
+      WebhookPullRequestEditedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit message.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `PR_BODY` - default to the pull request's body.
@@ -5419,20 +7477,28 @@ pub enum WebhookPullRequestEditedPullRequestItem2MergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestEditedPullRequestItem2MergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestEditedPullRequestItem2MergeCommitMessage::PrBody => "PR_BODY".to_string(),
       WebhookPullRequestEditedPullRequestItem2MergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       WebhookPullRequestEditedPullRequestItem2MergeCommitMessage::Blank => "BLANK".to_string(),
+      WebhookPullRequestEditedPullRequestItem2MergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit title.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `MERGE_MESSAGE` - default to the classic title for a merge message (e.g., "Merge pull request #123 from branch-name").
@@ -5442,9 +7508,16 @@ pub enum WebhookPullRequestEditedPullRequestItem2MergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestEditedPullRequestItem2MergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5452,11 +7525,12 @@ impl ToString for WebhookPullRequestEditedPullRequestItem2MergeCommitTitle {
       WebhookPullRequestEditedPullRequestItem2MergeCommitTitle::MergeMessage => {
         "MERGE_MESSAGE".to_string()
       }
+      WebhookPullRequestEditedPullRequestItem2MergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit message:
 /// - `PR_BODY` - default to the pull request's body.
 /// - `COMMIT_MESSAGES` - default to the branch's commit messages.
@@ -5469,9 +7543,16 @@ pub enum WebhookPullRequestEditedPullRequestItem2SquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestEditedPullRequestItem2SquashMergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5484,11 +7565,14 @@ impl ToString for WebhookPullRequestEditedPullRequestItem2SquashMergeCommitMessa
       WebhookPullRequestEditedPullRequestItem2SquashMergeCommitMessage::Blank => {
         "BLANK".to_string()
       }
+      WebhookPullRequestEditedPullRequestItem2SquashMergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit title:
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `COMMIT_OR_PR_TITLE` - default to the commit's title (if only one commit) or the pull request's title (when more than one commit).
@@ -5498,9 +7582,16 @@ pub enum WebhookPullRequestEditedPullRequestItem2SquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestEditedPullRequestItem2SquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5510,11 +7601,14 @@ impl ToString for WebhookPullRequestEditedPullRequestItem2SquashMergeCommitTitle
       WebhookPullRequestEditedPullRequestItem2SquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      WebhookPullRequestEditedPullRequestItem2SquashMergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestEditedPullRequest {
@@ -5523,87 +7617,127 @@ pub enum WebhookPullRequestEditedPullRequest {
   WebhookPullRequestEditedPullRequestItem2(WebhookPullRequestEditedPullRequestItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestEnqueuedAction {
   #[serde(rename = "enqueued")]
   Enqueued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestEnqueuedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestEnqueuedAction::Enqueued => "enqueued".to_string(),
+      WebhookPullRequestEnqueuedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestLabeledAction {
   #[serde(rename = "labeled")]
   Labeled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestLabeledAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestLabeledAction::Labeled => "labeled".to_string(),
+      WebhookPullRequestLabeledAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestLockedAction {
   #[serde(rename = "locked")]
   Locked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestLockedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestLockedAction::Locked => "locked".to_string(),
+      WebhookPullRequestLockedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestMilestonedAction {
   #[serde(rename = "milestoned")]
   Milestoned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestMilestonedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestMilestonedAction::Milestoned => "milestoned".to_string(),
+      WebhookPullRequestMilestonedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestOpenedAction {
   #[serde(rename = "opened")]
   Opened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestOpenedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestOpenedAction::Opened => "opened".to_string(),
+      WebhookPullRequestOpenedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit message.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `PR_BODY` - default to the pull request's body.
@@ -5616,20 +7750,28 @@ pub enum WebhookPullRequestOpenedPullRequestItem2MergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestOpenedPullRequestItem2MergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestOpenedPullRequestItem2MergeCommitMessage::PrBody => "PR_BODY".to_string(),
       WebhookPullRequestOpenedPullRequestItem2MergeCommitMessage::PrTitle => "PR_TITLE".to_string(),
       WebhookPullRequestOpenedPullRequestItem2MergeCommitMessage::Blank => "BLANK".to_string(),
+      WebhookPullRequestOpenedPullRequestItem2MergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit title.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `MERGE_MESSAGE` - default to the classic title for a merge message (e.g., Merge pull request #123 from branch-name).
@@ -5639,9 +7781,16 @@ pub enum WebhookPullRequestOpenedPullRequestItem2MergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestOpenedPullRequestItem2MergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5649,11 +7798,12 @@ impl ToString for WebhookPullRequestOpenedPullRequestItem2MergeCommitTitle {
       WebhookPullRequestOpenedPullRequestItem2MergeCommitTitle::MergeMessage => {
         "MERGE_MESSAGE".to_string()
       }
+      WebhookPullRequestOpenedPullRequestItem2MergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit message:
 /// - `PR_BODY` - default to the pull request's body.
 /// - `COMMIT_MESSAGES` - default to the branch's commit messages.
@@ -5666,9 +7816,16 @@ pub enum WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5681,11 +7838,14 @@ impl ToString for WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitMessa
       WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitMessage::Blank => {
         "BLANK".to_string()
       }
+      WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit title:
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `COMMIT_OR_PR_TITLE` - default to the commit's title (if only one commit) or the pull request's title (when more than one commit).
@@ -5695,9 +7855,16 @@ pub enum WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5707,11 +7874,14 @@ impl ToString for WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitTitle
       WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      WebhookPullRequestOpenedPullRequestItem2SquashMergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestOpenedPullRequest {
@@ -5720,23 +7890,31 @@ pub enum WebhookPullRequestOpenedPullRequest {
   WebhookPullRequestOpenedPullRequestItem2(WebhookPullRequestOpenedPullRequestItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReadyForReviewAction {
   #[serde(rename = "ready_for_review")]
   ReadyForReview,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReadyForReviewAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReadyForReviewAction::ReadyForReview => "ready_for_review".to_string(),
+      WebhookPullRequestReadyForReviewAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit message.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `PR_BODY` - default to the pull request's body.
@@ -5749,9 +7927,16 @@ pub enum WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5764,11 +7949,14 @@ impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitMes
       WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitMessage::Blank => {
         "BLANK".to_string()
       }
+      WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit title.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `MERGE_MESSAGE` - default to the classic title for a merge message (e.g., "Merge pull request #123 from branch-name").
@@ -5778,9 +7966,16 @@ pub enum WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5790,11 +7985,14 @@ impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitTit
       WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitTitle::MergeMessage => {
         "MERGE_MESSAGE".to_string()
       }
+      WebhookPullRequestReadyForReviewPullRequestItem2MergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit message:
 /// - `PR_BODY` - default to the pull request's body.
 /// - `COMMIT_MESSAGES` - default to the branch's commit messages.
@@ -5807,9 +8005,16 @@ pub enum WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitMessag
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5822,11 +8027,14 @@ impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCom
       WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitMessage::Blank => {
         "BLANK".to_string()
       }
+      WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit title:
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `COMMIT_OR_PR_TITLE` - default to the commit's title (if only one commit) or the pull request's title (when more than one commit).
@@ -5836,9 +8044,16 @@ pub enum WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitTitle
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5848,11 +8063,14 @@ impl ToString for WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCom
       WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      WebhookPullRequestReadyForReviewPullRequestItem2SquashMergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestReadyForReviewPullRequest {
@@ -5863,23 +8081,31 @@ pub enum WebhookPullRequestReadyForReviewPullRequest {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReopenedAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReopenedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReopenedAction::Reopened => "reopened".to_string(),
+      WebhookPullRequestReopenedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit message.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `PR_BODY` - default to the pull request's body.
@@ -5892,9 +8118,16 @@ pub enum WebhookPullRequestReopenedPullRequestItem2MergeCommitMessage {
   PrTitle,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReopenedPullRequestItem2MergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5903,11 +8136,12 @@ impl ToString for WebhookPullRequestReopenedPullRequestItem2MergeCommitMessage {
         "PR_TITLE".to_string()
       }
       WebhookPullRequestReopenedPullRequestItem2MergeCommitMessage::Blank => "BLANK".to_string(),
+      WebhookPullRequestReopenedPullRequestItem2MergeCommitMessage::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a merge commit title.
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `MERGE_MESSAGE` - default to the classic title for a merge message (e.g., "Merge pull request #123 from branch-name").
@@ -5917,9 +8151,16 @@ pub enum WebhookPullRequestReopenedPullRequestItem2MergeCommitTitle {
   PrTitle,
   #[serde(rename = "MERGE_MESSAGE")]
   MergeMessage,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReopenedPullRequestItem2MergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5927,11 +8168,12 @@ impl ToString for WebhookPullRequestReopenedPullRequestItem2MergeCommitTitle {
       WebhookPullRequestReopenedPullRequestItem2MergeCommitTitle::MergeMessage => {
         "MERGE_MESSAGE".to_string()
       }
+      WebhookPullRequestReopenedPullRequestItem2MergeCommitTitle::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit message:
 /// - `PR_BODY` - default to the pull request's body.
 /// - `COMMIT_MESSAGES` - default to the branch's commit messages.
@@ -5944,9 +8186,16 @@ pub enum WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitMessage {
   CommitMessages,
   #[serde(rename = "BLANK")]
   Blank,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitMessage {
   fn to_string(&self) -> String {
     match self {
@@ -5959,11 +8208,14 @@ impl ToString for WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitMes
       WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitMessage::Blank => {
         "BLANK".to_string()
       }
+      WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitMessage::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The default value for a squash merge commit title:
 /// - `PR_TITLE` - default to the pull request's title.
 /// - `COMMIT_OR_PR_TITLE` - default to the commit's title (if only one commit) or the pull request's title (when more than one commit).
@@ -5973,9 +8225,16 @@ pub enum WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitTitle {
   PrTitle,
   #[serde(rename = "COMMIT_OR_PR_TITLE")]
   CommitOrPrTitle,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitTitle {
   fn to_string(&self) -> String {
     match self {
@@ -5985,11 +8244,14 @@ impl ToString for WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitTit
       WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitTitle::CommitOrPrTitle => {
         "COMMIT_OR_PR_TITLE".to_string()
       }
+      WebhookPullRequestReopenedPullRequestItem2SquashMergeCommitTitle::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestReopenedPullRequest {
@@ -5998,18 +8260,26 @@ pub enum WebhookPullRequestReopenedPullRequest {
   WebhookPullRequestReopenedPullRequestItem2(WebhookPullRequestReopenedPullRequestItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewCommentCreatedAction::Created => "created".to_string(),
+      WebhookPullRequestReviewCommentCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -6034,6 +8304,13 @@ pub enum PullRequestReviewCommentAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "webhook",))]
@@ -6050,11 +8327,12 @@ impl ToString for PullRequestReviewCommentAuthorAssociation {
       PullRequestReviewCommentAuthorAssociation::Member => "MEMBER".to_string(),
       PullRequestReviewCommentAuthorAssociation::None => "NONE".to_string(),
       PullRequestReviewCommentAuthorAssociation::Owner => "OWNER".to_string(),
+      PullRequestReviewCommentAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentCreatedPullRequestActiveLockReason {
   #[serde(rename = "resolved")]
@@ -6065,9 +8343,16 @@ pub enum WebhookPullRequestReviewCommentCreatedPullRequestActiveLockReason {
   TooHeated,
   #[serde(rename = "spam")]
   Spam,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentCreatedPullRequestActiveLockReason {
   fn to_string(&self) -> String {
     match self {
@@ -6081,11 +8366,14 @@ impl ToString for WebhookPullRequestReviewCommentCreatedPullRequestActiveLockRea
         "too heated".to_string()
       }
       WebhookPullRequestReviewCommentCreatedPullRequestActiveLockReason::Spam => "spam".to_string(),
+      WebhookPullRequestReviewCommentCreatedPullRequestActiveLockReason::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentCreatedPullRequestAuthorAssociation {
@@ -6105,9 +8393,16 @@ pub enum WebhookPullRequestReviewCommentCreatedPullRequestAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentCreatedPullRequestAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -6135,46 +8430,65 @@ impl ToString for WebhookPullRequestReviewCommentCreatedPullRequestAuthorAssocia
       WebhookPullRequestReviewCommentCreatedPullRequestAuthorAssociation::Owner => {
         "OWNER".to_string()
       }
+      WebhookPullRequestReviewCommentCreatedPullRequestAuthorAssociation::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentCreatedPullRequestState {
   #[serde(rename = "open")]
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentCreatedPullRequestState {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewCommentCreatedPullRequestState::Open => "open".to_string(),
       WebhookPullRequestReviewCommentCreatedPullRequestState::Closed => "closed".to_string(),
+      WebhookPullRequestReviewCommentCreatedPullRequestState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentDeletedAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentDeletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewCommentDeletedAction::Deleted => "deleted".to_string(),
+      WebhookPullRequestReviewCommentDeletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentDeletedPullRequestActiveLockReason {
   #[serde(rename = "resolved")]
@@ -6185,9 +8499,16 @@ pub enum WebhookPullRequestReviewCommentDeletedPullRequestActiveLockReason {
   TooHeated,
   #[serde(rename = "spam")]
   Spam,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentDeletedPullRequestActiveLockReason {
   fn to_string(&self) -> String {
     match self {
@@ -6201,11 +8522,14 @@ impl ToString for WebhookPullRequestReviewCommentDeletedPullRequestActiveLockRea
         "too heated".to_string()
       }
       WebhookPullRequestReviewCommentDeletedPullRequestActiveLockReason::Spam => "spam".to_string(),
+      WebhookPullRequestReviewCommentDeletedPullRequestActiveLockReason::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentDeletedPullRequestAuthorAssociation {
@@ -6225,9 +8549,16 @@ pub enum WebhookPullRequestReviewCommentDeletedPullRequestAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentDeletedPullRequestAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -6255,46 +8586,65 @@ impl ToString for WebhookPullRequestReviewCommentDeletedPullRequestAuthorAssocia
       WebhookPullRequestReviewCommentDeletedPullRequestAuthorAssociation::Owner => {
         "OWNER".to_string()
       }
+      WebhookPullRequestReviewCommentDeletedPullRequestAuthorAssociation::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentDeletedPullRequestState {
   #[serde(rename = "open")]
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentDeletedPullRequestState {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewCommentDeletedPullRequestState::Open => "open".to_string(),
       WebhookPullRequestReviewCommentDeletedPullRequestState::Closed => "closed".to_string(),
+      WebhookPullRequestReviewCommentDeletedPullRequestState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentEditedAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentEditedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewCommentEditedAction::Edited => "edited".to_string(),
+      WebhookPullRequestReviewCommentEditedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentEditedPullRequestActiveLockReason {
   #[serde(rename = "resolved")]
@@ -6305,9 +8655,16 @@ pub enum WebhookPullRequestReviewCommentEditedPullRequestActiveLockReason {
   TooHeated,
   #[serde(rename = "spam")]
   Spam,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentEditedPullRequestActiveLockReason {
   fn to_string(&self) -> String {
     match self {
@@ -6321,11 +8678,14 @@ impl ToString for WebhookPullRequestReviewCommentEditedPullRequestActiveLockReas
         "too heated".to_string()
       }
       WebhookPullRequestReviewCommentEditedPullRequestActiveLockReason::Spam => "spam".to_string(),
+      WebhookPullRequestReviewCommentEditedPullRequestActiveLockReason::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentEditedPullRequestAuthorAssociation {
@@ -6345,9 +8705,16 @@ pub enum WebhookPullRequestReviewCommentEditedPullRequestAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentEditedPullRequestAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -6373,46 +8740,69 @@ impl ToString for WebhookPullRequestReviewCommentEditedPullRequestAuthorAssociat
       WebhookPullRequestReviewCommentEditedPullRequestAuthorAssociation::Owner => {
         "OWNER".to_string()
       }
+      WebhookPullRequestReviewCommentEditedPullRequestAuthorAssociation::Unknown(value) => {
+        value.clone()
+      }
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewCommentEditedPullRequestState {
   #[serde(rename = "open")]
   Open,
   #[serde(rename = "closed")]
   Closed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl ToString for WebhookPullRequestReviewCommentEditedPullRequestState {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewCommentEditedPullRequestState::Open => "open".to_string(),
       WebhookPullRequestReviewCommentEditedPullRequestState::Closed => "closed".to_string(),
+      WebhookPullRequestReviewCommentEditedPullRequestState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewDismissedAction {
   #[serde(rename = "dismissed")]
   Dismissed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl ToString for WebhookPullRequestReviewDismissedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewDismissedAction::Dismissed => "dismissed".to_string(),
+      WebhookPullRequestReviewDismissedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SimplePullRequestActiveLockReason {
   #[serde(rename = "resolved")]
@@ -6423,9 +8813,20 @@ pub enum SimplePullRequestActiveLockReason {
   TooHeated,
   #[serde(rename = "spam")]
   Spam,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl ToString for SimplePullRequestActiveLockReason {
   fn to_string(&self) -> String {
     match self {
@@ -6433,11 +8834,16 @@ impl ToString for SimplePullRequestActiveLockReason {
       SimplePullRequestActiveLockReason::OffTopic => "off-topic".to_string(),
       SimplePullRequestActiveLockReason::TooHeated => "too heated".to_string(),
       SimplePullRequestActiveLockReason::Spam => "spam".to_string(),
+      SimplePullRequestActiveLockReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SimplePullRequestAuthorAssociation {
@@ -6457,9 +8863,20 @@ pub enum SimplePullRequestAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl ToString for SimplePullRequestAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -6473,30 +8890,47 @@ impl ToString for SimplePullRequestAuthorAssociation {
       SimplePullRequestAuthorAssociation::Member => "MEMBER".to_string(),
       SimplePullRequestAuthorAssociation::None => "NONE".to_string(),
       SimplePullRequestAuthorAssociation::Owner => "OWNER".to_string(),
+      SimplePullRequestAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SimplePullRequestState {
   #[serde(rename = "open")]
   Open,
   #[serde(rename = "closed")]
   Closed,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl ToString for SimplePullRequestState {
   fn to_string(&self) -> String {
     match self {
       SimplePullRequestState::Open => "open".to_string(),
       SimplePullRequestState::Closed => "closed".to_string(),
+      SimplePullRequestState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewDismissedReviewAuthorAssociation {
@@ -6516,9 +8950,16 @@ pub enum WebhookPullRequestReviewDismissedReviewAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl ToString for WebhookPullRequestReviewDismissedReviewAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -6540,11 +8981,12 @@ impl ToString for WebhookPullRequestReviewDismissedReviewAuthorAssociation {
       WebhookPullRequestReviewDismissedReviewAuthorAssociation::Member => "MEMBER".to_string(),
       WebhookPullRequestReviewDismissedReviewAuthorAssociation::None => "NONE".to_string(),
       WebhookPullRequestReviewDismissedReviewAuthorAssociation::Owner => "OWNER".to_string(),
+      WebhookPullRequestReviewDismissedReviewAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewDismissedReviewState {
   #[serde(rename = "dismissed")]
@@ -6553,9 +8995,16 @@ pub enum WebhookPullRequestReviewDismissedReviewState {
   Approved,
   #[serde(rename = "changes_requested")]
   ChangesRequested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl ToString for WebhookPullRequestReviewDismissedReviewState {
   fn to_string(&self) -> String {
     match self {
@@ -6564,27 +9013,36 @@ impl ToString for WebhookPullRequestReviewDismissedReviewState {
       WebhookPullRequestReviewDismissedReviewState::ChangesRequested => {
         "changes_requested".to_string()
       }
+      WebhookPullRequestReviewDismissedReviewState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewEditedAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl ToString for WebhookPullRequestReviewEditedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewEditedAction::Edited => "edited".to_string(),
+      WebhookPullRequestReviewEditedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewEditedReviewAuthorAssociation {
@@ -6604,9 +9062,16 @@ pub enum WebhookPullRequestReviewEditedReviewAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl ToString for WebhookPullRequestReviewEditedReviewAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -6626,47 +9091,64 @@ impl ToString for WebhookPullRequestReviewEditedReviewAuthorAssociation {
       WebhookPullRequestReviewEditedReviewAuthorAssociation::Member => "MEMBER".to_string(),
       WebhookPullRequestReviewEditedReviewAuthorAssociation::None => "NONE".to_string(),
       WebhookPullRequestReviewEditedReviewAuthorAssociation::Owner => "OWNER".to_string(),
+      WebhookPullRequestReviewEditedReviewAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewRequestRemovedItem1Action {
   #[serde(rename = "review_request_removed")]
   ReviewRequestRemoved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReviewRequestRemovedItem1Action {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewRequestRemovedItem1Action::ReviewRequestRemoved => {
         "review_request_removed".to_string()
       }
+      WebhookPullRequestReviewRequestRemovedItem1Action::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewRequestRemovedItem2Action {
   #[serde(rename = "review_request_removed")]
   ReviewRequestRemoved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReviewRequestRemovedItem2Action {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewRequestRemovedItem2Action::ReviewRequestRemoved => {
         "review_request_removed".to_string()
       }
+      WebhookPullRequestReviewRequestRemovedItem2Action::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestReviewRequestRemoved {
@@ -6674,43 +9156,59 @@ pub enum WebhookPullRequestReviewRequestRemoved {
   WebhookPullRequestReviewRequestRemovedItem2(WebhookPullRequestReviewRequestRemovedItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewRequestedItem1Action {
   #[serde(rename = "review_requested")]
   ReviewRequested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReviewRequestedItem1Action {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewRequestedItem1Action::ReviewRequested => {
         "review_requested".to_string()
       }
+      WebhookPullRequestReviewRequestedItem1Action::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewRequestedItem2Action {
   #[serde(rename = "review_requested")]
   ReviewRequested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestReviewRequestedItem2Action {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewRequestedItem2Action::ReviewRequested => {
         "review_requested".to_string()
       }
+      WebhookPullRequestReviewRequestedItem2Action::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookPullRequestReviewRequested {
@@ -6718,23 +9216,31 @@ pub enum WebhookPullRequestReviewRequested {
   WebhookPullRequestReviewRequestedItem2(WebhookPullRequestReviewRequestedItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewSubmittedAction {
   #[serde(rename = "submitted")]
   Submitted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl ToString for WebhookPullRequestReviewSubmittedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewSubmittedAction::Submitted => "submitted".to_string(),
+      WebhookPullRequestReviewSubmittedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 /// How the author is associated with the repository.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewSubmittedReviewAuthorAssociation {
@@ -6754,9 +9260,16 @@ pub enum WebhookPullRequestReviewSubmittedReviewAuthorAssociation {
   None,
   #[serde(rename = "OWNER")]
   Owner,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl ToString for WebhookPullRequestReviewSubmittedReviewAuthorAssociation {
   fn to_string(&self) -> String {
     match self {
@@ -6778,210 +9291,307 @@ impl ToString for WebhookPullRequestReviewSubmittedReviewAuthorAssociation {
       WebhookPullRequestReviewSubmittedReviewAuthorAssociation::Member => "MEMBER".to_string(),
       WebhookPullRequestReviewSubmittedReviewAuthorAssociation::None => "NONE".to_string(),
       WebhookPullRequestReviewSubmittedReviewAuthorAssociation::Owner => "OWNER".to_string(),
+      WebhookPullRequestReviewSubmittedReviewAuthorAssociation::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewThreadResolvedAction {
   #[serde(rename = "resolved")]
   Resolved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 impl ToString for WebhookPullRequestReviewThreadResolvedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewThreadResolvedAction::Resolved => "resolved".to_string(),
+      WebhookPullRequestReviewThreadResolvedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestReviewThreadUnresolvedAction {
   #[serde(rename = "unresolved")]
   Unresolved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 impl ToString for WebhookPullRequestReviewThreadUnresolvedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestReviewThreadUnresolvedAction::Unresolved => "unresolved".to_string(),
+      WebhookPullRequestReviewThreadUnresolvedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestSynchronizeAction {
   #[serde(rename = "synchronize")]
   Synchronize,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestSynchronizeAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestSynchronizeAction::Synchronize => "synchronize".to_string(),
+      WebhookPullRequestSynchronizeAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestUnassignedAction {
   #[serde(rename = "unassigned")]
   Unassigned,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestUnassignedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestUnassignedAction::Unassigned => "unassigned".to_string(),
+      WebhookPullRequestUnassignedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestUnlabeledAction {
   #[serde(rename = "unlabeled")]
   Unlabeled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestUnlabeledAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestUnlabeledAction::Unlabeled => "unlabeled".to_string(),
+      WebhookPullRequestUnlabeledAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookPullRequestUnlockedAction {
   #[serde(rename = "unlocked")]
   Unlocked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl ToString for WebhookPullRequestUnlockedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookPullRequestUnlockedAction::Unlocked => "unlocked".to_string(),
+      WebhookPullRequestUnlockedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRegistryPackagePublishedAction {
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl ToString for WebhookRegistryPackagePublishedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookRegistryPackagePublishedAction::Published => "published".to_string(),
+      WebhookRegistryPackagePublishedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRegistryPackageUpdatedAction {
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl ToString for WebhookRegistryPackageUpdatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookRegistryPackageUpdatedAction::Updated => "updated".to_string(),
+      WebhookRegistryPackageUpdatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleaseCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ToString for ReleaseCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       ReleaseCreatedEventAction::Created => "created".to_string(),
+      ReleaseCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleaseDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ToString for ReleaseDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       ReleaseDeletedEventAction::Deleted => "deleted".to_string(),
+      ReleaseDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleaseEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ToString for ReleaseEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       ReleaseEditedEventAction::Edited => "edited".to_string(),
+      ReleaseEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleasePrereleasedEventAction {
   #[serde(rename = "prereleased")]
   Prereleased,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ToString for ReleasePrereleasedEventAction {
   fn to_string(&self) -> String {
     match self {
       ReleasePrereleasedEventAction::Prereleased => "prereleased".to_string(),
+      ReleasePrereleasedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 /// Whether the release is identified as a prerelease or a full release.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ReleasePrereleasedEventReleaseItem2Prerelease {
   Boolean(bool),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ReleasePrereleasedEventRelease {
@@ -6990,23 +9600,31 @@ pub enum ReleasePrereleasedEventRelease {
   ReleasePrereleasedEventReleaseItem2(ReleasePrereleasedEventReleaseItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleasePublishedEventAction {
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ToString for ReleasePublishedEventAction {
   fn to_string(&self) -> String {
     match self {
       ReleasePublishedEventAction::Published => "published".to_string(),
+      ReleasePublishedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ReleasePublishedEventRelease {
@@ -7015,39 +9633,55 @@ pub enum ReleasePublishedEventRelease {
   ReleasePublishedEventReleaseItem2(ReleasePublishedEventReleaseItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleaseReleasedEventAction {
   #[serde(rename = "released")]
   Released,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ToString for ReleaseReleasedEventAction {
   fn to_string(&self) -> String {
     match self {
       ReleaseReleasedEventAction::Released => "released".to_string(),
+      ReleaseReleasedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum ReleaseUnpublishedEventAction {
   #[serde(rename = "unpublished")]
   Unpublished,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ToString for ReleaseUnpublishedEventAction {
   fn to_string(&self) -> String {
     match self {
       ReleaseUnpublishedEventAction::Unpublished => "unpublished".to_string(),
+      ReleaseUnpublishedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ReleaseUnpublishedEventRelease {
@@ -7056,103 +9690,151 @@ pub enum ReleaseUnpublishedEventRelease {
   ReleaseUnpublishedEventReleaseItem2(ReleaseUnpublishedEventReleaseItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryAdvisoryPublishedEventAction {
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 impl ToString for RepositoryAdvisoryPublishedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryAdvisoryPublishedEventAction::Published => "published".to_string(),
+      RepositoryAdvisoryPublishedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryAdvisoryReportedEventAction {
   #[serde(rename = "reported")]
   Reported,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 impl ToString for RepositoryAdvisoryReportedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryAdvisoryReportedEventAction::Reported => "reported".to_string(),
+      RepositoryAdvisoryReportedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryArchivedEventAction {
   #[serde(rename = "archived")]
   Archived,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryArchivedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryArchivedEventAction::Archived => "archived".to_string(),
+      RepositoryArchivedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryCreatedEventAction::Created => "created".to_string(),
+      RepositoryCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryDeletedEventAction::Deleted => "deleted".to_string(),
+      RepositoryDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryEditedEventAction::Edited => "edited".to_string(),
+      RepositoryEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_import",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryImportStatus {
   #[serde(rename = "success")]
@@ -7161,164 +9843,244 @@ pub enum WebhookRepositoryImportStatus {
   Cancelled,
   #[serde(rename = "failure")]
   Failure,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_import",))]
 impl ToString for WebhookRepositoryImportStatus {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryImportStatus::Success => "success".to_string(),
       WebhookRepositoryImportStatus::Cancelled => "cancelled".to_string(),
       WebhookRepositoryImportStatus::Failure => "failure".to_string(),
+      WebhookRepositoryImportStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryPrivatizedEventAction {
   #[serde(rename = "privatized")]
   Privatized,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryPrivatizedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryPrivatizedEventAction::Privatized => "privatized".to_string(),
+      RepositoryPrivatizedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryPublicizedEventAction {
   #[serde(rename = "publicized")]
   Publicized,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryPublicizedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryPublicizedEventAction::Publicized => "publicized".to_string(),
+      RepositoryPublicizedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRenamedEventAction {
   #[serde(rename = "renamed")]
   Renamed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryRenamedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryRenamedEventAction::Renamed => "renamed".to_string(),
+      RepositoryRenamedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulesetCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl ToString for RepositoryRulesetCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulesetCreatedEventAction::Created => "created".to_string(),
+      RepositoryRulesetCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulesetDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl ToString for RepositoryRulesetDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulesetDeletedEventAction::Deleted => "deleted".to_string(),
+      RepositoryRulesetDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryRulesetEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl ToString for RepositoryRulesetEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryRulesetEditedEventAction::Edited => "edited".to_string(),
+      RepositoryRulesetEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryTransferredEventAction {
   #[serde(rename = "transferred")]
   Transferred,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryTransferredEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryTransferredEventAction::Transferred => "transferred".to_string(),
+      RepositoryTransferredEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryUnarchivedEventAction {
   #[serde(rename = "unarchived")]
   Unarchived,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl ToString for RepositoryUnarchivedEventAction {
   fn to_string(&self) -> String {
     match self {
       RepositoryUnarchivedEventAction::Unarchived => "unarchived".to_string(),
+      RepositoryUnarchivedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertCreateAction {
   #[serde(rename = "create")]
   Create,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertCreateAction {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertCreateAction::Create => "create".to_string(),
+      WebhookRepositoryVulnerabilityAlertCreateAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum RepositoryVulnerabilityAlertAlertState {
   #[serde(rename = "open")]
@@ -7327,36 +10089,52 @@ pub enum RepositoryVulnerabilityAlertAlertState {
   Dismissed,
   #[serde(rename = "fixed")]
   Fixed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for RepositoryVulnerabilityAlertAlertState {
   fn to_string(&self) -> String {
     match self {
       RepositoryVulnerabilityAlertAlertState::Open => "open".to_string(),
       RepositoryVulnerabilityAlertAlertState::Dismissed => "dismissed".to_string(),
       RepositoryVulnerabilityAlertAlertState::Fixed => "fixed".to_string(),
+      RepositoryVulnerabilityAlertAlertState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertCreateAlertItem2State {
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertCreateAlertItem2State {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertCreateAlertItem2State::Open => "open".to_string(),
+      WebhookRepositoryVulnerabilityAlertCreateAlertItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookRepositoryVulnerabilityAlertCreateAlert {
@@ -7367,41 +10145,57 @@ pub enum WebhookRepositoryVulnerabilityAlertCreateAlert {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertDismissAction {
   #[serde(rename = "dismiss")]
   Dismiss,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertDismissAction {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertDismissAction::Dismiss => "dismiss".to_string(),
+      WebhookRepositoryVulnerabilityAlertDismissAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertDismissAlertItem2State {
   #[serde(rename = "dismissed")]
   Dismissed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertDismissAlertItem2State {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertDismissAlertItem2State::Dismissed => {
         "dismissed".to_string()
       }
+      WebhookRepositoryVulnerabilityAlertDismissAlertItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookRepositoryVulnerabilityAlertDismissAlert {
@@ -7412,39 +10206,55 @@ pub enum WebhookRepositoryVulnerabilityAlertDismissAlert {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertReopenAction {
   #[serde(rename = "reopen")]
   Reopen,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertReopenAction {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertReopenAction::Reopen => "reopen".to_string(),
+      WebhookRepositoryVulnerabilityAlertReopenAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertReopenAlertItem2State {
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertReopenAlertItem2State {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertReopenAlertItem2State::Open => "open".to_string(),
+      WebhookRepositoryVulnerabilityAlertReopenAlertItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookRepositoryVulnerabilityAlertReopenAlert {
@@ -7455,42 +10265,58 @@ pub enum WebhookRepositoryVulnerabilityAlertReopenAlert {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertResolveAction {
   #[serde(rename = "resolve")]
   Resolve,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertResolveAction {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertResolveAction::Resolve => "resolve".to_string(),
+      WebhookRepositoryVulnerabilityAlertResolveAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookRepositoryVulnerabilityAlertResolveAlertItem2State {
   #[serde(rename = "fixed")]
   Fixed,
   #[serde(rename = "open")]
   Open,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl ToString for WebhookRepositoryVulnerabilityAlertResolveAlertItem2State {
   fn to_string(&self) -> String {
     match self {
       WebhookRepositoryVulnerabilityAlertResolveAlertItem2State::Fixed => "fixed".to_string(),
       WebhookRepositoryVulnerabilityAlertResolveAlertItem2State::Open => "open".to_string(),
+      WebhookRepositoryVulnerabilityAlertResolveAlertItem2State::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookRepositoryVulnerabilityAlertResolveAlert {
@@ -7501,23 +10327,35 @@ pub enum WebhookRepositoryVulnerabilityAlertResolveAlert {
   ),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecretScanningAlertCreatedAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl ToString for WebhookSecretScanningAlertCreatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecretScanningAlertCreatedAction::Created => "created".to_string(),
+      WebhookSecretScanningAlertCreatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// The reason for resolving the alert.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecretScanningAlertResolutionWebhook {
@@ -7533,9 +10371,20 @@ pub enum SecretScanningAlertResolutionWebhook {
   PatternDeleted,
   #[serde(rename = "pattern_edited")]
   PatternEdited,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl ToString for SecretScanningAlertResolutionWebhook {
   fn to_string(&self) -> String {
     match self {
@@ -7545,11 +10394,16 @@ impl ToString for SecretScanningAlertResolutionWebhook {
       SecretScanningAlertResolutionWebhook::UsedInTests => "used_in_tests".to_string(),
       SecretScanningAlertResolutionWebhook::PatternDeleted => "pattern_deleted".to_string(),
       SecretScanningAlertResolutionWebhook::PatternEdited => "pattern_edited".to_string(),
+      SecretScanningAlertResolutionWebhook::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 /// The token status as of the latest validity check.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecretScanningAlertWebhookValidity {
@@ -7559,280 +10413,420 @@ pub enum SecretScanningAlertWebhookValidity {
   Inactive,
   #[serde(rename = "unknown")]
   Unknown,
-}
-
-#[cfg(any(feature = "full", feature = "webhook",))]
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Other(String),
+}
+
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl ToString for SecretScanningAlertWebhookValidity {
   fn to_string(&self) -> String {
     match self {
       SecretScanningAlertWebhookValidity::Active => "active".to_string(),
       SecretScanningAlertWebhookValidity::Inactive => "inactive".to_string(),
       SecretScanningAlertWebhookValidity::Unknown => "unknown".to_string(),
+      SecretScanningAlertWebhookValidity::Other(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SecretScanningAlertLocationCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
 impl ToString for SecretScanningAlertLocationCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       SecretScanningAlertLocationCreatedEventAction::Created => "created".to_string(),
+      SecretScanningAlertLocationCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecretScanningAlertReopenedAction {
   #[serde(rename = "reopened")]
   Reopened,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl ToString for WebhookSecretScanningAlertReopenedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecretScanningAlertReopenedAction::Reopened => "reopened".to_string(),
+      WebhookSecretScanningAlertReopenedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecretScanningAlertResolvedAction {
   #[serde(rename = "resolved")]
   Resolved,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl ToString for WebhookSecretScanningAlertResolvedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecretScanningAlertResolvedAction::Resolved => "resolved".to_string(),
+      WebhookSecretScanningAlertResolvedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecretScanningAlertRevokedAction {
   #[serde(rename = "revoked")]
   Revoked,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl ToString for WebhookSecretScanningAlertRevokedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecretScanningAlertRevokedAction::Revoked => "revoked".to_string(),
+      WebhookSecretScanningAlertRevokedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecretScanningAlertValidatedAction {
   #[serde(rename = "validated")]
   Validated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl ToString for WebhookSecretScanningAlertValidatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecretScanningAlertValidatedAction::Validated => "validated".to_string(),
+      WebhookSecretScanningAlertValidatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecurityAdvisoryPublishedAction {
   #[serde(rename = "published")]
   Published,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl ToString for WebhookSecurityAdvisoryPublishedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecurityAdvisoryPublishedAction::Published => "published".to_string(),
+      WebhookSecurityAdvisoryPublishedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecurityAdvisoryUpdatedAction {
   #[serde(rename = "updated")]
   Updated,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl ToString for WebhookSecurityAdvisoryUpdatedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecurityAdvisoryUpdatedAction::Updated => "updated".to_string(),
+      WebhookSecurityAdvisoryUpdatedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSecurityAdvisoryWithdrawnAction {
   #[serde(rename = "withdrawn")]
   Withdrawn,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl ToString for WebhookSecurityAdvisoryWithdrawnAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSecurityAdvisoryWithdrawnAction::Withdrawn => "withdrawn".to_string(),
+      WebhookSecurityAdvisoryWithdrawnAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SponsorshipCancelledEventAction {
   #[serde(rename = "cancelled")]
   Cancelled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl ToString for SponsorshipCancelledEventAction {
   fn to_string(&self) -> String {
     match self {
       SponsorshipCancelledEventAction::Cancelled => "cancelled".to_string(),
+      SponsorshipCancelledEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SponsorshipCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl ToString for SponsorshipCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       SponsorshipCreatedEventAction::Created => "created".to_string(),
+      SponsorshipCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SponsorshipEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl ToString for SponsorshipEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       SponsorshipEditedEventAction::Edited => "edited".to_string(),
+      SponsorshipEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSponsorshipPendingCancellationAction {
   #[serde(rename = "pending_cancellation")]
   PendingCancellation,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl ToString for WebhookSponsorshipPendingCancellationAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSponsorshipPendingCancellationAction::PendingCancellation => {
         "pending_cancellation".to_string()
       }
+      WebhookSponsorshipPendingCancellationAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSponsorshipPendingTierChangeAction {
   #[serde(rename = "pending_tier_change")]
   PendingTierChange,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl ToString for WebhookSponsorshipPendingTierChangeAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSponsorshipPendingTierChangeAction::PendingTierChange => {
         "pending_tier_change".to_string()
       }
+      WebhookSponsorshipPendingTierChangeAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookSponsorshipTierChangedAction {
   #[serde(rename = "tier_changed")]
   TierChanged,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl ToString for WebhookSponsorshipTierChangedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookSponsorshipTierChangedAction::TierChanged => "tier_changed".to_string(),
+      WebhookSponsorshipTierChangedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum StarCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 impl ToString for StarCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       StarCreatedEventAction::Created => "created".to_string(),
+      StarCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum StarDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 impl ToString for StarDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       StarDeletedEventAction::Deleted => "deleted".to_string(),
+      StarDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum StatusEventCommitCommitAuthor {
@@ -7841,7 +10835,7 @@ pub enum StatusEventCommitCommitAuthor {
   StatusEventCommitCommitAuthorItem2(StatusEventCommitCommitAuthorItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum StatusEventCommitCommitCommitter {
@@ -7850,7 +10844,7 @@ pub enum StatusEventCommitCommitCommitter {
   StatusEventCommitCommitCommitterItem2(StatusEventCommitCommitCommitterItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum StatusEventCommitCommitVerificationReason {
   #[serde(rename = "expired_key")]
@@ -7883,9 +10877,16 @@ pub enum StatusEventCommitCommitVerificationReason {
   BadCert,
   #[serde(rename = "ocsp_pending")]
   OcspPending,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl ToString for StatusEventCommitCommitVerificationReason {
   fn to_string(&self) -> String {
     match self {
@@ -7910,11 +10911,12 @@ impl ToString for StatusEventCommitCommitVerificationReason {
       StatusEventCommitCommitVerificationReason::Valid => "valid".to_string(),
       StatusEventCommitCommitVerificationReason::BadCert => "bad_cert".to_string(),
       StatusEventCommitCommitVerificationReason::OcspPending => "ocsp_pending".to_string(),
+      StatusEventCommitCommitVerificationReason::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 /// The new state. Can be `pending`, `success`, `failure`, or `error`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum StatusEventState {
@@ -7926,9 +10928,16 @@ pub enum StatusEventState {
   Failure,
   #[serde(rename = "error")]
   Error,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl ToString for StatusEventState {
   fn to_string(&self) -> String {
     match self {
@@ -7936,125 +10945,182 @@ impl ToString for StatusEventState {
       StatusEventState::Success => "success".to_string(),
       StatusEventState::Failure => "failure".to_string(),
       StatusEventState::Error => "error".to_string(),
+      StatusEventState::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookTeamAddedToRepositoryAction {
   #[serde(rename = "added_to_repository")]
   AddedToRepository,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl ToString for WebhookTeamAddedToRepositoryAction {
   fn to_string(&self) -> String {
     match self {
       WebhookTeamAddedToRepositoryAction::AddedToRepository => "added_to_repository".to_string(),
+      WebhookTeamAddedToRepositoryAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TeamCreatedEventAction {
   #[serde(rename = "created")]
   Created,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl ToString for TeamCreatedEventAction {
   fn to_string(&self) -> String {
     match self {
       TeamCreatedEventAction::Created => "created".to_string(),
+      TeamCreatedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TeamDeletedEventAction {
   #[serde(rename = "deleted")]
   Deleted,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl ToString for TeamDeletedEventAction {
   fn to_string(&self) -> String {
     match self {
       TeamDeletedEventAction::Deleted => "deleted".to_string(),
+      TeamDeletedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum TeamEditedEventAction {
   #[serde(rename = "edited")]
   Edited,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl ToString for TeamEditedEventAction {
   fn to_string(&self) -> String {
     match self {
       TeamEditedEventAction::Edited => "edited".to_string(),
+      TeamEditedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookTeamRemovedFromRepositoryAction {
   #[serde(rename = "removed_from_repository")]
   RemovedFromRepository,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl ToString for WebhookTeamRemovedFromRepositoryAction {
   fn to_string(&self) -> String {
     match self {
       WebhookTeamRemovedFromRepositoryAction::RemovedFromRepository => {
         "removed_from_repository".to_string()
       }
+      WebhookTeamRemovedFromRepositoryAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_watch",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WatchStartedEventAction {
   #[serde(rename = "started")]
   Started,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_watch",))]
 impl ToString for WatchStartedEventAction {
   fn to_string(&self) -> String {
     match self {
       WatchStartedEventAction::Started => "started".to_string(),
+      WatchStartedEventAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobCompletedAction {
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobCompletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowJobCompletedAction::Completed => "completed".to_string(),
+      WebhookWorkflowJobCompletedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WorkflowJobConclusion {
   #[serde(rename = "success")]
@@ -8071,9 +11137,16 @@ pub enum WorkflowJobConclusion {
   Neutral,
   #[serde(rename = "timed_out")]
   TimedOut,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WorkflowJobConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -8084,11 +11157,12 @@ impl ToString for WorkflowJobConclusion {
       WorkflowJobConclusion::ActionRequired => "action_required".to_string(),
       WorkflowJobConclusion::Neutral => "neutral".to_string(),
       WorkflowJobConclusion::TimedOut => "timed_out".to_string(),
+      WorkflowJobConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 /// The current status of the job. Can be `queued`, `in_progress`, `waiting`, or `completed`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WorkflowJobStatus {
@@ -8100,9 +11174,16 @@ pub enum WorkflowJobStatus {
   Completed,
   #[serde(rename = "waiting")]
   Waiting,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WorkflowJobStatus {
   fn to_string(&self) -> String {
     match self {
@@ -8110,11 +11191,12 @@ impl ToString for WorkflowJobStatus {
       WorkflowJobStatus::InProgress => "in_progress".to_string(),
       WorkflowJobStatus::Completed => "completed".to_string(),
       WorkflowJobStatus::Waiting => "waiting".to_string(),
+      WorkflowJobStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WorkflowStepConclusion {
   #[serde(rename = "failure")]
@@ -8125,9 +11207,16 @@ pub enum WorkflowStepConclusion {
   Success,
   #[serde(rename = "cancelled")]
   Cancelled,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WorkflowStepConclusion {
   fn to_string(&self) -> String {
     match self {
@@ -8135,11 +11224,12 @@ impl ToString for WorkflowStepConclusion {
       WorkflowStepConclusion::Skipped => "skipped".to_string(),
       WorkflowStepConclusion::Success => "success".to_string(),
       WorkflowStepConclusion::Cancelled => "cancelled".to_string(),
+      WorkflowStepConclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WorkflowStepStatus {
   #[serde(rename = "in_progress")]
@@ -8148,20 +11238,28 @@ pub enum WorkflowStepStatus {
   Completed,
   #[serde(rename = "queued")]
   Queued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WorkflowStepStatus {
   fn to_string(&self) -> String {
     match self {
       WorkflowStepStatus::InProgress => "in_progress".to_string(),
       WorkflowStepStatus::Completed => "completed".to_string(),
       WorkflowStepStatus::Queued => "queued".to_string(),
+      WorkflowStepStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion {
   #[serde(rename = "success")]
@@ -8178,9 +11276,16 @@ pub enum WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion {
   Neutral,
   #[serde(rename = "timed_out")]
   TimedOut,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion {
   fn to_string(&self) -> String {
     match self {
@@ -8193,11 +11298,12 @@ impl ToString for WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion {
       }
       WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion::Neutral => "neutral".to_string(),
       WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion::TimedOut => "timed_out".to_string(),
+      WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookWorkflowJobCompletedWorkflowJob {
@@ -8206,23 +11312,31 @@ pub enum WebhookWorkflowJobCompletedWorkflowJob {
   WebhookWorkflowJobCompletedWorkflowJobItem2(WebhookWorkflowJobCompletedWorkflowJobItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobInProgressAction {
   #[serde(rename = "in_progress")]
   InProgress,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobInProgressAction {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowJobInProgressAction::InProgress => "in_progress".to_string(),
+      WebhookWorkflowJobInProgressAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobInProgressWorkflowJobItem2Status {
   #[serde(rename = "in_progress")]
@@ -8231,20 +11345,28 @@ pub enum WebhookWorkflowJobInProgressWorkflowJobItem2Status {
   Completed,
   #[serde(rename = "queued")]
   Queued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobInProgressWorkflowJobItem2Status {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowJobInProgressWorkflowJobItem2Status::InProgress => "in_progress".to_string(),
       WebhookWorkflowJobInProgressWorkflowJobItem2Status::Completed => "completed".to_string(),
       WebhookWorkflowJobInProgressWorkflowJobItem2Status::Queued => "queued".to_string(),
+      WebhookWorkflowJobInProgressWorkflowJobItem2Status::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookWorkflowJobInProgressWorkflowJob {
@@ -8253,23 +11375,31 @@ pub enum WebhookWorkflowJobInProgressWorkflowJob {
   WebhookWorkflowJobInProgressWorkflowJobItem2(WebhookWorkflowJobInProgressWorkflowJobItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobQueuedAction {
   #[serde(rename = "queued")]
   Queued,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobQueuedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowJobQueuedAction::Queued => "queued".to_string(),
+      WebhookWorkflowJobQueuedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobQueuedWorkflowJobStatus {
   #[serde(rename = "queued")]
@@ -8280,9 +11410,16 @@ pub enum WebhookWorkflowJobQueuedWorkflowJobStatus {
   Completed,
   #[serde(rename = "waiting")]
   Waiting,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobQueuedWorkflowJobStatus {
   fn to_string(&self) -> String {
     match self {
@@ -8290,27 +11427,36 @@ impl ToString for WebhookWorkflowJobQueuedWorkflowJobStatus {
       WebhookWorkflowJobQueuedWorkflowJobStatus::InProgress => "in_progress".to_string(),
       WebhookWorkflowJobQueuedWorkflowJobStatus::Completed => "completed".to_string(),
       WebhookWorkflowJobQueuedWorkflowJobStatus::Waiting => "waiting".to_string(),
+      WebhookWorkflowJobQueuedWorkflowJobStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobWaitingAction {
   #[serde(rename = "waiting")]
   Waiting,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobWaitingAction {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowJobWaitingAction::Waiting => "waiting".to_string(),
+      WebhookWorkflowJobWaitingAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowJobWaitingWorkflowJobStatus {
   #[serde(rename = "queued")]
@@ -8321,9 +11467,16 @@ pub enum WebhookWorkflowJobWaitingWorkflowJobStatus {
   Completed,
   #[serde(rename = "waiting")]
   Waiting,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl ToString for WebhookWorkflowJobWaitingWorkflowJobStatus {
   fn to_string(&self) -> String {
     match self {
@@ -8331,22 +11484,31 @@ impl ToString for WebhookWorkflowJobWaitingWorkflowJobStatus {
       WebhookWorkflowJobWaitingWorkflowJobStatus::InProgress => "in_progress".to_string(),
       WebhookWorkflowJobWaitingWorkflowJobStatus::Completed => "completed".to_string(),
       WebhookWorkflowJobWaitingWorkflowJobStatus::Waiting => "waiting".to_string(),
+      WebhookWorkflowJobWaitingWorkflowJobStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowRunCompletedAction {
   #[serde(rename = "completed")]
   Completed,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl ToString for WebhookWorkflowRunCompletedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowRunCompletedAction::Completed => "completed".to_string(),
+      WebhookWorkflowRunCompletedAction::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8370,6 +11532,13 @@ pub enum WorkflowRunConclusion {
   Stale,
   #[serde(rename = "skipped")]
   Skipped,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "webhook",))]
@@ -8384,6 +11553,7 @@ impl ToString for WorkflowRunConclusion {
       WorkflowRunConclusion::ActionRequired => "action_required".to_string(),
       WorkflowRunConclusion::Stale => "stale".to_string(),
       WorkflowRunConclusion::Skipped => "skipped".to_string(),
+      WorkflowRunConclusion::Unknown(value) => value.clone(),
     }
   }
 }
@@ -8403,6 +11573,13 @@ pub enum WorkflowRunStatus {
   Pending,
   #[serde(rename = "waiting")]
   Waiting,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
 #[cfg(any(feature = "full", feature = "webhook",))]
@@ -8415,11 +11592,12 @@ impl ToString for WorkflowRunStatus {
       WorkflowRunStatus::Queued => "queued".to_string(),
       WorkflowRunStatus::Pending => "pending".to_string(),
       WorkflowRunStatus::Waiting => "waiting".to_string(),
+      WorkflowRunStatus::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion {
   #[serde(rename = "success")]
@@ -8438,9 +11616,16 @@ pub enum WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion {
   Stale,
   #[serde(rename = "skipped")]
   Skipped,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl ToString for WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion {
   fn to_string(&self) -> String {
     match self {
@@ -8454,11 +11639,12 @@ impl ToString for WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion {
       }
       WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion::Stale => "stale".to_string(),
       WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion::Skipped => "skipped".to_string(),
+      WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookWorkflowRunCompletedWorkflowRun {
@@ -8466,23 +11652,31 @@ pub enum WebhookWorkflowRunCompletedWorkflowRun {
   WebhookWorkflowRunCompletedWorkflowRunItem2(WebhookWorkflowRunCompletedWorkflowRunItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowRunInProgressAction {
   #[serde(rename = "in_progress")]
   InProgress,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl ToString for WebhookWorkflowRunInProgressAction {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowRunInProgressAction::InProgress => "in_progress".to_string(),
+      WebhookWorkflowRunInProgressAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion {
   #[serde(rename = "success")]
@@ -8501,9 +11695,16 @@ pub enum WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion {
   Skipped,
   #[serde(rename = "stale")]
   Stale,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl ToString for WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion {
   fn to_string(&self) -> String {
     match self {
@@ -8517,11 +11718,12 @@ impl ToString for WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion {
       }
       WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion::Skipped => "skipped".to_string(),
       WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion::Stale => "stale".to_string(),
+      WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookWorkflowRunInProgressWorkflowRun {
@@ -8529,274 +11731,597 @@ pub enum WebhookWorkflowRunInProgressWorkflowRun {
   WebhookWorkflowRunInProgressWorkflowRunItem2(WebhookWorkflowRunInProgressWorkflowRunItem2),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WebhookWorkflowRunRequestedAction {
   #[serde(rename = "requested")]
   Requested,
+  /// A value added by GitHub after this version, rejected by the `strict` feature
+  #[serde(untagged)]
+  #[cfg_attr(
+    feature = "strict",
+    serde(deserialize_with = "crate::reject_unknown_variant")
+  )]
+  Unknown(String),
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl ToString for WebhookWorkflowRunRequestedAction {
   fn to_string(&self) -> String {
     match self {
       WebhookWorkflowRunRequestedAction::Requested => "requested".to_string(),
+      WebhookWorkflowRunRequestedAction::Unknown(value) => value.clone(),
     }
   }
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_event",))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookEvent {
+  #[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
   BranchProtectionConfigurationDisabled(BranchProtectionConfigurationDisabledEvent),
+  #[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
   BranchProtectionConfigurationEnabled(BranchProtectionConfigurationEnabledEvent),
+  #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
   BranchProtectionRuleCreated(BranchProtectionRuleCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
   BranchProtectionRuleDeleted(BranchProtectionRuleDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
   BranchProtectionRuleEdited(BranchProtectionRuleEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_check_run",))]
   CheckRunCompleted(CheckRunCompletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_check_run",))]
   CheckRunCreated(CheckRunCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_check_run",))]
   CheckRunRequestedAction(CheckRunRequestedActionEvent),
+  #[cfg(any(feature = "full", feature = "webhook_check_run",))]
   CheckRunRerequested(CheckRunReRequestedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
   CheckSuiteCompleted(WebhookCheckSuiteCompleted),
+  #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
   CheckSuiteRequested(WebhookCheckSuiteRequested),
+  #[cfg(any(feature = "full", feature = "webhook_check_suite",))]
   CheckSuiteRerequested(WebhookCheckSuiteRerequested),
+  #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
   CodeScanningAlertAppearedInBranch(WebhookCodeScanningAlertAppearedInBranch),
+  #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
   CodeScanningAlertClosedByUser(WebhookCodeScanningAlertClosedByUser),
+  #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
   CodeScanningAlertCreated(WebhookCodeScanningAlertCreated),
+  #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
   CodeScanningAlertFixed(WebhookCodeScanningAlertFixed),
+  #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
   CodeScanningAlertReopened(WebhookCodeScanningAlertReopened),
+  #[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
   CodeScanningAlertReopenedByUser(WebhookCodeScanningAlertReopenedByUser),
+  #[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
   CommitCommentCreated(WebhookCommitCommentCreated),
+  #[cfg(any(feature = "full", feature = "webhook_create",))]
   Create(CreateEvent),
+  #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
   CustomPropertyCreated(CustomPropertyCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
   CustomPropertyDeleted(CustomPropertyDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_custom_property",))]
   CustomPropertyUpdated(CustomPropertyUpdatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
   CustomPropertyValuesUpdated(CustomPropertyValuesUpdatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_delete",))]
   Delete(DeleteEvent),
+  #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
   DependabotAlertAutoDismissed(DependabotAlertAutoDismissedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
   DependabotAlertAutoReopened(DependabotAlertAutoReopenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
   DependabotAlertCreated(DependabotAlertCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
   DependabotAlertDismissed(DependabotAlertDismissedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
   DependabotAlertFixed(DependabotAlertFixedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
   DependabotAlertReintroduced(DependabotAlertReintroducedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
   DependabotAlertReopened(DependabotAlertReopenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
   DeployKeyCreated(WebhookDeployKeyCreated),
+  #[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
   DeployKeyDeleted(WebhookDeployKeyDeleted),
+  #[cfg(any(feature = "full", feature = "webhook_deployment",))]
   DeploymentCreated(DeploymentCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
   DeploymentProtectionRuleRequested(DeploymentProtectionRuleRequestedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
   DeploymentReviewApproved(WebhookDeploymentReviewApproved),
+  #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
   DeploymentReviewRejected(WebhookDeploymentReviewRejected),
+  #[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
   DeploymentReviewRequested(WebhookDeploymentReviewRequested),
+  #[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
   DeploymentStatusCreated(WebhookDeploymentStatusCreated),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionAnswered(DiscussionAnsweredEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionCategoryChanged(DiscussionCategoryChangedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionClosed(DiscussionClosedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
   DiscussionCommentCreated(WebhookDiscussionCommentCreated),
+  #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
   DiscussionCommentDeleted(WebhookDiscussionCommentDeleted),
+  #[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
   DiscussionCommentEdited(WebhookDiscussionCommentEdited),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionCreated(DiscussionCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionDeleted(DiscussionDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionEdited(DiscussionEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionLabeled(DiscussionLabeledEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionLocked(DiscussionLockedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionPinned(DiscussionPinnedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionReopened(DiscussionReopenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionTransferred(DiscussionTransferredEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionUnanswered(DiscussionUnansweredEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionUnlabeled(DiscussionUnlabeledEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionUnlocked(DiscussionUnlockedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_discussion",))]
   DiscussionUnpinned(DiscussionUnpinnedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_fork",))]
   Fork(ForkEvent),
+  #[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
   GithubAppAuthorizationRevoked(WebhookGithubAppAuthorizationRevoked),
+  #[cfg(any(feature = "full", feature = "webhook_gollum",))]
   Gollum(GollumEvent),
+  #[cfg(any(feature = "full", feature = "webhook_installation",))]
   InstallationCreated(InstallationCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_installation",))]
   InstallationDeleted(InstallationDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_installation",))]
   InstallationNewPermissionsAccepted(WebhookInstallationNewPermissionsAccepted),
+  #[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
   InstallationRepositoriesAdded(WebhookInstallationRepositoriesAdded),
+  #[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
   InstallationRepositoriesRemoved(WebhookInstallationRepositoriesRemoved),
+  #[cfg(any(feature = "full", feature = "webhook_installation",))]
   InstallationSuspend(InstallationSuspendEvent),
+  #[cfg(any(feature = "full", feature = "webhook_installation_target",))]
   InstallationTargetRenamed(WebhookInstallationTargetRenamed),
+  #[cfg(any(feature = "full", feature = "webhook_installation",))]
   InstallationUnsuspend(InstallationUnsuspendEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
   IssueCommentCreated(WebhookIssueCommentCreated),
+  #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
   IssueCommentDeleted(WebhookIssueCommentDeleted),
+  #[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
   IssueCommentEdited(WebhookIssueCommentEdited),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesAssigned(IssuesAssignedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesClosed(IssuesClosedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesDeleted(IssuesDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesDemilestoned(IssuesDemilestonedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesEdited(IssuesEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesLabeled(IssuesLabeledEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesLocked(IssuesLockedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesMilestoned(IssuesMilestonedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesOpened(IssuesOpenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesPinned(IssuesPinnedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesReopened(IssuesReopenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesTransferred(IssuesTransferredEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesUnassigned(IssuesUnassignedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesUnlabeled(IssuesUnlabeledEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesUnlocked(IssuesUnlockedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_issues",))]
   IssuesUnpinned(IssuesUnpinnedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_label",))]
   LabelCreated(LabelCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_label",))]
   LabelDeleted(LabelDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_label",))]
   LabelEdited(LabelEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
   MarketplacePurchaseCancelled(WebhookMarketplacePurchaseCancelled),
+  #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
   MarketplacePurchaseChanged(WebhookMarketplacePurchaseChanged),
+  #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
   MarketplacePurchasePendingChange(WebhookMarketplacePurchasePendingChange),
+  #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
   MarketplacePurchasePendingChangeCancelled(WebhookMarketplacePurchasePendingChangeCancelled),
+  #[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
   MarketplacePurchasePurchased(WebhookMarketplacePurchasePurchased),
+  #[cfg(any(feature = "full", feature = "webhook_member",))]
   MemberAdded(MemberAddedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_member",))]
   MemberEdited(MemberEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_member",))]
   MemberRemoved(MemberRemovedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_membership",))]
   MembershipAdded(MembershipAddedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_membership",))]
   MembershipRemoved(MembershipRemovedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_merge_group",))]
   MergeGroupChecksRequested(WebhookMergeGroupChecksRequested),
+  #[cfg(any(feature = "full", feature = "webhook_merge_group",))]
   MergeGroupDestroyed(WebhookMergeGroupDestroyed),
+  #[cfg(any(feature = "full", feature = "webhook_meta",))]
   MetaDeleted(MetaDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_milestone",))]
   MilestoneClosed(MilestoneClosedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_milestone",))]
   MilestoneCreated(MilestoneCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_milestone",))]
   MilestoneDeleted(MilestoneDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_milestone",))]
   MilestoneEdited(MilestoneEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_milestone",))]
   MilestoneOpened(MilestoneOpenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_org_block",))]
   OrgBlockBlocked(WebhookOrgBlockBlocked),
+  #[cfg(any(feature = "full", feature = "webhook_org_block",))]
   OrgBlockUnblocked(WebhookOrgBlockUnblocked),
+  #[cfg(any(feature = "full", feature = "webhook_organization",))]
   OrganizationDeleted(OrganizationDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_organization",))]
   OrganizationMemberAdded(WebhookOrganizationMemberAdded),
+  #[cfg(any(feature = "full", feature = "webhook_organization",))]
   OrganizationMemberInvited(WebhookOrganizationMemberInvited),
+  #[cfg(any(feature = "full", feature = "webhook_organization",))]
   OrganizationMemberRemoved(WebhookOrganizationMemberRemoved),
+  #[cfg(any(feature = "full", feature = "webhook_organization",))]
   OrganizationRenamed(OrganizationRenamedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_package",))]
   PackagePublished(PackagePublishedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_package",))]
   PackageUpdated(PackageUpdatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_page_build",))]
   PageBuild(WebhookPageBuild),
+  #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
   PersonalAccessTokenRequestApproved(WebhookPersonalAccessTokenRequestApproved),
+  #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
   PersonalAccessTokenRequestCancelled(WebhookPersonalAccessTokenRequestCancelled),
+  #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
   PersonalAccessTokenRequestCreated(WebhookPersonalAccessTokenRequestCreated),
+  #[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
   PersonalAccessTokenRequestDenied(WebhookPersonalAccessTokenRequestDenied),
+  #[cfg(any(feature = "full", feature = "webhook_ping",))]
   Ping(WebhookPing),
+  #[cfg(any(feature = "full", feature = "webhook_project_card",))]
   ProjectCardConverted(WebhookProjectCardConverted),
+  #[cfg(any(feature = "full", feature = "webhook_project_card",))]
   ProjectCardCreated(WebhookProjectCardCreated),
+  #[cfg(any(feature = "full", feature = "webhook_project_card",))]
   ProjectCardDeleted(WebhookProjectCardDeleted),
+  #[cfg(any(feature = "full", feature = "webhook_project_card",))]
   ProjectCardEdited(WebhookProjectCardEdited),
+  #[cfg(any(feature = "full", feature = "webhook_project_card",))]
   ProjectCardMoved(WebhookProjectCardMoved),
+  #[cfg(any(feature = "full", feature = "webhook_project",))]
   ProjectClosed(ProjectClosedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_project_column",))]
   ProjectColumnCreated(WebhookProjectColumnCreated),
+  #[cfg(any(feature = "full", feature = "webhook_project_column",))]
   ProjectColumnDeleted(WebhookProjectColumnDeleted),
+  #[cfg(any(feature = "full", feature = "webhook_project_column",))]
   ProjectColumnEdited(WebhookProjectColumnEdited),
+  #[cfg(any(feature = "full", feature = "webhook_project_column",))]
   ProjectColumnMoved(WebhookProjectColumnMoved),
+  #[cfg(any(feature = "full", feature = "webhook_project",))]
   ProjectCreated(ProjectCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_project",))]
   ProjectDeleted(ProjectDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_project",))]
   ProjectEdited(ProjectEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_project",))]
   ProjectReopened(ProjectReopenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
   ProjectsV2Closed(ProjectsV2ProjectClosedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
   ProjectsV2Created(WebhookProjectsV2ProjectCreated),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
   ProjectsV2Deleted(ProjectsV2ProjectDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
   ProjectsV2Edited(ProjectsV2ProjectEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
   ProjectsV2ItemArchived(ProjectsV2ItemArchivedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
   ProjectsV2ItemConverted(ProjectsV2ItemConvertedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
   ProjectsV2ItemCreated(ProjectsV2ItemCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
   ProjectsV2ItemDeleted(ProjectsV2ItemDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
   ProjectsV2ItemEdited(ProjectsV2ItemEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
   ProjectsV2ItemReordered(ProjectsV2ItemReorderedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
   ProjectsV2ItemRestored(ProjectsV2ItemRestoredEvent),
+  #[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
   ProjectsV2Reopened(ProjectsV2ProjectReopenedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_public",))]
   Public(PublicEvent),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestAssigned(WebhookPullRequestAssigned),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestAutoMergeDisabled(WebhookPullRequestAutoMergeDisabled),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestAutoMergeEnabled(WebhookPullRequestAutoMergeEnabled),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestClosed(WebhookPullRequestClosed),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestConvertedToDraft(WebhookPullRequestConvertedToDraft),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestDemilestoned(WebhookPullRequestDemilestoned),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestDequeued(WebhookPullRequestDequeued),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestEdited(WebhookPullRequestEdited),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestEnqueued(WebhookPullRequestEnqueued),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestLabeled(WebhookPullRequestLabeled),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestLocked(WebhookPullRequestLocked),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestMilestoned(WebhookPullRequestMilestoned),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestOpened(WebhookPullRequestOpened),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestReadyForReview(WebhookPullRequestReadyForReview),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestReopened(WebhookPullRequestReopened),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
   PullRequestReviewCommentCreated(WebhookPullRequestReviewCommentCreated),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
   PullRequestReviewCommentDeleted(WebhookPullRequestReviewCommentDeleted),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
   PullRequestReviewCommentEdited(WebhookPullRequestReviewCommentEdited),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
   PullRequestReviewDismissed(WebhookPullRequestReviewDismissed),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
   PullRequestReviewEdited(WebhookPullRequestReviewEdited),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestReviewRequestRemoved(WebhookPullRequestReviewRequestRemoved),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestReviewRequested(WebhookPullRequestReviewRequested),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
   PullRequestReviewSubmitted(WebhookPullRequestReviewSubmitted),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
   PullRequestReviewThreadResolved(WebhookPullRequestReviewThreadResolved),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
   PullRequestReviewThreadUnresolved(WebhookPullRequestReviewThreadUnresolved),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestSynchronize(WebhookPullRequestSynchronize),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestUnassigned(WebhookPullRequestUnassigned),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestUnlabeled(WebhookPullRequestUnlabeled),
+  #[cfg(any(feature = "full", feature = "webhook_pull_request",))]
   PullRequestUnlocked(WebhookPullRequestUnlocked),
+  #[cfg(any(feature = "full", feature = "webhook_push",))]
   Push(PushEvent),
+  #[cfg(any(feature = "full", feature = "webhook_registry_package",))]
   RegistryPackagePublished(WebhookRegistryPackagePublished),
+  #[cfg(any(feature = "full", feature = "webhook_registry_package",))]
   RegistryPackageUpdated(WebhookRegistryPackageUpdated),
+  #[cfg(any(feature = "full", feature = "webhook_release",))]
   ReleaseCreated(ReleaseCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_release",))]
   ReleaseDeleted(ReleaseDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_release",))]
   ReleaseEdited(ReleaseEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_release",))]
   ReleasePrereleased(ReleasePrereleasedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_release",))]
   ReleasePublished(ReleasePublishedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_release",))]
   ReleaseReleased(ReleaseReleasedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_release",))]
   ReleaseUnpublished(ReleaseUnpublishedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
   RepositoryAdvisoryPublished(RepositoryAdvisoryPublishedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
   RepositoryAdvisoryReported(RepositoryAdvisoryReportedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryArchived(RepositoryArchivedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryCreated(RepositoryCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryDeleted(RepositoryDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_dispatch",))]
   RepositoryDispatchSampleCollected(WebhookRepositoryDispatchSample),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryEdited(RepositoryEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_import",))]
   RepositoryImport(WebhookRepositoryImport),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryPrivatized(RepositoryPrivatizedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryPublicized(RepositoryPublicizedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryRenamed(RepositoryRenamedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
   RepositoryRulesetCreated(RepositoryRulesetCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
   RepositoryRulesetDeleted(RepositoryRulesetDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
   RepositoryRulesetEdited(RepositoryRulesetEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryTransferred(RepositoryTransferredEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository",))]
   RepositoryUnarchived(RepositoryUnarchivedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
   RepositoryVulnerabilityAlertCreate(WebhookRepositoryVulnerabilityAlertCreate),
+  #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
   RepositoryVulnerabilityAlertDismiss(WebhookRepositoryVulnerabilityAlertDismiss),
+  #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
   RepositoryVulnerabilityAlertReopen(WebhookRepositoryVulnerabilityAlertReopen),
+  #[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
   RepositoryVulnerabilityAlertResolve(WebhookRepositoryVulnerabilityAlertResolve),
+  #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
   SecretScanningAlertCreated(WebhookSecretScanningAlertCreated),
+  #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
   SecretScanningAlertLocationCreated(SecretScanningAlertLocationCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
   SecretScanningAlertReopened(WebhookSecretScanningAlertReopened),
+  #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
   SecretScanningAlertResolved(WebhookSecretScanningAlertResolved),
+  #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
   SecretScanningAlertRevoked(WebhookSecretScanningAlertRevoked),
+  #[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
   SecretScanningAlertValidated(WebhookSecretScanningAlertValidated),
+  #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
   SecurityAdvisoryPublished(WebhookSecurityAdvisoryPublished),
+  #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
   SecurityAdvisoryUpdated(WebhookSecurityAdvisoryUpdated),
+  #[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
   SecurityAdvisoryWithdrawn(WebhookSecurityAdvisoryWithdrawn),
+  #[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
   SecurityAndAnalysis(WebhookSecurityAndAnalysis),
+  #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
   SponsorshipCancelled(SponsorshipCancelledEvent),
+  #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
   SponsorshipCreated(SponsorshipCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
   SponsorshipEdited(SponsorshipEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
   SponsorshipPendingCancellation(WebhookSponsorshipPendingCancellation),
+  #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
   SponsorshipPendingTierChange(WebhookSponsorshipPendingTierChange),
+  #[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
   SponsorshipTierChanged(WebhookSponsorshipTierChanged),
+  #[cfg(any(feature = "full", feature = "webhook_star",))]
   StarCreated(StarCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_star",))]
   StarDeleted(StarDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_status",))]
   Status(StatusEvent),
+  #[cfg(any(feature = "full", feature = "webhook_team_add",))]
   TeamAdd(WebhookTeamAdd),
+  #[cfg(any(feature = "full", feature = "webhook_team",))]
   TeamAddedToRepository(WebhookTeamAddedToRepository),
+  #[cfg(any(feature = "full", feature = "webhook_team",))]
   TeamCreated(TeamCreatedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_team",))]
   TeamDeleted(TeamDeletedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_team",))]
   TeamEdited(TeamEditedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_team",))]
   TeamRemovedFromRepository(WebhookTeamRemovedFromRepository),
+  #[cfg(any(feature = "full", feature = "webhook_watch",))]
   WatchStarted(WatchStartedEvent),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_dispatch",))]
   WorkflowDispatch(WebhookWorkflowDispatch),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
   WorkflowJobCompleted(WebhookWorkflowJobCompleted),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
   WorkflowJobInProgress(WebhookWorkflowJobInProgress),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
   WorkflowJobQueued(WebhookWorkflowJobQueued),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
   WorkflowJobWaiting(WebhookWorkflowJobWaiting),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
   WorkflowRunCompleted(WebhookWorkflowRunCompleted),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
   WorkflowRunInProgress(WebhookWorkflowRunInProgress),
+  #[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
   WorkflowRunRequested(WebhookWorkflowRunRequested),
+  /// An event with an `action` that GitHub added after this version, rejected by the `strict` feature
+  Unknown(UnknownWebhookEvent),
 }
 
 /// Structs definition
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_personal_access_token_request",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_projects_v2",
+  feature = "webhook_projects_v2_item",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
+))]
 /// The GitHub App installation. Webhook payloads contain the `installation` property when the event is configured
 /// for and sent to a GitHub App. For more information,
 /// see "[Using webhooks with GitHub Apps](https://docs.github.com/apps/creating-github-apps/registering-a-github-app/using-webhooks-with-github-apps)."
@@ -8809,14 +12334,83 @@ pub struct SimpleInstallation {
   pub node_id: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_branch_protection_configuration",
+  feature = "webhook_branch_protection_rule",
+  feature = "webhook_check_run",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_create",
+  feature = "webhook_custom_property",
+  feature = "webhook_custom_property_values",
+  feature = "webhook_delete",
+  feature = "webhook_dependabot_alert",
+  feature = "webhook_deploy_key",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_protection_rule",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_fork",
+  feature = "webhook_gollum",
+  feature = "webhook_installation_target",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_label",
+  feature = "webhook_marketplace_purchase",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_merge_group",
+  feature = "webhook_meta",
+  feature = "webhook_milestone",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_personal_access_token_request",
+  feature = "webhook_project_card",
+  feature = "webhook_project",
+  feature = "webhook_project_column",
+  feature = "webhook_projects_v2",
+  feature = "webhook_projects_v2_item",
+  feature = "webhook_public",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_push",
+  feature = "webhook_registry_package",
+  feature = "webhook_release",
+  feature = "webhook_repository_advisory",
+  feature = "webhook_repository",
+  feature = "webhook_repository_dispatch",
+  feature = "webhook_repository_import",
+  feature = "webhook_repository_ruleset",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+  feature = "webhook_security_advisory",
+  feature = "webhook_security_and_analysis",
+  feature = "webhook_sponsorship",
+  feature = "webhook_star",
+  feature = "webhook_status",
+  feature = "webhook_team_add",
+  feature = "webhook_team",
+  feature = "webhook_watch",
+  feature = "webhook_workflow_dispatch",
+  feature = "webhook_workflow_job",
+  feature = "webhook_workflow_run",
+))]
 impl SimpleInstallation {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionConfigurationDisabledEvent {
@@ -8834,14 +12428,14 @@ pub struct BranchProtectionConfigurationDisabledEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 impl BranchProtectionConfigurationDisabledEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionConfigurationEnabledEvent {
@@ -8859,14 +12453,14 @@ pub struct BranchProtectionConfigurationEnabledEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_configuration",))]
 impl BranchProtectionConfigurationEnabledEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 /// The branch protection rule. Includes a `name` and all the [branch protection settings](https://docs.github.com/github/administering-a-repository/defining-the-mergeability-of-pull-requests/about-protected-branches#about-branch-protection-settings) applied to branches that match the name. Binary settings are boolean. Multi-level configurations are one of `off`, `non_admins`, or `everyone`. Actor and build lists are arrays of strings.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -8880,7 +12474,7 @@ pub struct BranchProtectionRule {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub create_protected: Option<bool>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub dismiss_stale_reviews_on_push: bool,
   pub id: i64,
   pub ignore_approvals_from_contributors: bool,
@@ -8907,17 +12501,17 @@ pub struct BranchProtectionRule {
   pub signature_requirement_enforcement_level:
     BranchProtectionRuleSignatureRequirementEnforcementLevel,
   pub strict_required_status_checks_policy: bool,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRule {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleCreatedEvent {
@@ -8937,14 +12531,14 @@ pub struct BranchProtectionRuleCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleDeletedEvent {
@@ -8964,14 +12558,14 @@ pub struct BranchProtectionRuleDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEventChangesAdminEnforced {
@@ -8980,28 +12574,28 @@ pub struct BranchProtectionRuleEditedEventChangesAdminEnforced {
   pub from: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChangesAdminEnforced {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEventChangesAuthorizedActorNames {
   pub from: Vec<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChangesAuthorizedActorNames {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEventChangesAuthorizedActorsOnly {
@@ -9010,14 +12604,14 @@ pub struct BranchProtectionRuleEditedEventChangesAuthorizedActorsOnly {
   pub from: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChangesAuthorizedActorsOnly {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEventChangesAuthorizedDismissalActorsOnly {
@@ -9026,56 +12620,56 @@ pub struct BranchProtectionRuleEditedEventChangesAuthorizedDismissalActorsOnly {
   pub from: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChangesAuthorizedDismissalActorsOnly {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevel {
   pub from: BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevelFrom,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChangesLinearHistoryRequirementEnforcementLevel {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEventChangesRequiredStatusChecks {
   pub from: Vec<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChangesRequiredStatusChecks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevel {
   pub from: BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevelFrom,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevel {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 /// If the action was `edited`, the changes to the rule.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -9106,14 +12700,14 @@ pub struct BranchProtectionRuleEditedEventChanges {
     Option<BranchProtectionRuleEditedEventChangesRequiredStatusChecksEnforcementLevel>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct BranchProtectionRuleEditedEvent {
@@ -9137,14 +12731,14 @@ pub struct BranchProtectionRuleEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_branch_protection_rule",))]
 impl BranchProtectionRuleEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunCompletedEvent {
@@ -9162,14 +12756,14 @@ pub struct CheckRunCompletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl CheckRunCompletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunCreatedEvent {
@@ -9187,14 +12781,14 @@ pub struct CheckRunCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl CheckRunCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 /// The action requested by the user.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -9205,14 +12799,14 @@ pub struct CheckRunRequestedActionEventRequestedAction {
   pub identifier: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl CheckRunRequestedActionEventRequestedAction {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunRequestedActionEvent {
@@ -9232,14 +12826,14 @@ pub struct CheckRunRequestedActionEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl CheckRunRequestedActionEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunReRequestedEvent {
@@ -9257,14 +12851,42 @@ pub struct CheckRunReRequestedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_run",))]
 impl CheckRunReRequestedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_sponsorship",
+  feature = "webhook_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct User {
@@ -9330,21 +12952,53 @@ pub struct User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_code_scanning_alert",
+  feature = "webhook_commit_comment",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+  feature = "webhook_installation",
+  feature = "webhook_installation_repositories",
+  feature = "webhook_issue_comment",
+  feature = "webhook_issues",
+  feature = "webhook_member",
+  feature = "webhook_membership",
+  feature = "webhook_org_block",
+  feature = "webhook_organization",
+  feature = "webhook_package",
+  feature = "webhook_page_build",
+  feature = "webhook_pull_request",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+  feature = "webhook_repository",
+  feature = "webhook_repository_vulnerability_alert",
+  feature = "webhook_sponsorship",
+  feature = "webhook_status",
+))]
 impl User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+))]
 /// GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct App {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -9376,17 +13030,27 @@ pub struct App {
   pub slug: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment_status",
+))]
 impl App {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepoRef {
@@ -9395,14 +13059,26 @@ pub struct RepoRef {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl RepoRef {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunPullRequestBase {
@@ -9412,14 +13088,26 @@ pub struct CheckRunPullRequestBase {
   pub sha: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl CheckRunPullRequestBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunPullRequestHead {
@@ -9429,14 +13117,26 @@ pub struct CheckRunPullRequestHead {
   pub sha: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl CheckRunPullRequestHead {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CheckRunPullRequest {
@@ -9447,14 +13147,20 @@ pub struct CheckRunPullRequest {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_check_suite",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl CheckRunPullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The [check_suite](https://docs.github.com/rest/checks/suites#get-a-check-suite).
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -9472,7 +13178,7 @@ pub struct WebhookCheckSuiteCompletedCheckSuite {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<WebhookCheckSuiteCompletedCheckSuiteConclusion>,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The head branch name the changes are on.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -9495,19 +13201,19 @@ pub struct WebhookCheckSuiteCompletedCheckSuite {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub status: Option<WebhookCheckSuiteCompletedCheckSuiteStatus>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL that points to the check suite API resource.
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl WebhookCheckSuiteCompletedCheckSuite {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCheckSuiteCompleted {
@@ -9527,14 +13233,14 @@ pub struct WebhookCheckSuiteCompleted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl WebhookCheckSuiteCompleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The [check_suite](https://docs.github.com/rest/checks/suites#get-a-check-suite).
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -9552,7 +13258,7 @@ pub struct WebhookCheckSuiteRequestedCheckSuite {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<WebhookCheckSuiteRequestedCheckSuiteConclusion>,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The head branch name the changes are on.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -9575,19 +13281,19 @@ pub struct WebhookCheckSuiteRequestedCheckSuite {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub status: Option<WebhookCheckSuiteRequestedCheckSuiteStatus>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL that points to the check suite API resource.
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl WebhookCheckSuiteRequestedCheckSuite {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCheckSuiteRequested {
@@ -9607,14 +13313,14 @@ pub struct WebhookCheckSuiteRequested {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl WebhookCheckSuiteRequested {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 /// The [check_suite](https://docs.github.com/rest/checks/suites#get-a-check-suite).
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -9632,7 +13338,7 @@ pub struct WebhookCheckSuiteRerequestedCheckSuite {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<WebhookCheckSuiteRerequestedCheckSuiteConclusion>,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The head branch name the changes are on.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -9655,19 +13361,19 @@ pub struct WebhookCheckSuiteRerequestedCheckSuite {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub status: Option<WebhookCheckSuiteRerequestedCheckSuiteStatus>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   /// URL that points to the check suite API resource.
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl WebhookCheckSuiteRerequestedCheckSuite {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCheckSuiteRerequested {
@@ -9687,14 +13393,14 @@ pub struct WebhookCheckSuiteRerequested {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_check_suite",))]
 impl WebhookCheckSuiteRerequested {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct AlertInstanceLocation {
@@ -9715,14 +13421,14 @@ pub struct AlertInstanceLocation {
   pub start_line: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl AlertInstanceLocation {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct AlertInstanceMessage {
@@ -9731,14 +13437,14 @@ pub struct AlertInstanceMessage {
   pub text: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl AlertInstanceMessage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct AlertInstance {
@@ -9769,14 +13475,14 @@ pub struct AlertInstance {
   pub state: AlertInstanceState,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl AlertInstance {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertAppearedInBranchAlertRule {
@@ -9790,14 +13496,14 @@ pub struct WebhookCodeScanningAlertAppearedInBranchAlertRule {
   pub severity: Option<WebhookCodeScanningAlertAppearedInBranchAlertRuleSeverity>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertAppearedInBranchAlertRule {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertAppearedInBranchAlertTool {
@@ -9809,24 +13515,24 @@ pub struct WebhookCodeScanningAlertAppearedInBranchAlertTool {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertAppearedInBranchAlertTool {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The code scanning alert involved in the event.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertAppearedInBranchAlert {
   /// The time that the alert was created in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ.`
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The time that the alert was dismissed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<User>,
@@ -9848,14 +13554,14 @@ pub struct WebhookCodeScanningAlertAppearedInBranchAlert {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertAppearedInBranchAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertAppearedInBranch {
@@ -9880,14 +13586,14 @@ pub struct WebhookCodeScanningAlertAppearedInBranch {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertAppearedInBranch {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertClosedByUserAlertRule {
@@ -9917,14 +13623,14 @@ pub struct WebhookCodeScanningAlertClosedByUserAlertRule {
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertClosedByUserAlertRule {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertClosedByUserAlertTool {
@@ -9939,22 +13645,22 @@ pub struct WebhookCodeScanningAlertClosedByUserAlertTool {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertClosedByUserAlertTool {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The code scanning alert involved in the event.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertClosedByUserAlert {
   /// The time that the alert was created in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ.`
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The time that the alert was dismissed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
-  pub dismissed_at: String,
+  pub dismissed_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<User>,
@@ -9976,14 +13682,14 @@ pub struct WebhookCodeScanningAlertClosedByUserAlert {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertClosedByUserAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertClosedByUser {
@@ -10008,14 +13714,14 @@ pub struct WebhookCodeScanningAlertClosedByUser {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertClosedByUser {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertCreatedAlertRule {
@@ -10045,14 +13751,14 @@ pub struct WebhookCodeScanningAlertCreatedAlertRule {
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertCreatedAlertRule {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertCreatedAlertTool {
@@ -10067,14 +13773,14 @@ pub struct WebhookCodeScanningAlertCreatedAlertTool {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertCreatedAlertTool {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The code scanning alert involved in the event.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -10082,7 +13788,7 @@ pub struct WebhookCodeScanningAlertCreatedAlert {
   /// The time that the alert was created in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ.`
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// The time that the alert was dismissed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   pub dismissed_at: serde_json::Value,
   pub dismissed_by: serde_json::Value,
@@ -10112,18 +13818,18 @@ pub struct WebhookCodeScanningAlertCreatedAlert {
   pub tool: Option<WebhookCodeScanningAlertCreatedAlertTool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertCreatedAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertCreated {
@@ -10148,14 +13854,14 @@ pub struct WebhookCodeScanningAlertCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertFixedAlertRule {
@@ -10185,14 +13891,14 @@ pub struct WebhookCodeScanningAlertFixedAlertRule {
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertFixedAlertRule {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertFixedAlertTool {
@@ -10207,24 +13913,24 @@ pub struct WebhookCodeScanningAlertFixedAlertTool {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertFixedAlertTool {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The code scanning alert involved in the event.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertFixedAlert {
   /// The time that the alert was created in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ.`
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The time that the alert was dismissed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<User>,
@@ -10249,14 +13955,14 @@ pub struct WebhookCodeScanningAlertFixedAlert {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertFixedAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertFixed {
@@ -10281,14 +13987,14 @@ pub struct WebhookCodeScanningAlertFixed {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertFixed {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopenedAlertRule {
@@ -10318,14 +14024,14 @@ pub struct WebhookCodeScanningAlertReopenedAlertRule {
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopenedAlertRule {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopenedAlertTool {
@@ -10340,24 +14046,24 @@ pub struct WebhookCodeScanningAlertReopenedAlertTool {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopenedAlertTool {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The code scanning alert involved in the event.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopenedAlert {
   /// The time that the alert was created in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ.`
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The time that the alert was dismissed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismissed_by: Option<serde_json::Value>,
@@ -10379,14 +14085,14 @@ pub struct WebhookCodeScanningAlertReopenedAlert {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopenedAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopened {
@@ -10417,14 +14123,14 @@ pub struct WebhookCodeScanningAlertReopened {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopened {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopenedByUserAlertRule {
@@ -10438,14 +14144,14 @@ pub struct WebhookCodeScanningAlertReopenedByUserAlertRule {
   pub severity: Option<WebhookCodeScanningAlertReopenedByUserAlertRuleSeverity>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopenedByUserAlertRule {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopenedByUserAlertTool {
@@ -10457,20 +14163,20 @@ pub struct WebhookCodeScanningAlertReopenedByUserAlertTool {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopenedByUserAlertTool {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 /// The code scanning alert involved in the event.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopenedByUserAlert {
   /// The time that the alert was created in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ.`
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The time that the alert was dismissed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   pub dismissed_at: serde_json::Value,
   pub dismissed_by: serde_json::Value,
@@ -10490,14 +14196,14 @@ pub struct WebhookCodeScanningAlertReopenedByUserAlert {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopenedByUserAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCodeScanningAlertReopenedByUser {
@@ -10522,14 +14228,19 @@ pub struct WebhookCodeScanningAlertReopenedByUser {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_code_scanning_alert",))]
 impl WebhookCodeScanningAlertReopenedByUser {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_commit_comment",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct Reactions {
@@ -10547,14 +14258,19 @@ pub struct Reactions {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_commit_comment",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 impl Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 /// The [commit comment](https://docs.github.com/rest/commits/comments#get-a-commit-comment) resource.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -10565,7 +14281,7 @@ pub struct WebhookCommitCommentCreatedComment {
   pub body: String,
   /// The SHA of the commit to which the comment applies.
   pub commit_id: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub html_url: String,
   /// The ID of the commit comment.
   pub id: i64,
@@ -10586,21 +14302,21 @@ pub struct WebhookCommitCommentCreatedComment {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub reactions: Option<Reactions>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 impl WebhookCommitCommentCreatedComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookCommitCommentCreated {
@@ -10621,14 +14337,14 @@ pub struct WebhookCommitCommentCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_commit_comment",))]
 impl WebhookCommitCommentCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_create",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CreateEvent {
@@ -10658,14 +14374,14 @@ pub struct CreateEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_create",))]
 impl CreateEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CustomPropertyCreatedEvent {
@@ -10683,14 +14399,14 @@ pub struct CustomPropertyCreatedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 impl CustomPropertyCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CustomPropertyDeletedEventDefinition {
@@ -10698,14 +14414,14 @@ pub struct CustomPropertyDeletedEventDefinition {
   pub property_name: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 impl CustomPropertyDeletedEventDefinition {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CustomPropertyDeletedEvent {
@@ -10723,14 +14439,14 @@ pub struct CustomPropertyDeletedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 impl CustomPropertyDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CustomPropertyUpdatedEvent {
@@ -10748,14 +14464,14 @@ pub struct CustomPropertyUpdatedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property",))]
 impl CustomPropertyUpdatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct CustomPropertyValuesUpdatedEvent {
@@ -10777,14 +14493,14 @@ pub struct CustomPropertyValuesUpdatedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_custom_property_values",))]
 impl CustomPropertyValuesUpdatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_delete",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeleteEvent {
@@ -10808,14 +14524,14 @@ pub struct DeleteEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_delete",))]
 impl DeleteEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlertAutoDismissedEvent {
@@ -10834,14 +14550,14 @@ pub struct DependabotAlertAutoDismissedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl DependabotAlertAutoDismissedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlertAutoReopenedEvent {
@@ -10860,14 +14576,14 @@ pub struct DependabotAlertAutoReopenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl DependabotAlertAutoReopenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlertCreatedEvent {
@@ -10886,14 +14602,14 @@ pub struct DependabotAlertCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl DependabotAlertCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlertDismissedEvent {
@@ -10912,14 +14628,14 @@ pub struct DependabotAlertDismissedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl DependabotAlertDismissedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlertFixedEvent {
@@ -10938,14 +14654,14 @@ pub struct DependabotAlertFixedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl DependabotAlertFixedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlertReintroducedEvent {
@@ -10964,14 +14680,14 @@ pub struct DependabotAlertReintroducedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl DependabotAlertReintroducedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DependabotAlertReopenedEvent {
@@ -10990,14 +14706,14 @@ pub struct DependabotAlertReopenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_dependabot_alert",))]
 impl DependabotAlertReopenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 /// The [`deploy key`](https://docs.github.com/rest/deploy-keys/deploy-keys#get-a-deploy-key) resource.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -11005,7 +14721,7 @@ pub struct WebhookDeployKeyCreatedKey {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub added_by: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub id: i64,
   pub key: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -11017,14 +14733,14 @@ pub struct WebhookDeployKeyCreatedKey {
   pub verified: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 impl WebhookDeployKeyCreatedKey {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeployKeyCreated {
@@ -11044,14 +14760,14 @@ pub struct WebhookDeployKeyCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 impl WebhookDeployKeyCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 /// The [`deploy key`](https://docs.github.com/rest/deploy-keys/deploy-keys#get-a-deploy-key) resource.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -11059,7 +14775,7 @@ pub struct WebhookDeployKeyDeletedKey {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub added_by: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub id: i64,
   pub key: String,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -11071,14 +14787,14 @@ pub struct WebhookDeployKeyDeletedKey {
   pub verified: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 impl WebhookDeployKeyDeletedKey {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeployKeyDeleted {
@@ -11098,14 +14814,19 @@ pub struct WebhookDeployKeyDeleted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deploy_key",))]
 impl WebhookDeployKeyDeleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentWorkflowRunHeadRepositoryOwner {
@@ -11166,14 +14887,24 @@ pub struct DeploymentWorkflowRunHeadRepositoryOwner {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl DeploymentWorkflowRunHeadRepositoryOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentWorkflowRunHeadRepository {
@@ -11317,14 +15048,24 @@ pub struct DeploymentWorkflowRunHeadRepository {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl DeploymentWorkflowRunHeadRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentWorkflowRunReferencedWorkflows {
@@ -11336,14 +15077,24 @@ pub struct DeploymentWorkflowRunReferencedWorkflows {
   pub sha: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl DeploymentWorkflowRunReferencedWorkflows {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentWorkflowRunRepositoryOwner {
@@ -11404,14 +15155,24 @@ pub struct DeploymentWorkflowRunRepositoryOwner {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl DeploymentWorkflowRunRepositoryOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentWorkflowRunRepository {
@@ -11555,14 +15316,24 @@ pub struct DeploymentWorkflowRunRepository {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl DeploymentWorkflowRunRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentWorkflowRun {
@@ -11583,7 +15354,7 @@ pub struct DeploymentWorkflowRun {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<DeploymentWorkflowRunConclusion>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub display_title: String,
   pub event: String,
   pub head_branch: String,
@@ -11620,12 +15391,12 @@ pub struct DeploymentWorkflowRun {
   pub rerun_url: Option<String>,
   pub run_attempt: i64,
   pub run_number: i64,
-  pub run_started_at: String,
+  pub run_started_at: DateTime,
   pub status: DeploymentWorkflowRunStatus,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub triggering_actor: Option<User>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   pub workflow_id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -11633,14 +15404,19 @@ pub struct DeploymentWorkflowRun {
   pub workflow_url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_deployment",
+  feature = "webhook_deployment_review",
+  feature = "webhook_deployment_status",
+))]
 impl DeploymentWorkflowRun {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentCreatedEvent {
@@ -11666,14 +15442,14 @@ pub struct DeploymentCreatedEvent {
   pub workflow_run: Option<DeploymentWorkflowRun>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment",))]
 impl DeploymentCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DeploymentProtectionRuleRequestedEvent {
@@ -11712,14 +15488,14 @@ pub struct DeploymentProtectionRuleRequestedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_protection_rule",))]
 impl DeploymentProtectionRuleRequestedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewApprovedApprover {
@@ -11780,14 +15556,14 @@ pub struct WebhookDeploymentReviewApprovedApprover {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewApprovedApprover {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewApprovedReviewers {
@@ -11800,35 +15576,35 @@ pub struct WebhookDeploymentReviewApprovedReviewers {
   pub type_: Option<WebhookDeploymentReviewApprovedReviewersType>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewApprovedReviewers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewApprovedWorkflowJobRun {
   pub conclusion: serde_json::Value,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub environment: String,
   pub html_url: String,
   pub id: i64,
   pub name: serde_json::Value,
   pub status: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewApprovedWorkflowJobRun {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewApprovedWorkflowJobRuns {
@@ -11837,7 +15613,7 @@ pub struct WebhookDeploymentReviewApprovedWorkflowJobRuns {
   pub conclusion: Option<serde_json::Value>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub environment: Option<String>,
@@ -11855,17 +15631,17 @@ pub struct WebhookDeploymentReviewApprovedWorkflowJobRuns {
   pub status: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewApprovedWorkflowJobRuns {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewApproved {
@@ -11900,14 +15676,14 @@ pub struct WebhookDeploymentReviewApproved {
   pub workflow_run: Option<DeploymentWorkflowRun>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewApproved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRejectedApprover {
@@ -11968,14 +15744,14 @@ pub struct WebhookDeploymentReviewRejectedApprover {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRejectedApprover {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRejectedReviewers {
@@ -11988,35 +15764,35 @@ pub struct WebhookDeploymentReviewRejectedReviewers {
   pub type_: Option<WebhookDeploymentReviewRejectedReviewersType>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRejectedReviewers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRejectedWorkflowJobRun {
   pub conclusion: serde_json::Value,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub environment: String,
   pub html_url: String,
   pub id: i64,
   pub name: serde_json::Value,
   pub status: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRejectedWorkflowJobRun {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRejectedWorkflowJobRuns {
@@ -12025,7 +15801,7 @@ pub struct WebhookDeploymentReviewRejectedWorkflowJobRuns {
   pub conclusion: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub environment: Option<String>,
@@ -12043,17 +15819,17 @@ pub struct WebhookDeploymentReviewRejectedWorkflowJobRuns {
   pub status: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRejectedWorkflowJobRuns {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRejected {
@@ -12088,14 +15864,14 @@ pub struct WebhookDeploymentReviewRejected {
   pub workflow_run: Option<DeploymentWorkflowRun>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRejected {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRequestedReviewers {
@@ -12108,19 +15884,19 @@ pub struct WebhookDeploymentReviewRequestedReviewers {
   pub type_: Option<WebhookDeploymentReviewRequestedReviewersType>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRequestedReviewers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRequestedWorkflowJobRun {
   pub conclusion: serde_json::Value,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub environment: String,
   pub html_url: String,
   pub id: i64,
@@ -12128,17 +15904,17 @@ pub struct WebhookDeploymentReviewRequestedWorkflowJobRun {
   #[builder(default, setter(strip_option))]
   pub name: Option<String>,
   pub status: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRequestedWorkflowJobRun {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentReviewRequested {
@@ -12164,20 +15940,20 @@ pub struct WebhookDeploymentReviewRequested {
   pub workflow_run: Option<DeploymentWorkflowRun>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_review",))]
 impl WebhookDeploymentReviewRequested {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentStatusCreatedCheckRun {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   /// The result of the completed check run. This value will be `null` until the check run has completed.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -12192,25 +15968,25 @@ pub struct WebhookDeploymentStatusCreatedCheckRun {
   /// The name of the check run.
   pub name: String,
   pub node_id: String,
-  pub started_at: String,
+  pub started_at: DateTime,
   /// The current status of the check run. Can be `queued`, `in_progress`, or `completed`.
   pub status: WebhookDeploymentStatusCreatedCheckRunStatus,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 impl WebhookDeploymentStatusCreatedCheckRun {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 /// The [deployment status](https://docs.github.com/rest/deployments/statuses#list-deployment-statuses).
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentStatusCreatedDeploymentStatus {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<User>,
@@ -12235,18 +16011,18 @@ pub struct WebhookDeploymentStatusCreatedDeploymentStatus {
   pub state: String,
   /// The optional link added to the status.
   pub target_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 impl WebhookDeploymentStatusCreatedDeploymentStatus {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDeploymentStatusCreated {
@@ -12277,14 +16053,14 @@ pub struct WebhookDeploymentStatusCreated {
   pub workflow_run: Option<DeploymentWorkflowRun>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_deployment_status",))]
 impl WebhookDeploymentStatusCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionAnsweredEventAnswer {
@@ -12292,7 +16068,7 @@ pub struct DiscussionAnsweredEventAnswer {
   pub author_association: DiscussionAnsweredEventAnswerAuthorAssociation,
   pub body: String,
   pub child_comment_count: i64,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub discussion_id: i64,
   pub html_url: String,
   pub id: i64,
@@ -12302,24 +16078,28 @@ pub struct DiscussionAnsweredEventAnswer {
   #[builder(default, setter(strip_option))]
   pub reactions: Option<Reactions>,
   pub repository_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionAnsweredEventAnswer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCategory {
-  pub created_at: String,
+  pub created_at: DateTime,
   pub description: String,
   pub emoji: String,
   pub id: i64,
@@ -12330,17 +16110,25 @@ pub struct DiscussionCategory {
   pub node_id: Option<String>,
   pub repository_id: i64,
   pub slug: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 impl DiscussionCategory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 /// A Discussion in a repository.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -12350,7 +16138,7 @@ pub struct Discussion {
   pub active_lock_reason: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub answer_chosen_at: Option<String>,
+  pub answer_chosen_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub answer_chosen_by: Option<User>,
@@ -12362,7 +16150,7 @@ pub struct Discussion {
   pub body: String,
   pub category: DiscussionCategory,
   pub comments: i64,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub html_url: String,
   pub id: i64,
   pub locked: bool,
@@ -12384,20 +16172,24 @@ pub struct Discussion {
   #[builder(default, setter(strip_option))]
   pub timeline_url: Option<String>,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_discussion",
+  feature = "webhook_discussion_comment",
+))]
 impl Discussion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionAnsweredEvent {
@@ -12417,18 +16209,18 @@ pub struct DiscussionAnsweredEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionAnsweredEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCategoryChangedEventChangesCategoryFrom {
-  pub created_at: String,
+  pub created_at: DateTime,
   pub description: String,
   pub emoji: String,
   pub id: i64,
@@ -12439,45 +16231,45 @@ pub struct DiscussionCategoryChangedEventChangesCategoryFrom {
   pub node_id: Option<String>,
   pub repository_id: i64,
   pub slug: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCategoryChangedEventChangesCategoryFrom {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCategoryChangedEventChangesCategory {
   pub from: DiscussionCategoryChangedEventChangesCategoryFrom,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCategoryChangedEventChangesCategory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCategoryChangedEventChanges {
   pub category: DiscussionCategoryChangedEventChangesCategory,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCategoryChangedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCategoryChangedEvent {
@@ -12497,14 +16289,14 @@ pub struct DiscussionCategoryChangedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCategoryChangedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionClosedEvent {
@@ -12523,14 +16315,14 @@ pub struct DiscussionClosedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionClosedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentCreatedComment {
@@ -12538,7 +16330,7 @@ pub struct WebhookDiscussionCommentCreatedComment {
   pub author_association: WebhookDiscussionCommentCreatedCommentAuthorAssociation,
   pub body: String,
   pub child_comment_count: i64,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub discussion_id: i64,
   pub html_url: String,
   pub id: i64,
@@ -12548,20 +16340,20 @@ pub struct WebhookDiscussionCommentCreatedComment {
   pub parent_id: Option<i64>,
   pub reactions: Reactions,
   pub repository_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentCreatedComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentCreated {
@@ -12581,14 +16373,14 @@ pub struct WebhookDiscussionCommentCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentDeletedComment {
@@ -12596,7 +16388,7 @@ pub struct WebhookDiscussionCommentDeletedComment {
   pub author_association: WebhookDiscussionCommentDeletedCommentAuthorAssociation,
   pub body: String,
   pub child_comment_count: i64,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub discussion_id: i64,
   pub html_url: String,
   pub id: i64,
@@ -12606,20 +16398,20 @@ pub struct WebhookDiscussionCommentDeletedComment {
   pub parent_id: Option<i64>,
   pub reactions: Reactions,
   pub repository_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentDeletedComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentDeleted {
@@ -12639,42 +16431,42 @@ pub struct WebhookDiscussionCommentDeleted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentDeleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentEditedChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentEditedChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentEditedChanges {
   pub body: WebhookDiscussionCommentEditedChangesBody,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentEditedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentEditedComment {
@@ -12682,7 +16474,7 @@ pub struct WebhookDiscussionCommentEditedComment {
   pub author_association: WebhookDiscussionCommentEditedCommentAuthorAssociation,
   pub body: String,
   pub child_comment_count: i64,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub discussion_id: i64,
   pub html_url: String,
   pub id: i64,
@@ -12692,20 +16484,20 @@ pub struct WebhookDiscussionCommentEditedComment {
   pub parent_id: Option<i64>,
   pub reactions: Reactions,
   pub repository_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentEditedComment {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookDiscussionCommentEdited {
@@ -12726,20 +16518,20 @@ pub struct WebhookDiscussionCommentEdited {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion_comment",))]
 impl WebhookDiscussionCommentEdited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCreatedEventDiscussionItem2Category {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -12766,17 +16558,17 @@ pub struct DiscussionCreatedEventDiscussionItem2Category {
   pub slug: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCreatedEventDiscussionItem2Category {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCreatedEventDiscussionItem2Reactions {
@@ -12814,14 +16606,14 @@ pub struct DiscussionCreatedEventDiscussionItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCreatedEventDiscussionItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCreatedEventDiscussionItem2User {
@@ -12882,14 +16674,14 @@ pub struct DiscussionCreatedEventDiscussionItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCreatedEventDiscussionItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCreatedEventDiscussionItem2 {
@@ -12915,7 +16707,7 @@ pub struct DiscussionCreatedEventDiscussionItem2 {
   pub comments: Option<i64>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub html_url: Option<String>,
@@ -12944,20 +16736,20 @@ pub struct DiscussionCreatedEventDiscussionItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<DiscussionCreatedEventDiscussionItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCreatedEventDiscussionItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionCreatedEvent {
@@ -12976,14 +16768,14 @@ pub struct DiscussionCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionDeletedEvent {
@@ -13002,42 +16794,42 @@ pub struct DiscussionDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionEditedEventChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionEditedEventChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionEditedEventChangesTitle {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionEditedEventChangesTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionEditedEventChanges {
@@ -13049,14 +16841,14 @@ pub struct DiscussionEditedEventChanges {
   pub title: Option<DiscussionEditedEventChangesTitle>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionEditedEvent {
@@ -13078,14 +16870,14 @@ pub struct DiscussionEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionLabeledEvent {
@@ -13105,14 +16897,14 @@ pub struct DiscussionLabeledEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionLabeledEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionLockedEvent {
@@ -13131,14 +16923,14 @@ pub struct DiscussionLockedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionLockedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionPinnedEvent {
@@ -13157,14 +16949,14 @@ pub struct DiscussionPinnedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionPinnedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionReopenedEvent {
@@ -13183,14 +16975,14 @@ pub struct DiscussionReopenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionReopenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionTransferredEventChanges {
@@ -13198,14 +16990,14 @@ pub struct DiscussionTransferredEventChanges {
   pub new_repository: Repository,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionTransferredEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionTransferredEvent {
@@ -13225,14 +17017,14 @@ pub struct DiscussionTransferredEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionTransferredEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionUnansweredEventOldAnswer {
@@ -13240,7 +17032,7 @@ pub struct DiscussionUnansweredEventOldAnswer {
   pub author_association: DiscussionUnansweredEventOldAnswerAuthorAssociation,
   pub body: String,
   pub child_comment_count: i64,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub discussion_id: i64,
   pub html_url: String,
   pub id: i64,
@@ -13250,20 +17042,20 @@ pub struct DiscussionUnansweredEventOldAnswer {
   #[builder(default, setter(strip_option))]
   pub reactions: Option<Reactions>,
   pub repository_url: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionUnansweredEventOldAnswer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionUnansweredEvent {
@@ -13279,14 +17071,14 @@ pub struct DiscussionUnansweredEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionUnansweredEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionUnlabeledEvent {
@@ -13306,14 +17098,14 @@ pub struct DiscussionUnlabeledEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionUnlabeledEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionUnlockedEvent {
@@ -13332,14 +17124,14 @@ pub struct DiscussionUnlockedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionUnlockedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct DiscussionUnpinnedEvent {
@@ -13358,14 +17150,14 @@ pub struct DiscussionUnpinnedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_discussion",))]
 impl DiscussionUnpinnedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ForkEventForkeeItem2Owner {
@@ -13426,14 +17218,14 @@ pub struct ForkEventForkeeItem2Owner {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 impl ForkEventForkeeItem2Owner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ForkEventForkeeItem2 {
@@ -13478,7 +17270,7 @@ pub struct ForkEventForkeeItem2 {
   pub contributors_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub default_branch: Option<String>,
@@ -13616,7 +17408,7 @@ pub struct ForkEventForkeeItem2 {
   pub pulls_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub pushed_at: Option<String>,
+  pub pushed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub releases_url: Option<String>,
@@ -13658,7 +17450,7 @@ pub struct ForkEventForkeeItem2 {
   pub trees_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -13673,14 +17465,14 @@ pub struct ForkEventForkeeItem2 {
   pub watchers_count: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 impl ForkEventForkeeItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 /// A user forks a repository.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -13700,14 +17492,14 @@ pub struct ForkEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_fork",))]
 impl ForkEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookGithubAppAuthorizationRevoked {
@@ -13715,14 +17507,14 @@ pub struct WebhookGithubAppAuthorizationRevoked {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_github_app_authorization",))]
 impl WebhookGithubAppAuthorizationRevoked {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct GollumEventPages {
@@ -13741,14 +17533,14 @@ pub struct GollumEventPages {
   pub title: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
 impl GollumEventPages {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct GollumEvent {
@@ -13767,14 +17559,14 @@ pub struct GollumEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_gollum",))]
 impl GollumEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationCreatedEventRepositories {
@@ -13788,14 +17580,14 @@ pub struct InstallationCreatedEventRepositories {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationCreatedEventRepositories {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationCreatedEvent {
@@ -13820,14 +17612,14 @@ pub struct InstallationCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationDeletedEventRepositories {
@@ -13841,14 +17633,14 @@ pub struct InstallationDeletedEventRepositories {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationDeletedEventRepositories {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationDeletedEvent {
@@ -13873,14 +17665,14 @@ pub struct InstallationDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationNewPermissionsAcceptedRepositories {
@@ -13894,14 +17686,14 @@ pub struct WebhookInstallationNewPermissionsAcceptedRepositories {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl WebhookInstallationNewPermissionsAcceptedRepositories {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationNewPermissionsAccepted {
@@ -13926,14 +17718,14 @@ pub struct WebhookInstallationNewPermissionsAccepted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl WebhookInstallationNewPermissionsAccepted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationRepositoriesAddedRepositoriesAdded {
@@ -13947,14 +17739,14 @@ pub struct WebhookInstallationRepositoriesAddedRepositoriesAdded {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl WebhookInstallationRepositoriesAddedRepositoriesAdded {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationRepositoriesAddedRepositoriesRemoved {
@@ -13978,14 +17770,14 @@ pub struct WebhookInstallationRepositoriesAddedRepositoriesRemoved {
   pub private: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl WebhookInstallationRepositoriesAddedRepositoriesRemoved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationRepositoriesAdded {
@@ -14012,14 +17804,14 @@ pub struct WebhookInstallationRepositoriesAdded {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl WebhookInstallationRepositoriesAdded {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationRepositoriesRemovedRepositoriesAdded {
@@ -14033,14 +17825,14 @@ pub struct WebhookInstallationRepositoriesRemovedRepositoriesAdded {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl WebhookInstallationRepositoriesRemovedRepositoriesAdded {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationRepositoriesRemovedRepositoriesRemoved {
@@ -14054,14 +17846,14 @@ pub struct WebhookInstallationRepositoriesRemovedRepositoriesRemoved {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl WebhookInstallationRepositoriesRemovedRepositoriesRemoved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationRepositoriesRemoved {
@@ -14088,14 +17880,14 @@ pub struct WebhookInstallationRepositoriesRemoved {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_repositories",))]
 impl WebhookInstallationRepositoriesRemoved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationSuspendEventRepositories {
@@ -14109,14 +17901,14 @@ pub struct InstallationSuspendEventRepositories {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationSuspendEventRepositories {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationSuspendEvent {
@@ -14141,24 +17933,24 @@ pub struct InstallationSuspendEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationSuspendEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationTargetRenamedAccount {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub archived_at: Option<String>,
+  pub archived_at: Option<DateTime>,
   pub avatar_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<serde_json::Value>,
@@ -14246,7 +18038,7 @@ pub struct WebhookInstallationTargetRenamedAccount {
   pub type_: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -14255,42 +18047,42 @@ pub struct WebhookInstallationTargetRenamedAccount {
   pub website_url: Option<serde_json::Value>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 impl WebhookInstallationTargetRenamedAccount {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationTargetRenamedChangesLogin {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 impl WebhookInstallationTargetRenamedChangesLogin {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationTargetRenamedChangesSlug {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 impl WebhookInstallationTargetRenamedChangesSlug {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationTargetRenamedChanges {
@@ -14302,14 +18094,14 @@ pub struct WebhookInstallationTargetRenamedChanges {
   pub slug: Option<WebhookInstallationTargetRenamedChangesSlug>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 impl WebhookInstallationTargetRenamedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookInstallationTargetRenamed {
@@ -14332,14 +18124,14 @@ pub struct WebhookInstallationTargetRenamed {
   pub target_type: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation_target",))]
 impl WebhookInstallationTargetRenamed {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationUnsuspendEventRepositories {
@@ -14353,14 +18145,14 @@ pub struct InstallationUnsuspendEventRepositories {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationUnsuspendEventRepositories {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct InstallationUnsuspendEvent {
@@ -14385,14 +18177,14 @@ pub struct InstallationUnsuspendEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_installation",))]
 impl InstallationUnsuspendEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentCreatedIssueItem2Reactions {
@@ -14430,14 +18222,14 @@ pub struct WebhookIssueCommentCreatedIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentCreatedIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentCreatedIssueItem2User {
@@ -14498,14 +18290,14 @@ pub struct WebhookIssueCommentCreatedIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentCreatedIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentCreatedIssueItem2 {
@@ -14526,7 +18318,7 @@ pub struct WebhookIssueCommentCreatedIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -14535,7 +18327,7 @@ pub struct WebhookIssueCommentCreatedIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -14578,7 +18370,7 @@ pub struct WebhookIssueCommentCreatedIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -14587,14 +18379,14 @@ pub struct WebhookIssueCommentCreatedIssueItem2 {
   pub user: Option<WebhookIssueCommentCreatedIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentCreatedIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentCreated {
@@ -14616,14 +18408,14 @@ pub struct WebhookIssueCommentCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentDeletedIssueItem2Reactions {
@@ -14661,14 +18453,14 @@ pub struct WebhookIssueCommentDeletedIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentDeletedIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentDeletedIssueItem2User {
@@ -14729,14 +18521,14 @@ pub struct WebhookIssueCommentDeletedIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentDeletedIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentDeletedIssueItem2 {
@@ -14757,7 +18549,7 @@ pub struct WebhookIssueCommentDeletedIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -14766,7 +18558,7 @@ pub struct WebhookIssueCommentDeletedIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -14809,7 +18601,7 @@ pub struct WebhookIssueCommentDeletedIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -14818,14 +18610,14 @@ pub struct WebhookIssueCommentDeletedIssueItem2 {
   pub user: Option<WebhookIssueCommentDeletedIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentDeletedIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentDeleted {
@@ -14847,14 +18639,14 @@ pub struct WebhookIssueCommentDeleted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentDeleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentEditedChangesBody {
@@ -14862,14 +18654,14 @@ pub struct WebhookIssueCommentEditedChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentEditedChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 /// The changes to the comment.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -14879,14 +18671,14 @@ pub struct WebhookIssueCommentEditedChanges {
   pub body: Option<WebhookIssueCommentEditedChangesBody>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentEditedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentEditedIssueItem2Reactions {
@@ -14924,14 +18716,14 @@ pub struct WebhookIssueCommentEditedIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentEditedIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentEditedIssueItem2User {
@@ -14992,14 +18784,14 @@ pub struct WebhookIssueCommentEditedIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentEditedIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentEditedIssueItem2 {
@@ -15020,7 +18812,7 @@ pub struct WebhookIssueCommentEditedIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -15029,7 +18821,7 @@ pub struct WebhookIssueCommentEditedIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -15072,7 +18864,7 @@ pub struct WebhookIssueCommentEditedIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -15081,14 +18873,14 @@ pub struct WebhookIssueCommentEditedIssueItem2 {
   pub user: Option<WebhookIssueCommentEditedIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentEditedIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookIssueCommentEdited {
@@ -15112,14 +18904,14 @@ pub struct WebhookIssueCommentEdited {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issue_comment",))]
 impl WebhookIssueCommentEdited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesAssignedEvent {
@@ -15143,14 +18935,14 @@ pub struct IssuesAssignedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesAssignedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesClosedEventIssueItem2Reactions {
@@ -15188,14 +18980,14 @@ pub struct IssuesClosedEventIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesClosedEventIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesClosedEventIssueItem2User {
@@ -15256,14 +19048,14 @@ pub struct IssuesClosedEventIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesClosedEventIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesClosedEventIssueItem2 {
@@ -15284,7 +19076,7 @@ pub struct IssuesClosedEventIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -15293,7 +19085,7 @@ pub struct IssuesClosedEventIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -15339,7 +19131,7 @@ pub struct IssuesClosedEventIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -15348,14 +19140,14 @@ pub struct IssuesClosedEventIssueItem2 {
   pub user: Option<IssuesClosedEventIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesClosedEventIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesClosedEvent {
@@ -15376,14 +19168,14 @@ pub struct IssuesClosedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesClosedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesDeletedEvent {
@@ -15403,14 +19195,14 @@ pub struct IssuesDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesDemilestonedEventIssueItem2Reactions {
@@ -15448,14 +19240,14 @@ pub struct IssuesDemilestonedEventIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesDemilestonedEventIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesDemilestonedEventIssueItem2User {
@@ -15516,14 +19308,14 @@ pub struct IssuesDemilestonedEventIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesDemilestonedEventIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesDemilestonedEventIssueItem2 {
@@ -15544,7 +19336,7 @@ pub struct IssuesDemilestonedEventIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -15553,7 +19345,7 @@ pub struct IssuesDemilestonedEventIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -15602,7 +19394,7 @@ pub struct IssuesDemilestonedEventIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -15611,14 +19403,14 @@ pub struct IssuesDemilestonedEventIssueItem2 {
   pub user: Option<IssuesDemilestonedEventIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesDemilestonedEventIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesDemilestonedEvent {
@@ -15641,14 +19433,14 @@ pub struct IssuesDemilestonedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesDemilestonedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesEditedEventChangesBody {
@@ -15656,14 +19448,14 @@ pub struct IssuesEditedEventChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesEditedEventChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesEditedEventChangesTitle {
@@ -15671,14 +19463,14 @@ pub struct IssuesEditedEventChangesTitle {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesEditedEventChangesTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 /// The changes to the issue.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -15691,14 +19483,14 @@ pub struct IssuesEditedEventChanges {
   pub title: Option<IssuesEditedEventChangesTitle>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesEditedEvent {
@@ -15723,14 +19515,14 @@ pub struct IssuesEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesLabeledEvent {
@@ -15753,14 +19545,14 @@ pub struct IssuesLabeledEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesLabeledEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesLockedEventIssueItem2Reactions {
@@ -15798,14 +19590,14 @@ pub struct IssuesLockedEventIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesLockedEventIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesLockedEventIssueItem2User {
@@ -15866,14 +19658,14 @@ pub struct IssuesLockedEventIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesLockedEventIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesLockedEventIssueItem2 {
@@ -15894,7 +19686,7 @@ pub struct IssuesLockedEventIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -15903,7 +19695,7 @@ pub struct IssuesLockedEventIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -15949,7 +19741,7 @@ pub struct IssuesLockedEventIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -15958,14 +19750,14 @@ pub struct IssuesLockedEventIssueItem2 {
   pub user: Option<IssuesLockedEventIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesLockedEventIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesLockedEvent {
@@ -15984,14 +19776,14 @@ pub struct IssuesLockedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesLockedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesMilestonedEventIssueItem2Reactions {
@@ -16029,14 +19821,14 @@ pub struct IssuesMilestonedEventIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesMilestonedEventIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesMilestonedEventIssueItem2User {
@@ -16097,14 +19889,14 @@ pub struct IssuesMilestonedEventIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesMilestonedEventIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesMilestonedEventIssueItem2 {
@@ -16125,7 +19917,7 @@ pub struct IssuesMilestonedEventIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -16134,7 +19926,7 @@ pub struct IssuesMilestonedEventIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -16181,7 +19973,7 @@ pub struct IssuesMilestonedEventIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -16190,14 +19982,14 @@ pub struct IssuesMilestonedEventIssueItem2 {
   pub user: Option<IssuesMilestonedEventIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesMilestonedEventIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesMilestonedEvent {
@@ -16218,14 +20010,14 @@ pub struct IssuesMilestonedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesMilestonedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesOpenedEventChanges {
@@ -16237,14 +20029,14 @@ pub struct IssuesOpenedEventChanges {
   pub old_repository: Repository,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesOpenedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesOpenedEvent {
@@ -16267,14 +20059,14 @@ pub struct IssuesOpenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesOpenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesPinnedEvent {
@@ -16294,14 +20086,14 @@ pub struct IssuesPinnedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesPinnedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesReopenedEventIssueItem2Reactions {
@@ -16339,14 +20131,14 @@ pub struct IssuesReopenedEventIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesReopenedEventIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesReopenedEventIssueItem2User {
@@ -16407,14 +20199,14 @@ pub struct IssuesReopenedEventIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesReopenedEventIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesReopenedEventIssueItem2 {
@@ -16435,7 +20227,7 @@ pub struct IssuesReopenedEventIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -16444,7 +20236,7 @@ pub struct IssuesReopenedEventIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -16490,7 +20282,7 @@ pub struct IssuesReopenedEventIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -16499,14 +20291,14 @@ pub struct IssuesReopenedEventIssueItem2 {
   pub user: Option<IssuesReopenedEventIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesReopenedEventIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesReopenedEvent {
@@ -16525,14 +20317,14 @@ pub struct IssuesReopenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesReopenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesTransferredEventChanges {
@@ -16542,14 +20334,14 @@ pub struct IssuesTransferredEventChanges {
   pub new_repository: Repository,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesTransferredEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesTransferredEvent {
@@ -16570,14 +20362,14 @@ pub struct IssuesTransferredEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesTransferredEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesUnassignedEvent {
@@ -16601,14 +20393,14 @@ pub struct IssuesUnassignedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesUnassignedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesUnlabeledEvent {
@@ -16631,14 +20423,14 @@ pub struct IssuesUnlabeledEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesUnlabeledEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesUnlockedEventIssueItem2Reactions {
@@ -16676,14 +20468,14 @@ pub struct IssuesUnlockedEventIssueItem2Reactions {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesUnlockedEventIssueItem2Reactions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesUnlockedEventIssueItem2User {
@@ -16744,14 +20536,14 @@ pub struct IssuesUnlockedEventIssueItem2User {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesUnlockedEventIssueItem2User {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesUnlockedEventIssueItem2 {
@@ -16770,7 +20562,7 @@ pub struct IssuesUnlockedEventIssueItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub comments: Option<i64>,
@@ -16779,7 +20571,7 @@ pub struct IssuesUnlockedEventIssueItem2 {
   pub comments_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub events_url: Option<String>,
@@ -16825,7 +20617,7 @@ pub struct IssuesUnlockedEventIssueItem2 {
   pub title: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -16834,14 +20626,14 @@ pub struct IssuesUnlockedEventIssueItem2 {
   pub user: Option<IssuesUnlockedEventIssueItem2User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesUnlockedEventIssueItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesUnlockedEvent {
@@ -16860,14 +20652,14 @@ pub struct IssuesUnlockedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesUnlockedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct IssuesUnpinnedEvent {
@@ -16887,14 +20679,14 @@ pub struct IssuesUnpinnedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_issues",))]
 impl IssuesUnpinnedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct LabelCreatedEvent {
@@ -16915,14 +20707,14 @@ pub struct LabelCreatedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl LabelCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct LabelDeletedEvent {
@@ -16941,14 +20733,14 @@ pub struct LabelDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl LabelDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct LabelEditedEventChangesColor {
@@ -16956,14 +20748,14 @@ pub struct LabelEditedEventChangesColor {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl LabelEditedEventChangesColor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct LabelEditedEventChangesDescription {
@@ -16971,14 +20763,14 @@ pub struct LabelEditedEventChangesDescription {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl LabelEditedEventChangesDescription {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct LabelEditedEventChangesName {
@@ -16986,14 +20778,14 @@ pub struct LabelEditedEventChangesName {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl LabelEditedEventChangesName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 /// The changes to the label if the action was `edited`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17009,14 +20801,14 @@ pub struct LabelEditedEventChanges {
   pub name: Option<LabelEditedEventChangesName>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl LabelEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct LabelEditedEvent {
@@ -17039,7 +20831,7 @@ pub struct LabelEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_label",))]
 impl LabelEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -17091,7 +20883,7 @@ impl MarketplacePurchasePlan {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2Account {
@@ -17113,14 +20905,14 @@ pub struct WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2Account {
   pub type_: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2Account {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2Plan {
@@ -17154,14 +20946,14 @@ pub struct WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2Plan {
   pub yearly_price_in_cents: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2Plan {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2 {
@@ -17188,14 +20980,14 @@ pub struct WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2 {
   pub unit_count: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseCancelledMarketplacePurchaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseCancelled {
@@ -17220,14 +21012,14 @@ pub struct WebhookMarketplacePurchaseCancelled {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseCancelled {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2Account {
@@ -17249,14 +21041,14 @@ pub struct WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2Account {
   pub type_: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2Account {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2Plan {
@@ -17289,14 +21081,14 @@ pub struct WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2Plan {
   pub yearly_price_in_cents: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2Plan {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2 {
@@ -17323,14 +21115,14 @@ pub struct WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2 {
   pub unit_count: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseChangedMarketplacePurchaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchaseChanged {
@@ -17355,14 +21147,14 @@ pub struct WebhookMarketplacePurchaseChanged {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchaseChanged {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2Account {
@@ -17384,14 +21176,14 @@ pub struct WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2Accoun
   pub type_: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2Account {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2Plan {
@@ -17425,14 +21217,14 @@ pub struct WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2Plan {
   pub yearly_price_in_cents: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2Plan {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2 {
@@ -17459,14 +21251,14 @@ pub struct WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2 {
   pub unit_count: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePendingChangeMarketplacePurchaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePendingChange {
@@ -17491,28 +21283,28 @@ pub struct WebhookMarketplacePurchasePendingChange {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePendingChange {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePendingChangeCancelledMarketplacePurchaseItem2 {
   pub next_billing_date: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePendingChangeCancelledMarketplacePurchaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePendingChangeCancelled {
@@ -17537,14 +21329,14 @@ pub struct WebhookMarketplacePurchasePendingChangeCancelled {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePendingChangeCancelled {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2Account {
@@ -17566,14 +21358,14 @@ pub struct WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2Account {
   pub type_: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2Account {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2Plan {
@@ -17607,14 +21399,14 @@ pub struct WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2Plan {
   pub yearly_price_in_cents: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2Plan {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2 {
@@ -17641,14 +21433,14 @@ pub struct WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2 {
   pub unit_count: Option<i64>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePurchasedMarketplacePurchaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMarketplacePurchasePurchased {
@@ -17673,14 +21465,14 @@ pub struct WebhookMarketplacePurchasePurchased {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_marketplace_purchase",))]
 impl WebhookMarketplacePurchasePurchased {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 /// This field is included for legacy purposes; use the `role_name` field instead. The `maintain`
 /// role is mapped to `write` and the `triage` role is mapped to `read`. To determine the role
 /// assigned to the collaborator, use the `role_name` field instead, which will provide the full
@@ -17691,14 +21483,14 @@ pub struct MemberAddedEventChangesPermission {
   pub to: MemberAddedEventChangesPermissionTo,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberAddedEventChangesPermission {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 /// The role assigned to the collaborator.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17706,14 +21498,14 @@ pub struct MemberAddedEventChangesRoleName {
   pub to: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberAddedEventChangesRoleName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MemberAddedEventChanges {
@@ -17730,14 +21522,14 @@ pub struct MemberAddedEventChanges {
   pub role_name: Option<MemberAddedEventChangesRoleName>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberAddedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MemberAddedEvent {
@@ -17761,14 +21553,14 @@ pub struct MemberAddedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberAddedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MemberEditedEventChangesOldPermission {
@@ -17776,14 +21568,14 @@ pub struct MemberEditedEventChangesOldPermission {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberEditedEventChangesOldPermission {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MemberEditedEventChangesPermission {
@@ -17795,14 +21587,14 @@ pub struct MemberEditedEventChangesPermission {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberEditedEventChangesPermission {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 /// The changes to the collaborator permissions
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -17815,14 +21607,14 @@ pub struct MemberEditedEventChanges {
   pub permission: Option<MemberEditedEventChangesPermission>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MemberEditedEvent {
@@ -17845,14 +21637,14 @@ pub struct MemberEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MemberRemovedEvent {
@@ -17873,14 +21665,14 @@ pub struct MemberRemovedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_member",))]
 impl MemberRemovedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MembershipAddedEvent {
@@ -17907,14 +21699,14 @@ pub struct MembershipAddedEvent {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 impl MembershipAddedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MembershipRemovedEvent {
@@ -17941,14 +21733,14 @@ pub struct MembershipRemovedEvent {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_membership",))]
 impl MembershipRemovedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 /// A group of pull requests that the merge queue has grouped together to be merged.
 ///
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
@@ -17965,14 +21757,14 @@ pub struct MergeGroup {
   pub head_sha: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 impl MergeGroup {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMergeGroupChecksRequested {
@@ -17992,14 +21784,14 @@ pub struct WebhookMergeGroupChecksRequested {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 impl WebhookMergeGroupChecksRequested {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookMergeGroupDestroyed {
@@ -18023,14 +21815,14 @@ pub struct WebhookMergeGroupDestroyed {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_merge_group",))]
 impl WebhookMergeGroupDestroyed {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MetaDeletedEventHookConfig {
@@ -18042,37 +21834,37 @@ pub struct MetaDeletedEventHookConfig {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 impl MetaDeletedEventHookConfig {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 /// The modified webhook. This will contain different keys based on the type of webhook it is: repository, organization, business, app, or GitHub Marketplace.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MetaDeletedEventHook {
   pub active: bool,
   pub config: MetaDeletedEventHookConfig,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub events: Vec<MetaDeletedEventHookEvents>,
   pub id: i64,
   pub name: String,
   #[serde(rename = "type")]
   pub type_: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 impl MetaDeletedEventHook {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MetaDeletedEvent {
@@ -18098,14 +21890,14 @@ pub struct MetaDeletedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_meta",))]
 impl MetaDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneClosedEvent {
@@ -18125,14 +21917,14 @@ pub struct MilestoneClosedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneClosedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneCreatedEvent {
@@ -18152,14 +21944,14 @@ pub struct MilestoneCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneDeletedEvent {
@@ -18179,14 +21971,14 @@ pub struct MilestoneDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneEditedEventChangesDescription {
@@ -18194,14 +21986,14 @@ pub struct MilestoneEditedEventChangesDescription {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneEditedEventChangesDescription {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneEditedEventChangesDueOn {
@@ -18209,14 +22001,14 @@ pub struct MilestoneEditedEventChangesDueOn {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneEditedEventChangesDueOn {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneEditedEventChangesTitle {
@@ -18224,14 +22016,14 @@ pub struct MilestoneEditedEventChangesTitle {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneEditedEventChangesTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 /// The changes to the milestone if the action was `edited`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -18247,14 +22039,14 @@ pub struct MilestoneEditedEventChanges {
   pub title: Option<MilestoneEditedEventChangesTitle>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneEditedEvent {
@@ -18276,14 +22068,14 @@ pub struct MilestoneEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct MilestoneOpenedEvent {
@@ -18303,14 +22095,14 @@ pub struct MilestoneOpenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_milestone",))]
 impl MilestoneOpenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookOrgBlockBlocked {
@@ -18331,14 +22123,14 @@ pub struct WebhookOrgBlockBlocked {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 impl WebhookOrgBlockBlocked {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookOrgBlockUnblocked {
@@ -18359,14 +22151,14 @@ pub struct WebhookOrgBlockUnblocked {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_org_block",))]
 impl WebhookOrgBlockUnblocked {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 /// The membership between the user and the organization. Not present when the action is `member_invited`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -18380,14 +22172,14 @@ pub struct Membership {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl Membership {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationDeletedEvent {
@@ -18409,14 +22201,14 @@ pub struct OrganizationDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl OrganizationDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookOrganizationMemberAdded {
@@ -18436,25 +22228,25 @@ pub struct WebhookOrganizationMemberAdded {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl WebhookOrganizationMemberAdded {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 /// The invitation for the user or email if the action is `member_invited`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookOrganizationMemberInvitedInvitation {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub email: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub failed_at: Option<String>,
+  pub failed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub failed_reason: Option<String>,
@@ -18474,14 +22266,14 @@ pub struct WebhookOrganizationMemberInvitedInvitation {
   pub team_count: f64,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl WebhookOrganizationMemberInvitedInvitation {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookOrganizationMemberInvited {
@@ -18504,14 +22296,14 @@ pub struct WebhookOrganizationMemberInvited {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl WebhookOrganizationMemberInvited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookOrganizationMemberRemoved {
@@ -18531,14 +22323,14 @@ pub struct WebhookOrganizationMemberRemoved {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl WebhookOrganizationMemberRemoved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationRenamedEventChangesLogin {
@@ -18547,14 +22339,14 @@ pub struct OrganizationRenamedEventChangesLogin {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl OrganizationRenamedEventChangesLogin {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationRenamedEventChanges {
@@ -18563,14 +22355,14 @@ pub struct OrganizationRenamedEventChanges {
   pub login: Option<OrganizationRenamedEventChangesLogin>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl OrganizationRenamedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct OrganizationRenamedEvent {
@@ -18595,14 +22387,14 @@ pub struct OrganizationRenamedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_organization",))]
 impl OrganizationRenamedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersionContainerMetadataTag {
@@ -18614,14 +22406,14 @@ pub struct PackagePublishedEventPackagePackageVersionContainerMetadataTag {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersionContainerMetadataTag {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersionContainerMetadata {
@@ -18636,14 +22428,14 @@ pub struct PackagePublishedEventPackagePackageVersionContainerMetadata {
   pub tag: Option<PackagePublishedEventPackagePackageVersionContainerMetadataTag>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersionContainerMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersionDockerMetadata {
@@ -18652,14 +22444,14 @@ pub struct PackagePublishedEventPackagePackageVersionDockerMetadata {
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersionDockerMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersionNpmMetadata {
@@ -18776,7 +22568,7 @@ pub struct PackagePublishedEventPackagePackageVersionNpmMetadata {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersionNpmMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -18809,7 +22601,7 @@ impl PackagePublishedEventPackagePackageVersionNugetMetadataValueItem4 {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersionNugetMetadata {
@@ -18824,19 +22616,19 @@ pub struct PackagePublishedEventPackagePackageVersionNugetMetadata {
   pub value: Option<serde_json::Value>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersionNugetMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersionPackageFiles {
   pub content_type: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub download_url: String,
   pub id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -18853,24 +22645,24 @@ pub struct PackagePublishedEventPackagePackageVersionPackageFiles {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub state: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersionPackageFiles {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersionRelease {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub author: Option<User>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub draft: bool,
   pub html_url: String,
   pub id: i64,
@@ -18878,20 +22670,24 @@ pub struct PackagePublishedEventPackagePackageVersionRelease {
   #[builder(default, setter(strip_option))]
   pub name: Option<String>,
   pub prerelease: bool,
-  pub published_at: String,
+  pub published_at: DateTime,
   pub tag_name: String,
   pub target_commitish: String,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersionRelease {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_package",
+  feature = "webhook_registry_package",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RubyGemsMetadataVersionInfo {
@@ -18900,14 +22696,22 @@ pub struct RubyGemsMetadataVersionInfo {
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_package",
+  feature = "webhook_registry_package",
+))]
 impl RubyGemsMetadataVersionInfo {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_package",
+  feature = "webhook_registry_package",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RubyGemsMetadata {
@@ -18943,14 +22747,18 @@ pub struct RubyGemsMetadata {
   pub version_info: Option<RubyGemsMetadataVersionInfo>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_package",
+  feature = "webhook_registry_package",
+))]
 impl RubyGemsMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackagePackageVersion {
@@ -18968,7 +22776,7 @@ pub struct PackagePublishedEventPackagePackageVersion {
   pub container_metadata: Option<PackagePublishedEventPackagePackageVersionContainerMetadata>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   pub description: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -19018,18 +22826,18 @@ pub struct PackagePublishedEventPackagePackageVersion {
   pub target_oid: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub version: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackagePackageVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackageRegistry {
@@ -19041,21 +22849,21 @@ pub struct PackagePublishedEventPackageRegistry {
   pub vendor: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackageRegistry {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 /// Information about the package.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEventPackage {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -19076,17 +22884,17 @@ pub struct PackagePublishedEventPackage {
   pub registry: Option<PackagePublishedEventPackageRegistry>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEventPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackagePublishedEvent {
@@ -19108,14 +22916,14 @@ pub struct PackagePublishedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackagePublishedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageUpdatedEventPackagePackageVersionDockerMetadata {
@@ -19124,19 +22932,19 @@ pub struct PackageUpdatedEventPackagePackageVersionDockerMetadata {
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackageUpdatedEventPackagePackageVersionDockerMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageUpdatedEventPackagePackageVersionPackageFiles {
   pub content_type: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub download_url: String,
   pub id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -19149,43 +22957,43 @@ pub struct PackageUpdatedEventPackagePackageVersionPackageFiles {
   pub sha256: String,
   pub size: i64,
   pub state: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackageUpdatedEventPackagePackageVersionPackageFiles {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageUpdatedEventPackagePackageVersionRelease {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub author: Option<User>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub draft: bool,
   pub html_url: String,
   pub id: i64,
   pub name: String,
   pub prerelease: bool,
-  pub published_at: String,
+  pub published_at: DateTime,
   pub tag_name: String,
   pub target_commitish: String,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackageUpdatedEventPackagePackageVersionRelease {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageUpdatedEventPackagePackageVersion {
@@ -19194,7 +23002,7 @@ pub struct PackageUpdatedEventPackagePackageVersion {
   pub author: Option<User>,
   pub body: String,
   pub body_html: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub description: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -19232,18 +23040,18 @@ pub struct PackageUpdatedEventPackagePackageVersion {
   pub tag_name: Option<String>,
   pub target_commitish: String,
   pub target_oid: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub version: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackageUpdatedEventPackagePackageVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageUpdatedEventPackageRegistry {
@@ -19255,19 +23063,19 @@ pub struct PackageUpdatedEventPackageRegistry {
   pub vendor: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackageUpdatedEventPackageRegistry {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 /// Information about the package.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageUpdatedEventPackage {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -19284,17 +23092,17 @@ pub struct PackageUpdatedEventPackage {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub registry: Option<PackageUpdatedEventPackageRegistry>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackageUpdatedEventPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PackageUpdatedEvent {
@@ -19314,14 +23122,14 @@ pub struct PackageUpdatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_package",))]
 impl PackageUpdatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPageBuildBuildError {
@@ -19330,14 +23138,14 @@ pub struct WebhookPageBuildBuildError {
   pub message: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
 impl WebhookPageBuildBuildError {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
 /// The [List GitHub Pages builds](https://docs.github.com/rest/pages/pages#list-github-pages-builds) itself.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19345,25 +23153,25 @@ pub struct WebhookPageBuildBuild {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub commit: Option<String>,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub duration: i64,
   pub error: WebhookPageBuildBuildError,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub pusher: Option<User>,
   pub status: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
 impl WebhookPageBuildBuild {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPageBuild {
@@ -19384,14 +23192,14 @@ pub struct WebhookPageBuild {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_page_build",))]
 impl WebhookPageBuild {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 /// New requested permissions, categorized by type of permission.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19407,14 +23215,14 @@ pub struct PersonalAccessTokenRequestPermissionsAdded {
   pub repository: Option<serde_json::Value>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl PersonalAccessTokenRequestPermissionsAdded {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 /// Permissions requested, categorized by type of permission. This field incorporates `permissions_added` and `permissions_upgraded`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19430,14 +23238,14 @@ pub struct PersonalAccessTokenRequestPermissionsResult {
   pub repository: Option<serde_json::Value>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl PersonalAccessTokenRequestPermissionsResult {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 /// Requested permissions that elevate access for a previously approved request for access, categorized by type of permission.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -19453,14 +23261,14 @@ pub struct PersonalAccessTokenRequestPermissionsUpgraded {
   pub repository: Option<serde_json::Value>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl PersonalAccessTokenRequestPermissionsUpgraded {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PersonalAccessTokenRequestRepositories {
@@ -19474,20 +23282,20 @@ pub struct PersonalAccessTokenRequestRepositories {
   pub private: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl PersonalAccessTokenRequestRepositories {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 /// Details of a Personal Access Token Request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PersonalAccessTokenRequest {
   /// Date and time when the request for access was created.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// Unique identifier of the request for access via fine-grained personal access token. Used as the `pat_request_id` parameter in the list and review API calls.
   pub id: i64,
   pub owner: SimpleUser,
@@ -19512,21 +23320,21 @@ pub struct PersonalAccessTokenRequest {
   /// Date and time when the associated fine-grained personal access token expires.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub token_expires_at: Option<String>,
+  pub token_expires_at: Option<DateTime>,
   /// Date and time when the associated fine-grained personal access token was last used for authentication.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub token_last_used_at: Option<String>,
+  pub token_last_used_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl PersonalAccessTokenRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPersonalAccessTokenRequestApproved {
@@ -19537,14 +23345,14 @@ pub struct WebhookPersonalAccessTokenRequestApproved {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl WebhookPersonalAccessTokenRequestApproved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPersonalAccessTokenRequestCancelled {
@@ -19555,14 +23363,14 @@ pub struct WebhookPersonalAccessTokenRequestCancelled {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl WebhookPersonalAccessTokenRequestCancelled {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPersonalAccessTokenRequestCreated {
@@ -19573,14 +23381,14 @@ pub struct WebhookPersonalAccessTokenRequestCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl WebhookPersonalAccessTokenRequestCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPersonalAccessTokenRequestDenied {
@@ -19591,14 +23399,14 @@ pub struct WebhookPersonalAccessTokenRequestDenied {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_personal_access_token_request",))]
 impl WebhookPersonalAccessTokenRequestDenied {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_ping",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPing {
@@ -19625,42 +23433,42 @@ pub struct WebhookPing {
   pub zen: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_ping",))]
 impl WebhookPing {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardConvertedChangesNote {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardConvertedChangesNote {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardConvertedChanges {
   pub note: WebhookProjectCardConvertedChangesNote,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardConvertedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardConverted {
@@ -19682,14 +23490,14 @@ pub struct WebhookProjectCardConverted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardConverted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardCreated {
@@ -19710,14 +23518,14 @@ pub struct WebhookProjectCardCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardDeleted {
@@ -19738,14 +23546,14 @@ pub struct WebhookProjectCardDeleted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardDeleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardEditedChangesNote {
@@ -19754,28 +23562,28 @@ pub struct WebhookProjectCardEditedChangesNote {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardEditedChangesNote {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardEditedChanges {
   pub note: WebhookProjectCardEditedChangesNote,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardEditedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardEdited {
@@ -19797,42 +23605,42 @@ pub struct WebhookProjectCardEdited {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardEdited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardMovedChangesColumnId {
   pub from: i64,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardMovedChangesColumnId {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardMovedChanges {
   pub column_id: WebhookProjectCardMovedChangesColumnId,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardMovedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardMovedProjectCardItem2Creator {
@@ -19893,14 +23701,14 @@ pub struct WebhookProjectCardMovedProjectCardItem2Creator {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardMovedProjectCardItem2Creator {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardMovedProjectCardItem2 {
@@ -19918,7 +23726,7 @@ pub struct WebhookProjectCardMovedProjectCardItem2 {
   pub column_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<WebhookProjectCardMovedProjectCardItem2Creator>,
@@ -19936,20 +23744,20 @@ pub struct WebhookProjectCardMovedProjectCardItem2 {
   pub project_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardMovedProjectCardItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectCardMoved {
@@ -19973,14 +23781,14 @@ pub struct WebhookProjectCardMoved {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_card",))]
 impl WebhookProjectCardMoved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectClosedEvent {
@@ -20001,14 +23809,14 @@ pub struct ProjectClosedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectClosedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectColumnCreated {
@@ -20031,14 +23839,14 @@ pub struct WebhookProjectColumnCreated {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl WebhookProjectColumnCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectColumnDeleted {
@@ -20061,28 +23869,28 @@ pub struct WebhookProjectColumnDeleted {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl WebhookProjectColumnDeleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectColumnEditedChangesName {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl WebhookProjectColumnEditedChangesName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectColumnEditedChanges {
@@ -20091,14 +23899,14 @@ pub struct WebhookProjectColumnEditedChanges {
   pub name: Option<WebhookProjectColumnEditedChangesName>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl WebhookProjectColumnEditedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectColumnEdited {
@@ -20122,14 +23930,14 @@ pub struct WebhookProjectColumnEdited {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl WebhookProjectColumnEdited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookProjectColumnMoved {
@@ -20150,14 +23958,14 @@ pub struct WebhookProjectColumnMoved {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project_column",))]
 impl WebhookProjectColumnMoved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectCreatedEvent {
@@ -20178,14 +23986,14 @@ pub struct ProjectCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectDeletedEvent {
@@ -20208,14 +24016,14 @@ pub struct ProjectDeletedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectEditedEventChangesBody {
@@ -20223,14 +24031,14 @@ pub struct ProjectEditedEventChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectEditedEventChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectEditedEventChangesName {
@@ -20238,14 +24046,14 @@ pub struct ProjectEditedEventChangesName {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectEditedEventChangesName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 /// The changes to the project if the action was `edited`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -20258,14 +24066,14 @@ pub struct ProjectEditedEventChanges {
   pub name: Option<ProjectEditedEventChangesName>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectEditedEvent {
@@ -20292,14 +24100,14 @@ pub struct ProjectEditedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectReopenedEvent {
@@ -20320,26 +24128,26 @@ pub struct ProjectReopenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_project",))]
 impl ProjectReopenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 /// A projects v2 project
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2Project {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
-  pub created_at: String,
+  pub closed_at: Option<DateTime>,
+  pub created_at: DateTime,
   pub creator: SimpleUser,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub deleted_at: Option<String>,
+  pub deleted_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub deleted_by: Option<SimpleUser>,
@@ -20355,17 +24163,17 @@ pub struct ProjectsV2Project {
   #[builder(default, setter(strip_option))]
   pub short_description: Option<String>,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2Project {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectClosedEvent {
@@ -20378,14 +24186,14 @@ pub struct ProjectsV2ProjectClosedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectClosedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 /// A project was created
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -20399,14 +24207,14 @@ pub struct WebhookProjectsV2ProjectCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl WebhookProjectsV2ProjectCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectDeletedEvent {
@@ -20419,14 +24227,14 @@ pub struct ProjectsV2ProjectDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectEditedEventChangesDescription {
@@ -20438,14 +24246,14 @@ pub struct ProjectsV2ProjectEditedEventChangesDescription {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectEditedEventChangesDescription {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectEditedEventChangesPublic {
@@ -20457,14 +24265,14 @@ pub struct ProjectsV2ProjectEditedEventChangesPublic {
   pub to: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectEditedEventChangesPublic {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectEditedEventChangesShortDescription {
@@ -20476,14 +24284,14 @@ pub struct ProjectsV2ProjectEditedEventChangesShortDescription {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectEditedEventChangesShortDescription {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectEditedEventChangesTitle {
@@ -20495,14 +24303,14 @@ pub struct ProjectsV2ProjectEditedEventChangesTitle {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectEditedEventChangesTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectEditedEventChanges {
@@ -20520,14 +24328,14 @@ pub struct ProjectsV2ProjectEditedEventChanges {
   pub title: Option<ProjectsV2ProjectEditedEventChangesTitle>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectEditedEvent {
@@ -20541,14 +24349,14 @@ pub struct ProjectsV2ProjectEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemArchivedEventChangesArchivedAt {
@@ -20560,14 +24368,14 @@ pub struct ProjectsV2ItemArchivedEventChangesArchivedAt {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemArchivedEventChangesArchivedAt {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemArchivedEventChanges {
@@ -20576,24 +24384,24 @@ pub struct ProjectsV2ItemArchivedEventChanges {
   pub archived_at: Option<ProjectsV2ItemArchivedEventChangesArchivedAt>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemArchivedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 /// An item belonging to a project
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2Item {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub archived_at: Option<String>,
+  pub archived_at: Option<DateTime>,
   pub content_node_id: String,
   pub content_type: ProjectsV2ItemContentType,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub creator: Option<SimpleUser>,
@@ -20604,17 +24412,17 @@ pub struct ProjectsV2Item {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub project_node_id: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2Item {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemArchivedEvent {
@@ -20628,14 +24436,14 @@ pub struct ProjectsV2ItemArchivedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemArchivedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemConvertedEventChangesContentType {
@@ -20647,14 +24455,14 @@ pub struct ProjectsV2ItemConvertedEventChangesContentType {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemConvertedEventChangesContentType {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemConvertedEventChanges {
@@ -20663,14 +24471,14 @@ pub struct ProjectsV2ItemConvertedEventChanges {
   pub content_type: Option<ProjectsV2ItemConvertedEventChangesContentType>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemConvertedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemConvertedEvent {
@@ -20684,14 +24492,14 @@ pub struct ProjectsV2ItemConvertedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemConvertedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemCreatedEvent {
@@ -20704,14 +24512,14 @@ pub struct ProjectsV2ItemCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemDeletedEvent {
@@ -20724,14 +24532,14 @@ pub struct ProjectsV2ItemDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemEditedEventChangesItem1FieldValue {
@@ -20743,28 +24551,28 @@ pub struct ProjectsV2ItemEditedEventChangesItem1FieldValue {
   pub field_type: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemEditedEventChangesItem1FieldValue {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemEditedEventChangesItem1 {
   pub field_value: ProjectsV2ItemEditedEventChangesItem1FieldValue,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemEditedEventChangesItem1 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemEditedEventChangesItem2Body {
@@ -20776,28 +24584,28 @@ pub struct ProjectsV2ItemEditedEventChangesItem2Body {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemEditedEventChangesItem2Body {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemEditedEventChangesItem2 {
   pub body: ProjectsV2ItemEditedEventChangesItem2Body,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemEditedEventChangesItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemEditedEvent {
@@ -20813,14 +24621,14 @@ pub struct ProjectsV2ItemEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemReorderedEventChangesPreviousProjectsV2ItemNodeId {
@@ -20832,14 +24640,14 @@ pub struct ProjectsV2ItemReorderedEventChangesPreviousProjectsV2ItemNodeId {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemReorderedEventChangesPreviousProjectsV2ItemNodeId {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemReorderedEventChanges {
@@ -20849,14 +24657,14 @@ pub struct ProjectsV2ItemReorderedEventChanges {
     Option<ProjectsV2ItemReorderedEventChangesPreviousProjectsV2ItemNodeId>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemReorderedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemReorderedEvent {
@@ -20870,14 +24678,14 @@ pub struct ProjectsV2ItemReorderedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemReorderedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemRestoredEventChangesArchivedAt {
@@ -20889,14 +24697,14 @@ pub struct ProjectsV2ItemRestoredEventChangesArchivedAt {
   pub to: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemRestoredEventChangesArchivedAt {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemRestoredEventChanges {
@@ -20905,14 +24713,14 @@ pub struct ProjectsV2ItemRestoredEventChanges {
   pub archived_at: Option<ProjectsV2ItemRestoredEventChangesArchivedAt>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemRestoredEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ItemRestoredEvent {
@@ -20926,14 +24734,14 @@ pub struct ProjectsV2ItemRestoredEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2_item",))]
 impl ProjectsV2ItemRestoredEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ProjectsV2ProjectReopenedEvent {
@@ -20946,14 +24754,14 @@ pub struct ProjectsV2ProjectReopenedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_projects_v2",))]
 impl ProjectsV2ProjectReopenedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_public",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PublicEvent {
@@ -20970,14 +24778,19 @@ pub struct PublicEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_public",))]
 impl PublicEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 /// The status of auto merging a pull request.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -20997,14 +24810,19 @@ pub struct PullRequestAutoMerge {
   pub merge_method: PullRequestAutoMergeMergeMethod,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review_comment",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl PullRequestAutoMerge {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestAssigned {
@@ -21028,14 +24846,14 @@ pub struct WebhookPullRequestAssigned {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestAssigned {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestAutoMergeDisabled {
@@ -21056,14 +24874,14 @@ pub struct WebhookPullRequestAutoMergeDisabled {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestAutoMergeDisabled {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestAutoMergeEnabled {
@@ -21086,14 +24904,14 @@ pub struct WebhookPullRequestAutoMergeEnabled {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestAutoMergeEnabled {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestClosedPullRequestItem2 {
@@ -21143,14 +24961,14 @@ pub struct WebhookPullRequestClosedPullRequestItem2 {
   pub use_squash_pr_title_as_default: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestClosedPullRequestItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestClosed {
@@ -21171,14 +24989,14 @@ pub struct WebhookPullRequestClosed {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestClosed {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestConvertedToDraftPullRequestItem2 {
@@ -21230,14 +25048,14 @@ pub struct WebhookPullRequestConvertedToDraftPullRequestItem2 {
   pub use_squash_pr_title_as_default: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestConvertedToDraftPullRequestItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestConvertedToDraft {
@@ -21258,14 +25076,14 @@ pub struct WebhookPullRequestConvertedToDraft {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestConvertedToDraft {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestDemilestoned {
@@ -21288,14 +25106,14 @@ pub struct WebhookPullRequestDemilestoned {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestDemilestoned {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestDequeued {
@@ -21316,42 +25134,42 @@ pub struct WebhookPullRequestDequeued {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestDequeued {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEditedChangesBaseRef {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEditedChangesBaseRef {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEditedChangesBaseSha {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEditedChangesBaseSha {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEditedChangesBase {
@@ -21360,14 +25178,14 @@ pub struct WebhookPullRequestEditedChangesBase {
   pub sha: WebhookPullRequestEditedChangesBaseSha,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEditedChangesBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEditedChangesBody {
@@ -21375,14 +25193,14 @@ pub struct WebhookPullRequestEditedChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEditedChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEditedChangesTitle {
@@ -21390,14 +25208,14 @@ pub struct WebhookPullRequestEditedChangesTitle {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEditedChangesTitle {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 /// The changes to the comment if the action was `edited`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -21413,14 +25231,14 @@ pub struct WebhookPullRequestEditedChanges {
   pub title: Option<WebhookPullRequestEditedChangesTitle>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEditedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEditedPullRequestItem2 {
@@ -21470,14 +25288,14 @@ pub struct WebhookPullRequestEditedPullRequestItem2 {
   pub use_squash_pr_title_as_default: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEditedPullRequestItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEdited {
@@ -21502,14 +25320,14 @@ pub struct WebhookPullRequestEdited {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEdited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestEnqueued {
@@ -21529,14 +25347,14 @@ pub struct WebhookPullRequestEnqueued {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestEnqueued {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestLabeled {
@@ -21560,14 +25378,14 @@ pub struct WebhookPullRequestLabeled {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestLabeled {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestLocked {
@@ -21588,14 +25406,14 @@ pub struct WebhookPullRequestLocked {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestLocked {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestMilestoned {
@@ -21618,14 +25436,14 @@ pub struct WebhookPullRequestMilestoned {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestMilestoned {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestOpenedPullRequestItem2 {
@@ -21675,14 +25493,14 @@ pub struct WebhookPullRequestOpenedPullRequestItem2 {
   pub use_squash_pr_title_as_default: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestOpenedPullRequestItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestOpened {
@@ -21703,14 +25521,14 @@ pub struct WebhookPullRequestOpened {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestOpened {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReadyForReviewPullRequestItem2 {
@@ -21761,14 +25579,14 @@ pub struct WebhookPullRequestReadyForReviewPullRequestItem2 {
   pub use_squash_pr_title_as_default: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReadyForReviewPullRequestItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReadyForReview {
@@ -21789,14 +25607,14 @@ pub struct WebhookPullRequestReadyForReview {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReadyForReview {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReopenedPullRequestItem2 {
@@ -21846,14 +25664,14 @@ pub struct WebhookPullRequestReopenedPullRequestItem2 {
   pub use_squash_pr_title_as_default: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReopenedPullRequestItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReopened {
@@ -21874,14 +25692,14 @@ pub struct WebhookPullRequestReopened {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReopened {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentCreatedPullRequestLinks {
@@ -21896,14 +25714,14 @@ pub struct WebhookPullRequestReviewCommentCreatedPullRequestLinks {
   pub statuses: Link,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentCreatedPullRequestLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentCreatedPullRequestBase {
@@ -21918,14 +25736,14 @@ pub struct WebhookPullRequestReviewCommentCreatedPullRequestBase {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentCreatedPullRequestBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentCreatedPullRequestHead {
@@ -21942,14 +25760,14 @@ pub struct WebhookPullRequestReviewCommentCreatedPullRequestHead {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentCreatedPullRequestHead {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentCreatedPullRequest {
@@ -21974,10 +25792,10 @@ pub struct WebhookPullRequestReviewCommentCreatedPullRequest {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub comments_url: String,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub diff_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -21993,7 +25811,7 @@ pub struct WebhookPullRequestReviewCommentCreatedPullRequest {
   pub merge_commit_sha: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   /// A collection of related issues and pull requests.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22008,21 +25826,21 @@ pub struct WebhookPullRequestReviewCommentCreatedPullRequest {
   pub state: WebhookPullRequestReviewCommentCreatedPullRequestState,
   pub statuses_url: String,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentCreatedPullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentCreated {
@@ -22043,14 +25861,14 @@ pub struct WebhookPullRequestReviewCommentCreated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentDeletedPullRequestLinks {
@@ -22065,14 +25883,14 @@ pub struct WebhookPullRequestReviewCommentDeletedPullRequestLinks {
   pub statuses: Link,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentDeletedPullRequestLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentDeletedPullRequestBase {
@@ -22087,14 +25905,14 @@ pub struct WebhookPullRequestReviewCommentDeletedPullRequestBase {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentDeletedPullRequestBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentDeletedPullRequestHead {
@@ -22111,14 +25929,14 @@ pub struct WebhookPullRequestReviewCommentDeletedPullRequestHead {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentDeletedPullRequestHead {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentDeletedPullRequest {
@@ -22143,10 +25961,10 @@ pub struct WebhookPullRequestReviewCommentDeletedPullRequest {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub comments_url: String,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub diff_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22162,7 +25980,7 @@ pub struct WebhookPullRequestReviewCommentDeletedPullRequest {
   pub merge_commit_sha: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   /// A collection of related issues and pull requests.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22177,21 +25995,21 @@ pub struct WebhookPullRequestReviewCommentDeletedPullRequest {
   pub state: WebhookPullRequestReviewCommentDeletedPullRequestState,
   pub statuses_url: String,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentDeletedPullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentDeleted {
@@ -22212,14 +26030,14 @@ pub struct WebhookPullRequestReviewCommentDeleted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentDeleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentEditedChangesBody {
@@ -22227,14 +26045,14 @@ pub struct WebhookPullRequestReviewCommentEditedChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentEditedChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 /// The changes to the comment.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22244,14 +26062,14 @@ pub struct WebhookPullRequestReviewCommentEditedChanges {
   pub body: Option<WebhookPullRequestReviewCommentEditedChangesBody>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentEditedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentEditedPullRequestLinks {
@@ -22266,14 +26084,14 @@ pub struct WebhookPullRequestReviewCommentEditedPullRequestLinks {
   pub statuses: Link,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentEditedPullRequestLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentEditedPullRequestBase {
@@ -22288,14 +26106,14 @@ pub struct WebhookPullRequestReviewCommentEditedPullRequestBase {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentEditedPullRequestBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentEditedPullRequestHead {
@@ -22312,14 +26130,14 @@ pub struct WebhookPullRequestReviewCommentEditedPullRequestHead {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentEditedPullRequestHead {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentEditedPullRequest {
@@ -22344,10 +26162,10 @@ pub struct WebhookPullRequestReviewCommentEditedPullRequest {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub comments_url: String,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub diff_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22363,7 +26181,7 @@ pub struct WebhookPullRequestReviewCommentEditedPullRequest {
   pub merge_commit_sha: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   /// A collection of related issues and pull requests.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22378,21 +26196,21 @@ pub struct WebhookPullRequestReviewCommentEditedPullRequest {
   pub state: WebhookPullRequestReviewCommentEditedPullRequestState,
   pub statuses_url: String,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentEditedPullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewCommentEdited {
@@ -22415,14 +26233,18 @@ pub struct WebhookPullRequestReviewCommentEdited {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_comment",))]
 impl WebhookPullRequestReviewCommentEdited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SimplePullRequestLinks {
@@ -22437,14 +26259,22 @@ pub struct SimplePullRequestLinks {
   pub statuses: Link,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl SimplePullRequestLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SimplePullRequestBase {
@@ -22459,14 +26289,22 @@ pub struct SimplePullRequestBase {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl SimplePullRequestBase {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SimplePullRequestHead {
@@ -22483,14 +26321,22 @@ pub struct SimplePullRequestHead {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl SimplePullRequestHead {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SimplePullRequest {
@@ -22515,10 +26361,10 @@ pub struct SimplePullRequest {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub closed_at: Option<String>,
+  pub closed_at: Option<DateTime>,
   pub comments_url: String,
   pub commits_url: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub diff_url: String,
   pub draft: bool,
   pub head: SimplePullRequestHead,
@@ -22532,7 +26378,7 @@ pub struct SimplePullRequest {
   pub merge_commit_sha: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub merged_at: Option<String>,
+  pub merged_at: Option<DateTime>,
   /// A collection of related issues and pull requests.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -22547,21 +26393,25 @@ pub struct SimplePullRequest {
   pub state: SimplePullRequestState,
   pub statuses_url: String,
   pub title: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_pull_request_review",
+  feature = "webhook_pull_request_review_thread",
+))]
 impl SimplePullRequest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewDismissedReviewLinks {
@@ -22569,14 +26419,14 @@ pub struct WebhookPullRequestReviewDismissedReviewLinks {
   pub pull_request: Link,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewDismissedReviewLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 /// The review that was affected.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22597,20 +26447,20 @@ pub struct WebhookPullRequestReviewDismissedReview {
   pub node_id: String,
   pub pull_request_url: String,
   pub state: WebhookPullRequestReviewDismissedReviewState,
-  pub submitted_at: String,
+  pub submitted_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewDismissedReview {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewDismissed {
@@ -22631,14 +26481,14 @@ pub struct WebhookPullRequestReviewDismissed {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewDismissed {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewEditedChangesBody {
@@ -22646,14 +26496,14 @@ pub struct WebhookPullRequestReviewEditedChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewEditedChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewEditedChanges {
@@ -22662,14 +26512,14 @@ pub struct WebhookPullRequestReviewEditedChanges {
   pub body: Option<WebhookPullRequestReviewEditedChangesBody>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewEditedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewEditedReviewLinks {
@@ -22677,14 +26527,14 @@ pub struct WebhookPullRequestReviewEditedReviewLinks {
   pub pull_request: Link,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewEditedReviewLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 /// The review that was affected.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22707,20 +26557,20 @@ pub struct WebhookPullRequestReviewEditedReview {
   pub state: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub submitted_at: Option<String>,
+  pub submitted_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewEditedReview {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewEdited {
@@ -22742,14 +26592,14 @@ pub struct WebhookPullRequestReviewEdited {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewEdited {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewRequestRemovedItem1 {
@@ -22773,14 +26623,14 @@ pub struct WebhookPullRequestReviewRequestRemovedItem1 {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReviewRequestRemovedItem1 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewRequestRemovedItem2 {
@@ -22803,14 +26653,14 @@ pub struct WebhookPullRequestReviewRequestRemovedItem2 {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReviewRequestRemovedItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewRequestedItem1 {
@@ -22834,14 +26684,14 @@ pub struct WebhookPullRequestReviewRequestedItem1 {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReviewRequestedItem1 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewRequestedItem2 {
@@ -22864,14 +26714,14 @@ pub struct WebhookPullRequestReviewRequestedItem2 {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestReviewRequestedItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewSubmittedReviewLinks {
@@ -22879,14 +26729,14 @@ pub struct WebhookPullRequestReviewSubmittedReviewLinks {
   pub pull_request: Link,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewSubmittedReviewLinks {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 /// The review that was affected.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -22909,20 +26759,20 @@ pub struct WebhookPullRequestReviewSubmittedReview {
   pub state: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub submitted_at: Option<String>,
+  pub submitted_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewSubmittedReview {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewSubmitted {
@@ -22943,14 +26793,14 @@ pub struct WebhookPullRequestReviewSubmitted {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review",))]
 impl WebhookPullRequestReviewSubmitted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewThreadResolvedThread {
@@ -22958,14 +26808,14 @@ pub struct WebhookPullRequestReviewThreadResolvedThread {
   pub node_id: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 impl WebhookPullRequestReviewThreadResolvedThread {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewThreadResolved {
@@ -22987,14 +26837,14 @@ pub struct WebhookPullRequestReviewThreadResolved {
   pub thread: WebhookPullRequestReviewThreadResolvedThread,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 impl WebhookPullRequestReviewThreadResolved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewThreadUnresolvedThread {
@@ -23002,14 +26852,14 @@ pub struct WebhookPullRequestReviewThreadUnresolvedThread {
   pub node_id: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 impl WebhookPullRequestReviewThreadUnresolvedThread {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestReviewThreadUnresolved {
@@ -23031,14 +26881,14 @@ pub struct WebhookPullRequestReviewThreadUnresolved {
   pub thread: WebhookPullRequestReviewThreadUnresolvedThread,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request_review_thread",))]
 impl WebhookPullRequestReviewThreadUnresolved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestSynchronize {
@@ -23061,14 +26911,14 @@ pub struct WebhookPullRequestSynchronize {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestSynchronize {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestUnassigned {
@@ -23094,14 +26944,14 @@ pub struct WebhookPullRequestUnassigned {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestUnassigned {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestUnlabeled {
@@ -23125,14 +26975,14 @@ pub struct WebhookPullRequestUnlabeled {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestUnlabeled {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookPullRequestUnlocked {
@@ -23153,14 +27003,14 @@ pub struct WebhookPullRequestUnlocked {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_pull_request",))]
 impl WebhookPullRequestUnlocked {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_push", feature = "webhook_status",))]
 /// Metaproperties for Git author/committer information.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -23178,14 +27028,14 @@ pub struct Committer {
   pub username: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_push", feature = "webhook_status",))]
 impl Committer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_push",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct PushEvent {
@@ -23230,14 +27080,14 @@ pub struct PushEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_push",))]
 impl PushEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackageOwner {
@@ -23262,14 +27112,14 @@ pub struct WebhookRegistryPackagePublishedRegistryPackageOwner {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackageOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionAuthor {
@@ -23294,14 +27144,14 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionAuthor {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionAuthor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainerMetadataTag {
@@ -23313,14 +27163,14 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainer
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainerMetadataTag {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainerMetadata {
@@ -23335,14 +27185,14 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainer
   pub tag: Option<WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainerMetadataTag>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainerMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionDockerMetadata {
@@ -23351,14 +27201,14 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionDockerMet
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionDockerMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionNpmMetadata {
@@ -23475,7 +27325,7 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionNpmMetada
   pub version: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionNpmMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -23508,7 +27358,7 @@ impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionNugetMetadataVa
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionNugetMetadata {
@@ -23523,19 +27373,19 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionNugetMeta
   pub value: Option<serde_json::Value>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionNugetMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionPackageFiles {
   pub content_type: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub download_url: String,
   pub id: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -23552,17 +27402,17 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionPackageFi
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub state: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionPackageFiles {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionReleaseAuthor {
@@ -23623,14 +27473,14 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionReleaseAu
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionReleaseAuthor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionRelease {
@@ -23639,7 +27489,7 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionRelease {
   pub author: Option<WebhookRegistryPackagePublishedRegistryPackagePackageVersionReleaseAuthor>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub draft: Option<bool>,
@@ -23657,7 +27507,7 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionRelease {
   pub prerelease: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub published_at: Option<String>,
+  pub published_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub tag_name: Option<String>,
@@ -23669,14 +27519,14 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersionRelease {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersionRelease {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersion {
@@ -23695,7 +27545,7 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersion {
     Option<WebhookRegistryPackagePublishedRegistryPackagePackageVersionContainerMetadata>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   pub description: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -23742,18 +27592,18 @@ pub struct WebhookRegistryPackagePublishedRegistryPackagePackageVersion {
   pub target_oid: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   pub version: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackagePackageVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackageRegistry {
@@ -23775,20 +27625,20 @@ pub struct WebhookRegistryPackagePublishedRegistryPackageRegistry {
   pub vendor: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackageRegistry {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublishedRegistryPackage {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub description: Option<String>,
@@ -23807,17 +27657,17 @@ pub struct WebhookRegistryPackagePublishedRegistryPackage {
   pub registry: Option<WebhookRegistryPackagePublishedRegistryPackageRegistry>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublishedRegistryPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackagePublished {
@@ -23838,14 +27688,14 @@ pub struct WebhookRegistryPackagePublished {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackagePublished {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackageOwner {
@@ -23870,14 +27720,14 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackageOwner {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackageOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionAuthor {
@@ -23902,14 +27752,14 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionAuthor {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackagePackageVersionAuthor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionDockerMetadata {
@@ -23918,14 +27768,14 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionDockerMetad
   pub tags: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackagePackageVersionDockerMetadata {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionPackageFiles {
@@ -23934,7 +27784,7 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionPackageFile
   pub content_type: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub download_url: Option<String>,
@@ -23961,17 +27811,17 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionPackageFile
   pub state: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackagePackageVersionPackageFiles {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionReleaseAuthor {
@@ -23996,45 +27846,45 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionReleaseAuth
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackagePackageVersionReleaseAuthor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersionRelease {
   pub author: WebhookRegistryPackageUpdatedRegistryPackagePackageVersionReleaseAuthor,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub draft: bool,
   pub html_url: String,
   pub id: i64,
   pub name: String,
   pub prerelease: bool,
-  pub published_at: String,
+  pub published_at: DateTime,
   pub tag_name: String,
   pub target_commitish: String,
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackagePackageVersionRelease {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersion {
   pub author: WebhookRegistryPackageUpdatedRegistryPackagePackageVersionAuthor,
   pub body: String,
   pub body_html: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   pub description: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -24068,22 +27918,22 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackagePackageVersion {
   pub tag_name: Option<String>,
   pub target_commitish: String,
   pub target_oid: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub version: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackagePackageVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdatedRegistryPackage {
-  pub created_at: String,
+  pub created_at: DateTime,
   pub description: serde_json::Value,
   pub ecosystem: String,
   pub html_url: String,
@@ -24096,17 +27946,17 @@ pub struct WebhookRegistryPackageUpdatedRegistryPackage {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub registry: Option<serde_json::Value>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdatedRegistryPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRegistryPackageUpdated {
@@ -24127,14 +27977,14 @@ pub struct WebhookRegistryPackageUpdated {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_registry_package",))]
 impl WebhookRegistryPackageUpdated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseCreatedEvent {
@@ -24154,14 +28004,14 @@ pub struct ReleaseCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseDeletedEvent {
@@ -24181,14 +28031,14 @@ pub struct ReleaseDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseEditedEventChangesBody {
@@ -24196,14 +28046,14 @@ pub struct ReleaseEditedEventChangesBody {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseEditedEventChangesBody {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseEditedEventChangesMakeLatest {
@@ -24211,14 +28061,14 @@ pub struct ReleaseEditedEventChangesMakeLatest {
   pub to: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseEditedEventChangesMakeLatest {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseEditedEventChangesName {
@@ -24226,14 +28076,14 @@ pub struct ReleaseEditedEventChangesName {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseEditedEventChangesName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseEditedEventChanges {
@@ -24248,14 +28098,14 @@ pub struct ReleaseEditedEventChanges {
   pub name: Option<ReleaseEditedEventChangesName>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseEditedEvent {
@@ -24278,14 +28128,14 @@ pub struct ReleaseEditedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleasePrereleasedEventReleaseItem2Author {
@@ -24346,14 +28196,14 @@ pub struct ReleasePrereleasedEventReleaseItem2Author {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleasePrereleasedEventReleaseItem2Author {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleasePrereleasedEventReleaseItem2 {
@@ -24371,7 +28221,7 @@ pub struct ReleasePrereleasedEventReleaseItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub draft: Option<bool>,
@@ -24391,7 +28241,7 @@ pub struct ReleasePrereleasedEventReleaseItem2 {
   pub prerelease: ReleasePrereleasedEventReleaseItem2Prerelease,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub published_at: Option<String>,
+  pub published_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub tag_name: Option<String>,
@@ -24412,14 +28262,14 @@ pub struct ReleasePrereleasedEventReleaseItem2 {
   pub zipball_url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleasePrereleasedEventReleaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleasePrereleasedEvent {
@@ -24440,14 +28290,14 @@ pub struct ReleasePrereleasedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleasePrereleasedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleasePublishedEventReleaseItem2Author {
@@ -24508,14 +28358,14 @@ pub struct ReleasePublishedEventReleaseItem2Author {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleasePublishedEventReleaseItem2Author {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleasePublishedEventReleaseItem2 {
@@ -24533,7 +28383,7 @@ pub struct ReleasePublishedEventReleaseItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub draft: Option<bool>,
@@ -24554,7 +28404,7 @@ pub struct ReleasePublishedEventReleaseItem2 {
   pub prerelease: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub published_at: Option<String>,
+  pub published_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub tag_name: Option<String>,
@@ -24575,14 +28425,14 @@ pub struct ReleasePublishedEventReleaseItem2 {
   pub zipball_url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleasePublishedEventReleaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleasePublishedEvent {
@@ -24603,14 +28453,14 @@ pub struct ReleasePublishedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleasePublishedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseReleasedEvent {
@@ -24632,14 +28482,14 @@ pub struct ReleaseReleasedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseReleasedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseUnpublishedEventReleaseItem2Author {
@@ -24700,14 +28550,14 @@ pub struct ReleaseUnpublishedEventReleaseItem2Author {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseUnpublishedEventReleaseItem2Author {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseUnpublishedEventReleaseItem2 {
@@ -24725,7 +28575,7 @@ pub struct ReleaseUnpublishedEventReleaseItem2 {
   pub body: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub draft: Option<bool>,
@@ -24746,7 +28596,7 @@ pub struct ReleaseUnpublishedEventReleaseItem2 {
   pub prerelease: Option<bool>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub published_at: Option<String>,
+  pub published_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub tag_name: Option<String>,
@@ -24767,14 +28617,14 @@ pub struct ReleaseUnpublishedEventReleaseItem2 {
   pub zipball_url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseUnpublishedEventReleaseItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct ReleaseUnpublishedEvent {
@@ -24795,14 +28645,14 @@ pub struct ReleaseUnpublishedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_release",))]
 impl ReleaseUnpublishedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryAdvisoryPublishedEvent {
@@ -24823,14 +28673,14 @@ pub struct RepositoryAdvisoryPublishedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 impl RepositoryAdvisoryPublishedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryAdvisoryReportedEvent {
@@ -24851,14 +28701,14 @@ pub struct RepositoryAdvisoryReportedEvent {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_advisory",))]
 impl RepositoryAdvisoryReportedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryArchivedEvent {
@@ -24876,14 +28726,14 @@ pub struct RepositoryArchivedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryArchivedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryCreatedEvent {
@@ -24901,14 +28751,14 @@ pub struct RepositoryCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryDeletedEvent {
@@ -24926,14 +28776,14 @@ pub struct RepositoryDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_dispatch",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryDispatchSample {
@@ -24955,28 +28805,28 @@ pub struct WebhookRepositoryDispatchSample {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_dispatch",))]
 impl WebhookRepositoryDispatchSample {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryEditedEventChangesDefaultBranch {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryEditedEventChangesDefaultBranch {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryEditedEventChangesDescription {
@@ -24985,14 +28835,14 @@ pub struct RepositoryEditedEventChangesDescription {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryEditedEventChangesDescription {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryEditedEventChangesHomepage {
@@ -25001,14 +28851,14 @@ pub struct RepositoryEditedEventChangesHomepage {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryEditedEventChangesHomepage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryEditedEventChangesTopics {
@@ -25017,14 +28867,14 @@ pub struct RepositoryEditedEventChangesTopics {
   pub from: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryEditedEventChangesTopics {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryEditedEventChanges {
@@ -25042,14 +28892,14 @@ pub struct RepositoryEditedEventChanges {
   pub topics: Option<RepositoryEditedEventChangesTopics>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryEditedEvent {
@@ -25068,14 +28918,14 @@ pub struct RepositoryEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_import",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryImport {
@@ -25093,14 +28943,14 @@ pub struct WebhookRepositoryImport {
   pub status: WebhookRepositoryImportStatus,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_import",))]
 impl WebhookRepositoryImport {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryPrivatizedEvent {
@@ -25118,14 +28968,14 @@ pub struct RepositoryPrivatizedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryPrivatizedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryPublicizedEvent {
@@ -25143,56 +28993,56 @@ pub struct RepositoryPublicizedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryPublicizedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRenamedEventChangesRepositoryName {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryRenamedEventChangesRepositoryName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRenamedEventChangesRepository {
   pub name: RepositoryRenamedEventChangesRepositoryName,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryRenamedEventChangesRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRenamedEventChanges {
   pub repository: RepositoryRenamedEventChangesRepository,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryRenamedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRenamedEvent {
@@ -25211,14 +29061,14 @@ pub struct RepositoryRenamedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryRenamedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetCreatedEvent {
@@ -25239,14 +29089,14 @@ pub struct RepositoryRulesetCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetDeletedEvent {
@@ -25267,14 +29117,14 @@ pub struct RepositoryRulesetDeletedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesConditionType {
@@ -25283,14 +29133,14 @@ pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesConditionT
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesConditionsUpdatedChangesConditionType {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesExclude {
@@ -25299,14 +29149,14 @@ pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesExclude {
   pub from: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesConditionsUpdatedChangesExclude {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesInclude {
@@ -25315,14 +29165,14 @@ pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesInclude {
   pub from: Option<Vec<String>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesConditionsUpdatedChangesInclude {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesTarget {
@@ -25331,14 +29181,14 @@ pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChangesTarget {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesConditionsUpdatedChangesTarget {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChanges {
@@ -25357,14 +29207,14 @@ pub struct RepositoryRulesetEditedEventChangesConditionsUpdatedChanges {
   pub target: Option<RepositoryRulesetEditedEventChangesConditionsUpdatedChangesTarget>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesConditionsUpdatedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesConditionsUpdated {
@@ -25376,14 +29226,14 @@ pub struct RepositoryRulesetEditedEventChangesConditionsUpdated {
   pub condition: Option<RepositoryRulesetConditionsForRefNames>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesConditionsUpdated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesConditions {
@@ -25398,14 +29248,14 @@ pub struct RepositoryRulesetEditedEventChangesConditions {
   pub updated: Option<Vec<RepositoryRulesetEditedEventChangesConditionsUpdated>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesConditions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesEnforcement {
@@ -25414,14 +29264,14 @@ pub struct RepositoryRulesetEditedEventChangesEnforcement {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesEnforcement {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesName {
@@ -25430,14 +29280,14 @@ pub struct RepositoryRulesetEditedEventChangesName {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChangesConfiguration {
@@ -25446,14 +29296,14 @@ pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChangesConfiguration {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesRulesUpdatedChangesConfiguration {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChangesPattern {
@@ -25462,14 +29312,14 @@ pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChangesPattern {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesRulesUpdatedChangesPattern {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChangesRuleType {
@@ -25478,14 +29328,14 @@ pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChangesRuleType {
   pub from: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesRulesUpdatedChangesRuleType {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChanges {
@@ -25500,14 +29350,14 @@ pub struct RepositoryRulesetEditedEventChangesRulesUpdatedChanges {
   pub rule_type: Option<RepositoryRulesetEditedEventChangesRulesUpdatedChangesRuleType>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesRulesUpdatedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesRulesUpdated {
@@ -25519,14 +29369,14 @@ pub struct RepositoryRulesetEditedEventChangesRulesUpdated {
   pub rule: Option<RepositoryRule>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesRulesUpdated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChangesRules {
@@ -25541,14 +29391,14 @@ pub struct RepositoryRulesetEditedEventChangesRules {
   pub updated: Option<Vec<RepositoryRulesetEditedEventChangesRulesUpdated>>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChangesRules {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEventChanges {
@@ -25566,14 +29416,14 @@ pub struct RepositoryRulesetEditedEventChanges {
   pub rules: Option<RepositoryRulesetEditedEventChangesRules>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryRulesetEditedEvent {
@@ -25597,14 +29447,14 @@ pub struct RepositoryRulesetEditedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_ruleset",))]
 impl RepositoryRulesetEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryTransferredEventChangesOwnerFrom {
@@ -25616,42 +29466,42 @@ pub struct RepositoryTransferredEventChangesOwnerFrom {
   pub user: Option<User>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryTransferredEventChangesOwnerFrom {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryTransferredEventChangesOwner {
   pub from: RepositoryTransferredEventChangesOwnerFrom,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryTransferredEventChangesOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryTransferredEventChanges {
   pub owner: RepositoryTransferredEventChangesOwner,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryTransferredEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryTransferredEvent {
@@ -25670,14 +29520,14 @@ pub struct RepositoryTransferredEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryTransferredEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryUnarchivedEvent {
@@ -25695,27 +29545,27 @@ pub struct RepositoryUnarchivedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository",))]
 impl RepositoryUnarchivedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 /// The security alert of the vulnerable dependency.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct RepositoryVulnerabilityAlertAlert {
   pub affected_package_name: String,
   pub affected_range: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismiss_reason: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub dismissed_at: Option<String>,
+  pub dismissed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismisser: Option<User>,
@@ -25728,7 +29578,7 @@ pub struct RepositoryVulnerabilityAlertAlert {
   pub fix_reason: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub fixed_at: Option<String>,
+  pub fixed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub fixed_in: Option<String>,
@@ -25740,14 +29590,14 @@ pub struct RepositoryVulnerabilityAlertAlert {
   pub state: RepositoryVulnerabilityAlertAlertState,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl RepositoryVulnerabilityAlertAlert {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertCreateAlertItem2 {
@@ -25759,7 +29609,7 @@ pub struct WebhookRepositoryVulnerabilityAlertCreateAlertItem2 {
   pub affected_range: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub external_identifier: Option<String>,
@@ -25787,14 +29637,14 @@ pub struct WebhookRepositoryVulnerabilityAlertCreateAlertItem2 {
   pub state: WebhookRepositoryVulnerabilityAlertCreateAlertItem2State,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertCreateAlertItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertCreate {
@@ -25813,14 +29663,14 @@ pub struct WebhookRepositoryVulnerabilityAlertCreate {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertCreate {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertDismissAlertItem2 {
@@ -25832,12 +29682,12 @@ pub struct WebhookRepositoryVulnerabilityAlertDismissAlertItem2 {
   pub affected_range: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismiss_comment: Option<String>,
   pub dismiss_reason: String,
-  pub dismissed_at: String,
+  pub dismissed_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub dismisser: Option<User>,
@@ -25868,14 +29718,14 @@ pub struct WebhookRepositoryVulnerabilityAlertDismissAlertItem2 {
   pub state: WebhookRepositoryVulnerabilityAlertDismissAlertItem2State,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertDismissAlertItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertDismiss {
@@ -25894,14 +29744,14 @@ pub struct WebhookRepositoryVulnerabilityAlertDismiss {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertDismiss {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertReopenAlertItem2 {
@@ -25913,7 +29763,7 @@ pub struct WebhookRepositoryVulnerabilityAlertReopenAlertItem2 {
   pub affected_range: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub external_identifier: Option<String>,
@@ -25941,14 +29791,14 @@ pub struct WebhookRepositoryVulnerabilityAlertReopenAlertItem2 {
   pub state: WebhookRepositoryVulnerabilityAlertReopenAlertItem2State,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertReopenAlertItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertReopen {
@@ -25967,14 +29817,14 @@ pub struct WebhookRepositoryVulnerabilityAlertReopen {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertReopen {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertResolveAlertItem2 {
@@ -25986,7 +29836,7 @@ pub struct WebhookRepositoryVulnerabilityAlertResolveAlertItem2 {
   pub affected_range: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub external_identifier: Option<String>,
@@ -25998,7 +29848,7 @@ pub struct WebhookRepositoryVulnerabilityAlertResolveAlertItem2 {
   pub fix_reason: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub fixed_at: Option<String>,
+  pub fixed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub fixed_in: Option<String>,
@@ -26020,14 +29870,14 @@ pub struct WebhookRepositoryVulnerabilityAlertResolveAlertItem2 {
   pub state: WebhookRepositoryVulnerabilityAlertResolveAlertItem2State,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertResolveAlertItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookRepositoryVulnerabilityAlertResolve {
@@ -26046,20 +29896,24 @@ pub struct WebhookRepositoryVulnerabilityAlertResolve {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_repository_vulnerability_alert",))]
 impl WebhookRepositoryVulnerabilityAlertResolve {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SecretScanningAlertWebhook {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub html_url: Option<String>,
@@ -26077,7 +29931,7 @@ pub struct SecretScanningAlertWebhook {
   /// The time that push protection was bypassed in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub push_protection_bypassed_at: Option<String>,
+  pub push_protection_bypassed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub push_protection_bypassed_by: Option<SimpleUser>,
@@ -26091,7 +29945,7 @@ pub struct SecretScanningAlertWebhook {
   /// The time that the alert was resolved in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub resolved_at: Option<String>,
+  pub resolved_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub resolved_by: Option<SimpleUser>,
@@ -26111,14 +29965,18 @@ pub struct SecretScanningAlertWebhook {
   pub validity: Option<SecretScanningAlertWebhookValidity>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(
+  feature = "full",
+  feature = "webhook_secret_scanning_alert",
+  feature = "webhook_secret_scanning_alert_location",
+))]
 impl SecretScanningAlertWebhook {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecretScanningAlertCreated {
@@ -26139,14 +29997,14 @@ pub struct WebhookSecretScanningAlertCreated {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl WebhookSecretScanningAlertCreated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SecretScanningAlertLocationCreatedEvent {
@@ -26165,14 +30023,14 @@ pub struct SecretScanningAlertLocationCreatedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert_location",))]
 impl SecretScanningAlertLocationCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecretScanningAlertReopened {
@@ -26193,14 +30051,14 @@ pub struct WebhookSecretScanningAlertReopened {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl WebhookSecretScanningAlertReopened {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecretScanningAlertResolved {
@@ -26221,14 +30079,14 @@ pub struct WebhookSecretScanningAlertResolved {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl WebhookSecretScanningAlertResolved {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecretScanningAlertRevoked {
@@ -26249,14 +30107,14 @@ pub struct WebhookSecretScanningAlertRevoked {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl WebhookSecretScanningAlertRevoked {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecretScanningAlertValidated {
@@ -26277,14 +30135,14 @@ pub struct WebhookSecretScanningAlertValidated {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_secret_scanning_alert",))]
 impl WebhookSecretScanningAlertValidated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryCvss {
@@ -26294,14 +30152,14 @@ pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryCvss {
   pub vector_string: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisoryCvss {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryCwes {
@@ -26309,14 +30167,14 @@ pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryCwes {
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisoryCwes {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryIdentifiers {
@@ -26325,42 +30183,42 @@ pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryIdentifiers {
   pub value: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisoryIdentifiers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryReferences {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisoryReferences {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilitiesFirstPatchedVersion {
   pub identifier: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilitiesFirstPatchedVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilitiesPackage {
@@ -26368,14 +30226,14 @@ pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilitiesPackag
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilitiesPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilities {
@@ -26388,14 +30246,14 @@ pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilities {
   pub vulnerable_version_range: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilities {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 /// The details of the security advisory, including summary, description, and severity.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -26405,25 +30263,25 @@ pub struct WebhookSecurityAdvisoryPublishedSecurityAdvisory {
   pub description: String,
   pub ghsa_id: String,
   pub identifiers: Vec<WebhookSecurityAdvisoryPublishedSecurityAdvisoryIdentifiers>,
-  pub published_at: String,
+  pub published_at: DateTime,
   pub references: Vec<WebhookSecurityAdvisoryPublishedSecurityAdvisoryReferences>,
   pub severity: String,
   pub summary: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub vulnerabilities: Vec<WebhookSecurityAdvisoryPublishedSecurityAdvisoryVulnerabilities>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub withdrawn_at: Option<String>,
+  pub withdrawn_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublishedSecurityAdvisory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryPublished {
@@ -26447,14 +30305,14 @@ pub struct WebhookSecurityAdvisoryPublished {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryPublished {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryCvss {
@@ -26464,14 +30322,14 @@ pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryCvss {
   pub vector_string: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisoryCvss {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryCwes {
@@ -26479,14 +30337,14 @@ pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryCwes {
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisoryCwes {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryIdentifiers {
@@ -26495,42 +30353,42 @@ pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryIdentifiers {
   pub value: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisoryIdentifiers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryReferences {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisoryReferences {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilitiesFirstPatchedVersion {
   pub identifier: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilitiesFirstPatchedVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilitiesPackage {
@@ -26538,14 +30396,14 @@ pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilitiesPackage
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilitiesPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilities {
@@ -26558,14 +30416,14 @@ pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilities {
   pub vulnerable_version_range: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilities {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 /// The details of the security advisory, including summary, description, and severity.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -26575,25 +30433,25 @@ pub struct WebhookSecurityAdvisoryUpdatedSecurityAdvisory {
   pub description: String,
   pub ghsa_id: String,
   pub identifiers: Vec<WebhookSecurityAdvisoryUpdatedSecurityAdvisoryIdentifiers>,
-  pub published_at: String,
+  pub published_at: DateTime,
   pub references: Vec<WebhookSecurityAdvisoryUpdatedSecurityAdvisoryReferences>,
   pub severity: String,
   pub summary: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub vulnerabilities: Vec<WebhookSecurityAdvisoryUpdatedSecurityAdvisoryVulnerabilities>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub withdrawn_at: Option<String>,
+  pub withdrawn_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdatedSecurityAdvisory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryUpdated {
@@ -26617,14 +30475,14 @@ pub struct WebhookSecurityAdvisoryUpdated {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryUpdated {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryCvss {
@@ -26634,14 +30492,14 @@ pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryCvss {
   pub vector_string: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryCvss {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryCwes {
@@ -26649,14 +30507,14 @@ pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryCwes {
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryCwes {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryIdentifiers {
@@ -26665,42 +30523,42 @@ pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryIdentifiers {
   pub value: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryIdentifiers {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryReferences {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryReferences {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilitiesFirstPatchedVersion {
   pub identifier: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilitiesFirstPatchedVersion {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilitiesPackage {
@@ -26708,14 +30566,14 @@ pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilitiesPackag
   pub name: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilitiesPackage {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilities {
@@ -26728,14 +30586,14 @@ pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilities {
   pub vulnerable_version_range: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilities {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 /// The details of the security advisory, including summary, description, and severity.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -26745,23 +30603,23 @@ pub struct WebhookSecurityAdvisoryWithdrawnSecurityAdvisory {
   pub description: String,
   pub ghsa_id: String,
   pub identifiers: Vec<WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryIdentifiers>,
-  pub published_at: String,
+  pub published_at: DateTime,
   pub references: Vec<WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryReferences>,
   pub severity: String,
   pub summary: String,
-  pub updated_at: String,
+  pub updated_at: DateTime,
   pub vulnerabilities: Vec<WebhookSecurityAdvisoryWithdrawnSecurityAdvisoryVulnerabilities>,
-  pub withdrawn_at: String,
+  pub withdrawn_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawnSecurityAdvisory {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAdvisoryWithdrawn {
@@ -26785,14 +30643,14 @@ pub struct WebhookSecurityAdvisoryWithdrawn {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_advisory",))]
 impl WebhookSecurityAdvisoryWithdrawn {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAndAnalysisChangesFrom {
@@ -26801,14 +30659,14 @@ pub struct WebhookSecurityAndAnalysisChangesFrom {
   pub security_and_analysis: Option<SecurityAndAnalysis>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
 impl WebhookSecurityAndAnalysisChangesFrom {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAndAnalysisChanges {
@@ -26817,14 +30675,14 @@ pub struct WebhookSecurityAndAnalysisChanges {
   pub from: Option<WebhookSecurityAndAnalysisChangesFrom>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
 impl WebhookSecurityAndAnalysisChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSecurityAndAnalysis {
@@ -26844,14 +30702,14 @@ pub struct WebhookSecurityAndAnalysis {
   pub sender: Option<SimpleUser>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_security_and_analysis",))]
 impl WebhookSecurityAndAnalysis {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipCancelledEventSponsorshipMaintainer {
@@ -26912,19 +30770,19 @@ pub struct SponsorshipCancelledEventSponsorshipMaintainer {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipCancelledEventSponsorshipMaintainer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 /// The `tier_changed` and `pending_tier_change` will include the original tier before the change or pending change. For more information, see the pending tier change payload.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipTier {
-  pub created_at: String,
+  pub created_at: DateTime,
   pub description: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -26939,18 +30797,18 @@ pub struct SponsorshipTier {
   pub node_id: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipTier {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipCancelledEventSponsorship {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub maintainer: Option<SponsorshipCancelledEventSponsorshipMaintainer>,
@@ -26966,14 +30824,14 @@ pub struct SponsorshipCancelledEventSponsorship {
   pub tier: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipCancelledEventSponsorship {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipCancelledEvent {
@@ -26994,14 +30852,14 @@ pub struct SponsorshipCancelledEvent {
   pub sponsorship: SponsorshipCancelledEventSponsorship,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipCancelledEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipCreatedEventSponsorshipMaintainer {
@@ -27062,18 +30920,18 @@ pub struct SponsorshipCreatedEventSponsorshipMaintainer {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipCreatedEventSponsorshipMaintainer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipCreatedEventSponsorship {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub maintainer: Option<SponsorshipCreatedEventSponsorshipMaintainer>,
@@ -27089,14 +30947,14 @@ pub struct SponsorshipCreatedEventSponsorship {
   pub tier: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipCreatedEventSponsorship {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipCreatedEvent {
@@ -27117,14 +30975,14 @@ pub struct SponsorshipCreatedEvent {
   pub sponsorship: SponsorshipCreatedEventSponsorship,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipEditedEventChangesPrivacyLevel {
@@ -27132,14 +30990,14 @@ pub struct SponsorshipEditedEventChangesPrivacyLevel {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipEditedEventChangesPrivacyLevel {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipEditedEventChanges {
@@ -27148,14 +31006,14 @@ pub struct SponsorshipEditedEventChanges {
   pub privacy_level: Option<SponsorshipEditedEventChangesPrivacyLevel>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipEditedEventSponsorshipMaintainer {
@@ -27216,18 +31074,18 @@ pub struct SponsorshipEditedEventSponsorshipMaintainer {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipEditedEventSponsorshipMaintainer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipEditedEventSponsorship {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub maintainer: Option<SponsorshipEditedEventSponsorshipMaintainer>,
@@ -27243,14 +31101,14 @@ pub struct SponsorshipEditedEventSponsorship {
   pub tier: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipEditedEventSponsorship {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct SponsorshipEditedEvent {
@@ -27272,14 +31130,14 @@ pub struct SponsorshipEditedEvent {
   pub sponsorship: SponsorshipEditedEventSponsorship,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl SponsorshipEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingCancellationSponsorshipMaintainer {
@@ -27340,18 +31198,18 @@ pub struct WebhookSponsorshipPendingCancellationSponsorshipMaintainer {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingCancellationSponsorshipMaintainer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingCancellationSponsorship {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub maintainer: Option<WebhookSponsorshipPendingCancellationSponsorshipMaintainer>,
@@ -27367,14 +31225,14 @@ pub struct WebhookSponsorshipPendingCancellationSponsorship {
   pub tier: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingCancellationSponsorship {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingCancellation {
@@ -27399,14 +31257,14 @@ pub struct WebhookSponsorshipPendingCancellation {
   pub sponsorship: WebhookSponsorshipPendingCancellationSponsorship,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingCancellation {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingTierChangeChangesTier {
@@ -27414,28 +31272,28 @@ pub struct WebhookSponsorshipPendingTierChangeChangesTier {
   pub from: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingTierChangeChangesTier {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingTierChangeChanges {
   pub tier: WebhookSponsorshipPendingTierChangeChangesTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingTierChangeChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingTierChangeSponsorshipMaintainer {
@@ -27496,18 +31354,18 @@ pub struct WebhookSponsorshipPendingTierChangeSponsorshipMaintainer {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingTierChangeSponsorshipMaintainer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingTierChangeSponsorship {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub maintainer: Option<WebhookSponsorshipPendingTierChangeSponsorshipMaintainer>,
@@ -27523,14 +31381,14 @@ pub struct WebhookSponsorshipPendingTierChangeSponsorship {
   pub tier: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingTierChangeSponsorship {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipPendingTierChange {
@@ -27556,14 +31414,14 @@ pub struct WebhookSponsorshipPendingTierChange {
   pub sponsorship: WebhookSponsorshipPendingTierChangeSponsorship,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipPendingTierChange {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipTierChangedChangesTier {
@@ -27571,28 +31429,28 @@ pub struct WebhookSponsorshipTierChangedChangesTier {
   pub from: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipTierChangedChangesTier {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipTierChangedChanges {
   pub tier: WebhookSponsorshipTierChangedChangesTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipTierChangedChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipTierChangedSponsorshipMaintainer {
@@ -27653,18 +31511,18 @@ pub struct WebhookSponsorshipTierChangedSponsorshipMaintainer {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipTierChangedSponsorshipMaintainer {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipTierChangedSponsorship {
-  pub created_at: String,
+  pub created_at: DateTime,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub maintainer: Option<WebhookSponsorshipTierChangedSponsorshipMaintainer>,
@@ -27680,14 +31538,14 @@ pub struct WebhookSponsorshipTierChangedSponsorship {
   pub tier: SponsorshipTier,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipTierChangedSponsorship {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookSponsorshipTierChanged {
@@ -27709,14 +31567,14 @@ pub struct WebhookSponsorshipTierChanged {
   pub sponsorship: WebhookSponsorshipTierChangedSponsorship,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_sponsorship",))]
 impl WebhookSponsorshipTierChanged {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StarCreatedEvent {
@@ -27735,17 +31593,17 @@ pub struct StarCreatedEvent {
   /// The time the star was created. This is a timestamp in ISO 8601 format: `YYYY-MM-DDTHH:MM:SSZ`. Will be `null` for the `deleted` action.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub starred_at: Option<String>,
+  pub starred_at: Option<DateTime>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 impl StarCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StarDeletedEvent {
@@ -27765,14 +31623,14 @@ pub struct StarDeletedEvent {
   pub starred_at: serde_json::Value,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_star",))]
 impl StarDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventBranchesCommit {
@@ -27784,14 +31642,14 @@ pub struct StatusEventBranchesCommit {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventBranchesCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventBranches {
@@ -27800,14 +31658,14 @@ pub struct StatusEventBranches {
   pub protected: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventBranches {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventCommitCommitAuthorItem2 {
@@ -27820,14 +31678,14 @@ pub struct StatusEventCommitCommitAuthorItem2 {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventCommitCommitAuthorItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventCommitCommitCommitterItem2 {
@@ -27840,14 +31698,14 @@ pub struct StatusEventCommitCommitCommitterItem2 {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventCommitCommitCommitterItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventCommitCommitTree {
@@ -27855,14 +31713,14 @@ pub struct StatusEventCommitCommitTree {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventCommitCommitTree {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventCommitCommitVerification {
@@ -27876,14 +31734,14 @@ pub struct StatusEventCommitCommitVerification {
   pub verified: bool,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventCommitCommitVerification {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventCommitCommit {
@@ -27896,14 +31754,14 @@ pub struct StatusEventCommitCommit {
   pub verification: StatusEventCommitCommitVerification,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventCommitCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventCommitParents {
@@ -27912,14 +31770,14 @@ pub struct StatusEventCommitParents {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventCommitParents {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEventCommit {
@@ -27938,14 +31796,14 @@ pub struct StatusEventCommit {
   pub url: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEventCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct StatusEvent {
@@ -27956,7 +31814,7 @@ pub struct StatusEvent {
   pub branches: Vec<StatusEventBranches>,
   pub commit: StatusEventCommit,
   pub context: String,
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The optional human-readable description added to the status.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -27983,17 +31841,17 @@ pub struct StatusEvent {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub target_url: Option<String>,
-  pub updated_at: String,
+  pub updated_at: DateTime,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_status",))]
 impl StatusEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team_add",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookTeamAdd {
@@ -28012,14 +31870,14 @@ pub struct WebhookTeamAdd {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team_add",))]
 impl WebhookTeamAdd {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookTeamAddedToRepository {
@@ -28042,14 +31900,14 @@ pub struct WebhookTeamAddedToRepository {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl WebhookTeamAddedToRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamCreatedEvent {
@@ -28070,14 +31928,14 @@ pub struct TeamCreatedEvent {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamCreatedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamDeletedEvent {
@@ -28100,14 +31958,14 @@ pub struct TeamDeletedEvent {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamDeletedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEventChangesDescription {
@@ -28115,14 +31973,14 @@ pub struct TeamEditedEventChangesDescription {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChangesDescription {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEventChangesName {
@@ -28130,14 +31988,14 @@ pub struct TeamEditedEventChangesName {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChangesName {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEventChangesNotificationSetting {
@@ -28145,14 +32003,14 @@ pub struct TeamEditedEventChangesNotificationSetting {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChangesNotificationSetting {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEventChangesPrivacy {
@@ -28160,14 +32018,14 @@ pub struct TeamEditedEventChangesPrivacy {
   pub from: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChangesPrivacy {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEventChangesRepositoryPermissionsFrom {
@@ -28185,42 +32043,42 @@ pub struct TeamEditedEventChangesRepositoryPermissionsFrom {
   pub push: Option<bool>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChangesRepositoryPermissionsFrom {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEventChangesRepositoryPermissions {
   pub from: TeamEditedEventChangesRepositoryPermissionsFrom,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChangesRepositoryPermissions {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEventChangesRepository {
   pub permissions: TeamEditedEventChangesRepositoryPermissions,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChangesRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 /// The changes to the team if the action was `edited`.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -28242,14 +32100,14 @@ pub struct TeamEditedEventChanges {
   pub repository: Option<TeamEditedEventChangesRepository>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEventChanges {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct TeamEditedEvent {
@@ -28272,14 +32130,14 @@ pub struct TeamEditedEvent {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl TeamEditedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookTeamRemovedFromRepository {
@@ -28300,14 +32158,14 @@ pub struct WebhookTeamRemovedFromRepository {
   pub team: Team,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_team",))]
 impl WebhookTeamRemovedFromRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_watch",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WatchStartedEvent {
@@ -28325,14 +32183,14 @@ pub struct WatchStartedEvent {
   pub sender: SimpleUser,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_watch",))]
 impl WatchStartedEvent {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_dispatch",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowDispatch {
@@ -28355,20 +32213,20 @@ pub struct WebhookWorkflowDispatch {
   pub workflow: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_dispatch",))]
 impl WebhookWorkflowDispatch {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WorkflowStep {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<WorkflowStepConclusion>,
@@ -28376,18 +32234,18 @@ pub struct WorkflowStep {
   pub number: i64,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub started_at: Option<String>,
+  pub started_at: Option<DateTime>,
   pub status: WorkflowStepStatus,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WorkflowStep {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 /// The workflow job. Many `workflow_job` keys, such as `head_sha`, `conclusion`, and `started_at` are the same as those in a [`check_run`](#check_run) object.
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
@@ -28395,12 +32253,12 @@ pub struct WorkflowJob {
   pub check_run_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<WorkflowJobConclusion>,
   /// The time that the job created.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the current branch.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -28431,7 +32289,7 @@ pub struct WorkflowJob {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub runner_name: Option<String>,
-  pub started_at: String,
+  pub started_at: DateTime,
   /// The current status of the job. Can be `queued`, `in_progress`, `waiting`, or `completed`.
   pub status: WorkflowJobStatus,
   pub steps: Vec<WorkflowStep>,
@@ -28442,14 +32300,14 @@ pub struct WorkflowJob {
   pub workflow_name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WorkflowJob {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobCompletedWorkflowJobItem2 {
@@ -28458,12 +32316,12 @@ pub struct WebhookWorkflowJobCompletedWorkflowJobItem2 {
   pub check_run_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   pub conclusion: WebhookWorkflowJobCompletedWorkflowJobItem2Conclusion,
   /// The time that the job created.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// The name of the current branch.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -28509,7 +32367,7 @@ pub struct WebhookWorkflowJobCompletedWorkflowJobItem2 {
   pub runner_name: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub started_at: Option<String>,
+  pub started_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub status: Option<String>,
@@ -28525,14 +32383,14 @@ pub struct WebhookWorkflowJobCompletedWorkflowJobItem2 {
   pub workflow_name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobCompletedWorkflowJobItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobCompleted {
@@ -28554,14 +32412,14 @@ pub struct WebhookWorkflowJobCompleted {
   pub workflow_job: WebhookWorkflowJobCompletedWorkflowJob,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobCompleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobInProgressWorkflowJobItem2 {
@@ -28570,14 +32428,14 @@ pub struct WebhookWorkflowJobInProgressWorkflowJobItem2 {
   pub check_run_url: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<String>,
   /// The time that the job created.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   /// The name of the current branch.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -28623,7 +32481,7 @@ pub struct WebhookWorkflowJobInProgressWorkflowJobItem2 {
   pub runner_name: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub started_at: Option<String>,
+  pub started_at: Option<DateTime>,
   pub status: WebhookWorkflowJobInProgressWorkflowJobItem2Status,
   pub steps: Vec<WorkflowStep>,
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -28635,14 +32493,14 @@ pub struct WebhookWorkflowJobInProgressWorkflowJobItem2 {
   pub workflow_name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobInProgressWorkflowJobItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobInProgress {
@@ -28664,26 +32522,26 @@ pub struct WebhookWorkflowJobInProgress {
   pub workflow_job: WebhookWorkflowJobInProgressWorkflowJob,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobInProgress {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobQueuedWorkflowJob {
   pub check_run_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<String>,
   /// The time that the job created.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the current branch.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -28709,7 +32567,7 @@ pub struct WebhookWorkflowJobQueuedWorkflowJob {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub runner_name: Option<String>,
-  pub started_at: String,
+  pub started_at: DateTime,
   pub status: WebhookWorkflowJobQueuedWorkflowJobStatus,
   pub steps: Vec<WorkflowStep>,
   pub url: String,
@@ -28719,14 +32577,14 @@ pub struct WebhookWorkflowJobQueuedWorkflowJob {
   pub workflow_name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobQueuedWorkflowJob {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobQueued {
@@ -28748,26 +32606,26 @@ pub struct WebhookWorkflowJobQueued {
   pub workflow_job: WebhookWorkflowJobQueuedWorkflowJob,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobQueued {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobWaitingWorkflowJob {
   pub check_run_url: String,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub completed_at: Option<String>,
+  pub completed_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub conclusion: Option<String>,
   /// The time that the job created.
-  pub created_at: String,
+  pub created_at: DateTime,
   /// The name of the current branch.
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
@@ -28793,7 +32651,7 @@ pub struct WebhookWorkflowJobWaitingWorkflowJob {
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub runner_name: Option<String>,
-  pub started_at: String,
+  pub started_at: DateTime,
   pub status: WebhookWorkflowJobWaitingWorkflowJobStatus,
   pub steps: Vec<WorkflowStep>,
   pub url: String,
@@ -28803,14 +32661,14 @@ pub struct WebhookWorkflowJobWaitingWorkflowJob {
   pub workflow_name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobWaitingWorkflowJob {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowJobWaiting {
@@ -28832,7 +32690,7 @@ pub struct WebhookWorkflowJobWaiting {
   pub workflow_job: WebhookWorkflowJobWaitingWorkflowJob,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_job",))]
 impl WebhookWorkflowJobWaiting {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -28976,7 +32834,7 @@ impl WorkflowRunReferencedWorkflows {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2Actor {
@@ -29037,14 +32895,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2Actor {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2Actor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommitAuthor {
@@ -29056,14 +32914,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommitAuthor {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommitAuthor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommitCommitter {
@@ -29075,14 +32933,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommitCommitter {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommitCommitter {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommit {
@@ -29106,14 +32964,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommit {
   pub tree_id: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2HeadCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadRepositoryOwner {
@@ -29174,14 +33032,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadRepositoryOwner {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2HeadRepositoryOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadRepository {
@@ -29325,14 +33183,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2HeadRepository {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2HeadRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2ReferencedWorkflows {
@@ -29344,14 +33202,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2ReferencedWorkflows {
   pub sha: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2ReferencedWorkflows {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2RepositoryOwner {
@@ -29412,14 +33270,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2RepositoryOwner {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2RepositoryOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2Repository {
@@ -29563,14 +33421,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2Repository {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2Repository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2TriggeringActor {
@@ -29631,14 +33489,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2TriggeringActor {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2TriggeringActor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompletedWorkflowRunItem2 {
@@ -29663,7 +33521,7 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2 {
   pub conclusion: WebhookWorkflowRunCompletedWorkflowRunItem2Conclusion,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub event: Option<String>,
@@ -29724,7 +33582,7 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2 {
   pub run_number: Option<i64>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub run_started_at: Option<String>,
+  pub run_started_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub status: Option<String>,
@@ -29733,7 +33591,7 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2 {
   pub triggering_actor: Option<WebhookWorkflowRunCompletedWorkflowRunItem2TriggeringActor>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -29745,14 +33603,14 @@ pub struct WebhookWorkflowRunCompletedWorkflowRunItem2 {
   pub workflow_url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompletedWorkflowRunItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunCompleted {
@@ -29774,14 +33632,14 @@ pub struct WebhookWorkflowRunCompleted {
   pub workflow_run: WebhookWorkflowRunCompletedWorkflowRun,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunCompleted {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2Actor {
@@ -29842,14 +33700,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2Actor {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2Actor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommitAuthor {
@@ -29861,14 +33719,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommitAuthor {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommitAuthor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommitCommitter {
@@ -29880,14 +33738,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommitCommitter {
   pub name: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommitCommitter {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommit {
@@ -29911,14 +33769,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommit {
   pub tree_id: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2HeadCommit {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadRepositoryOwner {
@@ -29979,14 +33837,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadRepositoryOwner {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2HeadRepositoryOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadRepository {
@@ -30130,14 +33988,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2HeadRepository {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2HeadRepository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2ReferencedWorkflows {
@@ -30149,14 +34007,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2ReferencedWorkflows {
   pub sha: String,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2ReferencedWorkflows {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2RepositoryOwner {
@@ -30217,14 +34075,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2RepositoryOwner {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2RepositoryOwner {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2Repository {
@@ -30368,14 +34226,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2Repository {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2Repository {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2TriggeringActor {
@@ -30436,14 +34294,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2TriggeringActor {
   pub url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2TriggeringActor {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgressWorkflowRunItem2 {
@@ -30470,7 +34328,7 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2 {
   pub conclusion: Option<WebhookWorkflowRunInProgressWorkflowRunItem2Conclusion>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub created_at: Option<String>,
+  pub created_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub event: Option<String>,
@@ -30531,7 +34389,7 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2 {
   pub run_number: Option<i64>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub run_started_at: Option<String>,
+  pub run_started_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub status: Option<String>,
@@ -30540,7 +34398,7 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2 {
   pub triggering_actor: Option<WebhookWorkflowRunInProgressWorkflowRunItem2TriggeringActor>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
-  pub updated_at: Option<String>,
+  pub updated_at: Option<DateTime>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[builder(default, setter(strip_option))]
   pub url: Option<String>,
@@ -30552,14 +34410,14 @@ pub struct WebhookWorkflowRunInProgressWorkflowRunItem2 {
   pub workflow_url: Option<String>,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgressWorkflowRunItem2 {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunInProgress {
@@ -30581,14 +34439,14 @@ pub struct WebhookWorkflowRunInProgress {
   pub workflow_run: WebhookWorkflowRunInProgressWorkflowRun,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunInProgress {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
     self
   }
 }
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 #[derive(Debug, Clone, Serialize, Deserialize, TypedBuilder)]
 #[builder(field_defaults(setter(into)))]
 pub struct WebhookWorkflowRunRequested {
@@ -30610,7 +34468,7 @@ pub struct WebhookWorkflowRunRequested {
   pub workflow_run: WorkflowRun,
 }
 
-#[cfg(any(feature = "full", feature = "webhook",))]
+#[cfg(any(feature = "full", feature = "webhook_workflow_run",))]
 impl WebhookWorkflowRunRequested {
   #[deprecated(note = "This is no longer required due to the use of [`TypedBuilder`]")]
   pub fn unwrap(self) -> Self {
@@ -0,0 +1,54 @@
+//! The fallback variant of the enumerations, with and without the `strict` feature.
+#![cfg(any(feature = "full", feature = "security_advisories"))]
+
+use octocrate_types::*;
+
+#[test]
+#[cfg(not(feature = "strict"))]
+fn test_unknown_enum_value() {
+  use SecurityAdvisoriesListGlobalAdvisoriesQueryType as AdvisoryType;
+
+  let types: Vec<AdvisoryType> = serde_json::from_str(r#"["reviewed", "withdrawn"]"#).unwrap();
+
+  assert_eq!(
+    types,
+    vec![
+      AdvisoryType::Reviewed,
+      AdvisoryType::Unknown("withdrawn".to_string())
+    ]
+  );
+  assert_eq!(types[1].to_string(), "withdrawn");
+  assert_eq!(
+    serde_json::to_string(&types).unwrap(),
+    r#"["reviewed","withdrawn"]"#
+  );
+
+  // `unknown` is a value of the enumeration, and new values are kept in `Other`
+  let severities: Vec<SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity> =
+    serde_json::from_str(r#"["unknown", "severe"]"#).unwrap();
+
+  assert_eq!(
+    severities,
+    vec![
+      SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity::Unknown,
+      SecurityAdvisoriesListGlobalAdvisoriesQuerySeverity::Other("severe".to_string())
+    ]
+  );
+}
+
+#[test]
+#[cfg(feature = "strict")]
+fn test_strict_enum_value() {
+  use SecurityAdvisoriesListGlobalAdvisoriesQueryType as AdvisoryType;
+
+  let reviewed: AdvisoryType = serde_json::from_str(r#""reviewed""#).unwrap();
+
+  assert_eq!(reviewed, AdvisoryType::Reviewed);
+  assert!(serde_json::from_str::<AdvisoryType>(r#""withdrawn""#).is_err());
+
+  // The variant is still there, for the crates of the graph that match on it
+  assert_eq!(
+    serde_json::to_string(&AdvisoryType::Unknown("withdrawn".to_string())).unwrap(),
+    r#""withdrawn""#
+  );
+}